@@ -731,6 +731,7 @@ async function commitMainRandomness(
       lotteryState: config.mainPDAs.lotteryState,
       randomnessAccountData: randomnessKeypair.publicKey,
       switchboardQueue: config.switchboardQueue,
+      prizePoolUsdc: config.mainPDAs.prizePoolUsdc,
       systemProgram: SystemProgram.programId,
     })
     .instruction();
@@ -1245,7 +1246,7 @@ async function handleStuckMainDraw(
 
     try {
       const signature = await (mainProgram.methods as any)
-        .cancelDraw("Bot: commit timed out after " + elapsed + "s")
+        .cancelDraw({ preserveTickets: {} })
         .accounts({
           authority: config.authorityKeypair.publicKey,
           lotteryState: config.mainPDAs.lotteryState,
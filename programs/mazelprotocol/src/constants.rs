@@ -26,12 +26,71 @@ pub const QUICK_PICK_SEED: &[u8] = b"quick_pick";
 pub const SYNDICATE_WARS_SEED: &[u8] = b"syndicate_wars";
 /// PDA seed for unified ticket accounts (bulk purchases)
 pub const UNIFIED_TICKET_SEED: &[u8] = b"unified_ticket";
+/// PDA seed for multi-draw ticket accounts
+pub const MULTI_DRAW_TICKET_SEED: &[u8] = b"multi_draw";
+/// PDA seed for the per-draw tickets of a recurring ticket purchase
+pub const RECURRING_TICKET_SEED: &[u8] = b"recurring_ticket";
+/// PDA seed for the recurring ticket schedule (pending tickets per future draw)
+pub const RECURRING_SCHEDULE_SEED: &[u8] = b"recurring_schedule";
+/// PDA seed for private syndicate invites
+pub const SYNDICATE_INVITE_SEED: &[u8] = b"syndicate_invite";
+/// PDA seed for the public syndicate registry
+pub const SYNDICATE_REGISTRY_SEED: &[u8] = b"syndicate_registry";
+/// PDA seed for per-syndicate waitlists
+pub const SYNDICATE_WAITLIST_SEED: &[u8] = b"waitlist";
+/// PDA seed for per-syndicate manager fee increase proposals
+pub const SYNDICATE_FEE_PROPOSAL_SEED: &[u8] = b"fee_proposal";
+/// PDA seed for per-draw staged syndicate prizes
+pub const SYNDICATE_PRIZE_STAGE_SEED: &[u8] = b"prize_stage";
+/// PDA seed for the recent draw history ring buffer
+pub const DRAW_HISTORY_SEED: &[u8] = b"draw_history";
+/// PDA seed for the all-time jackpot leaderboard
+pub const JACKPOT_LEADERBOARD_SEED: &[u8] = b"jackpot_leaderboard";
+/// PDA seed for the winning number frequency histogram
+pub const NUMBER_FREQUENCY_SEED: &[u8] = b"number_freq";
+/// PDA seed for the winning number bias detector
+pub const BIAS_DETECTOR_SEED: &[u8] = b"bias_detector";
+/// PDA seed for the lifetime lottery analytics
+pub const ANALYTICS_SEED: &[u8] = b"analytics";
+/// PDA seed for per-draw second chance draw accounts
+pub const SECOND_CHANCE_SEED: &[u8] = b"second_chance";
+/// PDA seed for second chance entry accounts
+pub const SECOND_CHANCE_ENTRY_SEED: &[u8] = b"second_chance_entry";
+/// PDA seed for prize escrow records
+pub const ESCROW_SEED: &[u8] = b"escrow";
+/// PDA seed for prize escrow USDC token accounts
+pub const ESCROW_USDC_SEED: &[u8] = b"escrow_usdc";
+/// PDA seed for cancelled draw ticket refund records
+pub const REFUND_RECORD_SEED: &[u8] = b"refund_record";
+/// PDA seed for the reserve balance history ring buffer
+pub const RESERVE_HISTORY_SEED: &[u8] = b"reserve_history";
+/// PDA seed for the purchase whitelist
+pub const WHITELIST_SEED: &[u8] = b"whitelist";
+/// PDA seed for a player's KYC record
+pub const KYC_SEED: &[u8] = b"kyc";
+/// PDA seed for a player's identity proof
+pub const IDENTITY_SEED: &[u8] = b"identity";
+/// PDA seed for a draw result challenge
+pub const CHALLENGE_SEED: &[u8] = b"challenge";
+/// PDA seed for a pending winning numbers override
+pub const PENDING_OVERRIDE_SEED: &[u8] = b"pending_override";
+/// PDA seed for the house fee recipients config
+pub const FEE_RECIPIENTS_SEED: &[u8] = b"fee_recipients";
 /// PDA seed for prize pool USDC token account
 pub const PRIZE_POOL_USDC_SEED: &[u8] = b"prize_pool_usdc";
 /// PDA seed for house fee USDC token account
 pub const HOUSE_FEE_USDC_SEED: &[u8] = b"house_fee_usdc";
-/// PDA seed for insurance pool USDC token account
-pub const INSURANCE_POOL_USDC_SEED: &[u8] = b"insurance_pool_usdc";
+/// PDA seed for the insurance tier 1 (liquid) USDC token account
+/// (keeps the original insurance pool seed)
+pub const INSURANCE_TIER1_SEED: &[u8] = b"insurance_pool_usdc";
+/// PDA seed for the insurance tier 2 (delayed) USDC token account
+pub const INSURANCE_TIER2_SEED: &[u8] = b"insurance_tier2_usdc";
+/// PDA seed for the pending insurance tier 2 withdrawal
+pub const TIER2_WITHDRAWAL_SEED: &[u8] = b"tier2_withdrawal";
+/// PDA seed for the Mazel Protocol program registry
+pub const PROTOCOL_REGISTRY_SEED: &[u8] = b"protocol_registry";
+/// PDA seed for a free QuickPick ticket credit
+pub const FREE_QP_CREDIT_SEED: &[u8] = b"free_qp_credit";
 
 // ============================================================================
 // GAME PARAMETERS (Main 6/46 Lottery)
@@ -52,17 +111,69 @@ pub const MAX_NUMBER: u8 = 46;
 /// Draw interval in seconds (24 hours)
 pub const DRAW_INTERVAL: i64 = 86400;
 
-/// Time before draw when ticket sales close (1 hour before draw)
-pub const TICKET_SALE_CUTOFF: i64 = 3600;
+/// Default time before draw when ticket sales close (5 minutes before draw).
+/// Configurable per lottery via `LotteryState::ticket_sale_cutoff_seconds`.
+pub const TICKET_SALE_CUTOFF: i64 = 300;
+
+/// Minimum configurable ticket sale cutoff (1 minute)
+pub const MIN_TICKET_SALE_CUTOFF: i64 = 60;
 
 /// Timeout for draw commit (1 hour) - if reveal doesn't happen, draw can be cancelled
 pub const DRAW_COMMIT_TIMEOUT: i64 = 3600;
 
+/// Delay after commit before execute_draw may fall back to slot-hash
+/// randomness when the VRF result is still unset (30 minutes)
+pub const VRF_FALLBACK_DELAY_SECONDS: i64 = 1800;
+
+/// Grace beyond the draw interval before `check_oracle_health` considers a
+/// randomness account's last reveal stale (1 hour)
+pub const ORACLE_MAX_STALENESS_SECONDS: i64 = 3600;
+
+/// Approximate slot duration used to turn slot counts into seconds
+pub const APPROX_SLOT_DURATION_MS: u64 = 400;
+
+/// Minimum time between two failed oracle health checks that both count
+/// towards `oracle_consecutive_failures` (1 hour)
+pub const ORACLE_FAILURE_COUNT_INTERVAL_SECONDS: i64 = 3600;
+
+/// Default consecutive oracle health check failures that pause the lottery
+pub const DEFAULT_MAX_ORACLE_FAILURES: u8 = 3;
+
+/// Time after `execute_draw` within which `finalize_draw` is expected (1 hour)
+pub const FINALIZE_TIMEOUT_SECONDS: i64 = 3600;
+
+/// Extra grace after the finalize timeout before anyone may force finalize (1 hour)
+pub const GRACE_PERIOD_SECONDS: i64 = 3600;
+
+/// Time after `execute_draw` after which anyone may force finalize (2 hours)
+pub const FINALIZE_AUTO_FORCE_SECONDS: i64 = FINALIZE_TIMEOUT_SECONDS + GRACE_PERIOD_SECONDS;
+
+/// Default delay after `next_draw_timestamp` before any keeper may commit
+/// randomness for the draw (10 minutes)
+pub const DEFAULT_KEEPER_GRACE_PERIOD_SECONDS: i64 = 600;
+
+/// Minimum configurable keeper grace period (1 minute)
+pub const MIN_KEEPER_GRACE_PERIOD_SECONDS: i64 = 60;
+
+/// Maximum configurable keeper grace period (24 hours)
+pub const MAX_KEEPER_GRACE_PERIOD_SECONDS: i64 = 86400;
+
+/// Minimum time between permissionless lottery stats snapshots (1 hour)
+pub const STATS_SNAPSHOT_COOLDOWN_SECONDS: i64 = 3600;
+
 /// Ticket claim expiration period (90 days in seconds)
 /// After this period from draw execution, tickets can no longer be claimed
 /// Set to 0 to disable expiration (tickets can be claimed forever)
 pub const TICKET_CLAIM_EXPIRATION: i64 = 90 * 24 * 60 * 60; // 90 days
 
+/// How long after finalization a draw's winner counts can be challenged: 24 hours
+pub const CHALLENGE_WINDOW_SECONDS: i64 = 24 * 60 * 60;
+
+/// Independent challenges that put a draw under review and pause the lottery
+pub const CHALLENGE_THRESHOLD: u8 = 3;
+/// Maximum ticket accounts closed by one cleanup_expired_tickets call
+pub const MAX_CLEANUP_TICKETS: usize = 20;
+
 // ============================================================================
 // DYNAMIC FEE TIERS
 // ============================================================================
@@ -98,6 +209,23 @@ pub const JACKPOT_CAP: u64 = 1_750_000_000_000;
 /// Initial seed amount: $500,000
 pub const SEED_AMOUNT: u64 = 500_000_000_000;
 
+/// Number of jackpot milestone thresholds
+pub const JACKPOT_MILESTONE_COUNT: usize = 5;
+/// Default jackpot milestones: $750k, $1M, $1.25M, $1.5M, $1.75M (soft cap)
+pub const DEFAULT_JACKPOT_MILESTONE_THRESHOLDS: [u64; JACKPOT_MILESTONE_COUNT] = [
+    750_000_000_000,
+    1_000_000_000_000,
+    1_250_000_000_000,
+    1_500_000_000_000,
+    1_750_000_000_000,
+];
+
+/// Number of (threshold, price) tiers for dynamic ticket pricing
+pub const PRICE_TIER_COUNT: usize = 4;
+
+/// Number of (streak_length, discount_bps) participation streak milestones
+pub const STREAK_MILESTONE_COUNT: usize = 5;
+
 // ============================================================================
 // PRIZE ALLOCATION (Basis Points - 10000 = 100%)
 // ============================================================================
@@ -108,8 +236,14 @@ pub const JACKPOT_ALLOCATION_BPS: u16 = 5560;
 pub const FIXED_PRIZE_ALLOCATION_BPS: u16 = 3940;
 /// Reserve allocation: 3%
 pub const RESERVE_ALLOCATION_BPS: u16 = 300;
-/// Insurance pool allocation: 2%
+/// Insurance pool allocation: 2% (tier 1 + tier 2)
 pub const INSURANCE_ALLOCATION_BPS: u16 = 200;
+/// Insurance tier 1 (liquid) allocation: 1.5%
+pub const INSURANCE_TIER1_ALLOCATION_BPS: u16 = 150;
+/// Insurance tier 2 (delayed) allocation: 0.5%
+pub const INSURANCE_TIER2_ALLOCATION_BPS: u16 = 50;
+/// Delay between requesting and executing a tier 2 to tier 1 transfer (24 hours)
+pub const INSURANCE_TIER2_WITHDRAWAL_DELAY: i64 = 86400;
 
 // ============================================================================
 // FIXED PRIZES (Normal Mode)
@@ -124,6 +258,9 @@ pub const MATCH_3_PRIZE: u64 = 5_000_000;
 /// Match 2 value: $2.50 (free ticket)
 pub const MATCH_2_VALUE: u64 = 2_500_000;
 
+/// Fixed tiers with a per-draw payout cap (indices 0-3 = Match 2-5)
+pub const FIXED_PAYOUT_CAP_TIER_COUNT: usize = 4;
+
 // ============================================================================
 // ROLLDOWN ALLOCATION (Basis Points - Percentage of Jackpot)
 // ============================================================================
@@ -135,6 +272,51 @@ pub const ROLLDOWN_MATCH_4_BPS: u16 = 3500;
 /// Match 3 rolldown allocation: 40%
 pub const ROLLDOWN_MATCH_3_BPS: u16 = 4000;
 
+/// Check that jackpot milestone thresholds are non-zero and strictly ascending
+pub fn is_valid_jackpot_milestone_thresholds(thresholds: &[u64; JACKPOT_MILESTONE_COUNT]) -> bool {
+    thresholds[0] > 0 && thresholds.windows(2).all(|pair| pair[0] < pair[1])
+}
+
+/// Bitmask of milestones `jackpot_balance` has reached that are not yet set
+/// in `emitted` (bit i = `thresholds[i]`)
+pub fn newly_reached_jackpot_milestones(
+    thresholds: &[u64; JACKPOT_MILESTONE_COUNT],
+    emitted: u8,
+    jackpot_balance: u64,
+) -> u8 {
+    thresholds
+        .iter()
+        .enumerate()
+        .filter(|(_, &threshold)| jackpot_balance >= threshold)
+        .fold(0u8, |mask, (index, _)| mask | (1 << index))
+        & !emitted
+}
+
+/// Bitmask of milestone bonuses whose threshold the jackpot crossed going
+/// from `old_balance` to `new_balance` (bit i = `thresholds[i]`)
+///
+/// Milestones without a bonus are skipped.
+pub fn crossed_milestone_bonuses(
+    thresholds: &[u64; JACKPOT_MILESTONE_COUNT],
+    prizes: &[u64; JACKPOT_MILESTONE_COUNT],
+    old_balance: u64,
+    new_balance: u64,
+) -> u8 {
+    thresholds
+        .iter()
+        .zip(prizes)
+        .enumerate()
+        .filter(|(_, (&threshold, &prize))| {
+            prize > 0 && old_balance < threshold && new_balance >= threshold
+        })
+        .fold(0u8, |mask, (index, _)| mask | (1 << index))
+}
+
+/// Check that a rolldown split ([Match 5, Match 4, Match 3] bps) sums to 100%
+pub fn is_valid_rolldown_split(split_bps: &[u16; 3]) -> bool {
+    split_bps.iter().map(|&bps| bps as u64).sum::<u64>() == BPS_DENOMINATOR
+}
+
 // ============================================================================
 // QUICK PICK EXPRESS PARAMETERS (5/35 Matrix)
 // ============================================================================
@@ -151,6 +333,14 @@ pub const QUICK_PICK_INTERVAL: i64 = 14400;
 /// Quick Pick minimum spend gate: $50 lifetime main lottery spend required
 pub const QUICK_PICK_MIN_SPEND_GATE: u64 = 50_000_000;
 
+/// QuickPick program ID (owner of the Quick Pick state read for free
+/// ticket credits, and caller of `redeem_free_quickpick_credit`)
+/// This must match the deployed QuickPick program ID in Anchor.toml
+pub const QUICKPICK_PROGRAM_ID: &str = "7XC1KT5mvsHHXbR2mH6er138fu2tJ4L2fAgmpjLnnZK2";
+
+/// How long a free QuickPick ticket credit from a Match 5 win stays valid: 7 days
+pub const FREE_QP_CREDIT_VALIDITY_SECONDS: i64 = 7 * 86400;
+
 /// Quick Pick seed amount: $5,000
 pub const QUICK_PICK_SEED_AMOUNT: u64 = 5_000_000_000;
 /// Quick Pick soft cap: $30,000
@@ -189,6 +379,14 @@ pub const QUICK_PICK_INSURANCE_ALLOCATION_BPS: u16 = 300; // 3%
 pub const SYNDICATE_WARS_POOL_BPS: u16 = 100;
 /// Minimum tickets to qualify for Syndicate Wars
 pub const SYNDICATE_WARS_MIN_TICKETS: u64 = 1000;
+/// Maximum knockout rounds in a Syndicate Wars bracket (32 syndicates)
+pub const MAX_WARS_BRACKET_ROUNDS: u8 = 5;
+/// Maximum matchups in one Syndicate Wars bracket round
+pub const MAX_WARS_MATCHUP_PAIRS: usize = 16;
+/// Maximum share of the wars prize pool reserved for the two finalists: 50%
+pub const MAX_WARS_FINALS_PRIZE_BPS: u16 = 5000;
+/// Lifetime tickets a referred player must buy before the referrer earns a credit
+pub const REFERRAL_QUALIFYING_TICKETS: u64 = 5;
 
 // ============================================================================
 // SYSTEM LIMITS
@@ -198,25 +396,117 @@ pub const SYNDICATE_WARS_MIN_TICKETS: u64 = 1000;
 pub const MAX_BULK_TICKETS: usize = 50;
 /// Maximum tickets per bulk purchase for syndicates
 pub const MAX_SYNDICATE_BULK_TICKETS: usize = 150;
-/// Maximum members per syndicate
-pub const MAX_SYNDICATE_MEMBERS: usize = 100;
+/// Maximum syndicate ticket accounts created per `create_syndicate_tickets_batch` call
+pub const MAX_SYNDICATE_TICKET_BATCH: usize = 10;
+/// Minimum consecutive draws covered by a multi-draw ticket
+pub const MIN_MULTI_DRAW_COUNT: u8 = 2;
+/// Maximum consecutive draws covered by a multi-draw ticket (fits the
+/// 64-bit claimed_draws bitmask)
+pub const MAX_MULTI_DRAW_COUNT: u8 = 52;
+/// Maximum future draws bought by one recurring ticket purchase
+pub const MAX_RECURRING_TICKET_DRAWS: usize = 10;
+/// How far past the current draw a recurring ticket may reach
+pub const MAX_RECURRING_DRAW_LOOKAHEAD: u64 = 100;
+/// Minimum contribution a non-creator syndicate member must hold: 1 USDC
+pub const MIN_SYNDICATE_MEMBER_CONTRIBUTION: u64 = 1_000_000;
 /// Maximum syndicate name length (UTF-8 bytes)
 pub const MAX_SYNDICATE_NAME_LENGTH: usize = 32;
+/// Maximum public syndicates listed in the syndicate registry
+pub const MAX_SYNDICATE_REGISTRY_ENTRIES: usize = 1000;
+/// Maximum wallets waiting to join a full syndicate
+pub const MAX_SYNDICATE_WAITLIST_ENTRIES: usize = 50;
+/// Maximum wallets on the purchase whitelist
+pub const MAX_WHITELIST_WALLETS: usize = 500;
+/// `compliance_mode`: purchases are not KYC-gated
+pub const COMPLIANCE_MODE_NONE: u8 = 0;
+/// `compliance_mode`: purchases require a valid KYC record
+pub const COMPLIANCE_MODE_KYC: u8 = 1;
+/// Maximum length of a KYC revocation reason
+pub const MAX_KYC_REVOKE_REASON_LENGTH: usize = 64;
+/// Maximum length of the lottery terms URI
+pub const MAX_TERMS_URI_LENGTH: usize = 200;
+/// Maximum programs listed in the protocol registry
+pub const MAX_PROTOCOL_REGISTRY_ENTRIES: usize = 16;
+/// Delay after a force finalization before a syndicate locked for that
+/// draw can be unlocked: 48 hours
+pub const SYNDICATE_FORCE_FINALIZE_UNLOCK_DELAY_SECONDS: i64 = 48 * 60 * 60;
 /// Maximum manager fee for syndicates: 5%
 pub const MAX_MANAGER_FEE_BPS: u16 = 500;
+/// Veto window before a proposed manager fee increase can be applied: 72 hours
+pub const SYNDICATE_FEE_TIMELOCK_SECONDS: i64 = 72 * 60 * 60;
+/// Minimum member share that can veto a manager fee increase: 20%
+pub const SYNDICATE_FEE_VETO_MIN_SHARE_BPS: u16 = 2000;
 /// Maximum tickets per draw per user
 pub const MAX_TICKETS_PER_DRAW_PER_USER: u64 = 5000;
+/// Rolling window for the per-wallet daily ticket limit (24 hours)
+pub const DAILY_LIMIT_WINDOW_SECONDS: i64 = 86400;
+/// Daily ticket limit every wallet starts with (players may only lower it)
+pub const DEFAULT_DAILY_TICKET_LIMIT: u32 = 1000;
+/// Rolling window for the per-wallet purchase velocity limit (1 hour)
+pub const VELOCITY_WINDOW_SECONDS: i64 = 3600;
+/// Share of the hourly velocity limit at which `PlayerVelocityAlert` fires: 80%
+pub const VELOCITY_ALERT_THRESHOLD_BPS: u16 = 8000;
+/// `LotteryStateDiff::flags_changed` bit: is_paused changed
+pub const STATE_DIFF_FLAG_PAUSED: u8 = 1 << 0;
+/// `LotteryStateDiff::flags_changed` bit: is_rolldown_active changed
+pub const STATE_DIFF_FLAG_ROLLDOWN_ACTIVE: u8 = 1 << 1;
+/// `LotteryStateDiff::flags_changed` bit: is_draw_in_progress changed
+pub const STATE_DIFF_FLAG_DRAW_IN_PROGRESS: u8 = 1 << 2;
+/// `LotteryStateDiff::flags_changed` bit: is_funded changed
+pub const STATE_DIFF_FLAG_FUNDED: u8 = 1 << 3;
+/// Rolling window for the house fee withdrawal cap (24 hours)
+pub const HOUSE_FEE_WITHDRAWAL_WINDOW_SECONDS: i64 = 86400;
+/// Share of the daily withdrawal cap at which `DailyWithdrawalCapApproaching` fires: 80%
+pub const WITHDRAWAL_CAP_ALERT_THRESHOLD_BPS: u16 = 8000;
+/// Share of the daily withdrawal cap above which the window's cumulative
+/// withdrawals also need the multisig cosigner: 50%
+pub const WITHDRAWAL_COSIGN_THRESHOLD_BPS: u16 = 5000;
+/// Shortest self-exclusion a player can request (1 day)
+pub const MIN_SELF_EXCLUSION_SECONDS: i64 = 86400;
+/// Longest self-exclusion a player can request at once (365 days)
+pub const MAX_SELF_EXCLUSION_SECONDS: i64 = 31_536_000;
 /// Maximum free tickets a user can accumulate
 pub const MAX_FREE_TICKETS: u64 = 1000;
 /// Basis points denominator
 pub const BPS_DENOMINATOR: u64 = 10000;
 
+// ============================================================================
+// OPERATOR PERMISSIONS (bitmask on LotteryState.operator_permissions)
+// ============================================================================
+
+/// Operator may call `commit_randomness`
+pub const OPERATOR_PERMISSION_COMMIT_RANDOMNESS: u64 = 1 << 0;
+/// Operator may call `execute_draw`
+pub const OPERATOR_PERMISSION_EXECUTE_DRAW: u64 = 1 << 1;
+/// Operator may call `submit_winner_merkle_root`
+pub const OPERATOR_PERMISSION_SUBMIT_MERKLE_ROOT: u64 = 1 << 2;
+/// All permission bits an operator can be granted
+pub const OPERATOR_PERMISSION_ALL: u64 = OPERATOR_PERMISSION_COMMIT_RANDOMNESS
+    | OPERATOR_PERMISSION_EXECUTE_DRAW
+    | OPERATOR_PERMISSION_SUBMIT_MERKLE_ROOT;
+
+/// Whether an operator permission mask only uses known bits
+pub fn is_valid_operator_permissions(permissions: u64) -> bool {
+    permissions & !OPERATOR_PERMISSION_ALL == 0
+}
+
+// ============================================================================
+// SCHEMA VERSIONING
+// ============================================================================
+
+/// Layout version written to `schema_version` on LotteryState, DrawResult,
+/// TicketData, UserStats and Syndicate. Instructions reject accounts at any
+/// other version; `migrate_lottery_state` and `migrate_account` bring older
+/// accounts up to date.
+pub const CURRENT_SCHEMA_VERSION: u8 = 1;
+
 // ============================================================================
 // ACCOUNT SIZES
 // ============================================================================
 
 /// LotteryState account size
 pub const LOTTERY_STATE_SIZE: usize = 8 + // discriminator
+    1 +  // schema_version
     32 + // authority
     33 + // pending_authority (Option<Pubkey>)
     32 + // switchboard_queue
@@ -224,7 +514,7 @@ pub const LOTTERY_STATE_SIZE: usize = 8 + // discriminator
     8 +  // current_draw_id
     8 +  // jackpot_balance
     8 +  // reserve_balance
-    8 +  // insurance_balance
+    8 +  // insurance_tier1_balance
     8 +  // fixed_prize_balance (dedicated tracked fixed prize pool)
     8 +  // ticket_price
     2 +  // house_fee_bps
@@ -249,13 +539,354 @@ pub const LOTTERY_STATE_SIZE: usize = 8 + // discriminator
     32 + // pending_config_hash (Issue 5 fix: hash of pending config)
     8 +  // emergency_transfer_total (rolling window aggregate)
     8 +  // emergency_transfer_window_start (window start timestamp)
+    1 +  // allow_third_party_closure
+    8 +  // min_tickets_per_draw
+    2 +  // rolldown_match_5_bps
+    2 +  // rolldown_match_4_bps
+    2 +  // rolldown_match_3_bps
+    8 +  // escrow_threshold_usdc
+    8 +  // escrow_release_delay_seconds
+    1 +  // house_fee_split_active
+    33 + // operator (Option<Pubkey>)
+    8 +  // operator_permissions
+    8 +  // execute_draw_timestamp
+    8 +  // reserve_minimum
+    1 +  // daily_limit_enabled
+    1 +  // entropy_validation_enabled
+    8 +  // ticket_sale_cutoff_seconds
+    8 +  // keeper_reward_lamports
+    8 +  // insurance_minimum
+    2 +  // insurance_replenishment_bps
+    8 +  // insurance_replenished_draw_id
+    40 + // jackpot_milestone_thresholds
+    1 +  // milestones_emitted
+    8 +  // keeper_grace_period_seconds
+    33 + // last_keeper (Option<Pubkey>)
+    8 +  // last_snapshot_timestamp
+    1 +  // allow_fallback_randomness
+    1 +  // use_pull_oracle
+    33 + // switchboard_pull_feed (Option<Pubkey>)
+    8 +  // committed_pull_round_id
+    32 + // draw_commit_hash
+    33 + // multisig_cosigner (Option<Pubkey>)
+    33 + // pending_multisig_cosigner (Option<Pubkey>)
+    8 +  // multisig_cosigner_timelock_end
+    1 +  // dynamic_price_enabled
+    64 + // price_tiers (4 x (threshold, price))
+    8 +  // cleanup_reward_lamports
+    8 +  // total_fixed_prizes_claimed
+    8 +  // reconcile_timelock_end
+    8 +  // quickpick_spend_gate_threshold
+    32 + // max_fixed_payout_per_tier (4 x u64)
+    8 +  // reserve_minimum_coverage_draws
+    1 +  // whitelist_mode
+    4 +  // max_tickets_per_wallet_per_hour
+    8 +  // jackpot_all_time_high
+    8 +  // jackpot_ath_draw_id
+    8 +  // jackpot_ath_timestamp
+    8 +  // jackpot_ath_for_season
+    1 +  // rolldown_cooldown_draws
+    8 +  // rolldown_cooldown_until_draw
+    1 +  // compliance_mode
+    33 + // kyc_authority (Option<Pubkey>)
+    8 +  // identity_verification_threshold
+    32 + // identity_oracle
+    1 +  // terms_version
+    4 + MAX_TERMS_URI_LENGTH + // terms_uri
+    8 +  // insurance_tier2_balance
+    8 +  // anchor_draw_time
+    40 + // milestone_thresholds
+    40 + // milestone_prizes
+    5 +  // milestone_claimed
+    8 +  // force_finalize_reward_lamports
+    8 +  // max_tickets_per_wallet_per_draw
+    2 +  // daily_withdrawal_cap_bps
+    8 +  // withdrawal_window_start
+    8 +  // withdrawals_in_window
+    2 +  // operator_profit_share_bps
+    8 +  // jackpot_guarantee_amount
+    8 +  // guarantee_reserve_balance
+    30 + // streak_milestone_bps (5 x (streak_length, discount_bps))
+    33 + // pending_upgrade_hash (Option<[u8; 32]>)
+    8 +  // upgrade_announce_slot
+    2 +  // reserve_target_multiplier_bps
+    8 +  // oracle_last_healthy_timestamp
+    1 +  // oracle_consecutive_failures
+    1 +  // max_oracle_failures
+    8 +  // oracle_last_failure_timestamp
+    8 +  // pending_recurring_tickets
+    1 +  // milestone_unlocked
     0; // no padding remaining (was 24, consumed by new fields: 8+8+8=24)
 
+/// Delay between proposing and applying a winning numbers override: 24 hours
+pub const WINNING_NUMBERS_OVERRIDE_DELAY: i64 = 24 * 60 * 60;
+
 /// Minimum timelock delay for config changes: 24 hours (in seconds)
 pub const CONFIG_TIMELOCK_DELAY: i64 = 86400;
 
+/// Rounding dust allowed when checking the prize pool against jackpot +
+/// reserve before a draw commit (USDC lamports)
+pub const SOLVENCY_TOLERANCE: u64 = 100;
+
+/// Waiting period between announcing a program upgrade and deploying it:
+/// ~48 hours at 400ms slots
+pub const UPGRADE_DELAY_SLOTS: u64 = 432_000;
+
+/// Delay before a multisig cosigner change takes effect: 48 hours
+pub const MULTISIG_COSIGNER_TIMELOCK_DELAY: i64 = 2 * CONFIG_TIMELOCK_DELAY;
+
+/// Window after the timelock ends in which a proposal can still be executed: 7 days
+pub const CONFIG_PROPOSAL_EXECUTION_WINDOW: i64 = 604800;
+
+/// Default minimum tickets required before a draw can be committed
+pub const DEFAULT_MIN_TICKETS_PER_DRAW: u64 = 1;
+
+/// Default delay before an escrowed jackpot prize can be released: 7 days
+pub const DEFAULT_ESCROW_RELEASE_DELAY: i64 = 604800;
+
+/// Maximum configurable escrow release delay: 30 days
+pub const MAX_ESCROW_RELEASE_DELAY: i64 = 2_592_000;
+
+/// Whether an executed draw has gone unfinalized past the timeout and grace period
+///
+/// Once overdue, `force_finalize_draw` becomes permissionless so the authority
+/// cannot hold a draw hostage. A zero timestamp means no draw was executed.
+pub fn is_finalize_overdue(execute_draw_timestamp: i64, current_timestamp: i64) -> bool {
+    if execute_draw_timestamp <= 0 {
+        return false;
+    }
+
+    match execute_draw_timestamp.checked_add(FINALIZE_AUTO_FORCE_SECONDS) {
+        Some(deadline) => current_timestamp > deadline,
+        None => false,
+    }
+}
+
+/// Prize pool token balance as a share of the jackpot and reserve it backs (bps)
+///
+/// Returns u64::MAX when nothing is owed.
+pub fn calculate_collateral_ratio_bps(prize_pool_amount: u64, liabilities: u64) -> u64 {
+    if liabilities == 0 {
+        return u64::MAX;
+    }
+
+    let ratio = (prize_pool_amount as u128 * BPS_DENOMINATOR as u128) / liabilities as u128;
+    ratio.min(u64::MAX as u128) as u64
+}
+
+/// Whether a pending config proposal was never executed within its window
+///
+/// A zero `config_timelock_end` means no proposal is pending.
+pub fn is_config_proposal_expired(config_timelock_end: i64, current_timestamp: i64) -> bool {
+    if config_timelock_end <= 0 {
+        return false;
+    }
+
+    match config_timelock_end.checked_add(CONFIG_PROPOSAL_EXECUTION_WINDOW) {
+        Some(expires_at) => current_timestamp > expires_at,
+        None => false,
+    }
+}
+
+/// Whether a draw committed at `commit_timestamp` may use fallback randomness
+pub fn is_vrf_fallback_ready(commit_timestamp: i64, current_timestamp: i64) -> bool {
+    if commit_timestamp <= 0 {
+        return false;
+    }
+
+    match commit_timestamp.checked_add(VRF_FALLBACK_DELAY_SECONDS) {
+        Some(ready_at) => current_timestamp >= ready_at,
+        None => false,
+    }
+}
+
+/// Whether a keeper may trigger the draw scheduled for `next_draw_timestamp`
+pub fn is_keeper_trigger_ready(
+    next_draw_timestamp: i64,
+    grace_period_seconds: i64,
+    current_timestamp: i64,
+) -> bool {
+    match next_draw_timestamp.checked_add(grace_period_seconds) {
+        Some(ready_at) => current_timestamp >= ready_at,
+        None => false,
+    }
+}
+
+/// First draw time after `current_timestamp` on the `draw_interval` grid
+/// through `anchor_draw_time`
+///
+/// Draws keep their clock time however late the previous draw ran.
+pub fn next_anchored_draw_timestamp(
+    current_timestamp: i64,
+    anchor_draw_time: i64,
+    draw_interval: i64,
+) -> i64 {
+    let periods = current_timestamp
+        .saturating_sub(anchor_draw_time)
+        .div_euclid(draw_interval);
+    periods
+        .saturating_add(1)
+        .saturating_mul(draw_interval)
+        .saturating_add(anchor_draw_time)
+}
+
+/// Whether `anchor_draw_time` is a valid time of day in seconds
+pub fn is_valid_anchor_draw_time(anchor_draw_time: i64) -> bool {
+    (0..SECONDS_PER_DAY).contains(&anchor_draw_time)
+}
+
+/// Whether the daily purchase window starting at `window_start` has elapsed
+pub fn is_daily_window_expired(window_start: i64, current_timestamp: i64) -> bool {
+    current_timestamp.saturating_sub(window_start) >= DAILY_LIMIT_WINDOW_SECONDS
+}
+
+/// Whether the hourly velocity window starting at `window_start` has elapsed
+pub fn is_velocity_window_expired(window_start: i64, current_timestamp: i64) -> bool {
+    current_timestamp.saturating_sub(window_start) >= VELOCITY_WINDOW_SECONDS
+}
+
+/// Member limit of a syndicate whose creator has `reputation_score`
+///
+/// Fixed when the syndicate is created; creators who have since gained
+/// reputation can close and recreate their syndicate for a higher limit.
+pub fn effective_max_members(reputation_score: u32) -> u8 {
+    match reputation_score {
+        0..=199 => 10,
+        200..=499 => 15,
+        500.. => 20,
+    }
+}
+
+/// House fees that may be withdrawn per window from an account holding
+/// `balance`, given `cap_bps` (None = uncapped)
+pub fn house_fee_withdrawal_cap(balance: u64, cap_bps: u16) -> Option<u64> {
+    if cap_bps == 0 {
+        return None;
+    }
+    Some((balance as u128 * cap_bps as u128 / BPS_DENOMINATOR as u128) as u64)
+}
+
+/// Whether `withdrawn` has reached WITHDRAWAL_CAP_ALERT_THRESHOLD_BPS of `cap`
+pub fn is_withdrawal_cap_alert(withdrawn: u64, cap: u64) -> bool {
+    withdrawn as u128 * BPS_DENOMINATOR as u128
+        >= cap as u128 * WITHDRAWAL_CAP_ALERT_THRESHOLD_BPS as u128
+}
+
+/// Whether the window's cumulative withdrawals `withdrawn` are above
+/// WITHDRAWAL_COSIGN_THRESHOLD_BPS of `cap`
+pub fn requires_withdrawal_cosign(withdrawn: u64, cap: u64) -> bool {
+    withdrawn as u128 * BPS_DENOMINATOR as u128
+        > cap as u128 * WITHDRAWAL_COSIGN_THRESHOLD_BPS as u128
+}
+
+/// Whether `tickets_this_hour` has reached VELOCITY_ALERT_THRESHOLD_BPS of
+/// a nonzero hourly `threshold`
+pub fn is_velocity_alert(tickets_this_hour: u32, threshold: u32) -> bool {
+    threshold > 0
+        && tickets_this_hour as u64 * BPS_DENOMINATOR
+            >= threshold as u64 * VELOCITY_ALERT_THRESHOLD_BPS as u64
+}
+
+/// Fixed prize balance implied by lifetime sales and fixed prize payouts
+///
+/// `total_tickets_sold * TICKET_PRICE * FIXED_PRIZE_ALLOCATION_BPS /
+/// BPS_DENOMINATOR - total_fixed_prizes_claimed`, floored at zero.
+pub fn expected_fixed_prize_balance(
+    total_tickets_sold: u64,
+    total_fixed_prizes_claimed: u64,
+) -> u64 {
+    let allocated =
+        total_tickets_sold as u128 * TICKET_PRICE as u128 * FIXED_PRIZE_ALLOCATION_BPS as u128
+            / BPS_DENOMINATOR as u128;
+    allocated
+        .saturating_sub(total_fixed_prizes_claimed as u128)
+        .min(u64::MAX as u128) as u64
+}
+
+/// End of a self-exclusion of `duration_seconds` requested at `current_timestamp`
+///
+/// Never earlier than `excluded_until`, so an exclusion can only be extended.
+pub fn extend_self_exclusion(
+    excluded_until: i64,
+    current_timestamp: i64,
+    duration_seconds: i64,
+) -> i64 {
+    current_timestamp
+        .saturating_add(duration_seconds)
+        .max(excluded_until)
+}
+
+/// Whether `cutoff` is a valid ticket sale cutoff for `draw_interval`
+///
+/// The cutoff must be at least MIN_TICKET_SALE_CUTOFF and at most a quarter
+/// of the draw interval, so sales stay open for most of each draw.
+pub fn is_valid_ticket_sale_cutoff(cutoff: i64, draw_interval: i64) -> bool {
+    cutoff >= MIN_TICKET_SALE_CUTOFF && cutoff <= draw_interval / 4
+}
+
+/// Portion of a house fee to divert to the insurance pool
+///
+/// Returns `house_fee * replenishment_bps / BPS_DENOMINATOR` while
+/// `insurance_balance` is below `insurance_minimum`, capped at the shortfall.
+pub fn calculate_insurance_replenishment(
+    house_fee: u64,
+    insurance_balance: u64,
+    insurance_minimum: u64,
+    replenishment_bps: u16,
+) -> u64 {
+    let shortfall = insurance_minimum.saturating_sub(insurance_balance);
+    let share = (house_fee as u128 * replenishment_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+    share.min(shortfall)
+}
+
+/// Split an insurance contribution into its (tier 1, tier 2) parts
+///
+/// Tier 2 gets INSURANCE_TIER2_ALLOCATION_BPS of every INSURANCE_ALLOCATION_BPS;
+/// rounding dust stays in tier 1.
+pub fn split_insurance_contribution(insurance_contribution: u64) -> (u64, u64) {
+    let tier2 = (insurance_contribution as u128 * INSURANCE_TIER2_ALLOCATION_BPS as u128
+        / INSURANCE_ALLOCATION_BPS as u128) as u64;
+    (insurance_contribution - tier2, tier2)
+}
+
+/// Whether `draw_count` is a valid number of draws for a multi-draw ticket
+pub fn is_valid_multi_draw_count(draw_count: u8) -> bool {
+    (MIN_MULTI_DRAW_COUNT..=MAX_MULTI_DRAW_COUNT).contains(&draw_count)
+}
+
+/// Whether `draw_ids` are 1..=MAX_RECURRING_TICKET_DRAWS consecutive draws
+/// after `current_draw_id`, within MAX_RECURRING_DRAW_LOOKAHEAD of it
+pub fn is_valid_recurring_draw_ids(draw_ids: &[u64], current_draw_id: u64) -> bool {
+    let (Some(&first), Some(&last)) = (draw_ids.first(), draw_ids.last()) else {
+        return false;
+    };
+    draw_ids.len() <= MAX_RECURRING_TICKET_DRAWS
+        && first > current_draw_id
+        && last <= current_draw_id.saturating_add(MAX_RECURRING_DRAW_LOOKAHEAD)
+        && draw_ids
+            .windows(2)
+            .all(|pair| pair[0].checked_add(1) == Some(pair[1]))
+}
+
+/// Whether a prize must be escrowed instead of paid directly
+///
+/// Only Match 6 prizes strictly above a non-zero threshold are escrowed.
+/// A threshold of 0 disables escrow.
+pub fn is_escrow_required(match_count: u8, prize_amount: u64, escrow_threshold: u64) -> bool {
+    escrow_threshold > 0 && match_count == 6 && prize_amount > escrow_threshold
+}
+
+/// Whether a prize may only be claimed with an identity proof
+///
+/// Prizes strictly above a non-zero threshold require one. A threshold of 0
+/// disables identity verification.
+pub fn is_identity_verification_required(prize_amount: u64, threshold: u64) -> bool {
+    threshold > 0 && prize_amount > threshold
+}
+
 /// DrawResult account size
 pub const DRAW_RESULT_SIZE: usize = 8 + // discriminator
+    1 +  // schema_version
     8 +  // draw_id
     6 +  // winning_numbers
     32 + // randomness_proof (32 bytes for signature)
@@ -275,11 +906,196 @@ pub const DRAW_RESULT_SIZE: usize = 8 + // discriminator
     1 +  // is_explicitly_finalized
     8 +  // total_committed (Fix #3: per-draw reclaim accounting)
     8 +  // total_reclaimed (Fix #3: per-draw reclaim accounting)
+    2 +  // rolldown_vrf_sample
+    2 +  // rolldown_probability_bps
+    1 +  // bump
+    33 + // winner_merkle_root (Option<[u8; 32]>, replaces the former 12 bytes of padding)
+    8 +  // merkle_root_submitted_at
+    1 +  // used_fallback_randomness
+    32 + // commit_hash
+    8 +  // force_finalized_at
+    8 +  // challenge_deadline
+    1 +  // challenge_count
+    1 +  // is_under_challenge_review
+    1 +  // is_challenge_upheld
+    32 + // override_justification
+    1 +  // was_overridden
+    32; // vrf_result_raw
+
+/// Pending winning numbers override account size
+pub const PENDING_OVERRIDE_SIZE: usize = 8 + // discriminator
+    1 +  // bump
+    8 +  // draw_id
+    6 +  // new_numbers
+    32 + // justification_hash
+    8 +  // proposed_at
+    8; // executable_at
+
+/// Pending insurance tier 2 withdrawal account size
+pub const TIER2_WITHDRAWAL_REQUEST_SIZE: usize = 8 + // discriminator
+    1 +  // bump
+    8 +  // amount
+    8 +  // requested_at
+    8; // executable_after
+
+/// Size per protocol registry entry
+pub const PROTOCOL_REGISTRY_ENTRY_SIZE: usize = 32 + // program_id
+    1 +  // program_type
+    1 +  // is_active
+    8 +  // registered_at
+    1; // version
+
+/// Protocol registry account size (room for MAX_PROTOCOL_REGISTRY_ENTRIES)
+pub const PROTOCOL_REGISTRY_SIZE: usize = 8 + // discriminator
+    1 +  // bump
+    4 + (MAX_PROTOCOL_REGISTRY_ENTRIES * PROTOCOL_REGISTRY_ENTRY_SIZE); // entries
+
+/// Draw challenge account size
+pub const DRAW_CHALLENGE_SIZE: usize = 8 + // discriminator
+    1 +  // bump
+    8 +  // draw_id
+    32 + // challenger
+    32 + // evidence_hash
+    1 +  // disputed_tier
+    8; // submitted_at
+
+/// Size of one Syndicate Wars bracket matchup
+pub const WARS_MATCHUP_SIZE: usize = 32 + // higher_seed
+    32; // lower_seed
+
+/// Syndicate Wars state account size
+pub const SYNDICATE_WARS_STATE_SIZE: usize = 8 + // discriminator
+    8 +  // month
+    8 +  // start_timestamp
+    8 +  // end_timestamp
+    8 +  // prize_pool
+    4 +  // registered_count
+    8 +  // min_tickets
+    1 +  // is_active
+    1 +  // is_distributed
+    1 +  // bump
+    1 +  // bracket_rounds
+    1 +  // current_round
+    4 + MAX_WARS_MATCHUP_PAIRS * WARS_MATCHUP_SIZE + // matchup_pairs
+    2 +  // finals_prize_bps
+    64; // finalists
+
+/// Number of finalized draws kept in the draw history ring buffer
+pub const DRAW_HISTORY_LENGTH: usize = 20;
+
+/// Size of one draw history summary
+pub const DRAW_HISTORY_SUMMARY_SIZE: usize = 8 + // draw_id
+    6 +  // winning_numbers
+    8 +  // total_tickets
+    1 +  // was_rolldown
+    8 +  // total_prizes
+    8 +  // jackpot_after
+    8; // timestamp
+
+/// Draw history buffer account size
+pub const DRAW_HISTORY_BUFFER_SIZE: usize = 8 + // discriminator
+    1 +  // bump
+    1 +  // head
+    8 +  // total_draws
+    DRAW_HISTORY_LENGTH * DRAW_HISTORY_SUMMARY_SIZE; // entries
+
+/// Number of finalized draws kept in the reserve history ring buffer
+pub const RESERVE_HISTORY_LENGTH: usize = 20;
+
+/// Size of one reserve snapshot
+pub const RESERVE_SNAPSHOT_SIZE: usize = 8 + // draw_id
+    8 +  // reserve_balance
+    8 +  // prizes_distributed
+    8; // tickets_sold
+
+/// Reserve history account size
+pub const RESERVE_HISTORY_SIZE: usize = 8 + // discriminator
     1 +  // bump
-    16; // padding (reduced from 32 to accommodate new fields)
+    1 +  // head
+    8 +  // total_snapshots
+    RESERVE_HISTORY_LENGTH * RESERVE_SNAPSHOT_SIZE; // snapshots
+
+/// Future draws tracked by the recurring ticket schedule; every draw a
+/// recurring ticket can target maps to its own slot
+pub const RECURRING_SCHEDULE_LENGTH: usize = MAX_RECURRING_DRAW_LOOKAHEAD as usize;
+
+/// Recurring ticket schedule account size
+pub const RECURRING_SCHEDULE_SIZE: usize = 8 + // discriminator
+    1 +  // bump
+    RECURRING_SCHEDULE_LENGTH * 4; // pending_tickets
+
+/// Highest reserve allocation `compute_reserve_health` recommends: 10%
+pub const MAX_RECOMMENDED_RESERVE_ALLOCATION_BPS: u16 = 1000;
+
+/// Default reserve target for `rebalance_reserve_to_jackpot`: 3x the rolling
+/// average prizes per draw
+pub const DEFAULT_RESERVE_TARGET_MULTIPLIER_BPS: u16 = 30_000;
+
+/// Number of prize wins kept on the all-time jackpot leaderboard
+pub const JACKPOT_LEADERBOARD_LENGTH: usize = 20;
+
+/// Size of one leaderboard entry
+pub const LEADERBOARD_ENTRY_SIZE: usize = 32 + // winner
+    8 +  // prize_amount
+    8 +  // draw_id
+    1 +  // match_count
+    8; // timestamp
+
+/// Jackpot leaderboard account size
+pub const JACKPOT_LEADERBOARD_SIZE: usize = 8 + // discriminator
+    1 +  // bump
+    JACKPOT_LEADERBOARD_LENGTH * LEADERBOARD_ENTRY_SIZE; // entries
+
+/// Slots in the number frequency histogram (index = number, slot 0 unused)
+pub const NUMBER_FREQUENCY_SLOTS: usize = MAX_NUMBER as usize + 1;
+
+/// Number frequency histogram account size
+pub const NUMBER_FREQUENCY_SIZE: usize = 8 + // discriminator
+    1 +  // bump
+    NUMBER_FREQUENCY_SLOTS * 4 + // frequencies
+    8; // total_draws_recorded
+
+/// Finalized draws between two chi-squared bias tests
+pub const BIAS_TEST_INTERVAL_DRAWS: u32 = 50;
+
+/// Fixed-point scale of the chi-squared statistic
+pub const CHI_SQUARED_SCALE: u64 = 10_000;
+
+/// Chi-squared critical value for 45 degrees of freedom at 5% significance
+/// (61.656), scaled by CHI_SQUARED_SCALE
+pub const CHI_SQUARED_THRESHOLD_5PCT: u64 = 616_560;
+
+/// Number bias detector account size
+pub const NUMBER_BIAS_DETECTOR_SIZE: usize = 8 + // discriminator
+    1 +  // bump
+    NUMBER_FREQUENCY_SLOTS * 4 + // win_counts
+    4 +  // total_draws
+    8; // last_bias_test_draw_id
+
+/// Length of an analytics season (365 days); the season player count
+/// resets when a purchase lands after the season ends
+pub const ANALYTICS_SEASON_SECONDS: i64 = 31_536_000;
+
+/// Seconds per day, for reporting player tenure
+pub const SECONDS_PER_DAY: i64 = 86400;
+
+/// Lottery analytics account size
+pub const LOTTERY_ANALYTICS_SIZE: usize = 8 + // discriminator
+    1 +  // bump
+    8 +  // total_draws
+    4 +  // total_jackpots_won
+    8 +  // largest_jackpot_ever
+    8 +  // largest_jackpot_draw_id
+    4 +  // total_rolldowns_executed
+    8 +  // total_prizes_ever_paid
+    8 +  // all_time_player_count
+    8 +  // current_season_player_count
+    4 +  // season_number
+    8; // season_start
 
 /// Ticket account size
 pub const TICKET_SIZE: usize = 8 + // discriminator
+    1 +  // schema_version
     32 + // owner
     8 +  // draw_id
     6 +  // numbers
@@ -288,11 +1104,16 @@ pub const TICKET_SIZE: usize = 8 + // discriminator
     1 +  // match_count
     8 +  // prize_amount
     33 + // syndicate (Option<Pubkey>)
+    32 + // original_owner (immutable, for gift audit trail)
+    1 +  // entered_second_chance
     1 +  // bump
-    8; // padding
+    1 +  // requires_refund
+    33 + // claim_agent (Option<Pubkey>)
+    6; // padding
 
 /// UserStats account size
 pub const USER_STATS_SIZE: usize = 8 + // discriminator
+    1 +  // schema_version
     32 + // wallet
     8 +  // total_tickets
     8 +  // total_spent
@@ -304,10 +1125,37 @@ pub const USER_STATS_SIZE: usize = 8 + // discriminator
     8 +  // tickets_this_draw
     4 +  // free_tickets_available
     1 +  // bump
-    16; // padding
+    33 + // referrer (Option<Pubkey>)
+    4 +  // referral_credits
+    4 +  // referred_players_bought
+    8 +  // total_prizes_won
+    8 +  // total_draws_entered
+    8 +  // largest_prize_won
+    1 +  // best_match_count
+    8 +  // last_win_draw_id
+    4 +  // consecutive_draws_played
+    8 +  // last_played_draw_id
+    4 +  // daily_tickets_purchased
+    8 +  // daily_window_start
+    4 +  // daily_ticket_limit (consumed the former 16 bytes of padding)
+    8 +  // self_excluded_until
+    4 +  // tickets_last_window
+    8 +  // velocity_window_start
+    1 +  // accepted_terms_version
+    4 +  // analytics_season
+    8 +  // total_house_fees_paid
+    8 +  // net_roi_lamports
+    8 +  // join_timestamp
+    8 +  // current_draw_id_purchased
+    8 +  // current_draw_tickets_count
+    4 +  // reputation_score
+    8 +  // last_participated_draw_id
+    8 +  // recurring_draw_base
+    16; // recurring_draw_mask
 
 /// Syndicate base account size (without members)
 pub const SYNDICATE_BASE_SIZE: usize = 8 + // discriminator
+    1 +  // schema_version
     32 + // creator
     32 + // original_creator (immutable, used for PDA seed derivation)
     8 +  // syndicate_id
@@ -319,7 +1167,15 @@ pub const SYNDICATE_BASE_SIZE: usize = 8 + // discriminator
     32 + // usdc_account
     4 +  // members vec length
     1 +  // bump
-    16; // padding
+    8 +  // max_contribution_per_member
+    1 +  // is_locked
+    8 +  // locked_draw_id
+    8 +  // pending_usdc (waitlist escrow, replaces 8 bytes of padding)
+    1 +  // max_members_at_creation
+    8 +  // ticket_draw_id
+    4 +  // tickets_purchased
+    4 +  // tickets_created
+    7; // padding
 
 /// Size per syndicate member
 pub const SYNDICATE_MEMBER_SIZE: usize = 32 + // wallet
@@ -327,6 +1183,158 @@ pub const SYNDICATE_MEMBER_SIZE: usize = 32 + // wallet
     2 +  // share_percentage_bps
     8; // unclaimed_prize (snapshot-based distribution to prevent race condition)
 
+/// Syndicate invite account size
+pub const SYNDICATE_INVITE_SIZE: usize = 8 + // discriminator
+    32 + // syndicate
+    32 + // code_hash
+    1 +  // use_count
+    1 +  // max_uses
+    8 +  // expires_at
+    1 +  // bump
+    8; // padding
+
+/// Syndicate registry base account size (without entries)
+pub const SYNDICATE_REGISTRY_BASE_SIZE: usize = 8 + // discriminator
+    1 +  // bump
+    4; // entries vec length
+
+/// Size per syndicate registry entry
+pub const SYNDICATE_REGISTRY_ENTRY_SIZE: usize = 32 + // syndicate
+    32 + // name
+    1 +  // member_count
+    8 +  // total_contribution
+    1; // is_full
+
+/// Syndicate waitlist base account size (without entries)
+pub const SYNDICATE_WAITLIST_BASE_SIZE: usize = 8 + // discriminator
+    32 + // syndicate
+    1 +  // bump
+    4; // waitlist vec length
+
+/// Purchase whitelist base account size (without wallets)
+pub const WHITELIST_BASE_SIZE: usize = 8 + // discriminator
+    1 +  // bump
+    32 + // gate_mint
+    4; // allowed_wallets vec length
+
+/// Size per whitelisted wallet
+pub const WHITELIST_WALLET_SIZE: usize = 32;
+
+/// Free QuickPick ticket credit account size
+pub const FREE_QP_CREDIT_SIZE: usize = 8 + // discriminator
+    1 +  // bump
+    32 + // beneficiary
+    8 +  // draw_id
+    8 +  // expires_at
+    1; // is_used
+
+/// Identity proof account size
+pub const IDENTITY_PROOF_SIZE: usize = 8 + // discriminator
+    1 +  // bump
+    32 + // player
+    2 +  // jurisdiction
+    8 +  // issued_at
+    8; // expires_at
+
+/// KYC record account size
+pub const KYC_RECORD_SIZE: usize = 8 + // discriminator
+    1 +  // bump
+    32 + // player
+    2 +  // jurisdiction
+    8 +  // approved_at
+    8 +  // expires_at
+    1; // is_revoked
+
+/// Size per syndicate waitlist entry
+pub const WAITLIST_ENTRY_SIZE: usize = 32 + // wallet
+    8 +  // desired_contribution
+    8; // joined_at
+
+/// Syndicate manager fee proposal account size
+pub const SYNDICATE_FEE_PROPOSAL_SIZE: usize = 8 + // discriminator
+    32 + // syndicate
+    2 +  // proposed_fee_bps
+    8 +  // proposed_at
+    8 +  // executable_after
+    1; // bump
+
+/// Syndicate prize stage account size
+pub const SYNDICATE_PRIZE_STAGE_SIZE: usize = 8 + // discriminator
+    32 + // syndicate
+    8 +  // draw_id
+    8 +  // staged_amount
+    4 +  // tickets_staged
+    1 +  // bump
+    8 * 5 + // prize_by_match
+    8; // escrowed_amount
+
+/// Second chance draw account size
+pub const SECOND_CHANCE_DRAW_SIZE: usize = 8 + // discriminator
+    8 +  // draw_id
+    8 +  // entry_count
+    8 +  // prize_pool
+    33 + // winner (Option<Pubkey>)
+    1 +  // is_resolved
+    1 +  // bump
+    32 + // randomness_account
+    8 +  // commit_slot
+    16; // padding
+
+/// Second chance entry account size
+pub const SECOND_CHANCE_ENTRY_SIZE: usize = 8 + // discriminator
+    8 +  // draw_id
+    32 + // ticket
+    32 + // owner
+    8 +  // entry_index
+    1 +  // bump
+    8; // padding
+
+/// Maximum number of house fee recipients
+pub const MAX_HOUSE_FEE_RECIPIENTS: usize = 4;
+
+/// `withdraw_house_fees` recipient index that selects the original
+/// (unsplit) house fee account instead of a recipient sub-account
+pub const HOUSE_FEE_LEGACY_INDEX: u8 = u8::MAX;
+
+/// House fee recipients account size
+pub const HOUSE_FEE_RECIPIENTS_SIZE: usize = 8 + // discriminator
+    4 + (MAX_HOUSE_FEE_RECIPIENTS * (32 + 2)) + // recipients (Vec<FeeRecipient>)
+    4 + (MAX_HOUSE_FEE_RECIPIENTS * (32 + 2)) + // pending_recipients (Vec<FeeRecipient>)
+    8 +  // pending_effective_at
+    MAX_HOUSE_FEE_RECIPIENTS + // sub_account_bumps
+    1 +  // bump
+    16; // padding
+
+/// Prize escrow account size
+pub const PRIZE_ESCROW_SIZE: usize = 8 + // discriminator
+    32 + // ticket
+    32 + // beneficiary
+    8 +  // amount
+    8 +  // release_timestamp
+    8 +  // draw_id
+    1 +  // bump
+    16; // padding
+
+/// Cancelled draw ticket refund record size
+pub const TICKET_REFUND_RECORD_SIZE: usize = 8 + // discriminator
+    8 +  // draw_id
+    8 +  // total_refundable
+    8 +  // total_refunded
+    1 +  // is_active
+    1 +  // bump
+    16; // padding
+
+/// Multi-draw ticket account size
+pub const MULTI_DRAW_TICKET_SIZE: usize = 8 + // discriminator
+    32 + // owner
+    8 +  // start_draw_id
+    8 +  // end_draw_id
+    6 +  // numbers
+    8 +  // claimed_draws (bitmask)
+    8 +  // purchase_timestamp
+    1 +  // bump
+    16; // padding
+
 /// Maximum aggregate emergency transfer amount per 24-hour rolling window.
 /// Set to 20% of hard cap. This prevents a compromised authority from
 /// draining the prize pool through repeated small emergency transfers.
@@ -409,31 +1417,41 @@ pub fn calculate_quick_pick_house_fee_bps(jackpot_balance: u64, is_rolldown: boo
 }
 
 /// Calculate rolldown probability (basis points) based on jackpot level
+/// using the default SOFT_CAP and HARD_CAP
+pub fn calculate_rolldown_probability_bps(jackpot_balance: u64) -> u16 {
+    calculate_rolldown_probability_bps_for_caps(jackpot_balance, SOFT_CAP, HARD_CAP)
+}
+
+/// Calculate rolldown probability (basis points) for the given caps
 /// Returns value between 0 and 10000 (0% to 100%)
 ///
 /// # Edge Cases Handled:
-/// - SOFT_CAP == HARD_CAP: Returns 100% probability
+/// - soft_cap == hard_cap: Returns 100% probability
 /// - range == 0: Returns 100% probability
-/// - jackpot_balance between SOFT_CAP and HARD_CAP: Linear interpolation
+/// - jackpot_balance between soft_cap and hard_cap: Linear interpolation
 /// - Overflow protection with u128 arithmetic
-pub fn calculate_rolldown_probability_bps(jackpot_balance: u64) -> u16 {
+pub fn calculate_rolldown_probability_bps_for_caps(
+    jackpot_balance: u64,
+    soft_cap: u64,
+    hard_cap: u64,
+) -> u16 {
     // Safety check: validate caps configuration
-    if SOFT_CAP > HARD_CAP {
+    if soft_cap > hard_cap {
         // Invalid configuration: soft cap exceeds hard cap
         return 0;
     }
 
-    if jackpot_balance < SOFT_CAP {
+    if jackpot_balance < soft_cap {
         return 0;
     }
 
-    if jackpot_balance >= HARD_CAP || SOFT_CAP == HARD_CAP {
+    if jackpot_balance >= hard_cap || soft_cap == hard_cap {
         return BPS_DENOMINATOR as u16; // 100%
     }
 
     // Linear interpolation between soft cap and hard cap
-    let excess = jackpot_balance.saturating_sub(SOFT_CAP);
-    let range = HARD_CAP.saturating_sub(SOFT_CAP);
+    let excess = jackpot_balance.saturating_sub(soft_cap);
+    let range = hard_cap.saturating_sub(soft_cap);
 
     if range == 0 {
         // No range between caps, use 100% probability
@@ -456,6 +1474,33 @@ pub fn calculate_rolldown_probability_bps(jackpot_balance: u64) -> u16 {
     probability.min(BPS_DENOMINATOR as u16)
 }
 
+/// Largest arithmetic step treated as a low-entropy pick (e.g. 1-2-3-4-5-6, 3-6-9-...)
+pub const LOW_ENTROPY_MAX_STEP: u8 = 3;
+
+/// Detect a low-entropy pick: the sorted numbers form an arithmetic
+/// sequence with a constant step of at most LOW_ENTROPY_MAX_STEP
+///
+/// Returns the detected step, or `None` if the pick is acceptable.
+pub fn detect_low_entropy_step(numbers: &[u8; NUMBERS_PER_TICKET]) -> Option<u8> {
+    let mut sorted = *numbers;
+    sorted.sort();
+
+    let step = sorted[1].wrapping_sub(sorted[0]);
+    if step == 0 || step > LOW_ENTROPY_MAX_STEP {
+        return None;
+    }
+
+    sorted
+        .windows(2)
+        .all(|pair| pair[1].wrapping_sub(pair[0]) == step)
+        .then_some(step)
+}
+
+/// Check that ticket numbers are not a low-entropy arithmetic sequence
+pub fn validate_numbers_entropy(numbers: &[u8; NUMBERS_PER_TICKET]) -> bool {
+    detect_low_entropy_step(numbers).is_none()
+}
+
 /// Validate lottery numbers (6/46 matrix)
 pub fn validate_lottery_numbers(numbers: &[u8; 6]) -> bool {
     // Check range for each number
@@ -537,6 +1582,19 @@ pub fn calculate_match_count(ticket_numbers: &[u8], winning_numbers: &[u8]) -> u
     matches
 }
 
+/// Syndicate Wars score of a ticket with `match_count` matches
+/// (Match 6 = 10,000 down to Match 2 = 1)
+pub fn wars_score_for_match(match_count: u8) -> u64 {
+    match match_count {
+        6 => 10_000,
+        5 => 1_000,
+        4 => 100,
+        3 => 10,
+        2 => 1,
+        _ => 0,
+    }
+}
+
 /// Calculate fixed prize amount based on match count (Normal Mode)
 pub fn calculate_fixed_prize(match_count: u8) -> u64 {
     match match_count {
@@ -582,6 +1640,19 @@ mod tests {
         assert!(!validate_lottery_numbers(&duplicate_numbers));
     }
 
+    #[test]
+    fn test_validate_numbers_entropy() {
+        assert!(!validate_numbers_entropy(&[1, 2, 3, 4, 5, 6]));
+        assert!(!validate_numbers_entropy(&[6, 5, 4, 3, 2, 1]));
+        assert!(!validate_numbers_entropy(&[3, 6, 9, 12, 15, 18]));
+        assert_eq!(detect_low_entropy_step(&[10, 12, 14, 16, 18, 20]), Some(2));
+
+        // Step above the threshold, or an irregular pick, is accepted
+        assert!(validate_numbers_entropy(&[4, 8, 12, 16, 20, 24]));
+        assert!(validate_numbers_entropy(&[1, 2, 3, 4, 5, 7]));
+        assert!(validate_numbers_entropy(&[5, 12, 23, 31, 38, 46]));
+    }
+
     #[test]
     fn test_calculate_match_count() {
         let ticket = [1, 2, 3, 4, 5, 6];
@@ -616,6 +1687,356 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_is_escrow_required() {
+        let threshold = 1_000_000_000_000;
+        assert!(is_escrow_required(6, threshold + 1, threshold));
+        assert!(!is_escrow_required(6, threshold, threshold));
+        assert!(!is_escrow_required(5, threshold + 1, threshold));
+
+        // Zero threshold bypasses escrow entirely
+        assert!(!is_escrow_required(6, u64::MAX, 0));
+    }
+
+    #[test]
+    fn test_is_identity_verification_required() {
+        let threshold = 600_000_000_000;
+        assert!(is_identity_verification_required(threshold + 1, threshold));
+        assert!(!is_identity_verification_required(threshold, threshold));
+
+        // Zero threshold disables verification
+        assert!(!is_identity_verification_required(u64::MAX, 0));
+    }
+
+    #[test]
+    fn test_is_valid_ticket_sale_cutoff() {
+        assert!(is_valid_ticket_sale_cutoff(
+            TICKET_SALE_CUTOFF,
+            DRAW_INTERVAL
+        ));
+        assert!(is_valid_ticket_sale_cutoff(MIN_TICKET_SALE_CUTOFF, 3600));
+        assert!(!is_valid_ticket_sale_cutoff(
+            MIN_TICKET_SALE_CUTOFF - 1,
+            3600
+        ));
+        assert!(is_valid_ticket_sale_cutoff(900, 3600));
+        assert!(!is_valid_ticket_sale_cutoff(901, 3600));
+    }
+
+    #[test]
+    fn test_split_insurance_contribution() {
+        assert_eq!(
+            INSURANCE_TIER1_ALLOCATION_BPS + INSURANCE_TIER2_ALLOCATION_BPS,
+            INSURANCE_ALLOCATION_BPS
+        );
+        assert_eq!(split_insurance_contribution(40_000), (30_000, 10_000));
+        // Dust stays in tier 1
+        assert_eq!(split_insurance_contribution(7), (6, 1));
+        assert_eq!(split_insurance_contribution(0), (0, 0));
+    }
+
+    #[test]
+    fn test_calculate_insurance_replenishment() {
+        // 50% of the house fee while well below the minimum
+        assert_eq!(
+            calculate_insurance_replenishment(1_000, 0, 10_000, 5_000),
+            500
+        );
+        // Capped at the remaining shortfall
+        assert_eq!(
+            calculate_insurance_replenishment(1_000, 9_800, 10_000, 5_000),
+            200
+        );
+        // Nothing once the minimum is reached, or when disabled
+        assert_eq!(
+            calculate_insurance_replenishment(1_000, 10_000, 10_000, 5_000),
+            0
+        );
+        assert_eq!(calculate_insurance_replenishment(1_000, 0, 0, 5_000), 0);
+        assert_eq!(calculate_insurance_replenishment(1_000, 0, 10_000, 0), 0);
+        // Never more than the house fee itself
+        assert_eq!(
+            calculate_insurance_replenishment(1_000, 0, 10_000, 10_000),
+            1_000
+        );
+    }
+
+    #[test]
+    fn test_is_daily_window_expired() {
+        let start = 1_700_000_000;
+        assert!(!is_daily_window_expired(start, start));
+        assert!(!is_daily_window_expired(
+            start,
+            start + DAILY_LIMIT_WINDOW_SECONDS - 1
+        ));
+        assert!(is_daily_window_expired(
+            start,
+            start + DAILY_LIMIT_WINDOW_SECONDS
+        ));
+
+        // A never-started window (0) is always expired
+        assert!(is_daily_window_expired(0, start));
+    }
+
+    #[test]
+    fn test_velocity_window_and_alert() {
+        let start = 1_700_000_000;
+        assert!(!is_velocity_window_expired(
+            start,
+            start + VELOCITY_WINDOW_SECONDS - 1
+        ));
+        assert!(is_velocity_window_expired(
+            start,
+            start + VELOCITY_WINDOW_SECONDS
+        ));
+
+        assert!(!is_velocity_alert(79, 100));
+        assert!(is_velocity_alert(80, 100));
+        assert!(is_velocity_alert(100, 100));
+        // Unlimited never alerts
+        assert!(!is_velocity_alert(u32::MAX, 0));
+    }
+
+    #[test]
+    fn test_effective_max_members() {
+        assert_eq!(effective_max_members(0), 10);
+        assert_eq!(effective_max_members(199), 10);
+        assert_eq!(effective_max_members(200), 15);
+        assert_eq!(effective_max_members(499), 15);
+        assert_eq!(effective_max_members(500), 20);
+        assert_eq!(effective_max_members(u32::MAX), 20);
+    }
+
+    #[test]
+    fn test_house_fee_withdrawal_cap() {
+        assert_eq!(house_fee_withdrawal_cap(1_000_000, 0), None);
+        assert_eq!(house_fee_withdrawal_cap(1_000_000, 2000), Some(200_000));
+        assert_eq!(house_fee_withdrawal_cap(u64::MAX, 10000), Some(u64::MAX));
+
+        assert!(!is_withdrawal_cap_alert(79, 100));
+        assert!(is_withdrawal_cap_alert(80, 100));
+
+        assert!(!requires_withdrawal_cosign(50, 100));
+        assert!(requires_withdrawal_cosign(51, 100));
+    }
+
+    #[test]
+    fn test_expected_fixed_prize_balance() {
+        // 1,000 tickets at $2.50 with 39.4% to fixed prizes = $985
+        assert_eq!(expected_fixed_prize_balance(1_000, 0), 985_000_000);
+        assert_eq!(expected_fixed_prize_balance(1_000, 5_000_000), 980_000_000);
+        // Payouts beyond the allocation floor at zero
+        assert_eq!(expected_fixed_prize_balance(1, u64::MAX), 0);
+    }
+
+    #[test]
+    fn test_extend_self_exclusion() {
+        let now = 1_700_000_000;
+        assert_eq!(
+            extend_self_exclusion(0, now, MIN_SELF_EXCLUSION_SECONDS),
+            now + MIN_SELF_EXCLUSION_SECONDS
+        );
+
+        // A shorter request cannot cut an existing exclusion short
+        let until = now + MAX_SELF_EXCLUSION_SECONDS;
+        assert_eq!(
+            extend_self_exclusion(until, now, MIN_SELF_EXCLUSION_SECONDS),
+            until
+        );
+
+        // A later request extends it
+        assert_eq!(
+            extend_self_exclusion(until, until - 10, MIN_SELF_EXCLUSION_SECONDS),
+            until - 10 + MIN_SELF_EXCLUSION_SECONDS
+        );
+    }
+
+    #[test]
+    fn test_is_vrf_fallback_ready() {
+        let committed_at = 1_700_000_000;
+        assert!(!is_vrf_fallback_ready(0, committed_at));
+        assert!(!is_vrf_fallback_ready(
+            committed_at,
+            committed_at + VRF_FALLBACK_DELAY_SECONDS - 1
+        ));
+        assert!(is_vrf_fallback_ready(
+            committed_at,
+            committed_at + VRF_FALLBACK_DELAY_SECONDS
+        ));
+        assert!(!is_vrf_fallback_ready(i64::MAX, i64::MAX));
+    }
+
+    #[test]
+    fn test_next_anchored_draw_timestamp() {
+        let day = SECONDS_PER_DAY;
+        let anchor = 20 * 3600; // 20:00 UTC
+        let draw_at = 19_000 * day + anchor;
+
+        // On time, late and very late draws all land on the next 20:00
+        assert_eq!(
+            next_anchored_draw_timestamp(draw_at, anchor, day),
+            draw_at + day
+        );
+        assert_eq!(
+            next_anchored_draw_timestamp(draw_at + 3 * 3600, anchor, day),
+            draw_at + day
+        );
+        assert_eq!(
+            next_anchored_draw_timestamp(draw_at + day + 1, anchor, day),
+            draw_at + 2 * day
+        );
+
+        // Sub-daily intervals align to the anchor modulo the interval
+        let hour = 3600;
+        assert_eq!(
+            next_anchored_draw_timestamp(draw_at + 90 * 60, anchor + 30 * 60, hour),
+            draw_at + 2 * hour + 30 * 60
+        );
+
+        assert!(is_valid_anchor_draw_time(0));
+        assert!(is_valid_anchor_draw_time(day - 1));
+        assert!(!is_valid_anchor_draw_time(day));
+        assert!(!is_valid_anchor_draw_time(-1));
+    }
+
+    #[test]
+    fn test_is_keeper_trigger_ready() {
+        let draw_at = 1_700_000_000;
+        let grace = DEFAULT_KEEPER_GRACE_PERIOD_SECONDS;
+        assert!(!is_keeper_trigger_ready(draw_at, grace, draw_at));
+        assert!(!is_keeper_trigger_ready(
+            draw_at,
+            grace,
+            draw_at + grace - 1
+        ));
+        assert!(is_keeper_trigger_ready(draw_at, grace, draw_at + grace));
+        assert!(!is_keeper_trigger_ready(i64::MAX, grace, i64::MAX));
+
+        // A configured grace period replaces the default
+        assert!(is_keeper_trigger_ready(
+            draw_at,
+            MIN_KEEPER_GRACE_PERIOD_SECONDS,
+            draw_at + MIN_KEEPER_GRACE_PERIOD_SECONDS
+        ));
+        assert!(!is_keeper_trigger_ready(
+            draw_at,
+            MAX_KEEPER_GRACE_PERIOD_SECONDS,
+            draw_at + grace
+        ));
+    }
+
+    #[test]
+    fn test_calculate_collateral_ratio_bps() {
+        assert_eq!(calculate_collateral_ratio_bps(1_000, 1_000), 10_000);
+        assert_eq!(calculate_collateral_ratio_bps(1_500, 1_000), 15_000);
+        assert_eq!(calculate_collateral_ratio_bps(999, 1_000), 9_990);
+        assert_eq!(calculate_collateral_ratio_bps(0, 1_000), 0);
+        assert_eq!(calculate_collateral_ratio_bps(1_000, 0), u64::MAX);
+        assert_eq!(calculate_collateral_ratio_bps(u64::MAX, 1), u64::MAX);
+    }
+
+    #[test]
+    fn test_is_config_proposal_expired() {
+        let timelock_end = 1_700_000_000;
+        assert!(!is_config_proposal_expired(0, timelock_end));
+        assert!(!is_config_proposal_expired(timelock_end, timelock_end));
+        assert!(!is_config_proposal_expired(
+            timelock_end,
+            timelock_end + CONFIG_PROPOSAL_EXECUTION_WINDOW
+        ));
+        assert!(is_config_proposal_expired(
+            timelock_end,
+            timelock_end + CONFIG_PROPOSAL_EXECUTION_WINDOW + 1
+        ));
+        assert!(!is_config_proposal_expired(i64::MAX, i64::MAX));
+    }
+
+    #[test]
+    fn test_is_finalize_overdue() {
+        let executed_at = 1_700_000_000;
+        let deadline = executed_at + FINALIZE_TIMEOUT_SECONDS + GRACE_PERIOD_SECONDS;
+        assert!(!is_finalize_overdue(executed_at, executed_at));
+        assert!(!is_finalize_overdue(executed_at, deadline));
+        assert!(is_finalize_overdue(executed_at, deadline + 1));
+
+        // No executed draw, or an overflowing timestamp, is never overdue
+        assert!(!is_finalize_overdue(0, i64::MAX));
+        assert!(!is_finalize_overdue(i64::MAX, i64::MAX));
+    }
+
+    #[test]
+    fn test_is_valid_operator_permissions() {
+        assert!(is_valid_operator_permissions(0));
+        assert!(is_valid_operator_permissions(OPERATOR_PERMISSION_ALL));
+        assert!(is_valid_operator_permissions(
+            OPERATOR_PERMISSION_EXECUTE_DRAW
+        ));
+        assert!(!is_valid_operator_permissions(1 << 3));
+        assert!(!is_valid_operator_permissions(u64::MAX));
+    }
+
+    #[test]
+    fn test_jackpot_milestones() {
+        let thresholds = DEFAULT_JACKPOT_MILESTONE_THRESHOLDS;
+        assert!(is_valid_jackpot_milestone_thresholds(&thresholds));
+        assert!(!is_valid_jackpot_milestone_thresholds(&[0, 1, 2, 3, 4]));
+        assert!(!is_valid_jackpot_milestone_thresholds(&[1, 2, 2, 3, 4]));
+        assert!(!is_valid_jackpot_milestone_thresholds(&[5, 4, 3, 2, 1]));
+        assert_eq!(
+            newly_reached_jackpot_milestones(&thresholds, 0, SEED_AMOUNT),
+            0
+        );
+        assert_eq!(
+            newly_reached_jackpot_milestones(&thresholds, 0, thresholds[0]),
+            0b00001
+        );
+        // Jumping past several thresholds reports all of them at once
+        assert_eq!(
+            newly_reached_jackpot_milestones(&thresholds, 0, thresholds[2]),
+            0b00111
+        );
+        // Already-emitted milestones are not reported again
+        assert_eq!(
+            newly_reached_jackpot_milestones(&thresholds, 0b00011, thresholds[2]),
+            0b00100
+        );
+        assert_eq!(
+            newly_reached_jackpot_milestones(&thresholds, 0b11111, u64::MAX),
+            0
+        );
+    }
+
+    #[test]
+    fn test_crossed_milestone_bonuses() {
+        let thresholds = [100, 200, 300, 400, 500];
+        let prizes = [10, 0, 30, 40, 50];
+
+        assert_eq!(crossed_milestone_bonuses(&thresholds, &prizes, 50, 99), 0);
+        assert_eq!(
+            crossed_milestone_bonuses(&thresholds, &prizes, 99, 100),
+            0b00001
+        );
+        // Milestone 1 has no bonus
+        assert_eq!(
+            crossed_milestone_bonuses(&thresholds, &prizes, 150, 350),
+            0b00100
+        );
+        // Already above the threshold
+        assert_eq!(crossed_milestone_bonuses(&thresholds, &prizes, 300, 310), 0);
+    }
+
+    #[test]
+    fn test_is_valid_rolldown_split() {
+        assert!(is_valid_rolldown_split(&[
+            ROLLDOWN_MATCH_5_BPS,
+            ROLLDOWN_MATCH_4_BPS,
+            ROLLDOWN_MATCH_3_BPS
+        ]));
+        assert!(is_valid_rolldown_split(&[0, 0, 10000]));
+        assert!(!is_valid_rolldown_split(&[2500, 3500, 3999]));
+        assert!(!is_valid_rolldown_split(&[u16::MAX, u16::MAX, 10000]));
+    }
+
     #[test]
     fn test_calculate_rolldown_probability_bps() {
         assert_eq!(calculate_rolldown_probability_bps(0), 0);
@@ -625,5 +2046,20 @@ mod tests {
         // At midpoint between soft and hard cap, probability should be 50%
         let midpoint = SOFT_CAP + (HARD_CAP - SOFT_CAP) / 2;
         assert_eq!(calculate_rolldown_probability_bps(midpoint), 5000);
+
+        // Custom caps: $10k soft, $20k hard
+        let (soft, hard) = (10_000_000_000, 20_000_000_000);
+        assert_eq!(
+            calculate_rolldown_probability_bps_for_caps(soft - 1, soft, hard),
+            0
+        );
+        assert_eq!(
+            calculate_rolldown_probability_bps_for_caps(15_000_000_000, soft, hard),
+            5000
+        );
+        assert_eq!(
+            calculate_rolldown_probability_bps_for_caps(hard, soft, hard),
+            10000
+        );
     }
 }
@@ -638,6 +638,836 @@ pub enum LottoError {
     /// Failed to deserialize a ticket account from remaining_accounts
     #[msg("Failed to deserialize ticket account from remaining_accounts.")]
     InvalidTicketAccount,
+
+    // ==========================================================================
+    // LUCKY DIP
+    // ==========================================================================
+    /// Lucky dip number derivation failed to find 6 unique numbers
+    #[msg("Lucky dip entropy exhausted before 6 unique numbers were found.")]
+    EntropyExhausted,
+
+    // ==========================================================================
+    // TICKET GIFTING
+    // ==========================================================================
+    /// The ticket's draw has already been finalized
+    #[msg("Draw has already been finalized.")]
+    DrawAlreadyFinalized,
+
+    /// Gift recipient is invalid (default key or the current owner)
+    #[msg("Invalid gift recipient.")]
+    InvalidGiftRecipient,
+
+    // ==========================================================================
+    // TICKET ACCOUNT CLOSURE
+    // ==========================================================================
+    /// Ticket is neither claimed nor past its claim window
+    #[msg("Ticket cannot be closed until it is claimed or its claim window expires.")]
+    TicketNotClosable,
+
+    /// Only the ticket owner may close this ticket
+    #[msg("Third-party ticket closure is disabled.")]
+    ThirdPartyClosureDisabled,
+
+    // ==========================================================================
+    // MULTI-DRAW PRIZE CLAIMS
+    // ==========================================================================
+    /// remaining_accounts must be non-empty (draw, ticket) pairs
+    #[msg("Multi-draw claim requires alternating draw result and ticket accounts.")]
+    InvalidMultiDrawClaimAccounts,
+
+    /// Too many (draw, ticket) pairs in a single claim
+    #[msg("Too many draw/ticket pairs in a single multi-draw claim.")]
+    TooManyClaimPairs,
+
+    /// Draw result account is not the canonical PDA or failed to deserialize
+    #[msg("Invalid draw result account.")]
+    InvalidDrawResultAccount,
+
+    // ==========================================================================
+    // REFERRALS
+    // ==========================================================================
+    /// Referrer is the player, the default key, or would create a referral loop
+    #[msg("Invalid referrer.")]
+    InvalidReferrer,
+
+    /// A referrer has already been registered for this player
+    #[msg("Referrer has already been set for this player.")]
+    ReferrerAlreadySet,
+
+    /// Player has already bought enough tickets to qualify
+    #[msg("Referral must be registered before the qualifying ticket purchase.")]
+    ReferralWindowClosed,
+
+    /// Qualifying purchase requires the referrer's UserStats account
+    #[msg("Referrer stats account is required for this purchase.")]
+    ReferrerStatsRequired,
+
+    /// Player has no referral credits to redeem
+    #[msg("No referral credits available.")]
+    NoReferralCredits,
+
+    /// Reserve cannot fund a referral ticket
+    #[msg("Insufficient reserve balance to fund referral ticket.")]
+    InsufficientReserveForReferral,
+
+    // ==========================================================================
+    // MINIMUM TICKETS PER DRAW
+    // ==========================================================================
+    /// Not enough tickets sold to commit randomness for this draw
+    #[msg("Not enough tickets sold for this draw. Postpone the draw instead.")]
+    InsufficientTicketsForDraw,
+
+    /// Draw cannot be postponed once the minimum ticket count is met
+    #[msg("Minimum ticket count already met; draw cannot be postponed.")]
+    MinTicketsAlreadyMet,
+
+    // ==========================================================================
+    // SYNDICATE INVITES
+    // ==========================================================================
+    /// Invite has passed its expiry time
+    #[msg("Syndicate invite has expired.")]
+    InviteExpired,
+
+    /// Invite has reached its maximum number of uses
+    #[msg("Syndicate invite has no uses remaining.")]
+    InviteExhausted,
+
+    /// Invite code does not match the supplied invite account
+    #[msg("Invalid syndicate invite code.")]
+    InvalidInviteCode,
+
+    // ==========================================================================
+    // SYNDICATE CONTRIBUTION CAP
+    // ==========================================================================
+    /// Member contribution would exceed the syndicate's per-member cap
+    #[msg("Contribution exceeds the syndicate's per-member limit.")]
+    ContributionExceedsLimit,
+
+    // ==========================================================================
+    // SYNDICATE LOCK
+    // ==========================================================================
+    /// Membership is frozen while the syndicate holds tickets for the current draw
+    #[msg("Syndicate is locked until the current draw is finalized.")]
+    SyndicateLocked,
+
+    // ==========================================================================
+    // ROLLDOWN CONFIG
+    // ==========================================================================
+    /// Rolldown tier shares do not sum to 100%
+    #[msg("Rolldown split must sum to exactly 10000 basis points.")]
+    InvalidRolldownSplit,
+
+    // ==========================================================================
+    // SECOND CHANCE
+    // ==========================================================================
+    /// Ticket has already been entered into the second chance draw
+    #[msg("Ticket already entered into the second chance draw.")]
+    SecondChanceAlreadyEntered,
+
+    /// Second chance draw has already been resolved
+    #[msg("Second chance draw already resolved.")]
+    SecondChanceAlreadyResolved,
+
+    /// Second chance draw has no entries to pick a winner from
+    #[msg("Second chance draw has no entries.")]
+    NoSecondChanceEntries,
+
+    /// Reserve cannot fund the requested second chance prize
+    #[msg("Insufficient reserve balance to fund second chance prize.")]
+    InsufficientReserveForSecondChance,
+
+    /// Supplied entry or payout account does not match the selected winner
+    #[msg("Invalid second chance winner accounts.")]
+    InvalidSecondChanceWinner,
+
+    // ==========================================================================
+    // PRIZE ESCROW
+    // ==========================================================================
+    /// Escrow accounts missing for a prize above the escrow threshold, or
+    /// supplied for a prize that is paid directly
+    #[msg("Escrow accounts must be supplied exactly when the prize is escrowed.")]
+    InvalidEscrowAccounts,
+
+    /// Prize must be escrowed and cannot be claimed through this instruction
+    #[msg("Prize exceeds the escrow threshold. Claim it individually with escrow accounts.")]
+    EscrowRequired,
+
+    /// Escrow release delay has not elapsed
+    #[msg("Escrowed prize is still locked.")]
+    EscrowLocked,
+
+    /// Escrow release delay outside the allowed range
+    #[msg("Invalid escrow release delay.")]
+    InvalidEscrowDelay,
+
+    // ==========================================================================
+    // HOUSE FEE RECIPIENTS
+    // ==========================================================================
+    /// Recipient list is too long, has a zero share, or does not sum to 100%
+    #[msg("Invalid house fee recipients: up to 4 entries summing to 10000 bps.")]
+    InvalidHouseFeeRecipients,
+
+    /// Fee recipients config or sub-accounts missing or mismatched
+    #[msg("Invalid house fee recipient accounts.")]
+    InvalidHouseFeeAccounts,
+
+    /// Recipient index does not refer to a configured recipient
+    #[msg("Invalid house fee recipient index.")]
+    InvalidRecipientIndex,
+
+    // ==========================================================================
+    // OPERATOR ROLE
+    // ==========================================================================
+    /// Operator address or permission mask is invalid
+    #[msg("Invalid operator: cannot be the authority, and permissions must use known bits.")]
+    InvalidOperator,
+
+    // ==========================================================================
+    // RESERVE MINIMUM
+    // ==========================================================================
+    /// Reserve balance is below the configured minimum
+    #[msg("Reserve balance is below the configured minimum.")]
+    ReserveBelowMinimum,
+
+    // ==========================================================================
+    // WINNER MERKLE ROOT
+    // ==========================================================================
+    /// A winner Merkle root was already submitted for this draw
+    #[msg("Winner Merkle root already submitted for this draw.")]
+    WinnerMerkleRootAlreadySubmitted,
+
+    /// No winner Merkle root has been submitted for this draw
+    #[msg("Winner Merkle root not submitted for this draw.")]
+    WinnerMerkleRootNotSubmitted,
+
+    // ==========================================================================
+    // JACKPOT RESEED
+    // ==========================================================================
+    /// Jackpot is already at or above the seed amount
+    #[msg("Jackpot does not need reseeding.")]
+    JackpotReseedNotNeeded,
+
+    /// Reserve cannot cover the jackpot deficit
+    #[msg("Insufficient reserve to reseed the jackpot.")]
+    InsufficientReserve,
+
+    // ==========================================================================
+    // DAILY PURCHASE LIMIT
+    // ==========================================================================
+    /// Purchase would exceed the player's daily ticket limit
+    #[msg("Daily ticket limit reached.")]
+    DailyLimitExceeded,
+
+    /// Personal daily limits can only be lowered
+    #[msg("Daily ticket limit can only be lowered.")]
+    InvalidDailyLimit,
+
+    // ==========================================================================
+    // NUMBER ENTROPY
+    // ==========================================================================
+    /// Numbers form a low-entropy arithmetic sequence
+    #[msg("Ticket numbers form a low-entropy pattern.")]
+    LowEntropyNumbers,
+
+    // ==========================================================================
+    // MULTI-DRAW TICKETS
+    // ==========================================================================
+    /// Multi-draw ticket draw count is outside the allowed range
+    #[msg("Multi-draw ticket draw count must be between 2 and 52.")]
+    InvalidMultiDrawCount,
+
+    /// Draw is outside the multi-draw ticket's range
+    #[msg("Draw is not covered by this multi-draw ticket.")]
+    DrawNotCoveredByTicket,
+
+    // ==========================================================================
+    // TICKET SALE CUTOFF
+    // ==========================================================================
+    /// Ticket sale cutoff is outside the allowed range
+    #[msg("Ticket sale cutoff must be at least 60 seconds and at most a quarter of the draw interval.")]
+    InvalidTicketSaleCutoff,
+
+    // ==========================================================================
+    // PRIZE SOLVENCY
+    // ==========================================================================
+    /// Internal prize balances cannot cover a prize payout
+    #[msg("Prize pool balances cannot cover this prize.")]
+    PrizePoolInsolvent,
+
+    // ==========================================================================
+    // SYNDICATE REGISTRY
+    // ==========================================================================
+    /// Syndicate registry already lists the maximum number of public syndicates
+    #[msg("Syndicate registry is full (max 1000 public syndicates).")]
+    RegistryFull,
+
+    // ==========================================================================
+    // KEEPER ERRORS
+    // ==========================================================================
+    /// Keeper grace period outside the allowed range
+    #[msg("Keeper grace period must be between 60 seconds and 24 hours.")]
+    InvalidKeeperGracePeriod,
+
+    // ==========================================================================
+    // CONFIG PROPOSAL ERRORS
+    // ==========================================================================
+    /// Pending config proposal was not executed within its window
+    #[msg("Config proposal expired. Propose the change again.")]
+    ConfigProposalExpired,
+
+    // ==========================================================================
+    // STATS SNAPSHOT ERRORS
+    // ==========================================================================
+    /// Stats snapshot requested before the cooldown elapsed
+    #[msg("Lottery stats snapshots are limited to one per hour.")]
+    SnapshotTooFrequent,
+
+    // ==========================================================================
+    // FALLBACK RANDOMNESS ERRORS
+    // ==========================================================================
+    /// VRF result is still unset and fallback randomness is disabled
+    #[msg("VRF result is stale and fallback randomness is disabled. Cancel the draw.")]
+    VRFResultStale,
+
+    // ==========================================================================
+    // PULL ORACLE ERRORS
+    // ==========================================================================
+    /// Pull feed has not produced a new round since the commit
+    #[msg("Pull oracle round has not advanced since the randomness commit.")]
+    PullOracleRoundNotAdvanced,
+
+    /// Pull oracle mode is enabled without a configured pull feed
+    #[msg("Pull oracle mode requires a Switchboard pull feed.")]
+    PullOracleFeedNotSet,
+
+    // ==========================================================================
+    // TOKEN ACCOUNT CREATION ERRORS
+    // ==========================================================================
+    /// Player cannot cover the rent for their new USDC token account
+    #[msg("Insufficient SOL to pay rent for the prize token account.")]
+    InsufficientRentForTokenAccount,
+
+    // ==========================================================================
+    // SYNDICATE WAITLIST ERRORS
+    // ==========================================================================
+    /// Waitlist is only open while the syndicate is full
+    #[msg("Syndicate is not full; join it directly.")]
+    SyndicateNotFull,
+
+    /// Waitlist already holds MAX_SYNDICATE_WAITLIST_ENTRIES wallets
+    #[msg("Syndicate waitlist is full.")]
+    WaitlistFull,
+
+    /// Wallet is already waiting for this syndicate
+    #[msg("Already on the syndicate waitlist.")]
+    AlreadyOnWaitlist,
+
+    /// Wallet is not on the syndicate waitlist
+    #[msg("Not on the syndicate waitlist.")]
+    NotOnWaitlist,
+
+    /// The syndicate holds waitlist escrow, so the waitlist account is needed
+    #[msg("Syndicate waitlist account required.")]
+    WaitlistAccountRequired,
+
+    /// Syndicate cannot close while waitlisted USDC is escrowed
+    #[msg("Syndicate waitlist still holds escrowed contributions.")]
+    WaitlistNotEmpty,
+
+    // ==========================================================================
+    // SYNDICATE UNLOCK ERRORS
+    // ==========================================================================
+    /// Syndicate has no active draw lock
+    #[msg("Syndicate is not locked.")]
+    SyndicateNotLocked,
+
+    /// The locked draw was force finalized; use unlock_syndicate_after_force_finalize
+    #[msg("Draw was force finalized; use the force-finalize unlock.")]
+    DrawWasForceFinalized,
+
+    /// The locked draw was not force finalized
+    #[msg("Draw was not force finalized.")]
+    DrawNotForceFinalized,
+
+    /// The post-force-finalize waiting period has not elapsed
+    #[msg("Syndicate cannot be unlocked yet after force finalization.")]
+    SyndicateUnlockTooEarly,
+
+    // ==========================================================================
+    // MULTISIG COSIGNER ERRORS
+    // ==========================================================================
+    /// PrizePool emergency transfers must also be signed by the cosigner
+    #[msg("Multisig cosigner signature required.")]
+    CosignerRequired,
+
+    /// Cosigner must differ from the authority, the default key and the current cosigner
+    #[msg("Invalid multisig cosigner.")]
+    InvalidCosigner,
+
+    // ==========================================================================
+    // SYNDICATE WARS BRACKET ERRORS
+    // ==========================================================================
+    /// Bracket rounds, finals share or entrant count are out of range
+    #[msg("Invalid Syndicate Wars bracket configuration.")]
+    InvalidBracketConfig,
+
+    /// The competition has no bracket yet
+    #[msg("Syndicate Wars bracket not started.")]
+    BracketNotStarted,
+
+    /// The competition already has a bracket
+    #[msg("Syndicate Wars bracket already started.")]
+    BracketAlreadyStarted,
+
+    /// Winners must hold exactly one syndicate from every current matchup
+    #[msg("Invalid Syndicate Wars bracket winners.")]
+    InvalidBracketWinners,
+
+    /// The bracket is already at its final round
+    #[msg("Syndicate Wars finals already reached.")]
+    FinalsAlreadyReached,
+
+    /// The bracket has not reached its final round
+    #[msg("Syndicate Wars finals not reached.")]
+    FinalsNotReached,
+
+    /// Syndicate is not one of the two finalists
+    #[msg("Syndicate is not a Syndicate Wars finalist.")]
+    NotAFinalist,
+
+    // ==========================================================================
+    // SELF-EXCLUSION ERRORS
+    // ==========================================================================
+    /// Player has self-excluded and cannot get new tickets yet
+    #[msg("Player is self-excluded.")]
+    PlayerSelfExcluded,
+
+    /// Self-exclusion duration outside MIN/MAX_SELF_EXCLUSION_SECONDS
+    #[msg("Invalid self-exclusion duration.")]
+    InvalidSelfExclusionDuration,
+
+    // ==========================================================================
+    // DYNAMIC TICKET PRICE ERRORS
+    // ==========================================================================
+    /// Effective ticket price is above the buyer's `max_price_usdc`
+    #[msg("Ticket price exceeds the maximum accepted price.")]
+    PriceExceedsSlippage,
+
+    /// Price tiers need positive prices and strictly ascending thresholds
+    #[msg("Invalid ticket price tiers.")]
+    InvalidPriceTiers,
+
+    // ==========================================================================
+    // TICKET CLEANUP ERRORS
+    // ==========================================================================
+    /// Cleanup needs 1-MAX_CLEANUP_TICKETS (ticket, draw result) account pairs
+    #[msg("Invalid expired ticket cleanup accounts.")]
+    InvalidCleanupAccounts,
+
+    // ==========================================================================
+    // SYNDICATE FEE PROPOSAL ERRORS
+    // ==========================================================================
+    /// A manager fee increase is already waiting on its veto window
+    #[msg("A manager fee proposal is already pending.")]
+    FeeProposalPending,
+
+    /// No manager fee increase is pending
+    #[msg("No manager fee proposal is pending.")]
+    NoFeeProposal,
+
+    /// The veto window of the pending fee increase has not ended
+    #[msg("Manager fee proposal veto window still open.")]
+    FeeProposalTimelockActive,
+
+    /// The veto window of the pending fee increase has ended
+    #[msg("Manager fee proposal veto window has closed.")]
+    FeeVetoWindowClosed,
+
+    /// Only members holding SYNDICATE_FEE_VETO_MIN_SHARE_BPS can veto
+    #[msg("Member share too small to veto the fee proposal.")]
+    FeeVetoShareTooLow,
+
+    // ==========================================================================
+    // SCHEMA VERSION ERRORS
+    // ==========================================================================
+    /// Account layout version differs from CURRENT_SCHEMA_VERSION
+    #[msg("Account schema version mismatch; migration required.")]
+    SchemaVersionMismatch,
+
+    /// Account is already at CURRENT_SCHEMA_VERSION
+    #[msg("Account schema is already current.")]
+    SchemaAlreadyCurrent,
+
+    // ==========================================================================
+    // CANCELLED DRAW REFUND ERRORS
+    // ==========================================================================
+    /// The refund record must be supplied exactly when refunding tickets
+    #[msg("Refund record must be supplied only for RefundTickets cancellation.")]
+    InvalidRefundRecord,
+
+    /// Tickets cannot be refunded once execute_draw has run
+    #[msg("Draw already executed; use force_finalize_draw instead.")]
+    DrawAlreadyExecuted,
+
+    /// Reserve cannot cover the refunds of a cancelled draw
+    #[msg("Insufficient reserve to refund cancelled draw tickets.")]
+    InsufficientReserveForRefund,
+
+    /// Ticket has no outstanding refund
+    #[msg("Ticket is not refundable.")]
+    TicketNotRefundable,
+
+    /// The refund record is closed or exhausted
+    #[msg("Cancelled draw refunds are no longer active.")]
+    RefundNotActive,
+
+    // ==========================================================================
+    // SYNDICATE WARS AUTO-REGISTRATION ERRORS
+    // ==========================================================================
+    /// Auto-registration needs [syndicate_wars_state, wars_entry] remaining accounts
+    #[msg("Invalid Syndicate Wars auto-registration accounts.")]
+    InvalidSyndicateWarsAccounts,
+
+    /// Only public syndicates can be auto-registered at creation
+    #[msg("Syndicate is not eligible for Syndicate Wars auto-registration.")]
+    SyndicateNotEligibleForWars,
+
+    // ==========================================================================
+    // SYNDICATE WARS ON-CHAIN SCORING ERRORS
+    // ==========================================================================
+    /// The draw was not drawn between the competition's start and end
+    #[msg("Draw is outside the Syndicate Wars competition.")]
+    WarsDrawOutsideCompetition,
+
+    /// Draws are scored once each, in increasing order
+    #[msg("Draw has already been scored for this syndicate.")]
+    WarsDrawAlreadyScored,
+
+    /// The same ticket account was passed more than once
+    #[msg("Duplicate ticket account in Syndicate Wars scoring.")]
+    DuplicateWarsScoreTicket,
+
+    /// Ranked syndicates are not ordered by ranking score
+    #[msg("Syndicate Wars ranking does not follow ranking scores.")]
+    WarsRankingOutOfOrder,
+
+    // ==========================================================================
+    // RESERVE HEALTH ERRORS
+    // ==========================================================================
+    /// No draw has been recorded in the reserve history yet
+    #[msg("Reserve history has no snapshots yet.")]
+    ReserveHistoryEmpty,
+
+    // ==========================================================================
+    // WHITELIST ERRORS
+    // ==========================================================================
+    /// The player is not on the purchase whitelist
+    #[msg("Player is not whitelisted.")]
+    PlayerNotWhitelisted,
+
+    /// The player holds none of the whitelist's gate mint
+    #[msg("Player does not hold the required gate token.")]
+    TokenGateNotSatisfied,
+
+    /// Whitelisted purchases must pass the whitelist (and gate token account)
+    #[msg("Whitelist account required while whitelist mode is enabled.")]
+    WhitelistAccountRequired,
+
+    /// The whitelist already holds MAX_WHITELIST_WALLETS wallets
+    #[msg("Whitelist is full.")]
+    WhitelistFull,
+
+    /// Enable mode must be PlayerWhitelist, or TokenGated with a gate mint
+    #[msg("Invalid whitelist mode.")]
+    InvalidWhitelistMode,
+
+    /// add_to_whitelist / remove_from_whitelist need at least one wallet
+    #[msg("Wallet list cannot be empty.")]
+    EmptyWalletList,
+
+    // ==========================================================================
+    // CHUNKED BATCH CLAIM ERRORS
+    // ==========================================================================
+    /// claim_batch_prizes_chunk needs max_entries of at least 1
+    #[msg("Batch claim chunk size must be at least 1.")]
+    InvalidBatchClaimChunk,
+
+    /// Every ticket of the unified ticket has already been processed
+    #[msg("Batch claim cursor is already complete.")]
+    BatchClaimComplete,
+
+    // ==========================================================================
+    // PURCHASE VELOCITY ERRORS
+    // ==========================================================================
+    /// The purchase would exceed max_tickets_per_wallet_per_hour
+    #[msg("Hourly ticket purchase limit exceeded for this wallet.")]
+    PurchaseVelocityExceeded,
+
+    // ==========================================================================
+    // RECURRING TICKET ERRORS
+    // ==========================================================================
+    /// Draw IDs are not 1-10 consecutive future draws within the lookahead
+    #[msg("Recurring ticket draw IDs must be 1-10 consecutive draws within 100 draws after the current one.")]
+    InvalidRecurringDrawIds,
+
+    /// A remaining account is not the uncreated recurring ticket PDA for its draw
+    #[msg("Invalid recurring ticket account.")]
+    InvalidRecurringTicketAccount,
+
+    // ==========================================================================
+    // KYC COMPLIANCE ERRORS
+    // ==========================================================================
+    /// KYC compliance mode needs a kyc_authority
+    #[msg("KYC authority is not set.")]
+    KycAuthorityNotSet,
+
+    /// Only the kyc_authority may approve or revoke KYC records
+    #[msg("Unauthorized: caller is not the KYC authority.")]
+    UnauthorizedKycAuthority,
+
+    /// Purchases in KYC compliance mode must pass the player's KYC record
+    #[msg("KYC record required while KYC compliance mode is enabled.")]
+    KycRecordRequired,
+
+    /// The player's KYC approval has lapsed
+    #[msg("KYC approval has expired.")]
+    KycExpired,
+
+    /// The player's KYC approval has been revoked
+    #[msg("KYC approval has been revoked.")]
+    KycRevoked,
+
+    /// KYC approvals must expire in the future
+    #[msg("KYC expiry must be in the future.")]
+    InvalidKycExpiry,
+
+    /// Jurisdiction must be a two-letter uppercase country code
+    #[msg("Invalid KYC jurisdiction code.")]
+    InvalidKycJurisdiction,
+
+    /// Revocation reason longer than MAX_KYC_REVOKE_REASON_LENGTH
+    #[msg("KYC revocation reason is too long.")]
+    KycRevokeReasonTooLong,
+
+    // ==========================================================================
+    // IDENTITY VERIFICATION ERRORS
+    // ==========================================================================
+    /// identity_verification_threshold needs an identity_oracle
+    #[msg("Identity oracle is not set.")]
+    IdentityOracleNotSet,
+
+    /// Only the identity_oracle may issue identity proofs
+    #[msg("Unauthorized: caller is not the identity oracle.")]
+    UnauthorizedIdentityOracle,
+
+    /// Prizes above identity_verification_threshold need claim_prize_with_identity
+    #[msg("Prize requires identity verification: use claim_prize_with_identity.")]
+    IdentityVerificationRequired,
+
+    /// The player's identity proof has lapsed
+    #[msg("Identity proof has expired.")]
+    IdentityProofExpired,
+
+    /// Identity proof for another player, or issued with a bad jurisdiction or expiry
+    #[msg("Invalid identity proof.")]
+    InvalidIdentityProof,
+
+    // ==========================================================================
+    // TERMS ERRORS
+    // ==========================================================================
+    /// The player has not accepted the current terms version
+    #[msg("Current lottery terms have not been accepted.")]
+    TermsNotAccepted,
+
+    /// Terms URI empty or longer than MAX_TERMS_URI_LENGTH
+    #[msg("Terms URI must be 1-200 characters.")]
+    InvalidTermsUri,
+
+    // ==========================================================================
+    // DRAW CHALLENGE ERRORS
+    // ==========================================================================
+    /// The draw's challenge window has closed (or it never had one)
+    #[msg("Draw challenge window is closed.")]
+    ChallengeWindowClosed,
+
+    /// Disputed tier must be a prize tier (Match 2-6)
+    #[msg("Disputed tier must be between 2 and 6.")]
+    InvalidDisputedTier,
+
+    /// resolve_draw_challenge needs a draw under challenge review
+    #[msg("Draw has no challenge under review.")]
+    NoActiveDrawChallenge,
+
+    /// Claims are frozen while a challenge is under review or after it was upheld
+    #[msg("Prize claims for this draw are frozen by a challenge.")]
+    DrawClaimsFrozen,
+
+    // ==========================================================================
+    // WINNING NUMBERS OVERRIDE ERRORS
+    // ==========================================================================
+    /// Winning numbers can only be overridden while the lottery is paused
+    #[msg("Lottery must be paused to override winning numbers.")]
+    OverrideRequiresPause,
+
+    /// The override's WINNING_NUMBERS_OVERRIDE_DELAY has not elapsed
+    #[msg("Winning numbers override timelock has not expired.")]
+    OverrideTimelockActive,
+
+    /// Numbers or justification differ from the pending override
+    #[msg("Override does not match the pending proposal.")]
+    OverrideMismatch,
+
+    // ==========================================================================
+    // INSURANCE TIER 2 ERRORS
+    // ==========================================================================
+    /// Amount exceeds the insurance tier 2 balance
+    #[msg("Insufficient insurance tier 2 balance.")]
+    InsufficientInsuranceTier2,
+
+    /// The withdrawal's INSURANCE_TIER2_WITHDRAWAL_DELAY has not elapsed
+    #[msg("Insurance tier 2 withdrawal delay has not expired.")]
+    Tier2WithdrawalTimelockActive,
+
+    // ==========================================================================
+    // CLAIM AGENT ERRORS
+    // ==========================================================================
+    /// The agent is the default key or the ticket owner
+    #[msg("Invalid claim agent.")]
+    InvalidClaimAgent,
+
+    /// Claim agents can only be set for tickets of the most recent draw
+    #[msg("Claim agents can only be authorized for the most recent draw.")]
+    ClaimAgentWindowClosed,
+
+    // ==========================================================================
+    // PROTOCOL REGISTRY ERRORS
+    // ==========================================================================
+    /// The registry already lists MAX_PROTOCOL_REGISTRY_ENTRIES programs
+    #[msg("Protocol registry is full.")]
+    ProtocolRegistryFull,
+
+    /// The program is not in the protocol registry
+    #[msg("Program is not registered.")]
+    ProgramNotRegistered,
+
+    // ==========================================================================
+    // MILESTONE BONUS ERRORS
+    // ==========================================================================
+    /// The milestone index is out of range
+    #[msg("Invalid milestone index.")]
+    InvalidMilestoneIndex,
+
+    /// The milestone has no bonus, is already claimed or is not unlocked
+    #[msg("Milestone bonus is not claimable.")]
+    MilestoneBonusNotClaimable,
+
+    /// The reserve cannot cover the milestone bonus
+    #[msg("Insufficient reserve for milestone bonus.")]
+    InsufficientReserveForMilestoneBonus,
+
+    // ==========================================================================
+    // PER-DRAW WALLET LIMIT ERRORS
+    // ==========================================================================
+    /// The purchase would exceed max_tickets_per_wallet_per_draw
+    #[msg("Per-draw ticket limit exceeded for this wallet.")]
+    PerWalletDrawLimitExceeded,
+
+    // ==========================================================================
+    // HOUSE FEE WITHDRAWAL CAP ERRORS
+    // ==========================================================================
+    /// The withdrawal would exceed the daily house fee withdrawal cap
+    #[msg("Daily house fee withdrawal cap exceeded.")]
+    DailyWithdrawalCapExceeded,
+
+    // ==========================================================================
+    // FREE QUICKPICK CREDIT ERRORS
+    // ==========================================================================
+    /// Quick Pick state or free credit account missing or invalid
+    #[msg("Invalid Quick Pick state or free QuickPick credit account.")]
+    InvalidFreeQuickPickCreditAccounts,
+
+    /// The free QuickPick credit was already redeemed
+    #[msg("Free QuickPick credit already used.")]
+    FreeQuickPickCreditUsed,
+
+    /// The free QuickPick credit has lapsed
+    #[msg("Free QuickPick credit has expired.")]
+    FreeQuickPickCreditExpired,
+
+    // ==========================================================================
+    // VRF AUDIT ERRORS
+    // ==========================================================================
+    /// The draw has no stored VRF bytes or its numbers were overridden
+    #[msg("VRF audit not applicable to this draw.")]
+    VrfAuditNotApplicable,
+
+    // ==========================================================================
+    // PROGRAM UPGRADE ERRORS
+    // ==========================================================================
+    /// No program upgrade has been announced
+    #[msg("No program upgrade announced.")]
+    NoUpgradeAnnounced,
+
+    /// The account is not this program's program data account
+    #[msg("Invalid program data account.")]
+    InvalidProgramDataAccount,
+
+    // ==========================================================================
+    // RESERVE REBALANCE ERRORS
+    // ==========================================================================
+    /// The reserve is at or below its target, or the jackpot is at the soft cap
+    #[msg("No reserve excess to move into the jackpot.")]
+    NoReserveExcess,
+
+    /// The sampled draws paid no prizes, so the reserve target is unknown
+    #[msg("Reserve history has no prize data to size the reserve target.")]
+    NoReservePrizeHistory,
+
+    // ==========================================================================
+    // SECOND CHANCE RANDOMNESS ERRORS
+    // ==========================================================================
+    /// Randomness is committed, so entries are closed and cannot be recommitted
+    #[msg("Second chance randomness already committed; entries are closed.")]
+    SecondChanceRandomnessCommitted,
+
+    /// execute_second_chance_draw needs committed randomness
+    #[msg("Second chance randomness has not been committed.")]
+    SecondChanceRandomnessNotCommitted,
+
+    /// Randomness account is not on the lottery's Switchboard queue
+    #[msg("Randomness account is not on the lottery's Switchboard queue.")]
+    RandomnessQueueMismatch,
+
+    // ==========================================================================
+    // SYNDICATE TICKET ERRORS
+    // ==========================================================================
+    /// Creating these ticket accounts would exceed the tickets the syndicate
+    /// bought for the current draw
+    #[msg("Syndicate has not purchased that many tickets for this draw.")]
+    SyndicateTicketsNotPurchased,
+
+    // ==========================================================================
+    // RECURRING TICKET SCHEDULE ERRORS
+    // ==========================================================================
+    /// Recurring tickets are pending, so opening the next draw needs the
+    /// recurring ticket schedule
+    #[msg("Recurring ticket schedule account is required while recurring tickets are pending.")]
+    RecurringScheduleRequired,
+
+    // ==========================================================================
+    // DRAW CHALLENGE CORRECTION ERRORS
+    // ==========================================================================
+    /// correct_challenged_winner_counts needs a draw whose challenge was upheld
+    #[msg("Draw has no upheld challenge to correct.")]
+    ChallengeNotUpheld,
+
+    // ==========================================================================
+    // SECOND CHANCE RECLAIM ERRORS
+    // ==========================================================================
+    /// A second chance prize can only be reclaimed from a draw with no
+    /// entries or whose claim window has expired
+    #[msg("Second chance draw has entries and its claim window is still open.")]
+    SecondChanceNotReclaimable,
 }
 
 impl From<LottoError> for ProgramError {
@@ -5,6 +5,12 @@
 
 use anchor_lang::prelude::*;
 
+use crate::constants::NUMBER_FREQUENCY_SLOTS;
+use crate::state::{
+    CancelDrawMode, DrawHistorySummary, FeeRecipient, LeaderboardEntry, ProgramType,
+    SyndicateRegistryEntry, SyndicateUnlockMethod, WarsMatchup, WhitelistMode,
+};
+
 // ============================================================================
 // TICKET EVENTS
 // ============================================================================
@@ -45,6 +51,290 @@ pub struct BulkTicketsPurchased {
     pub timestamp: i64,
 }
 
+/// Emitted when a purchase is priced from the dynamic price tiers
+#[event]
+pub struct DynamicPriceTierApplied {
+    /// Jackpot balance the tier was chosen from
+    pub jackpot_balance: u64,
+    /// Price charged per ticket in USDC lamports
+    pub effective_price_per_ticket: u64,
+}
+
+/// Emitted when a multi-draw ticket is purchased
+#[event]
+pub struct MultiDrawTicketPurchased {
+    /// Player wallet address
+    pub player: Pubkey,
+    /// Number of consecutive draws covered
+    pub draw_count: u8,
+    /// Total price paid
+    pub total_price: u64,
+}
+
+/// Emitted when a recurring ticket is purchased for several future draws
+#[event]
+pub struct RecurringTicketPurchased {
+    /// Player wallet address
+    pub player: Pubkey,
+    /// Selected numbers (sorted ascending)
+    pub numbers: [u8; 6],
+    /// Draws a ticket was created for
+    pub draw_ids: Vec<u64>,
+    /// Total price paid
+    pub total_price: u64,
+}
+
+/// Emitted when a purchase is rejected because the sale cutoff has passed
+#[event]
+pub struct TicketSaleClosed {
+    /// Draw whose ticket sales have closed
+    pub draw_id: u64,
+    /// Time at which sales closed
+    pub cutoff_time: i64,
+    /// Tickets sold for the draw before the cutoff
+    pub tickets_sold: u64,
+}
+
+/// Emitted when a player lowers their own daily ticket limit
+#[event]
+pub struct DailyLimitSet {
+    /// Player wallet address
+    pub player: Pubkey,
+    /// New daily ticket limit
+    pub limit: u32,
+}
+
+/// Emitted when a player starts or extends a self-exclusion
+#[event]
+pub struct PlayerSelfExcluded {
+    /// Player wallet address
+    pub player: Pubkey,
+    /// Purchases are blocked until this timestamp
+    pub excluded_until: i64,
+}
+
+/// Emitted when a purchase takes a wallet to 80% or more of the hourly
+/// velocity limit (possible bot-driven buying)
+#[event]
+pub struct PlayerVelocityAlert {
+    /// Player wallet address
+    pub player: Pubkey,
+    /// Tickets bought in the current hourly window, including this purchase
+    pub tickets_this_hour: u32,
+    /// Configured hourly limit
+    pub threshold: u32,
+}
+
+/// Emitted when a purchase is rejected for exceeding the per-draw wallet limit
+#[event]
+pub struct WalletDrawLimitReached {
+    /// Player wallet address
+    pub player: Pubkey,
+    /// Draw the purchase was for
+    pub draw_id: u64,
+    /// Configured per-draw limit
+    pub limit: u64,
+}
+
+/// Emitted when house fee withdrawals reach 80% of the daily withdrawal cap
+#[event]
+pub struct DailyWithdrawalCapApproaching {
+    /// House fees withdrawn in the current window
+    pub withdrawn: u64,
+    /// Withdrawal cap for the window
+    pub cap: u64,
+    /// Amount still withdrawable in the window
+    pub remaining: u64,
+}
+
+/// Emitted at the end of handlers that change lottery funds or status flags
+#[event]
+pub struct LotteryStateDiff {
+    /// Current draw ID after the change
+    pub draw_id: u64,
+    /// Change in jackpot balance
+    pub jackpot_delta: i64,
+    /// Change in reserve balance
+    pub reserve_delta: i64,
+    /// Change in insurance balance (both tiers)
+    pub insurance_delta: i64,
+    /// Tickets sold
+    pub tickets_delta: u64,
+    /// Prizes paid
+    pub prizes_paid_delta: u64,
+    /// STATE_DIFF_FLAG_* bits of the status flags that changed
+    pub flags_changed: u8,
+}
+
+/// Emitted when a Match 5 claim grants a free QuickPick ticket
+#[event]
+pub struct FreeQPCreditIssued {
+    /// Winning player
+    pub player: Pubkey,
+    /// Main lottery draw of the Match 5 ticket
+    pub main_draw_id: u64,
+    /// QuickPick draw the free ticket is for
+    pub qp_draw_id: u64,
+    /// When the credit lapses
+    pub expires_at: i64,
+}
+
+/// Emitted when QuickPick redeems a free ticket credit
+#[event]
+pub struct FreeQPCreditRedeemed {
+    /// Player who bought the free ticket
+    pub player: Pubkey,
+    /// QuickPick draw of the free ticket
+    pub qp_draw_id: u64,
+}
+
+/// Emitted when the authority changes who may buy tickets
+#[event]
+pub struct WhitelistModeSet {
+    /// New whitelist mode
+    pub mode: WhitelistMode,
+}
+
+/// Emitted when a wallet is added to the purchase whitelist
+#[event]
+pub struct WalletWhitelisted {
+    /// Whitelisted wallet
+    pub wallet: Pubkey,
+}
+
+/// Emitted when a wallet is removed from the purchase whitelist
+#[event]
+pub struct WalletRemovedFromWhitelist {
+    /// Removed wallet
+    pub wallet: Pubkey,
+}
+
+/// Emitted when a prize above the identity verification threshold is
+/// claimed with a valid identity proof
+#[event]
+pub struct LargeWinIdentityVerified {
+    /// Claimed ticket
+    pub ticket_id: Pubkey,
+    /// ISO 3166-1 alpha-2 country code of the winner
+    pub jurisdiction: [u8; 2],
+    /// Prize amount in USDC lamports
+    pub prize_amount: u64,
+}
+
+/// Emitted when the KYC authority approves (or re-approves) a player
+#[event]
+pub struct KYCApproved {
+    /// Approved player
+    pub player: Pubkey,
+    /// ISO 3166-1 alpha-2 country code of the player
+    pub jurisdiction: [u8; 2],
+    /// When the approval lapses
+    pub expires_at: i64,
+}
+
+/// Emitted when the KYC authority revokes a player's approval
+#[event]
+pub struct KYCRevoked {
+    /// Player whose approval was revoked
+    pub player: Pubkey,
+    /// Why the approval was revoked
+    pub reason: String,
+}
+
+/// Emitted when a player accepts the lottery terms
+#[event]
+pub struct TermsAccepted {
+    /// Player accepting the terms
+    pub player: Pubkey,
+    /// Accepted terms version
+    pub version: u8,
+    /// Acceptance timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when the authority publishes new lottery terms
+#[event]
+pub struct TermsUpdated {
+    /// New terms version
+    pub version: u8,
+    /// Location of the new terms
+    pub uri: String,
+}
+
+/// Emitted when a purchase is rejected by the player's daily ticket limit
+#[event]
+pub struct DailyLimitReached {
+    /// Player wallet address
+    pub player: Pubkey,
+    /// Start of the current daily window
+    pub window_start: i64,
+    /// Tickets the rejected purchase tried to buy
+    pub attempted_count: u32,
+}
+
+/// Emitted when a purchase is rejected for low-entropy numbers
+#[event]
+pub struct LowEntropyTicketRejected {
+    /// Player (or syndicate creator) who submitted the numbers
+    pub player: Pubkey,
+    /// Rejected numbers as submitted
+    pub numbers: [u8; 6],
+    /// Description of the detected pattern
+    pub pattern_detected: String,
+}
+
+/// Emitted when lucky dip numbers are derived on-chain
+#[event]
+pub struct LuckyDipGenerated {
+    /// Player wallet address
+    pub player: Pubkey,
+    /// Draw ID the ticket is for
+    pub draw_id: u64,
+    /// Derived numbers (sorted)
+    pub numbers: [u8; 6],
+    /// SHA256(player || slot || timestamp || total_tickets_sold)
+    pub entropy_hash: [u8; 32],
+}
+
+/// Emitted when a ticket (or unified ticket) is gifted to another wallet
+#[event]
+pub struct TicketGifted {
+    /// Previous owner
+    pub from: Pubkey,
+    /// New owner
+    pub to: Pubkey,
+    /// Ticket account public key (TicketData or UnifiedTicket)
+    pub ticket_id: Pubkey,
+    /// Draw ID the ticket is for
+    pub draw_id: u64,
+    /// Gift timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when a ticket (or unified ticket) account is closed and its rent recovered
+#[event]
+pub struct TicketAccountClosed {
+    /// Closed account public key (TicketData or UnifiedTicket)
+    pub account_key: Pubkey,
+    /// Ticket owner who received the rent
+    pub owner: Pubkey,
+    /// Lamports returned to the owner
+    pub rent_recovered_lamports: u64,
+    /// Draw ID the ticket was for
+    pub draw_id: u64,
+}
+
+/// Emitted when a caller closes a batch of expired, unclaimed ticket accounts
+#[event]
+pub struct ExpiredTicketsCleaned {
+    /// Number of ticket accounts closed
+    pub count: u32,
+    /// Rent lamports recovered from the closed accounts (paid to the caller)
+    pub total_rent_recovered: u64,
+    /// Caller that closed the accounts
+    pub caller: Pubkey,
+}
+
 // ============================================================================
 // DRAW EVENTS
 // ============================================================================
@@ -62,6 +352,19 @@ pub struct DrawInitialized {
     pub is_rolldown_pending: bool,
 }
 
+/// Result of checking a draw's stored commitment hash
+#[event]
+pub struct DrawCommitmentVerified {
+    /// Draw ID
+    pub draw_id: u64,
+    /// Whether the stored hash equals the expected hash
+    pub matches: bool,
+    /// Hash recorded at commit
+    pub stored_hash: [u8; 32],
+    /// Hash supplied by the caller
+    pub expected_hash: [u8; 32],
+}
+
 /// Emitted when randomness is committed for a draw
 #[event]
 pub struct RandomnessCommitted {
@@ -91,6 +394,9 @@ pub struct DrawExecuted {
 }
 
 /// Emitted when a draw is finalized with winner counts
+///
+/// Deprecated: superseded by `DrawFinalizedV2`, which also carries per-winner
+/// prizes and rolldown details. Still emitted for existing indexers.
 #[event]
 pub struct DrawFinalized {
     /// Draw ID
@@ -111,6 +417,372 @@ pub struct DrawFinalized {
     pub timestamp: i64,
 }
 
+/// Emitted when a draw is finalized, with every prize tier's winners and
+/// per-winner prize
+#[event]
+pub struct DrawFinalizedV2 {
+    /// Draw ID
+    pub draw_id: u64,
+    /// Winning numbers
+    pub winning_numbers: [u8; 6],
+    /// Tickets entered in the draw
+    pub total_tickets: u64,
+    /// Whether prizes were paid in rolldown mode
+    pub was_rolldown: bool,
+    /// Whether the rolldown was forced by the jackpot reaching the hard cap
+    pub was_rolldown_forced: bool,
+    /// Jackpot paid out to winners (0 if it carries over)
+    pub jackpot_distributed: u64,
+    /// Match 6 winners count
+    pub match_6_winners: u32,
+    /// Match 6 prize per winner
+    pub match_6_prize: u64,
+    /// Match 5 winners count
+    pub match_5_winners: u32,
+    /// Match 5 prize per winner
+    pub match_5_prize: u64,
+    /// Match 4 winners count
+    pub match_4_winners: u32,
+    /// Match 4 prize per winner
+    pub match_4_prize: u64,
+    /// Match 3 winners count
+    pub match_3_winners: u32,
+    /// Match 3 prize per winner
+    pub match_3_prize: u64,
+    /// Match 2 winners count
+    pub match_2_winners: u32,
+    /// Match 2 prize per winner
+    pub match_2_prize: u64,
+    /// Total USDC distributed
+    pub total_distributed: u64,
+    /// Whether fixed prizes were scaled down for lack of funds
+    pub prizes_scaled: bool,
+    /// Scale factor applied (10000 = no scaling)
+    pub scale_factor_bps: u16,
+    /// Insurance pool funds used to cover prizes
+    pub insurance_used: u64,
+    /// Jackpot balance after finalization
+    pub new_jackpot_balance: u64,
+    /// Reserve balance after finalization
+    pub new_reserve_balance: u64,
+    /// ID of the next draw
+    pub new_draw_id: u64,
+}
+
+/// Emitted when `finalize_draw` schedules the next draw on the anchor grid
+#[event]
+pub struct DrawScheduleAnchored {
+    /// Next draw timestamp
+    pub next_draw_timestamp: i64,
+    /// Draw time of day in seconds since midnight UTC
+    pub anchor_time: i64,
+    /// Draw interval in seconds
+    pub interval: i64,
+}
+
+/// Emitted when the authority proposes replacing a draw's winning numbers
+#[event]
+pub struct WinningNumbersOverrideProposed {
+    /// Draw whose numbers would be replaced
+    pub draw_id: u64,
+    /// Replacement winning numbers
+    pub new_numbers: [u8; 6],
+    /// SHA256 of the public explanation document
+    pub justification_hash: [u8; 32],
+    /// Earliest time the override can be applied
+    pub executable_at: i64,
+}
+
+/// Emitted when a draw's winning numbers are replaced before finalization
+#[event]
+pub struct WinningNumbersOverridden {
+    /// Draw whose numbers were replaced
+    pub draw_id: u64,
+    /// Winning numbers before the override
+    pub old_numbers: [u8; 6],
+    /// Winning numbers after the override
+    pub new_numbers: [u8; 6],
+    /// SHA256 of the public explanation document
+    pub justification_hash: [u8; 32],
+}
+
+/// Emitted when a draw's winner counts are challenged
+#[event]
+pub struct DrawChallengeSubmitted {
+    /// Challenged draw
+    pub draw_id: u64,
+    /// Challenging wallet
+    pub challenger: Pubkey,
+    /// Match tier whose winner count is disputed
+    pub disputed_tier: u8,
+    /// Hash of the off-chain evidence
+    pub evidence_hash: [u8; 32],
+    /// Challenges counted so far
+    pub challenge_count: u8,
+}
+
+/// Emitted when enough challenges put a draw under review (and pause the lottery)
+#[event]
+pub struct DrawChallengeThresholdReached {
+    /// Challenged draw
+    pub draw_id: u64,
+    /// Challenges counted
+    pub challenge_count: u8,
+}
+
+/// Emitted when the authority resolves a draw challenge review
+#[event]
+pub struct DrawChallengeResolved {
+    /// Challenged draw
+    pub draw_id: u64,
+    /// Whether the challenge was upheld (claims stay frozen)
+    pub accepted: bool,
+    /// Resolving authority
+    pub authority: Pubkey,
+    /// Resolution timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when the authority corrects the winner counts of a draw whose
+/// challenge was upheld, unfreezing its claims
+#[event]
+pub struct DrawWinnerCountsCorrected {
+    /// Corrected draw
+    pub draw_id: u64,
+    /// Corrected winner counts, Match 6 through Match 2
+    pub winner_counts: [u32; 5],
+    /// Corrected prize per winner, Match 6 through Match 2
+    pub prizes_per_winner: [u64; 5],
+    /// Correcting authority
+    pub authority: Pubkey,
+    /// Correction timestamp
+    pub timestamp: i64,
+}
+
+/// Periodic snapshot of lottery-wide statistics for analytics indexers
+#[event]
+pub struct LotteryStatsSnapshot {
+    /// Total tickets sold across all draws
+    pub total_tickets_sold: u64,
+    /// Total prizes paid out
+    pub total_prizes_paid: u64,
+    /// Total prizes committed at finalization
+    pub total_prizes_committed: u64,
+    /// Current jackpot balance
+    pub jackpot_balance: u64,
+    /// Current reserve balance
+    pub reserve_balance: u64,
+    /// Current insurance balance
+    pub insurance_balance: u64,
+    /// Current fixed prize balance
+    pub fixed_prize_balance: u64,
+    /// Current draw ID
+    pub current_draw_id: u64,
+    /// Tickets sold in the current draw
+    pub current_draw_tickets: u64,
+    /// Current house fee in basis points
+    pub house_fee_bps: u16,
+    /// Scheduled time of the next draw
+    pub next_draw_timestamp: i64,
+    /// Whether a rolldown is pending
+    pub is_rolldown_active: bool,
+    /// Whether the lottery is paused
+    pub is_paused: bool,
+    /// Soft cap
+    pub soft_cap: u64,
+    /// Hard cap
+    pub hard_cap: u64,
+    /// Prize pool USDC balance over jackpot + reserve (bps, u64::MAX if nothing owed)
+    pub collateral_ratio_bps: u64,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Snapshot of the recent draw history ring buffer
+#[event]
+pub struct DrawHistorySnapshot {
+    /// Recent finalized draws, oldest first
+    pub entries: Vec<DrawHistorySummary>,
+    /// Total draws ever recorded in the buffer
+    pub total_draws: u64,
+}
+
+/// Snapshot of the winning number frequency histogram
+#[event]
+pub struct NumberFrequencySnapshot {
+    /// Times each number has been drawn (index = number, slot 0 unused)
+    pub frequencies: [u32; NUMBER_FREQUENCY_SLOTS],
+    /// Most frequently drawn number (lowest on ties)
+    pub hot_number: u8,
+    /// Least frequently drawn number (lowest on ties)
+    pub cold_number: u8,
+    /// Draws per number if all numbers came up equally often
+    pub expected_frequency: u64,
+    /// Finalized draws counted
+    pub total_draws: u64,
+}
+
+/// Snapshot of the lifetime lottery analytics
+#[event]
+pub struct LotteryAnalyticsSnapshot {
+    /// Finalized draws
+    pub total_draws: u64,
+    /// Finalized draws with at least one Match 6 winner
+    pub total_jackpots_won: u32,
+    /// Largest jackpot balance ever reached
+    pub largest_jackpot_ever: u64,
+    /// Draw in which the largest jackpot was reached
+    pub largest_jackpot_draw_id: u64,
+    /// Finalized rolldown draws
+    pub total_rolldowns_executed: u32,
+    /// Prizes paid out as of the last finalized draw
+    pub total_prizes_ever_paid: u64,
+    /// Wallets that have ever bought a ticket
+    pub all_time_player_count: u64,
+    /// Wallets that have bought a ticket in the current season
+    pub current_season_player_count: u64,
+    /// Current season (starts at 1)
+    pub season_number: u32,
+    /// When the current season started
+    pub season_start: i64,
+}
+
+/// Emitted when the winning number distribution fails the chi-squared test
+#[event]
+pub struct StatisticalBiasAlert {
+    /// Chi-squared statistic, scaled by CHI_SQUARED_SCALE
+    pub chi_squared_scaled: u64,
+    /// Number contributing most to the statistic (lowest on ties)
+    pub most_biased_number: u8,
+    /// Draws per number if all numbers came up equally often
+    pub expected_count: u64,
+    /// Times `most_biased_number` has been drawn
+    pub actual_count: u32,
+    /// Finalized draws covered by the test
+    pub draw_range: u32,
+}
+
+/// Emitted when the winning number distribution passes the chi-squared test
+#[event]
+pub struct BiasTestPassed {
+    /// Draw that completed the test interval
+    pub draw_id: u64,
+    /// Chi-squared statistic, scaled by CHI_SQUARED_SCALE
+    pub chi_squared_scaled: u64,
+}
+
+/// Snapshot of the all-time jackpot leaderboard
+#[event]
+pub struct LeaderboardSnapshot {
+    /// Filled entries, largest prize first
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+/// Emitted when a prize claim enters the jackpot leaderboard
+#[event]
+pub struct LeaderboardEntryAdded {
+    /// 1-based leaderboard rank
+    pub rank: u8,
+    /// Prize winner
+    pub winner: Pubkey,
+    /// Prize amount (USDC lamports)
+    pub prize: u64,
+    /// Draw the prize was won in
+    pub draw_id: u64,
+}
+
+/// Emitted when the authority announces a program upgrade
+#[event]
+pub struct ProgramUpgradeAnnounced {
+    /// SHA256 of the new program bytecode
+    pub hash: [u8; 32],
+    /// Earliest slot the upgrade may be deployed in
+    pub earliest_slot: u64,
+}
+
+/// Emitted alongside draw finalization while an announced upgrade is in its
+/// waiting period
+#[event]
+pub struct UpgradePendingWarning {
+    /// SHA256 of the announced program bytecode
+    pub hash: [u8; 32],
+    /// Earliest slot the upgrade may be deployed in
+    pub earliest_slot: u64,
+}
+
+/// Emitted by verify_upgrade_hash
+#[event]
+pub struct UpgradeHashVerified {
+    /// Whether the deployed bytecode matches the announced hash
+    pub matches: bool,
+}
+
+/// Emitted when verify_upgrade_hash finds the announced upgrade deployed
+#[event]
+pub struct ProgramUpgradeActivated {
+    /// Slot the deployment was verified in
+    pub slot: u64,
+}
+
+/// Emitted when audit_vrf_result re-derives a draw's stored winning numbers
+#[event]
+pub struct VrfAuditPassed {
+    /// Audited draw
+    pub draw_id: u64,
+    /// SHA256 of the stored VRF bytes
+    pub vrf_hash: [u8; 32],
+}
+
+/// Emitted when audit_vrf_result derives different winning numbers than stored
+#[event]
+pub struct VrfAuditFailed {
+    /// Audited draw
+    pub draw_id: u64,
+    /// Winning numbers stored on the draw result
+    pub stored_numbers: [u8; 6],
+    /// Winning numbers derived from the stored VRF bytes
+    pub derived_numbers: [u8; 6],
+}
+
+/// Emitted by `check_oracle_health` for every checked randomness account
+#[event]
+pub struct OracleHealthReport {
+    /// Whether every check passed
+    pub is_healthy: bool,
+    /// Estimated time of the account's last reveal (0 if never revealed)
+    pub last_update_timestamp: i64,
+    /// Estimated seconds since the account's last reveal
+    pub staleness_seconds: i64,
+    /// Whether the account belongs to the lottery's Switchboard queue
+    pub queue_matches: bool,
+}
+
+/// Emitted when `check_oracle_health` finds the oracle unhealthy
+#[event]
+pub struct OracleHealthAlert {
+    /// First failed check
+    pub reason: String,
+}
+
+/// Emitted when execute_draw uses slot-hash fallback randomness because the
+/// VRF result was still unset
+#[event]
+pub struct FallbackRandomnessUsed {
+    /// Draw ID
+    pub draw_id: u64,
+    /// Randomness used to generate the winning numbers
+    pub fallback_hash: [u8; 32],
+}
+
+/// Emitted when execute_draw takes its randomness from the pull feed
+#[event]
+pub struct PullOracleDrawExecuted {
+    /// Draw ID
+    pub draw_id: u64,
+    /// Pull feed round (result slot) used for the draw
+    pub round_id: u64,
+}
+
 /// Emitted when a rolldown is executed
 #[event]
 pub struct RolldownExecuted {
@@ -128,6 +800,13 @@ pub struct RolldownExecuted {
     pub timestamp: i64,
 }
 
+/// Emitted when rolldowns are held off after a forced hard-cap rolldown
+#[event]
+pub struct RolldownCooldownActive {
+    /// Last draw ID without rolldowns (inclusive)
+    pub until_draw_id: u64,
+}
+
 // ============================================================================
 // PRIZE EVENTS
 // ============================================================================
@@ -151,6 +830,79 @@ pub struct PrizeClaimed {
     pub timestamp: i64,
 }
 
+/// Emitted when a ticket owner authorizes a claim agent
+#[event]
+pub struct ClaimAgentAuthorized {
+    /// Ticket the agent may claim
+    pub ticket: Pubkey,
+    /// Ticket owner
+    pub owner: Pubkey,
+    /// Authorized agent
+    pub agent: Pubkey,
+}
+
+/// Emitted when a claim agent claims a prize on the owner's behalf
+#[event]
+pub struct PrizeClaimedByAgent {
+    /// Claimed ticket
+    pub ticket: Pubkey,
+    /// Agent that signed the claim
+    pub agent: Pubkey,
+    /// Prize amount (paid to the ticket owner)
+    pub prize_amount: u64,
+}
+
+/// Emitted by `preview_prize` with the prize a ticket would receive
+#[event]
+pub struct PrizePreview {
+    /// Ticket account public key
+    pub ticket_id: Pubkey,
+    /// Draw ID
+    pub draw_id: u64,
+    /// Number of matches
+    pub match_count: u8,
+    /// Prize amount in USDC lamports
+    pub prize_amount: u64,
+    /// Whether the ticket has already been claimed
+    pub is_claimed: bool,
+    /// Last timestamp at which the prize can be claimed
+    pub claim_expires_at: i64,
+}
+
+/// Emitted by `preview_batch_prizes` with the prize of one unified ticket entry
+#[event]
+pub struct BatchPrizePreview {
+    /// Unified ticket account public key
+    pub batch_id: Pubkey,
+    /// Index of the ticket within the unified ticket
+    pub ticket_index: u32,
+    /// Draw ID
+    pub draw_id: u64,
+    /// Number of matches
+    pub match_count: u8,
+    /// Prize amount in USDC lamports
+    pub prize_amount: u64,
+    /// Whether the ticket has already been claimed
+    pub is_claimed: bool,
+    /// Last timestamp at which the prize can be claimed
+    pub claim_expires_at: i64,
+}
+
+/// Emitted for each chunk processed by `claim_batch_prizes_chunk`
+#[event]
+pub struct BatchClaimChunkProcessed {
+    /// Unified ticket account the chunk was claimed from
+    pub batch_id: Pubkey,
+    /// Claim cursor before the chunk
+    pub start_cursor: u16,
+    /// Claim cursor after the chunk (u16::MAX once complete)
+    pub end_cursor: u16,
+    /// USDC paid for this chunk in USDC lamports
+    pub prizes_this_chunk: u64,
+    /// Whether every ticket in the batch has been processed
+    pub cursor_complete: bool,
+}
+
 /// Emitted when the jackpot is won
 #[event]
 pub struct JackpotWon {
@@ -168,6 +920,99 @@ pub struct JackpotWon {
     pub timestamp: i64,
 }
 
+/// Emitted once per multi-draw claim, after the individual PrizeClaimed events
+#[event]
+pub struct MultiDrawPrizesClaimed {
+    /// Player wallet address
+    pub player: Pubkey,
+    /// Number of tickets claimed (already-claimed tickets are skipped)
+    pub pairs_processed: u32,
+    /// Total USDC transferred to the player
+    pub total_prize: u64,
+}
+
+/// Emitted when a jackpot prize is escrowed instead of paid directly
+#[event]
+pub struct PrizeEscrowed {
+    /// Ticket account public key
+    pub ticket_id: Pubkey,
+    /// Escrowed amount in USDC lamports
+    pub amount: u64,
+    /// Unix timestamp from which the prize can be released
+    pub release_at: i64,
+}
+
+/// Emitted when claim_prize creates the player's USDC associated token
+/// account to receive a prize
+#[event]
+pub struct TokenAccountAutoCreated {
+    /// Player who paid for the account
+    pub player: Pubkey,
+    /// The new associated token account
+    pub ata_pubkey: Pubkey,
+    /// Rent paid by the player in lamports
+    pub rent_paid_lamports: u64,
+}
+
+/// Emitted when an escrowed prize is released to the winner
+#[event]
+pub struct PrizeEscrowReleased {
+    /// Ticket account public key
+    pub ticket_id: Pubkey,
+    /// Wallet that received the prize
+    pub beneficiary: Pubkey,
+    /// Released amount in USDC lamports
+    pub amount: u64,
+}
+
+/// Emitted when a ticket is checked against its draw's winner Merkle root
+#[event]
+pub struct TicketMerkleVerified {
+    /// Whether the proof places the ticket in the winner set
+    pub is_included: bool,
+    /// Ticket account public key
+    pub ticket_pubkey: Pubkey,
+    /// Draw the ticket belongs to
+    pub draw_id: u64,
+}
+
+/// Emitted when randomness is committed for a second chance draw
+#[event]
+pub struct SecondChanceRandomnessCommitted {
+    /// Main draw the second chance draw belongs to
+    pub draw_id: u64,
+    /// Committed Switchboard randomness account
+    pub randomness_account: Pubkey,
+    /// Seed slot of the committed randomness
+    pub commit_slot: u64,
+    /// Entries closed at the commit
+    pub entry_count: u64,
+}
+
+/// Emitted when a second chance draw is resolved and its prize paid
+#[event]
+pub struct SecondChanceWinner {
+    /// Main draw the second chance draw belongs to
+    pub draw_id: u64,
+    /// Winning entry's ticket owner
+    pub winner: Pubkey,
+    /// Prize paid to the winner
+    pub prize: u64,
+}
+
+/// Emitted when an unresolved second chance prize is returned to the reserve
+#[event]
+pub struct SecondChancePrizeReclaimed {
+    /// Main draw the second chance draw belongs to
+    pub draw_id: u64,
+    /// Prize moved back to the reserve
+    pub amount: u64,
+    /// Entries in the draw when it was closed
+    pub entry_count: u64,
+    /// Reserve balance after the reclaim
+    pub new_reserve_balance: u64,
+}
+
 // ============================================================================
 // SYNDICATE EVENTS
 // ============================================================================
@@ -185,42 +1030,242 @@ pub struct SyndicateCreated {
     pub is_public: bool,
     /// Manager fee in basis points
     pub manager_fee_bps: u16,
+    /// Member limit from the creator's reputation score
+    pub max_members: u8,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when `create_syndicate` registers the new syndicate for Syndicate Wars
+#[event]
+pub struct SyndicateAutoRegisteredForWars {
+    /// Syndicate account public key
+    pub syndicate: Pubkey,
+    /// Competition month the syndicate entered
+    pub wars_season_id: u64,
+    /// Timestamp
+    pub registration_timestamp: i64,
+}
+
+/// Emitted when a member joins a syndicate
+#[event]
+pub struct SyndicateMemberJoined {
+    /// Syndicate account public key
+    pub syndicate: Pubkey,
+    /// Member wallet address
+    pub member: Pubkey,
+    /// USDC contribution amount
+    pub contribution: u64,
+    /// Share of the syndicate in basis points
+    pub share_bps: u16,
+    /// Current member count after joining
+    pub member_count: u32,
     /// Timestamp
     pub timestamp: i64,
 }
 
-/// Emitted when a member joins a syndicate
+/// Emitted when a wallet escrows USDC to wait for a place in a full syndicate
+#[event]
+pub struct WaitlistJoined {
+    /// Syndicate account public key
+    pub syndicate: Pubkey,
+    /// Waiting wallet
+    pub wallet: Pubkey,
+    /// USDC escrowed
+    pub contribution: u64,
+    /// 1-based position on the waitlist
+    pub position: u32,
+}
+
+/// Emitted when a wallet leaves a syndicate waitlist and reclaims its escrow
+#[event]
+pub struct WaitlistLeft {
+    /// Syndicate account public key
+    pub syndicate: Pubkey,
+    /// Wallet that left
+    pub wallet: Pubkey,
+    /// USDC refunded
+    pub refund: u64,
+}
+
+/// Emitted when leave_syndicate promotes the first waitlisted wallet
+#[event]
+pub struct MemberPromotedFromWaitlist {
+    /// Syndicate account public key
+    pub syndicate: Pubkey,
+    /// Promoted wallet
+    pub wallet: Pubkey,
+    /// Escrowed USDC added as contribution
+    pub contribution: u64,
+    /// Share of the syndicate after promotion (bps)
+    pub share_bps: u16,
+    /// Member count after promotion
+    pub member_count: u32,
+}
+
+/// Emitted when a syndicate manager proposes a manager fee increase
+#[event]
+pub struct FeeProposeStarted {
+    /// Syndicate account public key
+    pub syndicate: Pubkey,
+    /// Proposed manager fee (bps)
+    pub proposed_fee: u16,
+    /// When the manager can apply the increase if not vetoed
+    pub executable_after: i64,
+}
+
+/// Emitted when a member vetoes a pending manager fee increase
+#[event]
+pub struct FeeProposalRejected {
+    /// Syndicate account public key
+    pub syndicate: Pubkey,
+    /// Vetoing member wallet
+    pub member: Pubkey,
+    /// Vetoing member's share of the syndicate (bps)
+    pub share_bps: u16,
+}
+
+/// Emitted when a member withdraws part of their syndicate contribution
+#[event]
+pub struct MemberPartialWithdrawal {
+    /// Syndicate account public key
+    pub syndicate: Pubkey,
+    /// Member wallet address
+    pub member: Pubkey,
+    /// USDC withdrawn
+    pub amount: u64,
+    /// Member's contribution after the withdrawal
+    pub new_contribution: u64,
+    /// Member's share of the syndicate after the withdrawal (bps)
+    pub new_share_bps: u16,
+}
+
+/// Emitted when a batch of syndicate tickets is staged for prize distribution
+#[event]
+pub struct SyndicatePrizeStaged {
+    /// Syndicate account public key
+    pub syndicate: Pubkey,
+    /// Draw ID
+    pub draw_id: u64,
+    /// Tickets marked claimed in this batch
+    pub batch_tickets: u32,
+    /// Prize staged so far across all batches
+    pub accumulated_amount: u64,
+}
+
+/// Emitted when a syndicate prize is distributed
+#[event]
+pub struct SyndicatePrizeDistributed {
+    /// Syndicate account public key
+    pub syndicate: Pubkey,
+    /// Draw ID
+    pub draw_id: u64,
+    /// Total prize amount
+    pub total_prize: u64,
+    /// Manager fee taken
+    pub manager_fee: u64,
+    /// Number of members receiving shares
+    pub members_paid: u32,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when a private syndicate invite is used to join
+#[event]
+pub struct SyndicateInviteUsed {
+    /// Syndicate account
+    pub syndicate: Pubkey,
+    /// New member wallet
+    pub member: Pubkey,
+    /// Hash of the invite code used
+    pub code_hash: [u8; 32],
+    /// Uses left on the invite
+    pub remaining_uses: u8,
+}
+
+/// Emitted when a syndicate's per-member contribution cap changes
+#[event]
+pub struct SyndicateContributionCapSet {
+    /// Syndicate account
+    pub syndicate: Pubkey,
+    /// New cap in USDC lamports (0 = no limit)
+    pub new_cap: u64,
+}
+
+/// Snapshot of the public syndicate registry
+#[event]
+pub struct PublicSyndicatesSnapshot {
+    /// Number of listed syndicates
+    pub count: u32,
+    /// Listed syndicates
+    pub entries: Vec<SyndicateRegistryEntry>,
+}
+
+/// Emitted when a program is registered (or re-registered) in the
+/// protocol registry
+#[event]
+pub struct ProgramRegistered {
+    /// Registered program
+    pub program_id: Pubkey,
+    /// Kind of program
+    pub program_type: ProgramType,
+    /// Registration version
+    pub version: u8,
+}
+
+/// Emitted when a program is deactivated in the protocol registry
+#[event]
+pub struct ProgramDeactivated {
+    /// Deactivated program
+    pub program_id: Pubkey,
+}
+
+/// Result of a protocol registry lookup
 #[event]
-pub struct SyndicateMemberJoined {
-    /// Syndicate account public key
+pub struct ProgramVerified {
+    /// Program looked up
+    pub program_id: Pubkey,
+    /// Whether the program is in the registry
+    pub is_registered: bool,
+    /// Whether the program is active (false when not registered)
+    pub is_active: bool,
+    /// Registration version (0 when not registered)
+    pub version: u8,
+}
+
+/// Emitted when a syndicate buys tickets and membership is frozen
+#[event]
+pub struct SyndicateLocked {
+    /// Syndicate account
     pub syndicate: Pubkey,
-    /// Member wallet address
-    pub member: Pubkey,
-    /// USDC contribution amount
-    pub contribution: u64,
-    /// Share of the syndicate in basis points
-    pub share_bps: u16,
-    /// Current member count after joining
-    pub member_count: u32,
-    /// Timestamp
-    pub timestamp: i64,
+    /// Draw the syndicate is locked for
+    pub draw_id: u64,
 }
 
-/// Emitted when a syndicate prize is distributed
+/// Emitted when `create_syndicate_tickets_batch` creates a syndicate's ticket accounts
 #[event]
-pub struct SyndicatePrizeDistributed {
-    /// Syndicate account public key
+pub struct SyndicateTicketsBatchCreated {
+    /// Syndicate that owns the tickets
     pub syndicate: Pubkey,
-    /// Draw ID
+    /// Draw the tickets are for
     pub draw_id: u64,
-    /// Total prize amount
-    pub total_prize: u64,
-    /// Manager fee taken
-    pub manager_fee: u64,
-    /// Number of members receiving shares
-    pub members_paid: u32,
-    /// Timestamp
-    pub timestamp: i64,
+    /// Number of ticket accounts created
+    pub count: u8,
+    /// First ticket account of the batch
+    pub start_ticket_pda: Pubkey,
+}
+
+/// Emitted when a syndicate's draw lock is released
+#[event]
+pub struct SyndicateUnlocked {
+    /// Syndicate account
+    pub syndicate: Pubkey,
+    /// Draw the syndicate was locked for
+    pub draw_id: u64,
+    /// Wallet whose transaction released the lock
+    pub unlocked_by: Pubkey,
+    /// How the lock was released
+    pub method: SyndicateUnlockMethod,
 }
 
 // ============================================================================
@@ -246,6 +1291,127 @@ pub struct LotteryInitialized {
     pub timestamp: i64,
 }
 
+/// Emitted when a new house fee split takes effect
+#[event]
+pub struct HouseFeeRecipientsUpdated {
+    /// New split (empty = split disabled)
+    pub recipients: Vec<FeeRecipient>,
+}
+
+/// Emitted for each recipient's slice of a routed house fee
+#[event]
+pub struct HouseFeeRouted {
+    /// Recipient wallet
+    pub recipient: Pubkey,
+    /// Slice transferred to the recipient's sub-account
+    pub amount: u64,
+    /// Draw the purchase was made for
+    pub draw_id: u64,
+}
+
+/// Emitted when the draw operator is set or cleared
+#[event]
+pub struct OperatorSet {
+    /// New operator (None = only the authority can run draws)
+    pub new_operator: Option<Pubkey>,
+    /// Granted `OPERATOR_PERMISSION_*` bitmask
+    pub permissions_mask: u64,
+}
+
+/// Emitted when a multisig cosigner change is proposed
+#[event]
+pub struct CosignerChangeProposed {
+    /// Proposed cosigner (None = remove the cosigner)
+    pub proposed: Option<Pubkey>,
+    /// Earliest time the change can be applied
+    pub executable_after: i64,
+}
+
+/// Emitted when a multisig cosigner change takes effect
+#[event]
+pub struct CosignerSet {
+    /// Previous cosigner
+    pub old: Option<Pubkey>,
+    /// New cosigner (None = authority alone)
+    pub new: Option<Pubkey>,
+}
+
+/// Emitted when the fixed prize balance is reconciled against lifetime sales
+#[event]
+pub struct AccountingReconciled {
+    /// Fixed prize balance before reconciliation
+    pub old_fixed_prize_balance: u64,
+    /// Fixed prize balance after reconciliation
+    pub new_fixed_prize_balance: u64,
+    /// New minus old fixed prize balance (offset in the reserve balance)
+    pub discrepancy: i64,
+    /// Unix timestamp of the reconciliation
+    pub reconciled_at: i64,
+}
+
+/// Emitted when a player passes the QuickPick spend gate check
+#[event]
+pub struct SpendGateVerified {
+    /// Player whose spend was checked
+    pub player: Pubkey,
+    /// Player's lifetime main lottery spend (USDC lamports)
+    pub spent: u64,
+    /// Spend gate threshold at the time of the check
+    pub threshold: u64,
+}
+
+/// Emitted when an account is migrated to a newer schema version
+#[event]
+pub struct StateMigrated {
+    /// The migrated account
+    pub account: Pubkey,
+    /// Schema version before migration
+    pub from_version: u8,
+    /// Schema version after migration
+    pub to_version: u8,
+}
+
+/// Emitted when the rolldown tier split is changed
+#[event]
+pub struct RolldownSplitUpdated {
+    /// Previous split as [Match 5, Match 4, Match 3] basis points
+    pub old_bps: [u16; 3],
+    /// New split as [Match 5, Match 4, Match 3] basis points
+    pub new_bps: [u16; 3],
+}
+
+/// Emitted when a ticket purchase takes the jackpot past a milestone that
+/// carries a bonus
+#[event]
+pub struct MilestoneUnlocked {
+    /// Index into `milestone_thresholds`
+    pub index: u8,
+    /// Jackpot balance after the purchase
+    pub jackpot_balance: u64,
+    /// Bonus the first claimer receives
+    pub bonus_prize: u64,
+}
+
+/// Emitted when a milestone bonus is claimed
+#[event]
+pub struct MilestoneBonusClaimed {
+    /// Index into `milestone_thresholds`
+    pub index: u8,
+    /// Wallet that claimed the bonus
+    pub claimer: Pubkey,
+    /// Bonus paid in USDC lamports
+    pub amount: u64,
+}
+
+/// Emitted when the jackpot milestone thresholds are changed
+#[event]
+pub struct JackpotMilestoneThresholdsUpdated {
+    /// Previous thresholds in USDC lamports
+    pub old_thresholds: [u64; 5],
+    /// New thresholds in USDC lamports
+    pub new_thresholds: [u64; 5],
+}
+
 /// Emitted when configuration is updated
 #[event]
 pub struct ConfigUpdated {
@@ -261,6 +1427,55 @@ pub struct ConfigUpdated {
     pub timestamp: i64,
 }
 
+/// One field change in a config proposal, formatted for display
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigChangeSummary {
+    /// `UpdateConfigParams` field name
+    pub field_name: String,
+    /// Current value
+    pub old_value_str: String,
+    /// Proposed value
+    pub new_value_str: String,
+}
+
+/// Emitted when a config change is proposed and its timelock starts
+#[event]
+pub struct ConfigChangeProposed {
+    /// Hash of the proposed params (must match at execution)
+    pub proposal_hash: [u8; 32],
+    /// Fields changed by the proposal
+    pub changes: Vec<ConfigChangeSummary>,
+    /// Earliest time the proposal can be executed
+    pub executable_after: i64,
+    /// Authority who proposed the change
+    pub authority: Pubkey,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when a proposed config change is executed
+#[event]
+pub struct ConfigChangeExecuted {
+    /// Hash of the executed params
+    pub proposal_hash: [u8; 32],
+    /// Fields changed by the proposal
+    pub changes: Vec<ConfigChangeSummary>,
+    /// Authority who executed the change
+    pub authority: Pubkey,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when a pending config proposal is discarded because it was not
+/// executed within CONFIG_PROPOSAL_EXECUTION_WINDOW
+#[event]
+pub struct ConfigChangeExpired {
+    /// Hash of the expired proposal
+    pub proposal_hash: [u8; 32],
+    /// When the proposal expired
+    pub expired_at: i64,
+}
+
 /// Emitted when the lottery is paused
 #[event]
 pub struct EmergencyPause {
@@ -302,6 +1517,55 @@ pub struct UserStatsUpdated {
     pub timestamp: i64,
 }
 
+/// Emitted when a player's win history changes
+#[event]
+pub struct PlayerStatsUpdated {
+    /// Player wallet address
+    pub player: Pubkey,
+    /// Lifetime USDC prizes won
+    pub total_prizes_won: u64,
+    /// Lifetime USDC spent
+    pub total_spent: u64,
+    /// Distinct draws entered
+    pub total_draws_entered: u64,
+    /// Largest single USDC prize
+    pub largest_prize_won: u64,
+    /// Best match count achieved
+    pub best_match_count: u8,
+    /// Consecutive draws played
+    pub consecutive_draws_played: u32,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Snapshot of a player's lifetime value to the protocol
+#[event]
+pub struct PlayerLifetimeValueSnapshot {
+    /// Player wallet address
+    pub player: Pubkey,
+    /// Lifetime USDC spent
+    pub total_spent: u64,
+    /// Lifetime USDC prizes won
+    pub total_won: u64,
+    /// Lifetime house fees paid
+    pub total_fees_paid: u64,
+    /// total_won minus total_spent
+    pub net_roi: i64,
+    /// Whole days since the player's first interaction (0 if unknown)
+    pub tenure_days: u64,
+}
+
+/// Emitted when a purchase brings a player's streak to a discount milestone
+#[event]
+pub struct StreakMilestoneReached {
+    /// Player wallet address
+    pub player: Pubkey,
+    /// Consecutive draws played
+    pub streak_length: u32,
+    /// Discount applied to one ticket price, in basis points
+    pub discount_bps: u16,
+}
+
 /// Emitted when a user achieves a new streak record
 #[event]
 pub struct NewStreakRecord {
@@ -315,6 +1579,28 @@ pub struct NewStreakRecord {
     pub timestamp: i64,
 }
 
+/// Emitted when a player registers their referrer
+#[event]
+pub struct ReferralRegistered {
+    /// Referred player wallet
+    pub player: Pubkey,
+    /// Referrer wallet
+    pub referrer: Pubkey,
+    /// Registration timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when a referral credit is redeemed for a ticket
+#[event]
+pub struct ReferralCreditRedeemed {
+    /// Player wallet address
+    pub player: Pubkey,
+    /// Draw the ticket was issued for
+    pub draw_id: u64,
+    /// Referral credits left after redemption
+    pub credits_remaining: u32,
+}
+
 // ============================================================================
 // QUICK PICK EXPRESS EVENTS
 // ============================================================================
@@ -402,6 +1688,48 @@ pub struct SyndicateWarsFinalized {
     pub timestamp: i64,
 }
 
+/// Emitted when a syndicate's draw score is computed from its ticket accounts
+#[event]
+pub struct WarsScoreComputedOnChain {
+    /// Syndicate scored
+    pub syndicate: Pubkey,
+    /// Draw scored
+    pub draw_id: u64,
+    /// Score of the draw's tickets
+    pub score: u64,
+}
+
+/// Emitted when a Syndicate Wars bracket is seeded
+#[event]
+pub struct SyndicateWarsBracketStarted {
+    /// Competition month
+    pub month: u64,
+    /// Knockout rounds, including the finals
+    pub bracket_rounds: u8,
+    /// First round matchups
+    pub matchups: Vec<WarsMatchup>,
+}
+
+/// Emitted when a Syndicate Wars bracket round is decided
+#[event]
+pub struct SyndicateWarsBracketAdvanced {
+    /// Round now being played (0-based)
+    pub round: u8,
+    /// Winners of the previous round
+    pub winners: Vec<Pubkey>,
+    /// Matchups of the new round, seeded by score
+    pub next_matchups: Vec<WarsMatchup>,
+}
+
+/// Emitted when a Syndicate Wars bracket reaches its final round
+#[event]
+pub struct SyndicateWarsFinalsReached {
+    /// The two finalists
+    pub finalists: [Pubkey; 2],
+    /// Finals prize split between the finalists (USDC lamports)
+    pub prize_pool: u64,
+}
+
 /// Emitted when Syndicate Wars competition concludes with prize distribution
 #[event]
 pub struct SyndicateWarsConcluded {
@@ -434,6 +1762,28 @@ pub struct JackpotSeeded {
     pub timestamp: i64,
 }
 
+/// Emitted when the jackpot is topped back up to the seed amount from the reserve
+#[event]
+pub struct JackpotReseeded {
+    /// Amount moved from the reserve to the jackpot
+    pub amount: u64,
+    /// Jackpot balance after reseeding
+    pub new_jackpot_balance: u64,
+    /// Reserve balance after reseeding
+    pub new_reserve_balance: u64,
+    /// Signer who triggered the reseed
+    pub triggered_by: Pubkey,
+}
+
+/// Emitted when a reseed is attempted but the reserve cannot cover the deficit
+#[event]
+pub struct JackpotReseedFailed {
+    /// Deficit between the seed amount and the jackpot
+    pub required: u64,
+    /// Reserve balance available
+    pub available: u64,
+}
+
 /// Emitted when house fees are withdrawn
 #[event]
 pub struct HouseFeesWithdrawn {
@@ -447,6 +1797,19 @@ pub struct HouseFeesWithdrawn {
     pub timestamp: i64,
 }
 
+/// Emitted when house fees are split between the operator and the authority
+#[event]
+pub struct OperatorProfitsDistributed {
+    /// Amount paid to the operator
+    pub operator_amount: u64,
+    /// Amount paid to the authority
+    pub authority_amount: u64,
+    /// Total taken from the house fee account
+    pub total_distributed: u64,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
 /// Emitted when insurance pool is funded
 #[event]
 pub struct InsurancePoolFunded {
@@ -460,6 +1823,105 @@ pub struct InsurancePoolFunded {
     pub timestamp: i64,
 }
 
+/// Emitted when a transfer from insurance tier 2 to tier 1 is requested
+#[event]
+pub struct Tier2WithdrawalRequested {
+    /// Amount to move
+    pub amount: u64,
+    /// Earliest time the transfer can be executed
+    pub executable_after: i64,
+}
+
+/// Emitted when insurance tier 2 funds are moved to tier 1
+#[event]
+pub struct Tier2WithdrawalExecuted {
+    /// Amount moved
+    pub amount: u64,
+    /// Tier 1 balance after the transfer
+    pub new_tier1_balance: u64,
+    /// Tier 2 balance after the transfer
+    pub new_tier2_balance: u64,
+}
+
+/// Logged when a claim is rejected because the fixed prize, reserve and
+/// jackpot balances together cannot cover the prize (the claim fails, so
+/// this appears only in the failed transaction's logs)
+#[event]
+pub struct PrizePoolInsolvent {
+    /// Draw the prize belongs to
+    pub draw_id: u64,
+    /// Prize amount being claimed
+    pub prize: u64,
+    /// Part of the prize the balances cannot cover
+    pub deficit: u64,
+}
+
+/// Emitted when finalize_draw leaves the reserve below its configured minimum
+#[event]
+pub struct ReserveMinimumBreach {
+    /// Reserve balance after reseeding
+    pub balance: u64,
+    /// Configured reserve minimum
+    pub minimum: u64,
+}
+
+/// Emitted when finalize_draw tops the jackpot up to its guaranteed minimum
+#[event]
+pub struct JackpotGuaranteeTriggered {
+    /// Amount moved from the guarantee reserve to the jackpot
+    pub shortfall_covered: u64,
+    /// Jackpot balance after the top-up
+    pub new_jackpot_balance: u64,
+}
+
+/// Emitted when the guarantee reserve cannot cover the jackpot shortfall
+#[event]
+pub struct JackpotGuaranteeFailed {
+    /// Shortfall below the guaranteed jackpot
+    pub required: u64,
+    /// Guarantee reserve balance available
+    pub available: u64,
+}
+
+/// Emitted by compute_reserve_health with rolling reserve metrics
+#[event]
+pub struct ReserveHealthReport {
+    /// Draws of average prizes the reserve covers (u64::MAX when no prizes)
+    pub coverage_ratio_draws: u64,
+    /// Reserve change across the history window (positive = growing)
+    pub trend_bps: i64,
+    /// Suggested share of ticket revenue to send to the reserve
+    pub recommended_reserve_allocation_bps: u16,
+    /// Average prizes committed per draw (USDC lamports)
+    pub avg_prizes_per_draw: u64,
+    /// Average ticket revenue per draw (USDC lamports)
+    pub avg_revenue_per_draw: u64,
+    /// Number of snapshots the averages cover
+    pub draws_sampled: u8,
+}
+
+/// Emitted when reserve coverage drops below `reserve_minimum_coverage_draws`
+#[event]
+pub struct ReserveCoverageAlert {
+    /// Draws of average prizes the reserve covers
+    pub coverage_ratio_draws: u64,
+    /// Configured minimum coverage in draws
+    pub minimum_coverage_draws: u64,
+    /// Current reserve balance
+    pub reserve_balance: u64,
+}
+
+/// Emitted when `rebalance_reserve_to_jackpot` moves reserve excess into the jackpot
+#[event]
+pub struct ReserveToJackpotRebalanced {
+    /// Amount moved from the reserve to the jackpot
+    pub amount: u64,
+    /// Jackpot balance after the move
+    pub new_jackpot: u64,
+    /// Reserve balance after the move
+    pub new_reserve: u64,
+}
+
 // ============================================================================
 // DYNAMIC FEE & CAP EVENTS
 // ============================================================================
@@ -481,6 +1943,57 @@ pub struct DynamicFeeTierChanged {
     pub timestamp: i64,
 }
 
+/// Emitted the first time in a draw that the jackpot reaches a milestone
+#[event]
+pub struct JackpotMilestoneReached {
+    /// Index into `jackpot_milestone_thresholds`
+    pub threshold_index: u8,
+    /// Jackpot balance after the purchase
+    pub jackpot_balance: u64,
+    /// Current draw ID
+    pub draw_id: u64,
+    /// Tickets sold for the draw so far
+    pub tickets_sold_this_draw: u64,
+}
+
+/// Emitted when a ticket purchase pushes the jackpot past the hard cap and
+/// the excess is moved to the reserve
+#[event]
+pub struct JackpotOverflowRouted {
+    /// Amount moved from the jackpot to the reserve
+    pub overflow_amount: u64,
+    /// Reserve balance after the transfer
+    pub new_reserve_balance: u64,
+}
+
+/// Emitted when a ticket purchase takes the jackpot to a new all-time high
+#[event]
+pub struct JackpotATHReached {
+    /// New all-time high jackpot balance
+    pub new_ath: u64,
+    /// Previous all-time high
+    pub previous_ath: u64,
+    /// Draw the high was set in
+    pub draw_id: u64,
+    /// When the high was set
+    pub timestamp: i64,
+}
+
+/// Emitted by `get_jackpot_ath` with the jackpot high watermarks
+#[event]
+pub struct JackpotATHSnapshot {
+    /// All-time high jackpot balance
+    pub all_time_high: u64,
+    /// Draw the all-time high was set in
+    pub ath_draw_id: u64,
+    /// When the all-time high was set
+    pub ath_timestamp: i64,
+    /// Highest jackpot since the last jackpot win or rolldown
+    pub season_high: u64,
+    /// Current jackpot balance
+    pub jackpot_balance: u64,
+}
+
 /// Emitted when soft cap is reached and rolldown becomes possible
 #[event]
 pub struct SoftCapReached {
@@ -496,6 +2009,19 @@ pub struct SoftCapReached {
     pub timestamp: i64,
 }
 
+/// Emitted when the VRF sample is rolled against the soft cap rolldown probability
+#[event]
+pub struct RolldownProbabilityRolled {
+    /// Draw ID
+    pub draw_id: u64,
+    /// Rolldown probability in basis points (0-10000)
+    pub probability_bps: u16,
+    /// VRF sample (0-9999); rolldown triggers when sample < probability_bps
+    pub sample: u16,
+    /// Whether the rolldown was triggered
+    pub triggered: bool,
+}
+
 /// Emitted when hard cap is reached and rolldown is forced
 #[event]
 pub struct HardCapReached {
@@ -545,6 +2071,18 @@ pub struct EmergencyFundTransferred {
     pub timestamp: i64,
 }
 
+/// Logged when a draw commit finds the prize pool short of jackpot + reserve
+/// (the commit fails, so this appears only in the failed transaction's logs)
+#[event]
+pub struct SolvencyCheckFailedAtCommit {
+    /// USDC actually held by the prize pool token account
+    pub prize_pool_actual: u64,
+    /// Jackpot + reserve minus SOLVENCY_TOLERANCE
+    pub expected: u64,
+    /// Shortfall of the prize pool
+    pub deficit: u64,
+}
+
 /// Emitted when prize pool solvency check is performed
 #[event]
 pub struct SolvencyCheckPerformed {
@@ -568,6 +2106,21 @@ pub struct SolvencyCheckPerformed {
     pub timestamp: i64,
 }
 
+/// Emitted when a fixed tier's total payout hits `max_fixed_payout_per_tier`
+#[event]
+pub struct FixedPrizeCapApplied {
+    /// Draw ID
+    pub draw_id: u64,
+    /// Match tier (2-5)
+    pub tier: u8,
+    /// Tier total before the cap
+    pub original_total: u64,
+    /// Tier total after the cap
+    pub capped_total: u64,
+    /// Prize per winner after the cap
+    pub per_winner_prize: u64,
+}
+
 // ============================================================================
 // DRAW RECOVERY EVENTS
 // ============================================================================
@@ -577,14 +2130,42 @@ pub struct SolvencyCheckPerformed {
 pub struct DrawCancelled {
     /// Draw ID that was cancelled
     pub draw_id: u64,
-    /// Number of tickets affected (remain valid for rescheduled draw)
+    /// Number of tickets affected (carried over, or refundable with RefundTickets)
     pub tickets_affected: u64,
+    /// How the draw's tickets are handled
+    pub mode: CancelDrawMode,
     /// Timestamp when cancelled
     pub timestamp: i64,
     /// Reason for cancellation
     pub reason: String,
 }
 
+/// Emitted when a ticket of a refund-mode cancelled draw is refunded
+#[event]
+pub struct CancelledTicketRefunded {
+    /// Ticket owner receiving the refund
+    pub player: Pubkey,
+    /// Refunded ticket account
+    pub ticket_id: Pubkey,
+    /// Cancelled draw the ticket was for
+    pub draw_id: u64,
+    /// Amount refunded in USDC lamports
+    pub amount: u64,
+}
+
+/// Emitted when a draw is postponed because the minimum ticket count was not met
+#[event]
+pub struct DrawPostponed {
+    /// Draw ID that was postponed (unchanged - tickets remain valid)
+    pub draw_id: u64,
+    /// Previous scheduled draw timestamp
+    pub old_timestamp: i64,
+    /// New scheduled draw timestamp
+    pub new_timestamp: i64,
+    /// Reason for postponement
+    pub reason: String,
+}
+
 /// Emitted when a draw is force finalized (emergency)
 #[event]
 pub struct DrawForceFinalized {
@@ -592,7 +2173,7 @@ pub struct DrawForceFinalized {
     pub draw_id: u64,
     /// Number of tickets affected (will NOT receive prizes)
     pub tickets_affected: u64,
-    /// Authority who force finalized
+    /// Signer who force finalized (the authority, or anyone once finalize is overdue)
     pub authority: Pubkey,
     /// Reason for force finalization
     pub reason: String,
@@ -600,6 +2181,74 @@ pub struct DrawForceFinalized {
     pub timestamp: i64,
 }
 
+/// Emitted when a non-authority force finalizes a draw left unfinalized
+/// past the finalize timeout and grace period
+#[event]
+pub struct DrawAutoForceFinalized {
+    /// Draw ID that was force finalized
+    pub draw_id: u64,
+    /// Signer who triggered the force finalization
+    pub caller: Pubkey,
+    /// Seconds elapsed since `execute_draw`
+    pub elapsed_seconds: i64,
+    /// Bounty paid to the caller in lamports (0 if skipped)
+    pub reward_paid: u64,
+}
+
+/// Emitted when a keeper commits randomness for an overdue draw
+#[event]
+pub struct DrawTriggeredByKeeper {
+    /// Draw ID that was triggered
+    pub draw_id: u64,
+    /// Keeper who triggered the draw
+    pub keeper: Pubkey,
+    /// Reward paid to the keeper in lamports
+    pub reward_lamports: u64,
+    /// Seconds between `next_draw_timestamp` and the trigger
+    pub trigger_delay_seconds: i64,
+}
+
+/// Emitted when a keeper commits randomness via `keeper_commit_randomness`
+#[event]
+pub struct KeeperDrawCommit {
+    /// Draw ID that was committed
+    pub draw_id: u64,
+    /// Keeper who committed the randomness
+    pub keeper: Pubkey,
+    /// Seconds between `next_draw_timestamp` and the commit
+    pub delay_seconds: i64,
+}
+
+/// Emitted when the authority changes the keeper grace period
+#[event]
+pub struct KeeperGracePeriodUpdated {
+    /// Previous grace period in seconds
+    pub old_seconds: i64,
+    /// New grace period in seconds
+    pub new_seconds: i64,
+}
+
+/// Emitted when the authority tops up the keeper reward SOL balance
+#[event]
+pub struct KeeperReserveFunded {
+    /// Lamports added
+    pub amount: u64,
+    /// Lamports available for rewards after the top-up
+    pub available_lamports: u64,
+}
+
+/// Emitted on the first purchase of a draw that diverts part of the house
+/// fee to the insurance pool
+#[event]
+pub struct InsuranceAutoReplenished {
+    /// Draw the purchase was for
+    pub draw_id: u64,
+    /// House fee amount sent to the insurance pool instead
+    pub amount_rerouted: u64,
+    /// Insurance balance after the reroute
+    pub new_insurance_balance: u64,
+}
+
 /// SECURITY FIX (Audit Issue #5): Emitted when expired/unclaimed prize funds
 /// are reclaimed from a past draw back into the reserve pool.
 /// Without periodic reclamation, `total_prizes_committed` accumulates "zombie"
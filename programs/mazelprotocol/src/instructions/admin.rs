@@ -10,7 +10,7 @@
 //! - withdraw_house_fees: Withdraw accumulated house fees
 //! - transfer_authority: Two-step authority transfer (propose)
 //! - accept_authority: Two-step authority transfer (accept)
-//! - cancel_draw: Recovery mechanism for stuck draws
+//! - cancel_draw: Recovery mechanism for stuck draws (preserve or refund tickets)
 //! - check_solvency: On-chain solvency verification instruction
 
 use anchor_lang::prelude::*;
@@ -19,11 +19,21 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::constants::*;
 use crate::errors::LottoError;
 use crate::events::{
-    ConfigUpdated, DrawCancelled, DrawForceFinalized, EmergencyFundTransferred, EmergencyPause,
-    EmergencyUnpause, ExpiredPrizesReclaimed, HouseFeesWithdrawn, InsurancePoolFunded,
-    SolvencyCheckPerformed,
+    AccountingReconciled, ConfigChangeExecuted, ConfigChangeExpired, ConfigChangeProposed,
+    ConfigChangeSummary, ConfigUpdated, CosignerChangeProposed, CosignerSet,
+    DailyWithdrawalCapApproaching, DrawAutoForceFinalized, DrawCancelled, DrawForceFinalized,
+    DrawPostponed, EmergencyFundTransferred, EmergencyPause, EmergencyUnpause,
+    ExpiredPrizesReclaimed, HouseFeesWithdrawn, InsurancePoolFunded,
+    JackpotMilestoneThresholdsUpdated, OperatorSet, RolldownSplitUpdated, SolvencyCheckPerformed,
+};
+use crate::instructions::draw_refund::total_refundable_for;
+use crate::instructions::house_fee::house_fee_sub_account_address;
+use crate::instructions::keeper::lamports_above_rent;
+use crate::instructions::state_diff::{emit_state_diff, LotteryStateSnapshot};
+use crate::state::{
+    CancelDrawMode, DrawResult, HouseFeeRecipients, LotteryState, PriceTier,
+    RecurringTicketSchedule, SchemaVersioned, StreakMilestone, TicketRefundRecord,
 };
-use crate::state::{DrawResult, LotteryState};
 
 // ============================================================================
 // PAUSE INSTRUCTION
@@ -42,7 +52,8 @@ pub struct Pause<'info> {
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
         constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
-        constraint = !lottery_state.is_paused @ LottoError::Paused
+        constraint = !lottery_state.is_paused @ LottoError::Paused,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 }
@@ -66,8 +77,10 @@ pub struct Pause<'info> {
 pub fn handler_pause(ctx: Context<Pause>, reason: String) -> Result<()> {
     let clock = Clock::get()?;
     let lottery_state = &mut ctx.accounts.lottery_state;
+    let state_before = LotteryStateSnapshot::capture(lottery_state);
 
     lottery_state.is_paused = true;
+    emit_state_diff(&state_before, lottery_state);
 
     emit!(EmergencyPause {
         authority: ctx.accounts.authority.key(),
@@ -102,7 +115,10 @@ pub struct Unpause<'info> {
         constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
         constraint = lottery_state.is_paused @ LottoError::InvalidDrawState,
         // Cannot unpause if not funded
-        constraint = lottery_state.is_funded @ LottoError::LotteryNotInitialized
+        constraint = lottery_state.is_funded @ LottoError::LotteryNotInitialized,
+        // Cannot unpause while the reserve is below its configured floor
+        constraint = !lottery_state.is_reserve_below_minimum() @ LottoError::ReserveBelowMinimum,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 }
@@ -111,7 +127,8 @@ pub struct Unpause<'info> {
 ///
 /// This instruction resumes all lottery operations after a pause.
 /// Only the authority can unpause the lottery.
-/// The lottery must be funded before it can be unpaused.
+/// The lottery must be funded, and the reserve at or above `reserve_minimum`,
+/// before it can be unpaused.
 ///
 /// # Arguments
 /// * `ctx` - The context containing required accounts
@@ -121,8 +138,10 @@ pub struct Unpause<'info> {
 pub fn handler_unpause(ctx: Context<Unpause>) -> Result<()> {
     let clock = Clock::get()?;
     let lottery_state = &mut ctx.accounts.lottery_state;
+    let state_before = LotteryStateSnapshot::capture(lottery_state);
 
     lottery_state.is_paused = false;
+    emit_state_diff(&state_before, lottery_state);
 
     emit!(EmergencyUnpause {
         authority: ctx.accounts.authority.key(),
@@ -150,7 +169,7 @@ pub fn handler_unpause(ctx: Context<Unpause>) -> Result<()> {
 // detect and respond to a malicious proposal before it takes effect.
 
 /// Parameters for updating configuration
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct UpdateConfigParams {
     /// New ticket price (None to keep current)
     pub ticket_price: Option<u64>,
@@ -168,6 +187,100 @@ pub struct UpdateConfigParams {
     pub switchboard_queue: Option<Pubkey>,
     /// New draw interval (None to keep current)
     pub draw_interval: Option<i64>,
+    /// Allow non-owners to close expired tickets (None to keep current)
+    pub allow_third_party_closure: Option<bool>,
+    /// New minimum tickets per draw (None to keep current)
+    pub min_tickets_per_draw: Option<u64>,
+    /// New Match 5 rolldown share in basis points (None to keep current)
+    pub rolldown_match_5_bps: Option<u16>,
+    /// New Match 4 rolldown share in basis points (None to keep current)
+    pub rolldown_match_4_bps: Option<u16>,
+    /// New Match 3 rolldown share in basis points (None to keep current)
+    pub rolldown_match_3_bps: Option<u16>,
+    /// New jackpot escrow threshold, 0 disables escrow (None to keep current)
+    pub escrow_threshold_usdc: Option<u64>,
+    /// New escrow release delay in seconds (None to keep current)
+    pub escrow_release_delay_seconds: Option<i64>,
+    /// New reserve floor that triggers auto-pause, 0 disables (None to keep current)
+    pub reserve_minimum: Option<u64>,
+    /// Enforce per-wallet daily ticket limits (None to keep current)
+    pub daily_limit_enabled: Option<bool>,
+    /// Reject low-entropy number picks (None to keep current)
+    pub entropy_validation_enabled: Option<bool>,
+    /// New ticket sale cutoff before each draw in seconds (None to keep current)
+    pub ticket_sale_cutoff_seconds: Option<i64>,
+    /// New keeper reward in lamports, 0 disables (None to keep current)
+    pub keeper_reward_lamports: Option<u64>,
+    /// New insurance balance floor for house fee rerouting, 0 disables (None to keep current)
+    pub insurance_minimum: Option<u64>,
+    /// New share of the house fee rerouted to insurance in basis points (None to keep current)
+    pub insurance_replenishment_bps: Option<u16>,
+    /// New jackpot milestone thresholds, strictly ascending (None to keep current)
+    pub jackpot_milestone_thresholds: Option<[u64; 5]>,
+    /// Allow execute_draw to use slot-hash fallback randomness when the VRF
+    /// result is stale (None to keep current)
+    pub allow_fallback_randomness: Option<bool>,
+    /// Draw randomness from the Switchboard pull feed (None to keep current)
+    pub use_pull_oracle: Option<bool>,
+    /// New Switchboard pull feed (None to keep current)
+    pub switchboard_pull_feed: Option<Pubkey>,
+    /// Price tickets from the jackpot-level price tiers (None to keep current)
+    pub dynamic_price_enabled: Option<bool>,
+    /// New (threshold, price) tiers, ascending by threshold (None to keep current)
+    pub price_tiers: Option<[PriceTier; PRICE_TIER_COUNT]>,
+    /// New reward per expired ticket account cleaned up, 0 disables (None to keep current)
+    pub cleanup_reward_lamports: Option<u64>,
+    /// New lifetime spend required to pass the QuickPick spend gate (None to keep current)
+    pub quickpick_spend_gate_threshold: Option<u64>,
+    /// New per-draw Match 2-5 total payout caps, 0 = uncapped (None to keep current)
+    pub max_fixed_payout_per_tier: Option<[u64; FIXED_PAYOUT_CAP_TIER_COUNT]>,
+    /// New reserve coverage (in draws) below which `compute_reserve_health`
+    /// alerts, 0 disables (None to keep current)
+    pub reserve_minimum_coverage_draws: Option<u64>,
+    /// New per-wallet hourly ticket limit for `buy_ticket` and `buy_bulk`,
+    /// 0 = unlimited (None to keep current)
+    pub max_tickets_per_wallet_per_hour: Option<u32>,
+    /// New number of draws after a forced hard-cap rolldown during which
+    /// rolldowns stay disabled, 0 = no cooldown (None to keep current)
+    pub rolldown_cooldown_draws: Option<u8>,
+    /// New purchase compliance mode: 0 = none, 1 = KYC required (None to keep current)
+    pub compliance_mode: Option<u8>,
+    /// New wallet allowed to approve and revoke KYC records (None to keep current)
+    pub kyc_authority: Option<Pubkey>,
+    /// New prize amount above which claims need an identity proof, 0 disables
+    /// (None to keep current)
+    pub identity_verification_threshold: Option<u64>,
+    /// New oracle allowed to issue identity proofs (None to keep current)
+    pub identity_oracle: Option<Pubkey>,
+    /// New draw time of day in seconds since midnight UTC (None to keep current)
+    pub anchor_draw_time: Option<i64>,
+    /// New milestone bonus thresholds, strictly ascending (None to keep current)
+    pub milestone_thresholds: Option<[u64; 5]>,
+    /// New milestone bonuses, 0 = no bonus (None to keep current)
+    pub milestone_prizes: Option<[u64; 5]>,
+    /// New bounty in lamports for permissionless force finalization, 0
+    /// disables (None to keep current)
+    pub force_finalize_reward_lamports: Option<u64>,
+    /// New per-draw ticket limit per wallet, 0 = unlimited (None to keep current)
+    pub max_tickets_per_wallet_per_draw: Option<u64>,
+    /// New daily house fee withdrawal cap in basis points of the account
+    /// balance, 0 = uncapped (None to keep current)
+    pub daily_withdrawal_cap_bps: Option<u16>,
+    /// New operator share of distributed house fees in basis points
+    /// (None to keep current)
+    pub operator_profit_share_bps: Option<u16>,
+    /// New guaranteed minimum jackpot in USDC lamports, topped up from the
+    /// guarantee reserve, 0 disables (None to keep current)
+    pub jackpot_guarantee_amount: Option<u64>,
+    /// New (streak_length, discount_bps) participation streak milestones
+    /// (None to keep current)
+    pub streak_milestone_bps: Option<[StreakMilestone; STREAK_MILESTONE_COUNT]>,
+    /// New reserve target for `rebalance_reserve_to_jackpot`, as a multiple
+    /// of the rolling average prizes per draw in basis points (None to keep current)
+    pub reserve_target_multiplier_bps: Option<u16>,
+    /// New number of consecutive oracle health check failures that pause
+    /// the lottery (None to keep current)
+    pub max_oracle_failures: Option<u8>,
 }
 
 impl UpdateConfigParams {
@@ -249,327 +362,1980 @@ impl UpdateConfigParams {
                 hasher.update([0u8]);
             }
         }
-        let result = hasher.finalize();
-        let mut hash = [0u8; 32];
-        hash.copy_from_slice(result.as_slice());
-        hash
-    }
-}
-
-/// Accounts required for proposing/updating/executing configuration
-#[derive(Accounts)]
-pub struct UpdateConfig<'info> {
-    /// The authority updating the configuration
-    #[account(mut)]
-    pub authority: Signer<'info>,
-
-    /// The main lottery state account
-    #[account(
-        mut,
-        seeds = [LOTTERY_SEED],
-        bump = lottery_state.bump,
-        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
-        // Cannot update config during an active draw
-        constraint = !lottery_state.is_draw_in_progress @ LottoError::DrawInProgress
-    )]
-    pub lottery_state: Account<'info, LotteryState>,
-}
-
-/// Propose configuration changes (Phase 1 of timelock)
-///
-/// This instruction starts the timelock by storing a hash of the proposed
-/// changes and setting `config_timelock_end` to now + CONFIG_TIMELOCK_DELAY.
-/// The actual changes are NOT applied yet — they must be executed via
-/// `execute_config` after the timelock expires.
-///
-/// # Security
-/// - Anyone monitoring the chain can see this proposal event and the hash
-/// - The 24-hour delay gives the community time to detect malicious proposals
-/// - The authority can cancel a proposal via `cancel_config_proposal`
-///
-/// # Validation Rules (pre-validated before accepting proposal)
-/// - ticket_price: Must be > 0
-/// - house_fee_bps: Must be <= 5000 (50%)
-/// - draw_interval: minimum 1 hour, maximum 7 days
-///
-/// # Arguments
-/// * `ctx` - The context containing required accounts
-/// * `params` - The configuration parameters being proposed
-///
-/// # Returns
-/// * `Result<()>` - Success or error
-pub fn handler_propose_config(
-    ctx: Context<UpdateConfig>,
-    params: UpdateConfigParams,
-) -> Result<()> {
-    let clock = Clock::get()?;
-    let lottery_state = &mut ctx.accounts.lottery_state;
-
-    // Reject if there's already a pending proposal
-    require!(
-        lottery_state.config_timelock_end == 0,
-        LottoError::InvalidDrawState
-    );
-
-    // Pre-validate params so we catch errors early (before waiting 24h)
-    if let Some(ticket_price) = params.ticket_price {
-        require!(ticket_price > 0, LottoError::InvalidTicketPrice);
-    }
-    if let Some(house_fee_bps) = params.house_fee_bps {
-        require!(house_fee_bps <= 5000, LottoError::InvalidHouseFee);
-    }
-    if let Some(draw_interval) = params.draw_interval {
-        require!(
-            draw_interval >= 3600 && draw_interval <= 604800,
-            LottoError::InvalidDrawInterval
-        );
-    }
-
-    // Simulate the final state to validate relationships
-    let simulated_soft_cap = params.soft_cap.unwrap_or(lottery_state.soft_cap);
-    let simulated_hard_cap = params.hard_cap.unwrap_or(lottery_state.hard_cap);
-    let simulated_seed_amount = params.seed_amount.unwrap_or(lottery_state.seed_amount);
-    let simulated_jackpot_cap = params.jackpot_cap.unwrap_or(lottery_state.jackpot_cap);
-
-    require!(simulated_soft_cap > 0, LottoError::InvalidCapConfig);
-    require!(simulated_hard_cap > 0, LottoError::InvalidCapConfig);
-    require!(
-        simulated_soft_cap < simulated_hard_cap,
-        LottoError::InvalidCapConfig
-    );
-    require!(simulated_seed_amount > 0, LottoError::InvalidSeedAmount);
-    require!(
-        simulated_seed_amount < simulated_soft_cap,
-        LottoError::InvalidSeedAmount
-    );
-    require!(simulated_jackpot_cap > 0, LottoError::InvalidJackpotCap);
-    require!(
-        simulated_jackpot_cap <= simulated_hard_cap,
-        LottoError::InvalidJackpotCap
-    );
-
-    // Store the proposal hash and set the timelock
-    let config_hash = params.compute_hash();
-    lottery_state.pending_config_hash = config_hash;
-    lottery_state.config_timelock_end = clock
-        .unix_timestamp
-        .checked_add(CONFIG_TIMELOCK_DELAY)
-        .ok_or(LottoError::Overflow)?;
-
-    emit!(ConfigUpdated {
-        parameter: "config_proposed".to_string(),
-        old_value: 0,
-        new_value: lottery_state.config_timelock_end as u64,
-        authority: ctx.accounts.authority.key(),
-        timestamp: clock.unix_timestamp,
-    });
-
-    msg!("⏳ Configuration change PROPOSED (timelock started)");
-    msg!(
-        "  Config hash: {:?}",
-        &lottery_state.pending_config_hash[..8]
-    );
-    msg!(
-        "  Executable after: {} (unix timestamp)",
-        lottery_state.config_timelock_end
-    );
-    msg!(
-        "  Delay: {} seconds ({} hours)",
-        CONFIG_TIMELOCK_DELAY,
-        CONFIG_TIMELOCK_DELAY / 3600
-    );
-    msg!("  Call execute_config with the same params after the timelock expires.");
-
-    Ok(())
-}
-
-/// Cancel a pending configuration proposal
-///
-/// Clears the pending config hash and timelock, preventing execution.
-///
-/// # Arguments
-/// * `ctx` - The context containing required accounts
-///
-/// # Returns
-/// * `Result<()>` - Success or error
-pub fn handler_cancel_config_proposal(ctx: Context<UpdateConfig>) -> Result<()> {
-    let clock = Clock::get()?;
-    let lottery_state = &mut ctx.accounts.lottery_state;
-
-    require!(
-        lottery_state.config_timelock_end != 0,
-        LottoError::InvalidDrawState
-    );
-
-    lottery_state.pending_config_hash = [0u8; 32];
-    lottery_state.config_timelock_end = 0;
-
-    emit!(ConfigUpdated {
-        parameter: "config_proposal_cancelled".to_string(),
-        old_value: 0,
-        new_value: 0,
-        authority: ctx.accounts.authority.key(),
-        timestamp: clock.unix_timestamp,
-    });
-
-    msg!("❌ Configuration proposal CANCELLED.");
-
-    Ok(())
-}
-
-/// Execute a proposed configuration change (Phase 2 of timelock)
-///
-/// Applies the previously proposed configuration changes after the timelock
-/// has expired. The params must exactly match the proposed ones (verified via hash).
-///
-/// # Security
-/// - Timelock must have expired (current time >= config_timelock_end)
-/// - Params must hash to the same value as the proposal (prevents bait-and-switch)
-/// - All relationship validations are re-checked
-///
-/// # Arguments
-/// * `ctx` - The context containing required accounts
-/// * `params` - The configuration parameters (must match proposal)
-///
-/// # Returns
-/// * `Result<()>` - Success or error
-pub fn handler_execute_config(
-    ctx: Context<UpdateConfig>,
-    params: UpdateConfigParams,
-) -> Result<()> {
-    let clock = Clock::get()?;
-    let lottery_state = &mut ctx.accounts.lottery_state;
-
-    // Verify there is a pending proposal
-    require!(
-        lottery_state.config_timelock_end != 0,
-        LottoError::InvalidDrawState
-    );
-
-    // Verify the timelock has expired
-    require!(
-        clock.unix_timestamp >= lottery_state.config_timelock_end,
-        LottoError::InvalidTimestamp
-    );
-
-    // Verify the params hash matches the proposal (prevents bait-and-switch)
-    let config_hash = params.compute_hash();
-    require!(
-        config_hash == lottery_state.pending_config_hash,
-        LottoError::ConfigValidationFailed
-    );
-
-    // Clear the timelock state
-    lottery_state.pending_config_hash = [0u8; 32];
-    lottery_state.config_timelock_end = 0;
-
-    // Now apply the configuration changes (same logic as the old handler_update_config)
-    if let Some(ticket_price) = params.ticket_price {
-        require!(ticket_price > 0, LottoError::InvalidTicketPrice);
-
+        match self.allow_third_party_closure {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update([v as u8]);
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.min_tickets_per_draw {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.rolldown_match_5_bps {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.rolldown_match_4_bps {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.rolldown_match_3_bps {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.escrow_threshold_usdc {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.escrow_release_delay_seconds {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.reserve_minimum {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.daily_limit_enabled {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update([v as u8]);
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.entropy_validation_enabled {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update([v as u8]);
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.ticket_sale_cutoff_seconds {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.keeper_reward_lamports {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.insurance_minimum {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.insurance_replenishment_bps {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.jackpot_milestone_thresholds {
+            Some(thresholds) => {
+                hasher.update([1u8]);
+                for v in thresholds {
+                    hasher.update(v.to_le_bytes());
+                }
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.allow_fallback_randomness {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update([v as u8]);
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.use_pull_oracle {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update([v as u8]);
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.switchboard_pull_feed {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.dynamic_price_enabled {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update([v as u8]);
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.price_tiers {
+            Some(tiers) => {
+                hasher.update([1u8]);
+                for tier in tiers {
+                    hasher.update(tier.threshold.to_le_bytes());
+                    hasher.update(tier.price.to_le_bytes());
+                }
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.cleanup_reward_lamports {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.quickpick_spend_gate_threshold {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.max_fixed_payout_per_tier {
+            Some(caps) => {
+                hasher.update([1u8]);
+                for cap in caps {
+                    hasher.update(cap.to_le_bytes());
+                }
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.reserve_minimum_coverage_draws {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.max_tickets_per_wallet_per_hour {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.rolldown_cooldown_draws {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.compliance_mode {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.kyc_authority {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.identity_verification_threshold {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.identity_oracle {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.anchor_draw_time {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.milestone_thresholds {
+            Some(thresholds) => {
+                hasher.update([1u8]);
+                for v in thresholds {
+                    hasher.update(v.to_le_bytes());
+                }
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.milestone_prizes {
+            Some(prizes) => {
+                hasher.update([1u8]);
+                for v in prizes {
+                    hasher.update(v.to_le_bytes());
+                }
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.force_finalize_reward_lamports {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.max_tickets_per_wallet_per_draw {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.daily_withdrawal_cap_bps {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.operator_profit_share_bps {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.jackpot_guarantee_amount {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.streak_milestone_bps {
+            Some(milestones) => {
+                hasher.update([1u8]);
+                for milestone in milestones {
+                    hasher.update(milestone.streak_length.to_le_bytes());
+                    hasher.update(milestone.discount_bps.to_le_bytes());
+                }
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.reserve_target_multiplier_bps {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update(v.to_le_bytes());
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        match self.max_oracle_failures {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update([v]);
+            }
+            None => {
+                hasher.update([0u8]);
+            }
+        }
+        let result = hasher.finalize();
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(result.as_slice());
+        hash
+    }
+
+    /// Whether any rolldown tier share is being changed
+    pub fn changes_rolldown_split(&self) -> bool {
+        self.rolldown_match_5_bps.is_some()
+            || self.rolldown_match_4_bps.is_some()
+            || self.rolldown_match_3_bps.is_some()
+    }
+
+    /// Rolldown split that results from applying these params to `current`
+    pub fn resolve_rolldown_split(&self, current: [u16; 3]) -> [u16; 3] {
+        [
+            self.rolldown_match_5_bps.unwrap_or(current[0]),
+            self.rolldown_match_4_bps.unwrap_or(current[1]),
+            self.rolldown_match_3_bps.unwrap_or(current[2]),
+        ]
+    }
+
+    /// Human-readable summary of every field these params change
+    pub fn change_summaries(&self, lottery_state: &LotteryState) -> Vec<ConfigChangeSummary> {
+        let mut changes = Vec::new();
+        if let Some(v) = self.ticket_price {
+            push_config_change(
+                &mut changes,
+                "ticket_price",
+                lottery_state.ticket_price.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.house_fee_bps {
+            push_config_change(
+                &mut changes,
+                "house_fee_bps",
+                lottery_state.house_fee_bps.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.jackpot_cap {
+            push_config_change(
+                &mut changes,
+                "jackpot_cap",
+                lottery_state.jackpot_cap.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.seed_amount {
+            push_config_change(
+                &mut changes,
+                "seed_amount",
+                lottery_state.seed_amount.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.soft_cap {
+            push_config_change(
+                &mut changes,
+                "soft_cap",
+                lottery_state.soft_cap.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.hard_cap {
+            push_config_change(
+                &mut changes,
+                "hard_cap",
+                lottery_state.hard_cap.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.switchboard_queue {
+            push_config_change(
+                &mut changes,
+                "switchboard_queue",
+                lottery_state.switchboard_queue.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.draw_interval {
+            push_config_change(
+                &mut changes,
+                "draw_interval",
+                lottery_state.draw_interval.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.allow_third_party_closure {
+            push_config_change(
+                &mut changes,
+                "allow_third_party_closure",
+                lottery_state.allow_third_party_closure.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.min_tickets_per_draw {
+            push_config_change(
+                &mut changes,
+                "min_tickets_per_draw",
+                lottery_state.min_tickets_per_draw.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.rolldown_match_5_bps {
+            push_config_change(
+                &mut changes,
+                "rolldown_match_5_bps",
+                lottery_state.rolldown_match_5_bps.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.rolldown_match_4_bps {
+            push_config_change(
+                &mut changes,
+                "rolldown_match_4_bps",
+                lottery_state.rolldown_match_4_bps.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.rolldown_match_3_bps {
+            push_config_change(
+                &mut changes,
+                "rolldown_match_3_bps",
+                lottery_state.rolldown_match_3_bps.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.escrow_threshold_usdc {
+            push_config_change(
+                &mut changes,
+                "escrow_threshold_usdc",
+                lottery_state.escrow_threshold_usdc.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.escrow_release_delay_seconds {
+            push_config_change(
+                &mut changes,
+                "escrow_release_delay_seconds",
+                lottery_state.escrow_release_delay_seconds.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.reserve_minimum {
+            push_config_change(
+                &mut changes,
+                "reserve_minimum",
+                lottery_state.reserve_minimum.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.daily_limit_enabled {
+            push_config_change(
+                &mut changes,
+                "daily_limit_enabled",
+                lottery_state.daily_limit_enabled.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.entropy_validation_enabled {
+            push_config_change(
+                &mut changes,
+                "entropy_validation_enabled",
+                lottery_state.entropy_validation_enabled.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.ticket_sale_cutoff_seconds {
+            push_config_change(
+                &mut changes,
+                "ticket_sale_cutoff_seconds",
+                lottery_state.ticket_sale_cutoff_seconds.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.keeper_reward_lamports {
+            push_config_change(
+                &mut changes,
+                "keeper_reward_lamports",
+                lottery_state.keeper_reward_lamports.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.insurance_minimum {
+            push_config_change(
+                &mut changes,
+                "insurance_minimum",
+                lottery_state.insurance_minimum.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.insurance_replenishment_bps {
+            push_config_change(
+                &mut changes,
+                "insurance_replenishment_bps",
+                lottery_state.insurance_replenishment_bps.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.jackpot_milestone_thresholds {
+            push_config_change(
+                &mut changes,
+                "jackpot_milestone_thresholds",
+                format!("{:?}", lottery_state.jackpot_milestone_thresholds),
+                format!("{:?}", v),
+            );
+        }
+        if let Some(v) = self.allow_fallback_randomness {
+            push_config_change(
+                &mut changes,
+                "allow_fallback_randomness",
+                lottery_state.allow_fallback_randomness.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.use_pull_oracle {
+            push_config_change(
+                &mut changes,
+                "use_pull_oracle",
+                lottery_state.use_pull_oracle.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.switchboard_pull_feed {
+            push_config_change(
+                &mut changes,
+                "switchboard_pull_feed",
+                lottery_state
+                    .switchboard_pull_feed
+                    .map(|feed| feed.to_string())
+                    .unwrap_or_else(|| "none".to_string()),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.dynamic_price_enabled {
+            push_config_change(
+                &mut changes,
+                "dynamic_price_enabled",
+                lottery_state.dynamic_price_enabled.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.price_tiers {
+            push_config_change(
+                &mut changes,
+                "price_tiers",
+                format!("{:?}", lottery_state.price_tiers),
+                format!("{:?}", v),
+            );
+        }
+        if let Some(v) = self.cleanup_reward_lamports {
+            push_config_change(
+                &mut changes,
+                "cleanup_reward_lamports",
+                lottery_state.cleanup_reward_lamports.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.quickpick_spend_gate_threshold {
+            push_config_change(
+                &mut changes,
+                "quickpick_spend_gate_threshold",
+                lottery_state.quickpick_spend_gate_threshold.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.max_fixed_payout_per_tier {
+            push_config_change(
+                &mut changes,
+                "max_fixed_payout_per_tier",
+                format!("{:?}", lottery_state.max_fixed_payout_per_tier),
+                format!("{:?}", v),
+            );
+        }
+        if let Some(v) = self.reserve_minimum_coverage_draws {
+            push_config_change(
+                &mut changes,
+                "reserve_minimum_coverage_draws",
+                lottery_state.reserve_minimum_coverage_draws.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.max_tickets_per_wallet_per_hour {
+            push_config_change(
+                &mut changes,
+                "max_tickets_per_wallet_per_hour",
+                lottery_state.max_tickets_per_wallet_per_hour.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.rolldown_cooldown_draws {
+            push_config_change(
+                &mut changes,
+                "rolldown_cooldown_draws",
+                lottery_state.rolldown_cooldown_draws.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.compliance_mode {
+            push_config_change(
+                &mut changes,
+                "compliance_mode",
+                lottery_state.compliance_mode.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.kyc_authority {
+            push_config_change(
+                &mut changes,
+                "kyc_authority",
+                lottery_state
+                    .kyc_authority
+                    .map(|authority| authority.to_string())
+                    .unwrap_or_else(|| "none".to_string()),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.identity_verification_threshold {
+            push_config_change(
+                &mut changes,
+                "identity_verification_threshold",
+                lottery_state.identity_verification_threshold.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.identity_oracle {
+            push_config_change(
+                &mut changes,
+                "identity_oracle",
+                lottery_state.identity_oracle.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.anchor_draw_time {
+            push_config_change(
+                &mut changes,
+                "anchor_draw_time",
+                lottery_state.anchor_draw_time.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.milestone_thresholds {
+            push_config_change(
+                &mut changes,
+                "milestone_thresholds",
+                format!("{:?}", lottery_state.milestone_thresholds),
+                format!("{:?}", v),
+            );
+        }
+        if let Some(v) = self.milestone_prizes {
+            push_config_change(
+                &mut changes,
+                "milestone_prizes",
+                format!("{:?}", lottery_state.milestone_prizes),
+                format!("{:?}", v),
+            );
+        }
+        if let Some(v) = self.force_finalize_reward_lamports {
+            push_config_change(
+                &mut changes,
+                "force_finalize_reward_lamports",
+                lottery_state.force_finalize_reward_lamports.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.max_tickets_per_wallet_per_draw {
+            push_config_change(
+                &mut changes,
+                "max_tickets_per_wallet_per_draw",
+                lottery_state.max_tickets_per_wallet_per_draw.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.daily_withdrawal_cap_bps {
+            push_config_change(
+                &mut changes,
+                "daily_withdrawal_cap_bps",
+                lottery_state.daily_withdrawal_cap_bps.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.operator_profit_share_bps {
+            push_config_change(
+                &mut changes,
+                "operator_profit_share_bps",
+                lottery_state.operator_profit_share_bps.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.jackpot_guarantee_amount {
+            push_config_change(
+                &mut changes,
+                "jackpot_guarantee_amount",
+                lottery_state.jackpot_guarantee_amount.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.streak_milestone_bps {
+            push_config_change(
+                &mut changes,
+                "streak_milestone_bps",
+                format!("{:?}", lottery_state.streak_milestone_bps),
+                format!("{:?}", v),
+            );
+        }
+        if let Some(v) = self.reserve_target_multiplier_bps {
+            push_config_change(
+                &mut changes,
+                "reserve_target_multiplier_bps",
+                lottery_state.reserve_target_multiplier_bps.to_string(),
+                v.to_string(),
+            );
+        }
+        if let Some(v) = self.max_oracle_failures {
+            push_config_change(
+                &mut changes,
+                "max_oracle_failures",
+                lottery_state.max_oracle_failures.to_string(),
+                v.to_string(),
+            );
+        }
+        changes
+    }
+}
+
+/// Append a formatted field change to a config change summary
+fn push_config_change(
+    changes: &mut Vec<ConfigChangeSummary>,
+    field_name: &str,
+    old_value_str: String,
+    new_value_str: String,
+) {
+    changes.push(ConfigChangeSummary {
+        field_name: field_name.to_string(),
+        old_value_str,
+        new_value_str,
+    });
+}
+
+/// Discard a pending config proposal that outlived its execution window
+///
+/// Emits `ConfigChangeExpired` and returns whether a proposal was discarded.
+fn clear_expired_config_proposal(lottery_state: &mut LotteryState, current_timestamp: i64) -> bool {
+    if !is_config_proposal_expired(lottery_state.config_timelock_end, current_timestamp) {
+        return false;
+    }
+
+    let expired_at = lottery_state
+        .config_timelock_end
+        .saturating_add(CONFIG_PROPOSAL_EXECUTION_WINDOW);
+
+    emit!(ConfigChangeExpired {
+        proposal_hash: lottery_state.pending_config_hash,
+        expired_at,
+    });
+
+    lottery_state.pending_config_hash = [0u8; 32];
+    lottery_state.config_timelock_end = 0;
+
+    msg!(
+        "Expired config proposal discarded (expired at {})",
+        expired_at
+    );
+
+    true
+}
+
+/// Accounts required for proposing/updating/executing configuration
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    /// The authority updating the configuration
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        // Cannot update config during an active draw
+        constraint = !lottery_state.is_draw_in_progress @ LottoError::DrawInProgress,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+/// Propose configuration changes (Phase 1 of timelock)
+///
+/// This instruction starts the timelock by storing a hash of the proposed
+/// changes and setting `config_timelock_end` to now + CONFIG_TIMELOCK_DELAY.
+/// The actual changes are NOT applied yet — they must be executed via
+/// `execute_config` after the timelock expires.
+///
+/// # Security
+/// - Anyone monitoring the chain can see this proposal event and the hash
+/// - The 24-hour delay gives the community time to detect malicious proposals
+/// - The authority can cancel a proposal via `cancel_config_proposal`
+/// - A proposal not executed within CONFIG_PROPOSAL_EXECUTION_WINDOW after
+///   the timelock ends expires and is discarded by the next config call
+///
+/// # Validation Rules (pre-validated before accepting proposal)
+/// - ticket_price: Must be > 0
+/// - house_fee_bps: Must be <= 5000 (50%)
+/// - draw_interval: minimum 1 hour, maximum 7 days
+/// - min_tickets_per_draw: Must be > 0
+/// - rolldown_match_*_bps: Resulting split must sum to exactly 10000
+/// - escrow_release_delay_seconds: 0 to 30 days
+/// - ticket_sale_cutoff_seconds: 60 seconds to a quarter of the draw interval
+/// - insurance_replenishment_bps: Must be <= 10000
+/// - operator_profit_share_bps: Must be <= 10000
+/// - jackpot_guarantee_amount: Must be below the soft cap
+/// - streak_milestone_bps: Discounts <= 10000, used streak lengths ascending
+/// - reserve_target_multiplier_bps: Must be >= 10000 (one draw of prizes)
+/// - max_oracle_failures: Must be > 0
+/// - jackpot_milestone_thresholds: Non-zero and strictly ascending
+/// - milestone_thresholds: Non-zero and strictly ascending
+/// - use_pull_oracle: Requires a switchboard_pull_feed
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `params` - The configuration parameters being proposed
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_propose_config(
+    ctx: Context<UpdateConfig>,
+    params: UpdateConfigParams,
+) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    propose_config_change(&mut ctx.accounts.lottery_state, authority, params)
+}
+
+/// Validate `params` and start the config timelock for them
+///
+/// Shared by `propose_config` and single-parameter proposal instructions
+/// such as `set_operator_profit_share`.
+pub fn propose_config_change(
+    lottery_state: &mut LotteryState,
+    authority: Pubkey,
+    params: UpdateConfigParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Reject if there's already a pending proposal (an expired one is discarded)
+    clear_expired_config_proposal(lottery_state, clock.unix_timestamp);
+    require!(
+        lottery_state.config_timelock_end == 0,
+        LottoError::InvalidDrawState
+    );
+
+    // Pre-validate params so we catch errors early (before waiting 24h)
+    if let Some(ticket_price) = params.ticket_price {
+        require!(ticket_price > 0, LottoError::InvalidTicketPrice);
+    }
+    if let Some(house_fee_bps) = params.house_fee_bps {
+        require!(house_fee_bps <= 5000, LottoError::InvalidHouseFee);
+    }
+    if let Some(draw_interval) = params.draw_interval {
+        require!(
+            draw_interval >= 3600 && draw_interval <= 604800,
+            LottoError::InvalidDrawInterval
+        );
+    }
+    if let Some(min_tickets_per_draw) = params.min_tickets_per_draw {
+        require!(min_tickets_per_draw > 0, LottoError::InvalidConfig);
+    }
+    require!(
+        is_valid_rolldown_split(&params.resolve_rolldown_split(lottery_state.rolldown_split_bps())),
+        LottoError::InvalidRolldownSplit
+    );
+    if let Some(escrow_release_delay_seconds) = params.escrow_release_delay_seconds {
+        require!(
+            (0..=MAX_ESCROW_RELEASE_DELAY).contains(&escrow_release_delay_seconds),
+            LottoError::InvalidEscrowDelay
+        );
+    }
+    if let Some(insurance_replenishment_bps) = params.insurance_replenishment_bps {
+        require!(
+            insurance_replenishment_bps as u64 <= BPS_DENOMINATOR,
+            LottoError::InvalidBasisPoints
+        );
+    }
+    if let Some(daily_withdrawal_cap_bps) = params.daily_withdrawal_cap_bps {
+        require!(
+            daily_withdrawal_cap_bps as u64 <= BPS_DENOMINATOR,
+            LottoError::InvalidBasisPoints
+        );
+    }
+    if let Some(operator_profit_share_bps) = params.operator_profit_share_bps {
+        require!(
+            operator_profit_share_bps as u64 <= BPS_DENOMINATOR,
+            LottoError::InvalidBasisPoints
+        );
+    }
+    if let Some(thresholds) = &params.jackpot_milestone_thresholds {
+        require!(
+            is_valid_jackpot_milestone_thresholds(thresholds),
+            LottoError::InvalidConfig
+        );
+    }
+    if let Some(thresholds) = &params.milestone_thresholds {
+        require!(
+            is_valid_jackpot_milestone_thresholds(thresholds),
+            LottoError::InvalidConfig
+        );
+    }
+
+    if let Some(tiers) = &params.price_tiers {
+        require!(
+            PriceTier::is_valid_schedule(tiers),
+            LottoError::InvalidPriceTiers
+        );
+    }
+    if let Some(milestones) = &params.streak_milestone_bps {
+        require!(
+            StreakMilestone::is_valid_schedule(milestones),
+            LottoError::InvalidConfig
+        );
+    }
+    if let Some(multiplier_bps) = params.reserve_target_multiplier_bps {
+        require!(
+            multiplier_bps as u64 >= BPS_DENOMINATOR,
+            LottoError::InvalidConfig
+        );
+    }
+    if let Some(max_oracle_failures) = params.max_oracle_failures {
+        require!(max_oracle_failures > 0, LottoError::InvalidConfig);
+    }
+    if let Some(threshold) = params.quickpick_spend_gate_threshold {
+        require!(threshold > 0, LottoError::InvalidConfig);
+    }
+    if let Some(compliance_mode) = params.compliance_mode {
+        require!(
+            compliance_mode <= COMPLIANCE_MODE_KYC,
+            LottoError::InvalidConfig
+        );
+    }
+    if let Some(anchor_draw_time) = params.anchor_draw_time {
+        require!(
+            is_valid_anchor_draw_time(anchor_draw_time),
+            LottoError::InvalidConfig
+        );
+    }
+
+    // Simulate the final state to validate relationships
+    let simulated_dynamic_price_enabled = params
+        .dynamic_price_enabled
+        .unwrap_or(lottery_state.dynamic_price_enabled);
+    let simulated_price_tiers = params.price_tiers.unwrap_or(lottery_state.price_tiers);
+    require!(
+        !simulated_dynamic_price_enabled || PriceTier::is_valid_schedule(&simulated_price_tiers),
+        LottoError::InvalidPriceTiers
+    );
+    let simulated_use_pull_oracle = params
+        .use_pull_oracle
+        .unwrap_or(lottery_state.use_pull_oracle);
+    let simulated_pull_feed = params
+        .switchboard_pull_feed
+        .or(lottery_state.switchboard_pull_feed);
+    require!(
+        !simulated_use_pull_oracle || simulated_pull_feed.is_some(),
+        LottoError::PullOracleFeedNotSet
+    );
+    let simulated_compliance_mode = params
+        .compliance_mode
+        .unwrap_or(lottery_state.compliance_mode);
+    let simulated_kyc_authority = params.kyc_authority.or(lottery_state.kyc_authority);
+    require!(
+        simulated_compliance_mode < COMPLIANCE_MODE_KYC || simulated_kyc_authority.is_some(),
+        LottoError::KycAuthorityNotSet
+    );
+    let simulated_identity_threshold = params
+        .identity_verification_threshold
+        .unwrap_or(lottery_state.identity_verification_threshold);
+    let simulated_identity_oracle = params
+        .identity_oracle
+        .unwrap_or(lottery_state.identity_oracle);
+    require!(
+        simulated_identity_threshold == 0 || simulated_identity_oracle != Pubkey::default(),
+        LottoError::IdentityOracleNotSet
+    );
+    let simulated_draw_interval = params.draw_interval.unwrap_or(lottery_state.draw_interval);
+    let simulated_sale_cutoff = params
+        .ticket_sale_cutoff_seconds
+        .unwrap_or(lottery_state.ticket_sale_cutoff_seconds);
+    require!(
+        is_valid_ticket_sale_cutoff(simulated_sale_cutoff, simulated_draw_interval),
+        LottoError::InvalidTicketSaleCutoff
+    );
+    let simulated_soft_cap = params.soft_cap.unwrap_or(lottery_state.soft_cap);
+    let simulated_hard_cap = params.hard_cap.unwrap_or(lottery_state.hard_cap);
+    let simulated_seed_amount = params.seed_amount.unwrap_or(lottery_state.seed_amount);
+    let simulated_jackpot_cap = params.jackpot_cap.unwrap_or(lottery_state.jackpot_cap);
+
+    require!(simulated_soft_cap > 0, LottoError::InvalidCapConfig);
+    require!(simulated_hard_cap > 0, LottoError::InvalidCapConfig);
+    require!(
+        simulated_soft_cap < simulated_hard_cap,
+        LottoError::InvalidCapConfig
+    );
+    require!(simulated_seed_amount > 0, LottoError::InvalidSeedAmount);
+    require!(
+        simulated_seed_amount < simulated_soft_cap,
+        LottoError::InvalidSeedAmount
+    );
+    require!(simulated_jackpot_cap > 0, LottoError::InvalidJackpotCap);
+    require!(
+        simulated_jackpot_cap <= simulated_hard_cap,
+        LottoError::InvalidJackpotCap
+    );
+    // A guaranteed jackpot at or above the soft cap would force rolldowns
+    let simulated_guarantee = params
+        .jackpot_guarantee_amount
+        .unwrap_or(lottery_state.jackpot_guarantee_amount);
+    require!(
+        simulated_guarantee < simulated_soft_cap,
+        LottoError::InvalidCapConfig
+    );
+
+    // Store the proposal hash and set the timelock
+    let config_hash = params.compute_hash();
+    lottery_state.pending_config_hash = config_hash;
+    lottery_state.config_timelock_end = clock
+        .unix_timestamp
+        .checked_add(CONFIG_TIMELOCK_DELAY)
+        .ok_or(LottoError::Overflow)?;
+
+    emit!(ConfigChangeProposed {
+        proposal_hash: config_hash,
+        changes: params.change_summaries(lottery_state),
+        executable_after: lottery_state.config_timelock_end,
+        authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("⏳ Configuration change PROPOSED (timelock started)");
+    msg!(
+        "  Config hash: {:?}",
+        &lottery_state.pending_config_hash[..8]
+    );
+    msg!(
+        "  Executable after: {} (unix timestamp)",
+        lottery_state.config_timelock_end
+    );
+    msg!(
+        "  Delay: {} seconds ({} hours)",
+        CONFIG_TIMELOCK_DELAY,
+        CONFIG_TIMELOCK_DELAY / 3600
+    );
+    msg!("  Call execute_config with the same params after the timelock expires.");
+
+    Ok(())
+}
+
+/// Cancel a pending configuration proposal
+///
+/// Clears the pending config hash and timelock, preventing execution. A
+/// proposal past its execution window is discarded as expired instead.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_cancel_config_proposal(ctx: Context<UpdateConfig>) -> Result<()> {
+    let clock = Clock::get()?;
+    let lottery_state = &mut ctx.accounts.lottery_state;
+
+    require!(
+        lottery_state.config_timelock_end != 0,
+        LottoError::InvalidDrawState
+    );
+
+    if clear_expired_config_proposal(lottery_state, clock.unix_timestamp) {
+        return Ok(());
+    }
+
+    lottery_state.pending_config_hash = [0u8; 32];
+    lottery_state.config_timelock_end = 0;
+
+    emit!(ConfigUpdated {
+        parameter: "config_proposal_cancelled".to_string(),
+        old_value: 0,
+        new_value: 0,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("❌ Configuration proposal CANCELLED.");
+
+    Ok(())
+}
+
+/// Execute a proposed configuration change (Phase 2 of timelock)
+///
+/// Applies the previously proposed configuration changes after the timelock
+/// has expired. The params must exactly match the proposed ones (verified via hash).
+///
+/// # Security
+/// - Timelock must have expired (current time >= config_timelock_end)
+/// - The proposal must not be past its execution window
+/// - Params must hash to the same value as the proposal (prevents bait-and-switch)
+/// - All relationship validations are re-checked
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `params` - The configuration parameters (must match proposal)
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_execute_config(
+    ctx: Context<UpdateConfig>,
+    params: UpdateConfigParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let lottery_state = &mut ctx.accounts.lottery_state;
+
+    // Verify there is a pending proposal
+    require!(
+        lottery_state.config_timelock_end != 0,
+        LottoError::InvalidDrawState
+    );
+
+    // Verify the timelock has expired
+    require!(
+        clock.unix_timestamp >= lottery_state.config_timelock_end,
+        LottoError::InvalidTimestamp
+    );
+
+    // Verify the proposal is still within its execution window
+    require!(
+        !is_config_proposal_expired(lottery_state.config_timelock_end, clock.unix_timestamp),
+        LottoError::ConfigProposalExpired
+    );
+
+    // Verify the params hash matches the proposal (prevents bait-and-switch)
+    let config_hash = params.compute_hash();
+    require!(
+        config_hash == lottery_state.pending_config_hash,
+        LottoError::ConfigValidationFailed
+    );
+
+    // Clear the timelock state
+    lottery_state.pending_config_hash = [0u8; 32];
+    lottery_state.config_timelock_end = 0;
+
+    // Summarize against the pre-change state before applying
+    let changes = params.change_summaries(lottery_state);
+
+    // Now apply the configuration changes (same logic as the old handler_update_config)
+    if let Some(ticket_price) = params.ticket_price {
+        require!(ticket_price > 0, LottoError::InvalidTicketPrice);
+
+        emit!(ConfigUpdated {
+            parameter: "ticket_price".to_string(),
+            old_value: lottery_state.ticket_price,
+            new_value: ticket_price,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.ticket_price = ticket_price;
+        msg!("Updated ticket_price: {}", ticket_price);
+    }
+
+    if let Some(house_fee_bps) = params.house_fee_bps {
+        require!(house_fee_bps <= 5000, LottoError::InvalidHouseFee);
+
+        emit!(ConfigUpdated {
+            parameter: "house_fee_bps".to_string(),
+            old_value: lottery_state.house_fee_bps as u64,
+            new_value: house_fee_bps as u64,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.house_fee_bps = house_fee_bps;
+        msg!("Updated house_fee_bps: {}", house_fee_bps);
+    }
+
+    if let Some(jackpot_cap) = params.jackpot_cap {
+        emit!(ConfigUpdated {
+            parameter: "jackpot_cap".to_string(),
+            old_value: lottery_state.jackpot_cap,
+            new_value: jackpot_cap,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.jackpot_cap = jackpot_cap;
+        msg!("Updated jackpot_cap: {}", jackpot_cap);
+    }
+
+    if let Some(seed_amount) = params.seed_amount {
+        emit!(ConfigUpdated {
+            parameter: "seed_amount".to_string(),
+            old_value: lottery_state.seed_amount,
+            new_value: seed_amount,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.seed_amount = seed_amount;
+        msg!("Updated seed_amount: {}", seed_amount);
+    }
+
+    if let Some(soft_cap) = params.soft_cap {
+        emit!(ConfigUpdated {
+            parameter: "soft_cap".to_string(),
+            old_value: lottery_state.soft_cap,
+            new_value: soft_cap,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.soft_cap = soft_cap;
+        msg!("Updated soft_cap: {}", soft_cap);
+    }
+
+    if let Some(hard_cap) = params.hard_cap {
+        emit!(ConfigUpdated {
+            parameter: "hard_cap".to_string(),
+            old_value: lottery_state.hard_cap,
+            new_value: hard_cap,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.hard_cap = hard_cap;
+        msg!("Updated hard_cap: {}", hard_cap);
+    }
+
+    if let Some(switchboard_queue) = params.switchboard_queue {
+        lottery_state.switchboard_queue = switchboard_queue;
+        msg!("Updated switchboard_queue: {}", switchboard_queue);
+    }
+
+    if let Some(draw_interval) = params.draw_interval {
+        require!(
+            draw_interval >= 3600 && draw_interval <= 604800,
+            LottoError::InvalidDrawInterval
+        );
+
+        emit!(ConfigUpdated {
+            parameter: "draw_interval".to_string(),
+            old_value: lottery_state.draw_interval as u64,
+            new_value: draw_interval as u64,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.draw_interval = draw_interval;
+        msg!("Updated draw_interval: {}", draw_interval);
+    }
+
+    if let Some(allow_third_party_closure) = params.allow_third_party_closure {
+        apply_allow_third_party_closure(
+            lottery_state,
+            allow_third_party_closure,
+            ctx.accounts.authority.key(),
+            clock.unix_timestamp,
+        );
+    }
+
+    if let Some(daily_limit_enabled) = params.daily_limit_enabled {
+        apply_daily_limit_enabled(
+            lottery_state,
+            daily_limit_enabled,
+            ctx.accounts.authority.key(),
+            clock.unix_timestamp,
+        );
+    }
+
+    if let Some(min_tickets_per_draw) = params.min_tickets_per_draw {
+        require!(min_tickets_per_draw > 0, LottoError::InvalidConfig);
+
+        emit!(ConfigUpdated {
+            parameter: "min_tickets_per_draw".to_string(),
+            old_value: lottery_state.min_tickets_per_draw,
+            new_value: min_tickets_per_draw,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.min_tickets_per_draw = min_tickets_per_draw;
+        msg!("Updated min_tickets_per_draw: {}", min_tickets_per_draw);
+    }
+
+    if params.changes_rolldown_split() {
+        let old_bps = lottery_state.rolldown_split_bps();
+        let new_bps = params.resolve_rolldown_split(old_bps);
+        require!(
+            is_valid_rolldown_split(&new_bps),
+            LottoError::InvalidRolldownSplit
+        );
+
+        lottery_state.rolldown_match_5_bps = new_bps[0];
+        lottery_state.rolldown_match_4_bps = new_bps[1];
+        lottery_state.rolldown_match_3_bps = new_bps[2];
+
+        emit!(RolldownSplitUpdated { old_bps, new_bps });
+        msg!("Updated rolldown split: {:?} -> {:?}", old_bps, new_bps);
+    }
+
+    if let Some(escrow_threshold_usdc) = params.escrow_threshold_usdc {
+        emit!(ConfigUpdated {
+            parameter: "escrow_threshold_usdc".to_string(),
+            old_value: lottery_state.escrow_threshold_usdc,
+            new_value: escrow_threshold_usdc,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.escrow_threshold_usdc = escrow_threshold_usdc;
+        msg!("Updated escrow_threshold_usdc: {}", escrow_threshold_usdc);
+    }
+
+    if let Some(escrow_release_delay_seconds) = params.escrow_release_delay_seconds {
+        require!(
+            (0..=MAX_ESCROW_RELEASE_DELAY).contains(&escrow_release_delay_seconds),
+            LottoError::InvalidEscrowDelay
+        );
+
+        emit!(ConfigUpdated {
+            parameter: "escrow_release_delay_seconds".to_string(),
+            old_value: lottery_state.escrow_release_delay_seconds as u64,
+            new_value: escrow_release_delay_seconds as u64,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.escrow_release_delay_seconds = escrow_release_delay_seconds;
+        msg!(
+            "Updated escrow_release_delay_seconds: {}",
+            escrow_release_delay_seconds
+        );
+    }
+
+    if let Some(reserve_minimum) = params.reserve_minimum {
+        emit!(ConfigUpdated {
+            parameter: "reserve_minimum".to_string(),
+            old_value: lottery_state.reserve_minimum,
+            new_value: reserve_minimum,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.reserve_minimum = reserve_minimum;
+        msg!("Updated reserve_minimum: {}", reserve_minimum);
+    }
+
+    if let Some(entropy_validation_enabled) = params.entropy_validation_enabled {
+        emit!(ConfigUpdated {
+            parameter: "entropy_validation_enabled".to_string(),
+            old_value: lottery_state.entropy_validation_enabled as u64,
+            new_value: entropy_validation_enabled as u64,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.entropy_validation_enabled = entropy_validation_enabled;
+        msg!(
+            "Updated entropy_validation_enabled: {}",
+            entropy_validation_enabled
+        );
+    }
+
+    if let Some(ticket_sale_cutoff_seconds) = params.ticket_sale_cutoff_seconds {
+        emit!(ConfigUpdated {
+            parameter: "ticket_sale_cutoff_seconds".to_string(),
+            old_value: lottery_state.ticket_sale_cutoff_seconds as u64,
+            new_value: ticket_sale_cutoff_seconds as u64,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.ticket_sale_cutoff_seconds = ticket_sale_cutoff_seconds;
+        msg!(
+            "Updated ticket_sale_cutoff_seconds: {}",
+            ticket_sale_cutoff_seconds
+        );
+    }
+
+    if let Some(keeper_reward_lamports) = params.keeper_reward_lamports {
+        emit!(ConfigUpdated {
+            parameter: "keeper_reward_lamports".to_string(),
+            old_value: lottery_state.keeper_reward_lamports,
+            new_value: keeper_reward_lamports,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.keeper_reward_lamports = keeper_reward_lamports;
+        msg!("Updated keeper_reward_lamports: {}", keeper_reward_lamports);
+    }
+
+    if let Some(force_finalize_reward_lamports) = params.force_finalize_reward_lamports {
+        emit!(ConfigUpdated {
+            parameter: "force_finalize_reward_lamports".to_string(),
+            old_value: lottery_state.force_finalize_reward_lamports,
+            new_value: force_finalize_reward_lamports,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.force_finalize_reward_lamports = force_finalize_reward_lamports;
+        msg!(
+            "Updated force_finalize_reward_lamports: {}",
+            force_finalize_reward_lamports
+        );
+    }
+
+    if let Some(cleanup_reward_lamports) = params.cleanup_reward_lamports {
+        emit!(ConfigUpdated {
+            parameter: "cleanup_reward_lamports".to_string(),
+            old_value: lottery_state.cleanup_reward_lamports,
+            new_value: cleanup_reward_lamports,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.cleanup_reward_lamports = cleanup_reward_lamports;
+        msg!(
+            "Updated cleanup_reward_lamports: {}",
+            cleanup_reward_lamports
+        );
+    }
+
+    if let Some(quickpick_spend_gate_threshold) = params.quickpick_spend_gate_threshold {
+        emit!(ConfigUpdated {
+            parameter: "quickpick_spend_gate_threshold".to_string(),
+            old_value: lottery_state.quickpick_spend_gate_threshold,
+            new_value: quickpick_spend_gate_threshold,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.quickpick_spend_gate_threshold = quickpick_spend_gate_threshold;
+        msg!(
+            "Updated quickpick_spend_gate_threshold: {}",
+            quickpick_spend_gate_threshold
+        );
+    }
+
+    if let Some(max_fixed_payout_per_tier) = params.max_fixed_payout_per_tier {
+        emit!(ConfigUpdated {
+            parameter: "max_fixed_payout_per_tier".to_string(),
+            old_value: 0, // Cap array doesn't fit in u64, use 0 as placeholder
+            new_value: 0,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.max_fixed_payout_per_tier = max_fixed_payout_per_tier;
+        msg!(
+            "Updated max_fixed_payout_per_tier: {:?}",
+            max_fixed_payout_per_tier
+        );
+    }
+
+    if let Some(reserve_minimum_coverage_draws) = params.reserve_minimum_coverage_draws {
+        emit!(ConfigUpdated {
+            parameter: "reserve_minimum_coverage_draws".to_string(),
+            old_value: lottery_state.reserve_minimum_coverage_draws,
+            new_value: reserve_minimum_coverage_draws,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.reserve_minimum_coverage_draws = reserve_minimum_coverage_draws;
+        msg!(
+            "Updated reserve_minimum_coverage_draws: {}",
+            reserve_minimum_coverage_draws
+        );
+    }
+
+    if let Some(max_tickets_per_wallet_per_hour) = params.max_tickets_per_wallet_per_hour {
+        emit!(ConfigUpdated {
+            parameter: "max_tickets_per_wallet_per_hour".to_string(),
+            old_value: lottery_state.max_tickets_per_wallet_per_hour as u64,
+            new_value: max_tickets_per_wallet_per_hour as u64,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.max_tickets_per_wallet_per_hour = max_tickets_per_wallet_per_hour;
+        msg!(
+            "Updated max_tickets_per_wallet_per_hour: {}",
+            max_tickets_per_wallet_per_hour
+        );
+    }
+
+    if let Some(max_tickets_per_wallet_per_draw) = params.max_tickets_per_wallet_per_draw {
+        emit!(ConfigUpdated {
+            parameter: "max_tickets_per_wallet_per_draw".to_string(),
+            old_value: lottery_state.max_tickets_per_wallet_per_draw,
+            new_value: max_tickets_per_wallet_per_draw,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.max_tickets_per_wallet_per_draw = max_tickets_per_wallet_per_draw;
+        msg!(
+            "Updated max_tickets_per_wallet_per_draw: {}",
+            max_tickets_per_wallet_per_draw
+        );
+    }
+
+    if let Some(daily_withdrawal_cap_bps) = params.daily_withdrawal_cap_bps {
+        require!(
+            daily_withdrawal_cap_bps as u64 <= BPS_DENOMINATOR,
+            LottoError::InvalidBasisPoints
+        );
+
+        emit!(ConfigUpdated {
+            parameter: "daily_withdrawal_cap_bps".to_string(),
+            old_value: lottery_state.daily_withdrawal_cap_bps as u64,
+            new_value: daily_withdrawal_cap_bps as u64,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.daily_withdrawal_cap_bps = daily_withdrawal_cap_bps;
+        msg!(
+            "Updated daily_withdrawal_cap_bps: {}",
+            daily_withdrawal_cap_bps
+        );
+    }
+
+    if let Some(operator_profit_share_bps) = params.operator_profit_share_bps {
+        require!(
+            operator_profit_share_bps as u64 <= BPS_DENOMINATOR,
+            LottoError::InvalidBasisPoints
+        );
+
+        emit!(ConfigUpdated {
+            parameter: "operator_profit_share_bps".to_string(),
+            old_value: lottery_state.operator_profit_share_bps as u64,
+            new_value: operator_profit_share_bps as u64,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.operator_profit_share_bps = operator_profit_share_bps;
+        msg!(
+            "Updated operator_profit_share_bps: {}",
+            operator_profit_share_bps
+        );
+    }
+
+    if let Some(jackpot_guarantee_amount) = params.jackpot_guarantee_amount {
+        emit!(ConfigUpdated {
+            parameter: "jackpot_guarantee_amount".to_string(),
+            old_value: lottery_state.jackpot_guarantee_amount,
+            new_value: jackpot_guarantee_amount,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.jackpot_guarantee_amount = jackpot_guarantee_amount;
+        msg!(
+            "Updated jackpot_guarantee_amount: {}",
+            jackpot_guarantee_amount
+        );
+    }
+
+    if let Some(streak_milestone_bps) = params.streak_milestone_bps {
+        require!(
+            StreakMilestone::is_valid_schedule(&streak_milestone_bps),
+            LottoError::InvalidConfig
+        );
+
+        emit!(ConfigUpdated {
+            parameter: "streak_milestone_bps".to_string(),
+            old_value: 0, // Milestone array doesn't fit in u64, use 0 as placeholder
+            new_value: 0,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.streak_milestone_bps = streak_milestone_bps;
+        msg!("Updated streak_milestone_bps: {:?}", streak_milestone_bps);
+    }
+
+    if let Some(reserve_target_multiplier_bps) = params.reserve_target_multiplier_bps {
+        require!(
+            reserve_target_multiplier_bps as u64 >= BPS_DENOMINATOR,
+            LottoError::InvalidConfig
+        );
+
+        emit!(ConfigUpdated {
+            parameter: "reserve_target_multiplier_bps".to_string(),
+            old_value: lottery_state.reserve_target_multiplier_bps as u64,
+            new_value: reserve_target_multiplier_bps as u64,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.reserve_target_multiplier_bps = reserve_target_multiplier_bps;
+        msg!(
+            "Updated reserve_target_multiplier_bps: {}",
+            reserve_target_multiplier_bps
+        );
+    }
+
+    if let Some(max_oracle_failures) = params.max_oracle_failures {
+        require!(max_oracle_failures > 0, LottoError::InvalidConfig);
+
+        emit!(ConfigUpdated {
+            parameter: "max_oracle_failures".to_string(),
+            old_value: lottery_state.max_oracle_failures as u64,
+            new_value: max_oracle_failures as u64,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.max_oracle_failures = max_oracle_failures;
+        msg!("Updated max_oracle_failures: {}", max_oracle_failures);
+    }
+
+    if let Some(rolldown_cooldown_draws) = params.rolldown_cooldown_draws {
+        emit!(ConfigUpdated {
+            parameter: "rolldown_cooldown_draws".to_string(),
+            old_value: lottery_state.rolldown_cooldown_draws as u64,
+            new_value: rolldown_cooldown_draws as u64,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.rolldown_cooldown_draws = rolldown_cooldown_draws;
+        msg!(
+            "Updated rolldown_cooldown_draws: {}",
+            rolldown_cooldown_draws
+        );
+    }
+
+    if let Some(kyc_authority) = params.kyc_authority {
+        emit!(ConfigUpdated {
+            parameter: "kyc_authority".to_string(),
+            old_value: 0, // Pubkey doesn't fit in u64, use 0 as placeholder
+            new_value: 0,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.kyc_authority = Some(kyc_authority);
+        msg!("Updated kyc_authority: {}", kyc_authority);
+    }
+
+    if let Some(compliance_mode) = params.compliance_mode {
+        emit!(ConfigUpdated {
+            parameter: "compliance_mode".to_string(),
+            old_value: lottery_state.compliance_mode as u64,
+            new_value: compliance_mode as u64,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.compliance_mode = compliance_mode;
+        msg!("Updated compliance_mode: {}", compliance_mode);
+    }
+
+    if let Some(identity_oracle) = params.identity_oracle {
+        emit!(ConfigUpdated {
+            parameter: "identity_oracle".to_string(),
+            old_value: 0, // Pubkey doesn't fit in u64, use 0 as placeholder
+            new_value: 0,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.identity_oracle = identity_oracle;
+        msg!("Updated identity_oracle: {}", identity_oracle);
+    }
+
+    if let Some(new_thresholds) = params.milestone_thresholds {
+        require!(
+            is_valid_jackpot_milestone_thresholds(&new_thresholds),
+            LottoError::InvalidConfig
+        );
+        msg!(
+            "Updated milestone bonus thresholds: {:?} -> {:?}",
+            lottery_state.milestone_thresholds,
+            new_thresholds
+        );
+        lottery_state.milestone_thresholds = new_thresholds;
+        // Unlocks were earned against the old thresholds
+        lottery_state.milestone_unlocked = 0;
+    }
+
+    if let Some(new_prizes) = params.milestone_prizes {
+        msg!(
+            "Updated milestone bonuses: {:?} -> {:?}",
+            lottery_state.milestone_prizes,
+            new_prizes
+        );
+        lottery_state.milestone_prizes = new_prizes;
+    }
+
+    if let Some(anchor_draw_time) = params.anchor_draw_time {
+        require!(
+            is_valid_anchor_draw_time(anchor_draw_time),
+            LottoError::InvalidConfig
+        );
         emit!(ConfigUpdated {
-            parameter: "ticket_price".to_string(),
-            old_value: lottery_state.ticket_price,
-            new_value: ticket_price,
+            parameter: "anchor_draw_time".to_string(),
+            old_value: lottery_state.anchor_draw_time as u64,
+            new_value: anchor_draw_time as u64,
             authority: ctx.accounts.authority.key(),
             timestamp: clock.unix_timestamp,
         });
 
-        lottery_state.ticket_price = ticket_price;
-        msg!("Updated ticket_price: {}", ticket_price);
+        lottery_state.anchor_draw_time = anchor_draw_time;
+        msg!("Updated anchor_draw_time: {}", anchor_draw_time);
     }
 
-    if let Some(house_fee_bps) = params.house_fee_bps {
-        require!(house_fee_bps <= 5000, LottoError::InvalidHouseFee);
-
+    if let Some(identity_verification_threshold) = params.identity_verification_threshold {
         emit!(ConfigUpdated {
-            parameter: "house_fee_bps".to_string(),
-            old_value: lottery_state.house_fee_bps as u64,
-            new_value: house_fee_bps as u64,
+            parameter: "identity_verification_threshold".to_string(),
+            old_value: lottery_state.identity_verification_threshold,
+            new_value: identity_verification_threshold,
             authority: ctx.accounts.authority.key(),
             timestamp: clock.unix_timestamp,
         });
 
-        lottery_state.house_fee_bps = house_fee_bps;
-        msg!("Updated house_fee_bps: {}", house_fee_bps);
+        lottery_state.identity_verification_threshold = identity_verification_threshold;
+        msg!(
+            "Updated identity_verification_threshold: {}",
+            identity_verification_threshold
+        );
     }
 
-    if let Some(jackpot_cap) = params.jackpot_cap {
+    if let Some(insurance_minimum) = params.insurance_minimum {
         emit!(ConfigUpdated {
-            parameter: "jackpot_cap".to_string(),
-            old_value: lottery_state.jackpot_cap,
-            new_value: jackpot_cap,
+            parameter: "insurance_minimum".to_string(),
+            old_value: lottery_state.insurance_minimum,
+            new_value: insurance_minimum,
             authority: ctx.accounts.authority.key(),
             timestamp: clock.unix_timestamp,
         });
 
-        lottery_state.jackpot_cap = jackpot_cap;
-        msg!("Updated jackpot_cap: {}", jackpot_cap);
+        lottery_state.insurance_minimum = insurance_minimum;
+        msg!("Updated insurance_minimum: {}", insurance_minimum);
     }
 
-    if let Some(seed_amount) = params.seed_amount {
+    if let Some(insurance_replenishment_bps) = params.insurance_replenishment_bps {
+        require!(
+            insurance_replenishment_bps as u64 <= BPS_DENOMINATOR,
+            LottoError::InvalidBasisPoints
+        );
+
         emit!(ConfigUpdated {
-            parameter: "seed_amount".to_string(),
-            old_value: lottery_state.seed_amount,
-            new_value: seed_amount,
+            parameter: "insurance_replenishment_bps".to_string(),
+            old_value: lottery_state.insurance_replenishment_bps as u64,
+            new_value: insurance_replenishment_bps as u64,
             authority: ctx.accounts.authority.key(),
             timestamp: clock.unix_timestamp,
         });
 
-        lottery_state.seed_amount = seed_amount;
-        msg!("Updated seed_amount: {}", seed_amount);
+        lottery_state.insurance_replenishment_bps = insurance_replenishment_bps;
+        msg!(
+            "Updated insurance_replenishment_bps: {}",
+            insurance_replenishment_bps
+        );
     }
 
-    if let Some(soft_cap) = params.soft_cap {
+    if let Some(new_thresholds) = params.jackpot_milestone_thresholds {
+        require!(
+            is_valid_jackpot_milestone_thresholds(&new_thresholds),
+            LottoError::InvalidConfig
+        );
+
+        let old_thresholds = lottery_state.jackpot_milestone_thresholds;
+        lottery_state.jackpot_milestone_thresholds = new_thresholds;
+        // Announcements were for the old thresholds
+        lottery_state.milestones_emitted = 0;
+
+        emit!(JackpotMilestoneThresholdsUpdated {
+            old_thresholds,
+            new_thresholds,
+        });
+        msg!(
+            "Updated jackpot milestone thresholds: {:?} -> {:?}",
+            old_thresholds,
+            new_thresholds
+        );
+    }
+
+    if let Some(allow_fallback_randomness) = params.allow_fallback_randomness {
         emit!(ConfigUpdated {
-            parameter: "soft_cap".to_string(),
-            old_value: lottery_state.soft_cap,
-            new_value: soft_cap,
+            parameter: "allow_fallback_randomness".to_string(),
+            old_value: lottery_state.allow_fallback_randomness as u64,
+            new_value: allow_fallback_randomness as u64,
             authority: ctx.accounts.authority.key(),
             timestamp: clock.unix_timestamp,
         });
 
-        lottery_state.soft_cap = soft_cap;
-        msg!("Updated soft_cap: {}", soft_cap);
+        lottery_state.allow_fallback_randomness = allow_fallback_randomness;
+        msg!(
+            "Updated allow_fallback_randomness: {}",
+            allow_fallback_randomness
+        );
     }
 
-    if let Some(hard_cap) = params.hard_cap {
+    if let Some(switchboard_pull_feed) = params.switchboard_pull_feed {
         emit!(ConfigUpdated {
-            parameter: "hard_cap".to_string(),
-            old_value: lottery_state.hard_cap,
-            new_value: hard_cap,
+            parameter: "switchboard_pull_feed".to_string(),
+            old_value: 0, // Pubkey doesn't fit in u64, use 0 as placeholder
+            new_value: 0,
             authority: ctx.accounts.authority.key(),
             timestamp: clock.unix_timestamp,
         });
 
-        lottery_state.hard_cap = hard_cap;
-        msg!("Updated hard_cap: {}", hard_cap);
+        lottery_state.switchboard_pull_feed = Some(switchboard_pull_feed);
+        msg!("Updated switchboard_pull_feed: {}", switchboard_pull_feed);
     }
 
-    if let Some(switchboard_queue) = params.switchboard_queue {
-        lottery_state.switchboard_queue = switchboard_queue;
-        msg!("Updated switchboard_queue: {}", switchboard_queue);
+    if let Some(use_pull_oracle) = params.use_pull_oracle {
+        emit!(ConfigUpdated {
+            parameter: "use_pull_oracle".to_string(),
+            old_value: lottery_state.use_pull_oracle as u64,
+            new_value: use_pull_oracle as u64,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.use_pull_oracle = use_pull_oracle;
+        msg!("Updated use_pull_oracle: {}", use_pull_oracle);
     }
 
-    if let Some(draw_interval) = params.draw_interval {
+    if let Some(price_tiers) = params.price_tiers {
         require!(
-            draw_interval >= 3600 && draw_interval <= 604800,
-            LottoError::InvalidDrawInterval
+            PriceTier::is_valid_schedule(&price_tiers),
+            LottoError::InvalidPriceTiers
         );
 
         emit!(ConfigUpdated {
-            parameter: "draw_interval".to_string(),
-            old_value: lottery_state.draw_interval as u64,
-            new_value: draw_interval as u64,
+            parameter: "price_tiers".to_string(),
+            old_value: 0, // Tier array doesn't fit in u64, use 0 as placeholder
+            new_value: 0,
             authority: ctx.accounts.authority.key(),
             timestamp: clock.unix_timestamp,
         });
 
-        lottery_state.draw_interval = draw_interval;
-        msg!("Updated draw_interval: {}", draw_interval);
+        lottery_state.price_tiers = price_tiers;
+        msg!("Updated price_tiers: {:?}", price_tiers);
+    }
+
+    if let Some(dynamic_price_enabled) = params.dynamic_price_enabled {
+        emit!(ConfigUpdated {
+            parameter: "dynamic_price_enabled".to_string(),
+            old_value: lottery_state.dynamic_price_enabled as u64,
+            new_value: dynamic_price_enabled as u64,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        lottery_state.dynamic_price_enabled = dynamic_price_enabled;
+        msg!("Updated dynamic_price_enabled: {}", dynamic_price_enabled);
     }
 
     // Validate relationships after updates
+    require!(
+        !lottery_state.dynamic_price_enabled
+            || PriceTier::is_valid_schedule(&lottery_state.price_tiers),
+        LottoError::InvalidPriceTiers
+    );
+    require!(
+        !lottery_state.use_pull_oracle || lottery_state.switchboard_pull_feed.is_some(),
+        LottoError::PullOracleFeedNotSet
+    );
+    require!(
+        lottery_state.compliance_mode < COMPLIANCE_MODE_KYC
+            || lottery_state.kyc_authority.is_some(),
+        LottoError::KycAuthorityNotSet
+    );
+    require!(
+        lottery_state.identity_verification_threshold == 0
+            || lottery_state.identity_oracle != Pubkey::default(),
+        LottoError::IdentityOracleNotSet
+    );
+    require!(
+        is_valid_ticket_sale_cutoff(
+            lottery_state.ticket_sale_cutoff_seconds,
+            lottery_state.draw_interval
+        ),
+        LottoError::InvalidTicketSaleCutoff
+    );
     require!(lottery_state.soft_cap > 0, LottoError::InvalidCapConfig);
     require!(lottery_state.hard_cap > 0, LottoError::InvalidCapConfig);
     require!(
@@ -587,6 +2353,13 @@ pub fn handler_execute_config(
         LottoError::InvalidJackpotCap
     );
 
+    emit!(ConfigChangeExecuted {
+        proposal_hash: config_hash,
+        changes,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("✅ Configuration EXECUTED after timelock!");
 
     Ok(())
@@ -607,12 +2380,18 @@ pub fn handler_execute_config(
 /// # Returns
 /// * `Result<()>` - Success or error
 /// SECURITY FIX (Issue #2): Legacy immediate config update now ONLY allows
-/// non-sensitive operational parameters (switchboard_queue rotation).
+/// non-sensitive operational parameters (switchboard_queue, closure, daily limit).
 ///
 /// ALL financial parameters (ticket_price, house_fee_bps, jackpot_cap,
-/// seed_amount, soft_cap, hard_cap, draw_interval) MUST go through the
-/// propose_config → execute_config timelock flow. This prevents a
-/// compromised authority from instantly changing critical financial params.
+/// seed_amount, soft_cap, hard_cap, draw_interval, min_tickets_per_draw, rolldown split,
+/// escrow threshold and delay, reserve minimum, entropy validation, sale cutoff,
+/// keeper reward, insurance replenishment, jackpot milestones, fallback randomness,
+/// pull oracle, dynamic price tiers, cleanup reward, QuickPick spend gate,
+/// fixed payout caps, reserve coverage alert, hourly purchase velocity limit,
+/// rolldown cooldown, KYC compliance mode and authority, identity
+/// verification threshold and oracle)
+/// MUST go through the propose_config → execute_config timelock flow. This
+/// prevents a compromised authority from instantly changing critical financial params.
 ///
 /// If any sensitive parameter is provided, this handler will reject the call
 /// with an error directing the caller to use the timelock flow instead.
@@ -621,6 +2400,8 @@ pub fn handler_update_config(ctx: Context<UpdateConfig>, params: UpdateConfigPar
     let lottery_state = &mut ctx.accounts.lottery_state;
 
     // SECURITY: Reject if there's a pending timelock proposal to prevent bypass
+    // (an expired one is discarded)
+    clear_expired_config_proposal(lottery_state, clock.unix_timestamp);
     require!(
         lottery_state.config_timelock_end == 0,
         LottoError::InvalidDrawState
@@ -656,8 +2437,156 @@ pub fn handler_update_config(ctx: Context<UpdateConfig>, params: UpdateConfigPar
         params.draw_interval.is_none(),
         LottoError::ConfigValidationFailed
     );
+    require!(
+        params.min_tickets_per_draw.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        !params.changes_rolldown_split(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.escrow_threshold_usdc.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.escrow_release_delay_seconds.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.reserve_minimum.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.entropy_validation_enabled.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.ticket_sale_cutoff_seconds.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.keeper_reward_lamports.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.insurance_minimum.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.insurance_replenishment_bps.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.jackpot_milestone_thresholds.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.allow_fallback_randomness.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.use_pull_oracle.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.switchboard_pull_feed.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.dynamic_price_enabled.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.price_tiers.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.cleanup_reward_lamports.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.quickpick_spend_gate_threshold.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.max_fixed_payout_per_tier.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.reserve_minimum_coverage_draws.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.max_tickets_per_wallet_per_hour.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.rolldown_cooldown_draws.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.compliance_mode.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.kyc_authority.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.identity_verification_threshold.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.identity_oracle.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.anchor_draw_time.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.milestone_thresholds.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.milestone_prizes.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.force_finalize_reward_lamports.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.max_tickets_per_wallet_per_draw.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.daily_withdrawal_cap_bps.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.operator_profit_share_bps.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.jackpot_guarantee_amount.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.streak_milestone_bps.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.reserve_target_multiplier_bps.is_none(),
+        LottoError::ConfigValidationFailed
+    );
+    require!(
+        params.max_oracle_failures.is_none(),
+        LottoError::ConfigValidationFailed
+    );
 
-    // Only switchboard_queue can be updated immediately (operational, non-financial)
+    // Only operational, non-financial parameters can be updated immediately
     if let Some(switchboard_queue) = params.switchboard_queue {
         emit!(ConfigUpdated {
             parameter: "switchboard_queue".to_string(),
@@ -671,12 +2600,72 @@ pub fn handler_update_config(ctx: Context<UpdateConfig>, params: UpdateConfigPar
         msg!("Updated switchboard_queue: {}", switchboard_queue);
     }
 
-    msg!("Configuration updated (immediate mode — switchboard_queue only).");
+    // Ticket closure permissions and daily limits are operational (player protection)
+    if let Some(allow_third_party_closure) = params.allow_third_party_closure {
+        apply_allow_third_party_closure(
+            lottery_state,
+            allow_third_party_closure,
+            ctx.accounts.authority.key(),
+            clock.unix_timestamp,
+        );
+    }
+
+    if let Some(daily_limit_enabled) = params.daily_limit_enabled {
+        apply_daily_limit_enabled(
+            lottery_state,
+            daily_limit_enabled,
+            ctx.accounts.authority.key(),
+            clock.unix_timestamp,
+        );
+    }
+
+    msg!("Configuration updated (immediate mode — operational parameters only).");
     msg!("NOTE: All financial parameter changes require the propose_config → execute_config timelock flow.");
 
     Ok(())
 }
 
+/// Apply the third-party ticket closure flag and emit the config event
+fn apply_allow_third_party_closure(
+    lottery_state: &mut LotteryState,
+    allow_third_party_closure: bool,
+    authority: Pubkey,
+    timestamp: i64,
+) {
+    emit!(ConfigUpdated {
+        parameter: "allow_third_party_closure".to_string(),
+        old_value: lottery_state.allow_third_party_closure as u64,
+        new_value: allow_third_party_closure as u64,
+        authority,
+        timestamp,
+    });
+
+    lottery_state.allow_third_party_closure = allow_third_party_closure;
+    msg!(
+        "Updated allow_third_party_closure: {}",
+        allow_third_party_closure
+    );
+}
+
+/// Apply the daily ticket limit flag and emit the config event
+fn apply_daily_limit_enabled(
+    lottery_state: &mut LotteryState,
+    daily_limit_enabled: bool,
+    authority: Pubkey,
+    timestamp: i64,
+) {
+    emit!(ConfigUpdated {
+        parameter: "daily_limit_enabled".to_string(),
+        old_value: lottery_state.daily_limit_enabled as u64,
+        new_value: daily_limit_enabled as u64,
+        authority,
+        timestamp,
+    });
+
+    lottery_state.daily_limit_enabled = daily_limit_enabled;
+    msg!("Updated daily_limit_enabled: {}", daily_limit_enabled);
+}
+
 // ============================================================================
 // WITHDRAW HOUSE FEES INSTRUCTION
 // ============================================================================
@@ -693,16 +2682,15 @@ pub struct WithdrawHouseFees<'info> {
         mut,
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
-        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
-    /// House fee USDC token account
-    #[account(
-        mut,
-        seeds = [HOUSE_FEE_USDC_SEED],
-        bump
-    )]
+    /// House fee USDC token account to withdraw from: the original house fee
+    /// account for HOUSE_FEE_LEGACY_INDEX, otherwise the recipient's
+    /// sub-account (address verified in the handler)
+    #[account(mut)]
     pub house_fee_usdc: Account<'info, TokenAccount>,
 
     /// Destination USDC token account for withdrawn fees
@@ -711,6 +2699,18 @@ pub struct WithdrawHouseFees<'info> {
 
     /// Token program
     pub token_program: Program<'info, Token>,
+
+    /// House fee split config (required for recipient sub-account withdrawals)
+    #[account(
+        seeds = [FEE_RECIPIENTS_SEED],
+        bump = fee_recipients.bump
+    )]
+    pub fee_recipients: Option<Account<'info, HouseFeeRecipients>>,
+
+    /// The multisig cosigner (required once the window's withdrawals exceed
+    /// WITHDRAWAL_COSIGN_THRESHOLD_BPS of the daily cap while
+    /// `lottery_state.multisig_cosigner` is set)
+    pub optional_cosigner: Option<Signer<'info>>,
 }
 
 // ============================================================================
@@ -728,6 +2728,7 @@ pub struct CheckSolvency<'info> {
         mut,
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
@@ -738,9 +2739,9 @@ pub struct CheckSolvency<'info> {
     )]
     pub prize_pool_usdc: Account<'info, TokenAccount>,
 
-    /// Insurance pool USDC token account
+    /// Insurance tier 1 USDC token account
     #[account(
-        seeds = [INSURANCE_POOL_USDC_SEED],
+        seeds = [INSURANCE_TIER1_SEED],
         bump
     )]
     pub insurance_pool_usdc: Account<'info, TokenAccount>,
@@ -758,7 +2759,8 @@ pub struct CheckSolvency<'info> {
 ///
 /// # Checks performed:
 /// 1. Prize pool USDC balance >= jackpot_balance + reserve_balance
-/// 2. Insurance pool USDC balance >= insurance_balance
+/// 2. Insurance tier 1 USDC balance >= insurance_tier1_balance (tier 2 is not
+///    immediately accessible and is not counted)
 /// 3. All accounting values are non-negative (sanity)
 ///
 /// # Arguments
@@ -776,7 +2778,7 @@ pub fn handler_check_solvency(ctx: Context<CheckSolvency>) -> Result<()> {
     let expected_prize_pool = lottery_state
         .jackpot_balance
         .saturating_add(lottery_state.reserve_balance);
-    let expected_insurance = lottery_state.insurance_balance;
+    let expected_insurance = lottery_state.insurance_tier1_balance;
 
     // Allow a small tolerance for rounding dust (100 lamports = $0.0001)
     let tolerance: u64 = 100;
@@ -843,25 +2845,134 @@ pub fn handler_check_solvency(ctx: Context<CheckSolvency>) -> Result<()> {
     Ok(())
 }
 
+/// Address of the house fee account selected by `recipient_index`
+///
+/// HOUSE_FEE_LEGACY_INDEX selects the original house fee account; any other
+/// index needs `fee_recipients` and the recipient's created sub-account.
+pub(crate) fn house_fee_source_address(
+    fee_recipients: Option<&HouseFeeRecipients>,
+    recipient_index: u8,
+    program_id: &Pubkey,
+) -> Result<Pubkey> {
+    if recipient_index == HOUSE_FEE_LEGACY_INDEX {
+        return Ok(Pubkey::find_program_address(&[HOUSE_FEE_USDC_SEED], program_id).0);
+    }
+
+    let fee_recipients = fee_recipients.ok_or(LottoError::InvalidHouseFeeAccounts)?;
+    let sub_account_bump = fee_recipients
+        .sub_account_bumps
+        .get(recipient_index as usize)
+        .copied()
+        .filter(|&bump| bump != 0)
+        .ok_or(LottoError::InvalidRecipientIndex)?;
+    house_fee_sub_account_address(recipient_index, sub_account_bump, program_id)
+}
+
+/// Count a house fee withdrawal of `amount` against the daily window
+///
+/// With a nonzero `daily_withdrawal_cap_bps`, the window's withdrawals must
+/// stay within that share of `source_balance`, and any withdrawal that
+/// takes the window's total above WITHDRAWAL_COSIGN_THRESHOLD_BPS of the cap
+/// needs `cosigner` to be the multisig cosigner when one is set. Splitting a
+/// large withdrawal into smaller ones does not avoid the cosigner.
+pub(crate) fn record_house_fee_withdrawal(
+    lottery_state: &mut LotteryState,
+    source_balance: u64,
+    amount: u64,
+    cosigner: Option<Pubkey>,
+    now: i64,
+) -> Result<()> {
+    lottery_state.roll_withdrawal_window(now);
+    let withdrawn = lottery_state
+        .withdrawals_in_window
+        .checked_add(amount)
+        .ok_or(LottoError::Overflow)?;
+    if let Some(cap) =
+        house_fee_withdrawal_cap(source_balance, lottery_state.daily_withdrawal_cap_bps)
+    {
+        require!(withdrawn <= cap, LottoError::DailyWithdrawalCapExceeded);
+
+        let multisig_cosigner = lottery_state.multisig_cosigner;
+        require!(
+            multisig_cosigner.is_none()
+                || !requires_withdrawal_cosign(withdrawn, cap)
+                || cosigner == multisig_cosigner,
+            LottoError::CosignerRequired
+        );
+
+        if is_withdrawal_cap_alert(withdrawn, cap) {
+            emit!(DailyWithdrawalCapApproaching {
+                withdrawn,
+                cap,
+                remaining: cap.saturating_sub(withdrawn),
+            });
+        }
+    }
+    lottery_state.withdrawals_in_window = withdrawn;
+    Ok(())
+}
+
 /// Withdraw accumulated house fees
 ///
 /// This instruction allows the authority to withdraw house fees to a treasury account.
+/// When a house fee split is configured, each recipient's sub-account is
+/// withdrawn separately and only to a token account owned by that recipient.
+///
+/// With a nonzero `daily_withdrawal_cap_bps`, withdrawals per
+/// HOUSE_FEE_WITHDRAWAL_WINDOW_SECONDS are capped at that share of the
+/// source account's balance, and once the window's withdrawals exceed
+/// WITHDRAWAL_COSIGN_THRESHOLD_BPS of the cap each further withdrawal also
+/// needs the multisig cosigner's signature when one is set.
 ///
 /// # Arguments
 /// * `ctx` - The context containing required accounts
 /// * `amount` - Amount to withdraw (must be <= balance)
+/// * `recipient_index` - Recipient sub-account to withdraw from, or
+///   HOUSE_FEE_LEGACY_INDEX for the original house fee account
 ///
 /// # Returns
 /// * `Result<()>` - Success or error
-pub fn handler_withdraw_house_fees(ctx: Context<WithdrawHouseFees>, amount: u64) -> Result<()> {
+pub fn handler_withdraw_house_fees(
+    ctx: Context<WithdrawHouseFees>,
+    amount: u64,
+    recipient_index: u8,
+) -> Result<()> {
     let clock = Clock::get()?;
 
+    // Resolve the source account for the recipient index
+    let fee_recipients = ctx.accounts.fee_recipients.as_deref();
+    let expected_source =
+        house_fee_source_address(fee_recipients, recipient_index, ctx.program_id)?;
+
+    // Fees for an active recipient can only be paid out to that recipient
+    if let Some(recipient) = fee_recipients
+        .and_then(|fee_recipients| fee_recipients.recipients.get(recipient_index as usize))
+    {
+        require!(
+            ctx.accounts.destination_usdc.owner == recipient.recipient,
+            LottoError::TokenAccountOwnerMismatch
+        );
+    }
+    require!(
+        ctx.accounts.house_fee_usdc.key() == expected_source,
+        LottoError::InvalidHouseFeeAccounts
+    );
+
     // Validate amount
     require!(
         amount > 0 && amount <= ctx.accounts.house_fee_usdc.amount,
         LottoError::InsufficientFunds
     );
 
+    // Daily withdrawal cap
+    record_house_fee_withdrawal(
+        &mut ctx.accounts.lottery_state,
+        ctx.accounts.house_fee_usdc.amount,
+        amount,
+        ctx.accounts.optional_cosigner.as_ref().map(|s| s.key()),
+        clock.unix_timestamp,
+    )?;
+
     // Transfer from house fee account to destination
     let seeds = &[LOTTERY_SEED, &[ctx.accounts.lottery_state.bump]];
     let signer_seeds = &[&seeds[..]];
@@ -910,7 +3021,8 @@ pub struct ProposeAuthority<'info> {
         mut,
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
-        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 }
@@ -980,7 +3092,8 @@ pub struct AcceptAuthority<'info> {
         mut,
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
-        constraint = lottery_state.pending_authority == Some(new_authority.key()) @ LottoError::Unauthorized
+        constraint = lottery_state.pending_authority == Some(new_authority.key()) @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 }
@@ -1037,7 +3150,8 @@ pub struct CancelAuthorityTransfer<'info> {
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
         constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
-        constraint = lottery_state.pending_authority.is_some() @ LottoError::InvalidDrawState
+        constraint = lottery_state.pending_authority.is_some() @ LottoError::InvalidDrawState,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 }
@@ -1063,6 +3177,280 @@ pub fn handler_cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>)
     Ok(())
 }
 
+// ============================================================================
+// SET OPERATOR (DRAW EXECUTION DELEGATION)
+// ============================================================================
+
+/// Accounts required for setting the draw operator
+#[derive(Accounts)]
+pub struct SetOperator<'info> {
+    /// The current authority
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+/// Set or clear the draw operator
+///
+/// The operator may run the draw steps granted in `permissions`
+/// (`commit_randomness`, `execute_draw`). It can never pause, withdraw
+/// house fees, update config, transfer authority or move emergency funds;
+/// those instructions remain authority-only. Passing `None` clears the
+/// operator and its permissions.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `operator` - The operator address, or `None` to remove it
+/// * `permissions` - `OPERATOR_PERMISSION_*` bitmask
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_set_operator(
+    ctx: Context<SetOperator>,
+    operator: Option<Pubkey>,
+    permissions: u64,
+) -> Result<()> {
+    let lottery_state = &mut ctx.accounts.lottery_state;
+
+    require!(
+        is_valid_operator_permissions(permissions),
+        LottoError::InvalidOperator
+    );
+
+    let permissions = match operator {
+        Some(operator) => {
+            require!(
+                operator != Pubkey::default() && operator != lottery_state.authority,
+                LottoError::InvalidOperator
+            );
+            permissions
+        }
+        None => 0,
+    };
+
+    lottery_state.operator = operator;
+    lottery_state.operator_permissions = permissions;
+
+    emit!(OperatorSet {
+        new_operator: operator,
+        permissions_mask: permissions,
+    });
+
+    msg!("Operator updated!");
+    msg!("  Operator: {:?}", operator);
+    msg!("  Permissions: {:#b}", permissions);
+
+    Ok(())
+}
+
+// ============================================================================
+// SET MULTISIG COSIGNER
+// ============================================================================
+
+/// Accounts required for changing the emergency transfer cosigner
+#[derive(Accounts)]
+pub struct SetMultisigCosigner<'info> {
+    /// The current authority
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+/// Propose or apply a change to the multisig cosigner
+///
+/// The first call with a new value starts a MULTISIG_COSIGNER_TIMELOCK_DELAY
+/// (48 hour) timelock. Calling again with the same value once it has passed
+/// applies the change. Calling with a different value replaces the pending
+/// proposal and restarts the timelock.
+///
+/// While a cosigner is set, PrizePool emergency fund transfers must be
+/// signed by both the authority and the cosigner.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `cosigner` - The cosigner address, or `None` to remove it
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_set_multisig_cosigner(
+    ctx: Context<SetMultisigCosigner>,
+    cosigner: Option<Pubkey>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let lottery_state = &mut ctx.accounts.lottery_state;
+
+    if let Some(cosigner) = cosigner {
+        require!(
+            cosigner != Pubkey::default() && cosigner != lottery_state.authority,
+            LottoError::InvalidCosigner
+        );
+    }
+
+    let is_pending = lottery_state.multisig_cosigner_timelock_end != 0
+        && lottery_state.pending_multisig_cosigner == cosigner;
+
+    if !is_pending {
+        require!(
+            cosigner != lottery_state.multisig_cosigner,
+            LottoError::InvalidCosigner
+        );
+
+        let executable_after = clock
+            .unix_timestamp
+            .saturating_add(MULTISIG_COSIGNER_TIMELOCK_DELAY);
+        lottery_state.pending_multisig_cosigner = cosigner;
+        lottery_state.multisig_cosigner_timelock_end = executable_after;
+
+        emit!(CosignerChangeProposed {
+            proposed: cosigner,
+            executable_after,
+        });
+
+        msg!("⏳ Multisig cosigner change PROPOSED (timelock started)");
+        msg!("  Proposed cosigner: {:?}", cosigner);
+        msg!("  Executable after: {}", executable_after);
+
+        return Ok(());
+    }
+
+    require!(
+        clock.unix_timestamp >= lottery_state.multisig_cosigner_timelock_end,
+        LottoError::InvalidTimestamp
+    );
+
+    let old = lottery_state.multisig_cosigner;
+    lottery_state.multisig_cosigner = cosigner;
+    lottery_state.pending_multisig_cosigner = None;
+    lottery_state.multisig_cosigner_timelock_end = 0;
+
+    emit!(CosignerSet { old, new: cosigner });
+
+    msg!("✅ Multisig cosigner updated!");
+    msg!("  Cosigner: {:?} -> {:?}", old, cosigner);
+
+    Ok(())
+}
+
+// ============================================================================
+// RECONCILE PRIZE POOL ACCOUNTING
+// ============================================================================
+
+/// Accounts required for reconciling the fixed prize balance
+#[derive(Accounts)]
+pub struct ReconcilePrizePool<'info> {
+    /// The lottery authority
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account (must be paused)
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_paused @ LottoError::InvalidDrawState,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+/// Propose or run a correction of accounting drift in `fixed_prize_balance`
+///
+/// The first call starts a CONFIG_TIMELOCK_DELAY (24 hour) timelock. Calling
+/// again once it has passed resets `fixed_prize_balance` to
+/// `expected_fixed_prize_balance` and moves the difference into or out of
+/// `reserve_balance`, so no USDC moves and the internal total is unchanged.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_reconcile_prize_pool_accounting(ctx: Context<ReconcilePrizePool>) -> Result<()> {
+    let clock = Clock::get()?;
+    let lottery_state = &mut ctx.accounts.lottery_state;
+
+    if lottery_state.reconcile_timelock_end == 0 {
+        lottery_state.reconcile_timelock_end =
+            clock.unix_timestamp.saturating_add(CONFIG_TIMELOCK_DELAY);
+
+        msg!("⏳ Prize pool reconciliation PROPOSED (timelock started)");
+        msg!(
+            "  Executable after: {}",
+            lottery_state.reconcile_timelock_end
+        );
+
+        return Ok(());
+    }
+
+    require!(
+        clock.unix_timestamp >= lottery_state.reconcile_timelock_end,
+        LottoError::InvalidTimestamp
+    );
+
+    let old_fixed_prize_balance = lottery_state.fixed_prize_balance;
+    let new_fixed_prize_balance = expected_fixed_prize_balance(
+        lottery_state.total_tickets_sold,
+        lottery_state.total_fixed_prizes_claimed,
+    );
+
+    // The reserve absorbs the correction in the opposite direction
+    if new_fixed_prize_balance >= old_fixed_prize_balance {
+        let shortfall = new_fixed_prize_balance - old_fixed_prize_balance;
+        lottery_state.reserve_balance = lottery_state
+            .reserve_balance
+            .checked_sub(shortfall)
+            .ok_or(LottoError::InsufficientReserve)?;
+    } else {
+        let surplus = old_fixed_prize_balance - new_fixed_prize_balance;
+        lottery_state.reserve_balance = lottery_state
+            .reserve_balance
+            .checked_add(surplus)
+            .ok_or(LottoError::Overflow)?;
+    }
+    lottery_state.fixed_prize_balance = new_fixed_prize_balance;
+    lottery_state.reconcile_timelock_end = 0;
+
+    let discrepancy = (new_fixed_prize_balance as i128 - old_fixed_prize_balance as i128)
+        .clamp(i64::MIN as i128, i64::MAX as i128) as i64;
+
+    emit!(AccountingReconciled {
+        old_fixed_prize_balance,
+        new_fixed_prize_balance,
+        discrepancy,
+        reconciled_at: clock.unix_timestamp,
+    });
+
+    msg!("✅ Prize pool accounting reconciled!");
+    msg!(
+        "  Fixed prize balance: {} -> {} USDC lamports",
+        old_fixed_prize_balance,
+        new_fixed_prize_balance
+    );
+    msg!("  Discrepancy: {} USDC lamports", discrepancy);
+    msg!(
+        "  Reserve balance: {} USDC lamports",
+        lottery_state.reserve_balance
+    );
+
+    Ok(())
+}
+
 // ============================================================================
 // CANCEL DRAW (TIMEOUT RECOVERY)
 // ============================================================================
@@ -1080,9 +3468,33 @@ pub struct CancelDraw<'info> {
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
         constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
-        constraint = lottery_state.is_draw_in_progress @ LottoError::DrawNotInProgress
+        constraint = lottery_state.is_draw_in_progress @ LottoError::DrawNotInProgress,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
+
+    /// Refund record for the cancelled draw, required only with
+    /// `CancelDrawMode::RefundTickets`
+    #[account(
+        init,
+        payer = authority,
+        space = TICKET_REFUND_RECORD_SIZE,
+        seeds = [REFUND_RECORD_SEED, &lottery_state.current_draw_id.to_le_bytes()],
+        bump
+    )]
+    pub refund_record: Option<Account<'info, TicketRefundRecord>>,
+
+    /// System program, required only with `CancelDrawMode::RefundTickets`
+    pub system_program: Option<Program<'info, System>>,
+
+    /// Recurring ticket schedule (required while recurring tickets are
+    /// pending; the next draw starts with the ones bought for it)
+    #[account(
+        mut,
+        seeds = [RECURRING_SCHEDULE_SEED],
+        bump = recurring_schedule.bump
+    )]
+    pub recurring_schedule: Option<Box<Account<'info, RecurringTicketSchedule>>>,
 }
 
 /// Cancel a stuck draw (timeout recovery)
@@ -1097,57 +3509,103 @@ pub struct CancelDraw<'info> {
 /// - Any other issue causes the draw to get stuck
 ///
 /// # Ticket Handling
-/// When a draw is cancelled:
-/// - If execute_draw was NOT called yet: The draw_id is NOT incremented,
-///   so tickets remain valid for the rescheduled draw with the same draw_id.
+/// - `PreserveTickets`: The draw_id is NOT incremented, so tickets remain
+///   valid for the rescheduled draw with the same draw_id.
+/// - `RefundTickets`: The draw is abandoned. A `TicketRefundRecord` is
+///   created for it, the draw_id is incremented and each ticket counted in
+///   the draw can be refunded TICKET_PRICE from the reserve: single tickets
+///   via `claim_cancelled_draw_refund`, bulk tickets via
+///   `claim_cancelled_draw_bulk_refund` and multi-draw tickets starting at
+///   this draw via `claim_cancelled_draw_multi_draw_refund`.
+///   Only allowed before execute_draw has run.
 /// - If execute_draw WAS called but finalize failed: This should use
 ///   force_finalize_draw instead, as a DrawResult account already exists.
 ///
 /// # Security
 /// - Only the authority can cancel draws
 /// - The draw must have timed out (1 hour since commit)
-/// - Tickets with matching draw_id remain valid
+/// - Refunds must be covered by the reserve at cancellation time
 ///
 /// # Arguments
 /// * `ctx` - The context containing required accounts
+/// * `mode` - Whether to carry the tickets over or refund them
 ///
 /// # Returns
 /// * `Result<()>` - Success or error
-pub fn handler_cancel_draw(ctx: Context<CancelDraw>) -> Result<()> {
+pub fn handler_cancel_draw(ctx: Context<CancelDraw>, mode: CancelDrawMode) -> Result<()> {
     let clock = Clock::get()?;
-    let lottery_state = &mut ctx.accounts.lottery_state;
 
     // Verify the draw has timed out
     require!(
-        lottery_state.is_commit_timed_out(clock.unix_timestamp),
+        ctx.accounts
+            .lottery_state
+            .is_commit_timed_out(clock.unix_timestamp),
         LottoError::DrawNotReady
     );
 
+    let refund_tickets = mode == CancelDrawMode::RefundTickets;
+    require!(
+        ctx.accounts.refund_record.is_some() == refund_tickets,
+        LottoError::InvalidRefundRecord
+    );
+
+    let lottery_state = &mut ctx.accounts.lottery_state;
     let draw_id = lottery_state.current_draw_id;
     let commit_timestamp = lottery_state.commit_timestamp;
     let tickets_affected = lottery_state.current_draw_tickets;
 
+    if refund_tickets {
+        require!(
+            lottery_state.execute_draw_timestamp == 0,
+            LottoError::DrawAlreadyExecuted
+        );
+
+        let total_refundable = total_refundable_for(tickets_affected)?;
+        require!(
+            lottery_state.reserve_balance >= total_refundable,
+            LottoError::InsufficientReserveForRefund
+        );
+
+        if let Some(refund_record) = ctx.accounts.refund_record.as_mut() {
+            refund_record.draw_id = draw_id;
+            refund_record.total_refundable = total_refundable;
+            refund_record.total_refunded = 0;
+            refund_record.is_active = total_refundable > 0;
+            refund_record.bump = ctx
+                .bumps
+                .refund_record
+                .ok_or(LottoError::InvalidRefundRecord)?;
+        }
+    }
+
     // Reset draw state using the helper method
-    // IMPORTANT: We do NOT increment draw_id here so tickets remain valid
     lottery_state.reset_draw_state();
 
-    // Do NOT reset current_draw_tickets - they remain for the rescheduled draw
-    // Do NOT increment current_draw_id - tickets are for this draw_id
+    if refund_tickets {
+        // Abandon this draw_id; its tickets are refunded instead of drawn
+        lottery_state.open_next_draw(
+            ctx.accounts
+                .recurring_schedule
+                .as_deref_mut()
+                .map(|schedule| &mut **schedule),
+        )?;
+    }
+    // Otherwise do NOT reset current_draw_tickets or increment current_draw_id -
+    // the tickets remain for the rescheduled draw
 
-    // Schedule next draw attempt (same draw_id, just new timing)
+    // Schedule next draw attempt
     lottery_state.next_draw_timestamp = clock.unix_timestamp + lottery_state.draw_interval;
 
     // Emit cancellation event
     emit!(DrawCancelled {
         draw_id,
         tickets_affected,
+        mode,
         timestamp: clock.unix_timestamp,
         reason: "Timeout - randomness reveal failed".to_string(),
     });
 
     msg!("Draw cancelled due to timeout!");
-    msg!("  Draw ID: {} (unchanged - tickets remain valid)", draw_id);
-    msg!("  Tickets in draw: {} (preserved)", tickets_affected);
     msg!("  Commit timestamp: {}", commit_timestamp);
     msg!("  Current timestamp: {}", clock.unix_timestamp);
     msg!(
@@ -1158,11 +3616,107 @@ pub fn handler_cancel_draw(ctx: Context<CancelDraw>) -> Result<()> {
         "  Next draw attempt scheduled for: {}",
         lottery_state.next_draw_timestamp
     );
+
+    if refund_tickets {
+        msg!("  Draw ID: {} (abandoned)", draw_id);
+        msg!("  Tickets in draw: {} (refundable)", tickets_affected);
+        msg!("  Next draw ID: {}", lottery_state.current_draw_id);
+        msg!(
+            "  ✓ Tickets for draw {} can claim a refund via claim_cancelled_draw_refund, claim_cancelled_draw_bulk_refund or claim_cancelled_draw_multi_draw_refund.",
+            draw_id
+        );
+    } else {
+        msg!("  Draw ID: {} (unchanged - tickets remain valid)", draw_id);
+        msg!("  Tickets in draw: {} (preserved)", tickets_affected);
+        msg!(
+            "  ✓ Tickets for draw {} remain valid for the rescheduled draw.",
+            draw_id
+        );
+        msg!("  ✓ No refunds needed - same draw will be attempted again.");
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// POSTPONE DRAW (MINIMUM TICKETS NOT MET)
+// ============================================================================
+
+/// Accounts required for postponing a draw
+#[derive(Accounts)]
+pub struct PostponeDraw<'info> {
+    /// The authority postponing the draw
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = !lottery_state.is_draw_in_progress @ LottoError::DrawInProgress,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+/// Postpone the current draw when the minimum ticket count has not been met
+///
+/// Advances `next_draw_timestamp` by `extension_seconds` without touching
+/// `current_draw_id`, so every ticket already purchased stays in the draw.
+/// If the scheduled draw time has already passed, the extension is measured
+/// from the current time so ticket sales reopen.
+///
+/// # Security
+/// - Only the authority can postpone draws
+/// - Only allowed while current_draw_tickets < min_tickets_per_draw
+/// - extension_seconds must be in (0, draw_interval]
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `extension_seconds` - How far to push back the draw
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_postpone_draw(ctx: Context<PostponeDraw>, extension_seconds: i64) -> Result<()> {
+    let clock = Clock::get()?;
+    let lottery_state = &mut ctx.accounts.lottery_state;
+
+    require!(
+        extension_seconds > 0 && extension_seconds <= lottery_state.draw_interval,
+        LottoError::InvalidDuration
+    );
+    require!(
+        lottery_state.current_draw_tickets < lottery_state.min_tickets_per_draw,
+        LottoError::MinTicketsAlreadyMet
+    );
+
+    let draw_id = lottery_state.current_draw_id;
+    let old_timestamp = lottery_state.next_draw_timestamp;
+    let new_timestamp = old_timestamp
+        .max(clock.unix_timestamp)
+        .checked_add(extension_seconds)
+        .ok_or(LottoError::Overflow)?;
+
+    // Do NOT increment current_draw_id - tickets remain valid for this draw
+    lottery_state.next_draw_timestamp = new_timestamp;
+
+    emit!(DrawPostponed {
+        draw_id,
+        old_timestamp,
+        new_timestamp,
+        reason: "Minimum ticket count not met".to_string(),
+    });
+
+    msg!("Draw postponed!");
+    msg!("  Draw ID: {} (unchanged - tickets remain valid)", draw_id);
     msg!(
-        "  ✓ Tickets for draw {} remain valid for the rescheduled draw.",
-        draw_id
+        "  Tickets in draw: {} (minimum {})",
+        lottery_state.current_draw_tickets,
+        lottery_state.min_tickets_per_draw
     );
-    msg!("  ✓ No refunds needed - same draw will be attempted again.");
+    msg!("  Old draw time: {}", old_timestamp);
+    msg!("  New draw time: {}", new_timestamp);
 
     Ok(())
 }
@@ -1174,7 +3728,8 @@ pub fn handler_cancel_draw(ctx: Context<CancelDraw>) -> Result<()> {
 /// Accounts required for force finalizing a draw
 #[derive(Accounts)]
 pub struct ForceFinalizeDraw<'info> {
-    /// The authority force finalizing
+    /// The signer force finalizing. Must be the lottery authority unless the
+    /// executed draw is overdue for finalization (then anyone may call).
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -1183,8 +3738,11 @@ pub struct ForceFinalizeDraw<'info> {
         mut,
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
-        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
-        constraint = lottery_state.is_draw_in_progress @ LottoError::DrawNotInProgress
+        constraint = lottery_state.authority == authority.key()
+            || lottery_state.is_finalize_overdue(Clock::get()?.unix_timestamp)
+            @ LottoError::Unauthorized,
+        constraint = lottery_state.is_draw_in_progress @ LottoError::DrawNotInProgress,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
@@ -1199,9 +3757,19 @@ pub struct ForceFinalizeDraw<'info> {
         mut,
         seeds = [DRAW_SEED, &lottery_state.current_draw_id.to_le_bytes()],
         bump = draw_result.bump,
-        constraint = draw_result.draw_id == lottery_state.current_draw_id @ LottoError::DrawIdMismatch
+        constraint = draw_result.draw_id == lottery_state.current_draw_id @ LottoError::DrawIdMismatch,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub draw_result: Account<'info, DrawResult>,
+
+    /// Recurring ticket schedule (required while recurring tickets are
+    /// pending; the next draw starts with the ones bought for it)
+    #[account(
+        mut,
+        seeds = [RECURRING_SCHEDULE_SEED],
+        bump = recurring_schedule.bump
+    )]
+    pub recurring_schedule: Option<Box<Account<'info, RecurringTicketSchedule>>>,
 }
 
 /// Force finalize a draw without winner distribution (emergency only)
@@ -1212,6 +3780,13 @@ pub struct ForceFinalizeDraw<'info> {
 /// - The draw result account couldn't be created
 /// - Manual intervention is needed
 ///
+/// If `finalize_draw` has not been called within `FINALIZE_TIMEOUT_SECONDS`
+/// plus `GRACE_PERIOD_SECONDS` of `execute_draw`, any signer may call this
+/// so the authority cannot hold a draw hostage. Before then the authority's
+/// signature is required. A permissionless caller is paid
+/// `force_finalize_reward_lamports` from the lottery state's spare lamports
+/// (skipped, not failed, when the balance is too low).
+///
 /// After force finalization:
 /// - The jackpot remains intact
 /// - Tickets are NOT refunded (users must be compensated off-chain if needed)
@@ -1253,15 +3828,27 @@ pub fn handler_force_finalize_draw(ctx: Context<ForceFinalizeDraw>, reason: Stri
     draw_result.total_committed = 0;
     draw_result.total_reclaimed = 0;
 
+    // Starts the waiting period before locked syndicates can be unlocked
+    draw_result.force_finalized_at = clock.unix_timestamp;
+
     let lottery_state = &mut ctx.accounts.lottery_state;
 
     let draw_id = lottery_state.current_draw_id;
     let tickets_affected = lottery_state.current_draw_tickets;
+    let caller = ctx.accounts.authority.key();
+    let is_permissionless = caller != lottery_state.authority;
+    let elapsed_seconds = clock
+        .unix_timestamp
+        .saturating_sub(lottery_state.execute_draw_timestamp);
 
     // Reset draw state
     lottery_state.reset_draw_state();
-    lottery_state.current_draw_id = lottery_state.current_draw_id.saturating_add(1);
-    lottery_state.current_draw_tickets = 0;
+    lottery_state.open_next_draw(
+        ctx.accounts
+            .recurring_schedule
+            .as_deref_mut()
+            .map(|schedule| &mut **schedule),
+    )?;
     lottery_state.next_draw_timestamp = clock.unix_timestamp + lottery_state.draw_interval;
 
     // Update house fee based on new jackpot level
@@ -1271,12 +3858,40 @@ pub fn handler_force_finalize_draw(ctx: Context<ForceFinalizeDraw>, reason: Stri
     emit!(DrawForceFinalized {
         draw_id,
         tickets_affected,
-        authority: ctx.accounts.authority.key(),
+        authority: caller,
         reason: reason.clone(),
         timestamp: clock.unix_timestamp,
     });
 
-    msg!("⚠️  Draw FORCE FINALIZED by authority!");
+    if is_permissionless {
+        // Pay the caller from the lottery state's spare lamports, as for keepers
+        let lottery_state_info = lottery_state.to_account_info();
+        let bounty = lottery_state.force_finalize_reward_lamports;
+        let reward_paid = if bounty <= lamports_above_rent(&lottery_state_info)? {
+            bounty
+        } else {
+            msg!("Keeper reserve too low - force finalize bounty skipped");
+            0
+        };
+        if reward_paid > 0 {
+            let caller_info = ctx.accounts.authority.to_account_info();
+            **lottery_state_info.try_borrow_mut_lamports()? -= reward_paid;
+            **caller_info.try_borrow_mut_lamports()? += reward_paid;
+        }
+
+        emit!(DrawAutoForceFinalized {
+            draw_id,
+            caller,
+            elapsed_seconds,
+            reward_paid,
+        });
+        msg!("⚠️  Draw FORCE FINALIZED after finalize timeout!");
+        msg!("  Caller: {}", caller);
+        msg!("  Seconds since execute_draw: {}", elapsed_seconds);
+        msg!("  Bounty: {} lamports", reward_paid);
+    } else {
+        msg!("⚠️  Draw FORCE FINALIZED by authority!");
+    }
     msg!("  Draw ID: {}", draw_id);
     msg!("  Tickets affected: {}", tickets_affected);
     msg!("  Reason: {}", reason);
@@ -1311,7 +3926,8 @@ pub struct TransferAuthority<'info> {
         mut,
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
-        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 }
@@ -1387,20 +4003,25 @@ pub struct EmergencyFundTransfer<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    /// The multisig cosigner (required for PrizePool transfers while
+    /// `lottery_state.multisig_cosigner` is set)
+    pub optional_cosigner: Option<Signer<'info>>,
+
     /// The main lottery state account
     #[account(
         mut,
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
         constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
-        constraint = lottery_state.is_paused @ LottoError::InvalidDrawState
+        constraint = lottery_state.is_paused @ LottoError::InvalidDrawState,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
-    /// Insurance pool USDC token account (source for Insurance transfers)
+    /// Insurance tier 1 USDC token account (source for Insurance transfers)
     #[account(
         mut,
-        seeds = [INSURANCE_POOL_USDC_SEED],
+        seeds = [INSURANCE_TIER1_SEED],
         bump
     )]
     pub insurance_pool_usdc: Account<'info, TokenAccount>,
@@ -1433,7 +4054,8 @@ pub struct EmergencyFundTransfer<'info> {
 /// # Security Requirements:
 /// - Only callable by authority
 /// - Lottery must be paused
-/// - Requires multi-sig in production (not enforced in code)
+/// - PrizePool transfers require the multisig cosigner's signature too,
+///   when one is set (see `set_multisig_cosigner`)
 /// - Should have timelock in production (not enforced in code)
 /// - Emits detailed audit event
 ///
@@ -1457,6 +4079,7 @@ pub fn handler_emergency_fund_transfer(
     reason: String,
 ) -> Result<()> {
     let clock = Clock::get()?;
+    let state_before = LotteryStateSnapshot::capture(&ctx.accounts.lottery_state);
 
     // Validate lottery is paused for emergency operations
     require!(
@@ -1467,6 +4090,15 @@ pub fn handler_emergency_fund_transfer(
     // Validate amount
     require!(amount > 0, LottoError::InsufficientFunds);
 
+    // PrizePool transfers leave the protocol, so they need the cosigner too
+    let multisig_cosigner = ctx.accounts.lottery_state.multisig_cosigner;
+    require!(
+        multisig_cosigner.is_none()
+            || matches!(source, FundSource::Reserve | FundSource::Insurance)
+            || ctx.accounts.optional_cosigner.as_ref().map(|s| s.key()) == multisig_cosigner,
+        LottoError::CosignerRequired
+    );
+
     // SECURITY FIX (Issue #5): Cap the maximum per-call transfer amount for
     // PrizePool source to limit damage from a compromised authority.
     // Reserve and Insurance transfers stay within the protocol (pool-to-pool),
@@ -1562,13 +4194,13 @@ pub fn handler_emergency_fund_transfer(
         }
         FundSource::Insurance => {
             // Insurance has its own token account - transfer actual USDC to prize pool
-            let before = lottery_state.insurance_balance;
+            let before = lottery_state.insurance_tier1_balance;
             require!(
                 amount <= ctx.accounts.insurance_pool_usdc.amount,
                 LottoError::InsufficientFunds
             );
             require!(
-                amount <= lottery_state.insurance_balance,
+                amount <= lottery_state.insurance_tier1_balance,
                 LottoError::InsufficientFunds
             );
 
@@ -1583,15 +4215,15 @@ pub fn handler_emergency_fund_transfer(
             token::transfer(cpi_ctx, amount)?;
 
             // Update accounting
-            lottery_state.insurance_balance =
-                lottery_state.insurance_balance.saturating_sub(amount);
+            lottery_state.insurance_tier1_balance =
+                lottery_state.insurance_tier1_balance.saturating_sub(amount);
             // Add to jackpot to make it available for prizes
             lottery_state.jackpot_balance = lottery_state
                 .jackpot_balance
                 .checked_add(amount)
                 .ok_or(LottoError::Overflow)?;
 
-            let after = lottery_state.insurance_balance;
+            let after = lottery_state.insurance_tier1_balance;
             (before, after, "insurance_to_prize_pool".to_string())
         }
         FundSource::PrizePool => {
@@ -1673,13 +4305,15 @@ pub fn handler_emergency_fund_transfer(
     );
     msg!(
         "    Insurance balance: {} USDC lamports",
-        lottery_state.insurance_balance
+        lottery_state.insurance_tier1_balance
     );
     msg!(
         "    Safety buffer (reserve + insurance): {} USDC lamports",
         lottery_state.get_safety_buffer()
     );
 
+    emit_state_diff(&state_before, lottery_state);
+
     Ok(())
 }
 
@@ -1711,7 +4345,8 @@ pub struct ReclaimExpiredPrizes<'info> {
         mut,
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
-        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
@@ -1724,7 +4359,8 @@ pub struct ReclaimExpiredPrizes<'info> {
         mut,
         seeds = [DRAW_SEED, &params.draw_id.to_le_bytes()],
         bump = draw_result.bump,
-        constraint = draw_result.draw_id == params.draw_id @ LottoError::DrawIdMismatch
+        constraint = draw_result.draw_id == params.draw_id @ LottoError::DrawIdMismatch,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub draw_result: Account<'info, DrawResult>,
 }
@@ -0,0 +1,119 @@
+//! Lottery Analytics Instructions
+//!
+//! A singleton `LotteryAnalytics`, created by `initialize`, keeps lifetime
+//! statistics for dashboards. `finalize_draw` counts draws, rolldowns and
+//! jackpots won; `buy_ticket` and `buy_bulk` track the largest jackpot and
+//! count players, all-time and per ANALYTICS_SEASON_SECONDS season.
+//!
+//! - get_analytics: Emit the analytics
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::LotteryAnalyticsSnapshot;
+use crate::state::LotteryAnalytics;
+
+/// Accounts required for reading the lottery analytics
+#[derive(Accounts)]
+pub struct GetAnalytics<'info> {
+    /// The lottery analytics
+    #[account(
+        seeds = [ANALYTICS_SEED],
+        bump = lottery_analytics.bump
+    )]
+    pub lottery_analytics: Box<Account<'info, LotteryAnalytics>>,
+}
+
+/// Emit the lottery analytics
+///
+/// Permissionless and read-only; emits `LotteryAnalyticsSnapshot`.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_get_analytics(ctx: Context<GetAnalytics>) -> Result<()> {
+    let analytics = &ctx.accounts.lottery_analytics;
+
+    emit!(LotteryAnalyticsSnapshot {
+        total_draws: analytics.total_draws,
+        total_jackpots_won: analytics.total_jackpots_won,
+        largest_jackpot_ever: analytics.largest_jackpot_ever,
+        largest_jackpot_draw_id: analytics.largest_jackpot_draw_id,
+        total_rolldowns_executed: analytics.total_rolldowns_executed,
+        total_prizes_ever_paid: analytics.total_prizes_ever_paid,
+        all_time_player_count: analytics.all_time_player_count,
+        current_season_player_count: analytics.current_season_player_count,
+        season_number: analytics.season_number,
+        season_start: analytics.season_start,
+    });
+
+    msg!("Lottery analytics snapshot");
+    msg!("  Draws: {}", analytics.total_draws);
+    msg!("  Jackpots won: {}", analytics.total_jackpots_won);
+    msg!("  Rolldowns: {}", analytics.total_rolldowns_executed);
+    msg!(
+        "  Largest jackpot: {} (draw {})",
+        analytics.largest_jackpot_ever,
+        analytics.largest_jackpot_draw_id
+    );
+    msg!("  Players: {} all-time", analytics.all_time_player_count);
+    msg!(
+        "  Season {}: {} players",
+        analytics.season_number,
+        analytics.current_season_player_count
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::UserStats;
+
+    fn analytics_at(season_start: i64) -> LotteryAnalytics {
+        LotteryAnalytics {
+            season_number: 1,
+            season_start,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_record_player_counts_once_per_season() {
+        let mut analytics = analytics_at(0);
+        let mut user_stats = UserStats::default();
+
+        analytics.record_player(&mut user_stats, 100);
+        user_stats.total_tickets = 1;
+        analytics.record_player(&mut user_stats, 200);
+        assert_eq!(analytics.all_time_player_count, 1);
+        assert_eq!(analytics.current_season_player_count, 1);
+
+        // A new season resets the season count; the player counts again
+        analytics.record_player(&mut user_stats, ANALYTICS_SEASON_SECONDS);
+        assert_eq!(analytics.season_number, 2);
+        assert_eq!(analytics.season_start, ANALYTICS_SEASON_SECONDS);
+        assert_eq!(analytics.all_time_player_count, 1);
+        assert_eq!(analytics.current_season_player_count, 1);
+    }
+
+    #[test]
+    fn test_record_jackpot_and_finalized_draw() {
+        let mut analytics = analytics_at(0);
+
+        analytics.record_jackpot(5_000, 1);
+        analytics.record_jackpot(4_000, 2);
+        assert_eq!(analytics.largest_jackpot_ever, 5_000);
+        assert_eq!(analytics.largest_jackpot_draw_id, 1);
+
+        analytics.record_finalized_draw(false, true, 100);
+        analytics.record_finalized_draw(true, false, 300);
+        assert_eq!(analytics.total_draws, 2);
+        assert_eq!(analytics.total_jackpots_won, 1);
+        assert_eq!(analytics.total_rolldowns_executed, 1);
+        assert_eq!(analytics.total_prizes_ever_paid, 300);
+    }
+}
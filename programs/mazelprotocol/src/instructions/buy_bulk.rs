@@ -4,7 +4,7 @@
 //! It handles:
 //! - Number validation for all tickets
 //! - Per-user ticket limits enforcement
-//! - USDC transfer (player -> prize pool + house fee)
+//! - USDC transfer (player -> prize pool + house fee, split across fee recipients)
 //! - Unified ticket account creation for efficient storage
 //! - User stats updates
 //! - Fee calculation based on jackpot level
@@ -16,14 +16,36 @@ use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::constants::*;
 use crate::errors::LottoError;
-use crate::events::BulkTicketsPurchased;
-use crate::state::{LotteryState, UnifiedTicket, UserStats};
+use crate::events::{BulkTicketsPurchased, StreakMilestoneReached};
+use crate::instructions::buy_ticket::{
+    check_numbers_entropy, check_ticket_sale_cutoff, emit_jackpot_milestones, record_jackpot_ath,
+    resolve_ticket_price, route_jackpot_overflow, streak_discount, streak_milestone_for_purchase,
+    unlock_milestone_bonuses,
+};
+use crate::instructions::daily_limit::{
+    enforce_daily_limit, enforce_purchase_velocity, enforce_self_exclusion,
+    enforce_wallet_draw_limit,
+};
+use crate::instructions::house_fee::{
+    replenish_insurance_from_house_fee, route_house_fee, HouseFeeDestination, HouseFeeSource,
+};
+use crate::instructions::kyc::enforce_kyc;
+use crate::instructions::referral::credit_referrer_if_qualified;
+use crate::instructions::terms::enforce_terms_accepted;
+use crate::instructions::whitelist::enforce_whitelist;
+use crate::state::{
+    HouseFeeRecipients, KYCRecord, LotteryAnalytics, LotteryState, SchemaVersioned, UnifiedTicket,
+    UserStats, Whitelist,
+};
 
 /// Parameters for buying multiple tickets
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct BuyBulkParams {
     /// Array of ticket number sets, each containing 6 numbers between 1 and 46
     pub tickets: Vec<[u8; 6]>,
+    /// Highest per-ticket price the player accepts (slippage protection for
+    /// dynamic pricing)
+    pub max_price_usdc: u64,
 }
 
 /// Accounts required for buying multiple tickets
@@ -44,7 +66,8 @@ pub struct BuyBulk<'info> {
         bump = lottery_state.bump,
         constraint = !lottery_state.is_paused @ LottoError::Paused,
         constraint = lottery_state.is_funded @ LottoError::LotteryNotInitialized,
-        constraint = !lottery_state.is_draw_in_progress @ LottoError::DrawInProgress
+        constraint = !lottery_state.is_draw_in_progress @ LottoError::DrawInProgress,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Box<Account<'info, LotteryState>>,
 
@@ -87,14 +110,22 @@ pub struct BuyBulk<'info> {
     )]
     pub house_fee_usdc: Account<'info, TokenAccount>,
 
-    /// Insurance pool USDC token account
+    /// Insurance tier 1 USDC token account
     #[account(
         mut,
-        seeds = [INSURANCE_POOL_USDC_SEED],
+        seeds = [INSURANCE_TIER1_SEED],
         bump
     )]
     pub insurance_pool_usdc: Account<'info, TokenAccount>,
 
+    /// Insurance tier 2 USDC token account
+    #[account(
+        mut,
+        seeds = [INSURANCE_TIER2_SEED],
+        bump
+    )]
+    pub insurance_tier2_usdc: Box<Account<'info, TokenAccount>>,
+
     /// USDC mint
     pub usdc_mint: Account<'info, Mint>,
 
@@ -108,11 +139,54 @@ pub struct BuyBulk<'info> {
     )]
     pub user_stats: Account<'info, UserStats>,
 
+    /// Referrer's user statistics account (required only when this purchase
+    /// makes a referred player qualify; see `register_referral`)
+    #[account(
+        mut,
+        constraint = user_stats.referrer == Some(referrer_stats.wallet) @ LottoError::InvalidReferrer,
+        constraint = referrer_stats.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub referrer_stats: Option<Account<'info, UserStats>>,
+
     /// Token program
     pub token_program: Program<'info, Token>,
 
     /// System program
     pub system_program: Program<'info, System>,
+
+    /// House fee split config (required while a split is active; the
+    /// recipient sub-accounts are passed as remaining_accounts)
+    #[account(
+        seeds = [FEE_RECIPIENTS_SEED],
+        bump = fee_recipients.bump
+    )]
+    pub fee_recipients: Option<Account<'info, HouseFeeRecipients>>,
+
+    /// Purchase whitelist (required while `whitelist_mode` is not None)
+    #[account(
+        seeds = [WHITELIST_SEED],
+        bump = whitelist.bump
+    )]
+    pub whitelist: Option<Account<'info, Whitelist>>,
+
+    /// Player's token account for the whitelist gate mint (required in
+    /// TokenGated mode)
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Player's KYC record (required while `compliance_mode` is KYC)
+    #[account(
+        seeds = [KYC_SEED, player.key().as_ref()],
+        bump = kyc_record.bump
+    )]
+    pub kyc_record: Option<Account<'info, KYCRecord>>,
+
+    /// Lifetime lottery analytics (largest jackpot and player counts)
+    #[account(
+        mut,
+        seeds = [ANALYTICS_SEED],
+        bump = lottery_analytics.bump
+    )]
+    pub lottery_analytics: Box<Account<'info, LotteryAnalytics>>,
 }
 
 impl<'info> BuyBulk<'info> {
@@ -128,11 +202,40 @@ impl<'info> BuyBulk<'info> {
         token::transfer(cpi_ctx, amount)
     }
 
-    /// Transfer USDC from player to house fee account
-    pub fn transfer_to_house_fee(&self, amount: u64) -> Result<()> {
+    /// Transfer USDC from player to the house fee account(s)
+    ///
+    /// `sub_accounts` are the fee recipient sub-accounts, used only while a
+    /// house fee split is active.
+    pub fn transfer_to_house_fee(
+        &self,
+        amount: u64,
+        sub_accounts: &[AccountInfo<'info>],
+        program_id: &Pubkey,
+    ) -> Result<()> {
+        route_house_fee(
+            &HouseFeeSource {
+                from: self.player_usdc.to_account_info(),
+                authority: self.player.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                signer_seeds: &[],
+            },
+            &HouseFeeDestination {
+                house_fee_usdc: self.house_fee_usdc.to_account_info(),
+                fee_recipients: self.fee_recipients.as_deref(),
+                sub_accounts,
+            },
+            self.lottery_state.house_fee_split_active,
+            amount,
+            self.lottery_state.current_draw_id,
+            program_id,
+        )
+    }
+
+    /// Transfer USDC from player to insurance tier 1 account
+    pub fn transfer_to_insurance_pool(&self, amount: u64) -> Result<()> {
         let cpi_accounts = Transfer {
             from: self.player_usdc.to_account_info(),
-            to: self.house_fee_usdc.to_account_info(),
+            to: self.insurance_pool_usdc.to_account_info(),
             authority: self.player.to_account_info(),
         };
         let cpi_program = self.token_program.to_account_info();
@@ -140,11 +243,11 @@ impl<'info> BuyBulk<'info> {
         token::transfer(cpi_ctx, amount)
     }
 
-    /// Transfer USDC from player to insurance pool account
-    pub fn transfer_to_insurance_pool(&self, amount: u64) -> Result<()> {
+    /// Transfer USDC from player to insurance tier 2 account
+    pub fn transfer_to_insurance_tier2(&self, amount: u64) -> Result<()> {
         let cpi_accounts = Transfer {
             from: self.player_usdc.to_account_info(),
-            to: self.insurance_pool_usdc.to_account_info(),
+            to: self.insurance_tier2_usdc.to_account_info(),
             authority: self.player.to_account_info(),
         };
         let cpi_program = self.token_program.to_account_info();
@@ -181,7 +284,10 @@ impl<'info> BuyBulk<'info> {
 ///
 /// # Returns
 /// * `Result<()>` - Success or error
-pub fn handler(ctx: Context<BuyBulk>, params: BuyBulkParams) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BuyBulk<'info>>,
+    params: BuyBulkParams,
+) -> Result<()> {
     let clock = Clock::get()?;
     let ticket_count = params.tickets.len();
 
@@ -196,14 +302,18 @@ pub fn handler(ctx: Context<BuyBulk>, params: BuyBulkParams) -> Result<()> {
     let mut sorted_tickets: Vec<[u8; 6]> = Vec::with_capacity(ticket_count);
     for ticket in &params.tickets {
         validate_numbers(ticket)?;
+        check_numbers_entropy(
+            ctx.accounts.lottery_state.entropy_validation_enabled,
+            ctx.accounts.player.key(),
+            ticket,
+        )?;
         let mut sorted = *ticket;
         sorted.sort();
         sorted_tickets.push(sorted);
     }
 
     // Get values needed for validation and calculation before mutable borrows
-    let ticket_price = ctx.accounts.lottery_state.ticket_price;
-    let next_draw_timestamp = ctx.accounts.lottery_state.next_draw_timestamp;
+    let ticket_price = resolve_ticket_price(&ctx.accounts.lottery_state, params.max_price_usdc)?;
     let is_paused = ctx.accounts.lottery_state.is_paused;
     let is_funded = ctx.accounts.lottery_state.is_funded;
     let is_draw_in_progress = ctx.accounts.lottery_state.is_draw_in_progress;
@@ -213,13 +323,23 @@ pub fn handler(ctx: Context<BuyBulk>, params: BuyBulkParams) -> Result<()> {
     let house_fee_bps = ctx.accounts.lottery_state.get_current_house_fee_bps();
 
     // Check if ticket sales are open
-    let sale_cutoff_time = next_draw_timestamp.checked_sub(TICKET_SALE_CUTOFF);
-    let is_sale_open = !is_paused
-        && is_funded
-        && !is_draw_in_progress
-        && sale_cutoff_time.is_some()
-        && clock.unix_timestamp < sale_cutoff_time.expect("Sale cutoff time should be valid");
-    require!(is_sale_open, LottoError::TicketSaleEnded);
+    require!(
+        !is_paused && is_funded && !is_draw_in_progress,
+        LottoError::TicketSaleEnded
+    );
+    check_ticket_sale_cutoff(&ctx.accounts.lottery_state, clock.unix_timestamp)?;
+    enforce_whitelist(
+        ctx.accounts.lottery_state.whitelist_mode,
+        ctx.accounts.whitelist.as_deref(),
+        ctx.accounts.gate_token_account.as_deref(),
+        &ctx.accounts.player.key(),
+    )?;
+    enforce_kyc(
+        ctx.accounts.lottery_state.compliance_mode,
+        ctx.accounts.kyc_record.as_deref(),
+        &ctx.accounts.player.key(),
+        clock.unix_timestamp,
+    )?;
 
     // Check if jackpot is properly funded (minimum 100% of seed amount)
     let minimum_jackpot = ctx.accounts.lottery_state.seed_amount;
@@ -255,6 +375,17 @@ pub fn handler(ctx: Context<BuyBulk>, params: BuyBulkParams) -> Result<()> {
         .checked_mul(ticket_count as u64)
         .ok_or(LottoError::Overflow)?;
 
+    // Reaching a streak milestone takes a one-time discount off one ticket
+    let streak_milestone = streak_milestone_for_purchase(
+        &ctx.accounts.lottery_state,
+        &ctx.accounts.user_stats,
+        current_draw_id,
+    );
+    let total_price = match streak_milestone {
+        Some((_, discount_bps)) => total_price - streak_discount(ticket_price, discount_bps),
+        None => total_price,
+    };
+
     // Calculate dynamic house fee based on current jackpot level
     let total_house_fee =
         (total_price as u128 * house_fee_bps as u128 / BPS_DENOMINATOR as u128) as u64;
@@ -295,43 +426,64 @@ pub fn handler(ctx: Context<BuyBulk>, params: BuyBulkParams) -> Result<()> {
         LottoError::InsufficientFunds
     );
 
+    // While the insurance pool is below its minimum, part of the house fee
+    // goes to the insurance pool instead (insurance_tier1_balance is credited here)
+    let insurance_replenishment =
+        replenish_insurance_from_house_fee(&mut ctx.accounts.lottery_state, total_house_fee)?;
+    let house_fee_transfer = total_house_fee - insurance_replenishment;
+
     // Transfer to prize pool (excludes insurance - that goes to separate account)
     ctx.accounts
         .transfer_to_prize_pool(total_prize_pool_transfer)?;
 
     // Transfer to house fee account
-    ctx.accounts.transfer_to_house_fee(total_house_fee)?;
-
-    // Transfer insurance contribution to separate insurance pool
-    if total_insurance_contribution > 0 {
+    ctx.accounts.transfer_to_house_fee(
+        house_fee_transfer,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+
+    // Transfer the tier 1 insurance contribution (plus any rerouted house
+    // fee) and the tier 2 contribution to their separate insurance pools
+    let (insurance_tier1_contribution, insurance_tier2_transfer) =
+        split_insurance_contribution(total_insurance_contribution);
+    let insurance_transfer = insurance_tier1_contribution + insurance_replenishment;
+    if insurance_transfer > 0 {
+        ctx.accounts
+            .transfer_to_insurance_pool(insurance_transfer)?;
+    }
+    if insurance_tier2_transfer > 0 {
         ctx.accounts
-            .transfer_to_insurance_pool(total_insurance_contribution)?;
+            .transfer_to_insurance_tier2(insurance_tier2_transfer)?;
     }
 
     // SECURITY FIX (Issue #8): Replace debug_assert with runtime require!
     // debug_assert is stripped in release builds, leaving this critical
     // invariant unchecked in production. Use require! to enforce it always.
     require!(
-        total_house_fee + total_prize_pool_transfer + total_insurance_contribution == total_price,
+        house_fee_transfer
+            + total_prize_pool_transfer
+            + insurance_transfer
+            + insurance_tier2_transfer
+            == total_price,
         LottoError::SafetyCheckFailed
     );
 
     // Update lottery state with internal accounting
     let lottery_state = &mut ctx.accounts.lottery_state;
     let old_house_fee_bps = lottery_state.house_fee_bps;
+    let old_jackpot_balance = lottery_state.jackpot_balance;
 
     lottery_state.jackpot_balance = lottery_state
         .jackpot_balance
         .checked_add(total_jackpot_contribution)
         .ok_or(LottoError::Overflow)?;
+    route_jackpot_overflow(lottery_state)?;
     lottery_state.reserve_balance = lottery_state
         .reserve_balance
         .checked_add(total_reserve_contribution)
         .ok_or(LottoError::Overflow)?;
-    lottery_state.insurance_balance = lottery_state
-        .insurance_balance
-        .checked_add(total_insurance_contribution)
-        .ok_or(LottoError::Overflow)?;
+    lottery_state.credit_insurance(total_insurance_contribution)?;
     // SECURITY FIX (Issue #4): Track dedicated fixed prize pool balance.
     // This 39.4% allocation is now explicitly tracked instead of being implicit,
     // preventing fixed prize payouts from eroding the advertised jackpot.
@@ -382,6 +534,13 @@ pub fn handler(ctx: Context<BuyBulk>, params: BuyBulkParams) -> Result<()> {
         );
     }
 
+    emit_jackpot_milestones(lottery_state);
+    unlock_milestone_bonuses(lottery_state, old_jackpot_balance);
+    record_jackpot_ath(lottery_state, clock.unix_timestamp);
+    ctx.accounts
+        .lottery_analytics
+        .record_jackpot(lottery_state.jackpot_balance, current_draw_id);
+
     let new_jackpot_balance = lottery_state.jackpot_balance;
 
     // Create unified ticket account
@@ -395,18 +554,48 @@ pub fn handler(ctx: Context<BuyBulk>, params: BuyBulkParams) -> Result<()> {
     unified_ticket.syndicate = None;
     // Initialize claimed bitmap (1 bit per ticket, rounded up to bytes)
     unified_ticket.claimed_bitmap = vec![0u8; (ticket_count + 7) / 8];
+    unified_ticket.original_owner = ctx.accounts.player.key();
     unified_ticket.bump = ctx.bumps.unified_ticket;
+    unified_ticket.claim_cursor = 0;
 
     // Update user stats
     let user_stats = &mut ctx.accounts.user_stats;
 
     // Initialize if new
     if user_stats.wallet == Pubkey::default() {
+        user_stats.schema_version = CURRENT_SCHEMA_VERSION;
         user_stats.wallet = ctx.accounts.player.key();
         user_stats.bump = ctx.bumps.user_stats;
         user_stats.tickets_this_draw = 0;
         user_stats.last_draw_participated = 0;
+        user_stats.daily_ticket_limit = DEFAULT_DAILY_TICKET_LIMIT;
+        user_stats.join_timestamp = clock.unix_timestamp;
     }
+    user_stats.require_current_schema()?;
+
+    enforce_self_exclusion(user_stats, clock.unix_timestamp)?;
+    enforce_terms_accepted(lottery_state.terms_version, user_stats)?;
+
+    enforce_daily_limit(
+        lottery_state.daily_limit_enabled,
+        user_stats,
+        ticket_count as u32,
+        clock.unix_timestamp,
+    )?;
+
+    enforce_purchase_velocity(
+        lottery_state.max_tickets_per_wallet_per_hour,
+        user_stats,
+        ticket_count as u32,
+        clock.unix_timestamp,
+    )?;
+
+    enforce_wallet_draw_limit(
+        lottery_state.max_tickets_per_wallet_per_draw,
+        user_stats,
+        ticket_count as u64,
+        current_draw_id,
+    )?;
 
     // FIXED: Track tickets per draw for limit enforcement
     // Always update last_draw_participated to current draw
@@ -422,6 +611,11 @@ pub fn handler(ctx: Context<BuyBulk>, params: BuyBulkParams) -> Result<()> {
             .ok_or(LottoError::Overflow)?;
     }
 
+    ctx.accounts
+        .lottery_analytics
+        .record_player(user_stats, clock.unix_timestamp);
+
+    let previous_total_tickets = user_stats.total_tickets;
     user_stats.total_tickets = user_stats
         .total_tickets
         .checked_add(ticket_count as u64)
@@ -430,7 +624,27 @@ pub fn handler(ctx: Context<BuyBulk>, params: BuyBulkParams) -> Result<()> {
         .total_spent
         .checked_add(total_price)
         .ok_or(LottoError::Overflow)?;
+    user_stats.record_house_fee(total_house_fee)?;
     user_stats.update_streak(current_draw_id);
+    user_stats.record_draw_played(current_draw_id)?;
+    if let Some((streak_length, discount_bps)) = streak_milestone {
+        emit!(StreakMilestoneReached {
+            player: ctx.accounts.player.key(),
+            streak_length,
+            discount_bps,
+        });
+        msg!(
+            "  Streak milestone: {} draws, {}bps off",
+            streak_length,
+            discount_bps
+        );
+    }
+
+    credit_referrer_if_qualified(
+        user_stats,
+        previous_total_tickets,
+        ctx.accounts.referrer_stats.as_mut(),
+    )?;
 
     // Emit event
     emit!(BulkTicketsPurchased {
@@ -468,10 +682,16 @@ pub fn handler(ctx: Context<BuyBulk>, params: BuyBulkParams) -> Result<()> {
         "  Insurance contribution: {} USDC lamports",
         total_insurance_contribution
     );
+    if insurance_replenishment > 0 {
+        msg!(
+            "  House fee rerouted to insurance: {} USDC lamports",
+            insurance_replenishment
+        );
+    }
     msg!("  Current jackpot: {} USDC lamports", new_jackpot_balance);
     msg!(
         "  Insurance pool: {} USDC lamports",
-        lottery_state.insurance_balance
+        lottery_state.insurance_tier1_balance
     );
     msg!("  Rolldown active: {}", lottery_state.is_rolldown_active);
     msg!(
@@ -488,20 +708,21 @@ pub fn handler(ctx: Context<BuyBulk>, params: BuyBulkParams) -> Result<()> {
 }
 
 /// Validate ticket numbers
+///
+/// Uses the same single-pass bitmask check as `buy_ticket::validate_numbers`
+/// (no copy or sort).
 fn validate_numbers(numbers: &[u8; 6]) -> Result<()> {
-    // Check range for each number
+    // Single pass: range check, then one bit per number (MAX_NUMBER < 64)
+    let mut seen: u64 = 0;
     for &num in numbers.iter() {
         require!(
             num >= MIN_NUMBER && num <= MAX_NUMBER,
             LottoError::NumbersOutOfRange
         );
-    }
 
-    // Check for duplicates by sorting and comparing adjacent
-    let mut sorted = *numbers;
-    sorted.sort();
-    for i in 0..5 {
-        require!(sorted[i] != sorted[i + 1], LottoError::DuplicateNumbers);
+        let bit = 1u64 << num;
+        require!(seen & bit == 0, LottoError::DuplicateNumbers);
+        seen |= bit;
     }
 
     Ok(())
@@ -551,6 +772,7 @@ mod tests {
     fn test_bulk_params() {
         let params = BuyBulkParams {
             tickets: vec![[1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12]],
+            max_price_usdc: TICKET_PRICE,
         };
         assert_eq!(params.tickets.len(), 2);
     }
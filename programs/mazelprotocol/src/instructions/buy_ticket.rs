@@ -5,7 +5,7 @@
 //! - Number validation
 //! - Per-user ticket limits enforcement
 //! - Free ticket redemption (Match 2 credits)
-//! - USDC transfer (player -> prize pool + house fee)
+//! - USDC transfer (player -> prize pool + house fee, split across fee recipients)
 //! - Ticket account creation
 //! - User stats updates
 //! - Fee calculation based on jackpot level
@@ -15,8 +15,27 @@ use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::constants::*;
 use crate::errors::LottoError;
-use crate::events::TicketPurchased;
-use crate::state::{LotteryState, TicketData, UserStats};
+use crate::events::{
+    DynamicPriceTierApplied, JackpotATHReached, JackpotMilestoneReached, JackpotOverflowRouted,
+    LowEntropyTicketRejected, LuckyDipGenerated, MilestoneUnlocked, StreakMilestoneReached,
+    TicketPurchased, TicketSaleClosed,
+};
+use crate::instructions::daily_limit::{
+    enforce_daily_limit, enforce_purchase_velocity, enforce_self_exclusion,
+    enforce_wallet_draw_limit,
+};
+use crate::instructions::house_fee::{
+    replenish_insurance_from_house_fee, route_house_fee, HouseFeeDestination, HouseFeeSource,
+};
+use crate::instructions::kyc::enforce_kyc;
+use crate::instructions::referral::credit_referrer_if_qualified;
+use crate::instructions::state_diff::{emit_state_diff, LotteryStateSnapshot};
+use crate::instructions::terms::enforce_terms_accepted;
+use crate::instructions::whitelist::enforce_whitelist;
+use crate::state::{
+    HouseFeeRecipients, KYCRecord, LotteryAnalytics, LotteryState, SchemaVersioned, TicketData,
+    UserStats, Whitelist,
+};
 
 /// Parameters for buying a ticket
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -25,11 +44,13 @@ pub struct BuyTicketParams {
     pub numbers: [u8; 6],
     /// Whether to use a free ticket credit (if available)
     pub use_free_ticket: bool,
+    /// Highest ticket price the player accepts (slippage protection for
+    /// dynamic pricing)
+    pub max_price_usdc: u64,
 }
 
 /// Accounts required for buying a ticket
 #[derive(Accounts)]
-#[instruction(params: BuyTicketParams)]
 pub struct BuyTicket<'info> {
     /// The player purchasing the ticket
     #[account(mut)]
@@ -42,7 +63,8 @@ pub struct BuyTicket<'info> {
         bump = lottery_state.bump,
         constraint = !lottery_state.is_paused @ LottoError::Paused,
         constraint = lottery_state.is_funded @ LottoError::LotteryNotInitialized,
-        constraint = !lottery_state.is_draw_in_progress @ LottoError::DrawInProgress
+        constraint = !lottery_state.is_draw_in_progress @ LottoError::DrawInProgress,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
@@ -84,14 +106,22 @@ pub struct BuyTicket<'info> {
     )]
     pub house_fee_usdc: Account<'info, TokenAccount>,
 
-    /// Insurance pool USDC token account
+    /// Insurance tier 1 USDC token account
     #[account(
         mut,
-        seeds = [INSURANCE_POOL_USDC_SEED],
+        seeds = [INSURANCE_TIER1_SEED],
         bump
     )]
     pub insurance_pool_usdc: Account<'info, TokenAccount>,
 
+    /// Insurance tier 2 USDC token account
+    #[account(
+        mut,
+        seeds = [INSURANCE_TIER2_SEED],
+        bump
+    )]
+    pub insurance_tier2_usdc: Box<Account<'info, TokenAccount>>,
+
     /// USDC mint
     pub usdc_mint: Account<'info, Mint>,
 
@@ -105,11 +135,54 @@ pub struct BuyTicket<'info> {
     )]
     pub user_stats: Account<'info, UserStats>,
 
+    /// Referrer's user statistics account (required only when this purchase
+    /// makes a referred player qualify; see `register_referral`)
+    #[account(
+        mut,
+        constraint = user_stats.referrer == Some(referrer_stats.wallet) @ LottoError::InvalidReferrer,
+        constraint = referrer_stats.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub referrer_stats: Option<Account<'info, UserStats>>,
+
     /// Token program
     pub token_program: Program<'info, Token>,
 
     /// System program
     pub system_program: Program<'info, System>,
+
+    /// House fee split config (required while a split is active; the
+    /// recipient sub-accounts are passed as remaining_accounts)
+    #[account(
+        seeds = [FEE_RECIPIENTS_SEED],
+        bump = fee_recipients.bump
+    )]
+    pub fee_recipients: Option<Account<'info, HouseFeeRecipients>>,
+
+    /// Purchase whitelist (required while `whitelist_mode` is not None)
+    #[account(
+        seeds = [WHITELIST_SEED],
+        bump = whitelist.bump
+    )]
+    pub whitelist: Option<Account<'info, Whitelist>>,
+
+    /// Player's token account for the whitelist gate mint (required in
+    /// TokenGated mode)
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Player's KYC record (required while `compliance_mode` is KYC)
+    #[account(
+        seeds = [KYC_SEED, player.key().as_ref()],
+        bump = kyc_record.bump
+    )]
+    pub kyc_record: Option<Account<'info, KYCRecord>>,
+
+    /// Lifetime lottery analytics (largest jackpot and player counts)
+    #[account(
+        mut,
+        seeds = [ANALYTICS_SEED],
+        bump = lottery_analytics.bump
+    )]
+    pub lottery_analytics: Box<Account<'info, LotteryAnalytics>>,
 }
 
 impl<'info> BuyTicket<'info> {
@@ -125,11 +198,40 @@ impl<'info> BuyTicket<'info> {
         token::transfer(cpi_ctx, amount)
     }
 
-    /// Transfer USDC from player to house fee account
-    pub fn transfer_to_house_fee(&self, amount: u64) -> Result<()> {
+    /// Transfer USDC from player to the house fee account(s)
+    ///
+    /// `sub_accounts` are the fee recipient sub-accounts, used only while a
+    /// house fee split is active.
+    pub fn transfer_to_house_fee(
+        &self,
+        amount: u64,
+        sub_accounts: &[AccountInfo<'info>],
+        program_id: &Pubkey,
+    ) -> Result<()> {
+        route_house_fee(
+            &HouseFeeSource {
+                from: self.player_usdc.to_account_info(),
+                authority: self.player.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                signer_seeds: &[],
+            },
+            &HouseFeeDestination {
+                house_fee_usdc: self.house_fee_usdc.to_account_info(),
+                fee_recipients: self.fee_recipients.as_deref(),
+                sub_accounts,
+            },
+            self.lottery_state.house_fee_split_active,
+            amount,
+            self.lottery_state.current_draw_id,
+            program_id,
+        )
+    }
+
+    /// Transfer USDC from player to insurance tier 1 account
+    pub fn transfer_to_insurance_pool(&self, amount: u64) -> Result<()> {
         let cpi_accounts = Transfer {
             from: self.player_usdc.to_account_info(),
-            to: self.house_fee_usdc.to_account_info(),
+            to: self.insurance_pool_usdc.to_account_info(),
             authority: self.player.to_account_info(),
         };
         let cpi_program = self.token_program.to_account_info();
@@ -137,11 +239,11 @@ impl<'info> BuyTicket<'info> {
         token::transfer(cpi_ctx, amount)
     }
 
-    /// Transfer USDC from player to insurance pool account
-    pub fn transfer_to_insurance_pool(&self, amount: u64) -> Result<()> {
+    /// Transfer USDC from player to insurance tier 2 account
+    pub fn transfer_to_insurance_tier2(&self, amount: u64) -> Result<()> {
         let cpi_accounts = Transfer {
             from: self.player_usdc.to_account_info(),
-            to: self.insurance_pool_usdc.to_account_info(),
+            to: self.insurance_tier2_usdc.to_account_info(),
             authority: self.player.to_account_info(),
         };
         let cpi_program = self.token_program.to_account_info();
@@ -178,60 +280,116 @@ impl<'info> BuyTicket<'info> {
 ///
 /// # Returns
 /// * `Result<()>` - Success or error
-pub fn handler(ctx: Context<BuyTicket>, params: BuyTicketParams) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BuyTicket<'info>>,
+    params: BuyTicketParams,
+) -> Result<()> {
+    process_ticket_purchase(
+        ctx.accounts,
+        &ctx.bumps,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        params.numbers,
+        params.use_free_ticket,
+        params.max_price_usdc,
+    )
+}
+
+/// Shared single-ticket purchase flow used by `buy_ticket` and `buy_lucky_dip`
+fn process_ticket_purchase<'info>(
+    accounts: &mut BuyTicket<'info>,
+    bumps: &BuyTicketBumps,
+    house_fee_sub_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+    numbers: [u8; 6],
+    use_free_ticket: bool,
+    max_price_usdc: u64,
+) -> Result<()> {
     let clock = Clock::get()?;
+    let state_before = LotteryStateSnapshot::capture(&accounts.lottery_state);
 
     // Validate numbers first (before any borrows)
-    validate_numbers(&params.numbers)?;
+    validate_numbers(&numbers)?;
+    check_numbers_entropy(
+        accounts.lottery_state.entropy_validation_enabled,
+        accounts.player.key(),
+        &numbers,
+    )?;
 
     // Sort numbers for consistent storage
-    let mut sorted_numbers = params.numbers;
+    let mut sorted_numbers = numbers;
     sorted_numbers.sort();
 
     // Get values needed for validation and calculation before mutable borrows
-    let ticket_price = ctx.accounts.lottery_state.ticket_price;
-    let next_draw_timestamp = ctx.accounts.lottery_state.next_draw_timestamp;
-    let is_paused = ctx.accounts.lottery_state.is_paused;
-    let is_funded = ctx.accounts.lottery_state.is_funded;
-    let is_draw_in_progress = ctx.accounts.lottery_state.is_draw_in_progress;
-    let current_draw_id = ctx.accounts.lottery_state.current_draw_id;
-    let soft_cap = ctx.accounts.lottery_state.soft_cap;
-    let house_fee_bps = ctx.accounts.lottery_state.get_current_house_fee_bps();
+    let is_paused = accounts.lottery_state.is_paused;
+    let is_funded = accounts.lottery_state.is_funded;
+    let is_draw_in_progress = accounts.lottery_state.is_draw_in_progress;
+    let current_draw_id = accounts.lottery_state.current_draw_id;
+    let soft_cap = accounts.lottery_state.soft_cap;
+    let house_fee_bps = accounts.lottery_state.get_current_house_fee_bps();
 
     // Check if ticket sales are open
-    let sale_cutoff_time = next_draw_timestamp.checked_sub(TICKET_SALE_CUTOFF);
-    let is_sale_open = !is_paused
-        && is_funded
-        && !is_draw_in_progress
-        && sale_cutoff_time.is_some()
-        && clock.unix_timestamp < sale_cutoff_time.expect("Sale cutoff time should be valid");
-    require!(is_sale_open, LottoError::TicketSaleEnded);
+    require!(
+        !is_paused && is_funded && !is_draw_in_progress,
+        LottoError::TicketSaleEnded
+    );
+    check_ticket_sale_cutoff(&accounts.lottery_state, clock.unix_timestamp)?;
+    enforce_whitelist(
+        accounts.lottery_state.whitelist_mode,
+        accounts.whitelist.as_deref(),
+        accounts.gate_token_account.as_deref(),
+        &accounts.player.key(),
+    )?;
+    enforce_kyc(
+        accounts.lottery_state.compliance_mode,
+        accounts.kyc_record.as_deref(),
+        &accounts.player.key(),
+        clock.unix_timestamp,
+    )?;
 
     // Check if jackpot is properly funded (minimum 100% of seed amount)
-    let minimum_jackpot = ctx.accounts.lottery_state.seed_amount;
+    let minimum_jackpot = accounts.lottery_state.seed_amount;
     require!(
-        ctx.accounts.lottery_state.jackpot_balance >= minimum_jackpot,
+        accounts.lottery_state.jackpot_balance >= minimum_jackpot,
         LottoError::InsufficientJackpotFunding
     );
 
     // FIXED: Enforce per-user ticket limit
-    let user_tickets_this_draw = ctx.accounts.get_user_tickets_this_draw(current_draw_id);
+    let user_tickets_this_draw = accounts.get_user_tickets_this_draw(current_draw_id);
     require!(
         user_tickets_this_draw < MAX_TICKETS_PER_DRAW_PER_USER,
         LottoError::MaxTicketsPerDrawExceeded
     );
 
     // Check if user wants to use a free ticket and has one available
-    let free_tickets_available = ctx.accounts.user_stats.free_tickets_available;
-    let using_free_ticket = params.use_free_ticket && free_tickets_available > 0;
+    let free_tickets_available = accounts.user_stats.free_tickets_available;
+    let using_free_ticket = use_free_ticket && free_tickets_available > 0;
 
     // FIXED: Validate free ticket usage with correct error code
-    if params.use_free_ticket && free_tickets_available == 0 {
+    if use_free_ticket && free_tickets_available == 0 {
         msg!("Free ticket requested but none available!");
         msg!("  User free tickets: {}", free_tickets_available);
         return Err(LottoError::NoFreeTicketsAvailable.into());
     }
 
+    // Free tickets are not charged, so the slippage bound only applies when paying
+    let ticket_price = if using_free_ticket {
+        0
+    } else {
+        resolve_ticket_price(&accounts.lottery_state, max_price_usdc)?
+    };
+
+    // Reaching a streak milestone takes a one-time discount off this purchase
+    let streak_milestone = streak_milestone_for_purchase(
+        &accounts.lottery_state,
+        &accounts.user_stats,
+        current_draw_id,
+    );
+    let ticket_price = match streak_milestone {
+        Some((_, discount_bps)) => ticket_price - streak_discount(ticket_price, discount_bps),
+        None => ticket_price,
+    };
+
     // Calculate price and fees (0 if using free ticket)
     //
     // Fund allocation breakdown:
@@ -300,45 +458,63 @@ pub fn handler(ctx: Context<BuyTicket>, params: BuyTicketParams) -> Result<()> {
         )
     };
 
+    // While the insurance pool is below its minimum, part of the house fee
+    // goes to the insurance pool instead (insurance_tier1_balance is credited here)
+    let insurance_replenishment =
+        replenish_insurance_from_house_fee(&mut accounts.lottery_state, house_fee)?;
+    let house_fee_transfer = house_fee - insurance_replenishment;
+
     // Only perform USDC transfers if not using free ticket
     if !using_free_ticket {
         // Verify player has sufficient balance for TOTAL amount
         // Total = house_fee + prize_pool_transfer + insurance_contribution = ticket_price
         require!(
-            ctx.accounts.player_usdc.amount >= ticket_price,
+            accounts.player_usdc.amount >= ticket_price,
             LottoError::InsufficientFunds
         );
 
         // Transfer to prize pool (excludes insurance - that goes to separate account)
-        ctx.accounts.transfer_to_prize_pool(prize_pool_transfer)?;
-
-        // Transfer to house fee account
-        ctx.accounts.transfer_to_house_fee(house_fee)?;
-
-        // Transfer insurance contribution to separate insurance pool
-        if insurance_contribution > 0 {
-            ctx.accounts
-                .transfer_to_insurance_pool(insurance_contribution)?;
+        accounts.transfer_to_prize_pool(prize_pool_transfer)?;
+
+        // Transfer to house fee account (or fee recipient sub-accounts)
+        accounts.transfer_to_house_fee(house_fee_transfer, house_fee_sub_accounts, program_id)?;
+
+        // Transfer the tier 1 insurance contribution (plus any rerouted house
+        // fee) and the tier 2 contribution to their separate insurance pools
+        let (insurance_tier1_contribution, insurance_tier2_transfer) =
+            split_insurance_contribution(insurance_contribution);
+        let insurance_transfer = insurance_tier1_contribution + insurance_replenishment;
+        if insurance_transfer > 0 {
+            accounts.transfer_to_insurance_pool(insurance_transfer)?;
+        }
+        if insurance_tier2_transfer > 0 {
+            accounts.transfer_to_insurance_tier2(insurance_tier2_transfer)?;
         }
 
         // SECURITY FIX (Issue #8): Replace debug_assert with runtime require!
         // debug_assert is stripped in release builds, leaving this critical
         // invariant unchecked in production. Use require! to enforce it always.
         require!(
-            house_fee + prize_pool_transfer + insurance_contribution == ticket_price,
+            house_fee_transfer
+                + prize_pool_transfer
+                + insurance_transfer
+                + insurance_tier2_transfer
+                == ticket_price,
             LottoError::SafetyCheckFailed
         );
     }
 
     // Update lottery state with internal accounting
-    let lottery_state = &mut ctx.accounts.lottery_state;
+    let lottery_state = &mut accounts.lottery_state;
     let old_house_fee_bps = lottery_state.house_fee_bps;
+    let old_jackpot_balance = lottery_state.jackpot_balance;
 
     if jackpot_contribution > 0 {
         lottery_state.jackpot_balance = lottery_state
             .jackpot_balance
             .checked_add(jackpot_contribution)
             .ok_or(LottoError::Overflow)?;
+        route_jackpot_overflow(lottery_state)?;
     }
     if reserve_contribution > 0 {
         lottery_state.reserve_balance = lottery_state
@@ -347,10 +523,7 @@ pub fn handler(ctx: Context<BuyTicket>, params: BuyTicketParams) -> Result<()> {
             .ok_or(LottoError::Overflow)?;
     }
     if insurance_contribution > 0 {
-        lottery_state.insurance_balance = lottery_state
-            .insurance_balance
-            .checked_add(insurance_contribution)
-            .ok_or(LottoError::Overflow)?;
+        lottery_state.credit_insurance(insurance_contribution)?;
     }
     // SECURITY FIX (Issue #4): Track dedicated fixed prize pool balance.
     // This 39.4% allocation is now explicitly tracked instead of being implicit,
@@ -402,11 +575,19 @@ pub fn handler(ctx: Context<BuyTicket>, params: BuyTicketParams) -> Result<()> {
         );
     }
 
+    emit_jackpot_milestones(lottery_state);
+    unlock_milestone_bonuses(lottery_state, old_jackpot_balance);
+    record_jackpot_ath(lottery_state, clock.unix_timestamp);
+    accounts
+        .lottery_analytics
+        .record_jackpot(lottery_state.jackpot_balance, current_draw_id);
+
     let new_jackpot_balance = lottery_state.jackpot_balance;
 
     // Create ticket
-    let ticket = &mut ctx.accounts.ticket;
-    ticket.owner = ctx.accounts.player.key();
+    let ticket = &mut accounts.ticket;
+    ticket.schema_version = CURRENT_SCHEMA_VERSION;
+    ticket.owner = accounts.player.key();
     ticket.draw_id = current_draw_id;
     ticket.numbers = sorted_numbers;
     ticket.purchase_timestamp = clock.unix_timestamp;
@@ -414,18 +595,51 @@ pub fn handler(ctx: Context<BuyTicket>, params: BuyTicketParams) -> Result<()> {
     ticket.match_count = 0;
     ticket.prize_amount = 0;
     ticket.syndicate = None;
-    ticket.bump = ctx.bumps.ticket;
+    ticket.original_owner = accounts.player.key();
+    ticket.entered_second_chance = false;
+    ticket.bump = bumps.ticket;
+    // Free-ticket credits were never paid for, so they are not refundable
+    ticket.requires_refund = !using_free_ticket;
+    ticket.claim_agent = None;
 
     // Update user stats
-    let user_stats = &mut ctx.accounts.user_stats;
+    let user_stats = &mut accounts.user_stats;
 
     // Initialize if new
     if user_stats.wallet == Pubkey::default() {
-        user_stats.wallet = ctx.accounts.player.key();
-        user_stats.bump = ctx.bumps.user_stats;
+        user_stats.schema_version = CURRENT_SCHEMA_VERSION;
+        user_stats.wallet = accounts.player.key();
+        user_stats.bump = bumps.user_stats;
         user_stats.tickets_this_draw = 0;
         user_stats.last_draw_participated = 0;
+        user_stats.daily_ticket_limit = DEFAULT_DAILY_TICKET_LIMIT;
+        user_stats.join_timestamp = clock.unix_timestamp;
     }
+    user_stats.require_current_schema()?;
+
+    enforce_self_exclusion(user_stats, clock.unix_timestamp)?;
+    enforce_terms_accepted(accounts.lottery_state.terms_version, user_stats)?;
+
+    enforce_daily_limit(
+        accounts.lottery_state.daily_limit_enabled,
+        user_stats,
+        1,
+        clock.unix_timestamp,
+    )?;
+
+    enforce_purchase_velocity(
+        accounts.lottery_state.max_tickets_per_wallet_per_hour,
+        user_stats,
+        1,
+        clock.unix_timestamp,
+    )?;
+
+    enforce_wallet_draw_limit(
+        accounts.lottery_state.max_tickets_per_wallet_per_draw,
+        user_stats,
+        1,
+        current_draw_id,
+    )?;
 
     // FIXED: Track tickets per draw for limit enforcement
     // Always update last_draw_participated to current draw
@@ -441,6 +655,11 @@ pub fn handler(ctx: Context<BuyTicket>, params: BuyTicketParams) -> Result<()> {
             .ok_or(LottoError::Overflow)?;
     }
 
+    accounts
+        .lottery_analytics
+        .record_player(user_stats, clock.unix_timestamp);
+
+    let previous_total_tickets = user_stats.total_tickets;
     user_stats.total_tickets = user_stats
         .total_tickets
         .checked_add(1)
@@ -449,7 +668,27 @@ pub fn handler(ctx: Context<BuyTicket>, params: BuyTicketParams) -> Result<()> {
         .total_spent
         .checked_add(actual_price)
         .ok_or(LottoError::Overflow)?;
+    user_stats.record_house_fee(house_fee)?;
     user_stats.update_streak(current_draw_id);
+    user_stats.record_draw_played(current_draw_id)?;
+    if let Some((streak_length, discount_bps)) = streak_milestone {
+        emit!(StreakMilestoneReached {
+            player: accounts.player.key(),
+            streak_length,
+            discount_bps,
+        });
+        msg!(
+            "  Streak milestone: {} draws, {}bps off",
+            streak_length,
+            discount_bps
+        );
+    }
+
+    credit_referrer_if_qualified(
+        user_stats,
+        previous_total_tickets,
+        accounts.referrer_stats.as_mut(),
+    )?;
 
     // FIXED: Decrement free tickets if one was used
     if using_free_ticket {
@@ -458,8 +697,8 @@ pub fn handler(ctx: Context<BuyTicket>, params: BuyTicketParams) -> Result<()> {
 
     // Emit event
     emit!(TicketPurchased {
-        ticket: ctx.accounts.ticket.key(),
-        player: ctx.accounts.player.key(),
+        ticket: accounts.ticket.key(),
+        player: accounts.player.key(),
         draw_id: current_draw_id,
         numbers: sorted_numbers,
         price: actual_price,
@@ -468,12 +707,12 @@ pub fn handler(ctx: Context<BuyTicket>, params: BuyTicketParams) -> Result<()> {
     });
 
     msg!("Ticket purchased successfully!");
-    msg!("  Player: {}", ctx.accounts.player.key());
+    msg!("  Player: {}", accounts.player.key());
     msg!("  Draw ID: {}", current_draw_id);
     msg!("  Numbers: {:?}", sorted_numbers);
 
     // Log jackpot funding status
-    let minimum_jackpot = ctx.accounts.lottery_state.seed_amount;
+    let minimum_jackpot = accounts.lottery_state.seed_amount;
     msg!(
         "  Minimum jackpot required: {} USDC lamports",
         minimum_jackpot
@@ -513,14 +752,20 @@ pub fn handler(ctx: Context<BuyTicket>, params: BuyTicketParams) -> Result<()> {
             "  Insurance contribution: {} USDC lamports",
             insurance_contribution
         );
+        if insurance_replenishment > 0 {
+            msg!(
+                "  House fee rerouted to insurance: {} USDC lamports",
+                insurance_replenishment
+            );
+        }
     }
     msg!(
         "  Insurance pool: {} USDC lamports",
-        ctx.accounts.lottery_state.insurance_balance
+        accounts.lottery_state.insurance_tier1_balance
     );
     msg!(
         "  Rolldown active: {}",
-        ctx.accounts.lottery_state.is_rolldown_active
+        accounts.lottery_state.is_rolldown_active
     );
     msg!(
         "  User tickets this draw: {}/{}",
@@ -528,24 +773,370 @@ pub fn handler(ctx: Context<BuyTicket>, params: BuyTicketParams) -> Result<()> {
         MAX_TICKETS_PER_DRAW_PER_USER
     );
 
+    emit_state_diff(&state_before, &accounts.lottery_state);
+
+    Ok(())
+}
+
+// ============================================================================
+// LUCKY DIP INSTRUCTION
+// ============================================================================
+
+/// Maximum number of hash rounds attempted when deriving lucky dip numbers
+pub const LUCKY_DIP_MAX_ATTEMPTS: u8 = 10;
+
+/// Accounts required for a lucky dip purchase
+///
+/// Identical to `BuyTicket` - the numbers are derived on-chain instead of
+/// being supplied by the player, so the CPI cost is unchanged.
+#[derive(Accounts)]
+pub struct BuyLuckyDip<'info> {
+    /// Standard ticket purchase accounts
+    pub purchase: BuyTicket<'info>,
+}
+
+/// Compute the lucky dip entropy hash
+///
+/// SHA256(player || slot || unix_timestamp || total_tickets_sold)
+///
+/// `total_tickets_sold` changes with every purchase, so two lucky dips by the
+/// same player in the same slot still produce different numbers.
+pub fn compute_lucky_dip_entropy(
+    player: &Pubkey,
+    slot: u64,
+    unix_timestamp: i64,
+    total_tickets_sold: u64,
+) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    hasher.update(player.as_ref());
+    hasher.update(slot.to_le_bytes());
+    hasher.update(unix_timestamp.to_le_bytes());
+    hasher.update(total_tickets_sold.to_le_bytes());
+    hasher.finalize().into()
+}
+
+/// Derive 6 unique numbers in [MIN_NUMBER, MAX_NUMBER] from an entropy hash
+///
+/// Each attempt hashes SHA256(entropy_hash || attempt) and walks the output
+/// byte by byte. Bytes in the biased tail of the u8 range are rejected so the
+/// `% range` mapping is uniform. If an attempt runs out of bytes before
+/// finding 6 unique numbers, the next attempt starts over with a fresh hash.
+///
+/// # Returns
+/// * `Result<[u8; 6]>` - Sorted numbers, or `EntropyExhausted` after
+///   `LUCKY_DIP_MAX_ATTEMPTS` failed attempts
+pub fn derive_lucky_dip_numbers(entropy_hash: &[u8; 32]) -> Result<[u8; 6]> {
+    use sha2::{Digest, Sha256};
+
+    let range = (MAX_NUMBER - MIN_NUMBER + 1) as u16;
+    // Largest multiple of `range` that fits in a byte (230 for range = 46)
+    let reject_threshold = 256 - (256 % range);
+
+    for attempt in 0..LUCKY_DIP_MAX_ATTEMPTS {
+        let mut hasher = Sha256::new();
+        hasher.update(entropy_hash);
+        hasher.update([attempt]);
+        let round_hash = hasher.finalize();
+
+        let mut numbers = [0u8; 6];
+        let mut count = 0usize;
+
+        for &byte in round_hash.iter() {
+            if byte as u16 >= reject_threshold {
+                continue;
+            }
+            let candidate = MIN_NUMBER + (byte as u16 % range) as u8;
+            if numbers[..count].contains(&candidate) {
+                continue;
+            }
+            numbers[count] = candidate;
+            count += 1;
+            if count == 6 {
+                break;
+            }
+        }
+
+        if count == 6 {
+            numbers.sort();
+            return Ok(numbers);
+        }
+    }
+
+    Err(LottoError::EntropyExhausted.into())
+}
+
+/// Buy a single ticket with on-chain derived numbers (lucky dip)
+///
+/// This instruction:
+/// 1. Hashes the player key, slot, timestamp and total tickets sold
+/// 2. Derives 6 unique numbers from the hash via rejection sampling
+/// 3. Emits `LuckyDipGenerated` so the derivation can be verified off-chain
+/// 4. Runs the standard ticket purchase flow with the derived numbers
+///
+/// NOTE: Lucky dip numbers are predictable by anyone who can simulate the
+/// transaction. This is acceptable because ticket numbers carry no advantage
+/// before the draw randomness is committed.
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+/// * `max_price_usdc` - Highest ticket price the player accepts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_buy_lucky_dip<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BuyLuckyDip<'info>>,
+    max_price_usdc: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let accounts = &mut ctx.accounts.purchase;
+
+    let player = accounts.player.key();
+    let draw_id = accounts.lottery_state.current_draw_id;
+    let entropy_hash = compute_lucky_dip_entropy(
+        &player,
+        clock.slot,
+        clock.unix_timestamp,
+        accounts.lottery_state.total_tickets_sold,
+    );
+    let numbers = derive_lucky_dip_numbers(&entropy_hash)?;
+    validate_numbers(&numbers)?;
+
+    emit!(LuckyDipGenerated {
+        player,
+        draw_id,
+        numbers,
+        entropy_hash,
+    });
+
+    msg!("Lucky dip numbers generated: {:?}", numbers);
+
+    process_ticket_purchase(
+        accounts,
+        &ctx.bumps.purchase,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        numbers,
+        false,
+        max_price_usdc,
+    )
+}
+
+/// Reject purchases once the current draw's ticket sale cutoff has passed
+///
+/// Emits `TicketSaleClosed` with each rejected purchase; a failed
+/// transaction cannot record that the event was already sent.
+pub(crate) fn check_ticket_sale_cutoff(
+    lottery_state: &LotteryState,
+    current_timestamp: i64,
+) -> Result<()> {
+    let cutoff_time = lottery_state
+        .ticket_sale_cutoff_time()
+        .ok_or(LottoError::ArithmeticError)?;
+
+    if current_timestamp >= cutoff_time {
+        emit!(TicketSaleClosed {
+            draw_id: lottery_state.current_draw_id,
+            cutoff_time,
+            tickets_sold: lottery_state.current_draw_tickets,
+        });
+        msg!(
+            "Ticket sales closed for draw {}",
+            lottery_state.current_draw_id
+        );
+        msg!("  Cutoff time: {}", cutoff_time);
+        msg!("  Current time: {}", current_timestamp);
+        return Err(LottoError::TicketSaleEnded.into());
+    }
+
+    Ok(())
+}
+
+/// Announce jackpot milestones reached since the last announcement
+///
+/// Emits `JackpotMilestoneReached` for each newly reached threshold and
+/// records it in `milestones_emitted`, which finalize_draw clears.
+pub(crate) fn emit_jackpot_milestones(lottery_state: &mut LotteryState) {
+    let reached = newly_reached_jackpot_milestones(
+        &lottery_state.jackpot_milestone_thresholds,
+        lottery_state.milestones_emitted,
+        lottery_state.jackpot_balance,
+    );
+    if reached == 0 {
+        return;
+    }
+
+    for threshold_index in 0..JACKPOT_MILESTONE_COUNT as u8 {
+        if reached & (1 << threshold_index) != 0 {
+            emit!(JackpotMilestoneReached {
+                threshold_index,
+                jackpot_balance: lottery_state.jackpot_balance,
+                draw_id: lottery_state.current_draw_id,
+                tickets_sold_this_draw: lottery_state.current_draw_tickets,
+            });
+            msg!(
+                "🏆 Jackpot milestone {} reached: {} USDC lamports",
+                threshold_index,
+                lottery_state.jackpot_milestone_thresholds[threshold_index as usize]
+            );
+        }
+    }
+    lottery_state.milestones_emitted |= reached;
+}
+
+/// Unlock the milestone bonuses the jackpot crossed since `old_jackpot_balance`
+///
+/// Emits `MilestoneUnlocked` for each, records it in `milestone_unlocked`
+/// and marks it unclaimed.
+pub(crate) fn unlock_milestone_bonuses(lottery_state: &mut LotteryState, old_jackpot_balance: u64) {
+    let crossed = crossed_milestone_bonuses(
+        &lottery_state.milestone_thresholds,
+        &lottery_state.milestone_prizes,
+        old_jackpot_balance,
+        lottery_state.jackpot_balance,
+    );
+
+    for index in 0..JACKPOT_MILESTONE_COUNT {
+        if crossed & (1 << index) != 0 {
+            lottery_state.milestone_claimed[index] = false;
+            lottery_state.milestone_unlocked |= 1 << index;
+            emit!(MilestoneUnlocked {
+                index: index as u8,
+                jackpot_balance: lottery_state.jackpot_balance,
+                bonus_prize: lottery_state.milestone_prizes[index],
+            });
+            msg!(
+                "🎁 Milestone bonus {} unlocked: {} USDC lamports",
+                index,
+                lottery_state.milestone_prizes[index]
+            );
+        }
+    }
+}
+
+/// Keep the jackpot at or below the hard cap after a ticket contribution
+///
+/// Emits `JackpotOverflowRouted` when excess is moved to the reserve.
+pub(crate) fn route_jackpot_overflow(lottery_state: &mut LotteryState) -> Result<()> {
+    let overflow_amount = lottery_state.route_jackpot_overflow()?;
+    if overflow_amount == 0 {
+        return Ok(());
+    }
+
+    emit!(JackpotOverflowRouted {
+        overflow_amount,
+        new_reserve_balance: lottery_state.reserve_balance,
+    });
+    msg!(
+        "🛡️  Jackpot over hard cap: {} USDC lamports moved to reserve",
+        overflow_amount
+    );
+    Ok(())
+}
+
+/// Track the jackpot high watermarks after a ticket contribution
+///
+/// Emits `JackpotATHReached` when the jackpot sets a new all-time high.
+pub(crate) fn record_jackpot_ath(lottery_state: &mut LotteryState, current_timestamp: i64) {
+    let Some(previous_ath) = lottery_state.record_jackpot_high(current_timestamp) else {
+        return;
+    };
+
+    emit!(JackpotATHReached {
+        new_ath: lottery_state.jackpot_all_time_high,
+        previous_ath,
+        draw_id: lottery_state.jackpot_ath_draw_id,
+        timestamp: current_timestamp,
+    });
+    msg!(
+        "📈 Jackpot all-time high: {} USDC lamports",
+        lottery_state.jackpot_all_time_high
+    );
+}
+
+/// Ticket price to charge, rejecting it if above `max_price_usdc`
+///
+/// Emits `DynamicPriceTierApplied` when the price comes from the dynamic
+/// price tiers.
+pub(crate) fn resolve_ticket_price(
+    lottery_state: &LotteryState,
+    max_price_usdc: u64,
+) -> Result<u64> {
+    let price = lottery_state.effective_ticket_price();
+    require!(price <= max_price_usdc, LottoError::PriceExceedsSlippage);
+
+    if lottery_state.dynamic_price_enabled {
+        emit!(DynamicPriceTierApplied {
+            jackpot_balance: lottery_state.jackpot_balance,
+            effective_price_per_ticket: price,
+        });
+    }
+
+    Ok(price)
+}
+
+/// Streak milestone reached by the player's first purchase in
+/// `current_draw_id`, as (streak_length, discount_bps)
+pub(crate) fn streak_milestone_for_purchase(
+    lottery_state: &LotteryState,
+    user_stats: &UserStats,
+    current_draw_id: u64,
+) -> Option<(u32, u16)> {
+    let streak = user_stats.next_streak(current_draw_id)?;
+    let discount_bps = lottery_state.streak_discount_bps(streak)?;
+    Some((streak, discount_bps))
+}
+
+/// Amount a streak milestone takes off a purchase: `discount_bps` of one
+/// ticket price
+pub(crate) fn streak_discount(ticket_price: u64, discount_bps: u16) -> u64 {
+    (ticket_price as u128 * discount_bps as u128 / BPS_DENOMINATOR as u128) as u64
+}
+
+/// Reject low-entropy numbers when `entropy_validation_enabled` is set
+///
+/// Emits `LowEntropyTicketRejected` before failing so rejections are observable.
+pub(crate) fn check_numbers_entropy(
+    entropy_validation_enabled: bool,
+    player: Pubkey,
+    numbers: &[u8; 6],
+) -> Result<()> {
+    if !entropy_validation_enabled {
+        return Ok(());
+    }
+
+    if let Some(step) = detect_low_entropy_step(numbers) {
+        emit!(LowEntropyTicketRejected {
+            player,
+            numbers: *numbers,
+            pattern_detected: format!("arithmetic sequence with step {}", step),
+        });
+        msg!("Low-entropy numbers rejected: {:?}", numbers);
+        return Err(LottoError::LowEntropyNumbers.into());
+    }
+
     Ok(())
 }
 
 /// Validate ticket numbers
-fn validate_numbers(numbers: &[u8; 6]) -> Result<()> {
-    // Check range for each number
+///
+/// Duplicates are detected with a 64-bit mask in the same pass as the range
+/// check, instead of copying and sorting the numbers and comparing
+/// neighbours. This matters most for `buy_bulk`, which validates every
+/// ticket in the batch.
+pub(crate) fn validate_numbers(numbers: &[u8; 6]) -> Result<()> {
+    // Single pass: range check, then one bit per number (MAX_NUMBER < 64)
+    let mut seen: u64 = 0;
     for &num in numbers.iter() {
         require!(
             num >= MIN_NUMBER && num <= MAX_NUMBER,
             LottoError::NumbersOutOfRange
         );
-    }
 
-    // Check for duplicates by sorting and comparing adjacent
-    let mut sorted = *numbers;
-    sorted.sort();
-    for i in 0..5 {
-        require!(sorted[i] != sorted[i + 1], LottoError::DuplicateNumbers);
+        let bit = 1u64 << num;
+        require!(seen & bit == 0, LottoError::DuplicateNumbers);
+        seen |= bit;
     }
 
     Ok(())
@@ -554,6 +1145,60 @@ fn validate_numbers(numbers: &[u8; 6]) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::{PriceTier, StreakMilestone};
+
+    #[test]
+    fn test_record_jackpot_high() {
+        let mut lottery_state = LotteryState {
+            jackpot_balance: 500,
+            current_draw_id: 3,
+            ..Default::default()
+        };
+        assert_eq!(lottery_state.record_jackpot_high(100), Some(0));
+        assert_eq!(lottery_state.jackpot_all_time_high, 500);
+        assert_eq!(lottery_state.jackpot_ath_draw_id, 3);
+        assert_eq!(lottery_state.jackpot_ath_timestamp, 100);
+        assert_eq!(lottery_state.jackpot_ath_for_season, 500);
+
+        // New season below the all-time high only raises the season high
+        lottery_state.jackpot_ath_for_season = 0;
+        lottery_state.jackpot_balance = 300;
+        lottery_state.current_draw_id = 4;
+        assert_eq!(lottery_state.record_jackpot_high(200), None);
+        assert_eq!(lottery_state.jackpot_all_time_high, 500);
+        assert_eq!(lottery_state.jackpot_ath_draw_id, 3);
+        assert_eq!(lottery_state.jackpot_ath_for_season, 300);
+
+        lottery_state.jackpot_balance = 700;
+        assert_eq!(lottery_state.record_jackpot_high(300), Some(500));
+        assert_eq!(lottery_state.jackpot_ath_draw_id, 4);
+        assert_eq!(lottery_state.jackpot_ath_for_season, 700);
+    }
+
+    #[test]
+    fn test_route_jackpot_overflow() {
+        let mut lottery_state = LotteryState {
+            jackpot_balance: 900,
+            reserve_balance: 50,
+            hard_cap: 1_000,
+            ..Default::default()
+        };
+        assert_eq!(lottery_state.route_jackpot_overflow().unwrap(), 0);
+        assert!(!lottery_state.is_rolldown_active);
+
+        lottery_state.jackpot_balance = 1_200;
+        assert_eq!(lottery_state.route_jackpot_overflow().unwrap(), 200);
+        assert_eq!(lottery_state.jackpot_balance, 1_000);
+        assert_eq!(lottery_state.reserve_balance, 250);
+        assert!(lottery_state.is_rolldown_active);
+
+        // Rolldown flag is left to the draw while one is in progress
+        lottery_state.is_rolldown_active = false;
+        lottery_state.is_draw_in_progress = true;
+        lottery_state.jackpot_balance = 1_100;
+        assert_eq!(lottery_state.route_jackpot_overflow().unwrap(), 100);
+        assert!(!lottery_state.is_rolldown_active);
+    }
 
     #[test]
     fn test_validate_numbers_valid() {
@@ -566,10 +1211,140 @@ mod tests {
         let params = BuyTicketParams {
             numbers: [1, 2, 3, 4, 5, 6],
             use_free_ticket: true,
+            max_price_usdc: 0,
         };
         assert!(params.use_free_ticket);
     }
 
+    fn tiered_state(jackpot_balance: u64) -> LotteryState {
+        LotteryState {
+            ticket_price: 2_500_000,
+            jackpot_balance,
+            dynamic_price_enabled: true,
+            price_tiers: [
+                PriceTier {
+                    threshold: 0,
+                    price: 2_000_000,
+                },
+                PriceTier {
+                    threshold: 1_000_000_000_000,
+                    price: 2_500_000,
+                },
+                PriceTier {
+                    threshold: 1_500_000_000_000,
+                    price: 3_000_000,
+                },
+                PriceTier {
+                    threshold: 1_750_000_000_000,
+                    price: 3_500_000,
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_effective_ticket_price_uses_highest_reached_tier() {
+        assert_eq!(
+            tiered_state(500_000_000_000).effective_ticket_price(),
+            2_000_000
+        );
+        assert_eq!(
+            tiered_state(1_000_000_000_000).effective_ticket_price(),
+            2_500_000
+        );
+        assert_eq!(
+            tiered_state(1_600_000_000_000).effective_ticket_price(),
+            3_000_000
+        );
+        assert_eq!(
+            tiered_state(2_000_000_000_000).effective_ticket_price(),
+            3_500_000
+        );
+    }
+
+    #[test]
+    fn test_effective_ticket_price_falls_back_to_base_price() {
+        let mut state = tiered_state(2_000_000_000_000);
+        state.dynamic_price_enabled = false;
+        assert_eq!(state.effective_ticket_price(), 2_500_000);
+
+        // Below every tier
+        let mut state = tiered_state(100);
+        state.dynamic_price_enabled = true;
+        state.price_tiers[0].threshold = 1_000;
+        assert_eq!(state.effective_ticket_price(), 2_500_000);
+    }
+
+    #[test]
+    fn test_price_tier_schedule_validation() {
+        let tiers = tiered_state(0).price_tiers;
+        assert!(PriceTier::is_valid_schedule(&tiers));
+
+        let mut unsorted = tiers;
+        unsorted.swap(1, 2);
+        assert!(!PriceTier::is_valid_schedule(&unsorted));
+
+        let mut free = tiers;
+        free[3].price = 0;
+        assert!(!PriceTier::is_valid_schedule(&free));
+    }
+
+    #[test]
+    fn test_unlock_milestone_bonuses() {
+        let mut lottery_state = LotteryState {
+            jackpot_balance: 250,
+            milestone_thresholds: [100, 200, 300, 400, 500],
+            milestone_prizes: [10, 20, 30, 40, 50],
+            milestone_claimed: [true; JACKPOT_MILESTONE_COUNT],
+            ..Default::default()
+        };
+
+        unlock_milestone_bonuses(&mut lottery_state, 150);
+        assert_eq!(lottery_state.milestone_unlocked, 0b00010);
+        assert!(lottery_state.is_milestone_bonus_claimable(1));
+        // Milestone 0 was already passed before this purchase
+        assert!(!lottery_state.is_milestone_bonus_claimable(0));
+    }
+
+    #[test]
+    fn test_streak_milestone_for_purchase() {
+        let mut lottery_state = LotteryState::default();
+        lottery_state.streak_milestone_bps[0] = StreakMilestone {
+            streak_length: 2,
+            discount_bps: 1_000,
+        };
+        assert!(StreakMilestone::is_valid_schedule(
+            &lottery_state.streak_milestone_bps
+        ));
+        let mut user_stats = UserStats::default();
+
+        assert_eq!(
+            streak_milestone_for_purchase(&lottery_state, &user_stats, 5),
+            None
+        );
+        assert_eq!(user_stats.update_streak(5), Some(1));
+        assert_eq!(
+            streak_milestone_for_purchase(&lottery_state, &user_stats, 6),
+            Some((2, 1_000))
+        );
+        assert_eq!(user_stats.update_streak(6), Some(2));
+
+        // Only the first purchase in a draw reaches the milestone
+        assert_eq!(
+            streak_milestone_for_purchase(&lottery_state, &user_stats, 6),
+            None
+        );
+        assert_eq!(user_stats.update_streak(6), None);
+
+        // Skipping a draw restarts the streak
+        assert_eq!(user_stats.update_streak(8), Some(1));
+        assert_eq!(user_stats.current_streak, 1);
+        assert_eq!(user_stats.best_streak, 2);
+
+        assert_eq!(streak_discount(2_500_000, 1_000), 250_000);
+    }
+
     #[test]
     fn test_validate_numbers_valid_unsorted() {
         let numbers = [46, 1, 30, 10, 40, 20];
@@ -600,6 +1375,79 @@ mod tests {
         assert!(validate_numbers(&numbers).is_err());
     }
 
+    #[test]
+    fn test_validate_numbers_matches_sort_reference() {
+        // Compute units can't be measured in native tests; instead check the
+        // bitmask version agrees with the previous sort-based check on
+        // 10,000 pseudo-random picks (including out-of-range values).
+        let sort_reference = |numbers: &[u8; 6]| {
+            if numbers
+                .iter()
+                .any(|&n| !(MIN_NUMBER..=MAX_NUMBER).contains(&n))
+            {
+                return false;
+            }
+            let mut sorted = *numbers;
+            sorted.sort();
+            sorted.windows(2).all(|w| w[0] != w[1])
+        };
+
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        for _ in 0..10_000 {
+            let mut numbers = [0u8; 6];
+            for n in numbers.iter_mut() {
+                state = state
+                    .wrapping_mul(6_364_136_223_846_793_005)
+                    .wrapping_add(1_442_695_040_888_963_407);
+                *n = ((state >> 33) % 50) as u8;
+            }
+            assert_eq!(
+                validate_numbers(&numbers).is_ok(),
+                sort_reference(&numbers),
+                "Mismatch for {:?}",
+                numbers
+            );
+        }
+    }
+
+    // =========================================================================
+    // LUCKY DIP TESTS
+    // =========================================================================
+
+    #[test]
+    fn test_lucky_dip_numbers_valid() {
+        let player = Pubkey::new_unique();
+        for total_sold in 0..200u64 {
+            let entropy =
+                compute_lucky_dip_entropy(&player, 1_000 + total_sold, 1_700_000_000, total_sold);
+            let numbers = derive_lucky_dip_numbers(&entropy).expect("should derive numbers");
+            assert!(validate_numbers(&numbers).is_ok());
+            for i in 0..5 {
+                assert!(numbers[i] < numbers[i + 1], "Numbers not sorted");
+            }
+        }
+    }
+
+    #[test]
+    fn test_lucky_dip_deterministic() {
+        let player = Pubkey::new_unique();
+        let entropy_a = compute_lucky_dip_entropy(&player, 42, 1_700_000_000, 7);
+        let entropy_b = compute_lucky_dip_entropy(&player, 42, 1_700_000_000, 7);
+        assert_eq!(entropy_a, entropy_b);
+        assert_eq!(
+            derive_lucky_dip_numbers(&entropy_a).unwrap(),
+            derive_lucky_dip_numbers(&entropy_b).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_lucky_dip_entropy_changes_with_tickets_sold() {
+        let player = Pubkey::new_unique();
+        let entropy_a = compute_lucky_dip_entropy(&player, 42, 1_700_000_000, 7);
+        let entropy_b = compute_lucky_dip_entropy(&player, 42, 1_700_000_000, 8);
+        assert_ne!(entropy_a, entropy_b);
+    }
+
     // =========================================================================
     // ACCOUNTING REMAINDER FIX TESTS (Audit Issue #1)
     // =========================================================================
@@ -0,0 +1,145 @@
+//! Claim Agent Instructions
+//!
+//! A ticket owner can let another wallet (e.g. a custodian or a bot) claim
+//! the ticket's prize on their behalf. The agent only signs `claim_prize`;
+//! the prize is still paid to the owner's USDC account and counted in the
+//! owner's stats. Agents can be authorized once the ticket's draw is the
+//! most recent one and until the prize is claimed.
+//!
+//! - authorize_claim_agent: Set a ticket's claim agent (owner only)
+//! - deauthorize_claim_agent: Clear it (owner only)
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::ClaimAgentAuthorized;
+use crate::state::{LotteryState, SchemaVersioned, TicketData};
+
+/// Whether a claim agent can be authorized for a ticket of `ticket_draw_id`
+///
+/// Only tickets of the draw just before `current_draw_id` qualify.
+pub fn is_claim_agent_window_open(ticket_draw_id: u64, current_draw_id: u64) -> bool {
+    current_draw_id > 0 && ticket_draw_id == current_draw_id - 1
+}
+
+// ============================================================================
+// AUTHORIZE CLAIM AGENT
+// ============================================================================
+
+/// Accounts required for authorizing a claim agent
+#[derive(Accounts)]
+pub struct AuthorizeClaimAgent<'info> {
+    /// The ticket owner
+    pub owner: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The ticket whose prize the agent may claim
+    #[account(
+        mut,
+        constraint = ticket.owner == owner.key() @ LottoError::NotTicketOwner,
+        constraint = !ticket.is_claimed @ LottoError::AlreadyClaimed,
+        constraint = ticket.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub ticket: Account<'info, TicketData>,
+}
+
+/// Allow `agent` to claim the ticket's prize on the owner's behalf
+///
+/// Replaces any previously authorized agent.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `agent` - Wallet allowed to claim
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_authorize_claim_agent(
+    ctx: Context<AuthorizeClaimAgent>,
+    agent: Pubkey,
+) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    require!(
+        agent != Pubkey::default() && agent != owner,
+        LottoError::InvalidClaimAgent
+    );
+    require!(
+        is_claim_agent_window_open(
+            ctx.accounts.ticket.draw_id,
+            ctx.accounts.lottery_state.current_draw_id
+        ),
+        LottoError::ClaimAgentWindowClosed
+    );
+
+    let ticket_key = ctx.accounts.ticket.key();
+    ctx.accounts.ticket.claim_agent = Some(agent);
+
+    emit!(ClaimAgentAuthorized {
+        ticket: ticket_key,
+        owner,
+        agent,
+    });
+
+    msg!("Claim agent authorized");
+    msg!("  Ticket: {}", ticket_key);
+    msg!("  Agent: {}", agent);
+
+    Ok(())
+}
+
+// ============================================================================
+// DEAUTHORIZE CLAIM AGENT
+// ============================================================================
+
+/// Accounts required for removing a ticket's claim agent
+#[derive(Accounts)]
+pub struct DeauthorizeClaimAgent<'info> {
+    /// The ticket owner
+    pub owner: Signer<'info>,
+
+    /// The ticket whose claim agent is removed
+    #[account(
+        mut,
+        constraint = ticket.owner == owner.key() @ LottoError::NotTicketOwner,
+        constraint = ticket.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub ticket: Account<'info, TicketData>,
+}
+
+/// Remove the ticket's claim agent
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_deauthorize_claim_agent(ctx: Context<DeauthorizeClaimAgent>) -> Result<()> {
+    ctx.accounts.ticket.claim_agent = None;
+
+    msg!("Claim agent removed");
+    msg!("  Ticket: {}", ctx.accounts.ticket.key());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claim_agent_window() {
+        assert!(is_claim_agent_window_open(4, 5));
+        // Draw not executed yet
+        assert!(!is_claim_agent_window_open(5, 5));
+        // Older draws
+        assert!(!is_claim_agent_window_open(3, 5));
+        assert!(!is_claim_agent_window_open(0, 0));
+    }
+}
@@ -1,11 +1,13 @@
 //! Claim Bulk Prize Instructions
 //!
-//! SECURITY FIX (Issue #6): Both handlers now update lottery_state internal
-//! accounting (jackpot_balance, reserve_balance) when USDC prizes are transferred,
-//! keeping internal balances consistent with the actual prize_pool_usdc token account.
+//! Every USDC prize is deducted from the lottery's internal balances with
+//! `deduct_prize_from_balances` before it is transferred, keeping them
+//! consistent with the prize_pool_usdc token account.
 //!
 //! SECURITY FIX (Issue #10): claim_all handler enforces a MAX_BULK_CLAIM_BATCH
 //! limit to prevent compute budget exhaustion on large unified tickets.
+//! Larger unified tickets are claimed with claim_batch_prizes_chunk, which
+//! resumes from `unified_ticket.claim_cursor` on each call.
 //!
 //! This instruction allows players to claim their winnings from a unified ticket
 //! (bulk purchase) after a draw. It handles:
@@ -19,19 +21,35 @@
 //! - Free ticket credit for Match 2
 //! - Bitmap tracking for claimed tickets
 
+use std::ops::Range;
+
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::constants::*;
 use crate::errors::LottoError;
-use crate::events::PrizeClaimed;
-use crate::state::{DrawResult, LotteryState, UnifiedTicket, UserStats};
+use crate::events::{BatchClaimChunkProcessed, PrizeClaimed, PrizeEscrowed};
+use crate::instructions::claim_prize::{
+    check_prize_identity, deduct_prize_from_balances, emit_player_stats_updated,
+};
+use crate::instructions::free_quickpick_credit::issue_match_5_free_quickpick_credit;
+use crate::instructions::leaderboard::record_leaderboard_prize;
+use crate::state::{
+    DrawResult, IdentityProof, JackpotLeaderboard, LotteryState, PrizeEscrow, SchemaVersioned,
+    UnifiedTicket, UserStats,
+};
 
 /// Maximum number of tickets that can be claimed in a single claim_all transaction.
 /// Larger unified tickets must use individual claim_bulk_prize calls instead.
 /// This prevents compute budget exhaustion (Issue #10).
 pub const MAX_BULK_CLAIM_BATCH: usize = 20;
 
+/// Maximum tickets processed per claim_batch_prizes_chunk call
+pub const BATCH_CLAIM_STEP_SIZE: usize = MAX_BULK_CLAIM_BATCH;
+
+/// `UnifiedTicket::claim_cursor` value once every ticket has been processed
+pub const BATCH_CLAIM_CURSOR_COMPLETE: u16 = u16::MAX;
+
 /// Parameters for claiming a prize from a unified ticket
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct ClaimBulkPrizeParams {
@@ -78,7 +96,8 @@ pub struct ClaimBulkPrize<'info> {
     #[account(
         mut,
         seeds = [LOTTERY_SEED],
-        bump = lottery_state.bump
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
@@ -89,11 +108,15 @@ pub struct ClaimBulkPrize<'info> {
     )]
     pub unified_ticket: Account<'info, UnifiedTicket>,
 
-    /// The draw result for the ticket's draw
+    /// The draw result for the ticket's draw (must be finalized: before
+    /// finalize_draw every prize is zero and claiming would forfeit the ticket)
     #[account(
         seeds = [DRAW_SEED, &unified_ticket.draw_id.to_le_bytes()],
         bump = draw_result.bump,
-        constraint = draw_result.draw_id == unified_ticket.draw_id @ LottoError::DrawIdMismatch
+        constraint = draw_result.draw_id == unified_ticket.draw_id @ LottoError::DrawIdMismatch,
+        constraint = draw_result.is_finalized() @ LottoError::DrawNotFinalized,
+        constraint = !draw_result.are_claims_frozen() @ LottoError::DrawClaimsFrozen,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub draw_result: Account<'info, DrawResult>,
 
@@ -120,12 +143,65 @@ pub struct ClaimBulkPrize<'info> {
     #[account(
         mut,
         seeds = [USER_SEED, player.key().as_ref()],
-        bump = user_stats.bump
+        bump = user_stats.bump,
+        constraint = user_stats.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub user_stats: Account<'info, UserStats>,
 
+    /// All-time jackpot leaderboard
+    #[account(
+        mut,
+        seeds = [JACKPOT_LEADERBOARD_SEED],
+        bump = jackpot_leaderboard.bump
+    )]
+    pub jackpot_leaderboard: Box<Account<'info, JackpotLeaderboard>>,
+
     /// Token program
     pub token_program: Program<'info, Token>,
+
+    /// The player's identity proof, required only for prizes above
+    /// `identity_verification_threshold`
+    #[account(
+        seeds = [IDENTITY_SEED, player.key().as_ref()],
+        bump = identity_proof.bump,
+        constraint = identity_proof.player == player.key() @ LottoError::InvalidIdentityProof
+    )]
+    pub identity_proof: Option<Account<'info, IdentityProof>>,
+
+    /// Escrow record, required only when the prize is escrowed
+    #[account(
+        init,
+        payer = player,
+        space = PRIZE_ESCROW_SIZE,
+        seeds = [ESCROW_SEED, unified_ticket.key().as_ref()],
+        bump
+    )]
+    pub prize_escrow: Option<Account<'info, PrizeEscrow>>,
+
+    /// Escrow USDC token account, required only when the prize is escrowed
+    #[account(
+        init,
+        payer = player,
+        seeds = [ESCROW_USDC_SEED, unified_ticket.key().as_ref()],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = lottery_state
+    )]
+    pub escrow_usdc: Option<Account<'info, TokenAccount>>,
+
+    /// System program, required only when the prize is escrowed or a free
+    /// QuickPick credit is issued
+    pub system_program: Option<Program<'info, System>>,
+
+    /// QuickPick's state account, required only for Match 5 claims
+    /// CHECK: Owner and PDA verified when the free QuickPick credit is issued
+    pub quickpick_state: Option<UncheckedAccount<'info>>,
+
+    /// Free QuickPick credit PDA for the QuickPick draw on sale, required
+    /// only for Match 5 claims (created by the claim)
+    /// CHECK: PDA verified when the free QuickPick credit is issued
+    #[account(mut)]
+    pub free_qp_credit: Option<UncheckedAccount<'info>>,
 }
 
 /// Count the number of matching numbers between ticket and winning numbers
@@ -174,6 +250,10 @@ fn count_matches(ticket_numbers: &[u8; 6], winning_numbers: &[u8; 6]) -> u8 {
 /// 9. Marks the specific ticket as claimed in the bitmap
 /// 10. Updates user statistics
 ///
+/// Match 6 prizes above `lottery_state.escrow_threshold_usdc` are moved to
+/// the unified ticket's escrow token account, as in `claim_prize`. The
+/// escrow accounts must be supplied exactly when this applies.
+///
 /// # Prize Tiers (Normal Mode)
 /// - Match 6: Jackpot (variable, from draw result)
 /// - Match 5: $4,000
@@ -221,13 +301,6 @@ pub fn handler(ctx: Context<ClaimBulkPrize>, params: ClaimBulkPrizeParams) -> Re
     let draw_timestamp = ctx.accounts.draw_result.timestamp;
     let start_ticket_id = ctx.accounts.unified_ticket.start_ticket_id;
 
-    // Proper finalization check using the is_finalized method
-    // A draw is finalized when prize amounts have been calculated
-    require!(
-        ctx.accounts.draw_result.is_finalized(),
-        LottoError::DrawNotInProgress
-    );
-
     // Check ticket claim expiration (if enabled)
     // Tickets must be claimed within TICKET_CLAIM_EXPIRATION seconds of draw execution
     // FIXED: Use checked_add to prevent potential overflow
@@ -265,6 +338,28 @@ pub fn handler(ctx: Context<ClaimBulkPrize>, params: ClaimBulkPrizeParams) -> Re
     // Check if there's a prize to claim
     let has_prize = prize_amount > 0;
 
+    // Large wins need a current identity proof of the player
+    check_prize_identity(
+        &ctx.accounts.lottery_state,
+        player_key,
+        unified_ticket_key,
+        prize_amount,
+        ctx.accounts.identity_proof.as_deref(),
+        clock.unix_timestamp,
+    )?;
+
+    // Escrow accounts must be supplied exactly when the prize is escrowed
+    let escrow_prize = is_escrow_required(
+        match_count,
+        prize_amount,
+        ctx.accounts.lottery_state.escrow_threshold_usdc,
+    );
+    require!(
+        ctx.accounts.prize_escrow.is_some() == escrow_prize
+            && ctx.accounts.escrow_usdc.is_some() == escrow_prize,
+        LottoError::InvalidEscrowAccounts
+    );
+
     // Handle prize payment
     let mut free_ticket_credited = false;
     let mut actual_transfer_amount = 0u64;
@@ -281,10 +376,21 @@ pub fn handler(ctx: Context<ClaimBulkPrize>, params: ClaimBulkPrizeParams) -> Re
             LottoError::InsufficientPrizePool
         );
 
+        deduct_prize_from_balances(
+            &mut ctx.accounts.lottery_state,
+            ticket_draw_id,
+            match_count,
+            prize_amount,
+        )?;
+
         // Transfer USDC prize
+        let destination = match &ctx.accounts.escrow_usdc {
+            Some(escrow_usdc) if escrow_prize => escrow_usdc,
+            _ => &ctx.accounts.player_usdc,
+        };
         transfer_prize_internal(
             &ctx.accounts.prize_pool_usdc,
-            &ctx.accounts.player_usdc,
+            destination,
             &ctx.accounts.lottery_state,
             &ctx.accounts.token_program,
             prize_amount,
@@ -294,61 +400,40 @@ pub fn handler(ctx: Context<ClaimBulkPrize>, params: ClaimBulkPrizeParams) -> Re
         actual_transfer_amount = prize_amount;
     }
 
-    // SECURITY FIX (Issue #6 + Issue #4): Update lottery_state internal accounting
-    // to stay consistent with the actual prize_pool_usdc token account balance.
-    //
-    // Deduction priority depends on prize tier:
-    // - Match 6 (jackpot): deduct from jackpot_balance, then reserve as fallback.
-    // - Match 3/4/5 (fixed prizes): deduct from fixed_prize_balance first,
-    //   then reserve, then jackpot as last resort.
-    if actual_transfer_amount > 0 {
+    if actual_transfer_amount > 0 && !escrow_prize {
         let lottery_state = &mut ctx.accounts.lottery_state;
 
-        if match_count == 6 {
-            // Jackpot prize: deduct from jackpot_balance first
-            if lottery_state.jackpot_balance >= actual_transfer_amount {
-                lottery_state.jackpot_balance = lottery_state
-                    .jackpot_balance
-                    .saturating_sub(actual_transfer_amount);
-            } else {
-                let from_jackpot = lottery_state.jackpot_balance;
-                let remainder = actual_transfer_amount.saturating_sub(from_jackpot);
-                lottery_state.jackpot_balance = 0;
-                lottery_state.reserve_balance =
-                    lottery_state.reserve_balance.saturating_sub(remainder);
-            }
-        } else {
-            // Fixed prizes (Match 3/4/5): deduct from fixed_prize_balance first
-            let mut remaining = actual_transfer_amount;
-
-            let from_fixed = remaining.min(lottery_state.fixed_prize_balance);
-            lottery_state.fixed_prize_balance =
-                lottery_state.fixed_prize_balance.saturating_sub(from_fixed);
-            remaining = remaining.saturating_sub(from_fixed);
-
-            if remaining > 0 {
-                let from_reserve = remaining.min(lottery_state.reserve_balance);
-                lottery_state.reserve_balance =
-                    lottery_state.reserve_balance.saturating_sub(from_reserve);
-                remaining = remaining.saturating_sub(from_reserve);
-            }
-
-            if remaining > 0 {
-                lottery_state.jackpot_balance =
-                    lottery_state.jackpot_balance.saturating_sub(remaining);
-                msg!(
-                    "WARNING: Fixed prize payment required {} from jackpot (fixed pool exhausted)",
-                    remaining
-                );
-            }
-        }
-
-        // Increment total_prizes_paid at actual claim time
+        // Increment total_prizes_paid at actual claim time (escrowed prizes
+        // are counted on release)
         lottery_state.total_prizes_paid = lottery_state
             .total_prizes_paid
             .saturating_add(actual_transfer_amount);
     }
 
+    // Record the escrow
+    if let Some(prize_escrow) = ctx.accounts.prize_escrow.as_mut() {
+        let release_timestamp = clock
+            .unix_timestamp
+            .checked_add(ctx.accounts.lottery_state.escrow_release_delay_seconds)
+            .ok_or(LottoError::Overflow)?;
+
+        prize_escrow.ticket = unified_ticket_key;
+        prize_escrow.beneficiary = player_key;
+        prize_escrow.amount = actual_transfer_amount;
+        prize_escrow.release_timestamp = release_timestamp;
+        prize_escrow.draw_id = ticket_draw_id;
+        prize_escrow.bump = ctx
+            .bumps
+            .prize_escrow
+            .ok_or(LottoError::InvalidEscrowAccounts)?;
+
+        emit!(PrizeEscrowed {
+            ticket_id: unified_ticket_key,
+            amount: actual_transfer_amount,
+            release_at: release_timestamp,
+        });
+    }
+
     // Mark the specific ticket as claimed in the bitmap
     let unified_ticket = &mut ctx.accounts.unified_ticket;
     unified_ticket.mark_ticket_claimed(ticket_index);
@@ -395,6 +480,39 @@ pub fn handler(ctx: Context<ClaimBulkPrize>, params: ClaimBulkPrizeParams) -> Re
             .ok_or(LottoError::Overflow)?;
     }
 
+    // Match 5 also earns a free QuickPick ticket
+    if match_count == 5 {
+        issue_match_5_free_quickpick_credit(
+            player_key,
+            &ctx.accounts.player,
+            ticket_draw_id,
+            ctx.accounts.quickpick_state.as_ref(),
+            ctx.accounts.free_qp_credit.as_ref(),
+            ctx.accounts.system_program.as_ref(),
+            clock.unix_timestamp,
+        )?;
+    }
+
+    // Track win history
+    if actual_transfer_amount > 0 {
+        user_stats.record_winnings(
+            actual_transfer_amount,
+            actual_transfer_amount,
+            match_count,
+            Some(ticket_draw_id),
+        )?;
+        emit_player_stats_updated(user_stats, clock.unix_timestamp);
+
+        record_leaderboard_prize(
+            &mut ctx.accounts.jackpot_leaderboard,
+            player_key,
+            actual_transfer_amount,
+            ticket_draw_id,
+            match_count,
+            clock.unix_timestamp,
+        );
+    }
+
     // Emit event - use a unique identifier for the ticket
     let ticket_id = start_ticket_id + ticket_index as u64;
     emit!(PrizeClaimed {
@@ -451,7 +569,8 @@ pub struct ClaimAllBulkPrizes<'info> {
     #[account(
         mut,
         seeds = [LOTTERY_SEED],
-        bump = lottery_state.bump
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
@@ -462,11 +581,15 @@ pub struct ClaimAllBulkPrizes<'info> {
     )]
     pub unified_ticket: Account<'info, UnifiedTicket>,
 
-    /// The draw result for the ticket's draw
+    /// The draw result for the ticket's draw (must be finalized: before
+    /// finalize_draw every prize is zero and claiming would forfeit the ticket)
     #[account(
         seeds = [DRAW_SEED, &unified_ticket.draw_id.to_le_bytes()],
         bump = draw_result.bump,
-        constraint = draw_result.draw_id == unified_ticket.draw_id @ LottoError::DrawIdMismatch
+        constraint = draw_result.draw_id == unified_ticket.draw_id @ LottoError::DrawIdMismatch,
+        constraint = draw_result.is_finalized() @ LottoError::DrawNotFinalized,
+        constraint = !draw_result.are_claims_frozen() @ LottoError::DrawClaimsFrozen,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub draw_result: Account<'info, DrawResult>,
 
@@ -493,12 +616,34 @@ pub struct ClaimAllBulkPrizes<'info> {
     #[account(
         mut,
         seeds = [USER_SEED, player.key().as_ref()],
-        bump = user_stats.bump
+        bump = user_stats.bump,
+        constraint = user_stats.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub user_stats: Account<'info, UserStats>,
 
+    /// All-time jackpot leaderboard
+    #[account(
+        mut,
+        seeds = [JACKPOT_LEADERBOARD_SEED],
+        bump = jackpot_leaderboard.bump
+    )]
+    pub jackpot_leaderboard: Box<Account<'info, JackpotLeaderboard>>,
+
     /// Token program
     pub token_program: Program<'info, Token>,
+
+    /// System program, required only for Match 5 claims
+    pub system_program: Option<Program<'info, System>>,
+
+    /// QuickPick's state account, required only for Match 5 claims
+    /// CHECK: Owner and PDA verified when the free QuickPick credit is issued
+    pub quickpick_state: Option<UncheckedAccount<'info>>,
+
+    /// Free QuickPick credit PDA for the QuickPick draw on sale, required
+    /// only for Match 5 claims (created by the claim)
+    /// CHECK: PDA verified when the free QuickPick credit is issued
+    #[account(mut)]
+    pub free_qp_credit: Option<UncheckedAccount<'info>>,
 }
 
 /// Claim all prizes from a unified ticket
@@ -510,6 +655,9 @@ pub struct ClaimAllBulkPrizes<'info> {
 /// WARNING: For large unified tickets (>20-30 tickets), this may exceed
 /// compute limits. Use claim_bulk_prize for individual claims instead.
 ///
+/// Any Match 5 win issues one free QuickPick credit and requires the
+/// optional `quickpick_state`, `free_qp_credit` and `system_program`.
+///
 /// # Arguments
 /// * `ctx` - The context containing all required accounts
 ///
@@ -520,218 +668,268 @@ pub fn handler_claim_all(ctx: Context<ClaimAllBulkPrizes>) -> Result<()> {
 
     // Get values before mutable borrows
     let lottery_bump = ctx.accounts.lottery_state.bump;
-    let winning_numbers = ctx.accounts.draw_result.winning_numbers;
     let ticket_draw_id = ctx.accounts.unified_ticket.draw_id;
     let unified_ticket_key = ctx.accounts.unified_ticket.key();
     let player_key = ctx.accounts.player.key();
-    let mut prize_pool_balance = ctx.accounts.prize_pool_usdc.amount;
-    let draw_timestamp = ctx.accounts.draw_result.timestamp;
     let ticket_count = ctx.accounts.unified_ticket.ticket_count as usize;
 
     // SECURITY FIX (Issue #10): Enforce batch limit to prevent compute budget exhaustion.
-    // Large unified tickets (>20 tickets) must use individual claim_bulk_prize calls.
+    // Large unified tickets (>20 tickets) must use claim_batch_prizes_chunk or
+    // individual claim_bulk_prize calls.
     require!(
         ticket_count <= MAX_BULK_CLAIM_BATCH,
         LottoError::BulkPurchaseLimitExceeded
     );
 
-    // Proper finalization check
-    require!(
-        ctx.accounts.draw_result.is_finalized(),
-        LottoError::DrawNotInProgress
-    );
+    check_claim_not_expired(ctx.accounts.draw_result.timestamp, clock.unix_timestamp)?;
+
+    // Process all unclaimed tickets
+    let tally = claim_unified_ticket_range(
+        &mut ctx.accounts.lottery_state,
+        &mut ctx.accounts.unified_ticket,
+        &ctx.accounts.draw_result,
+        &mut ctx.accounts.jackpot_leaderboard,
+        ctx.accounts.prize_pool_usdc.amount,
+        0..ticket_count,
+        clock.unix_timestamp,
+    )?;
+
+    // Transfer total prize amount if any
+    if tally.total_prize_amount > 0 {
+        transfer_prize_internal(
+            &ctx.accounts.prize_pool_usdc,
+            &ctx.accounts.player_usdc,
+            &ctx.accounts.lottery_state,
+            &ctx.accounts.token_program,
+            tally.total_prize_amount,
+            lottery_bump,
+        )?;
+
+        let lottery_state = &mut ctx.accounts.lottery_state;
+
+        // Increment total_prizes_paid at actual claim time
+        lottery_state.total_prizes_paid = lottery_state
+            .total_prizes_paid
+            .saturating_add(tally.total_prize_amount);
+    }
+
+    credit_bulk_claim(
+        &mut ctx.accounts.user_stats,
+        &tally,
+        ticket_draw_id,
+        clock.unix_timestamp,
+    )?;
+
+    // Match 5 also earns a free QuickPick ticket
+    if tally.match_5_wins > 0 {
+        issue_match_5_free_quickpick_credit(
+            player_key,
+            &ctx.accounts.player,
+            ticket_draw_id,
+            ctx.accounts.quickpick_state.as_ref(),
+            ctx.accounts.free_qp_credit.as_ref(),
+            ctx.accounts.system_program.as_ref(),
+            clock.unix_timestamp,
+        )?;
+    }
+
+    msg!("Bulk prize claim completed!");
+    msg!("  Unified Ticket: {}", unified_ticket_key);
+    msg!("  Player: {}", player_key);
+    msg!("  Draw ID: {}", ticket_draw_id);
+    msg!("  Total tickets processed: {}", ticket_count);
+    tally.log();
+
+    Ok(())
+}
+
+/// Running totals of a claim over several tickets of a unified ticket
+#[derive(Default)]
+struct BulkClaimTally {
+    total_prize_amount: u64,
+    total_free_tickets: u32,
+    tickets_claimed: u32,
+    jackpot_wins: u32,
+    match_5_wins: u32,
+    skipped_insufficient_funds: u32,
+    largest_prize: u64,
+    best_match_count: u8,
+    /// First ticket left unclaimed because the prize pool ran short
+    first_shortfall: Option<usize>,
+}
+
+impl BulkClaimTally {
+    fn log(&self) {
+        msg!("  Tickets with prizes: {}", self.tickets_claimed);
+        msg!("  Total USDC won: {} lamports", self.total_prize_amount);
+        msg!("  Free tickets credited: {}", self.total_free_tickets);
+        if self.skipped_insufficient_funds > 0 {
+            msg!(
+                "  WARNING: {} tickets skipped due to insufficient prize pool (unclaimed, retryable)",
+                self.skipped_insufficient_funds
+            );
+        }
+        if self.jackpot_wins > 0 {
+            msg!("  JACKPOT WINS: {}", self.jackpot_wins);
+        }
+    }
+}
 
+/// Fail with `TicketExpired` once the claim window of a draw has passed
+fn check_claim_not_expired(draw_timestamp: i64, current_timestamp: i64) -> Result<()> {
     // FIXED: Check ticket claim expiration (if enabled) with checked arithmetic
     if TICKET_CLAIM_EXPIRATION > 0 {
         let claim_deadline = draw_timestamp
             .checked_add(TICKET_CLAIM_EXPIRATION)
             .ok_or(LottoError::ArithmeticError)?;
 
-        if clock.unix_timestamp > claim_deadline {
+        if current_timestamp > claim_deadline {
             msg!("Ticket claim expired!");
             msg!("  Draw timestamp: {}", draw_timestamp);
             msg!("  Claim deadline: {}", claim_deadline);
-            msg!("  Current time: {}", clock.unix_timestamp);
+            msg!("  Current time: {}", current_timestamp);
             return Err(LottoError::TicketExpired.into());
         }
     }
+    Ok(())
+}
 
-    // Track totals
-    let mut total_prize_amount = 0u64;
-    let mut total_free_tickets = 0u32;
-    let mut tickets_claimed = 0u32;
-    let mut jackpot_wins = 0u32;
-    let mut skipped_insufficient_funds = 0u32;
-
-    // FIXED: Track which tickets were successfully processed so we only
-    // mark those as claimed. Tickets skipped due to insufficient prize pool
-    // must NOT be marked claimed — the user should be able to claim them later.
-    // Use a simple boolean array (max ticket_count entries).
-    let mut processed = vec![false; ticket_count];
-
-    // Process all unclaimed tickets
-    for ticket_index in 0..ticket_count {
+/// Claim the unclaimed tickets of `unified_ticket` in `range`
+///
+/// Prizes are deducted from the lottery balances and recorded on the
+/// leaderboard but not transferred; the caller pays the tally total in one
+/// transfer. Tickets whose prize exceeds what is left of
+/// `prize_pool_balance` stay unclaimed so they can be retried later.
+/// Prizes above `identity_verification_threshold` or that must be escrowed
+/// fail the whole batch; they must be claimed with `claim_bulk_prize`.
+/// Claimed Match 5 wins are counted so the caller can issue the free
+/// QuickPick credit.
+fn claim_unified_ticket_range(
+    lottery_state: &mut LotteryState,
+    unified_ticket: &mut UnifiedTicket,
+    draw_result: &DrawResult,
+    jackpot_leaderboard: &mut JackpotLeaderboard,
+    mut prize_pool_balance: u64,
+    range: Range<usize>,
+    timestamp: i64,
+) -> Result<BulkClaimTally> {
+    let winning_numbers = draw_result.winning_numbers;
+    let ticket_draw_id = unified_ticket.draw_id;
+    let player_key = unified_ticket.owner;
+    let mut tally = BulkClaimTally::default();
+
+    for ticket_index in range {
         // Skip already claimed tickets
-        if ctx.accounts.unified_ticket.is_ticket_claimed(ticket_index) {
+        if unified_ticket.is_ticket_claimed(ticket_index) {
             continue;
         }
 
-        let ticket_numbers = ctx.accounts.unified_ticket.numbers[ticket_index];
+        let ticket_numbers = unified_ticket.numbers[ticket_index];
         let match_count = count_matches(&ticket_numbers, &winning_numbers);
 
         // Determine prize amount from draw result
         let prize_amount = match match_count {
-            6 => ctx.accounts.draw_result.match_6_prize_per_winner,
-            5 => ctx.accounts.draw_result.match_5_prize_per_winner,
-            4 => ctx.accounts.draw_result.match_4_prize_per_winner,
-            3 => ctx.accounts.draw_result.match_3_prize_per_winner,
-            2 => ctx.accounts.draw_result.match_2_prize_per_winner,
+            6 => draw_result.match_6_prize_per_winner,
+            5 => draw_result.match_5_prize_per_winner,
+            4 => draw_result.match_4_prize_per_winner,
+            3 => draw_result.match_3_prize_per_winner,
+            2 => draw_result.match_2_prize_per_winner,
             _ => 0,
         };
 
         if match_count == 2 && prize_amount > 0 {
             // Free ticket credit
-            total_free_tickets += 1;
-            tickets_claimed += 1;
-            processed[ticket_index] = true;
+            tally.total_free_tickets += 1;
+            tally.tickets_claimed += 1;
         } else if prize_amount > 0 {
+            require!(
+                !is_identity_verification_required(
+                    prize_amount,
+                    lottery_state.identity_verification_threshold
+                ),
+                LottoError::IdentityVerificationRequired
+            );
+            require!(
+                !is_escrow_required(
+                    match_count,
+                    prize_amount,
+                    lottery_state.escrow_threshold_usdc
+                ),
+                LottoError::EscrowRequired
+            );
+
             // Verify prize pool solvency
-            if prize_pool_balance >= prize_amount {
-                total_prize_amount += prize_amount;
-                prize_pool_balance -= prize_amount;
-                tickets_claimed += 1;
-                processed[ticket_index] = true;
-
-                if match_count == 6 {
-                    jackpot_wins += 1;
-                }
-            } else {
+            if prize_pool_balance < prize_amount {
                 // FIXED: Do NOT mark as claimed — insufficient funds.
                 // The user can retry this ticket later when the pool is replenished.
-                skipped_insufficient_funds += 1;
+                tally.skipped_insufficient_funds += 1;
+                tally.first_shortfall.get_or_insert(ticket_index);
                 msg!(
                     "  Ticket #{} skipped: prize {} exceeds available pool {}",
                     ticket_index,
                     prize_amount,
                     prize_pool_balance
                 );
+                continue;
             }
-        } else {
-            // No prize, safe to mark as claimed
-            tickets_claimed += 1;
-            processed[ticket_index] = true;
-        }
-    }
-
-    // Transfer total prize amount if any
-    if total_prize_amount > 0 {
-        transfer_prize_internal(
-            &ctx.accounts.prize_pool_usdc,
-            &ctx.accounts.player_usdc,
-            &ctx.accounts.lottery_state,
-            &ctx.accounts.token_program,
-            total_prize_amount,
-            lottery_bump,
-        )?;
 
-        // SECURITY FIX (Issue #6 + Issue #4): Update lottery_state internal accounting.
-        // For bulk claims, we conservatively deduct from fixed_prize_balance first
-        // for non-jackpot prizes. Since we're processing mixed tiers in bulk,
-        // we deduct the total from fixed first, then reserve, then jackpot.
-        // Jackpot wins are tracked separately above for stats.
-        let lottery_state = &mut ctx.accounts.lottery_state;
+            deduct_prize_from_balances(lottery_state, ticket_draw_id, match_count, prize_amount)?;
+
+            tally.total_prize_amount += prize_amount;
+            prize_pool_balance -= prize_amount;
+            tally.tickets_claimed += 1;
+            tally.largest_prize = tally.largest_prize.max(prize_amount);
+            tally.best_match_count = tally.best_match_count.max(match_count);
+
+            record_leaderboard_prize(
+                jackpot_leaderboard,
+                player_key,
+                prize_amount,
+                ticket_draw_id,
+                match_count,
+                timestamp,
+            );
 
-        // Separate jackpot prize amount from fixed prize amount
-        // jackpot_wins tracks Match-6 count; each Match-6 winner gets match_6_prize_per_winner
-        let jackpot_prize_total = if jackpot_wins > 0 {
-            ctx.accounts
-                .draw_result
-                .match_6_prize_per_winner
-                .saturating_mul(jackpot_wins as u64)
-                .min(total_prize_amount)
-        } else {
-            0u64
-        };
-        let fixed_prize_total = total_prize_amount.saturating_sub(jackpot_prize_total);
-
-        // Deduct jackpot portion from jackpot_balance
-        if jackpot_prize_total > 0 {
-            if lottery_state.jackpot_balance >= jackpot_prize_total {
-                lottery_state.jackpot_balance = lottery_state
-                    .jackpot_balance
-                    .saturating_sub(jackpot_prize_total);
-            } else {
-                let from_jackpot = lottery_state.jackpot_balance;
-                let remainder = jackpot_prize_total.saturating_sub(from_jackpot);
-                lottery_state.jackpot_balance = 0;
-                lottery_state.reserve_balance =
-                    lottery_state.reserve_balance.saturating_sub(remainder);
+            if match_count == 6 {
+                tally.jackpot_wins += 1;
             }
         }
 
-        // Deduct fixed prize portion from fixed_prize_balance first
-        if fixed_prize_total > 0 {
-            let mut remaining = fixed_prize_total;
-
-            let from_fixed = remaining.min(lottery_state.fixed_prize_balance);
-            lottery_state.fixed_prize_balance =
-                lottery_state.fixed_prize_balance.saturating_sub(from_fixed);
-            remaining = remaining.saturating_sub(from_fixed);
-
-            if remaining > 0 {
-                let from_reserve = remaining.min(lottery_state.reserve_balance);
-                lottery_state.reserve_balance =
-                    lottery_state.reserve_balance.saturating_sub(from_reserve);
-                remaining = remaining.saturating_sub(from_reserve);
-            }
-
-            if remaining > 0 {
-                lottery_state.jackpot_balance =
-                    lottery_state.jackpot_balance.saturating_sub(remaining);
-                msg!(
-                    "WARNING: Fixed prize payment required {} from jackpot (fixed pool exhausted)",
-                    remaining
-                );
-            }
+        if match_count == 5 {
+            tally.match_5_wins += 1;
         }
 
-        // Increment total_prizes_paid at actual claim time
-        lottery_state.total_prizes_paid = lottery_state
-            .total_prizes_paid
-            .saturating_add(total_prize_amount);
-    }
-
-    // FIXED: Only mark tickets as claimed if they were successfully processed.
-    // Tickets skipped due to insufficient prize pool remain unclaimed so the
-    // user can claim them later (e.g., after insurance top-up).
-    let unified_ticket = &mut ctx.accounts.unified_ticket;
-    for ticket_index in 0..ticket_count {
-        if processed[ticket_index] && !unified_ticket.is_ticket_claimed(ticket_index) {
-            unified_ticket.mark_ticket_claimed(ticket_index);
-        }
+        unified_ticket.mark_ticket_claimed(ticket_index);
     }
 
-    // Update user stats
-    let user_stats = &mut ctx.accounts.user_stats;
+    Ok(tally)
+}
 
-    if total_prize_amount > 0 {
+/// Credit a bulk claim's winnings and free tickets to the player's stats
+fn credit_bulk_claim(
+    user_stats: &mut UserStats,
+    tally: &BulkClaimTally,
+    ticket_draw_id: u64,
+    timestamp: i64,
+) -> Result<()> {
+    if tally.total_prize_amount > 0 {
         user_stats.total_won = user_stats
             .total_won
-            .checked_add(total_prize_amount)
+            .checked_add(tally.total_prize_amount)
             .ok_or(LottoError::Overflow)?;
     }
 
     // FIXED: Apply MAX_FREE_TICKETS limit when crediting free tickets
-    if total_free_tickets > 0 {
+    if tally.total_free_tickets > 0 {
         let current_free = user_stats.free_tickets_available;
         let max_addable = (MAX_FREE_TICKETS as u32).saturating_sub(current_free);
-        let actual_added = total_free_tickets.min(max_addable);
+        let actual_added = tally.total_free_tickets.min(max_addable);
 
-        if actual_added < total_free_tickets {
+        if actual_added < tally.total_free_tickets {
             msg!(
                 "WARNING: Free ticket limit reached. Only {} of {} free tickets credited.",
                 actual_added,
-                total_free_tickets
+                tally.total_free_tickets
             );
             msg!("  Current free tickets: {}", current_free);
             msg!("  Maximum allowed: {}", MAX_FREE_TICKETS);
@@ -745,29 +943,242 @@ pub fn handler_claim_all(ctx: Context<ClaimAllBulkPrizes>) -> Result<()> {
         }
     }
 
-    if jackpot_wins > 0 {
+    if tally.jackpot_wins > 0 {
         user_stats.jackpot_wins = user_stats
             .jackpot_wins
-            .checked_add(jackpot_wins)
+            .checked_add(tally.jackpot_wins)
             .ok_or(LottoError::Overflow)?;
     }
 
-    msg!("Bulk prize claim completed!");
-    msg!("  Unified Ticket: {}", unified_ticket_key);
-    msg!("  Player: {}", player_key);
-    msg!("  Draw ID: {}", ticket_draw_id);
-    msg!("  Total tickets processed: {}", ticket_count);
-    msg!("  Tickets with prizes: {}", tickets_claimed);
-    msg!("  Total USDC won: {} lamports", total_prize_amount);
-    msg!("  Free tickets credited: {}", total_free_tickets);
-    if skipped_insufficient_funds > 0 {
-        msg!(
-            "  WARNING: {} tickets skipped due to insufficient prize pool (unclaimed, retryable)",
-            skipped_insufficient_funds
-        );
+    // Track win history
+    if tally.total_prize_amount > 0 {
+        user_stats.record_winnings(
+            tally.total_prize_amount,
+            tally.largest_prize,
+            tally.best_match_count,
+            Some(ticket_draw_id),
+        )?;
+        emit_player_stats_updated(user_stats, timestamp);
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// CLAIM BATCH PRIZES CHUNK
+// ============================================================================
+
+/// Claim prizes from a unified ticket a chunk at a time
+///
+/// Same accounts as `ClaimAllBulkPrizes`; progress is kept in
+/// `unified_ticket.claim_cursor` so any batch size can be claimed.
+#[derive(Accounts)]
+pub struct ClaimBatchPrizesChunk<'info> {
+    /// The player claiming the prizes (must be unified ticket owner)
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The unified ticket containing the tickets
+    #[account(
+        mut,
+        constraint = unified_ticket.owner == player.key() @ LottoError::NotTicketOwner
+    )]
+    pub unified_ticket: Account<'info, UnifiedTicket>,
+
+    /// The draw result for the ticket's draw (must be finalized)
+    #[account(
+        seeds = [DRAW_SEED, &unified_ticket.draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = draw_result.draw_id == unified_ticket.draw_id @ LottoError::DrawIdMismatch,
+        constraint = draw_result.is_finalized() @ LottoError::DrawNotFinalized,
+        constraint = !draw_result.are_claims_frozen() @ LottoError::DrawClaimsFrozen,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub draw_result: Account<'info, DrawResult>,
+
+    /// Player's USDC token account (to receive prize)
+    #[account(
+        mut,
+        constraint = player_usdc.owner == player.key() @ LottoError::TokenAccountOwnerMismatch,
+        constraint = player_usdc.mint == usdc_mint.key() @ LottoError::InvalidUsdcMint
+    )]
+    pub player_usdc: Account<'info, TokenAccount>,
+
+    /// Prize pool USDC token account
+    #[account(
+        mut,
+        seeds = [PRIZE_POOL_USDC_SEED],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+
+    /// USDC mint
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// User statistics account
+    #[account(
+        mut,
+        seeds = [USER_SEED, player.key().as_ref()],
+        bump = user_stats.bump,
+        constraint = user_stats.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub user_stats: Account<'info, UserStats>,
+
+    /// All-time jackpot leaderboard
+    #[account(
+        mut,
+        seeds = [JACKPOT_LEADERBOARD_SEED],
+        bump = jackpot_leaderboard.bump
+    )]
+    pub jackpot_leaderboard: Box<Account<'info, JackpotLeaderboard>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program, required only for Match 5 claims
+    pub system_program: Option<Program<'info, System>>,
+
+    /// QuickPick's state account, required only for Match 5 claims
+    /// CHECK: Owner and PDA verified when the free QuickPick credit is issued
+    pub quickpick_state: Option<UncheckedAccount<'info>>,
+
+    /// Free QuickPick credit PDA for the QuickPick draw on sale, required
+    /// only for Match 5 claims (created by the claim)
+    /// CHECK: PDA verified when the free QuickPick credit is issued
+    #[account(mut)]
+    pub free_qp_credit: Option<UncheckedAccount<'info>>,
+}
+
+/// Cursor to store after a chunk stopped at `resume_index`
+///
+/// Returns BATCH_CLAIM_CURSOR_COMPLETE once `resume_index` reaches
+/// `ticket_count`.
+fn next_claim_cursor(resume_index: usize, ticket_count: usize) -> u16 {
+    if resume_index >= ticket_count {
+        BATCH_CLAIM_CURSOR_COMPLETE
+    } else {
+        resume_index as u16
+    }
+}
+
+/// Claim up to `max_entries` tickets of a unified ticket from its cursor
+///
+/// This instruction:
+/// 1. Processes `min(max_entries, BATCH_CLAIM_STEP_SIZE)` tickets starting
+///    at `unified_ticket.claim_cursor`, marking each one claimed
+/// 2. Pays the chunk's USDC prizes in a single transfer and issues the free
+///    QuickPick credit if the chunk holds a Match 5 win
+/// 3. Advances the cursor, stopping at the first ticket the prize pool
+///    cannot cover so it is retried on the next call
+/// 4. Emits `BatchClaimChunkProcessed`
+///
+/// Clients call this in a loop until `cursor_complete` is true (or the
+/// cursor stops moving because the prize pool is short).
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+/// * `max_entries` - Tickets to process in this call (capped at BATCH_CLAIM_STEP_SIZE)
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_claim_batch_prizes_chunk(
+    ctx: Context<ClaimBatchPrizesChunk>,
+    max_entries: u8,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(max_entries > 0, LottoError::InvalidBatchClaimChunk);
+    let start_cursor = ctx.accounts.unified_ticket.claim_cursor;
+    require!(
+        start_cursor != BATCH_CLAIM_CURSOR_COMPLETE,
+        LottoError::BatchClaimComplete
+    );
+
+    check_claim_not_expired(ctx.accounts.draw_result.timestamp, clock.unix_timestamp)?;
+
+    let lottery_bump = ctx.accounts.lottery_state.bump;
+    let ticket_draw_id = ctx.accounts.unified_ticket.draw_id;
+    let batch_id = ctx.accounts.unified_ticket.key();
+    let ticket_count = ctx.accounts.unified_ticket.ticket_count as usize;
+    let start = start_cursor as usize;
+    let end = start
+        .saturating_add((max_entries as usize).min(BATCH_CLAIM_STEP_SIZE))
+        .min(ticket_count);
+
+    let tally = claim_unified_ticket_range(
+        &mut ctx.accounts.lottery_state,
+        &mut ctx.accounts.unified_ticket,
+        &ctx.accounts.draw_result,
+        &mut ctx.accounts.jackpot_leaderboard,
+        ctx.accounts.prize_pool_usdc.amount,
+        start..end,
+        clock.unix_timestamp,
+    )?;
+
+    if tally.total_prize_amount > 0 {
+        transfer_prize_internal(
+            &ctx.accounts.prize_pool_usdc,
+            &ctx.accounts.player_usdc,
+            &ctx.accounts.lottery_state,
+            &ctx.accounts.token_program,
+            tally.total_prize_amount,
+            lottery_bump,
+        )?;
+
+        let lottery_state = &mut ctx.accounts.lottery_state;
+        lottery_state.total_prizes_paid = lottery_state
+            .total_prizes_paid
+            .saturating_add(tally.total_prize_amount);
     }
-    if jackpot_wins > 0 {
-        msg!("  JACKPOT WINS: {}", jackpot_wins);
+
+    credit_bulk_claim(
+        &mut ctx.accounts.user_stats,
+        &tally,
+        ticket_draw_id,
+        clock.unix_timestamp,
+    )?;
+
+    // Match 5 also earns a free QuickPick ticket
+    if tally.match_5_wins > 0 {
+        issue_match_5_free_quickpick_credit(
+            ctx.accounts.player.key(),
+            &ctx.accounts.player,
+            ticket_draw_id,
+            ctx.accounts.quickpick_state.as_ref(),
+            ctx.accounts.free_qp_credit.as_ref(),
+            ctx.accounts.system_program.as_ref(),
+            clock.unix_timestamp,
+        )?;
+    }
+
+    let end_cursor = next_claim_cursor(tally.first_shortfall.unwrap_or(end), ticket_count);
+    let cursor_complete = end_cursor == BATCH_CLAIM_CURSOR_COMPLETE;
+    ctx.accounts.unified_ticket.claim_cursor = end_cursor;
+
+    emit!(BatchClaimChunkProcessed {
+        batch_id,
+        start_cursor,
+        end_cursor,
+        prizes_this_chunk: tally.total_prize_amount,
+        cursor_complete,
+    });
+
+    msg!("Batch claim chunk processed!");
+    msg!("  Unified Ticket: {}", batch_id);
+    msg!("  Draw ID: {}", ticket_draw_id);
+    msg!("  Cursor: {} -> {}", start_cursor, end_cursor);
+    tally.log();
+    if cursor_complete {
+        msg!("  All {} tickets processed", ticket_count);
     }
 
     Ok(())
@@ -812,6 +1223,55 @@ mod tests {
         assert_eq!(count_matches(&ticket, &winning), 5);
     }
 
+    #[test]
+    fn test_claim_range_counts_match_5_wins() {
+        let mut lottery_state = LotteryState {
+            jackpot_balance: 1_000_000_000,
+            reserve_balance: 1_000_000_000,
+            ..Default::default()
+        };
+        let mut unified_ticket = UnifiedTicket {
+            ticket_count: 3,
+            numbers: vec![
+                [1, 2, 3, 4, 5, 6],
+                [1, 2, 3, 4, 5, 8],
+                [10, 11, 12, 13, 14, 15],
+            ],
+            claimed_bitmap: vec![0],
+            ..Default::default()
+        };
+        let draw_result = DrawResult {
+            winning_numbers: [1, 2, 3, 4, 5, 7],
+            match_5_prize_per_winner: 4_000_000,
+            ..Default::default()
+        };
+        let mut leaderboard = JackpotLeaderboard::default();
+
+        // The pool covers only one Match 5 prize; the other stays unclaimed
+        // and is not counted towards the free QuickPick credit
+        let tally = claim_unified_ticket_range(
+            &mut lottery_state,
+            &mut unified_ticket,
+            &draw_result,
+            &mut leaderboard,
+            4_000_000,
+            0..3,
+            0,
+        )
+        .unwrap();
+        assert_eq!(tally.match_5_wins, 1);
+        assert_eq!(tally.skipped_insufficient_funds, 1);
+        assert!(!unified_ticket.is_ticket_claimed(1));
+    }
+
+    #[test]
+    fn test_next_claim_cursor() {
+        assert_eq!(next_claim_cursor(0, 45), 0);
+        assert_eq!(next_claim_cursor(20, 45), 20);
+        assert_eq!(next_claim_cursor(45, 45), BATCH_CLAIM_CURSOR_COMPLETE);
+        assert_eq!(next_claim_cursor(0, 0), BATCH_CLAIM_CURSOR_COMPLETE);
+    }
+
     #[test]
     fn test_claim_bulk_prize_params() {
         let params = ClaimBulkPrizeParams { ticket_index: 5 };
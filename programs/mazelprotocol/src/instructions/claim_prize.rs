@@ -2,29 +2,48 @@
 //!
 //! This instruction allows players to claim their winnings after a draw.
 //! It handles:
-//! - Verification that the draw is complete and finalized
+//! - Verification that the draw is complete and finalized, and that its
+//!   claims are not frozen by a draw challenge
 //! - Ticket claim expiration check (configurable, default 90 days)
 //! - Match count calculation against winning numbers
 //! - Prize amount determination (fixed or rolldown)
 //! - Prize pool solvency verification (with detailed error reporting)
-//! - USDC transfer from prize pool to player
+//! - USDC transfer from prize pool to player, creating the player's USDC
+//!   associated token account (at the player's expense) if it is missing
 //! - User stats updates
 //! - Free ticket credit for Match 2
+//! - Free QuickPick ticket credit for Match 5
+//! - Escrow of Match 6 prizes above the configured escrow threshold
+//! - Identity proof for prizes above the identity verification threshold
+//!   (`claim_prize_with_identity`)
+//! - Per-draw claims on multi-draw tickets
+//! - Claims signed by the ticket's claim agent (paid to the owner)
 //! - Edge case handling for insufficient funds and expired claims
 
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{self, get_associated_token_address, AssociatedToken};
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::constants::*;
 use crate::errors::LottoError;
-use crate::events::PrizeClaimed;
-use crate::state::{DrawResult, LotteryState, TicketData, UserStats};
+use crate::events::{
+    LargeWinIdentityVerified, MultiDrawPrizesClaimed, PlayerStatsUpdated, PrizeClaimed,
+    PrizeClaimedByAgent, PrizeEscrowed, PrizePoolInsolvent, TokenAccountAutoCreated,
+};
+use crate::instructions::close_ticket::is_claim_window_expired;
+use crate::instructions::free_quickpick_credit::issue_match_5_free_quickpick_credit;
+use crate::instructions::leaderboard::record_leaderboard_prize;
+use crate::instructions::state_diff::{emit_state_diff, LotteryStateSnapshot};
+use crate::state::{
+    DrawResult, IdentityProof, JackpotLeaderboard, LotteryState, MultiDrawTicket, PrizeEscrow,
+    SchemaVersioned, TicketData, UserStats,
+};
 
 /// Transfer prize from prize pool to player (standalone function)
 /// Includes comprehensive solvency check before transfer
 fn transfer_prize_internal<'info>(
     prize_pool_usdc: &Account<'info, TokenAccount>,
-    player_usdc: &Account<'info, TokenAccount>,
+    player_usdc: &impl ToAccountInfo<'info>,
     lottery_state: &Account<'info, LotteryState>,
     token_program: &Program<'info, Token>,
     amount: u64,
@@ -57,7 +76,7 @@ fn transfer_prize_internal<'info>(
 /// Accounts required for claiming a prize
 #[derive(Accounts)]
 pub struct ClaimPrize<'info> {
-    /// The player claiming the prize (must be ticket owner)
+    /// The wallet claiming the prize (the ticket owner or its claim agent)
     #[account(mut)]
     pub player: Signer<'info>,
 
@@ -65,33 +84,39 @@ pub struct ClaimPrize<'info> {
     #[account(
         mut,
         seeds = [LOTTERY_SEED],
-        bump = lottery_state.bump
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
     /// The ticket being claimed
     #[account(
         mut,
-        constraint = ticket.owner == player.key() @ LottoError::NotTicketOwner,
-        constraint = !ticket.is_claimed @ LottoError::AlreadyClaimed
+        constraint = ticket.owner == player.key()
+            || ticket.claim_agent == Some(player.key()) @ LottoError::NotTicketOwner,
+        constraint = !ticket.is_claimed @ LottoError::AlreadyClaimed,
+        constraint = ticket.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub ticket: Account<'info, TicketData>,
 
-    /// The draw result for the ticket's draw
+    /// The draw result for the ticket's draw (must be finalized: before
+    /// finalize_draw every prize is zero and claiming would forfeit the ticket)
     #[account(
         seeds = [DRAW_SEED, &ticket.draw_id.to_le_bytes()],
         bump = draw_result.bump,
-        constraint = draw_result.draw_id == ticket.draw_id @ LottoError::DrawIdMismatch
+        constraint = draw_result.draw_id == ticket.draw_id @ LottoError::DrawIdMismatch,
+        constraint = draw_result.is_finalized() @ LottoError::DrawNotFinalized,
+        constraint = !draw_result.are_claims_frozen() @ LottoError::DrawClaimsFrozen,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub draw_result: Account<'info, DrawResult>,
 
-    /// Player's USDC token account (to receive prize)
-    #[account(
-        mut,
-        constraint = player_usdc.owner == player.key() @ LottoError::TokenAccountOwnerMismatch,
-        constraint = player_usdc.mint == usdc_mint.key() @ LottoError::InvalidUsdcMint
-    )]
-    pub player_usdc: Account<'info, TokenAccount>,
+    /// Ticket owner's USDC token account (to receive prize)
+    /// CHECK: Validated in `load_player_usdc`. If it does not exist yet it
+    /// must be the owner's USDC ATA, which is created when a USDC prize is
+    /// paid to an owner claiming for themselves.
+    #[account(mut)]
+    pub player_usdc: UncheckedAccount<'info>,
 
     /// Prize pool USDC token account
     #[account(
@@ -104,16 +129,140 @@ pub struct ClaimPrize<'info> {
     /// USDC mint
     pub usdc_mint: Account<'info, Mint>,
 
-    /// User statistics account
+    /// Ticket owner's user statistics account
     #[account(
         mut,
-        seeds = [USER_SEED, player.key().as_ref()],
-        bump = user_stats.bump
+        seeds = [USER_SEED, ticket.owner.as_ref()],
+        bump = user_stats.bump,
+        constraint = user_stats.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub user_stats: Account<'info, UserStats>,
 
+    /// Associated token program, required only when `player_usdc` must be
+    /// created
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    /// All-time jackpot leaderboard
+    #[account(
+        mut,
+        seeds = [JACKPOT_LEADERBOARD_SEED],
+        bump = jackpot_leaderboard.bump
+    )]
+    pub jackpot_leaderboard: Box<Account<'info, JackpotLeaderboard>>,
+
     /// Token program
     pub token_program: Program<'info, Token>,
+
+    /// Escrow record, required only when the prize is escrowed
+    #[account(
+        init,
+        payer = player,
+        space = PRIZE_ESCROW_SIZE,
+        seeds = [ESCROW_SEED, ticket.key().as_ref()],
+        bump
+    )]
+    pub prize_escrow: Option<Account<'info, PrizeEscrow>>,
+
+    /// Escrow USDC token account, required only when the prize is escrowed
+    #[account(
+        init,
+        payer = player,
+        seeds = [ESCROW_USDC_SEED, ticket.key().as_ref()],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = lottery_state
+    )]
+    pub escrow_usdc: Option<Account<'info, TokenAccount>>,
+
+    /// System program, required only when the prize is escrowed,
+    /// `player_usdc` must be created or a free QuickPick credit is issued
+    pub system_program: Option<Program<'info, System>>,
+
+    /// QuickPick's state account, required only for Match 5 claims
+    /// CHECK: Owner and PDA verified when the free QuickPick credit is issued
+    pub quickpick_state: Option<UncheckedAccount<'info>>,
+
+    /// Free QuickPick credit PDA for the QuickPick draw on sale, required
+    /// only for Match 5 claims (created by the claim)
+    /// CHECK: PDA verified when the free QuickPick credit is issued
+    #[account(mut)]
+    pub free_qp_credit: Option<UncheckedAccount<'info>>,
+}
+
+/// Load the ticket owner's USDC token account, creating their ATA if needed
+///
+/// An existing account must be a USDC token account owned by the ticket
+/// owner. A missing one is only created when `create_if_missing` is set (a
+/// USDC prize is being paid to the owner), so free-ticket and losing claims
+/// never charge the player rent. A claim agent cannot create it. Returns
+/// whether the account exists.
+fn load_player_usdc(accounts: &ClaimPrize, create_if_missing: bool) -> Result<bool> {
+    let player_usdc = accounts.player_usdc.to_account_info();
+
+    if player_usdc.data_is_empty() {
+        if !create_if_missing {
+            return Ok(false);
+        }
+
+        let player = accounts.player.key();
+        require!(player == accounts.ticket.owner, LottoError::AtaRequired);
+        require!(
+            player_usdc.key() == get_associated_token_address(&player, &accounts.usdc_mint.key()),
+            LottoError::AtaRequired
+        );
+        let associated_token_program = accounts
+            .associated_token_program
+            .as_ref()
+            .ok_or(LottoError::AtaRequired)?;
+        let system_program = accounts
+            .system_program
+            .as_ref()
+            .ok_or(LottoError::AtaRequired)?;
+
+        let rent_paid_lamports = Rent::get()?.minimum_balance(TokenAccount::LEN);
+        require!(
+            accounts.player.lamports() >= rent_paid_lamports,
+            LottoError::InsufficientRentForTokenAccount
+        );
+
+        associated_token::create(CpiContext::new(
+            associated_token_program.to_account_info(),
+            associated_token::Create {
+                payer: accounts.player.to_account_info(),
+                associated_token: player_usdc.clone(),
+                authority: accounts.player.to_account_info(),
+                mint: accounts.usdc_mint.to_account_info(),
+                system_program: system_program.to_account_info(),
+                token_program: accounts.token_program.to_account_info(),
+            },
+        ))?;
+
+        emit!(TokenAccountAutoCreated {
+            player,
+            ata_pubkey: player_usdc.key(),
+            rent_paid_lamports,
+        });
+
+        msg!("Player USDC token account created");
+        msg!("  ATA: {}", player_usdc.key());
+        msg!("  Rent paid: {} lamports", rent_paid_lamports);
+    }
+
+    require!(
+        player_usdc.owner == accounts.token_program.key,
+        LottoError::InvalidTokenAccount
+    );
+    let token_account = TokenAccount::try_deserialize(&mut &player_usdc.data.borrow()[..])?;
+    require!(
+        token_account.owner == accounts.ticket.owner,
+        LottoError::TokenAccountOwnerMismatch
+    );
+    require!(
+        token_account.mint == accounts.usdc_mint.key(),
+        LottoError::InvalidUsdcMint
+    );
+
+    Ok(true)
 }
 
 /// Count the number of matching numbers between ticket and winning numbers
@@ -126,7 +275,7 @@ pub struct ClaimPrize<'info> {
 ///
 /// # Returns
 /// * `u8` - Number of matching numbers (0-6)
-fn count_matches(ticket_numbers: &[u8; 6], winning_numbers: &[u8; 6]) -> u8 {
+pub(crate) fn count_matches(ticket_numbers: &[u8; 6], winning_numbers: &[u8; 6]) -> u8 {
     let mut matches = 0u8;
 
     // Both arrays are sorted, so we can use a two-pointer approach
@@ -148,6 +297,158 @@ fn count_matches(ticket_numbers: &[u8; 6], winning_numbers: &[u8; 6]) -> u8 {
     matches
 }
 
+/// Emit a PlayerStatsUpdated summary after a player's win history changes
+pub(crate) fn emit_player_stats_updated(user_stats: &UserStats, timestamp: i64) {
+    emit!(PlayerStatsUpdated {
+        player: user_stats.wallet,
+        total_prizes_won: user_stats.total_prizes_won,
+        total_spent: user_stats.total_spent,
+        total_draws_entered: user_stats.total_draws_entered,
+        largest_prize_won: user_stats.largest_prize_won,
+        best_match_count: user_stats.best_match_count,
+        consecutive_draws_played: user_stats.consecutive_draws_played,
+        timestamp,
+    });
+}
+
+/// Part of a prize the lottery's internal balances cannot cover
+///
+/// Uses the same sources as `deduct_prize_from_balances`: jackpot and
+/// reserve for Match 6, fixed prize, reserve and jackpot otherwise.
+pub fn prize_balance_deficit(lottery_state: &LotteryState, match_count: u8, amount: u64) -> u64 {
+    let available = if match_count == 6 {
+        lottery_state
+            .jackpot_balance
+            .saturating_add(lottery_state.reserve_balance)
+    } else {
+        lottery_state
+            .fixed_prize_balance
+            .saturating_add(lottery_state.reserve_balance)
+            .saturating_add(lottery_state.jackpot_balance)
+    };
+    amount.saturating_sub(available)
+}
+
+/// Deduct a prize from the lottery's internal balances before it is paid
+///
+/// - Match 6 (jackpot): jackpot_balance first, then reserve as fallback
+/// - Match 3/4/5 (fixed prizes): fixed_prize_balance, then reserve, then
+///   jackpot as last resort
+///
+/// If those balances cannot cover the prize, returns
+/// `LottoError::PrizePoolInsolvent` so the caller never transfers it. The
+/// failed transaction rolls back any state change, so the lottery is not
+/// paused here; `PrizePoolInsolvent` is emitted into its logs for monitoring
+/// to alert the authority.
+pub(crate) fn deduct_prize_from_balances(
+    lottery_state: &mut LotteryState,
+    draw_id: u64,
+    match_count: u8,
+    amount: u64,
+) -> Result<()> {
+    let deficit = prize_balance_deficit(lottery_state, match_count, amount);
+    if deficit > 0 {
+        emit!(PrizePoolInsolvent {
+            draw_id,
+            prize: amount,
+            deficit,
+        });
+        msg!("Prize pool insolvent!");
+        msg!("  Draw ID: {}", draw_id);
+        msg!("  Prize: {} USDC lamports", amount);
+        msg!("  Deficit: {} USDC lamports", deficit);
+        return Err(LottoError::PrizePoolInsolvent.into());
+    }
+
+    if match_count == 6 {
+        // Jackpot prize: deduct from jackpot_balance first
+        if lottery_state.jackpot_balance >= amount {
+            lottery_state.jackpot_balance = lottery_state.jackpot_balance.saturating_sub(amount);
+        } else {
+            let from_jackpot = lottery_state.jackpot_balance;
+            let remainder = amount.saturating_sub(from_jackpot);
+            lottery_state.jackpot_balance = 0;
+            lottery_state.reserve_balance = lottery_state.reserve_balance.saturating_sub(remainder);
+        }
+    } else {
+        // Fixed prizes (Match 3/4/5): deduct from fixed_prize_balance first,
+        // then reserve, then jackpot as last resort.
+        lottery_state.total_fixed_prizes_claimed = lottery_state
+            .total_fixed_prizes_claimed
+            .saturating_add(amount);
+        let mut remaining = amount;
+
+        // 1. Deduct from fixed_prize_balance
+        let from_fixed = remaining.min(lottery_state.fixed_prize_balance);
+        lottery_state.fixed_prize_balance =
+            lottery_state.fixed_prize_balance.saturating_sub(from_fixed);
+        remaining = remaining.saturating_sub(from_fixed);
+
+        // 2. Deduct remainder from reserve_balance
+        if remaining > 0 {
+            let from_reserve = remaining.min(lottery_state.reserve_balance);
+            lottery_state.reserve_balance =
+                lottery_state.reserve_balance.saturating_sub(from_reserve);
+            remaining = remaining.saturating_sub(from_reserve);
+        }
+
+        // 3. Last resort: deduct from jackpot_balance
+        if remaining > 0 {
+            lottery_state.jackpot_balance = lottery_state.jackpot_balance.saturating_sub(remaining);
+            msg!(
+                "WARNING: Fixed prize payment required {} from jackpot (fixed pool exhausted)",
+                remaining
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Require an identity proof of `player` for prizes above the threshold
+///
+/// Paths that cannot take a proof pass `None`, so prizes above
+/// `identity_verification_threshold` must be claimed with
+/// `claim_prize_with_identity` instead.
+pub(crate) fn check_prize_identity(
+    lottery_state: &LotteryState,
+    player: Pubkey,
+    ticket_id: Pubkey,
+    prize_amount: u64,
+    identity_proof: Option<&IdentityProof>,
+    now: i64,
+) -> Result<()> {
+    if !is_identity_verification_required(
+        prize_amount,
+        lottery_state.identity_verification_threshold,
+    ) {
+        return Ok(());
+    }
+
+    let identity_proof = identity_proof.ok_or(LottoError::IdentityVerificationRequired)?;
+    require!(
+        identity_proof.player == player,
+        LottoError::InvalidIdentityProof
+    );
+    require!(
+        !identity_proof.is_expired(now),
+        LottoError::IdentityProofExpired
+    );
+
+    emit!(LargeWinIdentityVerified {
+        ticket_id,
+        jurisdiction: identity_proof.jurisdiction,
+        prize_amount,
+    });
+
+    msg!("Large win identity verified");
+    msg!(
+        "  Jurisdiction: {}",
+        String::from_utf8_lossy(&identity_proof.jurisdiction)
+    );
+    Ok(())
+}
+
 /// Claim prize for a winning ticket
 ///
 /// This instruction:
@@ -161,6 +462,11 @@ fn count_matches(ticket_numbers: &[u8; 6], winning_numbers: &[u8; 6]) -> u8 {
 /// 8. Updates ticket state with match count and prize
 /// 9. Updates user statistics
 ///
+/// Match 6 prizes above `lottery_state.escrow_threshold_usdc` are moved to the
+/// ticket's escrow token account instead of the player, and can be withdrawn
+/// with `release_escrow_prize` after `escrow_release_delay_seconds`. The
+/// escrow accounts must be supplied exactly when this applies.
+///
 /// # Prize Tiers (Normal Mode)
 /// - Match 6: Jackpot (variable, from draw result)
 /// - Match 5: $4,000
@@ -181,24 +487,30 @@ fn count_matches(ticket_numbers: &[u8; 6], winning_numbers: &[u8; 6]) -> u8 {
 /// # Returns
 /// * `Result<()>` - Success or error
 pub fn handler(ctx: Context<ClaimPrize>) -> Result<()> {
+    process_claim(ctx.accounts, &ctx.bumps, None)
+}
+
+/// Claim logic shared by `claim_prize` and `claim_prize_with_identity`
+///
+/// Prizes above `identity_verification_threshold` require `identity_proof`.
+fn process_claim(
+    accounts: &mut ClaimPrize,
+    bumps: &ClaimPrizeBumps,
+    identity_proof: Option<&IdentityProof>,
+) -> Result<()> {
     let clock = Clock::get()?;
+    let state_before = LotteryStateSnapshot::capture(&accounts.lottery_state);
 
     // Get values before mutable borrows
-    let lottery_bump = ctx.accounts.lottery_state.bump;
-    let winning_numbers = ctx.accounts.draw_result.winning_numbers;
-    let ticket_numbers = ctx.accounts.ticket.numbers;
-    let ticket_draw_id = ctx.accounts.ticket.draw_id;
-    let ticket_key = ctx.accounts.ticket.key();
-    let player_key = ctx.accounts.player.key();
-    let prize_pool_balance = ctx.accounts.prize_pool_usdc.amount;
-    let draw_timestamp = ctx.accounts.draw_result.timestamp;
-
-    // FIXED: Proper finalization check using the is_finalized method
-    // A draw is finalized when prize amounts have been calculated
-    require!(
-        ctx.accounts.draw_result.is_finalized(),
-        LottoError::DrawNotInProgress
-    );
+    let lottery_bump = accounts.lottery_state.bump;
+    let winning_numbers = accounts.draw_result.winning_numbers;
+    let ticket_numbers = accounts.ticket.numbers;
+    let ticket_draw_id = accounts.ticket.draw_id;
+    let ticket_key = accounts.ticket.key();
+    let player_key = accounts.ticket.owner;
+    let claimer = accounts.player.key();
+    let prize_pool_balance = accounts.prize_pool_usdc.amount;
+    let draw_timestamp = accounts.draw_result.timestamp;
 
     // FIXED: Check ticket claim expiration (if enabled)
     // Tickets must be claimed within TICKET_CLAIM_EXPIRATION seconds of draw execution
@@ -226,17 +538,44 @@ pub fn handler(ctx: Context<ClaimPrize>) -> Result<()> {
 
     // Determine prize amount from draw result
     let prize_amount = match match_count {
-        6 => ctx.accounts.draw_result.match_6_prize_per_winner,
-        5 => ctx.accounts.draw_result.match_5_prize_per_winner,
-        4 => ctx.accounts.draw_result.match_4_prize_per_winner,
-        3 => ctx.accounts.draw_result.match_3_prize_per_winner,
-        2 => ctx.accounts.draw_result.match_2_prize_per_winner,
+        6 => accounts.draw_result.match_6_prize_per_winner,
+        5 => accounts.draw_result.match_5_prize_per_winner,
+        4 => accounts.draw_result.match_4_prize_per_winner,
+        3 => accounts.draw_result.match_3_prize_per_winner,
+        2 => accounts.draw_result.match_2_prize_per_winner,
         _ => 0,
     };
 
     // Check if there's a prize to claim
     let has_prize = prize_amount > 0;
 
+    // Large wins need a current identity proof of the player
+    check_prize_identity(
+        &accounts.lottery_state,
+        player_key,
+        ticket_key,
+        prize_amount,
+        identity_proof,
+        clock.unix_timestamp,
+    )?;
+
+    // Escrow accounts must be supplied exactly when the prize is escrowed
+    let escrow_prize = is_escrow_required(
+        match_count,
+        prize_amount,
+        accounts.lottery_state.escrow_threshold_usdc,
+    );
+    require!(
+        accounts.prize_escrow.is_some() == escrow_prize
+            && accounts.escrow_usdc.is_some() == escrow_prize,
+        LottoError::InvalidEscrowAccounts
+    );
+
+    // The player's USDC account is only created when the prize is paid
+    // straight to it
+    let pays_player_usdc = match_count != 2 && prize_amount > 0 && !escrow_prize;
+    let player_usdc_exists = load_player_usdc(accounts, pays_player_usdc)?;
+
     // Handle prize payment
     let mut free_ticket_credited = false;
     let mut actual_transfer_amount = 0u64;
@@ -259,12 +598,36 @@ pub fn handler(ctx: Context<ClaimPrize>) -> Result<()> {
             return Err(LottoError::InsufficientPrizePool.into());
         }
 
-        // Transfer USDC prize
+        // SECURITY FIX (Issue #6 + Issue #4): Update lottery_state internal accounting
+        // to stay consistent with the actual prize_pool_usdc token account balance.
+        //
+        // Deduction priority depends on prize tier:
+        // - Match 6 (jackpot): deduct from jackpot_balance, then reserve as fallback.
+        // - Match 3/4/5 (fixed prizes): deduct from fixed_prize_balance first (the
+        //   dedicated 39.4% allocation), then reserve, then jackpot as last resort.
+        //   This prevents fixed prize payouts from eroding the advertised jackpot.
+        // - Match 2: free ticket credit, no USDC transfer, no deduction needed.
+        //
+        // Deducting before the transfer means an insolvent claim fails without
+        // moving any USDC.
+        deduct_prize_from_balances(
+            &mut accounts.lottery_state,
+            ticket_draw_id,
+            match_count,
+            prize_amount,
+        )?;
+
+        // Transfer USDC prize (to escrow for large jackpot wins)
+        let destination = match &accounts.escrow_usdc {
+            Some(escrow_usdc) if escrow_prize => escrow_usdc.to_account_info(),
+            _ if player_usdc_exists => accounts.player_usdc.to_account_info(),
+            _ => return Err(LottoError::AtaRequired.into()),
+        };
         transfer_prize_internal(
-            &ctx.accounts.prize_pool_usdc,
-            &ctx.accounts.player_usdc,
-            &ctx.accounts.lottery_state,
-            &ctx.accounts.token_program,
+            &accounts.prize_pool_usdc,
+            &destination,
+            &accounts.lottery_state,
+            &accounts.token_program,
             prize_amount,
             lottery_bump,
         )?;
@@ -272,76 +635,54 @@ pub fn handler(ctx: Context<ClaimPrize>) -> Result<()> {
         actual_transfer_amount = prize_amount;
     }
 
-    // SECURITY FIX (Issue #6 + Issue #4): Update lottery_state internal accounting
-    // to stay consistent with the actual prize_pool_usdc token account balance.
-    //
-    // Deduction priority depends on prize tier:
-    // - Match 6 (jackpot): deduct from jackpot_balance, then reserve as fallback.
-    // - Match 3/4/5 (fixed prizes): deduct from fixed_prize_balance first (the
-    //   dedicated 39.4% allocation), then reserve, then jackpot as last resort.
-    //   This prevents fixed prize payouts from eroding the advertised jackpot.
-    // - Match 2: free ticket credit, no USDC transfer, no deduction needed.
     if actual_transfer_amount > 0 {
-        let lottery_state = &mut ctx.accounts.lottery_state;
-
-        if match_count == 6 {
-            // Jackpot prize: deduct from jackpot_balance first
-            if lottery_state.jackpot_balance >= actual_transfer_amount {
-                lottery_state.jackpot_balance = lottery_state
-                    .jackpot_balance
-                    .saturating_sub(actual_transfer_amount);
-            } else {
-                let from_jackpot = lottery_state.jackpot_balance;
-                let remainder = actual_transfer_amount.saturating_sub(from_jackpot);
-                lottery_state.jackpot_balance = 0;
-                lottery_state.reserve_balance =
-                    lottery_state.reserve_balance.saturating_sub(remainder);
-            }
-        } else {
-            // Fixed prizes (Match 3/4/5): deduct from fixed_prize_balance first,
-            // then reserve, then jackpot as last resort.
-            let mut remaining = actual_transfer_amount;
-
-            // 1. Deduct from fixed_prize_balance
-            let from_fixed = remaining.min(lottery_state.fixed_prize_balance);
-            lottery_state.fixed_prize_balance =
-                lottery_state.fixed_prize_balance.saturating_sub(from_fixed);
-            remaining = remaining.saturating_sub(from_fixed);
-
-            // 2. Deduct remainder from reserve_balance
-            if remaining > 0 {
-                let from_reserve = remaining.min(lottery_state.reserve_balance);
-                lottery_state.reserve_balance =
-                    lottery_state.reserve_balance.saturating_sub(from_reserve);
-                remaining = remaining.saturating_sub(from_reserve);
-            }
-
-            // 3. Last resort: deduct from jackpot_balance
-            if remaining > 0 {
-                lottery_state.jackpot_balance =
-                    lottery_state.jackpot_balance.saturating_sub(remaining);
-                msg!(
-                    "WARNING: Fixed prize payment required {} from jackpot (fixed pool exhausted)",
-                    remaining
-                );
-            }
-        }
+        let lottery_state = &mut accounts.lottery_state;
 
         // SECURITY FIX (Issue #6): Increment total_prizes_paid at actual claim time,
         // not at finalization time. This ensures the stat reflects real USDC transfers.
-        lottery_state.total_prizes_paid = lottery_state
-            .total_prizes_paid
-            .saturating_add(actual_transfer_amount);
+        // Escrowed prizes are counted when released to the winner.
+        if !escrow_prize {
+            lottery_state.total_prizes_paid = lottery_state
+                .total_prizes_paid
+                .saturating_add(actual_transfer_amount);
+        }
+    }
+
+    // Record the escrow
+    if let Some(prize_escrow) = accounts.prize_escrow.as_mut() {
+        let release_timestamp = clock
+            .unix_timestamp
+            .checked_add(accounts.lottery_state.escrow_release_delay_seconds)
+            .ok_or(LottoError::Overflow)?;
+
+        prize_escrow.ticket = ticket_key;
+        prize_escrow.beneficiary = player_key;
+        prize_escrow.amount = actual_transfer_amount;
+        prize_escrow.release_timestamp = release_timestamp;
+        prize_escrow.draw_id = ticket_draw_id;
+        prize_escrow.bump = bumps
+            .prize_escrow
+            .ok_or(LottoError::InvalidEscrowAccounts)?;
+
+        emit!(PrizeEscrowed {
+            ticket_id: ticket_key,
+            amount: actual_transfer_amount,
+            release_at: release_timestamp,
+        });
+
+        msg!("Jackpot prize escrowed!");
+        msg!("  Amount: {} USDC lamports", actual_transfer_amount);
+        msg!("  Releasable at: {} (unix timestamp)", release_timestamp);
     }
 
     // Update ticket state
-    let ticket = &mut ctx.accounts.ticket;
+    let ticket = &mut accounts.ticket;
     ticket.match_count = match_count;
     ticket.prize_amount = prize_amount;
     ticket.is_claimed = true;
 
     // Update user stats
-    let user_stats = &mut ctx.accounts.user_stats;
+    let user_stats = &mut accounts.user_stats;
 
     // Track USDC won (not including free ticket credits)
     if actual_transfer_amount > 0 {
@@ -384,6 +725,39 @@ pub fn handler(ctx: Context<ClaimPrize>) -> Result<()> {
             .ok_or(LottoError::Overflow)?;
     }
 
+    // Match 5 also earns a free QuickPick ticket
+    if match_count == 5 {
+        issue_match_5_free_quickpick_credit(
+            player_key,
+            &accounts.player,
+            ticket_draw_id,
+            accounts.quickpick_state.as_ref(),
+            accounts.free_qp_credit.as_ref(),
+            accounts.system_program.as_ref(),
+            clock.unix_timestamp,
+        )?;
+    }
+
+    // Track win history
+    if actual_transfer_amount > 0 {
+        user_stats.record_winnings(
+            actual_transfer_amount,
+            actual_transfer_amount,
+            match_count,
+            Some(ticket_draw_id),
+        )?;
+        emit_player_stats_updated(user_stats, clock.unix_timestamp);
+
+        record_leaderboard_prize(
+            &mut accounts.jackpot_leaderboard,
+            player_key,
+            actual_transfer_amount,
+            ticket_draw_id,
+            match_count,
+            clock.unix_timestamp,
+        );
+    }
+
     // Emit event
     emit!(PrizeClaimed {
         ticket: ticket_key,
@@ -395,6 +769,15 @@ pub fn handler(ctx: Context<ClaimPrize>) -> Result<()> {
         timestamp: clock.unix_timestamp,
     });
 
+    if claimer != player_key {
+        emit!(PrizeClaimedByAgent {
+            ticket: ticket_key,
+            agent: claimer,
+            prize_amount,
+        });
+        msg!("Claimed by agent: {}", claimer);
+    }
+
     if has_prize {
         msg!("Prize claimed successfully!");
         msg!("  Ticket: {}", ticket_key);
@@ -414,7 +797,7 @@ pub fn handler(ctx: Context<ClaimPrize>) -> Result<()> {
             msg!("  USDC transferred: {} lamports", actual_transfer_amount);
             msg!(
                 "  Prize pool balance after transfer: {} lamports",
-                ctx.accounts
+                accounts
                     .prize_pool_usdc
                     .amount
                     .saturating_sub(actual_transfer_amount)
@@ -434,6 +817,736 @@ pub fn handler(ctx: Context<ClaimPrize>) -> Result<()> {
         );
     }
 
+    emit_state_diff(&state_before, &accounts.lottery_state);
+
+    Ok(())
+}
+
+// ============================================================================
+// IDENTITY-VERIFIED PRIZE CLAIM INSTRUCTION
+// ============================================================================
+
+/// Accounts required for claiming a prize with an identity proof
+#[derive(Accounts)]
+pub struct ClaimPrizeWithIdentity<'info> {
+    /// The standard claim accounts
+    pub claim: ClaimPrize<'info>,
+
+    /// The player's identity proof
+    #[account(
+        seeds = [IDENTITY_SEED, claim.ticket.owner.as_ref()],
+        bump = identity_proof.bump,
+        constraint = identity_proof.player == claim.ticket.owner @ LottoError::InvalidIdentityProof
+    )]
+    pub identity_proof: Account<'info, IdentityProof>,
+}
+
+/// Claim a prize above `identity_verification_threshold`
+///
+/// Identical to `claim_prize` except that the player's unexpired identity
+/// proof is checked (and `LargeWinIdentityVerified` emitted) when the prize
+/// exceeds the threshold. Prizes at or below it may use either instruction.
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_claim_prize_with_identity(ctx: Context<ClaimPrizeWithIdentity>) -> Result<()> {
+    let accounts = ctx.accounts;
+    process_claim(
+        &mut accounts.claim,
+        &ctx.bumps.claim,
+        Some(&accounts.identity_proof),
+    )
+}
+
+// ============================================================================
+// MULTI-DRAW PRIZE CLAIM INSTRUCTION
+// ============================================================================
+
+/// Maximum number of (DrawResult, TicketData) pairs per multi-draw claim
+pub const MAX_MULTI_DRAW_CLAIM_PAIRS: usize = 10;
+
+/// Accounts required for claiming prizes across multiple draws
+///
+/// The tickets and their draw results are passed as `remaining_accounts`
+/// in alternating order: draw, ticket, draw, ticket, ...
+/// Ticket accounts must be writable so they can be marked claimed.
+#[derive(Accounts)]
+pub struct ClaimMultiDrawPrizes<'info> {
+    /// The player claiming the prizes (must own every ticket)
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// Player's USDC token account (to receive prizes)
+    #[account(
+        mut,
+        constraint = player_usdc.owner == player.key() @ LottoError::TokenAccountOwnerMismatch,
+        constraint = player_usdc.mint == usdc_mint.key() @ LottoError::InvalidUsdcMint
+    )]
+    pub player_usdc: Account<'info, TokenAccount>,
+
+    /// Prize pool USDC token account
+    #[account(
+        mut,
+        seeds = [PRIZE_POOL_USDC_SEED],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+
+    /// USDC mint
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// User statistics account
+    #[account(
+        mut,
+        seeds = [USER_SEED, player.key().as_ref()],
+        bump = user_stats.bump,
+        constraint = user_stats.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub user_stats: Account<'info, UserStats>,
+
+    /// All-time jackpot leaderboard
+    #[account(
+        mut,
+        seeds = [JACKPOT_LEADERBOARD_SEED],
+        bump = jackpot_leaderboard.bump
+    )]
+    pub jackpot_leaderboard: Box<Account<'info, JackpotLeaderboard>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// The player's identity proof, required only for prizes above
+    /// `identity_verification_threshold`
+    #[account(
+        seeds = [IDENTITY_SEED, player.key().as_ref()],
+        bump = identity_proof.bump,
+        constraint = identity_proof.player == player.key() @ LottoError::InvalidIdentityProof
+    )]
+    pub identity_proof: Option<Account<'info, IdentityProof>>,
+
+    /// System program, required only for Match 5 claims
+    pub system_program: Option<Program<'info, System>>,
+
+    /// QuickPick's state account, required only for Match 5 claims
+    /// CHECK: Owner and PDA verified when the free QuickPick credit is issued
+    pub quickpick_state: Option<UncheckedAccount<'info>>,
+
+    /// Free QuickPick credit PDA for the QuickPick draw on sale, required
+    /// only for Match 5 claims (created by the claim)
+    /// CHECK: PDA verified when the free QuickPick credit is issued
+    #[account(mut)]
+    pub free_qp_credit: Option<UncheckedAccount<'info>>,
+}
+
+/// Validate the shape of the remaining_accounts list for a multi-draw claim
+///
+/// # Returns
+/// * `Result<usize>` - Number of (draw, ticket) pairs
+fn validate_multi_draw_pair_count(remaining_len: usize) -> Result<usize> {
+    require!(
+        remaining_len > 0 && remaining_len % 2 == 0,
+        LottoError::InvalidMultiDrawClaimAccounts
+    );
+    let pairs = remaining_len / 2;
+    require!(
+        pairs <= MAX_MULTI_DRAW_CLAIM_PAIRS,
+        LottoError::TooManyClaimPairs
+    );
+    Ok(pairs)
+}
+
+/// Claim prizes for tickets across multiple draws in one transaction
+///
+/// For every (draw, ticket) pair the instruction:
+/// 1. Verifies both accounts are owned by this program and deserialize correctly
+/// 2. Verifies the draw result is the PDA for the ticket's draw and is finalized
+/// 3. Verifies the player owns the ticket and the claim window is open
+/// 4. Skips the pair if the ticket was already claimed
+/// 5. Counts matches, looks up the prize and marks the ticket claimed
+/// 6. Credits a free ticket for Match 2, or accumulates the USDC prize and
+///    records it on the jackpot leaderboard
+///
+/// A single USDC transfer is made at the end for the accumulated total.
+/// Prizes above `identity_verification_threshold` require `identity_proof`.
+/// Any Match 5 win issues one free QuickPick credit and requires the
+/// optional `quickpick_state`, `free_qp_credit` and `system_program`.
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_claim_multi_draw_prizes<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimMultiDrawPrizes<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let program_id = ctx.program_id;
+    let player_key = ctx.accounts.player.key();
+    let lottery_bump = ctx.accounts.lottery_state.bump;
+
+    let remaining = ctx.remaining_accounts;
+    let pair_count = validate_multi_draw_pair_count(remaining.len())?;
+
+    let mut total_prize: u64 = 0;
+    let mut pairs_processed: u32 = 0;
+    let mut free_tickets_earned: u32 = 0;
+    let mut jackpot_wins: u32 = 0;
+    let mut largest_prize: u64 = 0;
+    let mut best_match_count: u8 = 0;
+    let mut last_win_draw_id: Option<u64> = None;
+    let mut match_5_draw_id: Option<u64> = None;
+
+    for pair_index in 0..pair_count {
+        let draw_info = &remaining[pair_index * 2];
+        let ticket_info = &remaining[pair_index * 2 + 1];
+
+        // a) Both accounts must be owned by this program
+        require!(
+            draw_info.owner == program_id && ticket_info.owner == program_id,
+            LottoError::InvalidTicketAccount
+        );
+        require!(ticket_info.is_writable, LottoError::InvalidTicketAccount);
+
+        // b) Deserialize the draw result and verify it is the canonical PDA
+        let draw_result = {
+            let draw_data = draw_info.try_borrow_data()?;
+            let mut readable: &[u8] = &draw_data;
+            DrawResult::try_deserialize(&mut readable)
+                .map_err(|_| LottoError::InvalidDrawResultAccount)?
+        };
+        let expected_draw_key = Pubkey::create_program_address(
+            &[
+                DRAW_SEED,
+                &draw_result.draw_id.to_le_bytes(),
+                &[draw_result.bump],
+            ],
+            program_id,
+        )
+        .map_err(|_| LottoError::InvalidDrawResultAccount)?;
+        require!(
+            draw_info.key() == expected_draw_key,
+            LottoError::InvalidDrawResultAccount
+        );
+        draw_result.require_current_schema()?;
+
+        // c) Deserialize the ticket
+        let mut ticket_data_raw = ticket_info.try_borrow_mut_data()?;
+        let mut readable: &[u8] = &ticket_data_raw;
+        let mut ticket = TicketData::try_deserialize(&mut readable)
+            .map_err(|_| LottoError::InvalidTicketAccount)?;
+        ticket.require_current_schema()?;
+
+        // d) Ownership, draw pairing and finalization
+        require!(ticket.owner == player_key, LottoError::NotTicketOwner);
+        require!(
+            ticket.draw_id == draw_result.draw_id,
+            LottoError::DrawIdMismatch
+        );
+        require!(draw_result.is_finalized(), LottoError::DrawNotFinalized);
+        require!(
+            !draw_result.are_claims_frozen(),
+            LottoError::DrawClaimsFrozen
+        );
+
+        // e) Already-claimed tickets are skipped, not fatal
+        if ticket.is_claimed {
+            msg!(
+                "  Pair {}: ticket {} already claimed - skipped",
+                pair_index,
+                ticket_info.key()
+            );
+            continue;
+        }
+
+        // f) Claim window must still be open
+        let claim_deadline = draw_result
+            .timestamp
+            .checked_add(TICKET_CLAIM_EXPIRATION)
+            .ok_or(LottoError::ArithmeticError)?;
+        require!(
+            clock.unix_timestamp <= claim_deadline,
+            LottoError::TicketExpired
+        );
+
+        // g) Determine the prize
+        let match_count = count_matches(&ticket.numbers, &draw_result.winning_numbers);
+        let prize_amount = draw_result.get_prize_for_matches(match_count);
+        let free_ticket_issued = match_count == 2 && prize_amount > 0;
+        require!(
+            !is_escrow_required(
+                match_count,
+                prize_amount,
+                ctx.accounts.lottery_state.escrow_threshold_usdc
+            ),
+            LottoError::EscrowRequired
+        );
+        check_prize_identity(
+            &ctx.accounts.lottery_state,
+            player_key,
+            ticket_info.key(),
+            prize_amount,
+            ctx.accounts.identity_proof.as_deref(),
+            clock.unix_timestamp,
+        )?;
+
+        if free_ticket_issued {
+            free_tickets_earned += 1;
+        } else if prize_amount > 0 {
+            total_prize = total_prize
+                .checked_add(prize_amount)
+                .ok_or(LottoError::Overflow)?;
+            deduct_prize_from_balances(
+                &mut ctx.accounts.lottery_state,
+                ticket.draw_id,
+                match_count,
+                prize_amount,
+            )?;
+            if match_count == 6 {
+                jackpot_wins += 1;
+            }
+            largest_prize = largest_prize.max(prize_amount);
+            best_match_count = best_match_count.max(match_count);
+            last_win_draw_id = last_win_draw_id.max(Some(ticket.draw_id));
+
+            record_leaderboard_prize(
+                &mut ctx.accounts.jackpot_leaderboard,
+                player_key,
+                prize_amount,
+                ticket.draw_id,
+                match_count,
+                clock.unix_timestamp,
+            );
+        }
+
+        if match_count == 5 {
+            match_5_draw_id = match_5_draw_id.max(Some(ticket.draw_id));
+        }
+
+        // h) Mark claimed and write back
+        ticket.match_count = match_count;
+        ticket.prize_amount = prize_amount;
+        ticket.is_claimed = true;
+
+        let mut writer: &mut [u8] = &mut ticket_data_raw;
+        ticket
+            .try_serialize(&mut writer)
+            .map_err(|_| LottoError::InvalidTicketAccount)?;
+
+        pairs_processed += 1;
+
+        emit!(PrizeClaimed {
+            ticket: ticket_info.key(),
+            player: player_key,
+            draw_id: ticket.draw_id,
+            match_count,
+            prize_amount,
+            free_ticket_issued,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    // Single USDC transfer for the accumulated total
+    if total_prize > 0 {
+        transfer_prize_internal(
+            &ctx.accounts.prize_pool_usdc,
+            &ctx.accounts.player_usdc,
+            &ctx.accounts.lottery_state,
+            &ctx.accounts.token_program,
+            total_prize,
+            lottery_bump,
+        )?;
+
+        let lottery_state = &mut ctx.accounts.lottery_state;
+        lottery_state.total_prizes_paid =
+            lottery_state.total_prizes_paid.saturating_add(total_prize);
+    }
+
+    // Update user stats once for the whole batch
+    let user_stats = &mut ctx.accounts.user_stats;
+    user_stats.total_won = user_stats
+        .total_won
+        .checked_add(total_prize)
+        .ok_or(LottoError::Overflow)?;
+    user_stats.jackpot_wins = user_stats
+        .jackpot_wins
+        .checked_add(jackpot_wins)
+        .ok_or(LottoError::Overflow)?;
+
+    // Free ticket credits are capped at MAX_FREE_TICKETS; excess is skipped
+    let free_ticket_room =
+        (MAX_FREE_TICKETS as u32).saturating_sub(user_stats.free_tickets_available);
+    let free_tickets_credited = free_tickets_earned.min(free_ticket_room);
+    user_stats.free_tickets_available = user_stats
+        .free_tickets_available
+        .checked_add(free_tickets_credited)
+        .ok_or(LottoError::Overflow)?;
+    if free_tickets_credited < free_tickets_earned {
+        msg!("Free ticket limit reached - some Match 2 bonuses skipped but claims proceed!");
+    }
+
+    // Track win history
+    if total_prize > 0 {
+        user_stats.record_winnings(
+            total_prize,
+            largest_prize,
+            best_match_count,
+            last_win_draw_id,
+        )?;
+        emit_player_stats_updated(user_stats, clock.unix_timestamp);
+    }
+
+    // Match 5 also earns a free QuickPick ticket
+    if let Some(match_5_draw_id) = match_5_draw_id {
+        issue_match_5_free_quickpick_credit(
+            player_key,
+            &ctx.accounts.player,
+            match_5_draw_id,
+            ctx.accounts.quickpick_state.as_ref(),
+            ctx.accounts.free_qp_credit.as_ref(),
+            ctx.accounts.system_program.as_ref(),
+            clock.unix_timestamp,
+        )?;
+    }
+
+    emit!(MultiDrawPrizesClaimed {
+        player: player_key,
+        pairs_processed,
+        total_prize,
+    });
+
+    msg!("Multi-draw prizes claimed!");
+    msg!("  Player: {}", player_key);
+    msg!("  Pairs submitted: {}", pair_count);
+    msg!("  Pairs processed: {}", pairs_processed);
+    msg!("  Total USDC prize: {} lamports", total_prize);
+    msg!("  Free tickets credited: {}", free_tickets_credited);
+
+    Ok(())
+}
+
+// ============================================================================
+// MULTI-DRAW TICKET PRIZE CLAIM INSTRUCTION
+// ============================================================================
+
+/// Accounts required for claiming one draw's prize on a multi-draw ticket
+#[derive(Accounts)]
+pub struct ClaimMultiDrawTicketPrize<'info> {
+    /// The player claiming the prize (must be ticket owner)
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The multi-draw ticket being claimed
+    #[account(
+        mut,
+        seeds = [
+            MULTI_DRAW_TICKET_SEED,
+            multi_draw_ticket.owner.as_ref(),
+            &multi_draw_ticket.start_draw_id.to_le_bytes()
+        ],
+        bump = multi_draw_ticket.bump,
+        constraint = multi_draw_ticket.owner == player.key() @ LottoError::NotTicketOwner,
+        constraint = multi_draw_ticket.covers_draw(draw_result.draw_id) @ LottoError::DrawNotCoveredByTicket,
+        constraint = !multi_draw_ticket.is_draw_claimed(draw_result.draw_id) @ LottoError::AlreadyClaimed
+    )]
+    pub multi_draw_ticket: Account<'info, MultiDrawTicket>,
+
+    /// The draw result being claimed against
+    #[account(
+        seeds = [DRAW_SEED, &draw_result.draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = draw_result.is_finalized() @ LottoError::DrawNotFinalized,
+        constraint = !draw_result.are_claims_frozen() @ LottoError::DrawClaimsFrozen,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub draw_result: Account<'info, DrawResult>,
+
+    /// Player's USDC token account (to receive prize)
+    #[account(
+        mut,
+        constraint = player_usdc.owner == player.key() @ LottoError::TokenAccountOwnerMismatch,
+        constraint = player_usdc.mint == usdc_mint.key() @ LottoError::InvalidUsdcMint
+    )]
+    pub player_usdc: Account<'info, TokenAccount>,
+
+    /// Prize pool USDC token account
+    #[account(
+        mut,
+        seeds = [PRIZE_POOL_USDC_SEED],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+
+    /// USDC mint
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// User statistics account
+    #[account(
+        mut,
+        seeds = [USER_SEED, player.key().as_ref()],
+        bump = user_stats.bump,
+        constraint = user_stats.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub user_stats: Account<'info, UserStats>,
+
+    /// All-time jackpot leaderboard
+    #[account(
+        mut,
+        seeds = [JACKPOT_LEADERBOARD_SEED],
+        bump = jackpot_leaderboard.bump
+    )]
+    pub jackpot_leaderboard: Box<Account<'info, JackpotLeaderboard>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// Escrow record, required only when the prize is escrowed
+    #[account(
+        init,
+        payer = player,
+        space = PRIZE_ESCROW_SIZE,
+        seeds = [ESCROW_SEED, multi_draw_ticket.key().as_ref()],
+        bump
+    )]
+    pub prize_escrow: Option<Account<'info, PrizeEscrow>>,
+
+    /// Escrow USDC token account, required only when the prize is escrowed
+    #[account(
+        init,
+        payer = player,
+        seeds = [ESCROW_USDC_SEED, multi_draw_ticket.key().as_ref()],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = lottery_state
+    )]
+    pub escrow_usdc: Option<Account<'info, TokenAccount>>,
+
+    /// System program, required only when the prize is escrowed or a free
+    /// QuickPick credit is issued
+    pub system_program: Option<Program<'info, System>>,
+
+    /// The player's identity proof, required only for prizes above
+    /// `identity_verification_threshold`
+    #[account(
+        seeds = [IDENTITY_SEED, player.key().as_ref()],
+        bump = identity_proof.bump,
+        constraint = identity_proof.player == player.key() @ LottoError::InvalidIdentityProof
+    )]
+    pub identity_proof: Option<Account<'info, IdentityProof>>,
+
+    /// QuickPick's state account, required only for Match 5 claims
+    /// CHECK: Owner and PDA verified when the free QuickPick credit is issued
+    pub quickpick_state: Option<UncheckedAccount<'info>>,
+
+    /// Free QuickPick credit PDA for the QuickPick draw on sale, required
+    /// only for Match 5 claims (created by the claim)
+    /// CHECK: PDA verified when the free QuickPick credit is issued
+    #[account(mut)]
+    pub free_qp_credit: Option<UncheckedAccount<'info>>,
+}
+
+/// Claim the prize for one draw covered by a multi-draw ticket
+///
+/// Same prize rules as `claim_prize`: the draw must be finalized and its
+/// claim window open, Match 2 credits a free ticket, Match 5 issues a free
+/// QuickPick credit, and Match 6 prizes above the escrow threshold are
+/// escrowed against the multi-draw ticket.
+/// Prizes above `identity_verification_threshold` require `identity_proof`.
+/// The draw's bit in `claimed_draws` is set so it cannot be claimed twice.
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_claim_multi_draw_ticket_prize(
+    ctx: Context<ClaimMultiDrawTicketPrize>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let lottery_bump = ctx.accounts.lottery_state.bump;
+    let ticket_key = ctx.accounts.multi_draw_ticket.key();
+    let ticket_numbers = ctx.accounts.multi_draw_ticket.numbers;
+    let player_key = ctx.accounts.player.key();
+    let draw_id = ctx.accounts.draw_result.draw_id;
+
+    require!(
+        !is_claim_window_expired(ctx.accounts.draw_result.timestamp, clock.unix_timestamp),
+        LottoError::TicketExpired
+    );
+
+    let match_count = count_matches(&ticket_numbers, &ctx.accounts.draw_result.winning_numbers);
+    let prize_amount = ctx.accounts.draw_result.get_prize_for_matches(match_count);
+    let free_ticket_issued = match_count == 2 && prize_amount > 0;
+    check_prize_identity(
+        &ctx.accounts.lottery_state,
+        player_key,
+        ticket_key,
+        prize_amount,
+        ctx.accounts.identity_proof.as_deref(),
+        clock.unix_timestamp,
+    )?;
+
+    // Escrow accounts must be supplied exactly when the prize is escrowed
+    let escrow_prize = is_escrow_required(
+        match_count,
+        prize_amount,
+        ctx.accounts.lottery_state.escrow_threshold_usdc,
+    );
+    require!(
+        ctx.accounts.prize_escrow.is_some() == escrow_prize
+            && ctx.accounts.escrow_usdc.is_some() == escrow_prize,
+        LottoError::InvalidEscrowAccounts
+    );
+
+    let mut actual_transfer_amount = 0u64;
+    if prize_amount > 0 && !free_ticket_issued {
+        deduct_prize_from_balances(
+            &mut ctx.accounts.lottery_state,
+            draw_id,
+            match_count,
+            prize_amount,
+        )?;
+
+        let destination = match &ctx.accounts.escrow_usdc {
+            Some(escrow_usdc) if escrow_prize => escrow_usdc,
+            _ => &ctx.accounts.player_usdc,
+        };
+        transfer_prize_internal(
+            &ctx.accounts.prize_pool_usdc,
+            destination,
+            &ctx.accounts.lottery_state,
+            &ctx.accounts.token_program,
+            prize_amount,
+            lottery_bump,
+        )?;
+        actual_transfer_amount = prize_amount;
+
+        let lottery_state = &mut ctx.accounts.lottery_state;
+        if !escrow_prize {
+            lottery_state.total_prizes_paid = lottery_state
+                .total_prizes_paid
+                .saturating_add(actual_transfer_amount);
+        }
+    }
+
+    // Record the escrow
+    if let Some(prize_escrow) = ctx.accounts.prize_escrow.as_mut() {
+        let release_timestamp = clock
+            .unix_timestamp
+            .checked_add(ctx.accounts.lottery_state.escrow_release_delay_seconds)
+            .ok_or(LottoError::Overflow)?;
+
+        prize_escrow.ticket = ticket_key;
+        prize_escrow.beneficiary = player_key;
+        prize_escrow.amount = actual_transfer_amount;
+        prize_escrow.release_timestamp = release_timestamp;
+        prize_escrow.draw_id = draw_id;
+        prize_escrow.bump = ctx
+            .bumps
+            .prize_escrow
+            .ok_or(LottoError::InvalidEscrowAccounts)?;
+
+        emit!(PrizeEscrowed {
+            ticket_id: ticket_key,
+            amount: actual_transfer_amount,
+            release_at: release_timestamp,
+        });
+    }
+
+    require!(
+        ctx.accounts.multi_draw_ticket.mark_draw_claimed(draw_id),
+        LottoError::DrawNotCoveredByTicket
+    );
+
+    // Update user stats
+    let user_stats = &mut ctx.accounts.user_stats;
+    if free_ticket_issued {
+        if user_stats.free_tickets_available >= MAX_FREE_TICKETS as u32 {
+            msg!("Free ticket limit reached - bonus skipped but claim proceeds!");
+        } else {
+            user_stats.free_tickets_available = user_stats
+                .free_tickets_available
+                .checked_add(1)
+                .ok_or(LottoError::Overflow)?;
+        }
+    }
+    if match_count == 6 {
+        user_stats.jackpot_wins = user_stats
+            .jackpot_wins
+            .checked_add(1)
+            .ok_or(LottoError::Overflow)?;
+    }
+    if match_count == 5 {
+        issue_match_5_free_quickpick_credit(
+            player_key,
+            &ctx.accounts.player,
+            draw_id,
+            ctx.accounts.quickpick_state.as_ref(),
+            ctx.accounts.free_qp_credit.as_ref(),
+            ctx.accounts.system_program.as_ref(),
+            clock.unix_timestamp,
+        )?;
+    }
+    if actual_transfer_amount > 0 {
+        user_stats.total_won = user_stats
+            .total_won
+            .checked_add(actual_transfer_amount)
+            .ok_or(LottoError::Overflow)?;
+        user_stats.record_winnings(
+            actual_transfer_amount,
+            actual_transfer_amount,
+            match_count,
+            Some(draw_id),
+        )?;
+        emit_player_stats_updated(user_stats, clock.unix_timestamp);
+
+        record_leaderboard_prize(
+            &mut ctx.accounts.jackpot_leaderboard,
+            player_key,
+            actual_transfer_amount,
+            draw_id,
+            match_count,
+            clock.unix_timestamp,
+        );
+    }
+
+    emit!(PrizeClaimed {
+        ticket: ticket_key,
+        player: player_key,
+        draw_id,
+        match_count,
+        prize_amount,
+        free_ticket_issued,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Multi-draw ticket prize claimed!");
+    msg!("  Ticket: {}", ticket_key);
+    msg!("  Draw ID: {}", draw_id);
+    msg!("  Match count: {}", match_count);
+    msg!("  Prize amount: {} USDC lamports", prize_amount);
+    msg!("  Escrowed: {}", escrow_prize);
+
     Ok(())
 }
 
@@ -502,4 +1615,122 @@ mod tests {
         let winning_sorted = [1, 2, 3, 44, 45, 46];
         assert_eq!(count_matches(&ticket, &winning_sorted), 6);
     }
+
+    #[test]
+    fn test_multi_draw_pair_count_validation() {
+        assert_eq!(validate_multi_draw_pair_count(2).unwrap(), 1);
+        assert_eq!(
+            validate_multi_draw_pair_count(MAX_MULTI_DRAW_CLAIM_PAIRS * 2).unwrap(),
+            MAX_MULTI_DRAW_CLAIM_PAIRS
+        );
+        assert!(validate_multi_draw_pair_count(0).is_err());
+        assert!(validate_multi_draw_pair_count(3).is_err());
+        assert!(validate_multi_draw_pair_count(MAX_MULTI_DRAW_CLAIM_PAIRS * 2 + 2).is_err());
+    }
+
+    #[test]
+    fn test_deduct_prize_from_balances_sequential_claims() {
+        let mut lottery_state = LotteryState {
+            jackpot_balance: 2_000_000,
+            reserve_balance: 500_000,
+            fixed_prize_balance: 1_000_000,
+            ..Default::default()
+        };
+        let total_before = lottery_state.jackpot_balance
+            + lottery_state.reserve_balance
+            + lottery_state.fixed_prize_balance;
+
+        // Mixed tiers; 100 claims total 3_400_000 of the 3_500_000 available
+        let mut total_paid = 0u64;
+        for i in 0..100u64 {
+            let (match_count, prize) = match i % 4 {
+                0 => (6, 10_000),
+                1 => (5, 40_000),
+                2 => (4, 15_000),
+                _ => (3, 71_000),
+            };
+            deduct_prize_from_balances(&mut lottery_state, 1, match_count, prize).unwrap();
+            total_paid += prize;
+
+            let total_after = lottery_state.jackpot_balance
+                + lottery_state.reserve_balance
+                + lottery_state.fixed_prize_balance;
+            assert_eq!(total_after + total_paid, total_before);
+        }
+        assert_eq!(lottery_state.fixed_prize_balance, 0);
+        assert_eq!(lottery_state.reserve_balance, 0);
+        assert_eq!(lottery_state.jackpot_balance, 100_000);
+
+        // A prize the balances cannot cover is rejected before any balance
+        // changes
+        assert_eq!(prize_balance_deficit(&lottery_state, 5, 150_000), 50_000);
+        assert_eq!(
+            deduct_prize_from_balances(&mut lottery_state, 1, 5, 150_000).unwrap_err(),
+            LottoError::PrizePoolInsolvent.into()
+        );
+        assert_eq!(lottery_state.jackpot_balance, 100_000);
+        assert_eq!(
+            lottery_state.total_fixed_prizes_claimed,
+            3_400_000 - 250_000
+        );
+    }
+
+    #[test]
+    fn test_claim_requires_finalized_draw() {
+        // Between execute_draw and finalize_draw the winning numbers are set
+        // but every prize is zero, so the draw is not claimable yet
+        let mut draw_result = DrawResult {
+            winning_numbers: [4, 8, 15, 16, 23, 42],
+            ..Default::default()
+        };
+        assert!(!draw_result.is_finalized());
+        assert_eq!(draw_result.get_prize_for_matches(3), 0);
+
+        draw_result.match_3_prize_per_winner = 5_000_000;
+        assert!(draw_result.is_finalized());
+
+        // Explicit finalization covers draws with no paying tier
+        let no_winners = DrawResult {
+            is_explicitly_finalized: true,
+            ..Default::default()
+        };
+        assert!(no_winners.is_finalized());
+    }
+
+    #[test]
+    fn test_check_prize_identity() {
+        let player = Pubkey::new_unique();
+        let ticket = Pubkey::new_unique();
+        let lottery_state = LotteryState {
+            identity_verification_threshold: 1_000,
+            ..Default::default()
+        };
+        let proof = IdentityProof {
+            player,
+            jurisdiction: *b"US",
+            expires_at: 500,
+            ..Default::default()
+        };
+
+        // At or below the threshold no proof is needed
+        assert!(check_prize_identity(&lottery_state, player, ticket, 1_000, None, 0).is_ok());
+
+        // Above it, paths without a proof are rejected
+        assert!(check_prize_identity(&lottery_state, player, ticket, 1_001, None, 0).is_err());
+        assert!(
+            check_prize_identity(&lottery_state, player, ticket, 1_001, Some(&proof), 499).is_ok()
+        );
+        assert!(
+            check_prize_identity(&lottery_state, player, ticket, 1_001, Some(&proof), 500).is_err()
+        );
+        assert!(check_prize_identity(
+            &lottery_state,
+            Pubkey::new_unique(),
+            ticket,
+            1_001,
+            Some(&proof),
+            0
+        )
+        .is_err());
+    }
 }
@@ -0,0 +1,485 @@
+//! Close Ticket Instructions
+//!
+//! These instructions close spent ticket accounts and return the rent to the
+//! ticket owner. Without them, `TicketData` and `UnifiedTicket` accounts would
+//! hold SOL rent forever.
+//!
+//! A ticket can be closed once its draw is finalized and either:
+//! - The ticket has been claimed (every ticket, for a unified ticket), or
+//! - The claim window (TICKET_CLAIM_EXPIRATION) has expired
+//!
+//! The owner can always close an eligible ticket. Anyone else may close an
+//! expired ticket when `lottery_state.allow_third_party_closure` is enabled,
+//! which lets the community clean up abandoned accounts. Rent always goes to
+//! the ticket owner, never to the caller.
+//!
+//! Batch cleanup is the exception: `cleanup_expired_tickets` and
+//! `cleanup_expired_ticket_batches` let anyone close up to
+//! MAX_CLEANUP_TICKETS expired, unclaimed accounts at once. The rent goes to
+//! the caller (the dust collector), plus `cleanup_reward_lamports` per closed
+//! account from the lottery state's spare SOL.
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{ExpiredTicketsCleaned, TicketAccountClosed};
+use crate::instructions::keeper::lamports_above_rent;
+use crate::state::{DrawResult, LotteryState, SchemaVersioned, TicketData, UnifiedTicket};
+
+/// Accounts required for closing a single ticket
+#[derive(Accounts)]
+pub struct CloseTicket<'info> {
+    /// The caller closing the ticket (owner, or anyone if third-party closure is enabled)
+    pub closer: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The ticket being closed (rent returned to the owner)
+    #[account(
+        mut,
+        close = ticket_owner,
+        constraint = ticket.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub ticket: Account<'info, TicketData>,
+
+    /// The ticket owner who receives the recovered rent
+    /// CHECK: Validated against ticket.owner
+    #[account(
+        mut,
+        constraint = ticket_owner.key() == ticket.owner @ LottoError::NotTicketOwner
+    )]
+    pub ticket_owner: AccountInfo<'info>,
+
+    /// The draw result for the ticket's draw (must be finalized)
+    #[account(
+        seeds = [DRAW_SEED, &ticket.draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = draw_result.draw_id == ticket.draw_id @ LottoError::DrawIdMismatch,
+        constraint = draw_result.is_finalized() @ LottoError::DrawNotFinalized,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub draw_result: Account<'info, DrawResult>,
+}
+
+/// Accounts required for closing a unified (bulk) ticket
+#[derive(Accounts)]
+pub struct CloseTicketBatch<'info> {
+    /// The caller closing the ticket (owner, or anyone if third-party closure is enabled)
+    pub closer: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The unified ticket being closed (rent returned to the owner)
+    #[account(
+        mut,
+        close = ticket_owner
+    )]
+    pub unified_ticket: Account<'info, UnifiedTicket>,
+
+    /// The unified ticket owner who receives the recovered rent
+    /// CHECK: Validated against unified_ticket.owner
+    #[account(
+        mut,
+        constraint = ticket_owner.key() == unified_ticket.owner @ LottoError::NotTicketOwner
+    )]
+    pub ticket_owner: AccountInfo<'info>,
+
+    /// The draw result for the unified ticket's draw (must be finalized)
+    #[account(
+        seeds = [DRAW_SEED, &unified_ticket.draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = draw_result.draw_id == unified_ticket.draw_id @ LottoError::DrawIdMismatch,
+        constraint = draw_result.is_finalized() @ LottoError::DrawNotFinalized,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub draw_result: Account<'info, DrawResult>,
+}
+
+/// Check whether the claim window for a draw has expired
+pub fn is_claim_window_expired(draw_timestamp: i64, current_time: i64) -> bool {
+    match draw_timestamp.checked_add(TICKET_CLAIM_EXPIRATION) {
+        Some(deadline) => current_time > deadline,
+        None => false,
+    }
+}
+
+/// Decide whether a ticket account may be closed by the caller
+///
+/// # Arguments
+/// * `is_owner` - Whether the caller is the ticket owner
+/// * `fully_claimed` - Whether the ticket (all tickets, for a batch) has been claimed
+/// * `expired` - Whether the claim window has expired
+/// * `allow_third_party_closure` - Lottery-wide third-party closure flag
+pub fn check_close_allowed(
+    is_owner: bool,
+    fully_claimed: bool,
+    expired: bool,
+    allow_third_party_closure: bool,
+) -> Result<()> {
+    if is_owner {
+        require!(fully_claimed || expired, LottoError::TicketNotClosable);
+    } else {
+        // Third parties may only clean up expired tickets, and only if enabled
+        require!(
+            allow_third_party_closure,
+            LottoError::ThirdPartyClosureDisabled
+        );
+        require!(expired, LottoError::TicketNotClosable);
+    }
+    Ok(())
+}
+
+/// Close a ticket account and return its rent to the owner
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_close_ticket(ctx: Context<CloseTicket>) -> Result<()> {
+    let clock = Clock::get()?;
+    let ticket = &ctx.accounts.ticket;
+
+    let is_owner = ctx.accounts.closer.key() == ticket.owner;
+    let expired = is_claim_window_expired(ctx.accounts.draw_result.timestamp, clock.unix_timestamp);
+
+    check_close_allowed(
+        is_owner,
+        ticket.is_claimed,
+        expired,
+        ctx.accounts.lottery_state.allow_third_party_closure,
+    )?;
+
+    let rent_recovered_lamports = ticket.to_account_info().lamports();
+
+    emit!(TicketAccountClosed {
+        account_key: ticket.key(),
+        owner: ticket.owner,
+        rent_recovered_lamports,
+        draw_id: ticket.draw_id,
+    });
+
+    msg!("Ticket account closed!");
+    msg!("  Ticket: {}", ticket.key());
+    msg!("  Owner: {}", ticket.owner);
+    msg!("  Closed by: {}", ctx.accounts.closer.key());
+    msg!("  Rent recovered: {} lamports", rent_recovered_lamports);
+
+    Ok(())
+}
+
+/// Close a unified (bulk) ticket account and return its rent to the owner
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_close_ticket_batch(ctx: Context<CloseTicketBatch>) -> Result<()> {
+    let clock = Clock::get()?;
+    let unified_ticket = &ctx.accounts.unified_ticket;
+
+    let is_owner = ctx.accounts.closer.key() == unified_ticket.owner;
+    let expired = is_claim_window_expired(ctx.accounts.draw_result.timestamp, clock.unix_timestamp);
+    let fully_claimed =
+        (0..unified_ticket.ticket_count as usize).all(|i| unified_ticket.is_ticket_claimed(i));
+
+    check_close_allowed(
+        is_owner,
+        fully_claimed,
+        expired,
+        ctx.accounts.lottery_state.allow_third_party_closure,
+    )?;
+
+    let rent_recovered_lamports = unified_ticket.to_account_info().lamports();
+
+    emit!(TicketAccountClosed {
+        account_key: unified_ticket.key(),
+        owner: unified_ticket.owner,
+        rent_recovered_lamports,
+        draw_id: unified_ticket.draw_id,
+    });
+
+    msg!("Unified ticket account closed!");
+    msg!("  Unified ticket: {}", unified_ticket.key());
+    msg!("  Tickets: {}", unified_ticket.ticket_count);
+    msg!("  Owner: {}", unified_ticket.owner);
+    msg!("  Closed by: {}", ctx.accounts.closer.key());
+    msg!("  Rent recovered: {} lamports", rent_recovered_lamports);
+
+    Ok(())
+}
+
+// ============================================================================
+// CLEANUP EXPIRED TICKETS
+// ============================================================================
+
+/// Accounts required for closing a batch of expired, unclaimed `TicketData` accounts
+///
+/// Remaining accounts are (ticket, draw result) pairs: the draw result at
+/// index n+1 belongs to the ticket at index n.
+#[derive(Accounts)]
+pub struct CleanupExpiredTickets<'info> {
+    /// Any signer; receives the recovered rent and the cleanup reward
+    #[account(mut)]
+    pub dust_collector: Signer<'info>,
+
+    /// The main lottery state account (pays the cleanup reward)
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+/// Accounts required for closing a batch of expired, unclaimed `UnifiedTicket` accounts
+///
+/// Remaining accounts are (unified ticket, draw result) pairs, laid out as
+/// for `CleanupExpiredTickets`.
+#[derive(Accounts)]
+pub struct CleanupExpiredTicketBatches<'info> {
+    /// Any signer; receives the recovered rent and the cleanup reward
+    #[account(mut)]
+    pub dust_collector: Signer<'info>,
+
+    /// The main lottery state account (pays the cleanup reward)
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+/// Number of tickets in a cleanup call's (ticket, draw result) remaining accounts
+pub fn cleanup_ticket_count(remaining_accounts_len: usize) -> Result<usize> {
+    require!(
+        remaining_accounts_len > 0 && remaining_accounts_len % 2 == 0,
+        LottoError::InvalidCleanupAccounts
+    );
+    let count = remaining_accounts_len / 2;
+    require!(
+        count <= MAX_CLEANUP_TICKETS,
+        LottoError::InvalidCleanupAccounts
+    );
+    Ok(count)
+}
+
+/// Close every (ticket, draw result) pair in `remaining_accounts` into `dust_collector`
+///
+/// `ticket_info` returns a ticket's draw ID and whether it has been claimed.
+/// Each ticket must be owned by this program, unclaimed, and past the claim
+/// window of its finalized draw. Returns (closed count, rent recovered).
+fn close_expired_ticket_accounts<'info, T>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    program_id: &Pubkey,
+    dust_collector: &AccountInfo<'info>,
+    current_time: i64,
+    ticket_info: impl Fn(&T) -> (u64, bool),
+) -> Result<(u32, u64)>
+where
+    T: AccountSerialize + AccountDeserialize + Owner + Clone,
+{
+    let count = cleanup_ticket_count(remaining_accounts.len())?;
+    let mut total_rent_recovered: u64 = 0;
+
+    for pair in remaining_accounts.chunks_exact(2) {
+        let (ticket_account, draw_account) = (&pair[0], &pair[1]);
+        require!(
+            ticket_account.owner == program_id && draw_account.owner == program_id,
+            LottoError::InvalidAccountOwner
+        );
+
+        let ticket: Account<'info, T> = Account::try_from(ticket_account)?;
+        let draw_result: Account<'info, DrawResult> = Account::try_from(draw_account)?;
+        draw_result.require_current_schema()?;
+        let (draw_id, is_claimed) = ticket_info(&ticket);
+
+        require!(draw_result.draw_id == draw_id, LottoError::DrawIdMismatch);
+        require!(draw_result.is_finalized(), LottoError::DrawNotFinalized);
+        require!(
+            is_claim_window_expired(draw_result.timestamp, current_time) && !is_claimed,
+            LottoError::TicketNotClosable
+        );
+
+        total_rent_recovered = total_rent_recovered
+            .checked_add(ticket_account.lamports())
+            .ok_or(LottoError::Overflow)?;
+        ticket.close(dust_collector.clone())?;
+    }
+
+    Ok((count as u32, total_rent_recovered))
+}
+
+/// Pay the cleanup reward for `count` closed accounts and emit `ExpiredTicketsCleaned`
+///
+/// The reward is skipped (not failed) when the lottery state does not hold
+/// enough spare lamports, so cleanup never depends on it.
+fn finish_ticket_cleanup<'info>(
+    lottery_state: &Account<'info, LotteryState>,
+    dust_collector: &AccountInfo<'info>,
+    count: u32,
+    total_rent_recovered: u64,
+) -> Result<()> {
+    let lottery_state_info = lottery_state.to_account_info();
+    let total_reward = lottery_state
+        .cleanup_reward_lamports
+        .checked_mul(count as u64)
+        .ok_or(LottoError::Overflow)?;
+    let reward_lamports = if total_reward <= lamports_above_rent(&lottery_state_info)? {
+        total_reward
+    } else {
+        msg!("Lottery state SOL too low - cleanup reward skipped");
+        0
+    };
+
+    if reward_lamports > 0 {
+        **lottery_state_info.try_borrow_mut_lamports()? -= reward_lamports;
+        **dust_collector.try_borrow_mut_lamports()? += reward_lamports;
+    }
+
+    emit!(ExpiredTicketsCleaned {
+        count,
+        total_rent_recovered,
+        caller: dust_collector.key(),
+    });
+
+    msg!("Expired ticket accounts cleaned up!");
+    msg!("  Accounts closed: {}", count);
+    msg!("  Rent recovered: {} lamports", total_rent_recovered);
+    msg!("  Reward: {} lamports", reward_lamports);
+    msg!("  Caller: {}", dust_collector.key());
+
+    Ok(())
+}
+
+/// Close up to MAX_CLEANUP_TICKETS expired, unclaimed ticket accounts
+///
+/// Permissionless. The caller receives the recovered rent plus
+/// `cleanup_reward_lamports` per closed account.
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_cleanup_expired_tickets<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CleanupExpiredTickets<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let dust_collector = ctx.accounts.dust_collector.to_account_info();
+
+    let (count, total_rent_recovered) = close_expired_ticket_accounts::<TicketData>(
+        ctx.remaining_accounts,
+        ctx.program_id,
+        &dust_collector,
+        clock.unix_timestamp,
+        |ticket| (ticket.draw_id, ticket.is_claimed),
+    )?;
+
+    finish_ticket_cleanup(
+        &ctx.accounts.lottery_state,
+        &dust_collector,
+        count,
+        total_rent_recovered,
+    )
+}
+
+/// Close up to MAX_CLEANUP_TICKETS expired unified ticket accounts
+///
+/// Like `cleanup_expired_tickets`; a unified ticket counts as claimed only
+/// once every ticket in it has been claimed.
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_cleanup_expired_ticket_batches<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CleanupExpiredTicketBatches<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let dust_collector = ctx.accounts.dust_collector.to_account_info();
+
+    let (count, total_rent_recovered) = close_expired_ticket_accounts::<UnifiedTicket>(
+        ctx.remaining_accounts,
+        ctx.program_id,
+        &dust_collector,
+        clock.unix_timestamp,
+        |unified_ticket| {
+            let fully_claimed = (0..unified_ticket.ticket_count as usize)
+                .all(|i| unified_ticket.is_ticket_claimed(i));
+            (unified_ticket.draw_id, fully_claimed)
+        },
+    )?;
+
+    finish_ticket_cleanup(
+        &ctx.accounts.lottery_state,
+        &dust_collector,
+        count,
+        total_rent_recovered,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claim_window_expiry() {
+        let draw_timestamp = 1_700_000_000;
+        assert!(!is_claim_window_expired(
+            draw_timestamp,
+            draw_timestamp + TICKET_CLAIM_EXPIRATION
+        ));
+        assert!(is_claim_window_expired(
+            draw_timestamp,
+            draw_timestamp + TICKET_CLAIM_EXPIRATION + 1
+        ));
+    }
+
+    #[test]
+    fn test_owner_close_rules() {
+        assert!(check_close_allowed(true, true, false, false).is_ok());
+        assert!(check_close_allowed(true, false, true, false).is_ok());
+        assert!(check_close_allowed(true, false, false, true).is_err());
+    }
+
+    #[test]
+    fn test_third_party_close_rules() {
+        // Disabled: never allowed
+        assert!(check_close_allowed(false, true, true, false).is_err());
+        // Enabled: only expired tickets
+        assert!(check_close_allowed(false, false, true, true).is_ok());
+        assert!(check_close_allowed(false, true, false, true).is_err());
+    }
+
+    #[test]
+    fn test_cleanup_ticket_count() {
+        assert_eq!(cleanup_ticket_count(2).unwrap(), 1);
+        assert_eq!(
+            cleanup_ticket_count(MAX_CLEANUP_TICKETS * 2).unwrap(),
+            MAX_CLEANUP_TICKETS
+        );
+        // Empty, unpaired, or too many accounts
+        assert!(cleanup_ticket_count(0).is_err());
+        assert!(cleanup_ticket_count(3).is_err());
+        assert!(cleanup_ticket_count(MAX_CLEANUP_TICKETS * 2 + 2).is_err());
+    }
+}
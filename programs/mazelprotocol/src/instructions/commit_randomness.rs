@@ -11,19 +11,35 @@
 //!
 //! TIMEOUT: If execute_draw is not called within 1 hour of commit,
 //! the draw can be cancelled via cancel_draw to prevent stuck states.
+//!
+//! COMMITMENT: The commit also stores `draw_commit_hash` over the draw ID,
+//! ticket count, jackpot and commit slot/time. execute_draw copies it into
+//! the draw result, so anyone can check via verify_draw_commitment that the
+//! draw parameters were fixed before the randomness was revealed.
+//!
+//! SOLVENCY: The prize pool token account must hold at least jackpot +
+//! reserve (less SOLVENCY_TOLERANCE of rounding dust), or the commit fails
+//! with `SolvencyCheckFailedAtCommit` in its logs, so a draw is never run
+//! that cannot be paid out.
+//!
+//! PULL ORACLE: With `use_pull_oracle` set, the randomness account is the
+//! configured Switchboard pull feed and the commit only records its current
+//! round. execute_draw can then run as soon as the feed produces a new round.
 
 use anchor_lang::prelude::*;
-use switchboard_on_demand::accounts::RandomnessAccountData;
+use anchor_spl::token::TokenAccount;
+use switchboard_on_demand::accounts::{PullFeedAccountData, RandomnessAccountData};
 
 use crate::constants::*;
 use crate::errors::LottoError;
-use crate::events::RandomnessCommitted;
-use crate::state::LotteryState;
+use crate::events::{DrawCommitmentVerified, RandomnessCommitted, SolvencyCheckFailedAtCommit};
+use crate::state::{DrawResult, LotteryState, SchemaVersioned};
 
 /// Accounts required for committing to randomness
 #[derive(Accounts)]
 pub struct CommitRandomness<'info> {
-    /// The authority initiating the commit (must be lottery authority)
+    /// The authority initiating the commit (lottery authority, or the operator
+    /// when granted `OPERATOR_PERMISSION_COMMIT_RANDOMNESS`)
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -32,9 +48,13 @@ pub struct CommitRandomness<'info> {
         mut,
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
-        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.can_run_draw_step(
+            &authority.key(),
+            OPERATOR_PERMISSION_COMMIT_RANDOMNESS
+        ) @ LottoError::Unauthorized,
         constraint = !lottery_state.is_paused @ LottoError::Paused,
-        constraint = lottery_state.is_funded @ LottoError::LotteryNotInitialized
+        constraint = lottery_state.is_funded @ LottoError::LotteryNotInitialized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
@@ -49,6 +69,13 @@ pub struct CommitRandomness<'info> {
     )]
     pub switchboard_queue: AccountInfo<'info>,
 
+    /// Prize pool USDC token account (checked for solvency)
+    #[account(
+        seeds = [PRIZE_POOL_USDC_SEED],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+
     /// System program
     pub system_program: Program<'info, System>,
 }
@@ -56,34 +83,92 @@ pub struct CommitRandomness<'info> {
 impl<'info> CommitRandomness<'info> {
     /// Validate the randomness account data and return seed_slot
     pub fn get_randomness_seed_slot(&self, current_slot: u64) -> Result<u64> {
-        // Parse the randomness account data
-        let randomness_data =
-            RandomnessAccountData::parse(self.randomness_account_data.data.borrow())
-                .map_err(|_| LottoError::RandomnessParseError)?;
+        get_randomness_seed_slot(&self.randomness_account_data, current_slot)
+    }
+}
 
-        let seed_slot = randomness_data.seed_slot;
+/// Validate a Switchboard randomness account and return its seed_slot
+pub(crate) fn get_randomness_seed_slot(
+    randomness_account_data: &AccountInfo,
+    current_slot: u64,
+) -> Result<u64> {
+    // Parse the randomness account data
+    let randomness_data = RandomnessAccountData::parse(randomness_account_data.data.borrow())
+        .map_err(|_| LottoError::RandomnessParseError)?;
 
-        // SECURITY: Verify randomness is fresh (committed in current or recent slot)
-        // Allow up to 10 slots of slack for transaction propagation
-        require!(
-            seed_slot >= current_slot.saturating_sub(10),
-            LottoError::RandomnessExpired
-        );
+    let seed_slot = randomness_data.seed_slot;
 
-        // SECURITY: Verify randomness has NOT been revealed yet
-        // If get_value succeeds, the randomness is already revealed - this is bad!
-        if randomness_data.get_value(current_slot).is_ok() {
-            return Err(LottoError::RandomnessAlreadyRevealed.into());
-        }
+    // SECURITY: Verify randomness is fresh (committed in current or recent slot)
+    // Allow up to 10 slots of slack for transaction propagation
+    require!(
+        seed_slot >= current_slot.saturating_sub(10),
+        LottoError::RandomnessExpired
+    );
 
-        Ok(seed_slot)
+    // SECURITY: Verify randomness has NOT been revealed yet
+    // If get_value succeeds, the randomness is already revealed - this is bad!
+    if randomness_data.get_value(current_slot).is_ok() {
+        return Err(LottoError::RandomnessAlreadyRevealed.into());
     }
+
+    Ok(seed_slot)
+}
+
+/// Read the latest round of a Switchboard pull feed
+///
+/// The round ID is the slot at which the feed's current result was signed.
+/// Returns `(round_id, value)`.
+pub(crate) fn get_pull_feed_round(pull_feed: &AccountInfo) -> Result<(u64, i128)> {
+    let feed_data = PullFeedAccountData::parse(pull_feed.data.borrow())
+        .map_err(|_| LottoError::RandomnessParseError)?;
+
+    Ok((feed_data.result.slot, feed_data.result.value))
+}
+
+/// Prize pool shortfall against jackpot + reserve, as (expected, deficit)
+///
+/// None when `prize_pool_actual` covers jackpot + reserve less
+/// SOLVENCY_TOLERANCE.
+pub fn commit_solvency_shortfall(
+    prize_pool_actual: u64,
+    lottery_state: &LotteryState,
+) -> Option<(u64, u64)> {
+    let expected = lottery_state
+        .jackpot_balance
+        .saturating_add(lottery_state.reserve_balance)
+        .saturating_sub(SOLVENCY_TOLERANCE);
+    (prize_pool_actual < expected).then(|| (expected, expected - prize_pool_actual))
+}
+
+/// Hash of the draw parameters fixed at commit time
+///
+/// SHA256(draw_id || draw_tickets || jackpot_balance || slot || timestamp),
+/// all little-endian.
+pub fn compute_draw_commit_hash(
+    draw_id: u64,
+    draw_tickets: u64,
+    jackpot_balance: u64,
+    slot: u64,
+    timestamp: i64,
+) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    hasher.update(draw_id.to_le_bytes());
+    hasher.update(draw_tickets.to_le_bytes());
+    hasher.update(jackpot_balance.to_le_bytes());
+    hasher.update(slot.to_le_bytes());
+    hasher.update(timestamp.to_le_bytes());
+
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(hasher.finalize().as_slice());
+    hash
 }
 
 /// Commit to randomness for the upcoming draw
 ///
 /// This instruction:
-/// 1. Validates the lottery is ready for a draw
+/// 1. Validates the lottery is ready for a draw (including min_tickets_per_draw)
 /// 2. Parses and validates the Switchboard randomness account
 /// 3. Ensures the randomness hasn't been revealed yet
 /// 4. Stores the commit slot and timestamp for later verification
@@ -108,36 +193,93 @@ impl<'info> CommitRandomness<'info> {
 /// * `Result<()>` - Success or error
 pub fn handler(ctx: Context<CommitRandomness>) -> Result<()> {
     let clock = Clock::get()?;
+    commit_draw_randomness(
+        &mut ctx.accounts.lottery_state,
+        &ctx.accounts.randomness_account_data,
+        ctx.accounts.prize_pool_usdc.amount,
+        &clock,
+    )
+}
 
+/// Shared commit logic for `commit_randomness` and `trigger_draw_if_ready`
+///
+/// Callers are responsible for checking who may commit; this validates the
+/// draw timing, ticket count, prize pool solvency and randomness account,
+/// then records the commit.
+pub(crate) fn commit_draw_randomness(
+    lottery_state: &mut LotteryState,
+    randomness_account_data: &AccountInfo,
+    prize_pool_amount: u64,
+    clock: &Clock,
+) -> Result<()> {
     // Get values needed for validation before mutable borrow
-    let next_draw_timestamp = ctx.accounts.lottery_state.next_draw_timestamp;
-    let is_draw_in_progress = ctx.accounts.lottery_state.is_draw_in_progress;
-    let hard_cap = ctx.accounts.lottery_state.hard_cap;
-    let soft_cap = ctx.accounts.lottery_state.soft_cap;
-    let jackpot_balance = ctx.accounts.lottery_state.jackpot_balance;
-    let current_draw_id = ctx.accounts.lottery_state.current_draw_id;
+    let is_draw_in_progress = lottery_state.is_draw_in_progress;
+    let hard_cap = lottery_state.hard_cap;
+    let soft_cap = lottery_state.soft_cap;
+    let jackpot_balance = lottery_state.jackpot_balance;
+    let current_draw_id = lottery_state.current_draw_id;
 
     // Verify draw time has arrived (within the sale cutoff window)
     require!(
-        clock.unix_timestamp
-            >= next_draw_timestamp
-                .checked_sub(TICKET_SALE_CUTOFF)
-                .unwrap_or(i64::MIN),
+        clock.unix_timestamp >= lottery_state.ticket_sale_cutoff_time().unwrap_or(i64::MIN),
         LottoError::DrawNotReady
     );
 
     // Verify no draw is already in progress
     require!(!is_draw_in_progress, LottoError::DrawInProgress);
 
-    // Get and validate the seed slot from randomness account
-    let seed_slot = ctx.accounts.get_randomness_seed_slot(clock.slot)?;
+    // Verify enough tickets were sold (avoid wasting VRF requests on empty draws)
+    require!(
+        lottery_state.current_draw_tickets >= lottery_state.min_tickets_per_draw,
+        LottoError::InsufficientTicketsForDraw
+    );
+
+    // Refuse to run a draw the prize pool cannot pay out
+    if let Some((expected, deficit)) = commit_solvency_shortfall(prize_pool_amount, lottery_state) {
+        emit!(SolvencyCheckFailedAtCommit {
+            prize_pool_actual: prize_pool_amount,
+            expected,
+            deficit,
+        });
+        msg!("⚠️  Prize pool insolvent at commit!");
+        msg!("  Prize pool: {} USDC lamports", prize_pool_amount);
+        msg!("  Expected: {} USDC lamports", expected);
+        msg!("  Deficit: {} USDC lamports", deficit);
+        return Err(LottoError::InsufficientPrizePool.into());
+    }
+
+    // Get and validate the seed slot from randomness account. In pull
+    // oracle mode, record the feed's current round instead.
+    let seed_slot = if lottery_state.use_pull_oracle {
+        let pull_feed = lottery_state
+            .switchboard_pull_feed
+            .ok_or(LottoError::PullOracleFeedNotSet)?;
+        require!(
+            randomness_account_data.key() == pull_feed,
+            LottoError::InvalidRandomnessAccount
+        );
+
+        let (round_id, _) = get_pull_feed_round(randomness_account_data)?;
+        lottery_state.committed_pull_round_id = round_id;
+        msg!("Pull oracle round committed: {}", round_id);
+
+        clock.slot
+    } else {
+        get_randomness_seed_slot(randomness_account_data, clock.slot)?
+    };
 
     // Store the commit information
-    let lottery_state = &mut ctx.accounts.lottery_state;
     lottery_state.commit_slot = seed_slot;
     lottery_state.commit_timestamp = clock.unix_timestamp; // FIXED: Store timestamp for timeout
-    lottery_state.current_randomness_account = ctx.accounts.randomness_account_data.key();
+    lottery_state.current_randomness_account = randomness_account_data.key();
     lottery_state.is_draw_in_progress = true;
+    lottery_state.draw_commit_hash = compute_draw_commit_hash(
+        current_draw_id,
+        lottery_state.current_draw_tickets,
+        jackpot_balance,
+        clock.slot,
+        clock.unix_timestamp,
+    );
 
     // FIXED: Determine rolldown state more precisely
     // is_rolldown_active indicates that rolldown MIGHT happen
@@ -162,7 +304,7 @@ pub fn handler(ctx: Context<CommitRandomness>) -> Result<()> {
     }
 
     let is_rolldown_active = lottery_state.is_rolldown_active;
-    let randomness_account_key = ctx.accounts.randomness_account_data.key();
+    let randomness_account_key = randomness_account_data.key();
 
     // Emit event
     emit!(RandomnessCommitted {
@@ -189,3 +331,144 @@ pub fn handler(ctx: Context<CommitRandomness>) -> Result<()> {
 
     Ok(())
 }
+
+// ============================================================================
+// VERIFY DRAW COMMITMENT
+// ============================================================================
+
+/// Accounts required for checking a draw's commitment hash
+#[derive(Accounts)]
+#[instruction(draw_id: u64)]
+pub struct VerifyDrawCommitment<'info> {
+    /// The executed draw
+    #[account(
+        seeds = [DRAW_SEED, &draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub draw_result: Account<'info, DrawResult>,
+}
+
+/// Compare a draw's stored commitment hash with `expected_hash`
+///
+/// Permissionless and read-only; emits `DrawCommitmentVerified` whether or
+/// not the hashes match. Callers recompute `expected_hash` with
+/// `compute_draw_commit_hash` from the commit transaction.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `draw_id` - Draw to check
+/// * `expected_hash` - Hash the caller expects
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_verify_draw_commitment(
+    ctx: Context<VerifyDrawCommitment>,
+    draw_id: u64,
+    expected_hash: [u8; 32],
+) -> Result<()> {
+    let stored_hash = ctx.accounts.draw_result.commit_hash;
+    let matches = stored_hash == expected_hash;
+
+    emit!(DrawCommitmentVerified {
+        draw_id,
+        matches,
+        stored_hash,
+        expected_hash,
+    });
+
+    msg!("Draw commitment check");
+    msg!("  Draw ID: {}", draw_id);
+    msg!("  Matches: {}", matches);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commit_solvency_shortfall() {
+        let lottery_state = LotteryState {
+            jackpot_balance: 1_000_000,
+            reserve_balance: 500_000,
+            ..Default::default()
+        };
+
+        assert_eq!(commit_solvency_shortfall(1_500_000, &lottery_state), None);
+        // Rounding dust within the tolerance is ignored
+        assert_eq!(
+            commit_solvency_shortfall(1_500_000 - SOLVENCY_TOLERANCE, &lottery_state),
+            None
+        );
+        // Reserve drained below what the tracked balances promise
+        assert_eq!(
+            commit_solvency_shortfall(1_000_000, &lottery_state),
+            Some((1_500_000 - SOLVENCY_TOLERANCE, 500_000 - SOLVENCY_TOLERANCE))
+        );
+    }
+
+    #[test]
+    fn test_commit_rejects_underfunded_prize_pool() {
+        let mut lottery_state = LotteryState {
+            jackpot_balance: 1_000_000,
+            reserve_balance: 500_000,
+            next_draw_timestamp: 1_700_000_000,
+            current_draw_tickets: 10,
+            min_tickets_per_draw: 1,
+            ..Default::default()
+        };
+        let clock = Clock {
+            unix_timestamp: 1_700_000_000,
+            ..Default::default()
+        };
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = Vec::new();
+        let randomness_account_data =
+            AccountInfo::new(&key, false, false, &mut lamports, &mut data, &key, false, 0);
+
+        let err = commit_draw_randomness(
+            &mut lottery_state,
+            &randomness_account_data,
+            1_000_000,
+            &clock,
+        )
+        .unwrap_err();
+        assert_eq!(err, LottoError::InsufficientPrizePool.into());
+        // The gate rejects the commit without pausing or starting a draw
+        assert!(!lottery_state.is_paused);
+        assert!(!lottery_state.is_draw_in_progress);
+    }
+
+    #[test]
+    fn test_draw_commit_hash_binds_all_inputs() {
+        let base = compute_draw_commit_hash(7, 1_000, 5_000_000, 123, 1_700_000_000);
+
+        assert_eq!(
+            base,
+            compute_draw_commit_hash(7, 1_000, 5_000_000, 123, 1_700_000_000)
+        );
+        assert_ne!(
+            base,
+            compute_draw_commit_hash(8, 1_000, 5_000_000, 123, 1_700_000_000)
+        );
+        assert_ne!(
+            base,
+            compute_draw_commit_hash(7, 1_001, 5_000_000, 123, 1_700_000_000)
+        );
+        assert_ne!(
+            base,
+            compute_draw_commit_hash(7, 1_000, 5_000_001, 123, 1_700_000_000)
+        );
+        assert_ne!(
+            base,
+            compute_draw_commit_hash(7, 1_000, 5_000_000, 124, 1_700_000_000)
+        );
+        assert_ne!(
+            base,
+            compute_draw_commit_hash(7, 1_000, 5_000_000, 123, 1_700_000_001)
+        );
+    }
+}
@@ -0,0 +1,322 @@
+//! Daily Purchase Limit Instructions
+//!
+//! When `lottery_state.daily_limit_enabled` is set, `buy_ticket` and
+//! `buy_bulk` cap how many tickets a wallet can buy per rolling
+//! DAILY_LIMIT_WINDOW_SECONDS window. Every wallet starts at
+//! DEFAULT_DAILY_TICKET_LIMIT and can lower its own limit (down to zero)
+//! as a voluntary self-exclusion. A lowered limit can never be raised again.
+//!
+//! Players can also self-exclude outright for a fixed period with
+//! `self_exclude`. Until `user_stats.self_excluded_until` passes, no new
+//! tickets can be bought or redeemed, whether or not the daily limit is
+//! enabled. Claiming existing prizes is unaffected, and neither the
+//! authority nor the player can shorten an exclusion.
+//!
+//! Separately, `lottery_state.max_tickets_per_wallet_per_hour` caps how
+//! many tickets a wallet can buy per VELOCITY_WINDOW_SECONDS to slow down
+//! bot-driven buying (e.g. chasing rolldown EV), and
+//! `lottery_state.max_tickets_per_wallet_per_draw` caps how many tickets a
+//! wallet can buy for a single draw. Syndicate purchases go through their
+//! own instructions and are not counted.
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{
+    DailyLimitReached, DailyLimitSet, PlayerSelfExcluded, PlayerVelocityAlert,
+    WalletDrawLimitReached,
+};
+use crate::state::{SchemaVersioned, UserStats};
+
+/// Fail with `PlayerSelfExcluded` while the player's self-exclusion runs
+pub fn enforce_self_exclusion(user_stats: &UserStats, current_timestamp: i64) -> Result<()> {
+    require!(
+        !user_stats.is_self_excluded(current_timestamp),
+        LottoError::PlayerSelfExcluded
+    );
+    Ok(())
+}
+
+/// Record a purchase of `count` tickets against the player's daily limit
+///
+/// No-op when the lottery-wide limit is disabled. Emits `DailyLimitReached`
+/// and fails if the purchase would exceed the player's limit.
+pub fn enforce_daily_limit(
+    daily_limit_enabled: bool,
+    user_stats: &mut UserStats,
+    count: u32,
+    current_timestamp: i64,
+) -> Result<()> {
+    if !daily_limit_enabled {
+        return Ok(());
+    }
+
+    if !user_stats.record_daily_purchase(count, current_timestamp) {
+        emit!(DailyLimitReached {
+            player: user_stats.wallet,
+            window_start: user_stats.daily_window_start,
+            attempted_count: count,
+        });
+        msg!("Daily ticket limit reached!");
+        msg!("  Limit: {}", user_stats.daily_ticket_limit);
+        msg!(
+            "  Already purchased in window: {}",
+            user_stats.daily_tickets_purchased
+        );
+        msg!("  Attempted: {}", count);
+        return Err(LottoError::DailyLimitExceeded.into());
+    }
+
+    Ok(())
+}
+
+/// Record a purchase of `count` tickets against the hourly velocity limit
+///
+/// The window is tracked even while the limit is 0 (unlimited). Fails with
+/// `PurchaseVelocityExceeded` past the limit and emits
+/// `PlayerVelocityAlert` once the window reaches 80% of it.
+pub fn enforce_purchase_velocity(
+    max_tickets_per_wallet_per_hour: u32,
+    user_stats: &mut UserStats,
+    count: u32,
+    current_timestamp: i64,
+) -> Result<()> {
+    if !user_stats.record_velocity_purchase(
+        count,
+        current_timestamp,
+        max_tickets_per_wallet_per_hour,
+    ) {
+        msg!("Hourly ticket purchase limit reached!");
+        msg!("  Limit: {}", max_tickets_per_wallet_per_hour);
+        msg!(
+            "  Already purchased in window: {}",
+            user_stats.tickets_last_window
+        );
+        msg!("  Attempted: {}", count);
+        return Err(LottoError::PurchaseVelocityExceeded.into());
+    }
+
+    if is_velocity_alert(
+        user_stats.tickets_last_window,
+        max_tickets_per_wallet_per_hour,
+    ) {
+        emit!(PlayerVelocityAlert {
+            player: user_stats.wallet,
+            tickets_this_hour: user_stats.tickets_last_window,
+            threshold: max_tickets_per_wallet_per_hour,
+        });
+    }
+
+    Ok(())
+}
+
+/// Record a purchase of `count` tickets against the per-draw wallet limit
+///
+/// No-op while the limit is 0 (unlimited). Emits `WalletDrawLimitReached`
+/// and fails if the purchase would exceed it.
+pub fn enforce_wallet_draw_limit(
+    max_tickets_per_wallet_per_draw: u64,
+    user_stats: &mut UserStats,
+    count: u64,
+    current_draw_id: u64,
+) -> Result<()> {
+    if max_tickets_per_wallet_per_draw == 0 {
+        return Ok(());
+    }
+
+    if !user_stats.record_wallet_draw_purchase(
+        count,
+        current_draw_id,
+        max_tickets_per_wallet_per_draw,
+    ) {
+        emit!(WalletDrawLimitReached {
+            player: user_stats.wallet,
+            draw_id: current_draw_id,
+            limit: max_tickets_per_wallet_per_draw,
+        });
+        msg!("Per-draw ticket limit reached!");
+        msg!("  Limit: {}", max_tickets_per_wallet_per_draw);
+        msg!(
+            "  Already purchased this draw: {}",
+            user_stats.current_draw_tickets_count
+        );
+        msg!("  Attempted: {}", count);
+        return Err(LottoError::PerWalletDrawLimitExceeded.into());
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// SET PERSONAL DAILY LIMIT
+// ============================================================================
+
+/// Accounts required for lowering a player's daily ticket limit
+#[derive(Accounts)]
+pub struct SetPersonalDailyLimit<'info> {
+    /// The player setting their own limit
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// Player's user statistics account (created if needed so a player
+    /// can self-exclude before their first purchase)
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = USER_STATS_SIZE,
+        seeds = [USER_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Account<'info, UserStats>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Lower the player's own daily ticket limit
+///
+/// The new limit must be below the current one; a limit of 0 blocks
+/// all purchases while the lottery-wide daily limit is enabled.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `limit` - New daily ticket limit
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_set_personal_daily_limit(
+    ctx: Context<SetPersonalDailyLimit>,
+    limit: u32,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let player = ctx.accounts.player.key();
+    let user_stats = &mut ctx.accounts.user_stats;
+
+    // Initialize if new
+    if user_stats.wallet == Pubkey::default() {
+        user_stats.schema_version = CURRENT_SCHEMA_VERSION;
+        user_stats.wallet = player;
+        user_stats.bump = ctx.bumps.user_stats;
+        user_stats.tickets_this_draw = 0;
+        user_stats.last_draw_participated = 0;
+        user_stats.daily_ticket_limit = DEFAULT_DAILY_TICKET_LIMIT;
+        user_stats.join_timestamp = clock.unix_timestamp;
+    }
+    user_stats.require_current_schema()?;
+
+    require!(
+        limit < user_stats.daily_ticket_limit,
+        LottoError::InvalidDailyLimit
+    );
+
+    let old_limit = user_stats.daily_ticket_limit;
+    user_stats.daily_ticket_limit = limit;
+
+    emit!(DailyLimitSet { player, limit });
+
+    msg!("Personal daily ticket limit lowered!");
+    msg!("  Player: {}", player);
+    msg!("  Limit: {} -> {}", old_limit, limit);
+
+    Ok(())
+}
+
+// ============================================================================
+// SELF EXCLUDE
+// ============================================================================
+
+/// Accounts required for a player to self-exclude
+#[derive(Accounts)]
+pub struct SelfExclude<'info> {
+    /// The player excluding themselves
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// Player's user statistics account (created if needed so a player
+    /// can self-exclude before their first purchase)
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = USER_STATS_SIZE,
+        seeds = [USER_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Account<'info, UserStats>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Block the player's own ticket purchases for `duration_seconds`
+///
+/// An existing exclusion is only ever extended: requesting a period that
+/// ends before the current one leaves it unchanged.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `duration_seconds` - Exclusion length (1 day to 1 year)
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_self_exclude(ctx: Context<SelfExclude>, duration_seconds: i64) -> Result<()> {
+    require!(
+        (MIN_SELF_EXCLUSION_SECONDS..=MAX_SELF_EXCLUSION_SECONDS).contains(&duration_seconds),
+        LottoError::InvalidSelfExclusionDuration
+    );
+
+    let clock = Clock::get()?;
+    let player = ctx.accounts.player.key();
+    let user_stats = &mut ctx.accounts.user_stats;
+
+    // Initialize if new
+    if user_stats.wallet == Pubkey::default() {
+        user_stats.schema_version = CURRENT_SCHEMA_VERSION;
+        user_stats.wallet = player;
+        user_stats.bump = ctx.bumps.user_stats;
+        user_stats.tickets_this_draw = 0;
+        user_stats.last_draw_participated = 0;
+        user_stats.daily_ticket_limit = DEFAULT_DAILY_TICKET_LIMIT;
+        user_stats.join_timestamp = clock.unix_timestamp;
+    }
+    user_stats.require_current_schema()?;
+
+    let excluded_until = extend_self_exclusion(
+        user_stats.self_excluded_until,
+        clock.unix_timestamp,
+        duration_seconds,
+    );
+    user_stats.self_excluded_until = excluded_until;
+
+    emit!(PlayerSelfExcluded {
+        player,
+        excluded_until,
+    });
+
+    msg!("Player self-excluded!");
+    msg!("  Player: {}", player);
+    msg!("  Excluded until: {}", excluded_until);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_wallet_draw_purchase() {
+        let mut user_stats = UserStats::default();
+
+        assert!(user_stats.record_wallet_draw_purchase(3, 1, 5));
+        assert!(user_stats.record_wallet_draw_purchase(2, 1, 5));
+        assert!(!user_stats.record_wallet_draw_purchase(1, 1, 5));
+        assert_eq!(user_stats.current_draw_tickets_count, 5);
+
+        // A new draw starts counting afresh
+        assert!(user_stats.record_wallet_draw_purchase(5, 2, 5));
+        assert_eq!(user_stats.current_draw_id_purchased, 2);
+        assert!(!user_stats.record_wallet_draw_purchase(6, 3, 5));
+        assert_eq!(user_stats.current_draw_tickets_count, 0);
+    }
+}
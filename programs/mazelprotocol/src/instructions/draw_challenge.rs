@@ -0,0 +1,448 @@
+//! Draw Challenge Instructions
+//!
+//! Winner counts are submitted by the authority after off-chain indexing, so
+//! for CHALLENGE_WINDOW_SECONDS after `finalize_draw` anyone can dispute a
+//! draw's winner count for one prize tier. Each wallet counts once per draw.
+//! When CHALLENGE_THRESHOLD challenges accumulate, the draw goes under
+//! review: the lottery is paused and prize claims for the draw are frozen
+//! until the authority resolves the review.
+//!
+//! - challenge_winner_count: Dispute a draw's winner count (anyone)
+//! - resolve_draw_challenge: Uphold or reject a review (authority only)
+//! - correct_challenged_winner_counts: Resubmit the winner counts of an
+//!   upheld draw and unfreeze its claims (authority only)
+//!
+//! A rejected review unfreezes claims and resets the count; an upheld one
+//! keeps the draw's claims frozen until the authority corrects its winner
+//! counts. Either way the lottery stays paused until the authority calls
+//! `unpause`.
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{
+    DrawChallengeResolved, DrawChallengeSubmitted, DrawChallengeThresholdReached,
+    DrawWinnerCountsCorrected,
+};
+use crate::state::{DrawChallenge, DrawResult, LotteryState, SchemaVersioned, WinnerCounts};
+
+/// Whether a draw with `challenge_deadline` can still be challenged
+pub fn is_challenge_window_open(challenge_deadline: i64, current_timestamp: i64) -> bool {
+    challenge_deadline > 0 && current_timestamp <= challenge_deadline
+}
+
+/// Whether `tier` is a prize tier whose winner count can be disputed
+pub fn is_valid_disputed_tier(tier: u8) -> bool {
+    (2..=6).contains(&tier)
+}
+
+/// Per-winner prize of a tier after its winner count is corrected
+///
+/// The tier's payout set by `finalize_draw` is re-split across the corrected
+/// count. Pari-mutuel tiers (Match 6, and Match 3-5 of a rolldown) take the
+/// new split either way; a fixed prize only ever scales down, so a smaller
+/// count never raises it. A tier that had no winners keeps its prize.
+pub fn corrected_prize_per_winner(
+    prize: u64,
+    old_winners: u32,
+    new_winners: u32,
+    pari_mutuel: bool,
+) -> u64 {
+    if old_winners == 0 || new_winners == 0 {
+        return prize;
+    }
+    let tier_payout = prize as u128 * old_winners as u128;
+    let split = (tier_payout / new_winners as u128) as u64;
+    if pari_mutuel {
+        split
+    } else {
+        split.min(prize)
+    }
+}
+
+// ============================================================================
+// CHALLENGE WINNER COUNT
+// ============================================================================
+
+/// Accounts required for challenging a draw's winner count
+#[derive(Accounts)]
+#[instruction(draw_id: u64)]
+pub struct ChallengeWinnerCount<'info> {
+    /// Wallet submitting the challenge
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    /// The main lottery state account (paused when the threshold is reached)
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The challenged draw
+    #[account(
+        mut,
+        seeds = [DRAW_SEED, &draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = draw_result.is_finalized() @ LottoError::DrawNotFinalized,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub draw_result: Box<Account<'info, DrawResult>>,
+
+    /// The challenger's record for this draw
+    #[account(
+        init,
+        payer = challenger,
+        space = DRAW_CHALLENGE_SIZE,
+        seeds = [CHALLENGE_SEED, draw_result.key().as_ref(), challenger.key().as_ref()],
+        bump
+    )]
+    pub draw_challenge: Account<'info, DrawChallenge>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Dispute the winner count of `disputed_tier` in draw `draw_id`
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `draw_id` - Challenged draw
+/// * `evidence_hash` - Hash of the off-chain evidence
+/// * `disputed_tier` - Match tier whose winner count is disputed (2-6)
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_challenge_winner_count(
+    ctx: Context<ChallengeWinnerCount>,
+    draw_id: u64,
+    evidence_hash: [u8; 32],
+    disputed_tier: u8,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let draw_result = &mut ctx.accounts.draw_result;
+    require!(
+        is_challenge_window_open(draw_result.challenge_deadline, clock.unix_timestamp),
+        LottoError::ChallengeWindowClosed
+    );
+    require!(
+        is_valid_disputed_tier(disputed_tier),
+        LottoError::InvalidDisputedTier
+    );
+
+    let challenger = ctx.accounts.challenger.key();
+    let draw_challenge = &mut ctx.accounts.draw_challenge;
+    draw_challenge.bump = ctx.bumps.draw_challenge;
+    draw_challenge.draw_id = draw_id;
+    draw_challenge.challenger = challenger;
+    draw_challenge.evidence_hash = evidence_hash;
+    draw_challenge.disputed_tier = disputed_tier;
+    draw_challenge.submitted_at = clock.unix_timestamp;
+
+    draw_result.challenge_count = draw_result.challenge_count.saturating_add(1);
+    let challenge_count = draw_result.challenge_count;
+
+    emit!(DrawChallengeSubmitted {
+        draw_id,
+        challenger,
+        disputed_tier,
+        evidence_hash,
+        challenge_count,
+    });
+
+    msg!("Draw challenged");
+    msg!("  Draw ID: {}", draw_id);
+    msg!("  Challenger: {}", challenger);
+    msg!("  Disputed tier: Match {}", disputed_tier);
+    msg!("  Challenges: {}/{}", challenge_count, CHALLENGE_THRESHOLD);
+
+    if challenge_count >= CHALLENGE_THRESHOLD
+        && !draw_result.is_under_challenge_review
+        && !draw_result.is_challenge_upheld
+    {
+        draw_result.is_under_challenge_review = true;
+        ctx.accounts.lottery_state.is_paused = true;
+
+        emit!(DrawChallengeThresholdReached {
+            draw_id,
+            challenge_count,
+        });
+
+        msg!("⚠️  Challenge threshold reached: draw under review, lottery paused");
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// RESOLVE DRAW CHALLENGE
+// ============================================================================
+
+/// Accounts required for resolving a draw challenge review
+#[derive(Accounts)]
+#[instruction(draw_id: u64)]
+pub struct ResolveDrawChallenge<'info> {
+    /// The lottery authority
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The draw under review
+    #[account(
+        mut,
+        seeds = [DRAW_SEED, &draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = draw_result.is_under_challenge_review @ LottoError::NoActiveDrawChallenge,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub draw_result: Box<Account<'info, DrawResult>>,
+}
+
+/// Close the challenge review of draw `draw_id`
+///
+/// Upholding keeps the draw's prize claims frozen until
+/// `correct_challenged_winner_counts` fixes the result. Rejecting unfreezes
+/// them and resets the challenge count, so new challengers can still
+/// trigger another review within the window. The lottery stays paused
+/// either way until the authority calls `unpause`.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `draw_id` - Draw under review
+/// * `accept_challenge` - Whether the challenge is upheld
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_resolve_draw_challenge(
+    ctx: Context<ResolveDrawChallenge>,
+    draw_id: u64,
+    accept_challenge: bool,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let draw_result = &mut ctx.accounts.draw_result;
+
+    draw_result.is_under_challenge_review = false;
+    if accept_challenge {
+        draw_result.is_challenge_upheld = true;
+    } else {
+        draw_result.challenge_count = 0;
+    }
+
+    emit!(DrawChallengeResolved {
+        draw_id,
+        accepted: accept_challenge,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Draw challenge resolved");
+    msg!("  Draw ID: {}", draw_id);
+    msg!(
+        "  Outcome: {}",
+        if accept_challenge {
+            "upheld (claims stay frozen)"
+        } else {
+            "rejected (claims resume)"
+        }
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// CORRECT CHALLENGED WINNER COUNTS
+// ============================================================================
+
+/// Accounts required for correcting an upheld draw's winner counts
+#[derive(Accounts)]
+#[instruction(draw_id: u64)]
+pub struct CorrectChallengedWinnerCounts<'info> {
+    /// The lottery authority
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The draw whose challenge was upheld
+    #[account(
+        mut,
+        seeds = [DRAW_SEED, &draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = draw_result.is_challenge_upheld @ LottoError::ChallengeNotUpheld,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub draw_result: Box<Account<'info, DrawResult>>,
+}
+
+/// Resubmit the winner counts of draw `draw_id` after its challenge was upheld
+///
+/// Each tier's prize is re-split with `corrected_prize_per_winner`. A Match 6
+/// winner missed at finalization left the jackpot to carry over, so the
+/// corrected Match 6 winners split the current `jackpot_balance` instead.
+/// Match 2 stays a free ticket. The upheld flag and challenge count are
+/// cleared, so the draw's prize claims resume.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `draw_id` - Draw whose challenge was upheld
+/// * `winner_counts` - Corrected number of winners in each tier
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_correct_challenged_winner_counts(
+    ctx: Context<CorrectChallengedWinnerCounts>,
+    draw_id: u64,
+    winner_counts: WinnerCounts,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let jackpot_balance = ctx.accounts.lottery_state.jackpot_balance;
+    let draw_result = &mut ctx.accounts.draw_result;
+
+    let total_winners = (winner_counts.match_6 as u64)
+        .saturating_add(winner_counts.match_5 as u64)
+        .saturating_add(winner_counts.match_4 as u64)
+        .saturating_add(winner_counts.match_3 as u64)
+        .saturating_add(winner_counts.match_2 as u64);
+    require!(
+        total_winners <= draw_result.total_tickets,
+        LottoError::WinnerCountsExceedTickets
+    );
+
+    let rolldown = draw_result.was_rolldown;
+    draw_result.match_6_prize_per_winner =
+        if draw_result.match_6_winners == 0 && winner_counts.match_6 > 0 {
+            jackpot_balance / winner_counts.match_6 as u64
+        } else {
+            corrected_prize_per_winner(
+                draw_result.match_6_prize_per_winner,
+                draw_result.match_6_winners,
+                winner_counts.match_6,
+                true,
+            )
+        };
+    draw_result.match_5_prize_per_winner = corrected_prize_per_winner(
+        draw_result.match_5_prize_per_winner,
+        draw_result.match_5_winners,
+        winner_counts.match_5,
+        rolldown,
+    );
+    draw_result.match_4_prize_per_winner = corrected_prize_per_winner(
+        draw_result.match_4_prize_per_winner,
+        draw_result.match_4_winners,
+        winner_counts.match_4,
+        rolldown,
+    );
+    draw_result.match_3_prize_per_winner = corrected_prize_per_winner(
+        draw_result.match_3_prize_per_winner,
+        draw_result.match_3_winners,
+        winner_counts.match_3,
+        rolldown,
+    );
+
+    draw_result.match_6_winners = winner_counts.match_6;
+    draw_result.match_5_winners = winner_counts.match_5;
+    draw_result.match_4_winners = winner_counts.match_4;
+    draw_result.match_3_winners = winner_counts.match_3;
+    draw_result.match_2_winners = winner_counts.match_2;
+    draw_result.is_challenge_upheld = false;
+    draw_result.challenge_count = 0;
+
+    let prizes_per_winner = [
+        draw_result.match_6_prize_per_winner,
+        draw_result.match_5_prize_per_winner,
+        draw_result.match_4_prize_per_winner,
+        draw_result.match_3_prize_per_winner,
+        draw_result.match_2_prize_per_winner,
+    ];
+
+    emit!(DrawWinnerCountsCorrected {
+        draw_id,
+        winner_counts: [
+            winner_counts.match_6,
+            winner_counts.match_5,
+            winner_counts.match_4,
+            winner_counts.match_3,
+            winner_counts.match_2,
+        ],
+        prizes_per_winner,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Challenged winner counts corrected (claims resume)");
+    msg!("  Draw ID: {}", draw_id);
+    msg!(
+        "  Winners (M6..M2): {} / {} / {} / {} / {}",
+        winner_counts.match_6,
+        winner_counts.match_5,
+        winner_counts.match_4,
+        winner_counts.match_3,
+        winner_counts.match_2
+    );
+    msg!("  Prizes per winner (M6..M2): {:?}", prizes_per_winner);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_challenge_window() {
+        let deadline = 1_000 + CHALLENGE_WINDOW_SECONDS;
+        assert!(is_challenge_window_open(deadline, 1_000));
+        assert!(is_challenge_window_open(deadline, deadline));
+        assert!(!is_challenge_window_open(deadline, deadline + 1));
+
+        // Draws finalized without a window (e.g. force finalized)
+        assert!(!is_challenge_window_open(0, 0));
+    }
+
+    #[test]
+    fn test_disputed_tier_and_claim_freeze() {
+        assert!(!is_valid_disputed_tier(1));
+        assert!(is_valid_disputed_tier(2));
+        assert!(is_valid_disputed_tier(6));
+        assert!(!is_valid_disputed_tier(7));
+
+        let mut draw_result = DrawResult::default();
+        assert!(!draw_result.are_claims_frozen());
+        draw_result.is_under_challenge_review = true;
+        assert!(draw_result.are_claims_frozen());
+        draw_result.is_under_challenge_review = false;
+        draw_result.is_challenge_upheld = true;
+        assert!(draw_result.are_claims_frozen());
+    }
+
+    #[test]
+    fn test_corrected_prize_per_winner() {
+        // Pari-mutuel tiers re-split their payout either way
+        assert_eq!(corrected_prize_per_winner(900, 1, 3, true), 300);
+        assert_eq!(corrected_prize_per_winner(300, 3, 1, true), 900);
+
+        // Fixed prizes only scale down
+        assert_eq!(corrected_prize_per_winner(100, 4, 5, false), 80);
+        assert_eq!(corrected_prize_per_winner(100, 4, 2, false), 100);
+
+        // Tiers without winners on either side keep their prize
+        assert_eq!(corrected_prize_per_winner(100, 0, 5, false), 100);
+        assert_eq!(corrected_prize_per_winner(100, 5, 0, true), 100);
+    }
+}
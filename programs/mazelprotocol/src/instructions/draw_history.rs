@@ -0,0 +1,168 @@
+//! Draw History Instructions
+//!
+//! A singleton `DrawHistoryBuffer` keeps summaries of the last
+//! DRAW_HISTORY_LENGTH finalized draws so UIs can chart recent results
+//! without an indexer. `finalize_draw` appends to it.
+//!
+//! - initialize_draw_history: Create the buffer (authority only)
+//! - get_draw_history: Emit the recorded draws, oldest first
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::DrawHistorySnapshot;
+use crate::state::{DrawHistoryBuffer, LotteryState, SchemaVersioned};
+
+// ============================================================================
+// INITIALIZE DRAW HISTORY
+// ============================================================================
+
+/// Accounts required for creating the draw history buffer
+#[derive(Accounts)]
+pub struct InitializeDrawHistory<'info> {
+    /// The lottery authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The draw history buffer to be created (starts empty)
+    #[account(
+        init,
+        payer = authority,
+        space = DRAW_HISTORY_BUFFER_SIZE,
+        seeds = [DRAW_HISTORY_SEED],
+        bump
+    )]
+    pub draw_history: Account<'info, DrawHistoryBuffer>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the draw history buffer
+///
+/// Must run before the next `finalize_draw`, which records every finalized
+/// draw in the buffer.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_initialize_draw_history(ctx: Context<InitializeDrawHistory>) -> Result<()> {
+    let draw_history = &mut ctx.accounts.draw_history;
+    draw_history.bump = ctx.bumps.draw_history;
+    draw_history.head = 0;
+    draw_history.total_draws = 0;
+
+    msg!("Draw history initialized!");
+    msg!("  Buffer: {}", draw_history.key());
+    msg!("  Capacity: {} draws", DRAW_HISTORY_LENGTH);
+
+    Ok(())
+}
+
+// ============================================================================
+// GET DRAW HISTORY
+// ============================================================================
+
+/// Accounts required for reading the draw history buffer
+#[derive(Accounts)]
+pub struct GetDrawHistory<'info> {
+    /// The draw history buffer
+    #[account(
+        seeds = [DRAW_HISTORY_SEED],
+        bump = draw_history.bump
+    )]
+    pub draw_history: Account<'info, DrawHistoryBuffer>,
+}
+
+/// Emit the recorded draws, oldest first
+///
+/// Permissionless and read-only; emits `DrawHistorySnapshot`.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_get_draw_history(ctx: Context<GetDrawHistory>) -> Result<()> {
+    let draw_history = &ctx.accounts.draw_history;
+    let entries = draw_history.chronological();
+    let total_draws = draw_history.total_draws;
+
+    msg!("Draw history snapshot");
+    msg!("  Entries: {}", entries.len());
+    msg!("  Total draws recorded: {}", total_draws);
+
+    emit!(DrawHistorySnapshot {
+        entries,
+        total_draws,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::DrawHistorySummary;
+
+    fn summary(draw_id: u64) -> DrawHistorySummary {
+        DrawHistorySummary {
+            draw_id,
+            ..Default::default()
+        }
+    }
+
+    fn draw_ids(buffer: &DrawHistoryBuffer) -> Vec<u64> {
+        buffer.chronological().iter().map(|s| s.draw_id).collect()
+    }
+
+    #[test]
+    fn test_draw_history_chronological_before_wrap() {
+        let mut buffer = DrawHistoryBuffer::default();
+        assert!(buffer.chronological().is_empty());
+
+        for draw_id in 1..=3 {
+            buffer.push(summary(draw_id));
+        }
+
+        assert_eq!(draw_ids(&buffer), vec![1, 2, 3]);
+        assert_eq!(buffer.head, 3);
+        assert_eq!(buffer.total_draws, 3);
+    }
+
+    #[test]
+    fn test_draw_history_overwrites_oldest_after_wrap() {
+        let mut buffer = DrawHistoryBuffer::default();
+        let pushed = DRAW_HISTORY_LENGTH as u64 + 5;
+
+        for draw_id in 1..=pushed {
+            buffer.push(summary(draw_id));
+        }
+
+        let expected: Vec<u64> = (6..=pushed).collect();
+        assert_eq!(draw_ids(&buffer), expected);
+        assert_eq!(buffer.head, 5);
+        assert_eq!(buffer.total_draws, pushed);
+
+        // Exactly full: head back at 0, oldest is the first draw
+        let mut full = DrawHistoryBuffer::default();
+        for draw_id in 1..=DRAW_HISTORY_LENGTH as u64 {
+            full.push(summary(draw_id));
+        }
+        assert_eq!(full.head, 0);
+        assert_eq!(draw_ids(&full).first(), Some(&1));
+        assert_eq!(draw_ids(&full).len(), DRAW_HISTORY_LENGTH);
+    }
+}
@@ -0,0 +1,284 @@
+//! Winning Numbers Override Instructions
+//!
+//! Recovery path for a bug in how VRF output is turned into winning numbers.
+//! While the lottery is paused and before `finalize_draw`, the authority can
+//! replace a draw's winning numbers after a WINNING_NUMBERS_OVERRIDE_DELAY
+//! timelock. Every override carries the SHA256 of a public explanation
+//! document, stored on the draw result as an audit trail.
+//!
+//! - propose_winning_numbers_override: Start the timelock
+//! - override_winning_numbers: Apply the proposed numbers
+//! - cancel_winning_numbers_override: Drop the proposal
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{WinningNumbersOverridden, WinningNumbersOverrideProposed};
+use crate::state::{DrawResult, LotteryState, PendingOverride, SchemaVersioned};
+
+/// Whether a pending override may be applied at `current_timestamp`
+pub fn is_override_executable(executable_at: i64, current_timestamp: i64) -> bool {
+    current_timestamp >= executable_at
+}
+
+// ============================================================================
+// PROPOSE WINNING NUMBERS OVERRIDE
+// ============================================================================
+
+/// Accounts required for proposing a winning numbers override
+#[derive(Accounts)]
+#[instruction(draw_id: u64)]
+pub struct ProposeWinningNumbersOverride<'info> {
+    /// The lottery authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account (must be paused)
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_paused @ LottoError::OverrideRequiresPause,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The draw whose numbers are replaced (must not be finalized)
+    #[account(
+        seeds = [DRAW_SEED, &draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = !draw_result.is_finalized() @ LottoError::DrawAlreadyFinalized,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub draw_result: Box<Account<'info, DrawResult>>,
+
+    /// The pending override (one per draw)
+    #[account(
+        init,
+        payer = authority,
+        space = PENDING_OVERRIDE_SIZE,
+        seeds = [PENDING_OVERRIDE_SEED, &draw_id.to_le_bytes()],
+        bump
+    )]
+    pub pending_override: Account<'info, PendingOverride>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Propose replacing the winning numbers of draw `draw_id`
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `draw_id` - Draw whose numbers are replaced
+/// * `new_numbers` - Replacement winning numbers
+/// * `justification_hash` - SHA256 of the public explanation document
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_propose_winning_numbers_override(
+    ctx: Context<ProposeWinningNumbersOverride>,
+    draw_id: u64,
+    new_numbers: [u8; NUMBERS_PER_TICKET],
+    justification_hash: [u8; 32],
+) -> Result<()> {
+    require!(
+        validate_lottery_numbers(&new_numbers),
+        LottoError::InvalidNumbers
+    );
+    let clock = Clock::get()?;
+    let executable_at = clock
+        .unix_timestamp
+        .checked_add(WINNING_NUMBERS_OVERRIDE_DELAY)
+        .ok_or(LottoError::Overflow)?;
+
+    let mut sorted_numbers = new_numbers;
+    sorted_numbers.sort();
+
+    let pending_override = &mut ctx.accounts.pending_override;
+    pending_override.bump = ctx.bumps.pending_override;
+    pending_override.draw_id = draw_id;
+    pending_override.new_numbers = sorted_numbers;
+    pending_override.justification_hash = justification_hash;
+    pending_override.proposed_at = clock.unix_timestamp;
+    pending_override.executable_at = executable_at;
+
+    emit!(WinningNumbersOverrideProposed {
+        draw_id,
+        new_numbers: sorted_numbers,
+        justification_hash,
+        executable_at,
+    });
+
+    msg!("Winning numbers override proposed");
+    msg!("  Draw ID: {}", draw_id);
+    msg!(
+        "  Current numbers: {:?}",
+        ctx.accounts.draw_result.winning_numbers
+    );
+    msg!("  New numbers: {:?}", sorted_numbers);
+    msg!("  Executable at: {}", executable_at);
+
+    Ok(())
+}
+
+// ============================================================================
+// OVERRIDE WINNING NUMBERS
+// ============================================================================
+
+/// Accounts required for applying a winning numbers override
+#[derive(Accounts)]
+#[instruction(draw_id: u64)]
+pub struct OverrideWinningNumbers<'info> {
+    /// The lottery authority (receives the pending override's rent)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account (must be paused)
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_paused @ LottoError::OverrideRequiresPause,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The draw whose numbers are replaced (must not be finalized)
+    #[account(
+        mut,
+        seeds = [DRAW_SEED, &draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = !draw_result.is_finalized() @ LottoError::DrawAlreadyFinalized,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub draw_result: Box<Account<'info, DrawResult>>,
+
+    /// The pending override (closed once applied)
+    #[account(
+        mut,
+        close = authority,
+        seeds = [PENDING_OVERRIDE_SEED, &draw_id.to_le_bytes()],
+        bump = pending_override.bump
+    )]
+    pub pending_override: Account<'info, PendingOverride>,
+}
+
+/// Replace the winning numbers of draw `draw_id` with the proposed ones
+///
+/// `new_numbers` and `justification_hash` must repeat the proposal, so the
+/// authority cannot swap in different numbers after the timelock.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `draw_id` - Draw whose numbers are replaced
+/// * `new_numbers` - Replacement winning numbers (as proposed)
+/// * `justification_hash` - SHA256 of the public explanation document (as proposed)
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_override_winning_numbers(
+    ctx: Context<OverrideWinningNumbers>,
+    draw_id: u64,
+    new_numbers: [u8; NUMBERS_PER_TICKET],
+    justification_hash: [u8; 32],
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let pending_override = &ctx.accounts.pending_override;
+    require!(
+        is_override_executable(pending_override.executable_at, clock.unix_timestamp),
+        LottoError::OverrideTimelockActive
+    );
+
+    let mut sorted_numbers = new_numbers;
+    sorted_numbers.sort();
+    require!(
+        sorted_numbers == pending_override.new_numbers
+            && justification_hash == pending_override.justification_hash,
+        LottoError::OverrideMismatch
+    );
+
+    let draw_result = &mut ctx.accounts.draw_result;
+    let old_numbers = draw_result.winning_numbers;
+    draw_result.winning_numbers = sorted_numbers;
+    draw_result.override_justification = justification_hash;
+    draw_result.was_overridden = true;
+
+    emit!(WinningNumbersOverridden {
+        draw_id,
+        old_numbers,
+        new_numbers: sorted_numbers,
+        justification_hash,
+    });
+
+    msg!("⚠️  Winning numbers OVERRIDDEN by authority");
+    msg!("  Draw ID: {}", draw_id);
+    msg!("  Old numbers: {:?}", old_numbers);
+    msg!("  New numbers: {:?}", sorted_numbers);
+
+    Ok(())
+}
+
+// ============================================================================
+// CANCEL WINNING NUMBERS OVERRIDE
+// ============================================================================
+
+/// Accounts required for cancelling a pending winning numbers override
+#[derive(Accounts)]
+#[instruction(draw_id: u64)]
+pub struct CancelWinningNumbersOverride<'info> {
+    /// The lottery authority (receives the pending override's rent)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The pending override to drop
+    #[account(
+        mut,
+        close = authority,
+        seeds = [PENDING_OVERRIDE_SEED, &draw_id.to_le_bytes()],
+        bump = pending_override.bump
+    )]
+    pub pending_override: Account<'info, PendingOverride>,
+}
+
+/// Drop the pending winning numbers override of draw `draw_id`
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `draw_id` - Draw of the pending override
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_cancel_winning_numbers_override(
+    _ctx: Context<CancelWinningNumbersOverride>,
+    draw_id: u64,
+) -> Result<()> {
+    msg!("Winning numbers override cancelled");
+    msg!("  Draw ID: {}", draw_id);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_override_timelock() {
+        let proposed_at = 1_000;
+        let executable_at = proposed_at + WINNING_NUMBERS_OVERRIDE_DELAY;
+        assert!(!is_override_executable(executable_at, proposed_at));
+        assert!(!is_override_executable(executable_at, executable_at - 1));
+        assert!(is_override_executable(executable_at, executable_at));
+    }
+}
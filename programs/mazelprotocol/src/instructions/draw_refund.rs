@@ -0,0 +1,479 @@
+//! Cancelled Draw Refund Instruction
+//!
+//! `cancel_draw` with `CancelDrawMode::RefundTickets` abandons the draw ID
+//! instead of rescheduling it and creates a `TicketRefundRecord` for it,
+//! sized by the draw's `current_draw_tickets`. Every ticket whose payment
+//! was counted there is then refunded TICKET_PRICE from the reserve:
+//! `TicketData` flagged `requires_refund` at purchase, each unclaimed ticket
+//! of a `buy_bulk` `UnifiedTicket`, and a `MultiDrawTicket` for its first
+//! draw (later draws are not counted in their draw's tickets). Claiming is
+//! permissionless; the USDC always goes to a token account owned by the
+//! ticket owner.
+//!
+//! - claim_cancelled_draw_refund: Refund one ticket of a cancelled draw
+//! - claim_cancelled_draw_bulk_refund: Refund a bulk ticket account's
+//!   unclaimed tickets
+//! - claim_cancelled_draw_multi_draw_refund: Refund a multi-draw ticket's
+//!   first draw
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::CancelledTicketRefunded;
+use crate::instructions::claim_bulk_prize::BATCH_CLAIM_CURSOR_COMPLETE;
+use crate::state::{
+    LotteryState, MultiDrawTicket, SchemaVersioned, TicketData, TicketRefundRecord, UnifiedTicket,
+};
+
+/// Refund liability of a cancelled draw with `tickets` tickets
+pub fn total_refundable_for(tickets: u64) -> Result<u64> {
+    tickets
+        .checked_mul(TICKET_PRICE)
+        .ok_or(LottoError::Overflow.into())
+}
+
+/// Count `amount` against the refund record and the reserve, then transfer
+/// it from the prize pool to the ticket owner
+fn pay_cancelled_draw_refund<'info>(
+    lottery_state: &mut Account<'info, LotteryState>,
+    refund_record: &mut Account<'info, TicketRefundRecord>,
+    prize_pool_usdc: &Account<'info, TokenAccount>,
+    owner_usdc: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        refund_record.remaining() >= amount,
+        LottoError::RefundNotActive
+    );
+    refund_record.total_refunded = refund_record
+        .total_refunded
+        .checked_add(amount)
+        .ok_or(LottoError::Overflow)?;
+    if refund_record.remaining() < TICKET_PRICE {
+        refund_record.is_active = false;
+    }
+
+    require!(
+        lottery_state.reserve_balance >= amount,
+        LottoError::InsufficientReserveForRefund
+    );
+    require!(
+        prize_pool_usdc.amount >= amount,
+        LottoError::InsufficientPrizePool
+    );
+    lottery_state.reserve_balance = lottery_state
+        .reserve_balance
+        .checked_sub(amount)
+        .ok_or(LottoError::Underflow)?;
+
+    let lottery_bump = lottery_state.bump;
+    let seeds = &[LOTTERY_SEED, &[lottery_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: prize_pool_usdc.to_account_info(),
+        to: owner_usdc.to_account_info(),
+        authority: lottery_state.to_account_info(),
+    };
+    let cpi_ctx =
+        CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer_seeds);
+    token::transfer(cpi_ctx, amount)
+}
+
+// ============================================================================
+// CLAIM CANCELLED DRAW REFUND
+// ============================================================================
+
+/// Accounts required for refunding a ticket of a cancelled draw
+#[derive(Accounts)]
+pub struct ClaimCancelledDrawRefund<'info> {
+    /// Anyone may submit the refund; it is paid to the ticket owner
+    pub caller: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The ticket being refunded
+    #[account(
+        mut,
+        constraint = ticket.requires_refund @ LottoError::TicketNotRefundable,
+        constraint = !ticket.is_claimed @ LottoError::AlreadyClaimed,
+        constraint = ticket.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub ticket: Account<'info, TicketData>,
+
+    /// Refund record of the ticket's (cancelled) draw
+    #[account(
+        mut,
+        seeds = [REFUND_RECORD_SEED, &ticket.draw_id.to_le_bytes()],
+        bump = refund_record.bump,
+        constraint = refund_record.draw_id == ticket.draw_id @ LottoError::DrawIdMismatch,
+        constraint = refund_record.is_active @ LottoError::RefundNotActive
+    )]
+    pub refund_record: Account<'info, TicketRefundRecord>,
+
+    /// Ticket owner's USDC token account (receives the refund)
+    #[account(
+        mut,
+        constraint = owner_usdc.owner == ticket.owner @ LottoError::TokenAccountOwnerMismatch,
+        constraint = owner_usdc.mint == prize_pool_usdc.mint @ LottoError::InvalidUsdcMint
+    )]
+    pub owner_usdc: Account<'info, TokenAccount>,
+
+    /// Prize pool USDC token account (holds the reserve)
+    #[account(
+        mut,
+        seeds = [PRIZE_POOL_USDC_SEED],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Refund a ticket of a draw cancelled with `CancelDrawMode::RefundTickets`
+///
+/// This instruction:
+/// 1. Checks the ticket still requires a refund and the record is active
+/// 2. Deducts TICKET_PRICE from the record's remaining capacity and the
+///    reserve, deactivating the record once exhausted
+/// 3. Transfers TICKET_PRICE to the ticket owner
+/// 4. Clears `ticket.requires_refund`
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_claim_cancelled_draw_refund(ctx: Context<ClaimCancelledDrawRefund>) -> Result<()> {
+    let amount = TICKET_PRICE;
+    let ticket_id = ctx.accounts.ticket.key();
+    let player = ctx.accounts.ticket.owner;
+    let draw_id = ctx.accounts.ticket.draw_id;
+
+    pay_cancelled_draw_refund(
+        &mut ctx.accounts.lottery_state,
+        &mut ctx.accounts.refund_record,
+        &ctx.accounts.prize_pool_usdc,
+        &ctx.accounts.owner_usdc,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    ctx.accounts.ticket.requires_refund = false;
+
+    emit!(CancelledTicketRefunded {
+        player,
+        ticket_id,
+        draw_id,
+        amount,
+    });
+
+    msg!("Cancelled draw ticket refunded!");
+    msg!("  Ticket: {}", ticket_id);
+    msg!("  Owner: {}", player);
+    msg!("  Draw ID: {}", draw_id);
+    msg!("  Amount: {} USDC lamports", amount);
+    msg!(
+        "  Reserve balance: {} USDC lamports",
+        ctx.accounts.lottery_state.reserve_balance
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// CLAIM CANCELLED DRAW BULK REFUND
+// ============================================================================
+
+/// Unclaimed tickets of a unified ticket account
+pub fn unclaimed_ticket_count(unified_ticket: &UnifiedTicket) -> u64 {
+    (0..unified_ticket.ticket_count as usize)
+        .filter(|&i| !unified_ticket.is_ticket_claimed(i))
+        .count() as u64
+}
+
+/// Accounts required for refunding a bulk ticket account of a cancelled draw
+#[derive(Accounts)]
+pub struct ClaimCancelledDrawBulkRefund<'info> {
+    /// Anyone may submit the refund; it is paid to the ticket owner
+    pub caller: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The bulk ticket account being refunded
+    #[account(mut)]
+    pub unified_ticket: Account<'info, UnifiedTicket>,
+
+    /// Refund record of the tickets' (cancelled) draw
+    #[account(
+        mut,
+        seeds = [REFUND_RECORD_SEED, &unified_ticket.draw_id.to_le_bytes()],
+        bump = refund_record.bump,
+        constraint = refund_record.draw_id == unified_ticket.draw_id @ LottoError::DrawIdMismatch,
+        constraint = refund_record.is_active @ LottoError::RefundNotActive
+    )]
+    pub refund_record: Account<'info, TicketRefundRecord>,
+
+    /// Ticket owner's USDC token account (receives the refund)
+    #[account(
+        mut,
+        constraint = owner_usdc.owner == unified_ticket.owner @ LottoError::TokenAccountOwnerMismatch,
+        constraint = owner_usdc.mint == prize_pool_usdc.mint @ LottoError::InvalidUsdcMint
+    )]
+    pub owner_usdc: Account<'info, TokenAccount>,
+
+    /// Prize pool USDC token account (holds the reserve)
+    #[account(
+        mut,
+        seeds = [PRIZE_POOL_USDC_SEED],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Refund the unclaimed tickets of a bulk ticket account whose draw was
+/// cancelled with `CancelDrawMode::RefundTickets`
+///
+/// Pays TICKET_PRICE per unclaimed ticket, then marks every ticket claimed
+/// so the account can be closed.
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_claim_cancelled_draw_bulk_refund(
+    ctx: Context<ClaimCancelledDrawBulkRefund>,
+) -> Result<()> {
+    let ticket_id = ctx.accounts.unified_ticket.key();
+    let player = ctx.accounts.unified_ticket.owner;
+    let draw_id = ctx.accounts.unified_ticket.draw_id;
+
+    let tickets = unclaimed_ticket_count(&ctx.accounts.unified_ticket);
+    require!(tickets > 0, LottoError::TicketNotRefundable);
+    let amount = total_refundable_for(tickets)?;
+
+    pay_cancelled_draw_refund(
+        &mut ctx.accounts.lottery_state,
+        &mut ctx.accounts.refund_record,
+        &ctx.accounts.prize_pool_usdc,
+        &ctx.accounts.owner_usdc,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    let unified_ticket = &mut ctx.accounts.unified_ticket;
+    for i in 0..unified_ticket.ticket_count as usize {
+        unified_ticket.mark_ticket_claimed(i);
+    }
+    unified_ticket.claim_cursor = BATCH_CLAIM_CURSOR_COMPLETE;
+
+    emit!(CancelledTicketRefunded {
+        player,
+        ticket_id,
+        draw_id,
+        amount,
+    });
+
+    msg!("Cancelled draw bulk tickets refunded!");
+    msg!("  Ticket account: {}", ticket_id);
+    msg!("  Owner: {}", player);
+    msg!("  Draw ID: {}", draw_id);
+    msg!("  Tickets refunded: {}", tickets);
+    msg!("  Amount: {} USDC lamports", amount);
+
+    Ok(())
+}
+
+// ============================================================================
+// CLAIM CANCELLED DRAW MULTI-DRAW REFUND
+// ============================================================================
+
+/// Accounts required for refunding a multi-draw ticket's cancelled first draw
+#[derive(Accounts)]
+pub struct ClaimCancelledDrawMultiDrawRefund<'info> {
+    /// Anyone may submit the refund; it is paid to the ticket owner
+    pub caller: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The multi-draw ticket being refunded
+    #[account(
+        mut,
+        seeds = [
+            MULTI_DRAW_TICKET_SEED,
+            multi_draw_ticket.owner.as_ref(),
+            &multi_draw_ticket.start_draw_id.to_le_bytes()
+        ],
+        bump = multi_draw_ticket.bump,
+        constraint = !multi_draw_ticket.is_draw_claimed(multi_draw_ticket.start_draw_id) @ LottoError::TicketNotRefundable
+    )]
+    pub multi_draw_ticket: Account<'info, MultiDrawTicket>,
+
+    /// Refund record of the ticket's first (cancelled) draw
+    #[account(
+        mut,
+        seeds = [REFUND_RECORD_SEED, &multi_draw_ticket.start_draw_id.to_le_bytes()],
+        bump = refund_record.bump,
+        constraint = refund_record.draw_id == multi_draw_ticket.start_draw_id @ LottoError::DrawIdMismatch,
+        constraint = refund_record.is_active @ LottoError::RefundNotActive
+    )]
+    pub refund_record: Account<'info, TicketRefundRecord>,
+
+    /// Ticket owner's USDC token account (receives the refund)
+    #[account(
+        mut,
+        constraint = owner_usdc.owner == multi_draw_ticket.owner @ LottoError::TokenAccountOwnerMismatch,
+        constraint = owner_usdc.mint == prize_pool_usdc.mint @ LottoError::InvalidUsdcMint
+    )]
+    pub owner_usdc: Account<'info, TokenAccount>,
+
+    /// Prize pool USDC token account (holds the reserve)
+    #[account(
+        mut,
+        seeds = [PRIZE_POOL_USDC_SEED],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Refund a multi-draw ticket whose first draw was cancelled with
+/// `CancelDrawMode::RefundTickets`
+///
+/// A multi-draw ticket counts towards its first draw's tickets only, so
+/// that is the draw it is refunded for. Pays TICKET_PRICE and marks the
+/// draw claimed.
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_claim_cancelled_draw_multi_draw_refund(
+    ctx: Context<ClaimCancelledDrawMultiDrawRefund>,
+) -> Result<()> {
+    let amount = TICKET_PRICE;
+    let ticket_id = ctx.accounts.multi_draw_ticket.key();
+    let player = ctx.accounts.multi_draw_ticket.owner;
+    let draw_id = ctx.accounts.multi_draw_ticket.start_draw_id;
+
+    pay_cancelled_draw_refund(
+        &mut ctx.accounts.lottery_state,
+        &mut ctx.accounts.refund_record,
+        &ctx.accounts.prize_pool_usdc,
+        &ctx.accounts.owner_usdc,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    ctx.accounts.multi_draw_ticket.mark_draw_claimed(draw_id);
+
+    emit!(CancelledTicketRefunded {
+        player,
+        ticket_id,
+        draw_id,
+        amount,
+    });
+
+    msg!("Cancelled draw multi-draw ticket refunded!");
+    msg!("  Ticket: {}", ticket_id);
+    msg!("  Owner: {}", player);
+    msg!("  Draw ID: {}", draw_id);
+    msg!("  Amount: {} USDC lamports", amount);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_total_refundable_for() {
+        assert_eq!(total_refundable_for(0).unwrap(), 0);
+        assert_eq!(total_refundable_for(4).unwrap(), 4 * TICKET_PRICE);
+        assert!(total_refundable_for(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_refund_record_remaining() {
+        let mut record = TicketRefundRecord {
+            draw_id: 7,
+            total_refundable: 3 * TICKET_PRICE,
+            is_active: true,
+            ..Default::default()
+        };
+        assert_eq!(record.remaining(), 3 * TICKET_PRICE);
+
+        record.total_refunded = 3 * TICKET_PRICE;
+        assert_eq!(record.remaining(), 0);
+    }
+
+    #[test]
+    fn test_unclaimed_ticket_count() {
+        let mut unified_ticket = UnifiedTicket {
+            ticket_count: 10,
+            claimed_bitmap: vec![0u8; 2],
+            ..Default::default()
+        };
+        assert_eq!(unclaimed_ticket_count(&unified_ticket), 10);
+
+        unified_ticket.mark_ticket_claimed(0);
+        unified_ticket.mark_ticket_claimed(9);
+        assert_eq!(unclaimed_ticket_count(&unified_ticket), 8);
+        assert_eq!(
+            total_refundable_for(unclaimed_ticket_count(&unified_ticket)).unwrap(),
+            8 * TICKET_PRICE
+        );
+    }
+
+    #[test]
+    fn test_refundable_ticket_fits_ticket_size() {
+        let ticket = TicketData {
+            schema_version: CURRENT_SCHEMA_VERSION,
+            bump: 254,
+            requires_refund: true,
+            ..Default::default()
+        };
+        let mut data = Vec::new();
+        ticket.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= TICKET_SIZE);
+
+        data.resize(TICKET_SIZE, 0);
+        let decoded = TicketData::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(decoded.bump, 254);
+        assert!(decoded.requires_refund);
+        assert!(decoded.claim_agent.is_none());
+    }
+}
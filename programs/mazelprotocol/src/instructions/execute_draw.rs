@@ -13,17 +13,31 @@
 //! - Below soft cap: No rolldown possible (probability = 0%)
 //! - At soft cap: Probabilistic rolldown begins (linear scaling)
 //! - At hard cap: Forced rolldown (probability = 100%)
+//! - During the cooldown after a forced rolldown: No rolldown, whatever the caps
 //!
 //! SECURITY: This must be called in the slot AFTER the commit.
 //! The randomness is only valid if seed_slot == clock.slot - 1.
+//!
+//! PULL ORACLE: With `use_pull_oracle` set, the randomness comes from the
+//! configured pull feed's latest round, which must be newer than the round
+//! recorded at commit so a pre-commit value cannot be substituted.
+//!
+//! FALLBACK: If the VRF result is still unset VRF_FALLBACK_DELAY_SECONDS after
+//! the commit and `allow_fallback_randomness` is set, the winning numbers are
+//! derived from a recent slot hash instead. Without the flag the draw stays
+//! stuck until `cancel_draw`.
 
 use anchor_lang::prelude::*;
 use switchboard_on_demand::accounts::RandomnessAccountData;
 
 use crate::constants::*;
 use crate::errors::LottoError;
-use crate::events::{DrawExecuted, HardCapReached, SoftCapReached};
-use crate::state::{DrawResult, LotteryState};
+use crate::events::{
+    DrawExecuted, FallbackRandomnessUsed, HardCapReached, PullOracleDrawExecuted,
+    RolldownCooldownActive, RolldownProbabilityRolled, SoftCapReached,
+};
+use crate::instructions::commit_randomness::get_pull_feed_round;
+use crate::state::{DrawResult, LotteryState, SchemaVersioned};
 
 /// Accounts required for executing the draw
 #[derive(Accounts)]
@@ -31,8 +45,12 @@ pub struct ExecuteDraw<'info> {
     /// The authority executing the draw (SECURITY FIX: was previously permissionless)
     /// Without this, anyone could call execute_draw once randomness was committed,
     /// enabling MEV actors to observe randomness and frontrun the reveal.
+    /// The operator may also execute when granted `OPERATOR_PERMISSION_EXECUTE_DRAW`.
     #[account(
-        constraint = authority.key() == lottery_state.authority @ LottoError::Unauthorized
+        constraint = lottery_state.can_run_draw_step(
+            &authority.key(),
+            OPERATOR_PERMISSION_EXECUTE_DRAW
+        ) @ LottoError::Unauthorized
     )]
     pub authority: Signer<'info>,
 
@@ -42,7 +60,8 @@ pub struct ExecuteDraw<'info> {
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
         constraint = lottery_state.is_draw_in_progress @ LottoError::DrawNotInProgress,
-        constraint = !lottery_state.is_paused @ LottoError::Paused
+        constraint = !lottery_state.is_paused @ LottoError::Paused,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
@@ -63,6 +82,12 @@ pub struct ExecuteDraw<'info> {
     )]
     pub randomness_account_data: AccountInfo<'info>,
 
+    /// SlotHashes sysvar (source of fallback randomness)
+    /// CHECK: Address is checked against the sysvar ID; only the most recent
+    /// hash is read
+    #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::ID)]
+    pub recent_slothashes: AccountInfo<'info>,
+
     /// The payer for the draw result account creation
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -110,6 +135,69 @@ impl<'info> ExecuteDraw<'info> {
 
         Ok(revealed_value)
     }
+
+    /// Whether the Switchboard oracle has not yet written a random value
+    pub fn is_vrf_result_unset(&self) -> Result<bool> {
+        let randomness_data =
+            RandomnessAccountData::parse(self.randomness_account_data.data.borrow())
+                .map_err(|_| LottoError::RandomnessParseError)?;
+
+        Ok(randomness_data.value == [0u8; 32])
+    }
+
+    /// Most recent entry in the SlotHashes sysvar
+    pub fn get_recent_slot_hash(&self) -> Result<[u8; 32]> {
+        // Layout: u64 entry count, then (u64 slot, [u8; 32] hash) entries
+        // newest first. Reading the first hash avoids deserializing the sysvar.
+        let data = self.recent_slothashes.try_borrow_data()?;
+        require!(data.len() >= 48, LottoError::RandomnessNotResolved);
+
+        let mut slot_hash = [0u8; 32];
+        slot_hash.copy_from_slice(&data[16..48]);
+        Ok(slot_hash)
+    }
+}
+
+/// Derive draw randomness from a pull feed round
+///
+/// SHA256("pull_feed" || feed || round_id || value || draw_id).
+fn pull_feed_randomness(feed: &Pubkey, round_id: u64, value: i128, draw_id: u64) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    hasher.update(b"pull_feed");
+    hasher.update(feed.as_ref());
+    hasher.update(round_id.to_le_bytes());
+    hasher.update(value.to_le_bytes());
+    hasher.update(draw_id.to_le_bytes());
+
+    let mut randomness = [0u8; 32];
+    randomness.copy_from_slice(hasher.finalize().as_slice());
+    randomness
+}
+
+/// Derive fallback randomness for a draw whose VRF result never arrived
+///
+/// SHA256(recent_slot_hash || unix_timestamp || draw_id || total_tickets_sold).
+/// Weaker than VRF (the slot leader can influence the slot hash), which is
+/// why it is opt-in via `allow_fallback_randomness`.
+fn fallback_randomness(
+    recent_slot_hash: &[u8; 32],
+    unix_timestamp: i64,
+    draw_id: u64,
+    total_tickets_sold: u64,
+) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    hasher.update(recent_slot_hash);
+    hasher.update(unix_timestamp.to_le_bytes());
+    hasher.update(draw_id.to_le_bytes());
+    hasher.update(total_tickets_sold.to_le_bytes());
+
+    let mut randomness = [0u8; 32];
+    randomness.copy_from_slice(hasher.finalize().as_slice());
+    randomness
 }
 
 /// Generate winning numbers from randomness bytes using rejection sampling.
@@ -138,7 +226,7 @@ impl<'info> ExecuteDraw<'info> {
 ///
 /// # Returns
 /// * `Result<[u8; 6]>` - Sorted array of 6 unique winning numbers, or error
-fn generate_winning_numbers(randomness: &[u8; 32]) -> Result<[u8; 6]> {
+pub(crate) fn generate_winning_numbers(randomness: &[u8; 32]) -> Result<[u8; 6]> {
     use sha2::{Digest, Sha256};
 
     let n = MAX_NUMBER as u32; // 46
@@ -247,55 +335,32 @@ fn generate_winning_numbers(randomness: &[u8; 32]) -> Result<[u8; 6]> {
     Ok(winning_numbers)
 }
 
-/// Determine if rolldown should trigger based on randomness and probability
+/// Extract the rolldown sample from the VRF output
 ///
-/// Uses SHA256 hash of randomness for more uniform distribution
-/// to make a probabilistic decision based on the rolldown probability
-/// calculated from jackpot level.
+/// Reads a `u16` from the first two bytes of the revealed randomness and
+/// scales it into the basis-point range [0, 10000) so it can be compared
+/// directly against the rolldown probability.
 ///
 /// # Arguments
 /// * `randomness` - 32 bytes of verified randomness
+///
+/// # Returns
+/// * `u16` - Sample in the range 0-9999
+fn rolldown_vrf_sample(randomness: &[u8; 32]) -> u16 {
+    let raw = u16::from_le_bytes([randomness[0], randomness[1]]);
+    (raw as u64 * BPS_DENOMINATOR / (u16::MAX as u64 + 1)) as u16
+}
+
+/// Determine if rolldown should trigger for a VRF sample and probability
+///
+/// # Arguments
+/// * `vrf_sample` - Sample from `rolldown_vrf_sample` (0-9999)
 /// * `probability_bps` - Rolldown probability in basis points (0-10000)
 ///
 /// # Returns
 /// * `bool` - True if rolldown should trigger
-fn should_trigger_rolldown(randomness: &[u8; 32], probability_bps: u16) -> bool {
-    if probability_bps >= 10000 {
-        return true; // 100% probability (hard cap)
-    }
-    if probability_bps == 0 {
-        return false; // 0% probability (below soft cap)
-    }
-
-    // FIXED: Use domain-separated SHA256 hash to prevent correlated randomness.
-    // Previously, the same raw randomness was hashed for both number generation
-    // and rolldown decision, which could create exploitable correlations.
-    // By adding a domain separator ("rolldown_decision"), the hash output is
-    // completely independent from the one used for winning numbers.
-    use sha2::{Digest, Sha256};
-    let mut hasher = Sha256::new();
-    hasher.update(b"rolldown_decision");
-    hasher.update(randomness);
-    let hash_result = hasher.finalize();
-    let hash_bytes = hash_result.as_slice();
-
-    // Use first 4 bytes of hash for the roll, with bounds checking
-    let roll = if hash_bytes.len() >= 4 {
-        let roll_bytes: [u8; 4] = hash_bytes[0..4]
-            .try_into()
-            .expect("Hash slice should be 4 bytes");
-        u32::from_le_bytes(roll_bytes)
-    } else {
-        // Fallback: pad with zeros if hash is too short
-        let mut bytes = [0u8; 4];
-        bytes[..hash_bytes.len()].copy_from_slice(hash_bytes);
-        u32::from_le_bytes(bytes)
-    };
-
-    // Calculate threshold (0-9999)
-    let threshold = roll % 10000;
-
-    threshold < probability_bps as u32
+fn should_trigger_rolldown(vrf_sample: u16, probability_bps: u16) -> bool {
+    vrf_sample < probability_bps
 }
 
 /// Execute the draw by revealing randomness and generating winning numbers
@@ -330,7 +395,8 @@ pub fn handler(ctx: Context<ExecuteDraw>) -> Result<()> {
     let current_draw_tickets = ctx.accounts.lottery_state.current_draw_tickets;
     let soft_cap = ctx.accounts.lottery_state.soft_cap;
     let hard_cap = ctx.accounts.lottery_state.hard_cap;
-    let rolldown_probability_bps = ctx.accounts.lottery_state.get_rolldown_probability_bps();
+    let rolldown_probability_bps =
+        calculate_rolldown_probability_bps_for_caps(jackpot_balance, soft_cap, hard_cap);
 
     // Log soft/hard cap status
     msg!("📊 Soft/Hard Cap Status:");
@@ -343,10 +409,55 @@ pub fn handler(ctx: Context<ExecuteDraw>) -> Result<()> {
         rolldown_probability_bps as f64 / 100.0
     );
 
-    // Get the revealed randomness
-    let randomness = ctx
-        .accounts
-        .get_revealed_randomness(clock.slot, commit_slot)?;
+    // Get the revealed randomness, falling back to slot-hash randomness if
+    // the VRF result never arrived
+    let use_pull_oracle = ctx.accounts.lottery_state.use_pull_oracle;
+    let commit_timestamp = ctx.accounts.lottery_state.commit_timestamp;
+    let used_fallback_randomness = !use_pull_oracle
+        && is_vrf_fallback_ready(commit_timestamp, clock.unix_timestamp)
+        && ctx.accounts.is_vrf_result_unset()?;
+
+    let randomness = if use_pull_oracle {
+        let pull_feed = ctx.accounts.randomness_account_data.key();
+        let (round_id, value) = get_pull_feed_round(&ctx.accounts.randomness_account_data)?;
+
+        // SECURITY: Only a round produced after the commit is acceptable
+        require!(
+            round_id > ctx.accounts.lottery_state.committed_pull_round_id,
+            LottoError::PullOracleRoundNotAdvanced
+        );
+
+        emit!(PullOracleDrawExecuted {
+            draw_id: current_draw_id,
+            round_id,
+        });
+        msg!("Pull oracle round used: {}", round_id);
+
+        pull_feed_randomness(&pull_feed, round_id, value, current_draw_id)
+    } else if used_fallback_randomness {
+        require!(
+            ctx.accounts.lottery_state.allow_fallback_randomness,
+            LottoError::VRFResultStale
+        );
+
+        let fallback_hash = fallback_randomness(
+            &ctx.accounts.get_recent_slot_hash()?,
+            clock.unix_timestamp,
+            current_draw_id,
+            ctx.accounts.lottery_state.total_tickets_sold,
+        );
+
+        emit!(FallbackRandomnessUsed {
+            draw_id: current_draw_id,
+            fallback_hash,
+        });
+        msg!("⚠️  VRF result stale - using fallback randomness");
+
+        fallback_hash
+    } else {
+        ctx.accounts
+            .get_revealed_randomness(clock.slot, commit_slot)?
+    };
 
     // FIXED: Stronger security check - verify randomness has sufficient entropy
     // Require at least 8 unique bytes out of 32 (25% uniqueness minimum)
@@ -378,10 +489,27 @@ pub fn handler(ctx: Context<ExecuteDraw>) -> Result<()> {
     // ==========================================================================
     // Determine if this draw triggers a rolldown based on:
     // - Hard cap: 100% probability (forced rolldown)
-    // - Soft cap to hard cap: Linear probability scaling
+    // - Soft cap to hard cap: Linear probability scaling, rolled against the VRF sample
     // - Below soft cap: 0% probability (no rolldown)
+    // - Rolldown cooldown after a forced rolldown: no rolldown
+    let vrf_sample = rolldown_vrf_sample(&randomness);
+
+    let was_rolldown = if ctx
+        .accounts
+        .lottery_state
+        .is_rolldown_cooldown_active(current_draw_id)
+    {
+        let until_draw_id = ctx.accounts.lottery_state.rolldown_cooldown_until_draw;
+        ctx.accounts.lottery_state.is_rolldown_active = false;
+
+        emit!(RolldownCooldownActive { until_draw_id });
+        msg!(
+            "⏸️  Rolldown cooldown active until draw {}: rolldown skipped",
+            until_draw_id
+        );
 
-    let was_rolldown = if jackpot_balance >= hard_cap {
+        false
+    } else if jackpot_balance >= hard_cap {
         // Hard cap reached - FORCED rolldown (100% probability)
         msg!("⚠️  HARD CAP TRIGGERED: Forced rolldown!");
 
@@ -393,9 +521,16 @@ pub fn handler(ctx: Context<ExecuteDraw>) -> Result<()> {
         });
 
         true
-    } else if is_rolldown_active && jackpot_balance >= soft_cap {
-        // Soft cap reached - probabilistic rolldown
-        let triggered = should_trigger_rolldown(&randomness, rolldown_probability_bps);
+    } else if jackpot_balance >= soft_cap {
+        // Soft cap reached - probabilistic rolldown decided by the VRF sample
+        let triggered = should_trigger_rolldown(vrf_sample, rolldown_probability_bps);
+
+        emit!(RolldownProbabilityRolled {
+            draw_id: current_draw_id,
+            probability_bps: rolldown_probability_bps,
+            sample: vrf_sample,
+            triggered,
+        });
 
         if triggered {
             msg!(
@@ -425,12 +560,16 @@ pub fn handler(ctx: Context<ExecuteDraw>) -> Result<()> {
 
     // Create draw result
     let draw_result = &mut ctx.accounts.draw_result;
+    draw_result.schema_version = CURRENT_SCHEMA_VERSION;
     draw_result.draw_id = current_draw_id;
     draw_result.winning_numbers = winning_numbers;
     draw_result.randomness_proof = randomness;
+    draw_result.vrf_result_raw = randomness;
     draw_result.timestamp = clock.unix_timestamp;
     draw_result.total_tickets = current_draw_tickets;
     draw_result.was_rolldown = was_rolldown;
+    draw_result.rolldown_vrf_sample = vrf_sample;
+    draw_result.rolldown_probability_bps = rolldown_probability_bps;
 
     // Winner counts will be set during finalize_draw
     draw_result.match_6_winners = 0;
@@ -455,6 +594,14 @@ pub fn handler(ctx: Context<ExecuteDraw>) -> Result<()> {
     draw_result.total_committed = 0;
     draw_result.total_reclaimed = 0;
 
+    // Winner Merkle root is published after finalization
+    draw_result.winner_merkle_root = None;
+    draw_result.merkle_root_submitted_at = 0;
+
+    draw_result.used_fallback_randomness = used_fallback_randomness;
+    draw_result.commit_hash = ctx.accounts.lottery_state.draw_commit_hash;
+    draw_result.force_finalized_at = 0;
+
     // Store hash of randomness for additional verification
     use sha2::{Digest, Sha256};
     let mut hasher = Sha256::new();
@@ -466,6 +613,16 @@ pub fn handler(ctx: Context<ExecuteDraw>) -> Result<()> {
 
     draw_result.bump = ctx.bumps.draw_result;
 
+    // Start the finalize timeout (force_finalize_draw turns permissionless once overdue)
+    ctx.accounts.lottery_state.execute_draw_timestamp = clock.unix_timestamp;
+
+    // The oracle delivered this draw's randomness: clear its health failures
+    if !use_pull_oracle && !used_fallback_randomness {
+        let lottery_state = &mut ctx.accounts.lottery_state;
+        lottery_state.oracle_consecutive_failures = 0;
+        lottery_state.oracle_last_healthy_timestamp = clock.unix_timestamp;
+    }
+
     // Emit event
     emit!(DrawExecuted {
         draw_id: current_draw_id,
@@ -483,9 +640,11 @@ pub fn handler(ctx: Context<ExecuteDraw>) -> Result<()> {
     msg!("  Jackpot at draw: {} USDC lamports", jackpot_balance);
     if was_rolldown {
         msg!("  🎰 ROLLDOWN ACTIVE: Jackpot will be distributed to lower tiers!");
-        msg!("    Match 5: 25% of jackpot (pari-mutuel)");
-        msg!("    Match 4: 35% of jackpot (pari-mutuel)");
-        msg!("    Match 3: 40% of jackpot (pari-mutuel)");
+        let [match_5_bps, match_4_bps, match_3_bps] =
+            ctx.accounts.lottery_state.rolldown_split_bps();
+        msg!("    Match 5: {} bps of jackpot (pari-mutuel)", match_5_bps);
+        msg!("    Match 4: {} bps of jackpot (pari-mutuel)", match_4_bps);
+        msg!("    Match 3: {} bps of jackpot (pari-mutuel)", match_3_bps);
     }
 
     Ok(())
@@ -495,6 +654,52 @@ pub fn handler(ctx: Context<ExecuteDraw>) -> Result<()> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_pull_feed_randomness_binds_all_inputs() {
+        let feed = Pubkey::new_unique();
+        let base = pull_feed_randomness(&feed, 1_000, 42, 7);
+
+        assert_eq!(base, pull_feed_randomness(&feed, 1_000, 42, 7));
+        assert_ne!(
+            base,
+            pull_feed_randomness(&Pubkey::new_unique(), 1_000, 42, 7)
+        );
+        assert_ne!(base, pull_feed_randomness(&feed, 1_001, 42, 7));
+        assert_ne!(base, pull_feed_randomness(&feed, 1_000, 43, 7));
+        assert_ne!(base, pull_feed_randomness(&feed, 1_000, 42, 8));
+        assert!(generate_winning_numbers(&base).is_ok());
+    }
+
+    #[test]
+    fn test_fallback_randomness_binds_all_inputs() {
+        let slot_hash = [7u8; 32];
+        let base = fallback_randomness(&slot_hash, 1_700_000_000, 42, 1_000);
+
+        assert_eq!(
+            base,
+            fallback_randomness(&slot_hash, 1_700_000_000, 42, 1_000)
+        );
+        assert_ne!(
+            base,
+            fallback_randomness(&[8u8; 32], 1_700_000_000, 42, 1_000)
+        );
+        assert_ne!(
+            base,
+            fallback_randomness(&slot_hash, 1_700_000_001, 42, 1_000)
+        );
+        assert_ne!(
+            base,
+            fallback_randomness(&slot_hash, 1_700_000_000, 43, 1_000)
+        );
+        assert_ne!(
+            base,
+            fallback_randomness(&slot_hash, 1_700_000_000, 42, 1_001)
+        );
+
+        // Fallback output must yield a valid draw
+        assert!(generate_winning_numbers(&base).is_ok());
+    }
+
     #[test]
     fn test_generate_winning_numbers_uniqueness() {
         let randomness = [
@@ -539,12 +744,30 @@ mod tests {
 
     #[test]
     fn test_should_trigger_rolldown() {
-        let randomness = [0xFF; 32]; // High value
+        let sample = rolldown_vrf_sample(&[0xFF; 32]); // High value
 
         // 100% probability should always trigger
-        assert!(should_trigger_rolldown(&randomness, 10000));
+        assert!(should_trigger_rolldown(sample, 10000));
 
         // 0% probability should never trigger
-        assert!(!should_trigger_rolldown(&randomness, 0));
+        assert!(!should_trigger_rolldown(sample, 0));
+
+        assert!(should_trigger_rolldown(4999, 5000));
+        assert!(!should_trigger_rolldown(5000, 5000));
+    }
+
+    #[test]
+    fn test_rolldown_vrf_sample_range() {
+        let mut randomness = [0u8; 32];
+        assert_eq!(rolldown_vrf_sample(&randomness), 0);
+
+        randomness[0] = 0xFF;
+        randomness[1] = 0xFF;
+        assert_eq!(rolldown_vrf_sample(&randomness), 9999);
+
+        // 0x8000 is the midpoint of the u16 range
+        randomness[0] = 0x00;
+        randomness[1] = 0x80;
+        assert_eq!(rolldown_vrf_sample(&randomness), 5000);
     }
 }
@@ -5,24 +5,40 @@
 //!
 //! The finalization process:
 //! 1. Validates winner counts submitted by authority
-//! 2. Performs solvency check (jackpot + reserve + insurance)
+//! 2. Performs solvency check (jackpot + reserve + insurance tier 1)
 //! 3. Calculates prizes based on mode (fixed or pari-mutuel rolldown)
-//! 4. Uses insurance pool if needed for prize shortfalls
+//! 4. Uses insurance tier 1 if needed for prize shortfalls
 //! 5. Handles zero-winner tiers by redistributing funds
-//! 6. Updates the draw result with prize amounts
+//! 6. Updates the draw result with prize amounts and opens the
+//!    CHALLENGE_WINDOW_SECONDS window for disputing winner counts
 //! 7. Resets lottery state for the next draw
-//! 8. Seeds the new jackpot if rolldown occurred
+//! 8. Seeds the new jackpot if rolldown occurred (starting the rolldown
+//!    cooldown after a forced hard-cap rolldown), then tops it up to the
+//!    guaranteed minimum from the guarantee reserve (pausing if it cannot)
 //! 9. Updates dynamic house fee based on new jackpot level
+//! 10. Records a summary in the draw history ring buffer
+//! 11. Records a reserve snapshot in the reserve history ring buffer
+//! 12. Counts the winning numbers in the bias detector, running the
+//!     chi-squared bias test every BIAS_TEST_INTERVAL_DRAWS draws
 
 use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::errors::LottoError;
 use crate::events::{
-    DrawFinalized, DynamicFeeTierChanged, EmergencyPause, InsurancePoolUsed, RolldownExecuted,
-    SoftCapReached, SolvencyCheckPerformed,
+    DrawFinalized, DrawFinalizedV2, DrawScheduleAnchored, DynamicFeeTierChanged, EmergencyPause,
+    FixedPrizeCapApplied, InsurancePoolUsed, JackpotGuaranteeFailed, JackpotGuaranteeTriggered,
+    ReserveMinimumBreach, RolldownCooldownActive, RolldownExecuted, SoftCapReached,
+    SolvencyCheckPerformed, UpgradePendingWarning,
+};
+use crate::instructions::jackpot_guarantee::{apply_jackpot_guarantee, JackpotGuaranteeOutcome};
+use crate::instructions::number_bias::run_bias_test_if_due;
+use crate::instructions::state_diff::{emit_state_diff, LotteryStateSnapshot};
+use crate::state::{
+    DrawHistoryBuffer, DrawHistorySummary, DrawResult, LotteryAnalytics, LotteryState,
+    NumberBiasDetector, NumberFrequencyAccount, RecurringTicketSchedule, ReserveHistory,
+    ReserveSnapshot, SchemaVersioned, WinnerCounts,
 };
-use crate::state::{DrawResult, LotteryState, WinnerCounts};
 
 /// Parameters for finalizing the draw
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -50,7 +66,8 @@ pub struct FinalizeDraw<'info> {
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
         constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
-        constraint = lottery_state.is_draw_in_progress @ LottoError::DrawNotInProgress
+        constraint = lottery_state.is_draw_in_progress @ LottoError::DrawNotInProgress,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
@@ -59,9 +76,59 @@ pub struct FinalizeDraw<'info> {
         mut,
         seeds = [DRAW_SEED, &lottery_state.current_draw_id.to_le_bytes()],
         bump = draw_result.bump,
-        constraint = draw_result.draw_id == lottery_state.current_draw_id @ LottoError::DrawIdMismatch
+        constraint = draw_result.draw_id == lottery_state.current_draw_id @ LottoError::DrawIdMismatch,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub draw_result: Account<'info, DrawResult>,
+
+    /// Recent draw history ring buffer (records this draw)
+    #[account(
+        mut,
+        seeds = [DRAW_HISTORY_SEED],
+        bump = draw_history.bump
+    )]
+    pub draw_history: Account<'info, DrawHistoryBuffer>,
+
+    /// Winning number frequency histogram (counts this draw's numbers)
+    #[account(
+        mut,
+        seeds = [NUMBER_FREQUENCY_SEED],
+        bump = number_frequency.bump
+    )]
+    pub number_frequency: Box<Account<'info, NumberFrequencyAccount>>,
+
+    /// Reserve history ring buffer (records this draw's reserve snapshot)
+    #[account(
+        mut,
+        seeds = [RESERVE_HISTORY_SEED],
+        bump = reserve_history.bump
+    )]
+    pub reserve_history: Box<Account<'info, ReserveHistory>>,
+
+    /// Winning number bias detector (counts this draw's numbers)
+    #[account(
+        mut,
+        seeds = [BIAS_DETECTOR_SEED],
+        bump = number_bias_detector.bump
+    )]
+    pub number_bias_detector: Box<Account<'info, NumberBiasDetector>>,
+
+    /// Lifetime lottery analytics (counts this draw)
+    #[account(
+        mut,
+        seeds = [ANALYTICS_SEED],
+        bump = lottery_analytics.bump
+    )]
+    pub lottery_analytics: Box<Account<'info, LotteryAnalytics>>,
+
+    /// Recurring ticket schedule (required while recurring tickets are
+    /// pending; the next draw starts with the ones bought for it)
+    #[account(
+        mut,
+        seeds = [RECURRING_SCHEDULE_SEED],
+        bump = recurring_schedule.bump
+    )]
+    pub recurring_schedule: Option<Box<Account<'info, RecurringTicketSchedule>>>,
 }
 
 /// Result of prize calculation
@@ -76,6 +143,43 @@ struct PrizeCalculation {
     was_scaled_down: bool, // True if prizes were scaled down due to insufficient funds
     scale_factor_bps: u16, // Scale factor applied (10000 = 100%, no scaling)
     calculation_details: String, // Detailed explanation of calculation for debugging
+    capped_tiers: Vec<TierPayoutCap>, // Fixed tiers limited by max_fixed_payout_per_tier
+}
+
+/// A fixed tier whose total payout was limited by `max_fixed_payout_per_tier`
+#[derive(Debug, PartialEq, Eq)]
+struct TierPayoutCap {
+    tier: u8,
+    original_total: u64,
+    capped_total: u64,
+    per_winner_prize: u64,
+}
+
+/// Limit a fixed tier's total payout to `cap` (0 = uncapped)
+///
+/// Returns the per-winner prize to pay and, when the cap triggered, the
+/// details for `FixedPrizeCapApplied`.
+fn apply_fixed_payout_cap(
+    tier: u8,
+    prize_per_winner: u64,
+    winners: u32,
+    cap: u64,
+) -> (u64, Option<TierPayoutCap>) {
+    let original_total = prize_per_winner.saturating_mul(winners as u64);
+    if cap == 0 || winners == 0 || original_total <= cap {
+        return (prize_per_winner, None);
+    }
+
+    let per_winner_prize = cap / winners as u64;
+    (
+        per_winner_prize,
+        Some(TierPayoutCap {
+            tier,
+            original_total,
+            capped_total: cap,
+            per_winner_prize,
+        }),
+    )
 }
 
 /// Calculate prizes for normal mode (fixed prizes) with solvency check
@@ -91,10 +195,15 @@ struct PrizeCalculation {
 /// the fixed prizes (Match 3, 4, 5) are scaled down proportionally.
 /// Match 6 (jackpot) and Match 2 (free ticket credit) are not affected.
 ///
+/// PAYOUT CAPS: After solvency scaling, a Match 2-5 tier whose total
+/// exceeds its nonzero `max_fixed_payout_per_tier` entry pays
+/// `cap / winners` per winner instead.
+///
 /// # Arguments
 /// * `winner_counts` - Number of winners in each tier
 /// * `jackpot_balance` - Current jackpot balance
 /// * `available_prize_pool` - Total available funds in prize pool for fixed prizes
+/// * `max_fixed_payout_per_tier` - Match 2-5 total payout caps (0 = uncapped)
 ///
 /// # Returns
 /// * `PrizeCalculation` - Prize per winner for each tier and totals
@@ -102,6 +211,7 @@ fn calculate_fixed_prizes(
     winner_counts: &WinnerCounts,
     jackpot_balance: u64,
     available_prize_pool: u64,
+    max_fixed_payout_per_tier: &[u64; FIXED_PAYOUT_CAP_TIER_COUNT],
 ) -> PrizeCalculation {
     // FIXED: Validate winner counts are reasonable
     let total_tickets_estimate = winner_counts
@@ -202,6 +312,23 @@ fn calculate_fixed_prizes(
     // because it's a free ticket credit, not actual USDC transferred
     let match_2_prize = MATCH_2_VALUE;
 
+    // Apply per-tier payout caps (index 0 = Match 2 ... index 3 = Match 5)
+    let mut capped_tiers = Vec::new();
+    let mut capped_prize = |tier: u8, prize: u64, winners: u32| {
+        let (prize, cap) = apply_fixed_payout_cap(
+            tier,
+            prize,
+            winners,
+            max_fixed_payout_per_tier[(tier - 2) as usize],
+        );
+        capped_tiers.extend(cap);
+        prize
+    };
+    let match_2_prize = capped_prize(2, match_2_prize, winner_counts.match_2);
+    let match_3_prize = capped_prize(3, match_3_prize, winner_counts.match_3);
+    let match_4_prize = capped_prize(4, match_4_prize, winner_counts.match_4);
+    let match_5_prize = capped_prize(5, match_5_prize, winner_counts.match_5);
+
     // FIXED: Calculate total with checked arithmetic
     // Note: Match 2 is NOT included because it's a free ticket credit, not USDC transfer
     let total_distributed = match_6_prize
@@ -223,12 +350,14 @@ fn calculate_fixed_prizes(
         was_scaled_down: was_scaled,
         scale_factor_bps: scale_bps,
         calculation_details: scale_details,
+        capped_tiers,
     }
 }
 
 /// Calculate prizes for rolldown mode (pari-mutuel) with redistribution
 ///
-/// In rolldown mode, the entire jackpot is distributed to lower tiers:
+/// In rolldown mode, the entire jackpot is distributed to lower tiers
+/// according to the configured split (defaults shown):
 /// - Match 5: 25% of jackpot
 /// - Match 4: 35% of jackpot
 /// - Match 3: 40% of jackpot
@@ -240,13 +369,17 @@ fn calculate_fixed_prizes(
 /// # Arguments
 /// * `winner_counts` - Number of winners in each tier
 /// * `jackpot_balance` - Jackpot being distributed
+/// * `split_bps` - Rolldown split as [Match 5, Match 4, Match 3] basis points
 ///
 /// # Returns
 /// * `PrizeCalculation` - Prize per winner for each tier and totals
 fn calculate_rolldown_prizes(
     winner_counts: &WinnerCounts,
     jackpot_balance: u64,
+    split_bps: [u16; 3],
 ) -> PrizeCalculation {
+    let [match_5_bps, match_4_bps, match_3_bps] = split_bps;
+
     // Match 6 gets nothing in rolldown (no jackpot winner by definition)
     let match_6_prize = 0u64;
 
@@ -257,17 +390,17 @@ fn calculate_rolldown_prizes(
 
     // Calculate initial pool allocations with overflow protection
     let initial_match_5_pool = (jackpot_balance as u128)
-        .checked_mul(ROLLDOWN_MATCH_5_BPS as u128)
+        .checked_mul(match_5_bps as u128)
         .and_then(|prod| prod.checked_div(BPS_DENOMINATOR as u128))
         .unwrap_or(0) as u64;
 
     let initial_match_4_pool = (jackpot_balance as u128)
-        .checked_mul(ROLLDOWN_MATCH_4_BPS as u128)
+        .checked_mul(match_4_bps as u128)
         .and_then(|prod| prod.checked_div(BPS_DENOMINATOR as u128))
         .unwrap_or(0) as u64;
 
     let initial_match_3_pool = (jackpot_balance as u128)
-        .checked_mul(ROLLDOWN_MATCH_3_BPS as u128)
+        .checked_mul(match_3_bps as u128)
         .and_then(|prod| prod.checked_div(BPS_DENOMINATOR as u128))
         .unwrap_or(0) as u64;
 
@@ -310,9 +443,9 @@ fn calculate_rolldown_prizes(
             }
 
             // Calculate the total BPS for tiers with winners with overflow protection
-            let total_winner_bps = (if has_match_5 { ROLLDOWN_MATCH_5_BPS } else { 0 })
-                .checked_add(if has_match_4 { ROLLDOWN_MATCH_4_BPS } else { 0 })
-                .and_then(|sum| sum.checked_add(if has_match_3 { ROLLDOWN_MATCH_3_BPS } else { 0 }))
+            let total_winner_bps = (if has_match_5 { match_5_bps } else { 0 })
+                .checked_add(if has_match_4 { match_4_bps } else { 0 })
+                .and_then(|sum| sum.checked_add(if has_match_3 { match_3_bps } else { 0 }))
                 .unwrap_or(0);
 
             // Safety check: total_winner_bps should be > 0 if we have winners
@@ -329,6 +462,7 @@ fn calculate_rolldown_prizes(
                     was_scaled_down: false,
                     scale_factor_bps: 10000,
                     calculation_details: String::from("Error: Zero total winner BPS"),
+                    capped_tiers: Vec::new(),
                 };
             }
 
@@ -336,7 +470,7 @@ fn calculate_rolldown_prizes(
             let match_5_pool = if has_match_5 {
                 let base = initial_match_5_pool;
                 let redistribution = (redistributable as u128)
-                    .checked_mul(ROLLDOWN_MATCH_5_BPS as u128)
+                    .checked_mul(match_5_bps as u128)
                     .and_then(|prod| prod.checked_div(total_winner_bps as u128))
                     .unwrap_or(0) as u64;
                 base.checked_add(redistribution).unwrap_or(u64::MAX)
@@ -347,7 +481,7 @@ fn calculate_rolldown_prizes(
             let match_4_pool = if has_match_4 {
                 let base = initial_match_4_pool;
                 let redistribution = (redistributable as u128)
-                    .checked_mul(ROLLDOWN_MATCH_4_BPS as u128)
+                    .checked_mul(match_4_bps as u128)
                     .and_then(|prod| prod.checked_div(total_winner_bps as u128))
                     .unwrap_or(0) as u64;
                 base.checked_add(redistribution).unwrap_or(u64::MAX)
@@ -358,7 +492,7 @@ fn calculate_rolldown_prizes(
             let match_3_pool = if has_match_3 {
                 let base = initial_match_3_pool;
                 let redistribution = (redistributable as u128)
-                    .checked_mul(ROLLDOWN_MATCH_3_BPS as u128)
+                    .checked_mul(match_3_bps as u128)
                     .and_then(|prod| prod.checked_div(total_winner_bps as u128))
                     .unwrap_or(0) as u64;
                 base.checked_add(redistribution).unwrap_or(u64::MAX)
@@ -435,6 +569,7 @@ fn calculate_rolldown_prizes(
         was_scaled_down: false, // Rolldown mode distributes available funds, no scaling needed
         scale_factor_bps: 10000,
         calculation_details,
+        capped_tiers: Vec::new(),
     }
 }
 
@@ -458,6 +593,7 @@ fn calculate_rolldown_prizes(
 /// * `Result<()>` - Success or error
 pub fn handler(ctx: Context<FinalizeDraw>, params: FinalizeDrawParams) -> Result<()> {
     let clock = Clock::get()?;
+    let state_before = LotteryStateSnapshot::capture(&ctx.accounts.lottery_state);
     let lottery_state = &mut ctx.accounts.lottery_state;
     let draw_result = &mut ctx.accounts.draw_result;
 
@@ -632,6 +768,18 @@ pub fn handler(ctx: Context<FinalizeDraw>, params: FinalizeDrawParams) -> Result
     // Determine prize mode and calculate prizes
     let jackpot_at_draw = lottery_state.jackpot_balance;
     let was_rolldown = draw_result.was_rolldown && params.winner_counts.match_6 == 0;
+    let was_rolldown_forced = was_rolldown && jackpot_at_draw >= lottery_state.hard_cap;
+    // A rolldown with no Match 3-5 winners carries the jackpot over
+    let jackpot_distributed = if params.winner_counts.match_6 > 0
+        || (was_rolldown
+            && (params.winner_counts.match_5 > 0
+                || params.winner_counts.match_4 > 0
+                || params.winner_counts.match_3 > 0))
+    {
+        jackpot_at_draw
+    } else {
+        0
+    };
 
     // ==========================================================================
     // SOLVENCY CHECK WITH INSURANCE POOL INTEGRATION
@@ -655,8 +803,8 @@ pub fn handler(ctx: Context<FinalizeDraw>, params: FinalizeDrawParams) -> Result
     let primary_funds = jackpot_at_draw
         .saturating_add(lottery_state.reserve_balance)
         .saturating_add(lottery_state.fixed_prize_balance);
-    let total_available = primary_funds.saturating_add(lottery_state.insurance_balance);
-    let insurance_balance_before = lottery_state.insurance_balance;
+    let total_available = primary_funds.saturating_add(lottery_state.insurance_tier1_balance);
+    let insurance_balance_before = lottery_state.insurance_tier1_balance;
 
     msg!("📊 Solvency check:");
     msg!("  Jackpot balance: {} USDC lamports", jackpot_at_draw);
@@ -670,17 +818,43 @@ pub fn handler(ctx: Context<FinalizeDraw>, params: FinalizeDrawParams) -> Result
     );
     msg!(
         "  Insurance balance: {} USDC lamports",
-        lottery_state.insurance_balance
+        lottery_state.insurance_tier1_balance
     );
     msg!("  Total available: {} USDC lamports", total_available);
 
     // Calculate prizes with available funds
     let prize_calc = if was_rolldown {
-        calculate_rolldown_prizes(&params.winner_counts, jackpot_at_draw)
+        calculate_rolldown_prizes(
+            &params.winner_counts,
+            jackpot_at_draw,
+            lottery_state.rolldown_split_bps(),
+        )
     } else {
-        calculate_fixed_prizes(&params.winner_counts, jackpot_at_draw, total_available)
+        calculate_fixed_prizes(
+            &params.winner_counts,
+            jackpot_at_draw,
+            total_available,
+            &lottery_state.max_fixed_payout_per_tier,
+        )
     };
 
+    for cap in &prize_calc.capped_tiers {
+        emit!(FixedPrizeCapApplied {
+            draw_id: lottery_state.current_draw_id,
+            tier: cap.tier,
+            original_total: cap.original_total,
+            capped_total: cap.capped_total,
+            per_winner_prize: cap.per_winner_prize,
+        });
+        msg!(
+            "Match {} payout capped: {} -> {} USDC lamports ({} per winner)",
+            cap.tier,
+            cap.original_total,
+            cap.capped_total,
+            cap.per_winner_prize
+        );
+    }
+
     // Check if insurance pool needs to be used
     let mut insurance_used = 0u64;
     if prize_calc.total_distributed > primary_funds && !was_rolldown {
@@ -688,18 +862,18 @@ pub fn handler(ctx: Context<FinalizeDraw>, params: FinalizeDrawParams) -> Result
         insurance_used = prize_calc
             .total_distributed
             .saturating_sub(primary_funds)
-            .min(lottery_state.insurance_balance);
+            .min(lottery_state.insurance_tier1_balance);
 
         if insurance_used > 0 {
-            lottery_state.insurance_balance = lottery_state
-                .insurance_balance
+            lottery_state.insurance_tier1_balance = lottery_state
+                .insurance_tier1_balance
                 .saturating_sub(insurance_used);
 
             msg!("⚠️  INSURANCE POOL ACTIVATED!");
             msg!("  Amount used: {} USDC lamports", insurance_used);
             msg!(
                 "  Remaining insurance: {} USDC lamports",
-                lottery_state.insurance_balance
+                lottery_state.insurance_tier1_balance
             );
 
             // Emit insurance pool usage event
@@ -707,7 +881,7 @@ pub fn handler(ctx: Context<FinalizeDraw>, params: FinalizeDrawParams) -> Result
                 draw_id: lottery_state.current_draw_id,
                 amount_used: insurance_used,
                 balance_before: insurance_balance_before,
-                balance_after: lottery_state.insurance_balance,
+                balance_after: lottery_state.insurance_tier1_balance,
                 reason: format!(
                     "Prize pool shortfall: {} required, {} available from primary funds",
                     prize_calc.total_distributed, primary_funds
@@ -755,6 +929,9 @@ pub fn handler(ctx: Context<FinalizeDraw>, params: FinalizeDrawParams) -> Result
     // FIXED: Explicitly mark draw as finalized to handle edge cases
     // (e.g., rolldowns with only Match 3/4 winners where prize values might be 0 for other tiers)
     draw_result.is_explicitly_finalized = true;
+    draw_result.challenge_deadline = clock
+        .unix_timestamp
+        .saturating_add(CHALLENGE_WINDOW_SECONDS);
 
     // FIXED: Add any undistributed funds to reserve (from empty tiers or integer division)
     if prize_calc.undistributed > 0 {
@@ -785,6 +962,10 @@ pub fn handler(ctx: Context<FinalizeDraw>, params: FinalizeDrawParams) -> Result
             lottery_state.reserve_balance = lottery_state
                 .reserve_balance
                 .saturating_sub(seed_from_reserve);
+            // A new jackpot season starts with the reseeded jackpot
+            lottery_state.jackpot_ath_for_season = 0;
+            lottery_state.milestone_claimed = [false; JACKPOT_MILESTONE_COUNT];
+            lottery_state.milestone_unlocked = 0;
 
             // Emit rolldown event
             emit!(RolldownExecuted {
@@ -806,6 +987,16 @@ pub fn handler(ctx: Context<FinalizeDraw>, params: FinalizeDrawParams) -> Result
                 "  New jackpot seeded: {} USDC lamports",
                 lottery_state.jackpot_balance
             );
+
+            // A forced hard-cap rolldown keeps rolldowns off for a while so a
+            // ticket surge cannot refill the reseeded jackpot straight into another
+            if jackpot_at_draw >= lottery_state.hard_cap
+                && lottery_state.rolldown_cooldown_draws > 0
+            {
+                let until_draw_id = lottery_state.start_rolldown_cooldown();
+                emit!(RolldownCooldownActive { until_draw_id });
+                msg!("  Rolldown cooldown until draw {}", until_draw_id);
+            }
         } else {
             // Rolldown triggered but NO winners in any tier
             // Jackpot remains for next draw (not moved to reserve)
@@ -824,6 +1015,10 @@ pub fn handler(ctx: Context<FinalizeDraw>, params: FinalizeDrawParams) -> Result
         lottery_state.reserve_balance = lottery_state
             .reserve_balance
             .saturating_sub(seed_from_reserve);
+        // A new jackpot season starts with the reseeded jackpot
+        lottery_state.jackpot_ath_for_season = 0;
+        lottery_state.milestone_claimed = [false; JACKPOT_MILESTONE_COUNT];
+        lottery_state.milestone_unlocked = 0;
 
         msg!("Jackpot won by {} winners!", params.winner_counts.match_6);
         msg!(
@@ -837,6 +1032,49 @@ pub fn handler(ctx: Context<FinalizeDraw>, params: FinalizeDrawParams) -> Result
     }
     // If no jackpot winner and no rolldown, jackpot continues to accumulate
 
+    // ==========================================================================
+    // JACKPOT GUARANTEE
+    // ==========================================================================
+    match apply_jackpot_guarantee(lottery_state) {
+        JackpotGuaranteeOutcome::NotNeeded => {}
+        JackpotGuaranteeOutcome::Triggered { shortfall_covered } => {
+            emit!(JackpotGuaranteeTriggered {
+                shortfall_covered,
+                new_jackpot_balance: lottery_state.jackpot_balance,
+            });
+            msg!("Jackpot guarantee triggered");
+            msg!(
+                "  Topped up from guarantee reserve: {} USDC lamports",
+                shortfall_covered
+            );
+            msg!(
+                "  Guarantee reserve left: {} USDC lamports",
+                lottery_state.guarantee_reserve_balance
+            );
+        }
+        JackpotGuaranteeOutcome::Failed {
+            required,
+            available,
+        } => {
+            lottery_state.is_paused = true;
+
+            msg!("⚠️  Jackpot guarantee cannot be met!");
+            msg!("  Shortfall: {} USDC lamports", required);
+            msg!("  Guarantee reserve: {} USDC lamports", available);
+            msg!("  Lottery has been PAUSED until the guarantee is funded.");
+
+            emit!(JackpotGuaranteeFailed {
+                required,
+                available,
+            });
+            emit!(EmergencyPause {
+                authority: ctx.accounts.authority.key(),
+                reason: "Jackpot guarantee reserve insufficient".to_string(),
+                timestamp: clock.unix_timestamp,
+            });
+        }
+    }
+
     // SECURITY FIX (Issue #6): Track committed prizes separately from actual paid prizes.
     // total_prizes_committed reflects what was promised at finalization time.
     // total_prizes_paid is now incremented at actual claim time (in claim_prize/claim_bulk_prize).
@@ -852,11 +1090,26 @@ pub fn handler(ctx: Context<FinalizeDraw>, params: FinalizeDrawParams) -> Result
 
     // Reset for next draw using helper method
     lottery_state.reset_draw_state();
-    lottery_state.current_draw_id = lottery_state.current_draw_id.saturating_add(1);
-    lottery_state.current_draw_tickets = 0;
-
-    // Set next draw timestamp
-    lottery_state.next_draw_timestamp = clock.unix_timestamp + lottery_state.draw_interval;
+    lottery_state.open_next_draw(
+        ctx.accounts
+            .recurring_schedule
+            .as_deref_mut()
+            .map(|schedule| &mut **schedule),
+    )?;
+    // Jackpot may have been won or rolled down; re-announce milestones next draw
+    lottery_state.milestones_emitted = 0;
+
+    // Set next draw timestamp on the anchor grid so late draws don't drift
+    lottery_state.next_draw_timestamp = next_anchored_draw_timestamp(
+        clock.unix_timestamp,
+        lottery_state.anchor_draw_time,
+        lottery_state.draw_interval,
+    );
+    emit!(DrawScheduleAnchored {
+        next_draw_timestamp: lottery_state.next_draw_timestamp,
+        anchor_time: lottery_state.anchor_draw_time,
+        interval: lottery_state.draw_interval,
+    });
 
     // ==========================================================================
     // JACKPOT FUNDING SAFETY CHECK
@@ -901,6 +1154,34 @@ pub fn handler(ctx: Context<FinalizeDraw>, params: FinalizeDrawParams) -> Result
         msg!("  Minimum required: {} USDC lamports", minimum_jackpot);
     }
 
+    // ==========================================================================
+    // RESERVE MINIMUM FLOOR
+    // ==========================================================================
+    if lottery_state.is_reserve_below_minimum() {
+        lottery_state.is_paused = true;
+
+        msg!("⚠️  Reserve below minimum!");
+        msg!(
+            "  Reserve balance: {} USDC lamports",
+            lottery_state.reserve_balance
+        );
+        msg!(
+            "  Reserve minimum: {} USDC lamports",
+            lottery_state.reserve_minimum
+        );
+        msg!("  Lottery has been PAUSED until the reserve is topped up.");
+
+        emit!(ReserveMinimumBreach {
+            balance: lottery_state.reserve_balance,
+            minimum: lottery_state.reserve_minimum,
+        });
+        emit!(EmergencyPause {
+            authority: ctx.accounts.authority.key(),
+            reason: "Reserve below minimum".to_string(),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
     // ==========================================================================
     // DYNAMIC HOUSE FEE UPDATE
     // ==========================================================================
@@ -932,8 +1213,15 @@ pub fn handler(ctx: Context<FinalizeDraw>, params: FinalizeDrawParams) -> Result
     // ==========================================================================
     // SOFT/HARD CAP CHECK FOR NEXT DRAW
     // ==========================================================================
-    // Only check caps if lottery is not paused due to insufficient funding
-    if !lottery_state.is_paused {
+    // Only check caps if lottery is not paused due to insufficient funding and
+    // the next draw is outside the rolldown cooldown
+    if lottery_state.is_rolldown_cooldown_active(lottery_state.current_draw_id) {
+        msg!(
+            "⏸️  Rolldown cooldown active until draw {} - cap checks skipped.",
+            lottery_state.rolldown_cooldown_until_draw
+        );
+        lottery_state.is_rolldown_active = false;
+    } else if !lottery_state.is_paused {
         if lottery_state.jackpot_balance >= lottery_state.hard_cap {
             lottery_state.is_rolldown_active = true;
             msg!(
@@ -977,6 +1265,44 @@ pub fn handler(ctx: Context<FinalizeDraw>, params: FinalizeDrawParams) -> Result
         total_distributed: prize_calc.total_distributed,
         timestamp: clock.unix_timestamp,
     });
+    emit!(DrawFinalizedV2 {
+        draw_id: draw_result.draw_id,
+        winning_numbers: draw_result.winning_numbers,
+        total_tickets: draw_result.total_tickets,
+        was_rolldown,
+        was_rolldown_forced,
+        jackpot_distributed,
+        match_6_winners: params.winner_counts.match_6,
+        match_6_prize: prize_calc.match_6_prize,
+        match_5_winners: params.winner_counts.match_5,
+        match_5_prize: prize_calc.match_5_prize,
+        match_4_winners: params.winner_counts.match_4,
+        match_4_prize: prize_calc.match_4_prize,
+        match_3_winners: params.winner_counts.match_3,
+        match_3_prize: prize_calc.match_3_prize,
+        match_2_winners: params.winner_counts.match_2,
+        match_2_prize: prize_calc.match_2_prize,
+        total_distributed: prize_calc.total_distributed,
+        prizes_scaled: prize_calc.was_scaled_down,
+        scale_factor_bps: prize_calc.scale_factor_bps,
+        insurance_used,
+        new_jackpot_balance: lottery_state.jackpot_balance,
+        new_reserve_balance: lottery_state.reserve_balance,
+        new_draw_id: lottery_state.current_draw_id,
+    });
+    if let Some(hash) = lottery_state
+        .pending_upgrade_hash
+        .filter(|_| lottery_state.is_upgrade_pending(clock.slot))
+    {
+        emit!(UpgradePendingWarning {
+            hash,
+            earliest_slot: lottery_state.upgrade_earliest_slot(),
+        });
+        msg!(
+            "⚠️  Program upgrade pending (earliest slot {})",
+            lottery_state.upgrade_earliest_slot()
+        );
+    }
 
     msg!("Draw finalized successfully!");
     msg!("  Draw ID: {}", draw_result.draw_id);
@@ -1046,7 +1372,7 @@ pub fn handler(ctx: Context<FinalizeDraw>, params: FinalizeDrawParams) -> Result
         msg!("  🛡️ INSURANCE USED: {} USDC lamports", insurance_used);
         msg!(
             "  Insurance remaining: {} USDC lamports",
-            lottery_state.insurance_balance
+            lottery_state.insurance_tier1_balance
         );
     }
     msg!(
@@ -1098,7 +1424,7 @@ pub fn handler(ctx: Context<FinalizeDraw>, params: FinalizeDrawParams) -> Result
     let accounting_sum = lottery_state
         .jackpot_balance
         .saturating_add(lottery_state.reserve_balance)
-        .saturating_add(lottery_state.insurance_balance);
+        .saturating_add(lottery_state.insurance_tier1_balance);
 
     // The accounting sum should never be zero unless the lottery is paused for funding
     if accounting_sum == 0 && !lottery_state.is_paused {
@@ -1115,6 +1441,37 @@ pub fn handler(ctx: Context<FinalizeDraw>, params: FinalizeDrawParams) -> Result
 
     msg!("✅ Post-condition assertions passed.");
 
+    // Record the finalized draw for UIs
+    ctx.accounts.draw_history.push(DrawHistorySummary {
+        draw_id: draw_result.draw_id,
+        winning_numbers: draw_result.winning_numbers,
+        total_tickets: draw_result.total_tickets,
+        was_rolldown,
+        total_prizes: prize_calc.total_distributed,
+        jackpot_after: lottery_state.jackpot_balance,
+        timestamp: draw_result.timestamp,
+    });
+    ctx.accounts
+        .number_frequency
+        .record_draw(&draw_result.winning_numbers)?;
+    ctx.accounts.reserve_history.push(ReserveSnapshot {
+        draw_id: draw_result.draw_id,
+        reserve_balance: lottery_state.reserve_balance,
+        prizes_distributed: prize_calc.total_distributed,
+        tickets_sold: draw_result.total_tickets,
+    });
+    ctx.accounts
+        .number_bias_detector
+        .record_draw(&draw_result.winning_numbers)?;
+    run_bias_test_if_due(&mut ctx.accounts.number_bias_detector, draw_result.draw_id);
+    ctx.accounts.lottery_analytics.record_finalized_draw(
+        was_rolldown,
+        params.winner_counts.match_6 > 0,
+        lottery_state.total_prizes_paid,
+    );
+
+    emit_state_diff(&state_before, lottery_state);
+
     Ok(())
 }
 
@@ -1122,6 +1479,14 @@ pub fn handler(ctx: Context<FinalizeDraw>, params: FinalizeDrawParams) -> Result
 mod tests {
     use super::*;
 
+    const DEFAULT_SPLIT: [u16; 3] = [
+        ROLLDOWN_MATCH_5_BPS,
+        ROLLDOWN_MATCH_4_BPS,
+        ROLLDOWN_MATCH_3_BPS,
+    ];
+
+    const NO_CAPS: [u64; FIXED_PAYOUT_CAP_TIER_COUNT] = [0; FIXED_PAYOUT_CAP_TIER_COUNT];
+
     #[test]
     fn test_calculate_fixed_prizes() {
         let winner_counts = WinnerCounts {
@@ -1135,7 +1500,8 @@ mod tests {
         let jackpot = 1_000_000_000_000u64; // $1M
         let available_prize_pool = 2_000_000_000_000u64; // $2M (plenty of funds)
 
-        let result = calculate_fixed_prizes(&winner_counts, jackpot, available_prize_pool);
+        let result =
+            calculate_fixed_prizes(&winner_counts, jackpot, available_prize_pool, &NO_CAPS);
 
         assert_eq!(result.match_6_prize, 0); // No Match 6 winners
         assert_eq!(result.match_5_prize, MATCH_5_PRIZE);
@@ -1164,7 +1530,8 @@ mod tests {
                                             // Required: 100*$4000 + 1000*$150 + 10000*$5 = $400k + $150k + $50k = $600k
         let available_prize_pool = 300_000_000_000u64; // Only $300k available (50% of needed)
 
-        let result = calculate_fixed_prizes(&winner_counts, jackpot, available_prize_pool);
+        let result =
+            calculate_fixed_prizes(&winner_counts, jackpot, available_prize_pool, &NO_CAPS);
 
         assert!(result.was_scaled_down); // Should be scaled
         assert!(result.scale_factor_bps < 10000); // Scale factor < 100%
@@ -1175,6 +1542,59 @@ mod tests {
         assert!(result.match_3_prize < MATCH_3_PRIZE);
     }
 
+    #[test]
+    fn test_calculate_fixed_prizes_with_payout_caps() {
+        let winner_counts = WinnerCounts {
+            match_6: 0,
+            match_5: 10,
+            match_4: 10,
+            match_3: 0,
+            match_2: 500,
+        };
+        let jackpot = 1_000_000_000_000u64;
+        let available_prize_pool = 2_000_000_000_000u64;
+
+        // Cap Match 5 at half its total; leave the others uncapped
+        let match_5_cap = MATCH_5_PRIZE * 5;
+        let caps = [0, 0, 0, match_5_cap];
+        let result = calculate_fixed_prizes(&winner_counts, jackpot, available_prize_pool, &caps);
+
+        assert_eq!(result.match_5_prize, MATCH_5_PRIZE / 2);
+        assert_eq!(result.match_4_prize, MATCH_4_PRIZE);
+        assert_eq!(result.match_2_prize, MATCH_2_VALUE);
+        assert_eq!(
+            result.capped_tiers,
+            vec![TierPayoutCap {
+                tier: 5,
+                original_total: MATCH_5_PRIZE * 10,
+                capped_total: match_5_cap,
+                per_winner_prize: MATCH_5_PRIZE / 2,
+            }]
+        );
+        assert_eq!(result.total_distributed, match_5_cap + MATCH_4_PRIZE * 10);
+    }
+
+    #[test]
+    fn test_apply_fixed_payout_cap() {
+        // Zero cap, no winners, or a total within the cap leave the prize alone
+        assert_eq!(
+            apply_fixed_payout_cap(4, MATCH_4_PRIZE, 10, 0),
+            (MATCH_4_PRIZE, None)
+        );
+        assert_eq!(
+            apply_fixed_payout_cap(4, MATCH_4_PRIZE, 0, 1),
+            (MATCH_4_PRIZE, None)
+        );
+        assert_eq!(
+            apply_fixed_payout_cap(4, MATCH_4_PRIZE, 10, MATCH_4_PRIZE * 10),
+            (MATCH_4_PRIZE, None)
+        );
+
+        let (prize, cap) = apply_fixed_payout_cap(3, MATCH_3_PRIZE, 3, 10_000_000);
+        assert_eq!(prize, 3_333_333);
+        assert_eq!(cap.unwrap().original_total, MATCH_3_PRIZE * 3);
+    }
+
     #[test]
     fn test_calculate_rolldown_prizes() {
         let winner_counts = WinnerCounts {
@@ -1187,7 +1607,7 @@ mod tests {
 
         let jackpot = 1_750_000_000_000u64; // $1.75M (soft cap)
 
-        let result = calculate_rolldown_prizes(&winner_counts, jackpot);
+        let result = calculate_rolldown_prizes(&winner_counts, jackpot, DEFAULT_SPLIT);
 
         // Match 6 prize should be 0 in rolldown
         assert_eq!(result.match_6_prize, 0);
@@ -1216,7 +1636,7 @@ mod tests {
 
         let jackpot = 2_000_000_000_000u64;
 
-        let result = calculate_rolldown_prizes(&winner_counts, jackpot);
+        let result = calculate_rolldown_prizes(&winner_counts, jackpot, DEFAULT_SPLIT);
 
         // Match 5 prize should be 0 when no winners
         assert_eq!(result.match_6_prize, 0);
@@ -1255,7 +1675,7 @@ mod tests {
 
         let jackpot = 2_000_000_000_000u64;
 
-        let result = calculate_rolldown_prizes(&winner_counts, jackpot);
+        let result = calculate_rolldown_prizes(&winner_counts, jackpot, DEFAULT_SPLIT);
 
         // All prize pools should be 0
         assert_eq!(result.match_6_prize, 0);
@@ -1285,7 +1705,7 @@ mod tests {
 
         let jackpot = 1_800_000_000_000u64; // $1.8M
 
-        let result = calculate_rolldown_prizes(&winner_counts, jackpot);
+        let result = calculate_rolldown_prizes(&winner_counts, jackpot, DEFAULT_SPLIT);
 
         // Match 3 should get the entire jackpot (all redistributed to it)
         assert_eq!(result.match_5_prize, 0);
@@ -1296,6 +1716,26 @@ mod tests {
         assert_eq!(result.match_3_prize, expected_match_3_prize);
     }
 
+    #[test]
+    fn test_rolldown_uses_configured_split() {
+        let winner_counts = WinnerCounts {
+            match_6: 0,
+            match_5: 1,
+            match_4: 10,
+            match_3: 100,
+            match_2: 0,
+        };
+
+        let jackpot = 2_000_000_000_000u64; // $2M
+
+        // 50% / 30% / 20% instead of the default 25% / 35% / 40%
+        let result = calculate_rolldown_prizes(&winner_counts, jackpot, [5000, 3000, 2000]);
+
+        assert_eq!(result.match_5_prize, jackpot / 2);
+        assert_eq!(result.match_4_prize, jackpot * 3 / 10 / 10);
+        assert_eq!(result.match_3_prize, jackpot / 5 / 100);
+    }
+
     // =========================================================================
     // FIXED PRIZE BALANCE SOLVENCY TESTS (Audit Issue #4)
     // =========================================================================
@@ -1328,7 +1768,7 @@ mod tests {
         // total_available_old = primary_funds + insurance = $500,006
         let old_primary = jackpot.saturating_add(reserve);
         let old_total = old_primary.saturating_add(insurance);
-        let old_result = calculate_fixed_prizes(&winner_counts, jackpot, old_total);
+        let old_result = calculate_fixed_prizes(&winner_counts, jackpot, old_total, &NO_CAPS);
         // Old approach has plenty of funds from jackpot, so no scaling either
         // (because funds_for_fixed = available_prize_pool when match_6 == 0)
         assert!(!old_result.was_scaled_down);
@@ -1340,7 +1780,7 @@ mod tests {
             .saturating_add(reserve)
             .saturating_add(fixed_prize_bal);
         let new_total = new_primary.saturating_add(insurance);
-        let new_result = calculate_fixed_prizes(&winner_counts, jackpot, new_total);
+        let new_result = calculate_fixed_prizes(&winner_counts, jackpot, new_total, &NO_CAPS);
         assert!(!new_result.was_scaled_down);
 
         // Both should pay full fixed prizes
@@ -1380,7 +1820,7 @@ mod tests {
         // available = jackpot + reserve + insurance = $500k + $5k + $2k = $507k
         // funds_for_fixed = available - jackpot = $7k  (less than $12k needed → SCALED)
         let old_available = jackpot.saturating_add(reserve).saturating_add(insurance);
-        let old_result = calculate_fixed_prizes(&winner_counts, jackpot, old_available);
+        let old_result = calculate_fixed_prizes(&winner_counts, jackpot, old_available, &NO_CAPS);
         assert!(
             old_result.was_scaled_down,
             "Without fixed_prize_balance, prizes should be scaled down"
@@ -1394,7 +1834,7 @@ mod tests {
             .saturating_add(reserve)
             .saturating_add(fixed_prize_bal)
             .saturating_add(insurance);
-        let new_result = calculate_fixed_prizes(&winner_counts, jackpot, new_available);
+        let new_result = calculate_fixed_prizes(&winner_counts, jackpot, new_available, &NO_CAPS);
         assert!(
             !new_result.was_scaled_down,
             "With fixed_prize_balance included, prizes should NOT be scaled down"
@@ -1431,7 +1871,7 @@ mod tests {
             .saturating_add(reserve)
             .saturating_add(fixed_prize_bal)
             .saturating_add(insurance);
-        let result = calculate_fixed_prizes(&winner_counts, jackpot, total_available);
+        let result = calculate_fixed_prizes(&winner_counts, jackpot, total_available, &NO_CAPS);
 
         assert!(
             result.was_scaled_down,
@@ -1442,4 +1882,20 @@ mod tests {
         assert!(result.match_4_prize < MATCH_4_PRIZE);
         assert!(result.match_3_prize < MATCH_3_PRIZE);
     }
+
+    #[test]
+    fn test_rolldown_cooldown_window() {
+        let mut lottery_state = LotteryState {
+            current_draw_id: 10,
+            ..Default::default()
+        };
+        assert!(!lottery_state.is_rolldown_cooldown_active(10));
+
+        // Forced rolldown in draw 10 holds rolldowns off for draws 11-13
+        lottery_state.rolldown_cooldown_draws = 3;
+        assert_eq!(lottery_state.start_rolldown_cooldown(), 13);
+        assert!(lottery_state.is_rolldown_cooldown_active(11));
+        assert!(lottery_state.is_rolldown_cooldown_active(13));
+        assert!(!lottery_state.is_rolldown_cooldown_active(14));
+    }
 }
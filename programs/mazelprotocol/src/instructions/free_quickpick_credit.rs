@@ -0,0 +1,255 @@
+//! Free QuickPick Credit Instructions
+//!
+//! A Match 5 win in the main lottery also earns a free QuickPick Express
+//! ticket. Every prize claim path (single, bulk, batch chunk and multi-draw)
+//! creates a `FreeQuickPickCredit` for the QuickPick draw currently on sale,
+//! valid for FREE_QP_CREDIT_VALIDITY_SECONDS.
+//! QuickPick's `buy_ticket` spends it by CPI into
+//! `redeem_free_quickpick_credit`, signed by the Quick Pick state PDA, and
+//! skips the USDC payment for that ticket.
+//!
+//! - redeem_free_quickpick_credit: Mark a credit used (QuickPick CPI only)
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{FreeQPCreditIssued, FreeQPCreditRedeemed};
+use crate::state::FreeQuickPickCredit;
+
+/// QuickPick program ID
+pub fn quickpick_program_id() -> Result<Pubkey> {
+    QUICKPICK_PROGRAM_ID
+        .parse::<Pubkey>()
+        .map_err(|_| LottoError::InvalidFreeQuickPickCreditAccounts.into())
+}
+
+/// Address of QuickPick's state PDA
+pub fn quickpick_state_address() -> Result<Pubkey> {
+    Ok(Pubkey::find_program_address(&[QUICK_PICK_SEED], &quickpick_program_id()?).0)
+}
+
+/// Read `current_draw` from the raw Quick Pick state account data
+///
+/// `current_draw` is the first field after the Anchor discriminator.
+pub fn read_quickpick_current_draw(data: &[u8]) -> Option<u64> {
+    let bytes = data.get(8..16)?;
+    Some(u64::from_le_bytes(bytes.try_into().ok()?))
+}
+
+/// Create the free QuickPick credit earned by a Match 5 claim
+///
+/// `quickpick_state` must be QuickPick's state PDA and `credit_info` the
+/// uncreated credit PDA for its current draw. A player already holding a
+/// credit for that draw keeps it and gets no second one.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn issue_free_quickpick_credit<'info>(
+    program_id: &Pubkey,
+    player: Pubkey,
+    main_draw_id: u64,
+    quickpick_state: &AccountInfo<'info>,
+    credit_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    current_time: i64,
+) -> Result<()> {
+    require!(
+        quickpick_state.owner == &quickpick_program_id()?
+            && quickpick_state.key() == quickpick_state_address()?,
+        LottoError::InvalidFreeQuickPickCreditAccounts
+    );
+    let qp_draw_id = read_quickpick_current_draw(&quickpick_state.try_borrow_data()?)
+        .ok_or(LottoError::InvalidFreeQuickPickCreditAccounts)?;
+
+    let qp_draw_bytes = qp_draw_id.to_le_bytes();
+    let (expected_credit_key, credit_bump) = Pubkey::find_program_address(
+        &[FREE_QP_CREDIT_SEED, player.as_ref(), &qp_draw_bytes],
+        program_id,
+    );
+    require!(
+        credit_info.key() == expected_credit_key && credit_info.is_writable,
+        LottoError::InvalidFreeQuickPickCreditAccounts
+    );
+    if !credit_info.data_is_empty() {
+        msg!("Free QuickPick credit already held for draw {}", qp_draw_id);
+        return Ok(());
+    }
+
+    let credit_seeds: &[&[u8]] = &[
+        FREE_QP_CREDIT_SEED,
+        player.as_ref(),
+        &qp_draw_bytes,
+        &[credit_bump],
+    ];
+    let rent = Rent::get()?;
+    anchor_lang::system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::CreateAccount {
+                from: payer.clone(),
+                to: credit_info.clone(),
+            },
+            &[credit_seeds],
+        ),
+        rent.minimum_balance(FREE_QP_CREDIT_SIZE),
+        FREE_QP_CREDIT_SIZE as u64,
+        program_id,
+    )?;
+
+    let expires_at = current_time
+        .checked_add(FREE_QP_CREDIT_VALIDITY_SECONDS)
+        .ok_or(LottoError::Overflow)?;
+    let credit = FreeQuickPickCredit {
+        bump: credit_bump,
+        beneficiary: player,
+        draw_id: qp_draw_id,
+        expires_at,
+        is_used: false,
+    };
+    {
+        let mut data = credit_info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        credit.try_serialize(&mut writer)?;
+    }
+
+    emit!(FreeQPCreditIssued {
+        player,
+        main_draw_id,
+        qp_draw_id,
+        expires_at,
+    });
+
+    msg!("Free QuickPick ticket credited!");
+    msg!("  QuickPick draw: #{}", qp_draw_id);
+    msg!("  Expires at: {}", expires_at);
+
+    Ok(())
+}
+
+/// Issue the free QuickPick credit for a Match 5 win from a claim's
+/// optional accounts
+///
+/// Fails with `InvalidFreeQuickPickCreditAccounts` unless `quickpick_state`,
+/// `free_qp_credit` and `system_program` are all supplied. The credit is
+/// keyed by QuickPick draw, so several Match 5 wins claimed together earn
+/// a single credit. `payer` funds the credit's rent.
+pub(crate) fn issue_match_5_free_quickpick_credit<'info>(
+    player: Pubkey,
+    payer: &Signer<'info>,
+    main_draw_id: u64,
+    quickpick_state: Option<&UncheckedAccount<'info>>,
+    free_qp_credit: Option<&UncheckedAccount<'info>>,
+    system_program: Option<&Program<'info, System>>,
+    current_time: i64,
+) -> Result<()> {
+    let (Some(quickpick_state), Some(free_qp_credit), Some(system_program)) =
+        (quickpick_state, free_qp_credit, system_program)
+    else {
+        return Err(LottoError::InvalidFreeQuickPickCreditAccounts.into());
+    };
+    issue_free_quickpick_credit(
+        &crate::ID,
+        player,
+        main_draw_id,
+        &quickpick_state.to_account_info(),
+        &free_qp_credit.to_account_info(),
+        &payer.to_account_info(),
+        &system_program.to_account_info(),
+        current_time,
+    )
+}
+
+// ============================================================================
+// REDEEM FREE QUICKPICK CREDIT
+// ============================================================================
+
+/// Accounts required for redeeming a free QuickPick credit
+#[derive(Accounts)]
+#[instruction(qp_draw_id: u64)]
+pub struct RedeemFreeQuickPickCredit<'info> {
+    /// QuickPick's state PDA, signing for the QuickPick program
+    #[account(
+        constraint = quick_pick_state.key() == quickpick_state_address()? @ LottoError::InvalidFreeQuickPickCreditAccounts
+    )]
+    pub quick_pick_state: Signer<'info>,
+
+    /// The player buying the free ticket
+    pub player: Signer<'info>,
+
+    /// The player's credit for `qp_draw_id`
+    #[account(
+        mut,
+        seeds = [FREE_QP_CREDIT_SEED, player.key().as_ref(), &qp_draw_id.to_le_bytes()],
+        bump = free_qp_credit.bump,
+        constraint = free_qp_credit.beneficiary == player.key() @ LottoError::InvalidFreeQuickPickCreditAccounts,
+        constraint = !free_qp_credit.is_used @ LottoError::FreeQuickPickCreditUsed
+    )]
+    pub free_qp_credit: Account<'info, FreeQuickPickCredit>,
+}
+
+/// Mark the player's free QuickPick credit for `qp_draw_id` as used
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `qp_draw_id` - QuickPick draw the free ticket is bought for
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_redeem_free_quickpick_credit(
+    ctx: Context<RedeemFreeQuickPickCredit>,
+    qp_draw_id: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let credit = &mut ctx.accounts.free_qp_credit;
+    require!(
+        !credit.is_expired(clock.unix_timestamp),
+        LottoError::FreeQuickPickCreditExpired
+    );
+    credit.is_used = true;
+
+    let player = ctx.accounts.player.key();
+    emit!(FreeQPCreditRedeemed { player, qp_draw_id });
+
+    msg!("Free QuickPick credit redeemed");
+    msg!("  Player: {}", player);
+    msg!("  QuickPick draw: #{}", qp_draw_id);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_quickpick_current_draw() {
+        let mut data = vec![0u8; 24];
+        data[8..16].copy_from_slice(&42u64.to_le_bytes());
+        assert_eq!(read_quickpick_current_draw(&data), Some(42));
+        assert_eq!(read_quickpick_current_draw(&data[..12]), None);
+    }
+
+    #[test]
+    fn test_free_quickpick_credit_expiry() {
+        let credit = FreeQuickPickCredit {
+            expires_at: 1_000 + FREE_QP_CREDIT_VALIDITY_SECONDS,
+            ..Default::default()
+        };
+        assert!(!credit.is_expired(1_000));
+        assert!(credit.is_expired(1_000 + FREE_QP_CREDIT_VALIDITY_SECONDS));
+        assert!(quickpick_program_id().is_ok());
+    }
+
+    #[test]
+    fn test_match_5_credit_requires_quickpick_accounts() {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = Vec::new();
+        let info = AccountInfo::new(&key, true, true, &mut lamports, &mut data, &key, false, 0);
+        let payer = Signer::try_from(&info).unwrap();
+
+        let err =
+            issue_match_5_free_quickpick_credit(key, &payer, 1, None, None, None, 0).unwrap_err();
+        assert_eq!(err, LottoError::InvalidFreeQuickPickCreditAccounts.into());
+    }
+}
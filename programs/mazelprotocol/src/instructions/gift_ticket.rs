@@ -0,0 +1,335 @@
+//! Gift Ticket Instructions
+//!
+//! These instructions transfer ownership of an unclaimed ticket to another wallet,
+//! enabling promotional giveaways and gifting between players.
+//!
+//! Rules:
+//! - Only the current owner can gift a ticket
+//! - Claimed tickets cannot be gifted
+//! - Tickets for a finalized draw cannot be gifted (prizes are already known);
+//!   the draw's DrawResult PDA is passed to check this
+//! - `original_owner` is never modified, preserving the purchase audit trail
+//!
+//! The recipient's UserStats account is created if needed so the recipient
+//! can claim any prize won by the gifted ticket.
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::TicketGifted;
+use crate::state::{DrawResult, SchemaVersioned, TicketData, UnifiedTicket, UserStats};
+
+/// Accounts required for gifting a single ticket
+#[derive(Accounts)]
+#[instruction(recipient: Pubkey)]
+pub struct GiftTicket<'info> {
+    /// The current ticket owner
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The ticket being gifted
+    #[account(
+        mut,
+        constraint = ticket.owner == signer.key() @ LottoError::NotTicketOwner,
+        constraint = !ticket.is_claimed @ LottoError::AlreadyClaimed,
+        constraint = ticket.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub ticket: Account<'info, TicketData>,
+
+    /// DrawResult PDA of the ticket's draw
+    /// CHECK: Address checked by seeds. The account does not exist until
+    /// `execute_draw` runs; otherwise it is loaded by `is_draw_finalized`.
+    #[account(
+        seeds = [DRAW_SEED, &ticket.draw_id.to_le_bytes()],
+        bump
+    )]
+    pub draw_result: UncheckedAccount<'info>,
+
+    /// Recipient's user statistics account (created if needed so they can claim)
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = USER_STATS_SIZE,
+        seeds = [USER_SEED, recipient.as_ref()],
+        bump
+    )]
+    pub recipient_stats: Account<'info, UserStats>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts required for gifting a unified (bulk) ticket
+#[derive(Accounts)]
+#[instruction(recipient: Pubkey)]
+pub struct GiftTicketBatch<'info> {
+    /// The current unified ticket owner
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The unified ticket being gifted
+    #[account(
+        mut,
+        constraint = unified_ticket.owner == signer.key() @ LottoError::NotTicketOwner
+    )]
+    pub unified_ticket: Account<'info, UnifiedTicket>,
+
+    /// DrawResult PDA of the ticket's draw
+    /// CHECK: Address checked by seeds. The account does not exist until
+    /// `execute_draw` runs; otherwise it is loaded by `is_draw_finalized`.
+    #[account(
+        seeds = [DRAW_SEED, &unified_ticket.draw_id.to_le_bytes()],
+        bump
+    )]
+    pub draw_result: UncheckedAccount<'info>,
+
+    /// Recipient's user statistics account (created if needed so they can claim)
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = USER_STATS_SIZE,
+        seeds = [USER_SEED, recipient.as_ref()],
+        bump
+    )]
+    pub recipient_stats: Account<'info, UserStats>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Check whether a ticket's draw has already been finalized
+///
+/// `draw_result` is the draw's DrawResult PDA. Until `execute_draw` creates
+/// it the draw cannot be finalized. Draws abandoned by `cancel_draw` never
+/// get a DrawResult, so their tickets stay giftable and are refunded to
+/// their current owner.
+pub fn is_draw_finalized(draw_result: &AccountInfo, program_id: &Pubkey) -> Result<bool> {
+    if draw_result.data_is_empty() {
+        return Ok(false);
+    }
+    require!(
+        draw_result.owner == program_id,
+        LottoError::InvalidDrawResultAccount
+    );
+    let data = draw_result.try_borrow_data()?;
+    let draw_result = DrawResult::try_deserialize(&mut &data[..])
+        .map_err(|_| LottoError::InvalidDrawResultAccount)?;
+    Ok(draw_result.is_finalized())
+}
+
+/// Validate a gift recipient
+fn validate_recipient(recipient: &Pubkey, current_owner: &Pubkey) -> Result<()> {
+    require!(
+        *recipient != Pubkey::default(),
+        LottoError::InvalidGiftRecipient
+    );
+    require!(recipient != current_owner, LottoError::InvalidGiftRecipient);
+    Ok(())
+}
+
+/// Initialize a freshly created recipient UserStats account
+///
+/// Existing accounts must already be at CURRENT_SCHEMA_VERSION.
+fn init_recipient_stats(
+    recipient_stats: &mut UserStats,
+    recipient: Pubkey,
+    bump: u8,
+    current_timestamp: i64,
+) -> Result<()> {
+    if recipient_stats.wallet == Pubkey::default() {
+        recipient_stats.schema_version = CURRENT_SCHEMA_VERSION;
+        recipient_stats.wallet = recipient;
+        recipient_stats.bump = bump;
+        recipient_stats.tickets_this_draw = 0;
+        recipient_stats.last_draw_participated = 0;
+        recipient_stats.daily_ticket_limit = DEFAULT_DAILY_TICKET_LIMIT;
+        recipient_stats.join_timestamp = current_timestamp;
+    }
+    recipient_stats.require_current_schema()
+}
+
+/// Gift a single ticket to another wallet
+///
+/// This instruction:
+/// 1. Verifies the signer owns the ticket and it is unclaimed
+/// 2. Verifies the ticket's draw has not been finalized
+/// 3. Transfers ownership to the recipient
+/// 4. Creates the recipient's UserStats account if needed
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+/// * `recipient` - Wallet receiving the ticket
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_gift_ticket(ctx: Context<GiftTicket>, recipient: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+    let from = ctx.accounts.signer.key();
+
+    validate_recipient(&recipient, &from)?;
+    require!(
+        !is_draw_finalized(&ctx.accounts.draw_result, ctx.program_id)?,
+        LottoError::DrawAlreadyFinalized
+    );
+
+    let ticket = &mut ctx.accounts.ticket;
+
+    ticket.owner = recipient;
+    ticket.claim_agent = None;
+    let draw_id = ticket.draw_id;
+
+    init_recipient_stats(
+        &mut ctx.accounts.recipient_stats,
+        recipient,
+        ctx.bumps.recipient_stats,
+        clock.unix_timestamp,
+    )?;
+
+    emit!(TicketGifted {
+        from,
+        to: recipient,
+        ticket_id: ctx.accounts.ticket.key(),
+        draw_id,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Ticket gifted successfully!");
+    msg!("  Ticket: {}", ctx.accounts.ticket.key());
+    msg!("  From: {}", from);
+    msg!("  To: {}", recipient);
+    msg!("  Original owner: {}", ctx.accounts.ticket.original_owner);
+    msg!("  Draw ID: {}", draw_id);
+
+    Ok(())
+}
+
+/// Gift a unified (bulk) ticket to another wallet
+///
+/// All tickets in the unified account move together. The batch must have
+/// no claimed tickets, and its draw must not have been finalized.
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+/// * `recipient` - Wallet receiving the tickets
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_gift_ticket_batch(ctx: Context<GiftTicketBatch>, recipient: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+    let from = ctx.accounts.signer.key();
+
+    validate_recipient(&recipient, &from)?;
+    require!(
+        !is_draw_finalized(&ctx.accounts.draw_result, ctx.program_id)?,
+        LottoError::DrawAlreadyFinalized
+    );
+
+    let unified_ticket = &mut ctx.accounts.unified_ticket;
+    require!(
+        unified_ticket.claimed_bitmap.iter().all(|&byte| byte == 0),
+        LottoError::AlreadyClaimed
+    );
+
+    unified_ticket.owner = recipient;
+    let draw_id = unified_ticket.draw_id;
+    let ticket_count = unified_ticket.ticket_count;
+
+    init_recipient_stats(
+        &mut ctx.accounts.recipient_stats,
+        recipient,
+        ctx.bumps.recipient_stats,
+        clock.unix_timestamp,
+    )?;
+
+    emit!(TicketGifted {
+        from,
+        to: recipient,
+        ticket_id: ctx.accounts.unified_ticket.key(),
+        draw_id,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Ticket batch gifted successfully!");
+    msg!("  Unified ticket: {}", ctx.accounts.unified_ticket.key());
+    msg!("  Tickets: {}", ticket_count);
+    msg!("  From: {}", from);
+    msg!("  To: {}", recipient);
+    msg!("  Draw ID: {}", draw_id);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_draw_finalized() {
+        let program_id = crate::ID;
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = Vec::new();
+        let info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+        // Not executed yet
+        assert!(!is_draw_finalized(&info, &program_id).unwrap());
+
+        for (is_explicitly_finalized, expected) in [(false, false), (true, true)] {
+            let mut data = Vec::new();
+            DrawResult {
+                draw_id: 5,
+                is_explicitly_finalized,
+                ..Default::default()
+            }
+            .try_serialize(&mut data)
+            .unwrap();
+            data.resize(DRAW_RESULT_SIZE, 0);
+            let mut lamports = 0;
+            let info = AccountInfo::new(
+                &key,
+                false,
+                false,
+                &mut lamports,
+                &mut data,
+                &program_id,
+                false,
+                0,
+            );
+            assert_eq!(is_draw_finalized(&info, &program_id).unwrap(), expected);
+
+            // A DrawResult owned by another program is rejected
+            let other_program = Pubkey::new_unique();
+            let mut lamports = 0;
+            let mut data = info.try_borrow_data().unwrap().to_vec();
+            let foreign = AccountInfo::new(
+                &key,
+                false,
+                false,
+                &mut lamports,
+                &mut data,
+                &other_program,
+                false,
+                0,
+            );
+            assert!(is_draw_finalized(&foreign, &program_id).is_err());
+        }
+    }
+
+    #[test]
+    fn test_validate_recipient() {
+        let owner = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        assert!(validate_recipient(&recipient, &owner).is_ok());
+        assert!(validate_recipient(&owner, &owner).is_err());
+        assert!(validate_recipient(&Pubkey::default(), &owner).is_err());
+    }
+}
@@ -0,0 +1,476 @@
+//! House Fee Recipient Instructions
+//!
+//! The house fee can be split across up to MAX_HOUSE_FEE_RECIPIENTS
+//! recipients. Each recipient index has its own USDC sub-account, and every
+//! purchase routes a proportional slice of its house fee to each sub-account.
+//!
+//! - initialize_house_fee_sub_account: Create the USDC sub-account for an index
+//! - set_house_fee_recipients: Propose a split, then apply it after the timelock
+//!
+//! While no split is active, house fees go to the original house fee account.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{
+    ConfigUpdated, HouseFeeRecipientsUpdated, HouseFeeRouted, InsuranceAutoReplenished,
+};
+use crate::state::{FeeRecipient, HouseFeeRecipients, LotteryState, SchemaVersioned};
+
+// ============================================================================
+// INITIALIZE HOUSE FEE SUB-ACCOUNT
+// ============================================================================
+
+/// Accounts required for creating a recipient's house fee sub-account
+#[derive(Accounts)]
+#[instruction(recipient_index: u8)]
+pub struct InitializeHouseFeeSubAccount<'info> {
+    /// Lottery authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// House fee recipients config (created on first use)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = HOUSE_FEE_RECIPIENTS_SIZE,
+        seeds = [FEE_RECIPIENTS_SEED],
+        bump
+    )]
+    pub fee_recipients: Account<'info, HouseFeeRecipients>,
+
+    /// The recipient's USDC sub-account to be created
+    #[account(
+        init,
+        payer = authority,
+        seeds = [HOUSE_FEE_USDC_SEED, &[recipient_index]],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = lottery_state
+    )]
+    pub sub_account: Account<'info, TokenAccount>,
+
+    /// USDC mint
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the USDC sub-account for a house fee recipient index
+///
+/// Sub-accounts must exist for every index before a split using them can
+/// be applied.
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+/// * `recipient_index` - Recipient index (0 to MAX_HOUSE_FEE_RECIPIENTS - 1)
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_initialize_house_fee_sub_account(
+    ctx: Context<InitializeHouseFeeSubAccount>,
+    recipient_index: u8,
+) -> Result<()> {
+    require!(
+        (recipient_index as usize) < MAX_HOUSE_FEE_RECIPIENTS,
+        LottoError::InvalidRecipientIndex
+    );
+
+    let fee_recipients = &mut ctx.accounts.fee_recipients;
+    fee_recipients.bump = ctx.bumps.fee_recipients;
+    fee_recipients.sub_account_bumps[recipient_index as usize] = ctx.bumps.sub_account;
+
+    msg!("House fee sub-account created!");
+    msg!("  Recipient index: {}", recipient_index);
+    msg!("  Sub-account: {}", ctx.accounts.sub_account.key());
+
+    Ok(())
+}
+
+// ============================================================================
+// SET HOUSE FEE RECIPIENTS
+// ============================================================================
+
+/// Accounts required for proposing or applying a house fee split
+#[derive(Accounts)]
+pub struct SetHouseFeeRecipients<'info> {
+    /// Lottery authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// House fee recipients config (created on first use)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = HOUSE_FEE_RECIPIENTS_SIZE,
+        seeds = [FEE_RECIPIENTS_SEED],
+        bump
+    )]
+    pub fee_recipients: Account<'info, HouseFeeRecipients>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Check a proposed house fee split
+///
+/// An empty list is valid and disables the split. Otherwise there may be at
+/// most MAX_HOUSE_FEE_RECIPIENTS entries, each with a non-zero share and a
+/// non-default wallet, and the shares must sum to exactly BPS_DENOMINATOR.
+pub fn is_valid_house_fee_split(recipients: &[FeeRecipient]) -> bool {
+    if recipients.is_empty() {
+        return true;
+    }
+    if recipients.len() > MAX_HOUSE_FEE_RECIPIENTS {
+        return false;
+    }
+    if recipients
+        .iter()
+        .any(|r| r.bps == 0 || r.recipient == Pubkey::default())
+    {
+        return false;
+    }
+    recipients.iter().map(|r| r.bps as u64).sum::<u64>() == BPS_DENOMINATOR
+}
+
+/// Propose a house fee split, or apply the pending one after the timelock
+///
+/// Calling with a list that differs from the pending proposal (re)starts the
+/// CONFIG_TIMELOCK_DELAY timelock. Calling again with the same list once the
+/// timelock has expired applies it. Every recipient index must already have
+/// a sub-account (see `initialize_house_fee_sub_account`).
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+/// * `recipients` - New split (empty to disable splitting)
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_set_house_fee_recipients(
+    ctx: Context<SetHouseFeeRecipients>,
+    recipients: Vec<FeeRecipient>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        is_valid_house_fee_split(&recipients),
+        LottoError::InvalidHouseFeeRecipients
+    );
+
+    let fee_recipients = &mut ctx.accounts.fee_recipients;
+    fee_recipients.bump = ctx.bumps.fee_recipients;
+
+    let is_pending =
+        fee_recipients.pending_effective_at != 0 && fee_recipients.pending_recipients == recipients;
+
+    if !is_pending {
+        // Phase 1: stage the split and start the timelock
+        let effective_at = clock
+            .unix_timestamp
+            .checked_add(CONFIG_TIMELOCK_DELAY)
+            .ok_or(LottoError::Overflow)?;
+        fee_recipients.pending_recipients = recipients;
+        fee_recipients.pending_effective_at = effective_at;
+
+        emit!(ConfigUpdated {
+            parameter: "house_fee_recipients_proposed".to_string(),
+            old_value: 0,
+            new_value: effective_at as u64,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("⏳ House fee split PROPOSED (timelock started)");
+        msg!("  Executable after: {} (unix timestamp)", effective_at);
+        msg!("  Call set_house_fee_recipients with the same list after the timelock expires.");
+        return Ok(());
+    }
+
+    // Phase 2: apply the pending split
+    require!(
+        clock.unix_timestamp >= fee_recipients.pending_effective_at,
+        LottoError::InvalidTimestamp
+    );
+    require!(
+        fee_recipients
+            .sub_account_bumps
+            .iter()
+            .take(recipients.len())
+            .all(|&bump| bump != 0),
+        LottoError::InvalidHouseFeeAccounts
+    );
+
+    fee_recipients.recipients = recipients;
+    fee_recipients.pending_recipients = Vec::new();
+    fee_recipients.pending_effective_at = 0;
+
+    let split_active = fee_recipients.is_split_active();
+    ctx.accounts.lottery_state.house_fee_split_active = split_active;
+
+    emit!(HouseFeeRecipientsUpdated {
+        recipients: fee_recipients.recipients.clone(),
+    });
+
+    msg!("✅ House fee split APPLIED");
+    for (index, recipient) in fee_recipients.recipients.iter().enumerate() {
+        msg!(
+            "  Recipient {}: {} ({} bps)",
+            index,
+            recipient.recipient,
+            recipient.bps
+        );
+    }
+    if !split_active {
+        msg!("  Split disabled: fees go to the house fee account");
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// HOUSE FEE ROUTING
+// ============================================================================
+
+/// Split a house fee into per-recipient slices
+///
+/// Slices are proportional to each recipient's share; the last recipient
+/// receives the rounding remainder so the slices always sum to `amount`.
+pub fn split_house_fee(amount: u64, recipients: &[FeeRecipient]) -> Vec<u64> {
+    let mut slices = Vec::with_capacity(recipients.len());
+    let mut remaining = amount;
+    for (index, recipient) in recipients.iter().enumerate() {
+        let slice = if index + 1 == recipients.len() {
+            remaining
+        } else {
+            (amount as u128 * recipient.bps as u128 / BPS_DENOMINATOR as u128) as u64
+        };
+        let slice = slice.min(remaining);
+        remaining -= slice;
+        slices.push(slice);
+    }
+    slices
+}
+
+/// Address of the USDC sub-account for a recipient index
+pub fn house_fee_sub_account_address(
+    recipient_index: u8,
+    bump: u8,
+    program_id: &Pubkey,
+) -> Result<Pubkey> {
+    Pubkey::create_program_address(
+        &[HOUSE_FEE_USDC_SEED, &[recipient_index], &[bump]],
+        program_id,
+    )
+    .map_err(|_| LottoError::InvalidHouseFeeAccounts.into())
+}
+
+/// Token account paying a house fee and the authority signing for it
+pub(crate) struct HouseFeeSource<'a, 'info> {
+    /// Token account the fee is paid from
+    pub from: AccountInfo<'info>,
+    /// Owner of `from` (player wallet or PDA)
+    pub authority: AccountInfo<'info>,
+    /// Token program
+    pub token_program: AccountInfo<'info>,
+    /// PDA signer seeds when `authority` is a PDA (empty for wallets)
+    pub signer_seeds: &'a [&'a [&'a [u8]]],
+}
+
+impl<'info> HouseFeeSource<'_, 'info> {
+    fn transfer(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: self.from.clone(),
+            to,
+            authority: self.authority.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.clone(),
+            cpi_accounts,
+            self.signer_seeds,
+        );
+        token::transfer(cpi_ctx, amount)
+    }
+}
+
+/// Where a house fee can be sent
+pub(crate) struct HouseFeeDestination<'a, 'info> {
+    /// Original house fee account (used while no split is active)
+    pub house_fee_usdc: AccountInfo<'info>,
+    /// Split config, required while `house_fee_split_active` is set
+    pub fee_recipients: Option<&'a HouseFeeRecipients>,
+    /// Recipient sub-accounts in recipient order (from remaining_accounts)
+    pub sub_accounts: &'a [AccountInfo<'info>],
+}
+
+/// Transfer a house fee, splitting it across recipients when a split is active
+///
+/// Emits `HouseFeeRouted` for each recipient slice.
+pub(crate) fn route_house_fee<'info>(
+    source: &HouseFeeSource<'_, 'info>,
+    destination: &HouseFeeDestination<'_, 'info>,
+    split_active: bool,
+    amount: u64,
+    draw_id: u64,
+    program_id: &Pubkey,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    if !split_active {
+        return source.transfer(destination.house_fee_usdc.clone(), amount);
+    }
+
+    let fee_recipients = destination
+        .fee_recipients
+        .filter(|config| config.is_split_active())
+        .ok_or(LottoError::InvalidHouseFeeAccounts)?;
+    let recipients = &fee_recipients.recipients;
+    require!(
+        destination.sub_accounts.len() >= recipients.len(),
+        LottoError::InvalidHouseFeeAccounts
+    );
+
+    let slices = split_house_fee(amount, recipients);
+    for (index, (recipient, slice)) in recipients.iter().zip(slices).enumerate() {
+        let sub_account = &destination.sub_accounts[index];
+        let expected = house_fee_sub_account_address(
+            index as u8,
+            fee_recipients.sub_account_bumps[index],
+            program_id,
+        )?;
+        require!(
+            sub_account.key() == expected && sub_account.is_writable,
+            LottoError::InvalidHouseFeeAccounts
+        );
+
+        if slice > 0 {
+            source.transfer(sub_account.clone(), slice)?;
+        }
+
+        emit!(HouseFeeRouted {
+            recipient: recipient.recipient,
+            amount: slice,
+            draw_id,
+        });
+    }
+
+    Ok(())
+}
+
+/// Divert part of a house fee to the insurance pool while it is below minimum
+///
+/// Credits `insurance_tier1_balance` and returns the amount the caller must send
+/// to `insurance_pool_usdc` instead of the house fee. Emits
+/// `InsuranceAutoReplenished` on the first reroute of each draw.
+pub(crate) fn replenish_insurance_from_house_fee(
+    lottery_state: &mut LotteryState,
+    house_fee: u64,
+) -> Result<u64> {
+    let amount = calculate_insurance_replenishment(
+        house_fee,
+        lottery_state.insurance_tier1_balance,
+        lottery_state.insurance_minimum,
+        lottery_state.insurance_replenishment_bps,
+    );
+    if amount == 0 {
+        return Ok(0);
+    }
+
+    lottery_state.insurance_tier1_balance = lottery_state
+        .insurance_tier1_balance
+        .checked_add(amount)
+        .ok_or(LottoError::Overflow)?;
+
+    let draw_id = lottery_state.current_draw_id;
+    if lottery_state.insurance_replenished_draw_id != draw_id {
+        lottery_state.insurance_replenished_draw_id = draw_id;
+        emit!(InsuranceAutoReplenished {
+            draw_id,
+            amount_rerouted: amount,
+            new_insurance_balance: lottery_state.insurance_tier1_balance,
+        });
+    }
+
+    Ok(amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recipient(bps: u16) -> FeeRecipient {
+        FeeRecipient {
+            recipient: Pubkey::new_unique(),
+            bps,
+        }
+    }
+
+    #[test]
+    fn test_is_valid_house_fee_split() {
+        assert!(is_valid_house_fee_split(&[]));
+        assert!(is_valid_house_fee_split(&[recipient(10000)]));
+        assert!(is_valid_house_fee_split(&[
+            recipient(5000),
+            recipient(3000),
+            recipient(1500),
+            recipient(500),
+        ]));
+
+        // Must sum to exactly 100%
+        assert!(!is_valid_house_fee_split(&[
+            recipient(5000),
+            recipient(4999)
+        ]));
+        // At most 4 recipients
+        assert!(!is_valid_house_fee_split(&[
+            recipient(2000),
+            recipient(2000),
+            recipient(2000),
+            recipient(2000),
+            recipient(2000),
+        ]));
+        // No zero shares or default wallets
+        assert!(!is_valid_house_fee_split(&[recipient(10000), recipient(0)]));
+        assert!(!is_valid_house_fee_split(&[FeeRecipient {
+            recipient: Pubkey::default(),
+            bps: 10000,
+        }]));
+    }
+
+    #[test]
+    fn test_split_house_fee_sums_to_amount() {
+        let recipients = [recipient(3333), recipient(3333), recipient(3334)];
+        let slices = split_house_fee(1_000_001, &recipients);
+        assert_eq!(slices, vec![333_300, 333_300, 333_401]);
+        assert_eq!(slices.iter().sum::<u64>(), 1_000_001);
+
+        assert_eq!(split_house_fee(0, &recipients), vec![0, 0, 0]);
+        assert_eq!(split_house_fee(7, &[recipient(10000)]), vec![7]);
+    }
+}
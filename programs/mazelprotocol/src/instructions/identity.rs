@@ -0,0 +1,87 @@
+//! Identity Proof Instructions
+//!
+//! Prizes above `lottery_state.identity_verification_threshold` can only be
+//! claimed through `claim_prize_with_identity`, which requires the winner's
+//! `IdentityProof`. Proofs are issued by `lottery_state.identity_oracle`
+//! after off-chain verification; both settings go through the config
+//! timelock.
+//!
+//! - issue_identity_proof: Issue or renew a player's identity proof
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::instructions::kyc::is_valid_jurisdiction;
+use crate::state::{IdentityProof, LotteryState, SchemaVersioned};
+
+/// Accounts required for issuing an identity proof
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct IssueIdentityProof<'info> {
+    /// The identity oracle
+    #[account(mut)]
+    pub identity_oracle: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.identity_oracle != Pubkey::default() @ LottoError::IdentityOracleNotSet,
+        constraint = lottery_state.identity_oracle == identity_oracle.key() @ LottoError::UnauthorizedIdentityOracle,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The player's identity proof (created on first issue)
+    #[account(
+        init_if_needed,
+        payer = identity_oracle,
+        space = IDENTITY_PROOF_SIZE,
+        seeds = [IDENTITY_SEED, player.as_ref()],
+        bump
+    )]
+    pub identity_proof: Account<'info, IdentityProof>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Issue `player` an identity proof valid until `expires_at`
+///
+/// Renews an existing proof.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `player` - Verified player
+/// * `jurisdiction` - ISO 3166-1 alpha-2 country code of the player
+/// * `expires_at` - When the proof lapses
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_issue_identity_proof(
+    ctx: Context<IssueIdentityProof>,
+    player: Pubkey,
+    jurisdiction: [u8; 2],
+    expires_at: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(
+        is_valid_jurisdiction(&jurisdiction) && expires_at > clock.unix_timestamp,
+        LottoError::InvalidIdentityProof
+    );
+
+    let identity_proof = &mut ctx.accounts.identity_proof;
+    identity_proof.bump = ctx.bumps.identity_proof;
+    identity_proof.player = player;
+    identity_proof.jurisdiction = jurisdiction;
+    identity_proof.issued_at = clock.unix_timestamp;
+    identity_proof.expires_at = expires_at;
+
+    msg!("Identity proof issued");
+    msg!("  Player: {}", player);
+    msg!("  Jurisdiction: {}", String::from_utf8_lossy(&jurisdiction));
+    msg!("  Expires at: {}", expires_at);
+
+    Ok(())
+}
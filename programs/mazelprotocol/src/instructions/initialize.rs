@@ -11,8 +11,10 @@ use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::constants::*;
 use crate::errors::LottoError;
-use crate::events::{JackpotSeeded, LotteryInitialized};
-use crate::state::LotteryState;
+use crate::events::{JackpotReseedFailed, JackpotReseeded, JackpotSeeded, LotteryInitialized};
+use crate::state::{
+    JackpotLeaderboard, LotteryAnalytics, LotteryState, NumberFrequencyAccount, SchemaVersioned,
+};
 
 /// Parameters for initializing the lottery
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -77,17 +79,58 @@ pub struct Initialize<'info> {
     )]
     pub house_fee_usdc: Account<'info, TokenAccount>,
 
-    /// Insurance pool USDC token account (PDA-controlled)
+    /// Insurance tier 1 (liquid) USDC token account (PDA-controlled)
     #[account(
         init,
         payer = authority,
-        seeds = [INSURANCE_POOL_USDC_SEED],
+        seeds = [INSURANCE_TIER1_SEED],
         bump,
         token::mint = usdc_mint,
         token::authority = lottery_state
     )]
     pub insurance_pool_usdc: Account<'info, TokenAccount>,
 
+    /// Insurance tier 2 (delayed) USDC token account (PDA-controlled)
+    #[account(
+        init,
+        payer = authority,
+        seeds = [INSURANCE_TIER2_SEED],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = lottery_state
+    )]
+    pub insurance_tier2_usdc: Box<Account<'info, TokenAccount>>,
+
+    /// All-time jackpot leaderboard (PDA, starts empty)
+    #[account(
+        init,
+        payer = authority,
+        space = JACKPOT_LEADERBOARD_SIZE,
+        seeds = [JACKPOT_LEADERBOARD_SEED],
+        bump
+    )]
+    pub jackpot_leaderboard: Box<Account<'info, JackpotLeaderboard>>,
+
+    /// Winning number frequency histogram (PDA, starts empty)
+    #[account(
+        init,
+        payer = authority,
+        space = NUMBER_FREQUENCY_SIZE,
+        seeds = [NUMBER_FREQUENCY_SEED],
+        bump
+    )]
+    pub number_frequency: Box<Account<'info, NumberFrequencyAccount>>,
+
+    /// Lifetime lottery analytics (PDA, starts at season 1)
+    #[account(
+        init,
+        payer = authority,
+        space = LOTTERY_ANALYTICS_SIZE,
+        seeds = [ANALYTICS_SEED],
+        bump
+    )]
+    pub lottery_analytics: Box<Account<'info, LotteryAnalytics>>,
+
     /// System program
     pub system_program: Program<'info, System>,
 
@@ -177,13 +220,14 @@ pub fn handler(ctx: Context<Initialize>, params: InitializeParams) -> Result<()>
     // Initialize lottery state
     // CRITICAL FIX: jackpot_balance starts at 0, not seed_amount
     // The fund_seed instruction must be called to deposit actual USDC
+    lottery_state.schema_version = CURRENT_SCHEMA_VERSION;
     lottery_state.authority = ctx.accounts.authority.key();
     lottery_state.switchboard_queue = params.switchboard_queue;
     lottery_state.current_randomness_account = Pubkey::default();
     lottery_state.current_draw_id = 1; // First draw
     lottery_state.jackpot_balance = 0; // FIXED: Start at 0, fund_seed will set this
     lottery_state.reserve_balance = 0;
-    lottery_state.insurance_balance = 0;
+    lottery_state.insurance_tier1_balance = 0;
     lottery_state.ticket_price = params.ticket_price;
     lottery_state.house_fee_bps = params.house_fee_bps;
     lottery_state.jackpot_cap = params.jackpot_cap;
@@ -201,6 +245,7 @@ pub fn handler(ctx: Context<Initialize>, params: InitializeParams) -> Result<()>
     lottery_state.fixed_prize_balance = 0;
     lottery_state.emergency_transfer_total = 0;
     lottery_state.emergency_transfer_window_start = 0;
+    lottery_state.init_v1_fields();
     lottery_state.is_draw_in_progress = false;
     lottery_state.is_rolldown_active = false;
     lottery_state.is_paused = true; // FIXED: Start paused until funded
@@ -208,6 +253,13 @@ pub fn handler(ctx: Context<Initialize>, params: InitializeParams) -> Result<()>
     lottery_state.pending_authority = None; // For two-step authority transfer
     lottery_state.bump = ctx.bumps.lottery_state;
 
+    ctx.accounts.jackpot_leaderboard.bump = ctx.bumps.jackpot_leaderboard;
+    ctx.accounts.number_frequency.bump = ctx.bumps.number_frequency;
+    let lottery_analytics = &mut ctx.accounts.lottery_analytics;
+    lottery_analytics.bump = ctx.bumps.lottery_analytics;
+    lottery_analytics.season_number = 1;
+    lottery_analytics.season_start = clock.unix_timestamp;
+
     // Emit initialization event
     emit!(LotteryInitialized {
         authority: ctx.accounts.authority.key(),
@@ -255,7 +307,8 @@ pub struct FundSeed<'info> {
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
         constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
-        constraint = !lottery_state.is_funded @ LottoError::AlreadyInitialized
+        constraint = !lottery_state.is_funded @ LottoError::AlreadyInitialized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
@@ -343,26 +396,26 @@ pub fn handler_fund_seed(ctx: Context<FundSeed>) -> Result<()> {
 /// Accounts required for adding reserve funds
 #[derive(Accounts)]
 pub struct AddReserveFunds<'info> {
-    /// The authority adding funds
+    /// The funder adding funds (anyone may top up the reserve)
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub funder: Signer<'info>,
 
     /// The main lottery state account
     #[account(
         mut,
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
-        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
-    /// Authority's USDC token account (source of funds)
+    /// Funder's USDC token account (source of funds)
     #[account(
         mut,
-        constraint = authority_usdc.owner == authority.key() @ LottoError::TokenAccountOwnerMismatch,
-        constraint = authority_usdc.mint == usdc_mint.key() @ LottoError::InvalidUsdcMint
+        constraint = funder_usdc.owner == funder.key() @ LottoError::TokenAccountOwnerMismatch,
+        constraint = funder_usdc.mint == usdc_mint.key() @ LottoError::InvalidUsdcMint
     )]
-    pub authority_usdc: Account<'info, TokenAccount>,
+    pub funder_usdc: Account<'info, TokenAccount>,
 
     /// Prize pool USDC token account
     #[account(
@@ -381,8 +434,10 @@ pub struct AddReserveFunds<'info> {
 
 /// Add funds to the reserve pool
 ///
-/// This instruction allows the authority to add additional funds to the reserve,
-/// which can be used to seed jackpots after wins or rollovers.
+/// This instruction allows anyone to add additional funds to the reserve,
+/// which can be used to seed jackpots after wins or rollovers. Community
+/// top-ups can lift the reserve back above `reserve_minimum` after an
+/// auto-pause so the authority can `unpause`.
 ///
 /// # Arguments
 /// * `ctx` - The context containing all required accounts
@@ -393,15 +448,15 @@ pub struct AddReserveFunds<'info> {
 pub fn handler_add_reserve_funds(ctx: Context<AddReserveFunds>, amount: u64) -> Result<()> {
     require!(amount > 0, LottoError::InvalidSeedAmount);
     require!(
-        ctx.accounts.authority_usdc.amount >= amount,
+        ctx.accounts.funder_usdc.amount >= amount,
         LottoError::InsufficientFunds
     );
 
-    // Transfer USDC from authority to prize pool
+    // Transfer USDC from funder to prize pool
     let cpi_accounts = Transfer {
-        from: ctx.accounts.authority_usdc.to_account_info(),
+        from: ctx.accounts.funder_usdc.to_account_info(),
         to: ctx.accounts.prize_pool_usdc.to_account_info(),
-        authority: ctx.accounts.authority.to_account_info(),
+        authority: ctx.accounts.funder.to_account_info(),
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
@@ -415,6 +470,7 @@ pub fn handler_add_reserve_funds(ctx: Context<AddReserveFunds>, amount: u64) ->
         .ok_or(LottoError::Overflow)?;
 
     msg!("Reserve funds added successfully!");
+    msg!("  Funder: {}", ctx.accounts.funder.key());
     msg!("  Amount added: {} USDC lamports", amount);
     msg!(
         "  New reserve balance: {} USDC lamports",
@@ -423,3 +479,87 @@ pub fn handler_add_reserve_funds(ctx: Context<AddReserveFunds>, amount: u64) ->
 
     Ok(())
 }
+
+// ============================================================================
+// RESEED JACKPOT INSTRUCTION
+// ============================================================================
+
+/// Accounts required for reseeding the jackpot from the reserve
+#[derive(Accounts)]
+pub struct ReseedJackpot<'info> {
+    /// Anyone may trigger a reseed
+    pub caller: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_funded @ LottoError::LotteryNotInitialized,
+        constraint = !lottery_state.is_draw_in_progress @ LottoError::DrawInProgress,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+/// Reseed the jackpot up to `seed_amount` from the reserve
+///
+/// `finalize_draw` only reseeds during finalization. After a reserve top-up
+/// this lets anyone restore the jackpot without waiting for a draw cycle.
+/// Works while paused. Reserve funds already sit in the prize pool token
+/// account, so this is an accounting move only.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_reseed_jackpot(ctx: Context<ReseedJackpot>) -> Result<()> {
+    let lottery_state = &mut ctx.accounts.lottery_state;
+
+    require!(
+        lottery_state.jackpot_balance < lottery_state.seed_amount,
+        LottoError::JackpotReseedNotNeeded
+    );
+
+    let deficit = lottery_state.seed_amount - lottery_state.jackpot_balance;
+    if lottery_state.reserve_balance < deficit {
+        emit!(JackpotReseedFailed {
+            required: deficit,
+            available: lottery_state.reserve_balance,
+        });
+        msg!("Jackpot reseed failed: reserve cannot cover the deficit");
+        msg!("  Required: {} USDC lamports", deficit);
+        msg!(
+            "  Available: {} USDC lamports",
+            lottery_state.reserve_balance
+        );
+        return Err(LottoError::InsufficientReserve.into());
+    }
+
+    lottery_state.reserve_balance -= deficit;
+    lottery_state.jackpot_balance = lottery_state.seed_amount;
+
+    // Jackpot level changed, so the dynamic fee tier may have too
+    lottery_state.house_fee_bps = lottery_state.get_current_house_fee_bps();
+
+    emit!(JackpotReseeded {
+        amount: deficit,
+        new_jackpot_balance: lottery_state.jackpot_balance,
+        new_reserve_balance: lottery_state.reserve_balance,
+        triggered_by: ctx.accounts.caller.key(),
+    });
+
+    msg!("Jackpot reseeded from reserve!");
+    msg!("  Amount: {} USDC lamports", deficit);
+    msg!(
+        "  New jackpot balance: {} USDC lamports",
+        lottery_state.jackpot_balance
+    );
+    msg!(
+        "  New reserve balance: {} USDC lamports",
+        lottery_state.reserve_balance
+    );
+
+    Ok(())
+}
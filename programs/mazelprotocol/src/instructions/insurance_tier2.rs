@@ -0,0 +1,237 @@
+//! Insurance Tier 2 Instructions
+//!
+//! Ticket insurance contributions are split between two pools: tier 1
+//! (INSURANCE_TIER1_ALLOCATION_BPS, liquid) backs `finalize_draw`,
+//! `check_solvency` and emergency transfers, while tier 2
+//! (INSURANCE_TIER2_ALLOCATION_BPS) is a delayed reserve. Tier 2 funds
+//! can only reach tier 1 through a withdrawal that waits out
+//! INSURANCE_TIER2_WITHDRAWAL_DELAY.
+//!
+//! - request_tier2_withdrawal: Start a tier 2 to tier 1 transfer (authority only)
+//! - execute_tier2_withdrawal: Complete it after the delay (authority only)
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{Tier2WithdrawalExecuted, Tier2WithdrawalRequested};
+use crate::state::{LotteryState, SchemaVersioned, Tier2WithdrawalRequest};
+
+/// Whether a tier 2 withdrawal may be executed at `current_timestamp`
+pub fn is_tier2_withdrawal_executable(executable_after: i64, current_timestamp: i64) -> bool {
+    current_timestamp >= executable_after
+}
+
+// ============================================================================
+// REQUEST TIER 2 WITHDRAWAL
+// ============================================================================
+
+/// Accounts required for requesting a tier 2 withdrawal
+#[derive(Accounts)]
+pub struct RequestTier2Withdrawal<'info> {
+    /// The lottery authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The pending withdrawal (one at a time)
+    #[account(
+        init,
+        payer = authority,
+        space = TIER2_WITHDRAWAL_REQUEST_SIZE,
+        seeds = [TIER2_WITHDRAWAL_SEED],
+        bump
+    )]
+    pub withdrawal_request: Account<'info, Tier2WithdrawalRequest>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Request moving `amount` from insurance tier 2 to tier 1
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `amount` - USDC lamports to move (at most the tier 2 balance)
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_request_tier2_withdrawal(
+    ctx: Context<RequestTier2Withdrawal>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, LottoError::InvalidAmount);
+    require!(
+        amount <= ctx.accounts.lottery_state.insurance_tier2_balance,
+        LottoError::InsufficientInsuranceTier2
+    );
+
+    let clock = Clock::get()?;
+    let executable_after = clock
+        .unix_timestamp
+        .checked_add(INSURANCE_TIER2_WITHDRAWAL_DELAY)
+        .ok_or(LottoError::Overflow)?;
+
+    let withdrawal_request = &mut ctx.accounts.withdrawal_request;
+    withdrawal_request.bump = ctx.bumps.withdrawal_request;
+    withdrawal_request.amount = amount;
+    withdrawal_request.requested_at = clock.unix_timestamp;
+    withdrawal_request.executable_after = executable_after;
+
+    emit!(Tier2WithdrawalRequested {
+        amount,
+        executable_after,
+    });
+
+    msg!("Insurance tier 2 withdrawal requested");
+    msg!("  Amount: {} USDC lamports", amount);
+    msg!("  Executable after: {}", executable_after);
+
+    Ok(())
+}
+
+// ============================================================================
+// EXECUTE TIER 2 WITHDRAWAL
+// ============================================================================
+
+/// Accounts required for executing a tier 2 withdrawal
+#[derive(Accounts)]
+pub struct ExecuteTier2Withdrawal<'info> {
+    /// The lottery authority (receives the request's rent)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The pending withdrawal (closed once executed)
+    #[account(
+        mut,
+        close = authority,
+        seeds = [TIER2_WITHDRAWAL_SEED],
+        bump = withdrawal_request.bump
+    )]
+    pub withdrawal_request: Account<'info, Tier2WithdrawalRequest>,
+
+    /// Insurance tier 1 USDC token account (destination)
+    #[account(
+        mut,
+        seeds = [INSURANCE_TIER1_SEED],
+        bump
+    )]
+    pub insurance_pool_usdc: Account<'info, TokenAccount>,
+
+    /// Insurance tier 2 USDC token account (source)
+    #[account(
+        mut,
+        seeds = [INSURANCE_TIER2_SEED],
+        bump
+    )]
+    pub insurance_tier2_usdc: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Move the requested amount from insurance tier 2 to tier 1
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_execute_tier2_withdrawal(ctx: Context<ExecuteTier2Withdrawal>) -> Result<()> {
+    let clock = Clock::get()?;
+    let amount = ctx.accounts.withdrawal_request.amount;
+    require!(
+        is_tier2_withdrawal_executable(
+            ctx.accounts.withdrawal_request.executable_after,
+            clock.unix_timestamp
+        ),
+        LottoError::Tier2WithdrawalTimelockActive
+    );
+    require!(
+        amount <= ctx.accounts.lottery_state.insurance_tier2_balance
+            && amount <= ctx.accounts.insurance_tier2_usdc.amount,
+        LottoError::InsufficientInsuranceTier2
+    );
+
+    let seeds = &[LOTTERY_SEED, &[ctx.accounts.lottery_state.bump]];
+    let signer_seeds = &[&seeds[..]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.insurance_tier2_usdc.to_account_info(),
+        to: ctx.accounts.insurance_pool_usdc.to_account_info(),
+        authority: ctx.accounts.lottery_state.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    token::transfer(cpi_ctx, amount)?;
+
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    lottery_state.insurance_tier2_balance = lottery_state
+        .insurance_tier2_balance
+        .checked_sub(amount)
+        .ok_or(LottoError::InsufficientInsuranceTier2)?;
+    lottery_state.insurance_tier1_balance = lottery_state
+        .insurance_tier1_balance
+        .checked_add(amount)
+        .ok_or(LottoError::Overflow)?;
+
+    emit!(Tier2WithdrawalExecuted {
+        amount,
+        new_tier1_balance: lottery_state.insurance_tier1_balance,
+        new_tier2_balance: lottery_state.insurance_tier2_balance,
+    });
+
+    msg!("Insurance tier 2 withdrawal executed");
+    msg!("  Amount: {} USDC lamports", amount);
+    msg!(
+        "  Tier 1 balance: {} USDC lamports",
+        lottery_state.insurance_tier1_balance
+    );
+    msg!(
+        "  Tier 2 balance: {} USDC lamports",
+        lottery_state.insurance_tier2_balance
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tier2_withdrawal_delay() {
+        let requested_at = 1_000;
+        let executable_after = requested_at + INSURANCE_TIER2_WITHDRAWAL_DELAY;
+        assert!(!is_tier2_withdrawal_executable(
+            executable_after,
+            requested_at
+        ));
+        assert!(!is_tier2_withdrawal_executable(
+            executable_after,
+            executable_after - 1
+        ));
+        assert!(is_tier2_withdrawal_executable(
+            executable_after,
+            executable_after
+        ));
+    }
+}
@@ -0,0 +1,268 @@
+//! Jackpot Guarantee Instructions
+//!
+//! A launch can promise a minimum jackpot, `lottery_state.jackpot_guarantee_amount`
+//! (set through the config timelock). The guarantee is backed by a dedicated
+//! reserve, `lottery_state.guarantee_reserve_balance`, whose USDC sits in the
+//! prize pool next to the regular reserve. After reseeding, `finalize_draw`
+//! tops the jackpot up to the guarantee from that reserve, or pauses the
+//! lottery when the reserve cannot cover the shortfall.
+//!
+//! - fund_jackpot_guarantee: Deposit USDC into the guarantee reserve (anyone)
+//! - withdraw_guarantee_reserve: Withdraw from the guarantee reserve (authority
+//!   only, while paused)
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::state::{LotteryState, SchemaVersioned};
+
+/// Result of applying the jackpot guarantee after reseeding
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JackpotGuaranteeOutcome {
+    /// No guarantee set, or the jackpot already meets it
+    NotNeeded,
+    /// The jackpot was topped up from the guarantee reserve
+    Triggered { shortfall_covered: u64 },
+    /// The guarantee reserve cannot cover the shortfall
+    Failed { required: u64, available: u64 },
+}
+
+/// Top the jackpot up to `jackpot_guarantee_amount` from the guarantee reserve
+///
+/// Nothing is moved when the reserve cannot cover the whole shortfall.
+pub fn apply_jackpot_guarantee(lottery_state: &mut LotteryState) -> JackpotGuaranteeOutcome {
+    let shortfall = lottery_state
+        .jackpot_guarantee_amount
+        .saturating_sub(lottery_state.jackpot_balance);
+    if shortfall == 0 {
+        return JackpotGuaranteeOutcome::NotNeeded;
+    }
+    if lottery_state.guarantee_reserve_balance < shortfall {
+        return JackpotGuaranteeOutcome::Failed {
+            required: shortfall,
+            available: lottery_state.guarantee_reserve_balance,
+        };
+    }
+    lottery_state.guarantee_reserve_balance -= shortfall;
+    lottery_state.jackpot_balance += shortfall;
+    JackpotGuaranteeOutcome::Triggered {
+        shortfall_covered: shortfall,
+    }
+}
+
+// ============================================================================
+// FUND JACKPOT GUARANTEE
+// ============================================================================
+
+/// Accounts required for funding the jackpot guarantee reserve
+#[derive(Accounts)]
+pub struct FundJackpotGuarantee<'info> {
+    /// The funder (anyone may back the guarantee)
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// Funder's USDC token account (source of funds)
+    #[account(
+        mut,
+        constraint = funder_usdc.owner == funder.key() @ LottoError::TokenAccountOwnerMismatch,
+        constraint = funder_usdc.mint == usdc_mint.key() @ LottoError::InvalidUsdcMint
+    )]
+    pub funder_usdc: Account<'info, TokenAccount>,
+
+    /// Prize pool USDC token account
+    #[account(
+        mut,
+        seeds = [PRIZE_POOL_USDC_SEED],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+
+    /// USDC mint
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Deposit `amount` into the jackpot guarantee reserve
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `amount` - Amount of USDC lamports to deposit
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_fund_jackpot_guarantee(
+    ctx: Context<FundJackpotGuarantee>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, LottoError::InvalidSeedAmount);
+    require!(
+        ctx.accounts.funder_usdc.amount >= amount,
+        LottoError::InsufficientFunds
+    );
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.funder_usdc.to_account_info(),
+        to: ctx.accounts.prize_pool_usdc.to_account_info(),
+        authority: ctx.accounts.funder.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    lottery_state.guarantee_reserve_balance = lottery_state
+        .guarantee_reserve_balance
+        .checked_add(amount)
+        .ok_or(LottoError::Overflow)?;
+
+    msg!("Jackpot guarantee funded!");
+    msg!("  Funder: {}", ctx.accounts.funder.key());
+    msg!("  Amount added: {} USDC lamports", amount);
+    msg!(
+        "  Guarantee reserve: {} USDC lamports",
+        lottery_state.guarantee_reserve_balance
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// WITHDRAW GUARANTEE RESERVE
+// ============================================================================
+
+/// Accounts required for withdrawing from the jackpot guarantee reserve
+#[derive(Accounts)]
+pub struct WithdrawGuaranteeReserve<'info> {
+    /// The lottery authority
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_paused @ LottoError::InvalidDrawState,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// Prize pool USDC token account
+    #[account(
+        mut,
+        seeds = [PRIZE_POOL_USDC_SEED],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+
+    /// Authority's USDC token account
+    #[account(
+        mut,
+        constraint = authority_usdc.owner == authority.key() @ LottoError::TokenAccountOwnerMismatch,
+        constraint = authority_usdc.mint == prize_pool_usdc.mint @ LottoError::InvalidUsdcMint
+    )]
+    pub authority_usdc: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Withdraw `amount` from the jackpot guarantee reserve to the authority
+///
+/// Only allowed while the lottery is paused, so the guarantee cannot be
+/// pulled out from under a running draw.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `amount` - Amount of USDC lamports to withdraw
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_withdraw_guarantee_reserve(
+    ctx: Context<WithdrawGuaranteeReserve>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, LottoError::InvalidSeedAmount);
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    require!(
+        lottery_state.guarantee_reserve_balance >= amount
+            && ctx.accounts.prize_pool_usdc.amount >= amount,
+        LottoError::InsufficientFunds
+    );
+    lottery_state.guarantee_reserve_balance -= amount;
+
+    let seeds = &[LOTTERY_SEED, &[ctx.accounts.lottery_state.bump]];
+    let signer_seeds = &[&seeds[..]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.prize_pool_usdc.to_account_info(),
+        to: ctx.accounts.authority_usdc.to_account_info(),
+        authority: ctx.accounts.lottery_state.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    token::transfer(cpi_ctx, amount)?;
+
+    msg!("Guarantee reserve withdrawn");
+    msg!("  Amount: {} USDC lamports", amount);
+    msg!(
+        "  Remaining guarantee reserve: {} USDC lamports",
+        ctx.accounts.lottery_state.guarantee_reserve_balance
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_jackpot_guarantee() {
+        let mut lottery_state = LotteryState {
+            jackpot_balance: 400,
+            guarantee_reserve_balance: 1_000,
+            ..Default::default()
+        };
+
+        // No guarantee configured
+        assert_eq!(
+            apply_jackpot_guarantee(&mut lottery_state),
+            JackpotGuaranteeOutcome::NotNeeded
+        );
+
+        lottery_state.jackpot_guarantee_amount = 1_000;
+        assert_eq!(
+            apply_jackpot_guarantee(&mut lottery_state),
+            JackpotGuaranteeOutcome::Triggered {
+                shortfall_covered: 600
+            }
+        );
+        assert_eq!(lottery_state.jackpot_balance, 1_000);
+        assert_eq!(lottery_state.guarantee_reserve_balance, 400);
+
+        // Reserve too small: nothing moves
+        lottery_state.jackpot_balance = 0;
+        assert_eq!(
+            apply_jackpot_guarantee(&mut lottery_state),
+            JackpotGuaranteeOutcome::Failed {
+                required: 1_000,
+                available: 400
+            }
+        );
+        assert_eq!(lottery_state.jackpot_balance, 0);
+        assert_eq!(lottery_state.guarantee_reserve_balance, 400);
+    }
+}
@@ -0,0 +1,360 @@
+//! Keeper Draw Trigger Instructions
+//!
+//! If neither the authority nor the operator commits randomness for a draw,
+//! any signer can do so once `keeper_grace_period_seconds` have passed after
+//! `next_draw_timestamp`. The keeper is recorded in `last_keeper`.
+//!
+//! - trigger_draw_if_ready: Permissionless commit of an overdue draw, paying
+//!   `keeper_reward_lamports` from the lottery state account's SOL balance
+//!   above its rent-exempt minimum
+//! - keeper_commit_randomness: Permissionless commit of an overdue draw
+//!   without a reward
+//! - fund_keeper_reserve: Authority tops up the SOL used for keeper rewards
+//! - set_keeper_grace_period: Authority sets the keeper grace period
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::TokenAccount;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{
+    DrawTriggeredByKeeper, KeeperDrawCommit, KeeperGracePeriodUpdated, KeeperReserveFunded,
+};
+use crate::instructions::commit_randomness::commit_draw_randomness;
+use crate::state::{LotteryState, SchemaVersioned};
+
+/// Lamports held by `account` above its rent-exempt minimum
+pub(crate) fn lamports_above_rent(account: &AccountInfo) -> Result<u64> {
+    let rent_minimum = Rent::get()?.minimum_balance(account.data_len());
+    Ok(account.lamports().saturating_sub(rent_minimum))
+}
+
+/// Commit randomness on behalf of `keeper` once the grace period has passed
+///
+/// Returns the seconds elapsed since `next_draw_timestamp`.
+fn keeper_commit_draw_randomness(
+    lottery_state: &mut LotteryState,
+    randomness_account_data: &AccountInfo,
+    prize_pool_amount: u64,
+    keeper: Pubkey,
+    clock: &Clock,
+) -> Result<i64> {
+    let next_draw_timestamp = lottery_state.next_draw_timestamp;
+
+    require!(
+        is_keeper_trigger_ready(
+            next_draw_timestamp,
+            lottery_state.keeper_grace_period_seconds,
+            clock.unix_timestamp
+        ),
+        LottoError::DrawNotReady
+    );
+
+    commit_draw_randomness(
+        lottery_state,
+        randomness_account_data,
+        prize_pool_amount,
+        clock,
+    )?;
+    lottery_state.last_keeper = Some(keeper);
+
+    Ok(clock.unix_timestamp.saturating_sub(next_draw_timestamp))
+}
+
+// ============================================================================
+// TRIGGER DRAW IF READY
+// ============================================================================
+
+/// Accounts required for a keeper to trigger an overdue draw
+#[derive(Accounts)]
+pub struct TriggerDrawIfReady<'info> {
+    /// Any signer; receives the keeper reward
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// The main lottery state account (pays the keeper reward)
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = !lottery_state.is_paused @ LottoError::Paused,
+        constraint = lottery_state.is_funded @ LottoError::LotteryNotInitialized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The Switchboard randomness account
+    /// CHECK: This account is validated manually by parsing RandomnessAccountData
+    pub randomness_account_data: AccountInfo<'info>,
+
+    /// The Switchboard queue account
+    /// CHECK: Validated against stored queue in lottery state
+    #[account(
+        constraint = switchboard_queue.key() == lottery_state.switchboard_queue @ LottoError::SwitchboardQueueNotSet
+    )]
+    pub switchboard_queue: AccountInfo<'info>,
+
+    /// Prize pool USDC token account (checked for solvency)
+    #[account(
+        seeds = [PRIZE_POOL_USDC_SEED],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Commit randomness for an overdue draw and pay the keeper
+///
+/// Runs the same checks and state changes as `commit_randomness`. The
+/// reward is skipped (not failed) when it is zero or the lottery state
+/// does not hold enough spare lamports, so liveness never depends on it.
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_trigger_draw_if_ready(ctx: Context<TriggerDrawIfReady>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let trigger_delay_seconds = keeper_commit_draw_randomness(
+        &mut ctx.accounts.lottery_state,
+        &ctx.accounts.randomness_account_data,
+        ctx.accounts.prize_pool_usdc.amount,
+        ctx.accounts.keeper.key(),
+        &clock,
+    )?;
+
+    // Pay the keeper from the lottery state's spare lamports
+    let lottery_state_info = ctx.accounts.lottery_state.to_account_info();
+    let keeper_reward = ctx.accounts.lottery_state.keeper_reward_lamports;
+    let reward_lamports = if keeper_reward <= lamports_above_rent(&lottery_state_info)? {
+        keeper_reward
+    } else {
+        msg!("Keeper reserve too low - reward skipped");
+        0
+    };
+
+    if reward_lamports > 0 {
+        let keeper_info = ctx.accounts.keeper.to_account_info();
+        **lottery_state_info.try_borrow_mut_lamports()? -= reward_lamports;
+        **keeper_info.try_borrow_mut_lamports()? += reward_lamports;
+    }
+
+    let draw_id = ctx.accounts.lottery_state.current_draw_id;
+
+    emit!(DrawTriggeredByKeeper {
+        draw_id,
+        keeper: ctx.accounts.keeper.key(),
+        reward_lamports,
+        trigger_delay_seconds,
+    });
+
+    msg!("Draw triggered by keeper!");
+    msg!("  Draw ID: {}", draw_id);
+    msg!("  Keeper: {}", ctx.accounts.keeper.key());
+    msg!("  Reward: {} lamports", reward_lamports);
+    msg!("  Trigger delay: {} seconds", trigger_delay_seconds);
+
+    Ok(())
+}
+
+// ============================================================================
+// KEEPER COMMIT RANDOMNESS
+// ============================================================================
+
+/// Accounts required for a keeper to commit randomness for an overdue draw
+#[derive(Accounts)]
+pub struct KeeperCommitRandomness<'info> {
+    /// Any signer
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = !lottery_state.is_paused @ LottoError::Paused,
+        constraint = lottery_state.is_funded @ LottoError::LotteryNotInitialized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The Switchboard randomness account
+    /// CHECK: This account is validated manually by parsing RandomnessAccountData
+    pub randomness_account_data: AccountInfo<'info>,
+
+    /// The Switchboard queue account
+    /// CHECK: Validated against stored queue in lottery state
+    #[account(
+        constraint = switchboard_queue.key() == lottery_state.switchboard_queue @ LottoError::SwitchboardQueueNotSet
+    )]
+    pub switchboard_queue: AccountInfo<'info>,
+
+    /// Prize pool USDC token account (checked for solvency)
+    #[account(
+        seeds = [PRIZE_POOL_USDC_SEED],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Commit randomness for an overdue draw without a keeper reward
+///
+/// Runs the same checks and state changes as `commit_randomness`, minus the
+/// authority check, once `keeper_grace_period_seconds` have passed after
+/// `next_draw_timestamp`.
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_keeper_commit_randomness(ctx: Context<KeeperCommitRandomness>) -> Result<()> {
+    let clock = Clock::get()?;
+    let keeper = ctx.accounts.keeper.key();
+
+    let delay_seconds = keeper_commit_draw_randomness(
+        &mut ctx.accounts.lottery_state,
+        &ctx.accounts.randomness_account_data,
+        ctx.accounts.prize_pool_usdc.amount,
+        keeper,
+        &clock,
+    )?;
+
+    let draw_id = ctx.accounts.lottery_state.current_draw_id;
+
+    emit!(KeeperDrawCommit {
+        draw_id,
+        keeper,
+        delay_seconds,
+    });
+
+    msg!("Randomness committed by keeper!");
+    msg!("  Draw ID: {}", draw_id);
+    msg!("  Keeper: {}", keeper);
+    msg!("  Delay: {} seconds", delay_seconds);
+
+    Ok(())
+}
+
+// ============================================================================
+// FUND KEEPER RESERVE
+// ============================================================================
+
+/// Accounts required for topping up the keeper reward balance
+#[derive(Accounts)]
+pub struct FundKeeperReserve<'info> {
+    /// The lottery authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account (holds the keeper SOL)
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Transfer SOL from the authority to the lottery state for keeper rewards
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `amount` - Lamports to add
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_fund_keeper_reserve(ctx: Context<FundKeeperReserve>, amount: u64) -> Result<()> {
+    require!(amount > 0, LottoError::InvalidAmount);
+
+    let cpi_accounts = system_program::Transfer {
+        from: ctx.accounts.authority.to_account_info(),
+        to: ctx.accounts.lottery_state.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    system_program::transfer(cpi_ctx, amount)?;
+
+    let available_lamports = lamports_above_rent(&ctx.accounts.lottery_state.to_account_info())?;
+
+    emit!(KeeperReserveFunded {
+        amount,
+        available_lamports,
+    });
+
+    msg!("Keeper reserve funded!");
+    msg!("  Amount: {} lamports", amount);
+    msg!("  Available for rewards: {} lamports", available_lamports);
+
+    Ok(())
+}
+
+// ============================================================================
+// SET KEEPER GRACE PERIOD
+// ============================================================================
+
+/// Accounts required for changing the keeper grace period
+#[derive(Accounts)]
+pub struct SetKeeperGracePeriod<'info> {
+    /// The lottery authority
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+/// Set how long after `next_draw_timestamp` keepers must wait before
+/// committing randomness
+///
+/// Operational setting, so it applies immediately rather than through the
+/// config timelock.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `seconds` - New grace period in seconds
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_set_keeper_grace_period(
+    ctx: Context<SetKeeperGracePeriod>,
+    seconds: i64,
+) -> Result<()> {
+    require!(
+        (MIN_KEEPER_GRACE_PERIOD_SECONDS..=MAX_KEEPER_GRACE_PERIOD_SECONDS).contains(&seconds),
+        LottoError::InvalidKeeperGracePeriod
+    );
+
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    let old_seconds = lottery_state.keeper_grace_period_seconds;
+    lottery_state.keeper_grace_period_seconds = seconds;
+
+    emit!(KeeperGracePeriodUpdated {
+        old_seconds,
+        new_seconds: seconds,
+    });
+
+    msg!("Keeper grace period updated!");
+    msg!("  Grace period: {} -> {} seconds", old_seconds, seconds);
+
+    Ok(())
+}
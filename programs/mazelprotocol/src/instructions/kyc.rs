@@ -0,0 +1,230 @@
+//! KYC Compliance Instructions
+//!
+//! For regulated launches the authority can set `lottery_state.compliance_mode`
+//! to COMPLIANCE_MODE_KYC through the config timelock. Every ticket purchase
+//! (including multi-draw, recurring and referral credit tickets) then
+//! requires the player's `KYCRecord`, which must be neither expired nor
+//! revoked. Records are maintained by `lottery_state.kyc_authority` (also
+//! timelocked), typically a compliance provider's hot wallet. Claims are
+//! never restricted.
+//!
+//! - approve_kyc: Approve a player, creating or renewing their record
+//! - revoke_kyc: Revoke a player's approval
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{KYCApproved, KYCRevoked};
+use crate::state::{KYCRecord, LotteryState, SchemaVersioned};
+
+/// Fail unless `player` may buy under `compliance_mode`
+///
+/// No-op below COMPLIANCE_MODE_KYC. Otherwise the player's KYC record is
+/// required and must be neither revoked nor expired.
+pub fn enforce_kyc(
+    compliance_mode: u8,
+    kyc_record: Option<&KYCRecord>,
+    player: &Pubkey,
+    current_timestamp: i64,
+) -> Result<()> {
+    if compliance_mode < COMPLIANCE_MODE_KYC {
+        return Ok(());
+    }
+    let kyc_record = kyc_record.ok_or(LottoError::KycRecordRequired)?;
+    require!(kyc_record.player == *player, LottoError::KycRecordRequired);
+    require!(!kyc_record.is_revoked, LottoError::KycRevoked);
+    require!(
+        current_timestamp < kyc_record.expires_at,
+        LottoError::KycExpired
+    );
+    Ok(())
+}
+
+/// Whether `jurisdiction` looks like an ISO 3166-1 alpha-2 code
+pub fn is_valid_jurisdiction(jurisdiction: &[u8; 2]) -> bool {
+    jurisdiction.iter().all(u8::is_ascii_uppercase)
+}
+
+// ============================================================================
+// APPROVE KYC
+// ============================================================================
+
+/// Accounts required for approving a player's KYC
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct ApproveKYC<'info> {
+    /// The KYC authority
+    #[account(mut)]
+    pub kyc_authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.kyc_authority == Some(kyc_authority.key()) @ LottoError::UnauthorizedKycAuthority,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The player's KYC record (created on first approval)
+    #[account(
+        init_if_needed,
+        payer = kyc_authority,
+        space = KYC_RECORD_SIZE,
+        seeds = [KYC_SEED, player.as_ref()],
+        bump
+    )]
+    pub kyc_record: Account<'info, KYCRecord>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Approve `player` until `expires_at`
+///
+/// Renews an existing record, clearing any earlier revocation.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `player` - Player being approved
+/// * `jurisdiction` - ISO 3166-1 alpha-2 country code of the player
+/// * `expires_at` - When the approval lapses
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_approve_kyc(
+    ctx: Context<ApproveKYC>,
+    player: Pubkey,
+    jurisdiction: [u8; 2],
+    expires_at: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(
+        is_valid_jurisdiction(&jurisdiction),
+        LottoError::InvalidKycJurisdiction
+    );
+    require!(
+        expires_at > clock.unix_timestamp,
+        LottoError::InvalidKycExpiry
+    );
+
+    let kyc_record = &mut ctx.accounts.kyc_record;
+    kyc_record.bump = ctx.bumps.kyc_record;
+    kyc_record.player = player;
+    kyc_record.jurisdiction = jurisdiction;
+    kyc_record.approved_at = clock.unix_timestamp;
+    kyc_record.expires_at = expires_at;
+    kyc_record.is_revoked = false;
+
+    emit!(KYCApproved {
+        player,
+        jurisdiction,
+        expires_at,
+    });
+
+    msg!("KYC approved");
+    msg!("  Player: {}", player);
+    msg!("  Jurisdiction: {}", String::from_utf8_lossy(&jurisdiction));
+    msg!("  Expires at: {}", expires_at);
+
+    Ok(())
+}
+
+// ============================================================================
+// REVOKE KYC
+// ============================================================================
+
+/// Accounts required for revoking a player's KYC
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct RevokeKYC<'info> {
+    /// The KYC authority
+    pub kyc_authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.kyc_authority == Some(kyc_authority.key()) @ LottoError::UnauthorizedKycAuthority,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The player's KYC record
+    #[account(
+        mut,
+        seeds = [KYC_SEED, player.as_ref()],
+        bump = kyc_record.bump
+    )]
+    pub kyc_record: Account<'info, KYCRecord>,
+}
+
+/// Revoke `player`'s approval
+///
+/// The record is kept so a later `approve_kyc` can renew it.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `player` - Player being revoked
+/// * `reason` - Why the approval is revoked (up to MAX_KYC_REVOKE_REASON_LENGTH bytes)
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_revoke_kyc(ctx: Context<RevokeKYC>, player: Pubkey, reason: String) -> Result<()> {
+    require!(
+        reason.len() <= MAX_KYC_REVOKE_REASON_LENGTH,
+        LottoError::KycRevokeReasonTooLong
+    );
+
+    ctx.accounts.kyc_record.is_revoked = true;
+
+    msg!("KYC revoked");
+    msg!("  Player: {}", player);
+    msg!("  Reason: {}", reason);
+
+    emit!(KYCRevoked { player, reason });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enforce_kyc() {
+        let player = Pubkey::new_unique();
+        let mut record = KYCRecord {
+            player,
+            jurisdiction: *b"DE",
+            approved_at: 100,
+            expires_at: 1_000,
+            ..Default::default()
+        };
+
+        // Not enforced outside KYC mode
+        assert!(enforce_kyc(COMPLIANCE_MODE_NONE, None, &player, 500).is_ok());
+
+        assert!(enforce_kyc(COMPLIANCE_MODE_KYC, None, &player, 500).is_err());
+        assert!(enforce_kyc(COMPLIANCE_MODE_KYC, Some(&record), &player, 500).is_ok());
+        assert!(enforce_kyc(
+            COMPLIANCE_MODE_KYC,
+            Some(&record),
+            &Pubkey::new_unique(),
+            500
+        )
+        .is_err());
+        assert!(enforce_kyc(COMPLIANCE_MODE_KYC, Some(&record), &player, 1_000).is_err());
+
+        record.is_revoked = true;
+        assert!(enforce_kyc(COMPLIANCE_MODE_KYC, Some(&record), &player, 500).is_err());
+    }
+
+    #[test]
+    fn test_is_valid_jurisdiction() {
+        assert!(is_valid_jurisdiction(b"US"));
+        assert!(!is_valid_jurisdiction(b"us"));
+        assert!(!is_valid_jurisdiction(&[0, 0]));
+    }
+}
@@ -0,0 +1,213 @@
+//! Jackpot Leaderboard Instructions
+//!
+//! A singleton `JackpotLeaderboard`, created by `initialize`, keeps the
+//! JACKPOT_LEADERBOARD_LENGTH largest prize wins of all time. `claim_prize`,
+//! `claim_bulk_prize` and `claim_all_bulk_prizes` record every USDC prize
+//! that beats the last-ranked entry.
+//!
+//! The jackpot's own high watermarks live on `LotteryState` and are raised
+//! by ticket purchases.
+//!
+//! - get_leaderboard: Emit the current ranking
+//! - get_jackpot_ath: Emit the all-time and current-season jackpot highs
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{JackpotATHSnapshot, LeaderboardEntryAdded, LeaderboardSnapshot};
+use crate::state::{JackpotLeaderboard, LeaderboardEntry, LotteryState, SchemaVersioned};
+
+/// Record a claimed prize on the leaderboard if it qualifies
+///
+/// Emits `LeaderboardEntryAdded` when the prize is inserted.
+pub(crate) fn record_leaderboard_prize(
+    leaderboard: &mut JackpotLeaderboard,
+    winner: Pubkey,
+    prize_amount: u64,
+    draw_id: u64,
+    match_count: u8,
+    timestamp: i64,
+) {
+    let Some(rank) = leaderboard.insert(LeaderboardEntry {
+        winner,
+        prize_amount,
+        draw_id,
+        match_count,
+        timestamp,
+    }) else {
+        return;
+    };
+
+    emit!(LeaderboardEntryAdded {
+        rank,
+        winner,
+        prize: prize_amount,
+        draw_id,
+    });
+
+    msg!("Prize entered jackpot leaderboard at rank {}", rank);
+}
+
+// ============================================================================
+// GET LEADERBOARD
+// ============================================================================
+
+/// Accounts required for reading the jackpot leaderboard
+#[derive(Accounts)]
+pub struct GetLeaderboard<'info> {
+    /// The jackpot leaderboard
+    #[account(
+        seeds = [JACKPOT_LEADERBOARD_SEED],
+        bump = jackpot_leaderboard.bump
+    )]
+    pub jackpot_leaderboard: Account<'info, JackpotLeaderboard>,
+}
+
+/// Emit the jackpot leaderboard, largest prize first
+///
+/// Permissionless and read-only; emits `LeaderboardSnapshot`.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_get_leaderboard(ctx: Context<GetLeaderboard>) -> Result<()> {
+    let entries = ctx.accounts.jackpot_leaderboard.ranked();
+
+    msg!("Jackpot leaderboard snapshot");
+    msg!("  Entries: {}", entries.len());
+
+    emit!(LeaderboardSnapshot { entries });
+
+    Ok(())
+}
+
+// ============================================================================
+// GET JACKPOT ATH
+// ============================================================================
+
+/// Accounts required for reading the jackpot high watermarks
+#[derive(Accounts)]
+pub struct GetJackpotATH<'info> {
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+/// Emit the all-time and current-season jackpot highs
+///
+/// Permissionless and read-only; emits `JackpotATHSnapshot`.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_get_jackpot_ath(ctx: Context<GetJackpotATH>) -> Result<()> {
+    let lottery_state = &ctx.accounts.lottery_state;
+
+    emit!(JackpotATHSnapshot {
+        all_time_high: lottery_state.jackpot_all_time_high,
+        ath_draw_id: lottery_state.jackpot_ath_draw_id,
+        ath_timestamp: lottery_state.jackpot_ath_timestamp,
+        season_high: lottery_state.jackpot_ath_for_season,
+        jackpot_balance: lottery_state.jackpot_balance,
+    });
+
+    msg!("Jackpot high watermarks");
+    msg!(
+        "  All-time high: {} USDC lamports (draw {})",
+        lottery_state.jackpot_all_time_high,
+        lottery_state.jackpot_ath_draw_id
+    );
+    msg!(
+        "  Season high: {} USDC lamports",
+        lottery_state.jackpot_ath_for_season
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(prize_amount: u64, draw_id: u64) -> LeaderboardEntry {
+        LeaderboardEntry {
+            prize_amount,
+            draw_id,
+            ..Default::default()
+        }
+    }
+
+    fn prizes(leaderboard: &JackpotLeaderboard) -> Vec<u64> {
+        leaderboard
+            .ranked()
+            .iter()
+            .map(|e| e.prize_amount)
+            .collect()
+    }
+
+    #[test]
+    fn test_insert_keeps_descending_order() {
+        let mut leaderboard = JackpotLeaderboard::default();
+
+        assert_eq!(leaderboard.insert(entry(500, 1)), Some(1));
+        assert_eq!(leaderboard.insert(entry(900, 2)), Some(1));
+        assert_eq!(leaderboard.insert(entry(700, 3)), Some(2));
+        assert_eq!(leaderboard.insert(entry(100, 4)), Some(4));
+
+        assert_eq!(prizes(&leaderboard), vec![900, 700, 500, 100]);
+    }
+
+    #[test]
+    fn test_ties_rank_below_existing_entry() {
+        let mut leaderboard = JackpotLeaderboard::default();
+        leaderboard.insert(entry(500, 1));
+
+        assert_eq!(leaderboard.insert(entry(500, 2)), Some(2));
+        assert_eq!(leaderboard.entries[0].draw_id, 1);
+        assert_eq!(leaderboard.entries[1].draw_id, 2);
+    }
+
+    #[test]
+    fn test_full_leaderboard_drops_smallest_and_skips_non_qualifying() {
+        let mut leaderboard = JackpotLeaderboard::default();
+        for i in 1..=JACKPOT_LEADERBOARD_LENGTH as u64 {
+            leaderboard.insert(entry(i * 10, i));
+        }
+        assert_eq!(leaderboard.ranked().len(), JACKPOT_LEADERBOARD_LENGTH);
+
+        // Equal to the last-ranked prize does not qualify
+        assert_eq!(leaderboard.insert(entry(10, 99)), None);
+        assert_eq!(leaderboard.insert(entry(5, 99)), None);
+
+        assert_eq!(leaderboard.insert(entry(15, 100)), Some(20));
+        let ranked = prizes(&leaderboard);
+        assert_eq!(ranked.len(), JACKPOT_LEADERBOARD_LENGTH);
+        assert_eq!(ranked[0], 200);
+        assert_eq!(ranked[JACKPOT_LEADERBOARD_LENGTH - 1], 15);
+    }
+
+    #[test]
+    fn test_zero_prize_never_qualifies() {
+        let mut leaderboard = JackpotLeaderboard::default();
+        assert_eq!(leaderboard.insert(entry(0, 1)), None);
+        assert!(leaderboard.ranked().is_empty());
+    }
+
+    #[test]
+    fn test_leaderboard_size_matches_layout() {
+        let leaderboard = JackpotLeaderboard::default();
+        assert_eq!(
+            8 + leaderboard.try_to_vec().unwrap().len(),
+            JACKPOT_LEADERBOARD_SIZE
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! Player Lifetime Value Instructions
+//!
+//! `UserStats` tracks what each player has spent, won and paid in house
+//! fees. `buy_ticket` and `buy_bulk` add the house fee of every purchase;
+//! `net_roi_lamports` is refreshed on purchases and prize claims.
+//!
+//! - get_player_lifetime_value: Emit a player's lifetime value
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::PlayerLifetimeValueSnapshot;
+use crate::state::{SchemaVersioned, UserStats};
+
+/// Accounts required for reading a player's lifetime value
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct GetPlayerLifetimeValue<'info> {
+    /// The player's user statistics account
+    #[account(
+        seeds = [USER_SEED, player.as_ref()],
+        bump = user_stats.bump,
+        constraint = user_stats.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub user_stats: Account<'info, UserStats>,
+}
+
+/// Emit `player`'s lifetime value
+///
+/// Permissionless and read-only; emits `PlayerLifetimeValueSnapshot`.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `player` - Player whose lifetime value is emitted
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_get_player_lifetime_value(
+    ctx: Context<GetPlayerLifetimeValue>,
+    player: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let user_stats = &ctx.accounts.user_stats;
+    let tenure_days = user_stats.tenure_days(clock.unix_timestamp);
+
+    emit!(PlayerLifetimeValueSnapshot {
+        player,
+        total_spent: user_stats.total_spent,
+        total_won: user_stats.total_prizes_won,
+        total_fees_paid: user_stats.total_house_fees_paid,
+        net_roi: user_stats.net_roi_lamports,
+        tenure_days,
+    });
+
+    msg!("Player lifetime value");
+    msg!("  Player: {}", player);
+    msg!("  Spent: {}", user_stats.total_spent);
+    msg!("  Won: {}", user_stats.total_prizes_won);
+    msg!("  House fees paid: {}", user_stats.total_house_fees_paid);
+    msg!("  Net ROI: {}", user_stats.net_roi_lamports);
+    msg!("  Tenure: {} days", tenure_days);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_net_roi_and_fees() {
+        let mut user_stats = UserStats {
+            total_spent: 3 * TICKET_PRICE,
+            ..Default::default()
+        };
+        user_stats.record_house_fee(TICKET_PRICE / 10).unwrap();
+        assert_eq!(user_stats.total_house_fees_paid, TICKET_PRICE / 10);
+        assert_eq!(user_stats.net_roi_lamports, -3 * TICKET_PRICE as i64);
+
+        user_stats
+            .record_winnings(5 * TICKET_PRICE, 5 * TICKET_PRICE, 4, Some(1))
+            .unwrap();
+        assert_eq!(user_stats.net_roi_lamports, 2 * TICKET_PRICE as i64);
+    }
+
+    #[test]
+    fn test_tenure_days() {
+        let mut user_stats = UserStats::default();
+        assert_eq!(user_stats.tenure_days(10 * SECONDS_PER_DAY), 0);
+
+        user_stats.join_timestamp = SECONDS_PER_DAY;
+        assert_eq!(user_stats.tenure_days(SECONDS_PER_DAY), 0);
+        assert_eq!(user_stats.tenure_days(3 * SECONDS_PER_DAY - 1), 1);
+        assert_eq!(user_stats.tenure_days(0), 0);
+    }
+}
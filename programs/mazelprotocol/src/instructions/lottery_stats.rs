@@ -0,0 +1,98 @@
+//! Lottery Statistics Snapshot Instruction
+//!
+//! Lets analytics indexers record lottery-wide statistics from the event log
+//! instead of decoding `LotteryState`. Permissionless, but limited to one
+//! snapshot per STATS_SNAPSHOT_COOLDOWN_SECONDS so it cannot be used to spam
+//! events.
+//!
+//! - snapshot_lottery_stats: Emit `LotteryStatsSnapshot`
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::LotteryStatsSnapshot;
+use crate::state::{LotteryState, SchemaVersioned};
+
+/// Accounts required for a lottery stats snapshot
+#[derive(Accounts)]
+pub struct SnapshotLotteryStats<'info> {
+    /// Anyone can take a snapshot
+    pub caller: Signer<'info>,
+
+    /// The main lottery state account (records the snapshot time)
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// Prize pool USDC token account (holds jackpot + reserve funds)
+    #[account(
+        seeds = [PRIZE_POOL_USDC_SEED],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+}
+
+/// Emit a snapshot of lottery-wide statistics
+///
+/// Only `last_snapshot_timestamp` is modified.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_snapshot_lottery_stats(ctx: Context<SnapshotLotteryStats>) -> Result<()> {
+    let clock = Clock::get()?;
+    let lottery_state = &mut ctx.accounts.lottery_state;
+
+    if lottery_state.last_snapshot_timestamp > 0 {
+        require!(
+            clock.unix_timestamp
+                >= lottery_state
+                    .last_snapshot_timestamp
+                    .saturating_add(STATS_SNAPSHOT_COOLDOWN_SECONDS),
+            LottoError::SnapshotTooFrequent
+        );
+    }
+    lottery_state.last_snapshot_timestamp = clock.unix_timestamp;
+
+    let collateral_ratio_bps = calculate_collateral_ratio_bps(
+        ctx.accounts.prize_pool_usdc.amount,
+        lottery_state
+            .jackpot_balance
+            .saturating_add(lottery_state.reserve_balance),
+    );
+
+    emit!(LotteryStatsSnapshot {
+        total_tickets_sold: lottery_state.total_tickets_sold,
+        total_prizes_paid: lottery_state.total_prizes_paid,
+        total_prizes_committed: lottery_state.total_prizes_committed,
+        jackpot_balance: lottery_state.jackpot_balance,
+        reserve_balance: lottery_state.reserve_balance,
+        insurance_balance: lottery_state.insurance_tier1_balance,
+        fixed_prize_balance: lottery_state.fixed_prize_balance,
+        current_draw_id: lottery_state.current_draw_id,
+        current_draw_tickets: lottery_state.current_draw_tickets,
+        house_fee_bps: lottery_state.house_fee_bps,
+        next_draw_timestamp: lottery_state.next_draw_timestamp,
+        is_rolldown_active: lottery_state.is_rolldown_active,
+        is_paused: lottery_state.is_paused,
+        soft_cap: lottery_state.soft_cap,
+        hard_cap: lottery_state.hard_cap,
+        collateral_ratio_bps,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Lottery stats snapshot");
+    msg!("  Draw ID: {}", lottery_state.current_draw_id);
+    msg!("  Total tickets sold: {}", lottery_state.total_tickets_sold);
+    msg!("  Collateral ratio: {} bps", collateral_ratio_bps);
+
+    Ok(())
+}
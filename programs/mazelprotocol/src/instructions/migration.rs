@@ -0,0 +1,1043 @@
+//! Schema Migration Instructions
+//!
+//! LotteryState, DrawResult, TicketData, UserStats and Syndicate start with a
+//! `schema_version` byte. Every other instruction rejects accounts whose
+//! version differs from CURRENT_SCHEMA_VERSION, so an upgrade that changes
+//! a layout bumps the constant and adds a step here that initializes the
+//! fields introduced by that version.
+//!
+//! Accounts written before versioning (v0) have no `schema_version` byte
+//! and are shorter than the current layout, so they cannot be loaded as the
+//! current struct. They are recognised by their original size, decoded
+//! with the v0 layouts below, reallocated to the current size and
+//! rewritten with every field added since given its initial value.
+//!
+//! - migrate_lottery_state: Authority brings the lottery state up to
+//!   CURRENT_SCHEMA_VERSION (once per version)
+//! - migrate_account: Anyone brings a DrawResult, TicketData, UserStats or
+//!   Syndicate up to CURRENT_SCHEMA_VERSION, paying any extra rent
+
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::StateMigrated;
+use crate::state::{
+    DrawResult, LotteryState, SchemaVersioned, Syndicate, SyndicateMember, TicketData, UserStats,
+};
+
+// ============================================================================
+// V0 LAYOUTS
+// ============================================================================
+
+/// LotteryState account size before versioning
+pub const LEGACY_LOTTERY_STATE_SIZE: usize = 8 + // discriminator
+    32 + // authority
+    33 + // pending_authority
+    32 + // switchboard_queue
+    32 + // current_randomness_account
+    8 * 5 + // current_draw_id .. fixed_prize_balance
+    8 +  // ticket_price
+    2 +  // house_fee_bps
+    8 * 4 + // jackpot_cap, seed_amount, soft_cap, hard_cap
+    8 * 4 + // next_draw_timestamp, draw_interval, commit_slot, commit_timestamp
+    8 * 4 + // current_draw_tickets .. total_prizes_committed
+    5 +  // is_draw_in_progress, is_rolldown_active, is_paused, is_funded, bump
+    8 +  // config_timelock_end
+    32 + // pending_config_hash
+    8 +  // emergency_transfer_total
+    8; // emergency_transfer_window_start
+
+/// DrawResult account size before versioning
+pub const LEGACY_DRAW_RESULT_SIZE: usize = 8 + // discriminator
+    8 +  // draw_id
+    6 +  // winning_numbers
+    32 + // randomness_proof
+    8 +  // timestamp
+    8 +  // total_tickets
+    1 +  // was_rolldown
+    4 * 5 + // match_6_winners .. match_2_winners
+    8 * 5 + // match_6_prize_per_winner .. match_2_prize_per_winner
+    1 +  // is_explicitly_finalized
+    8 +  // total_committed
+    8 +  // total_reclaimed
+    1 +  // bump
+    16; // padding
+
+/// TicketData account size before versioning
+pub const LEGACY_TICKET_SIZE: usize = 8 + // discriminator
+    32 + // owner
+    8 +  // draw_id
+    6 +  // numbers
+    8 +  // purchase_timestamp
+    1 +  // is_claimed
+    1 +  // match_count
+    8 +  // prize_amount
+    33 + // syndicate
+    1 +  // bump
+    8; // padding
+
+/// UserStats account size before versioning
+pub const LEGACY_USER_STATS_SIZE: usize = 8 + // discriminator
+    32 + // wallet
+    8 * 3 + // total_tickets, total_spent, total_won
+    4 * 3 + // current_streak, best_streak, jackpot_wins
+    8 +  // last_draw_participated
+    8 +  // tickets_this_draw
+    4 +  // free_tickets_available
+    1 +  // bump
+    16; // padding
+
+/// Syndicate account size before versioning, excluding members
+pub const LEGACY_SYNDICATE_BASE_SIZE: usize = 8 + // discriminator
+    32 + // creator
+    32 + // original_creator
+    8 +  // syndicate_id
+    32 + // name
+    1 +  // is_public
+    4 +  // member_count
+    8 +  // total_contribution
+    2 +  // manager_fee_bps
+    32 + // usdc_account
+    4 +  // members vec length
+    1 +  // bump
+    16; // padding
+
+/// LotteryState as written before versioning
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct LotteryStateV0 {
+    pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
+    pub switchboard_queue: Pubkey,
+    pub current_randomness_account: Pubkey,
+    pub current_draw_id: u64,
+    pub jackpot_balance: u64,
+    pub reserve_balance: u64,
+    pub insurance_balance: u64,
+    pub fixed_prize_balance: u64,
+    pub ticket_price: u64,
+    pub house_fee_bps: u16,
+    pub jackpot_cap: u64,
+    pub seed_amount: u64,
+    pub soft_cap: u64,
+    pub hard_cap: u64,
+    pub next_draw_timestamp: i64,
+    pub draw_interval: i64,
+    pub commit_slot: u64,
+    pub commit_timestamp: i64,
+    pub current_draw_tickets: u64,
+    pub total_tickets_sold: u64,
+    pub total_prizes_paid: u64,
+    pub total_prizes_committed: u64,
+    pub is_draw_in_progress: bool,
+    pub is_rolldown_active: bool,
+    pub is_paused: bool,
+    pub is_funded: bool,
+    pub bump: u8,
+    pub config_timelock_end: i64,
+    pub pending_config_hash: [u8; 32],
+    pub emergency_transfer_total: u64,
+    pub emergency_transfer_window_start: i64,
+}
+
+/// DrawResult as written before versioning
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct DrawResultV0 {
+    pub draw_id: u64,
+    pub winning_numbers: [u8; 6],
+    pub randomness_proof: [u8; 32],
+    pub timestamp: i64,
+    pub total_tickets: u64,
+    pub was_rolldown: bool,
+    pub match_6_winners: u32,
+    pub match_5_winners: u32,
+    pub match_4_winners: u32,
+    pub match_3_winners: u32,
+    pub match_2_winners: u32,
+    pub match_6_prize_per_winner: u64,
+    pub match_5_prize_per_winner: u64,
+    pub match_4_prize_per_winner: u64,
+    pub match_3_prize_per_winner: u64,
+    pub match_2_prize_per_winner: u64,
+    pub is_explicitly_finalized: bool,
+    pub total_committed: u64,
+    pub total_reclaimed: u64,
+    pub bump: u8,
+}
+
+/// TicketData as written before versioning
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct TicketDataV0 {
+    pub owner: Pubkey,
+    pub draw_id: u64,
+    pub numbers: [u8; 6],
+    pub purchase_timestamp: i64,
+    pub is_claimed: bool,
+    pub match_count: u8,
+    pub prize_amount: u64,
+    pub syndicate: Option<Pubkey>,
+    pub bump: u8,
+}
+
+/// UserStats as written before versioning
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UserStatsV0 {
+    pub wallet: Pubkey,
+    pub total_tickets: u64,
+    pub total_spent: u64,
+    pub total_won: u64,
+    pub current_streak: u32,
+    pub best_streak: u32,
+    pub jackpot_wins: u32,
+    pub last_draw_participated: u64,
+    pub tickets_this_draw: u64,
+    pub free_tickets_available: u32,
+    pub bump: u8,
+}
+
+/// Syndicate as written before versioning
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SyndicateV0 {
+    pub creator: Pubkey,
+    pub original_creator: Pubkey,
+    pub syndicate_id: u64,
+    pub name: [u8; 32],
+    pub is_public: bool,
+    pub member_count: u32,
+    pub total_contribution: u64,
+    pub manager_fee_bps: u16,
+    pub usdc_account: Pubkey,
+    pub members: Vec<SyndicateMember>,
+    pub bump: u8,
+}
+
+// ============================================================================
+// V0 -> V1 CONVERSION
+// ============================================================================
+
+/// LotteryState with the v0 fields kept and every v1 field initialized
+pub fn lottery_state_from_v0(legacy: LotteryStateV0) -> LotteryState {
+    let mut lottery_state = LotteryState {
+        schema_version: 1,
+        authority: legacy.authority,
+        pending_authority: legacy.pending_authority,
+        switchboard_queue: legacy.switchboard_queue,
+        current_randomness_account: legacy.current_randomness_account,
+        current_draw_id: legacy.current_draw_id,
+        jackpot_balance: legacy.jackpot_balance,
+        reserve_balance: legacy.reserve_balance,
+        insurance_tier1_balance: legacy.insurance_balance,
+        fixed_prize_balance: legacy.fixed_prize_balance,
+        ticket_price: legacy.ticket_price,
+        house_fee_bps: legacy.house_fee_bps,
+        jackpot_cap: legacy.jackpot_cap,
+        seed_amount: legacy.seed_amount,
+        soft_cap: legacy.soft_cap,
+        hard_cap: legacy.hard_cap,
+        next_draw_timestamp: legacy.next_draw_timestamp,
+        draw_interval: legacy.draw_interval,
+        commit_slot: legacy.commit_slot,
+        commit_timestamp: legacy.commit_timestamp,
+        current_draw_tickets: legacy.current_draw_tickets,
+        total_tickets_sold: legacy.total_tickets_sold,
+        total_prizes_paid: legacy.total_prizes_paid,
+        total_prizes_committed: legacy.total_prizes_committed,
+        is_draw_in_progress: legacy.is_draw_in_progress,
+        is_rolldown_active: legacy.is_rolldown_active,
+        is_paused: legacy.is_paused,
+        is_funded: legacy.is_funded,
+        bump: legacy.bump,
+        config_timelock_end: legacy.config_timelock_end,
+        pending_config_hash: legacy.pending_config_hash,
+        emergency_transfer_total: legacy.emergency_transfer_total,
+        emergency_transfer_window_start: legacy.emergency_transfer_window_start,
+        ..Default::default()
+    };
+    lottery_state.init_v1_fields();
+    lottery_state
+}
+
+/// DrawResult with the v0 fields kept and every v1 field initialized
+///
+/// The challenge window is closed (`challenge_deadline` 0) and no merkle
+/// root or commitment hash is recorded.
+pub fn draw_result_from_v0(legacy: DrawResultV0) -> DrawResult {
+    DrawResult {
+        schema_version: 1,
+        draw_id: legacy.draw_id,
+        winning_numbers: legacy.winning_numbers,
+        randomness_proof: legacy.randomness_proof,
+        timestamp: legacy.timestamp,
+        total_tickets: legacy.total_tickets,
+        was_rolldown: legacy.was_rolldown,
+        match_6_winners: legacy.match_6_winners,
+        match_5_winners: legacy.match_5_winners,
+        match_4_winners: legacy.match_4_winners,
+        match_3_winners: legacy.match_3_winners,
+        match_2_winners: legacy.match_2_winners,
+        match_6_prize_per_winner: legacy.match_6_prize_per_winner,
+        match_5_prize_per_winner: legacy.match_5_prize_per_winner,
+        match_4_prize_per_winner: legacy.match_4_prize_per_winner,
+        match_3_prize_per_winner: legacy.match_3_prize_per_winner,
+        match_2_prize_per_winner: legacy.match_2_prize_per_winner,
+        is_explicitly_finalized: legacy.is_explicitly_finalized,
+        total_committed: legacy.total_committed,
+        total_reclaimed: legacy.total_reclaimed,
+        bump: legacy.bump,
+        ..Default::default()
+    }
+}
+
+/// TicketData with the v0 fields kept and every v1 field initialized
+///
+/// The buyer is taken as the original owner. Whether a v0 ticket was paid
+/// for was never recorded, so it is not refundable if its draw is cancelled.
+pub fn ticket_from_v0(legacy: TicketDataV0) -> TicketData {
+    TicketData {
+        schema_version: 1,
+        owner: legacy.owner,
+        draw_id: legacy.draw_id,
+        numbers: legacy.numbers,
+        purchase_timestamp: legacy.purchase_timestamp,
+        is_claimed: legacy.is_claimed,
+        match_count: legacy.match_count,
+        prize_amount: legacy.prize_amount,
+        syndicate: legacy.syndicate,
+        original_owner: legacy.owner,
+        entered_second_chance: false,
+        bump: legacy.bump,
+        requires_refund: false,
+        claim_agent: None,
+    }
+}
+
+/// UserStats with the v0 fields kept and every v1 field initialized
+///
+/// Lifetime winnings carry over, and the per-draw wallet count starts from
+/// the v0 `tickets_this_draw`.
+pub fn user_stats_from_v0(legacy: UserStatsV0) -> UserStats {
+    UserStats {
+        schema_version: 1,
+        wallet: legacy.wallet,
+        total_tickets: legacy.total_tickets,
+        total_spent: legacy.total_spent,
+        total_won: legacy.total_won,
+        current_streak: legacy.current_streak,
+        best_streak: legacy.best_streak,
+        jackpot_wins: legacy.jackpot_wins,
+        last_draw_participated: legacy.last_draw_participated,
+        tickets_this_draw: legacy.tickets_this_draw,
+        free_tickets_available: legacy.free_tickets_available,
+        bump: legacy.bump,
+        total_prizes_won: legacy.total_won,
+        net_roi_lamports: (legacy.total_won as i128 - legacy.total_spent as i128)
+            .clamp(i64::MIN as i128, i64::MAX as i128) as i64,
+        daily_ticket_limit: DEFAULT_DAILY_TICKET_LIMIT,
+        join_timestamp: 0,
+        current_draw_id_purchased: legacy.last_draw_participated,
+        current_draw_tickets_count: legacy.tickets_this_draw,
+        ..Default::default()
+    }
+}
+
+/// Syndicate with the v0 fields kept and every v1 field initialized
+///
+/// The member limit is the base `effective_max_members`, raised to the
+/// current member count so no existing member is over the limit.
+pub fn syndicate_from_v0(legacy: SyndicateV0) -> Syndicate {
+    let max_members = legacy
+        .member_count
+        .max(effective_max_members(0) as u32)
+        .min(u8::MAX as u32) as u8;
+    Syndicate {
+        schema_version: 1,
+        creator: legacy.creator,
+        original_creator: legacy.original_creator,
+        syndicate_id: legacy.syndicate_id,
+        name: legacy.name,
+        is_public: legacy.is_public,
+        member_count: legacy.member_count,
+        total_contribution: legacy.total_contribution,
+        manager_fee_bps: legacy.manager_fee_bps,
+        usdc_account: legacy.usdc_account,
+        members: legacy.members,
+        bump: legacy.bump,
+        max_contribution_per_member: 0,
+        is_locked: false,
+        locked_draw_id: 0,
+        pending_usdc: 0,
+        max_members_at_creation: max_members,
+        ticket_draw_id: 0,
+        tickets_purchased: 0,
+        tickets_created: 0,
+    }
+}
+
+// ============================================================================
+// RAW ACCOUNT MIGRATION
+// ============================================================================
+
+/// A migrated account and the version it was migrated from
+pub struct Migrated<T> {
+    pub from_version: u8,
+    pub account: T,
+}
+
+/// Fail unless `from_version` is older than CURRENT_SCHEMA_VERSION
+fn require_upgradable(from_version: u8) -> Result<()> {
+    require!(
+        from_version != CURRENT_SCHEMA_VERSION,
+        LottoError::SchemaAlreadyCurrent
+    );
+    require!(
+        from_version < CURRENT_SCHEMA_VERSION,
+        LottoError::SchemaVersionMismatch
+    );
+    Ok(())
+}
+
+/// Decode raw account data in either the v0 or the current layout and
+/// bring it up to CURRENT_SCHEMA_VERSION
+///
+/// `is_v0` tells the layouts apart by data length. Fails with
+/// `SchemaAlreadyCurrent` when there is nothing to migrate and
+/// `SchemaVersionMismatch` for versions newer than this program.
+fn migrate_data<T, V>(
+    data: &[u8],
+    is_v0: impl FnOnce(usize) -> bool,
+    from_v0: impl FnOnce(V) -> T,
+    set_version: impl FnOnce(&mut T, u8),
+) -> Result<Migrated<T>>
+where
+    T: AccountDeserialize + Discriminator + SchemaVersioned,
+    V: AnchorDeserialize,
+{
+    require!(
+        data.len() >= 8 && &data[..8] == T::DISCRIMINATOR,
+        LottoError::InvalidAccountData
+    );
+
+    let (from_version, mut account) = if is_v0(data.len()) {
+        let legacy = V::deserialize(&mut &data[8..]).map_err(|_| LottoError::InvalidAccountData)?;
+        (0, from_v0(legacy))
+    } else {
+        let account = T::try_deserialize(&mut &data[..])?;
+        (account.version(), account)
+    };
+    require_upgradable(from_version)?;
+
+    // v1 is the first versioned layout; later versions add their steps here
+    set_version(&mut account, CURRENT_SCHEMA_VERSION);
+
+    Ok(Migrated {
+        from_version,
+        account,
+    })
+}
+
+/// Migrate raw LotteryState data to CURRENT_SCHEMA_VERSION
+pub fn migrate_lottery_state_data(data: &[u8]) -> Result<Migrated<LotteryState>> {
+    migrate_data(
+        data,
+        |len| len == LEGACY_LOTTERY_STATE_SIZE,
+        lottery_state_from_v0,
+        |account: &mut LotteryState, version| account.schema_version = version,
+    )
+}
+
+/// Migrate raw DrawResult data to CURRENT_SCHEMA_VERSION
+pub fn migrate_draw_result_data(data: &[u8]) -> Result<Migrated<DrawResult>> {
+    migrate_data(
+        data,
+        |len| len == LEGACY_DRAW_RESULT_SIZE,
+        draw_result_from_v0,
+        |account: &mut DrawResult, version| account.schema_version = version,
+    )
+}
+
+/// Migrate raw TicketData data to CURRENT_SCHEMA_VERSION
+pub fn migrate_ticket_data(data: &[u8]) -> Result<Migrated<TicketData>> {
+    migrate_data(
+        data,
+        |len| len == LEGACY_TICKET_SIZE,
+        ticket_from_v0,
+        |account: &mut TicketData, version| account.schema_version = version,
+    )
+}
+
+/// Migrate raw UserStats data to CURRENT_SCHEMA_VERSION
+pub fn migrate_user_stats_data(data: &[u8]) -> Result<Migrated<UserStats>> {
+    migrate_data(
+        data,
+        |len| len == LEGACY_USER_STATS_SIZE,
+        user_stats_from_v0,
+        |account: &mut UserStats, version| account.schema_version = version,
+    )
+}
+
+/// Whether `len` is a v0 Syndicate size (base plus whole members)
+///
+/// The v0 and current base sizes differ by less than one member, so the two
+/// layouts never share a size.
+pub fn is_legacy_syndicate_size(len: usize) -> bool {
+    len >= LEGACY_SYNDICATE_BASE_SIZE
+        && (len - LEGACY_SYNDICATE_BASE_SIZE) % SYNDICATE_MEMBER_SIZE == 0
+}
+
+/// Migrate raw Syndicate data to CURRENT_SCHEMA_VERSION
+pub fn migrate_syndicate_data(data: &[u8]) -> Result<Migrated<Syndicate>> {
+    migrate_data(
+        data,
+        is_legacy_syndicate_size,
+        syndicate_from_v0,
+        |account: &mut Syndicate, version| account.schema_version = version,
+    )
+}
+
+/// Grow `account` to at least `min_len` (topping up rent from `payer`) and
+/// write `migrated` over its data
+fn write_migrated_account<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    migrated: &T,
+    min_len: usize,
+) -> Result<()> {
+    if min_len > account.data_len() {
+        let rent_needed = Rent::get()?
+            .minimum_balance(min_len)
+            .saturating_sub(account.lamports());
+        if rent_needed > 0 {
+            let cpi_context = CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            );
+            anchor_lang::system_program::transfer(cpi_context, rent_needed)?;
+        }
+        account.resize(min_len)?;
+    }
+
+    let mut data = account.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    migrated.try_serialize(&mut writer)?;
+    Ok(())
+}
+
+/// Emit `StateMigrated` and log the version change
+fn log_migration(account: Pubkey, kind: &str, from_version: u8) {
+    emit!(StateMigrated {
+        account,
+        from_version,
+        to_version: CURRENT_SCHEMA_VERSION,
+    });
+
+    msg!("{} migrated!", kind);
+    msg!("  Account: {}", account);
+    msg!(
+        "  Schema version: {} -> {}",
+        from_version,
+        CURRENT_SCHEMA_VERSION
+    );
+}
+
+// ============================================================================
+// MIGRATE LOTTERY STATE
+// ============================================================================
+
+/// Accounts required for migrating the lottery state schema
+#[derive(Accounts)]
+pub struct MigrateLotteryState<'info> {
+    /// The lottery authority (pays any extra rent)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account (any schema version)
+    /// CHECK: Read as raw data in either layout; the stored authority is
+    /// checked in the handler
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump,
+        owner = crate::ID @ LottoError::InvalidAccountOwner
+    )]
+    pub lottery_state: UncheckedAccount<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Migrate the lottery state to CURRENT_SCHEMA_VERSION
+///
+/// Emits `StateMigrated`. Calling it again before the next version bump
+/// fails with `SchemaAlreadyCurrent`.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_migrate_lottery_state(ctx: Context<MigrateLotteryState>) -> Result<()> {
+    let account_info = ctx.accounts.lottery_state.to_account_info();
+    let migrated = migrate_lottery_state_data(&account_info.try_borrow_data()?)?;
+    require!(
+        migrated.account.authority == ctx.accounts.authority.key(),
+        LottoError::Unauthorized
+    );
+
+    write_migrated_account(
+        &account_info,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &migrated.account,
+        LOTTERY_STATE_SIZE,
+    )?;
+
+    log_migration(account_info.key(), "Lottery state", migrated.from_version);
+
+    Ok(())
+}
+
+// ============================================================================
+// MIGRATE ACCOUNT
+// ============================================================================
+
+/// Accounts required for migrating a DrawResult, TicketData, UserStats or
+/// Syndicate account
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// Anyone (pays any extra rent)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The account to migrate (any schema version)
+    /// CHECK: Must be owned by this program; the discriminator selects the
+    /// layout and the data is read raw in the handler
+    #[account(
+        mut,
+        owner = crate::ID @ LottoError::InvalidAccountOwner
+    )]
+    pub account: UncheckedAccount<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Migrate a DrawResult, TicketData, UserStats or Syndicate account to
+/// CURRENT_SCHEMA_VERSION
+///
+/// Permissionless: the migration only initializes fields, so it has the
+/// same result whoever calls it. Emits `StateMigrated`.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+    let account_info = ctx.accounts.account.to_account_info();
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    let discriminator: [u8; 8] = {
+        let data = account_info.try_borrow_data()?;
+        require!(data.len() >= 8, LottoError::InvalidAccountData);
+        data[..8].try_into().unwrap()
+    };
+
+    let (kind, from_version) = if discriminator == DrawResult::DISCRIMINATOR {
+        let migrated = migrate_draw_result_data(&account_info.try_borrow_data()?)?;
+        write_migrated_account(
+            &account_info,
+            &payer,
+            &system_program,
+            &migrated.account,
+            DRAW_RESULT_SIZE,
+        )?;
+        ("Draw result", migrated.from_version)
+    } else if discriminator == TicketData::DISCRIMINATOR {
+        let migrated = migrate_ticket_data(&account_info.try_borrow_data()?)?;
+        write_migrated_account(
+            &account_info,
+            &payer,
+            &system_program,
+            &migrated.account,
+            TICKET_SIZE,
+        )?;
+        ("Ticket", migrated.from_version)
+    } else if discriminator == UserStats::DISCRIMINATOR {
+        let migrated = migrate_user_stats_data(&account_info.try_borrow_data()?)?;
+        write_migrated_account(
+            &account_info,
+            &payer,
+            &system_program,
+            &migrated.account,
+            USER_STATS_SIZE,
+        )?;
+        ("User stats", migrated.from_version)
+    } else if discriminator == Syndicate::DISCRIMINATOR {
+        let migrated = migrate_syndicate_data(&account_info.try_borrow_data()?)?;
+        let min_len = Syndicate::size_for_members(migrated.account.members.len());
+        write_migrated_account(
+            &account_info,
+            &payer,
+            &system_program,
+            &migrated.account,
+            min_len,
+        )?;
+        ("Syndicate", migrated.from_version)
+    } else {
+        return Err(LottoError::InvalidAccountData.into());
+    };
+
+    log_migration(account_info.key(), kind, from_version);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds account data field by field, as the v0 program wrote it
+    struct RawAccount(Vec<u8>);
+
+    impl RawAccount {
+        fn new(discriminator: &[u8]) -> Self {
+            RawAccount(discriminator.to_vec())
+        }
+        fn bytes(mut self, bytes: &[u8]) -> Self {
+            self.0.extend_from_slice(bytes);
+            self
+        }
+        fn pubkey(self, key: &Pubkey) -> Self {
+            self.bytes(key.as_ref())
+        }
+        fn option_pubkey(self, key: Option<&Pubkey>) -> Self {
+            match key {
+                Some(key) => self.bytes(&[1]).pubkey(key),
+                None => self.bytes(&[0]),
+            }
+        }
+        fn u8(self, value: u8) -> Self {
+            self.bytes(&[value])
+        }
+        fn u16(self, value: u16) -> Self {
+            self.bytes(&value.to_le_bytes())
+        }
+        fn u32(self, value: u32) -> Self {
+            self.bytes(&value.to_le_bytes())
+        }
+        fn u64(self, value: u64) -> Self {
+            self.bytes(&value.to_le_bytes())
+        }
+        fn i64(self, value: i64) -> Self {
+            self.bytes(&value.to_le_bytes())
+        }
+        /// Zero-fill the account's trailing padding, checking every field
+        /// before it was written
+        fn padded(mut self, size: usize, padding: usize) -> Vec<u8> {
+            assert_eq!(self.0.len() + padding, size);
+            self.0.resize(size, 0);
+            self.0
+        }
+    }
+
+    fn v0_lottery_state(authority: &Pubkey) -> Vec<u8> {
+        RawAccount::new(LotteryState::DISCRIMINATOR)
+            .pubkey(authority)
+            .option_pubkey(Some(&Pubkey::new_from_array([2; 32])))
+            .pubkey(&Pubkey::new_from_array([3; 32]))
+            .pubkey(&Pubkey::default())
+            .u64(42) // current_draw_id
+            .u64(1_500_000_000_000) // jackpot_balance
+            .u64(250_000_000) // reserve_balance
+            .u64(90_000_000) // insurance_balance
+            .u64(60_000_000) // fixed_prize_balance
+            .u64(TICKET_PRICE)
+            .u16(3_400) // house_fee_bps
+            .u64(JACKPOT_CAP)
+            .u64(SEED_AMOUNT)
+            .u64(SOFT_CAP)
+            .u64(HARD_CAP)
+            .i64(1_700_000_000 + 3 * 3_600) // next_draw_timestamp
+            .i64(DRAW_INTERVAL)
+            .u64(0) // commit_slot
+            .i64(0) // commit_timestamp
+            .u64(1_234) // current_draw_tickets
+            .u64(98_765) // total_tickets_sold
+            .u64(7_000_000_000) // total_prizes_paid
+            .u64(8_000_000_000) // total_prizes_committed
+            .bytes(&[0, 0, 0, 1]) // in progress, rolldown, paused, funded
+            .u8(253) // bump
+            .i64(0) // config_timelock_end
+            .bytes(&[0; 32]) // pending_config_hash
+            .u64(5_000) // emergency_transfer_total
+            .i64(1_699_000_000) // emergency_transfer_window_start
+            .padded(LEGACY_LOTTERY_STATE_SIZE, 0)
+    }
+
+    #[test]
+    fn test_schema_version_follows_discriminator() {
+        let lottery_state = LotteryState {
+            schema_version: CURRENT_SCHEMA_VERSION,
+            ..Default::default()
+        };
+        let mut data = Vec::new();
+        lottery_state.try_serialize(&mut data).unwrap();
+
+        assert_eq!(data[8], CURRENT_SCHEMA_VERSION);
+        assert!(LotteryState::try_deserialize(&mut data.as_slice())
+            .unwrap()
+            .is_current_schema());
+    }
+
+    #[test]
+    fn test_v0_sizes_differ_from_current_sizes() {
+        // Current accounts are never mistaken for v0 ones
+        assert_ne!(LOTTERY_STATE_SIZE, LEGACY_LOTTERY_STATE_SIZE);
+        assert_ne!(DRAW_RESULT_SIZE, LEGACY_DRAW_RESULT_SIZE);
+        assert_ne!(TICKET_SIZE, LEGACY_TICKET_SIZE);
+        assert_ne!(USER_STATS_SIZE, LEGACY_USER_STATS_SIZE);
+        for members in 0..=u8::MAX as usize {
+            assert!(!is_legacy_syndicate_size(Syndicate::size_for_members(
+                members
+            )));
+        }
+    }
+
+    #[test]
+    fn test_migrate_v0_lottery_state() {
+        let authority = Pubkey::new_unique();
+        let data = v0_lottery_state(&authority);
+        assert!(LotteryState::try_deserialize(&mut data.as_slice()).is_err());
+
+        let migrated = migrate_lottery_state_data(&data).unwrap();
+        assert_eq!(migrated.from_version, 0);
+        let lottery_state = migrated.account;
+
+        // v0 fields carry over
+        assert!(lottery_state.is_current_schema());
+        assert_eq!(lottery_state.authority, authority);
+        assert_eq!(
+            lottery_state.pending_authority,
+            Some(Pubkey::new_from_array([2; 32]))
+        );
+        assert_eq!(lottery_state.current_draw_id, 42);
+        assert_eq!(lottery_state.jackpot_balance, 1_500_000_000_000);
+        assert_eq!(lottery_state.insurance_tier1_balance, 90_000_000);
+        assert_eq!(lottery_state.house_fee_bps, 3_400);
+        assert_eq!(lottery_state.current_draw_tickets, 1_234);
+        assert!(lottery_state.is_funded && !lottery_state.is_paused);
+        assert_eq!(lottery_state.bump, 253);
+        assert_eq!(lottery_state.emergency_transfer_window_start, 1_699_000_000);
+
+        // v1 fields get the same initial values as a new lottery
+        assert_eq!(
+            lottery_state.min_tickets_per_draw,
+            DEFAULT_MIN_TICKETS_PER_DRAW
+        );
+        assert_eq!(lottery_state.rolldown_match_5_bps, ROLLDOWN_MATCH_5_BPS);
+        assert_eq!(lottery_state.rolldown_match_4_bps, ROLLDOWN_MATCH_4_BPS);
+        assert_eq!(lottery_state.rolldown_match_3_bps, ROLLDOWN_MATCH_3_BPS);
+        assert_eq!(
+            lottery_state.reserve_target_multiplier_bps,
+            DEFAULT_RESERVE_TARGET_MULTIPLIER_BPS
+        );
+        assert_eq!(
+            lottery_state.max_oracle_failures,
+            DEFAULT_MAX_ORACLE_FAILURES
+        );
+        assert_eq!(
+            lottery_state.quickpick_spend_gate_threshold,
+            QUICK_PICK_MIN_SPEND_GATE
+        );
+        assert_eq!(lottery_state.ticket_sale_cutoff_seconds, TICKET_SALE_CUTOFF);
+        assert_eq!(
+            lottery_state.anchor_draw_time,
+            (1_700_000_000 + 3 * 3_600) % SECONDS_PER_DAY
+        );
+
+        // The migrated state fits the current account size
+        let mut data = Vec::new();
+        lottery_state.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= LOTTERY_STATE_SIZE);
+        data.resize(LOTTERY_STATE_SIZE, 0);
+        assert!(migrate_lottery_state_data(&data).is_err());
+    }
+
+    #[test]
+    fn test_migrate_v0_draw_result() {
+        let data = RawAccount::new(DrawResult::DISCRIMINATOR)
+            .u64(7) // draw_id
+            .bytes(&[3, 9, 14, 22, 31, 45])
+            .bytes(&[5; 32]) // randomness_proof
+            .i64(1_700_000_000)
+            .u64(10_000) // total_tickets
+            .u8(0) // was_rolldown
+            .u32(0)
+            .u32(1)
+            .u32(20)
+            .u32(300)
+            .u32(4_000)
+            .u64(0)
+            .u64(4_000_000_000)
+            .u64(150_000_000)
+            .u64(5_000_000)
+            .u64(2_500_000)
+            .u8(1) // is_explicitly_finalized
+            .u64(9_000_000_000) // total_committed
+            .u64(0) // total_reclaimed
+            .u8(250) // bump
+            .padded(LEGACY_DRAW_RESULT_SIZE, 16);
+
+        let migrated = migrate_draw_result_data(&data).unwrap();
+        let draw_result = migrated.account;
+        assert_eq!(migrated.from_version, 0);
+        assert!(draw_result.is_current_schema());
+        assert_eq!(draw_result.draw_id, 7);
+        assert_eq!(draw_result.winning_numbers, [3, 9, 14, 22, 31, 45]);
+        assert_eq!(draw_result.match_3_winners, 300);
+        assert_eq!(draw_result.match_5_prize_per_winner, 4_000_000_000);
+        assert_eq!(draw_result.total_committed, 9_000_000_000);
+        assert_eq!(draw_result.bump, 250);
+        assert!(draw_result.is_finalized());
+        assert!(!draw_result.are_claims_frozen());
+        assert_eq!(draw_result.challenge_deadline, 0);
+        assert!(draw_result.winner_merkle_root.is_none());
+    }
+
+    #[test]
+    fn test_migrate_v0_ticket() {
+        let owner = Pubkey::new_unique();
+        let syndicate = Pubkey::new_unique();
+        let data = RawAccount::new(TicketData::DISCRIMINATOR)
+            .pubkey(&owner)
+            .u64(7) // draw_id
+            .bytes(&[1, 2, 3, 4, 5, 6])
+            .i64(1_699_999_000)
+            .u8(0) // is_claimed
+            .u8(0) // match_count
+            .u64(0) // prize_amount
+            .option_pubkey(Some(&syndicate))
+            .u8(251) // bump
+            .padded(LEGACY_TICKET_SIZE, 8);
+
+        let migrated = migrate_ticket_data(&data).unwrap();
+        let ticket = migrated.account;
+        assert!(ticket.is_current_schema());
+        assert_eq!(ticket.owner, owner);
+        assert_eq!(ticket.original_owner, owner);
+        assert_eq!(ticket.numbers, [1, 2, 3, 4, 5, 6]);
+        assert_eq!(ticket.syndicate, Some(syndicate));
+        assert_eq!(ticket.bump, 251);
+        assert!(!ticket.is_claimed && !ticket.entered_second_chance);
+        assert!(!ticket.requires_refund);
+        assert!(ticket.claim_agent.is_none());
+
+        let mut data = Vec::new();
+        ticket.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= TICKET_SIZE);
+    }
+
+    #[test]
+    fn test_migrate_v0_user_stats() {
+        let wallet = Pubkey::new_unique();
+        let data = RawAccount::new(UserStats::DISCRIMINATOR)
+            .pubkey(&wallet)
+            .u64(120) // total_tickets
+            .u64(300_000_000) // total_spent
+            .u64(155_000_000) // total_won
+            .u32(4) // current_streak
+            .u32(9) // best_streak
+            .u32(0) // jackpot_wins
+            .u64(41) // last_draw_participated
+            .u64(6) // tickets_this_draw
+            .u32(2) // free_tickets_available
+            .u8(249) // bump
+            .padded(LEGACY_USER_STATS_SIZE, 16);
+
+        let migrated = migrate_user_stats_data(&data).unwrap();
+        let user_stats = migrated.account;
+        assert!(user_stats.is_current_schema());
+        assert_eq!(user_stats.wallet, wallet);
+        assert_eq!(user_stats.total_spent, 300_000_000);
+        assert_eq!(user_stats.free_tickets_available, 2);
+        assert_eq!(user_stats.bump, 249);
+        assert_eq!(user_stats.total_prizes_won, 155_000_000);
+        assert_eq!(user_stats.net_roi_lamports, -145_000_000);
+        assert_eq!(user_stats.daily_ticket_limit, DEFAULT_DAILY_TICKET_LIMIT);
+        assert_eq!(user_stats.current_draw_id_purchased, 41);
+        assert_eq!(user_stats.current_draw_tickets_count, 6);
+        assert_eq!(user_stats.join_timestamp, 0);
+
+        let mut data = Vec::new();
+        user_stats.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= USER_STATS_SIZE);
+    }
+
+    #[test]
+    fn test_migrate_v0_syndicate() {
+        let creator = Pubkey::new_unique();
+        let member = Pubkey::new_unique();
+        let mut raw = RawAccount::new(Syndicate::DISCRIMINATOR)
+            .pubkey(&creator)
+            .pubkey(&creator)
+            .u64(3) // syndicate_id
+            .bytes(&[b'x'; 32])
+            .u8(1) // is_public
+            .u32(2) // member_count
+            .u64(50_000_000) // total_contribution
+            .u16(500) // manager_fee_bps
+            .pubkey(&Pubkey::new_unique())
+            .u32(2); // members
+        for (wallet, contribution, share) in [(&creator, 0, 0), (&member, 50_000_000, 10_000)] {
+            raw = raw.pubkey(wallet).u64(contribution).u16(share).u64(0);
+        }
+        let data = raw
+            .u8(248) // bump
+            .padded(LEGACY_SYNDICATE_BASE_SIZE + 2 * SYNDICATE_MEMBER_SIZE, 16);
+        assert!(is_legacy_syndicate_size(data.len()));
+
+        let migrated = migrate_syndicate_data(&data).unwrap();
+        let syndicate = migrated.account;
+        assert!(syndicate.is_current_schema());
+        assert_eq!(syndicate.original_creator, creator);
+        assert_eq!(syndicate.members.len(), 2);
+        assert_eq!(syndicate.members[1].wallet, member);
+        assert_eq!(syndicate.members[1].share_percentage_bps, 10_000);
+        assert_eq!(syndicate.bump, 248);
+        assert_eq!(syndicate.max_members_at_creation, effective_max_members(0));
+        assert!(!syndicate.is_locked && !syndicate.is_full());
+
+        let mut data = Vec::new();
+        syndicate.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= Syndicate::size_for_members(2));
+    }
+
+    #[test]
+    fn test_migrate_rejects_current_newer_and_foreign_accounts() {
+        let mut lottery_state = LotteryState {
+            schema_version: CURRENT_SCHEMA_VERSION,
+            ..Default::default()
+        };
+        let mut data = Vec::new();
+        lottery_state.try_serialize(&mut data).unwrap();
+        data.resize(LOTTERY_STATE_SIZE, 0);
+        assert!(migrate_lottery_state_data(&data).is_err());
+
+        lottery_state.schema_version = CURRENT_SCHEMA_VERSION + 1;
+        let mut data = Vec::new();
+        lottery_state.try_serialize(&mut data).unwrap();
+        data.resize(LOTTERY_STATE_SIZE, 0);
+        assert!(migrate_lottery_state_data(&data).is_err());
+
+        // A v0 ticket is not a draw result
+        let mut ticket = TicketData::DISCRIMINATOR.to_vec();
+        ticket.resize(LEGACY_TICKET_SIZE, 0);
+        assert!(migrate_draw_result_data(&ticket).is_err());
+    }
+}
@@ -0,0 +1,242 @@
+//! Milestone Bonus Instructions
+//!
+//! The authority can attach a bonus to each of five jackpot milestones
+//! (`milestone_thresholds` / `milestone_prizes`, set through the config
+//! timelock). When a `buy_ticket` or `buy_bulk` takes the jackpot past a
+//! milestone, the bonus unlocks and the first player to claim it is paid
+//! from the reserve. Bonuses unlock again after the jackpot is won or
+//! rolled down.
+//!
+//! - fund_milestone_prize_pool: Deposit USDC into the reserve backing bonuses (authority only)
+//! - claim_milestone_bonus: Claim an unlocked bonus (anyone, first come first served)
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::MilestoneBonusClaimed;
+use crate::state::{LotteryState, SchemaVersioned};
+
+// ============================================================================
+// FUND MILESTONE PRIZE POOL
+// ============================================================================
+
+/// Accounts required for funding milestone bonuses
+#[derive(Accounts)]
+pub struct FundMilestonePrizePool<'info> {
+    /// The lottery authority
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// Authority's USDC token account (source of funds)
+    #[account(
+        mut,
+        constraint = authority_usdc.owner == authority.key() @ LottoError::TokenAccountOwnerMismatch,
+        constraint = authority_usdc.mint == usdc_mint.key() @ LottoError::InvalidUsdcMint
+    )]
+    pub authority_usdc: Account<'info, TokenAccount>,
+
+    /// Prize pool USDC token account (holds the reserve)
+    #[account(
+        mut,
+        seeds = [PRIZE_POOL_USDC_SEED],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+
+    /// USDC mint
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Deposit `amount` into the reserve that pays milestone bonuses
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `amount` - USDC lamports to deposit
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_fund_milestone_prize_pool(
+    ctx: Context<FundMilestonePrizePool>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, LottoError::InvalidAmount);
+    require!(
+        ctx.accounts.authority_usdc.amount >= amount,
+        LottoError::InsufficientFunds
+    );
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.authority_usdc.to_account_info(),
+        to: ctx.accounts.prize_pool_usdc.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    lottery_state.reserve_balance = lottery_state
+        .reserve_balance
+        .checked_add(amount)
+        .ok_or(LottoError::Overflow)?;
+
+    msg!("Milestone prize pool funded!");
+    msg!("  Amount: {} USDC lamports", amount);
+    msg!(
+        "  Reserve balance: {} USDC lamports",
+        lottery_state.reserve_balance
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// CLAIM MILESTONE BONUS
+// ============================================================================
+
+/// Accounts required for claiming a milestone bonus
+#[derive(Accounts)]
+pub struct ClaimMilestoneBonus<'info> {
+    /// The claimer (receives the bonus)
+    pub claimer: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = !lottery_state.is_paused @ LottoError::Paused,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// Claimer's USDC token account
+    #[account(
+        mut,
+        constraint = claimer_usdc.owner == claimer.key() @ LottoError::TokenAccountOwnerMismatch,
+        constraint = claimer_usdc.mint == prize_pool_usdc.mint @ LottoError::InvalidUsdcMint
+    )]
+    pub claimer_usdc: Account<'info, TokenAccount>,
+
+    /// Prize pool USDC token account (holds the reserve)
+    #[account(
+        mut,
+        seeds = [PRIZE_POOL_USDC_SEED],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Claim the bonus of milestone `index`
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `index` - Milestone index (0-4)
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_claim_milestone_bonus(ctx: Context<ClaimMilestoneBonus>, index: u8) -> Result<()> {
+    let index = index as usize;
+    require!(
+        index < JACKPOT_MILESTONE_COUNT,
+        LottoError::InvalidMilestoneIndex
+    );
+
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    require!(
+        lottery_state.is_milestone_bonus_claimable(index),
+        LottoError::MilestoneBonusNotClaimable
+    );
+    let amount = lottery_state.milestone_prizes[index];
+    require!(
+        lottery_state.reserve_balance >= amount,
+        LottoError::InsufficientReserveForMilestoneBonus
+    );
+    require!(
+        ctx.accounts.prize_pool_usdc.amount >= amount,
+        LottoError::InsufficientPrizePool
+    );
+
+    lottery_state.reserve_balance = lottery_state
+        .reserve_balance
+        .checked_sub(amount)
+        .ok_or(LottoError::Underflow)?;
+    lottery_state.milestone_claimed[index] = true;
+
+    let seeds = &[LOTTERY_SEED, &[lottery_state.bump]];
+    let signer_seeds = &[&seeds[..]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.prize_pool_usdc.to_account_info(),
+        to: ctx.accounts.claimer_usdc.to_account_info(),
+        authority: ctx.accounts.lottery_state.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::transfer(cpi_ctx, amount)?;
+
+    let claimer = ctx.accounts.claimer.key();
+    emit!(MilestoneBonusClaimed {
+        index: index as u8,
+        claimer,
+        amount,
+    });
+
+    msg!("Milestone bonus claimed!");
+    msg!("  Milestone: {}", index);
+    msg!("  Claimer: {}", claimer);
+    msg!("  Amount: {} USDC lamports", amount);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_milestone_bonus_claimable() {
+        let mut lottery_state = LotteryState {
+            jackpot_balance: 250,
+            milestone_thresholds: [100, 200, 300, 400, 500],
+            milestone_prizes: [10, 0, 30, 40, 50],
+            milestone_unlocked: 0b00011,
+            ..Default::default()
+        };
+        assert!(lottery_state.is_milestone_bonus_claimable(0));
+        // No bonus attached
+        assert!(!lottery_state.is_milestone_bonus_claimable(1));
+        // Not unlocked yet
+        assert!(!lottery_state.is_milestone_bonus_claimable(2));
+        assert!(!lottery_state.is_milestone_bonus_claimable(JACKPOT_MILESTONE_COUNT));
+
+        // A jackpot at the threshold is not enough without an unlock
+        lottery_state.jackpot_balance = 350;
+        assert!(!lottery_state.is_milestone_bonus_claimable(2));
+
+        // An unlocked bonus stays claimable after the jackpot falls back
+        lottery_state.jackpot_balance = 50;
+        assert!(lottery_state.is_milestone_bonus_claimable(0));
+
+        lottery_state.milestone_claimed[0] = true;
+        assert!(!lottery_state.is_milestone_bonus_claimable(0));
+    }
+}
@@ -11,65 +11,256 @@ pub mod initialize;
 // Ticket purchase
 pub mod buy_ticket;
 
+// Account schema version migration
+pub mod migration;
+
+// House fee recipient split
+pub mod house_fee;
+
 // Bulk ticket purchase
 pub mod buy_bulk;
 
+// Multi-draw ticket purchase
+pub mod multi_draw_ticket;
+
+// Recurring ticket purchase (one ticket per future draw)
+pub mod recurring_ticket;
+
 // Bulk prize claiming
 pub mod claim_bulk_prize;
 
 // Randomness commit (Switchboard integration)
 pub mod commit_randomness;
 
+// Permissionless keeper draw trigger
+pub mod keeper;
+
 // Draw execution (reveal and generate winning numbers)
 pub mod execute_draw;
 
 // Draw finalization (set winner counts and prizes)
 pub mod finalize_draw;
 
+// Recent draw history ring buffer
+pub mod draw_history;
+
+// Reserve history ring buffer and health metrics
+pub mod reserve_history;
+
 // Prize claiming
 pub mod claim_prize;
 
+// Refunds for draws cancelled in refund mode
+pub mod draw_refund;
+
+// Read-only prize previews for unclaimed tickets
+pub mod prize_preview;
+
+// All-time jackpot leaderboard
+pub mod leaderboard;
+
+// Winning number frequency histogram
+pub mod number_frequency;
+
+// Chi-squared bias test of the winning number distribution
+pub mod number_bias;
+
+// Escrowed jackpot prize release
+pub mod prize_escrow;
+
+// Ticket gifting
+pub mod gift_ticket;
+
+// Ticket account closure (rent recovery)
+pub mod close_ticket;
+
+// Referral registration and credit redemption
+pub mod referral;
+
+// Permissionless lottery statistics snapshot
+pub mod lottery_stats;
+
+// Per-wallet daily purchase limit (self-exclusion)
+pub mod daily_limit;
+
+// Purchase whitelist for private betas and geofenced launches
+pub mod whitelist;
+
+// KYC records for compliance-gated purchases
+pub mod kyc;
+
+// Identity proofs for large prize claims
+pub mod identity;
+
+// Versioned lottery terms acceptance
+pub mod terms;
+
+// Challenge window for disputing finalized winner counts
+pub mod draw_challenge;
+
+// Timelocked winning numbers override for VRF interpretation bugs
+pub mod draw_override;
+
+// Lifetime lottery analytics
+pub mod analytics;
+
+// Player lifetime value reporting
+pub mod lifetime_value;
+
+// Two-tier insurance pool (delayed tier 2 withdrawals)
+pub mod insurance_tier2;
+
+// Delegated prize claims
+pub mod claim_agent;
+
+// Mazel Protocol program registry
+pub mod protocol_registry;
+
+// Jackpot milestone bonuses
+pub mod milestone_bonus;
+
+// Lottery state diff events for log subscribers
+pub mod state_diff;
+
+// Operator profit sharing for whitelabel deployments
+pub mod operator_profits;
+
+// Free QuickPick tickets for main lottery Match 5 wins
+pub mod free_quickpick_credit;
+
+// Guaranteed minimum jackpot backed by a dedicated reserve
+pub mod jackpot_guarantee;
+
+// Permissionless re-derivation of winning numbers from stored VRF bytes
+pub mod vrf_audit;
+
+// Program upgrade announcements with a mandatory waiting period
+pub mod program_upgrade;
+
+// Permissionless Switchboard oracle health checks
+pub mod oracle_health;
+
+// Cross-program QuickPick spend gate check
+pub mod spend_gate;
+
 // Syndicate management
 pub mod syndicate;
 
+// Public syndicate registry
+pub mod syndicate_registry;
+
 // Syndicate Wars competition
 pub mod syndicate_wars;
 
+// Second chance draw for non-winning tickets
+pub mod second_chance;
+
+// Winner Merkle root publication and inclusion checks
+pub mod winner_merkle;
+
 // Re-export account structs and params from admin
 pub use admin::{
     AcceptAuthority, CancelAuthorityTransfer, CancelDraw, CheckSolvency, EmergencyFundTransfer,
-    ForceFinalizeDraw, FundSource, Pause, ProposeAuthority, ReclaimExpiredPrizes,
-    ReclaimExpiredPrizesParams, TransferAuthority, Unpause, UpdateConfig, UpdateConfigParams,
-    WithdrawHouseFees,
+    ForceFinalizeDraw, FundSource, Pause, PostponeDraw, ProposeAuthority, ReclaimExpiredPrizes,
+    ReclaimExpiredPrizesParams, ReconcilePrizePool, SetMultisigCosigner, SetOperator,
+    TransferAuthority, Unpause, UpdateConfig, UpdateConfigParams, WithdrawHouseFees,
 };
 
 // Re-export account structs and params from initialize
-pub use initialize::{AddReserveFunds, FundSeed, Initialize, InitializeParams};
+pub use initialize::{AddReserveFunds, FundSeed, Initialize, InitializeParams, ReseedJackpot};
+
+// Re-export account structs from schema migration
+pub use migration::{MigrateAccount, MigrateLotteryState};
 
 // Re-export account structs and params from ticket operations
+pub use analytics::GetAnalytics;
 pub use buy_bulk::{BuyBulk, BuyBulkParams};
-pub use buy_ticket::{BuyTicket, BuyTicketParams};
-pub use claim_bulk_prize::{ClaimAllBulkPrizes, ClaimBulkPrize, ClaimBulkPrizeParams};
-pub use claim_prize::ClaimPrize;
+pub use buy_ticket::{BuyLuckyDip, BuyTicket, BuyTicketParams};
+pub use claim_agent::{AuthorizeClaimAgent, DeauthorizeClaimAgent};
+pub use claim_bulk_prize::{
+    ClaimAllBulkPrizes, ClaimBatchPrizesChunk, ClaimBulkPrize, ClaimBulkPrizeParams,
+};
+pub use claim_prize::{
+    ClaimMultiDrawPrizes, ClaimMultiDrawTicketPrize, ClaimPrize, ClaimPrizeWithIdentity,
+};
+pub use close_ticket::{
+    CleanupExpiredTicketBatches, CleanupExpiredTickets, CloseTicket, CloseTicketBatch,
+};
+pub use daily_limit::{SelfExclude, SetPersonalDailyLimit};
+pub use draw_challenge::{
+    ChallengeWinnerCount, CorrectChallengedWinnerCounts, ResolveDrawChallenge,
+};
+pub use draw_override::{
+    CancelWinningNumbersOverride, OverrideWinningNumbers, ProposeWinningNumbersOverride,
+};
+pub use draw_refund::{
+    ClaimCancelledDrawBulkRefund, ClaimCancelledDrawMultiDrawRefund, ClaimCancelledDrawRefund,
+};
+pub use free_quickpick_credit::RedeemFreeQuickPickCredit;
+pub use gift_ticket::{GiftTicket, GiftTicketBatch};
+pub use house_fee::{InitializeHouseFeeSubAccount, SetHouseFeeRecipients};
+pub use identity::IssueIdentityProof;
+pub use insurance_tier2::{ExecuteTier2Withdrawal, RequestTier2Withdrawal};
+pub use jackpot_guarantee::{FundJackpotGuarantee, WithdrawGuaranteeReserve};
+pub use kyc::{ApproveKYC, RevokeKYC};
+pub use leaderboard::{GetJackpotATH, GetLeaderboard};
+pub use lifetime_value::GetPlayerLifetimeValue;
+pub use milestone_bonus::{ClaimMilestoneBonus, FundMilestonePrizePool};
+pub use multi_draw_ticket::BuyMultiDrawTicket;
+pub use number_bias::InitializeBiasDetector;
+pub use number_frequency::GetNumberFrequencies;
+pub use operator_profits::{DistributeOperatorProfits, SetOperatorProfitShare};
+pub use oracle_health::CheckOracleHealth;
+pub use prize_escrow::ReleaseEscrowPrize;
+pub use prize_preview::{PreviewBatchPrizes, PreviewPrize};
+pub use program_upgrade::{AnnounceProgramUpgrade, ClearUpgradeAnnouncement, VerifyUpgradeHash};
+pub use protocol_registry::{DeactivateProgram, RegisterProgram, VerifyProgramRegistered};
+pub use recurring_ticket::BuyRecurringTicket;
+pub use referral::{RedeemReferralCredit, RegisterReferral};
+pub use second_chance::{
+    AddSecondChancePrize, EnterSecondChance, ExecuteSecondChanceDraw, ReclaimSecondChancePrize,
+};
+pub use spend_gate::VerifySpendGate;
+pub use terms::{AcceptTerms, UpdateTerms};
+pub use vrf_audit::AuditVrfResult;
+pub use whitelist::{AddToWhitelist, DisableWhitelist, RemoveFromWhitelist, SetWhitelistMode};
 
 // Re-export account structs from randomness and draw operations
-pub use commit_randomness::CommitRandomness;
+pub use commit_randomness::{CommitRandomness, VerifyDrawCommitment};
+pub use draw_history::{GetDrawHistory, InitializeDrawHistory};
 pub use execute_draw::ExecuteDraw;
 pub use finalize_draw::{FinalizeDraw, FinalizeDrawParams};
+pub use keeper::{
+    FundKeeperReserve, KeeperCommitRandomness, SetKeeperGracePeriod, TriggerDrawIfReady,
+};
+pub use reserve_history::{
+    ComputeReserveHealth, InitializeReserveHistory, RebalanceReserveToJackpot,
+};
+pub use winner_merkle::{SubmitWinnerMerkleRoot, VerifyTicketMerkleInclusion};
 
 // Re-export account structs and params from syndicate operations
 pub use syndicate::{
     BuySyndicateTickets, BuySyndicateTicketsParams, ClaimSyndicateMemberPrize,
-    ClaimSyndicateMemberPrizeParams, CloseSyndicate, CreateSyndicate, CreateSyndicateParams,
-    CreateSyndicateTicket, DistributeSyndicatePrize, DistributeSyndicatePrizeParams, JoinSyndicate,
-    JoinSyndicateParams, LeaveSyndicate, RemoveSyndicateMember, RemoveSyndicateMemberParams,
-    TransferSyndicateCreator, TransferSyndicateCreatorParams, UpdateSyndicateConfig,
-    UpdateSyndicateConfigParams, WithdrawCreatorContribution,
+    ClaimSyndicateMemberPrizeParams, CloseSyndicate, CreateSyndicate, CreateSyndicateInvite,
+    CreateSyndicateParams, CreateSyndicateTicket, CreateSyndicateTicketsBatch, ExecuteFeeProposal,
+    FinalizeStagedPrize, JoinSyndicate, JoinSyndicateParams, JoinSyndicateWaitlist, LeaveSyndicate,
+    LeaveWaitlist, PartialWithdrawMemberContribution, RejectFeeProposal,
+    ReleaseSyndicateEscrowPrize, RemoveSyndicateMember, RemoveSyndicateMemberParams,
+    StageSyndicatePrize, SyndicateWarsAutoRegisterParams, TransferSyndicateCreator,
+    TransferSyndicateCreatorParams, UnlockSyndicate, UnlockSyndicateAfterForceFinalize,
+    UpdateSyndicateConfig, UpdateSyndicateConfigParams, WithdrawCreatorContribution,
 };
 
+// Re-export account structs from the lottery stats snapshot
+pub use lottery_stats::SnapshotLotteryStats;
+
+// Re-export account structs from the syndicate registry
+pub use syndicate_registry::{GetPublicSyndicates, InitializeSyndicateRegistry};
+
 // Re-export account structs and params from syndicate wars operations
 pub use syndicate_wars::{
-    ClaimSyndicateWarsPrize, ClaimSyndicateWarsPrizeParams, DistributeSyndicateWarsPrizes,
+    AdvanceWarsBracket, ClaimFinalsBracketPrize, ClaimSyndicateWarsPrize,
+    ClaimSyndicateWarsPrizeParams, ComputeWarsScoreOnChain, DistributeSyndicateWarsPrizes,
     DistributeSyndicateWarsPrizesParams, FinalizeSyndicateWars, InitializeSyndicateWars,
     InitializeSyndicateWarsParams, RegisterForSyndicateWars, UpdateSyndicateWarsStats,
     UpdateSyndicateWarsStatsParams,
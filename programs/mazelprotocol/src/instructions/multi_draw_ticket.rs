@@ -0,0 +1,530 @@
+//! Buy Multi-Draw Ticket Instruction
+//!
+//! A multi-draw ticket plays the same numbers in `draw_count` consecutive
+//! draws, starting with the current one, for the effective ticket price
+//! times `draw_count`.
+//! The full price is allocated to the pools at purchase time, exactly as a
+//! bulk purchase of the same size would be. Prizes are claimed per draw with
+//! `claim_multi_draw_ticket_prize`, which tracks claimed draws in the
+//! ticket's `claimed_draws` bitmask.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::MultiDrawTicketPurchased;
+use crate::instructions::buy_ticket::{
+    check_numbers_entropy, check_ticket_sale_cutoff, emit_jackpot_milestones, record_jackpot_ath,
+    resolve_ticket_price, route_jackpot_overflow, unlock_milestone_bonuses,
+};
+use crate::instructions::daily_limit::{
+    enforce_daily_limit, enforce_purchase_velocity, enforce_self_exclusion,
+    enforce_wallet_draw_limit,
+};
+use crate::instructions::house_fee::{
+    replenish_insurance_from_house_fee, route_house_fee, HouseFeeDestination, HouseFeeSource,
+};
+use crate::instructions::kyc::enforce_kyc;
+use crate::instructions::referral::credit_referrer_if_qualified;
+use crate::instructions::terms::enforce_terms_accepted;
+use crate::instructions::whitelist::enforce_whitelist;
+use crate::state::{
+    HouseFeeRecipients, KYCRecord, LotteryState, MultiDrawTicket, SchemaVersioned, UserStats,
+    Whitelist,
+};
+
+/// Accounts required for buying a multi-draw ticket
+#[derive(Accounts)]
+pub struct BuyMultiDrawTicket<'info> {
+    /// The player purchasing the ticket
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// The main lottery state account (boxed to keep the stack frame small)
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = !lottery_state.is_paused @ LottoError::Paused,
+        constraint = lottery_state.is_funded @ LottoError::LotteryNotInitialized,
+        constraint = !lottery_state.is_draw_in_progress @ LottoError::DrawInProgress,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Box<Account<'info, LotteryState>>,
+
+    /// The multi-draw ticket account to be created
+    #[account(
+        init,
+        payer = player,
+        space = MULTI_DRAW_TICKET_SIZE,
+        seeds = [
+            MULTI_DRAW_TICKET_SEED,
+            player.key().as_ref(),
+            &lottery_state.current_draw_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub multi_draw_ticket: Account<'info, MultiDrawTicket>,
+
+    /// Player's USDC token account
+    #[account(
+        mut,
+        constraint = player_usdc.owner == player.key() @ LottoError::TokenAccountOwnerMismatch,
+        constraint = player_usdc.mint == usdc_mint.key() @ LottoError::InvalidUsdcMint
+    )]
+    pub player_usdc: Account<'info, TokenAccount>,
+
+    /// Prize pool USDC token account
+    #[account(
+        mut,
+        seeds = [PRIZE_POOL_USDC_SEED],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+
+    /// House fee USDC token account
+    #[account(
+        mut,
+        seeds = [HOUSE_FEE_USDC_SEED],
+        bump
+    )]
+    pub house_fee_usdc: Account<'info, TokenAccount>,
+
+    /// Insurance tier 1 USDC token account
+    #[account(
+        mut,
+        seeds = [INSURANCE_TIER1_SEED],
+        bump
+    )]
+    pub insurance_pool_usdc: Account<'info, TokenAccount>,
+
+    /// Insurance tier 2 USDC token account
+    #[account(
+        mut,
+        seeds = [INSURANCE_TIER2_SEED],
+        bump
+    )]
+    pub insurance_tier2_usdc: Box<Account<'info, TokenAccount>>,
+
+    /// USDC mint
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// User statistics account
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = USER_STATS_SIZE,
+        seeds = [USER_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Account<'info, UserStats>,
+
+    /// Referrer's user statistics account (required only when this purchase
+    /// makes a referred player qualify; see `register_referral`)
+    #[account(
+        mut,
+        constraint = user_stats.referrer == Some(referrer_stats.wallet) @ LottoError::InvalidReferrer,
+        constraint = referrer_stats.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub referrer_stats: Option<Account<'info, UserStats>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// House fee split config (required while a split is active; the
+    /// recipient sub-accounts are passed as remaining_accounts)
+    #[account(
+        seeds = [FEE_RECIPIENTS_SEED],
+        bump = fee_recipients.bump
+    )]
+    pub fee_recipients: Option<Account<'info, HouseFeeRecipients>>,
+
+    /// Purchase whitelist (required while `whitelist_mode` is not None)
+    #[account(
+        seeds = [WHITELIST_SEED],
+        bump = whitelist.bump
+    )]
+    pub whitelist: Option<Account<'info, Whitelist>>,
+
+    /// Player's token account for the whitelist gate mint (required in
+    /// TokenGated mode)
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Player's KYC record (required while `compliance_mode` is KYC)
+    #[account(
+        seeds = [KYC_SEED, player.key().as_ref()],
+        bump = kyc_record.bump
+    )]
+    pub kyc_record: Option<Account<'info, KYCRecord>>,
+}
+
+impl<'info> BuyMultiDrawTicket<'info> {
+    /// Transfer USDC from player to a pool token account
+    fn transfer_from_player(&self, to: &Account<'info, TokenAccount>, amount: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: self.player_usdc.to_account_info(),
+            to: to.to_account_info(),
+            authority: self.player.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, amount)
+    }
+
+    /// Transfer USDC from player to the house fee account(s)
+    fn transfer_to_house_fee(
+        &self,
+        amount: u64,
+        sub_accounts: &[AccountInfo<'info>],
+        program_id: &Pubkey,
+    ) -> Result<()> {
+        route_house_fee(
+            &HouseFeeSource {
+                from: self.player_usdc.to_account_info(),
+                authority: self.player.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                signer_seeds: &[],
+            },
+            &HouseFeeDestination {
+                house_fee_usdc: self.house_fee_usdc.to_account_info(),
+                fee_recipients: self.fee_recipients.as_deref(),
+                sub_accounts,
+            },
+            self.lottery_state.house_fee_split_active,
+            amount,
+            self.lottery_state.current_draw_id,
+            program_id,
+        )
+    }
+}
+
+/// Buy a ticket valid for `draw_count` consecutive draws
+///
+/// This instruction:
+/// 1. Validates the draw count (2-52) and the selected numbers
+/// 2. Verifies ticket sales are open for the current draw
+/// 3. Charges the effective ticket price times `draw_count`, split across
+///    the prize pool,
+///    house fee and insurance pool like a bulk purchase
+/// 4. Creates the multi-draw ticket for draws
+///    `current_draw_id..=current_draw_id + draw_count - 1`
+/// 5. Updates user statistics and lottery state
+///
+/// The ticket counts as one entry towards the current draw's ticket count
+/// and the per-draw wallet limits, and as `draw_count` tickets towards
+/// lifetime totals and the daily and hourly velocity limits.
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+/// * `numbers` - 6 numbers, each between 1 and 46
+/// * `draw_count` - Number of consecutive draws the ticket is valid for
+/// * `max_price_usdc` - Highest per-draw ticket price the player accepts
+///   (slippage protection for dynamic pricing)
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BuyMultiDrawTicket<'info>>,
+    numbers: [u8; NUMBERS_PER_TICKET],
+    draw_count: u8,
+    max_price_usdc: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let player_key = ctx.accounts.player.key();
+
+    require!(
+        is_valid_multi_draw_count(draw_count),
+        LottoError::InvalidMultiDrawCount
+    );
+    require!(
+        validate_lottery_numbers(&numbers),
+        LottoError::InvalidNumbers
+    );
+    check_numbers_entropy(
+        ctx.accounts.lottery_state.entropy_validation_enabled,
+        player_key,
+        &numbers,
+    )?;
+
+    let mut sorted_numbers = numbers;
+    sorted_numbers.sort();
+
+    let ticket_price = resolve_ticket_price(&ctx.accounts.lottery_state, max_price_usdc)?;
+    let current_draw_id = ctx.accounts.lottery_state.current_draw_id;
+    let soft_cap = ctx.accounts.lottery_state.soft_cap;
+    let house_fee_bps = ctx.accounts.lottery_state.get_current_house_fee_bps();
+
+    // Check if ticket sales are open
+    check_ticket_sale_cutoff(&ctx.accounts.lottery_state, clock.unix_timestamp)?;
+    enforce_whitelist(
+        ctx.accounts.lottery_state.whitelist_mode,
+        ctx.accounts.whitelist.as_deref(),
+        ctx.accounts.gate_token_account.as_deref(),
+        &player_key,
+    )?;
+    enforce_kyc(
+        ctx.accounts.lottery_state.compliance_mode,
+        ctx.accounts.kyc_record.as_deref(),
+        &player_key,
+        clock.unix_timestamp,
+    )?;
+    require!(
+        ctx.accounts.lottery_state.jackpot_balance >= ctx.accounts.lottery_state.seed_amount,
+        LottoError::InsufficientJackpotFunding
+    );
+
+    // Enforce per-user ticket limit for the current draw
+    let user_tickets_this_draw =
+        if ctx.accounts.user_stats.last_draw_participated == current_draw_id {
+            ctx.accounts.user_stats.tickets_this_draw
+        } else {
+            0
+        };
+    require!(
+        user_tickets_this_draw < MAX_TICKETS_PER_DRAW_PER_USER,
+        LottoError::MaxTicketsPerDrawExceeded
+    );
+
+    // Fund allocation (same split as buy_bulk, scaled by draw_count)
+    let total_price = ticket_price
+        .checked_mul(draw_count as u64)
+        .ok_or(LottoError::Overflow)?;
+    let house_fee = (total_price as u128 * house_fee_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+    let after_house_fee = total_price.saturating_sub(house_fee);
+    let insurance_contribution = (after_house_fee as u128 * INSURANCE_ALLOCATION_BPS as u128
+        / BPS_DENOMINATOR as u128) as u64;
+    let prize_pool_transfer = after_house_fee.saturating_sub(insurance_contribution);
+    let jackpot_contribution = (prize_pool_transfer as u128 * JACKPOT_ALLOCATION_BPS as u128
+        / BPS_DENOMINATOR as u128) as u64;
+    let fixed_prize_contribution = (prize_pool_transfer as u128
+        * FIXED_PRIZE_ALLOCATION_BPS as u128
+        / BPS_DENOMINATOR as u128) as u64;
+    let reserve_contribution = prize_pool_transfer
+        .saturating_sub(jackpot_contribution)
+        .saturating_sub(fixed_prize_contribution);
+
+    require!(
+        ctx.accounts.player_usdc.amount >= total_price,
+        LottoError::InsufficientFunds
+    );
+
+    // Part of the house fee tops up the insurance pool while it is below minimum
+    let insurance_replenishment =
+        replenish_insurance_from_house_fee(&mut ctx.accounts.lottery_state, house_fee)?;
+    let house_fee_transfer = house_fee - insurance_replenishment;
+    let (insurance_tier1_contribution, insurance_tier2_transfer) =
+        split_insurance_contribution(insurance_contribution);
+    let insurance_transfer = insurance_tier1_contribution + insurance_replenishment;
+
+    ctx.accounts
+        .transfer_from_player(&ctx.accounts.prize_pool_usdc, prize_pool_transfer)?;
+    ctx.accounts.transfer_to_house_fee(
+        house_fee_transfer,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+    if insurance_transfer > 0 {
+        ctx.accounts
+            .transfer_from_player(&ctx.accounts.insurance_pool_usdc, insurance_transfer)?;
+    }
+    if insurance_tier2_transfer > 0 {
+        ctx.accounts
+            .transfer_from_player(&ctx.accounts.insurance_tier2_usdc, insurance_tier2_transfer)?;
+    }
+
+    require!(
+        house_fee_transfer + prize_pool_transfer + insurance_transfer + insurance_tier2_transfer
+            == total_price,
+        LottoError::SafetyCheckFailed
+    );
+
+    // Update lottery state with internal accounting
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    let old_jackpot_balance = lottery_state.jackpot_balance;
+    lottery_state.jackpot_balance = lottery_state
+        .jackpot_balance
+        .checked_add(jackpot_contribution)
+        .ok_or(LottoError::Overflow)?;
+    route_jackpot_overflow(lottery_state)?;
+    lottery_state.reserve_balance = lottery_state
+        .reserve_balance
+        .checked_add(reserve_contribution)
+        .ok_or(LottoError::Overflow)?;
+    lottery_state.credit_insurance(insurance_contribution)?;
+    lottery_state.fixed_prize_balance = lottery_state
+        .fixed_prize_balance
+        .checked_add(fixed_prize_contribution)
+        .ok_or(LottoError::Overflow)?;
+    lottery_state.current_draw_tickets = lottery_state
+        .current_draw_tickets
+        .checked_add(1)
+        .ok_or(LottoError::Overflow)?;
+    lottery_state.total_tickets_sold = lottery_state
+        .total_tickets_sold
+        .checked_add(draw_count as u64)
+        .ok_or(LottoError::Overflow)?;
+    lottery_state.house_fee_bps = lottery_state.get_current_house_fee_bps();
+
+    if lottery_state.jackpot_balance >= soft_cap && !lottery_state.is_rolldown_active {
+        lottery_state.is_rolldown_active = true;
+        msg!(
+            "🎰 ROLLDOWN ACTIVATED! Jackpot {} >= Soft Cap {}",
+            lottery_state.jackpot_balance,
+            soft_cap
+        );
+    }
+
+    emit_jackpot_milestones(lottery_state);
+    unlock_milestone_bonuses(lottery_state, old_jackpot_balance);
+    record_jackpot_ath(lottery_state, clock.unix_timestamp);
+
+    let daily_limit_enabled = lottery_state.daily_limit_enabled;
+
+    // Create the multi-draw ticket
+    let end_draw_id = current_draw_id
+        .checked_add(draw_count as u64 - 1)
+        .ok_or(LottoError::Overflow)?;
+    let multi_draw_ticket = &mut ctx.accounts.multi_draw_ticket;
+    multi_draw_ticket.owner = player_key;
+    multi_draw_ticket.start_draw_id = current_draw_id;
+    multi_draw_ticket.end_draw_id = end_draw_id;
+    multi_draw_ticket.numbers = sorted_numbers;
+    multi_draw_ticket.claimed_draws = 0;
+    multi_draw_ticket.purchase_timestamp = clock.unix_timestamp;
+    multi_draw_ticket.bump = ctx.bumps.multi_draw_ticket;
+
+    // Update user stats
+    let user_stats = &mut ctx.accounts.user_stats;
+
+    // Initialize if new
+    if user_stats.wallet == Pubkey::default() {
+        user_stats.schema_version = CURRENT_SCHEMA_VERSION;
+        user_stats.wallet = player_key;
+        user_stats.bump = ctx.bumps.user_stats;
+        user_stats.tickets_this_draw = 0;
+        user_stats.last_draw_participated = 0;
+        user_stats.daily_ticket_limit = DEFAULT_DAILY_TICKET_LIMIT;
+        user_stats.join_timestamp = clock.unix_timestamp;
+    }
+    user_stats.require_current_schema()?;
+
+    enforce_self_exclusion(user_stats, clock.unix_timestamp)?;
+    enforce_terms_accepted(lottery_state.terms_version, user_stats)?;
+
+    enforce_daily_limit(
+        daily_limit_enabled,
+        user_stats,
+        draw_count as u32,
+        clock.unix_timestamp,
+    )?;
+
+    enforce_purchase_velocity(
+        lottery_state.max_tickets_per_wallet_per_hour,
+        user_stats,
+        draw_count as u32,
+        clock.unix_timestamp,
+    )?;
+
+    enforce_wallet_draw_limit(
+        lottery_state.max_tickets_per_wallet_per_draw,
+        user_stats,
+        1,
+        current_draw_id,
+    )?;
+
+    if user_stats.last_draw_participated != current_draw_id {
+        user_stats.tickets_this_draw = 1;
+        user_stats.last_draw_participated = current_draw_id;
+    } else {
+        user_stats.tickets_this_draw = user_stats
+            .tickets_this_draw
+            .checked_add(1)
+            .ok_or(LottoError::Overflow)?;
+    }
+
+    let previous_total_tickets = user_stats.total_tickets;
+    user_stats.total_tickets = user_stats
+        .total_tickets
+        .checked_add(draw_count as u64)
+        .ok_or(LottoError::Overflow)?;
+    user_stats.total_spent = user_stats
+        .total_spent
+        .checked_add(total_price)
+        .ok_or(LottoError::Overflow)?;
+    user_stats.update_streak(current_draw_id);
+    user_stats.record_draw_played(current_draw_id)?;
+
+    credit_referrer_if_qualified(
+        user_stats,
+        previous_total_tickets,
+        ctx.accounts.referrer_stats.as_mut(),
+    )?;
+
+    emit!(MultiDrawTicketPurchased {
+        player: player_key,
+        draw_count,
+        total_price,
+    });
+
+    msg!("Multi-draw ticket purchased successfully!");
+    msg!("  Player: {}", player_key);
+    msg!("  Numbers: {:?}", sorted_numbers);
+    msg!("  Draws: {} - {}", current_draw_id, end_draw_id);
+    msg!("  Total price: {} USDC lamports", total_price);
+    msg!(
+        "  House fee ({}bps): {} USDC lamports",
+        house_fee_bps,
+        house_fee
+    );
+    msg!(
+        "  Prize pool transfer: {} USDC lamports",
+        prize_pool_transfer
+    );
+    msg!(
+        "  Insurance contribution: {} USDC lamports",
+        insurance_contribution
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multi_draw_ticket_claimed_draws() {
+        assert!(!is_valid_multi_draw_count(1));
+        assert!(is_valid_multi_draw_count(MIN_MULTI_DRAW_COUNT));
+        assert!(is_valid_multi_draw_count(MAX_MULTI_DRAW_COUNT));
+        assert!(!is_valid_multi_draw_count(MAX_MULTI_DRAW_COUNT + 1));
+
+        let mut ticket = MultiDrawTicket {
+            start_draw_id: 10,
+            end_draw_id: 10 + MAX_MULTI_DRAW_COUNT as u64 - 1,
+            ..Default::default()
+        };
+
+        assert!(!ticket.covers_draw(9));
+        assert!(ticket.covers_draw(10));
+        assert!(ticket.covers_draw(ticket.end_draw_id));
+        assert!(!ticket.covers_draw(ticket.end_draw_id + 1));
+
+        assert!(!ticket.is_draw_claimed(12));
+        assert!(ticket.mark_draw_claimed(12));
+        assert!(ticket.is_draw_claimed(12));
+        assert_eq!(ticket.claimed_draws, 1 << 2);
+
+        let last = ticket.end_draw_id;
+        assert!(ticket.mark_draw_claimed(last));
+        assert!(ticket.is_draw_claimed(last));
+        assert!(!ticket.is_draw_claimed(11));
+
+        // Draws outside the range can never be claimed
+        assert!(ticket.is_draw_claimed(9));
+        assert!(!ticket.mark_draw_claimed(last + 1));
+    }
+}
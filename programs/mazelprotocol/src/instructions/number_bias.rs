@@ -0,0 +1,211 @@
+//! Number Bias Detector Instructions
+//!
+//! A singleton `NumberBiasDetector` counts the winning numbers of every
+//! finalized draw. Every BIAS_TEST_INTERVAL_DRAWS draws `finalize_draw` runs a
+//! simplified chi-squared goodness-of-fit test against a uniform distribution
+//! and raises `StatisticalBiasAlert` when it fails at 5% significance.
+//!
+//! - initialize_bias_detector: Create the detector (authority only)
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{BiasTestPassed, StatisticalBiasAlert};
+use crate::state::{LotteryState, NumberBiasDetector, SchemaVersioned};
+
+/// Outcome of a chi-squared test over the winning number counts
+#[derive(Debug, PartialEq, Eq)]
+pub struct ChiSquaredResult {
+    pub chi_squared_scaled: u64,
+    pub most_biased_number: u8,
+    pub expected_count: u64,
+    pub actual_count: u32,
+}
+
+/// Chi-squared statistic of `win_counts` against a uniform distribution
+///
+/// Each number is expected `total_draws * 6 / 46` times. Counts and the
+/// expectation are scaled by CHI_SQUARED_SCALE, so the sum of
+/// `(count - expected)^2 / expected` comes out scaled by it as well.
+/// Returns `None` before any draw has been recorded.
+pub fn compute_chi_squared(
+    win_counts: &[u32; NUMBER_FREQUENCY_SLOTS],
+    total_draws: u32,
+) -> Option<ChiSquaredResult> {
+    let expected = total_draws as u128 * NUMBERS_PER_TICKET as u128 * CHI_SQUARED_SCALE as u128
+        / MAX_NUMBER as u128;
+    if expected == 0 {
+        return None;
+    }
+
+    let mut chi_squared: u128 = 0;
+    let mut most_biased_number = MIN_NUMBER;
+    let mut largest_contrib: u128 = 0;
+    for number in MIN_NUMBER..=MAX_NUMBER {
+        let observed = win_counts[number as usize] as u128 * CHI_SQUARED_SCALE as u128;
+        let contrib = observed.abs_diff(expected).pow(2) / expected;
+        chi_squared += contrib;
+        if contrib > largest_contrib {
+            largest_contrib = contrib;
+            most_biased_number = number;
+        }
+    }
+
+    Some(ChiSquaredResult {
+        chi_squared_scaled: chi_squared.min(u64::MAX as u128) as u64,
+        most_biased_number,
+        expected_count: (expected / CHI_SQUARED_SCALE as u128) as u64,
+        actual_count: win_counts[most_biased_number as usize],
+    })
+}
+
+/// Run the bias test if the draw just recorded completes an interval
+///
+/// Emits `StatisticalBiasAlert` above CHI_SQUARED_THRESHOLD_5PCT and
+/// `BiasTestPassed` otherwise.
+pub(crate) fn run_bias_test_if_due(detector: &mut NumberBiasDetector, draw_id: u64) {
+    if !detector.is_bias_test_due() {
+        return;
+    }
+    let Some(result) = compute_chi_squared(&detector.win_counts, detector.total_draws) else {
+        return;
+    };
+    detector.last_bias_test_draw_id = draw_id;
+
+    if result.chi_squared_scaled > CHI_SQUARED_THRESHOLD_5PCT {
+        emit!(StatisticalBiasAlert {
+            chi_squared_scaled: result.chi_squared_scaled,
+            most_biased_number: result.most_biased_number,
+            expected_count: result.expected_count,
+            actual_count: result.actual_count,
+            draw_range: detector.total_draws,
+        });
+        msg!(
+            "⚠️  Winning number bias detected over {} draws: chi-squared {} > {}",
+            detector.total_draws,
+            result.chi_squared_scaled,
+            CHI_SQUARED_THRESHOLD_5PCT
+        );
+        msg!(
+            "  Most biased number: {} ({} draws, {} expected)",
+            result.most_biased_number,
+            result.actual_count,
+            result.expected_count
+        );
+    } else {
+        emit!(BiasTestPassed {
+            draw_id,
+            chi_squared_scaled: result.chi_squared_scaled,
+        });
+        msg!(
+            "Bias test passed over {} draws: chi-squared {}",
+            detector.total_draws,
+            result.chi_squared_scaled
+        );
+    }
+}
+
+// ============================================================================
+// INITIALIZE BIAS DETECTOR
+// ============================================================================
+
+/// Accounts required for creating the number bias detector
+#[derive(Accounts)]
+pub struct InitializeBiasDetector<'info> {
+    /// The lottery authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The bias detector to be created (starts with no draws counted)
+    #[account(
+        init,
+        payer = authority,
+        space = NUMBER_BIAS_DETECTOR_SIZE,
+        seeds = [BIAS_DETECTOR_SEED],
+        bump
+    )]
+    pub number_bias_detector: Box<Account<'info, NumberBiasDetector>>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the number bias detector
+///
+/// Must run before the next `finalize_draw`, which counts every finalized
+/// draw's winning numbers.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_initialize_bias_detector(ctx: Context<InitializeBiasDetector>) -> Result<()> {
+    let detector = &mut ctx.accounts.number_bias_detector;
+    detector.bump = ctx.bumps.number_bias_detector;
+    detector.win_counts = [0; NUMBER_FREQUENCY_SLOTS];
+    detector.total_draws = 0;
+    detector.last_bias_test_draw_id = 0;
+
+    msg!("Number bias detector initialized!");
+    msg!("  Detector: {}", detector.key());
+    msg!("  Test interval: {} draws", BIAS_TEST_INTERVAL_DRAWS);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chi_squared_uniform_distribution_is_zero() {
+        assert_eq!(compute_chi_squared(&[0; NUMBER_FREQUENCY_SLOTS], 0), None);
+
+        let mut win_counts = [6; NUMBER_FREQUENCY_SLOTS];
+        win_counts[0] = 0;
+        let result = compute_chi_squared(&win_counts, 46).unwrap();
+        assert_eq!(result.chi_squared_scaled, 0);
+        assert_eq!(result.expected_count, 6);
+    }
+
+    #[test]
+    fn test_chi_squared_flags_skewed_distribution() {
+        // The same six numbers won all 50 draws
+        let mut win_counts = [0; NUMBER_FREQUENCY_SLOTS];
+        for number in [4, 9, 16, 25, 36, 41] {
+            win_counts[number] = 50;
+        }
+        let result = compute_chi_squared(&win_counts, 50).unwrap();
+
+        assert!(result.chi_squared_scaled > CHI_SQUARED_THRESHOLD_5PCT);
+        assert_eq!(result.most_biased_number, 4);
+        assert_eq!(result.actual_count, 50);
+        assert_eq!(result.expected_count, 6);
+    }
+
+    #[test]
+    fn test_bias_test_due_every_interval() {
+        let mut detector = NumberBiasDetector::default();
+        assert!(!detector.is_bias_test_due());
+
+        for _ in 0..BIAS_TEST_INTERVAL_DRAWS {
+            detector.record_draw(&[1, 2, 3, 4, 5, 6]).unwrap();
+        }
+        assert!(detector.is_bias_test_due());
+        assert_eq!(detector.win_counts[6], BIAS_TEST_INTERVAL_DRAWS);
+
+        detector.record_draw(&[1, 2, 3, 4, 5, 6]).unwrap();
+        assert!(!detector.is_bias_test_due());
+    }
+}
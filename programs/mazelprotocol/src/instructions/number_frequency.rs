@@ -0,0 +1,134 @@
+//! Number Frequency Instructions
+//!
+//! A singleton `NumberFrequencyAccount`, created by `initialize`, counts how
+//! often each number from MIN_NUMBER to MAX_NUMBER has been drawn.
+//! `finalize_draw` adds the six winning numbers of every draw.
+//!
+//! - get_number_frequencies: Emit the histogram with hot and cold numbers
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::NumberFrequencySnapshot;
+use crate::state::NumberFrequencyAccount;
+
+/// Accounts required for reading the number frequency histogram
+#[derive(Accounts)]
+pub struct GetNumberFrequencies<'info> {
+    /// The number frequency histogram
+    #[account(
+        seeds = [NUMBER_FREQUENCY_SEED],
+        bump = number_frequency.bump
+    )]
+    pub number_frequency: Box<Account<'info, NumberFrequencyAccount>>,
+}
+
+/// Emit the number frequency histogram
+///
+/// Permissionless and read-only; emits `NumberFrequencySnapshot`.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_get_number_frequencies(ctx: Context<GetNumberFrequencies>) -> Result<()> {
+    let number_frequency = &ctx.accounts.number_frequency;
+    let hot_number = number_frequency.hot_number();
+    let cold_number = number_frequency.cold_number();
+    let expected_frequency = number_frequency.expected_frequency();
+    let total_draws = number_frequency.total_draws_recorded;
+
+    emit!(NumberFrequencySnapshot {
+        frequencies: number_frequency.frequencies,
+        hot_number,
+        cold_number,
+        expected_frequency,
+        total_draws,
+    });
+
+    msg!("Number frequency snapshot");
+    msg!("  Draws recorded: {}", total_draws);
+    msg!(
+        "  Hot number: {} ({} draws)",
+        hot_number,
+        number_frequency.frequencies[hot_number as usize]
+    );
+    msg!(
+        "  Cold number: {} ({} draws)",
+        cold_number,
+        number_frequency.frequencies[cold_number as usize]
+    );
+    msg!("  Expected per number: {}", expected_frequency);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_draw_counts_each_number() {
+        let mut number_frequency = NumberFrequencyAccount::default();
+
+        number_frequency.record_draw(&[1, 2, 3, 4, 5, 46]).unwrap();
+        number_frequency
+            .record_draw(&[2, 3, 10, 20, 30, 46])
+            .unwrap();
+
+        assert_eq!(number_frequency.total_draws_recorded, 2);
+        assert_eq!(number_frequency.frequencies[1], 1);
+        assert_eq!(number_frequency.frequencies[2], 2);
+        assert_eq!(number_frequency.frequencies[46], 2);
+        assert_eq!(number_frequency.frequencies[0], 0);
+        assert_eq!(number_frequency.frequencies.iter().sum::<u32>(), 12);
+    }
+
+    #[test]
+    fn test_record_draw_rejects_out_of_range_numbers() {
+        let mut number_frequency = NumberFrequencyAccount::default();
+
+        assert!(number_frequency.record_draw(&[0, 2, 3, 4, 5, 6]).is_err());
+        assert!(number_frequency.record_draw(&[1, 2, 3, 4, 5, 47]).is_err());
+    }
+
+    #[test]
+    fn test_record_draw_fails_on_overflow() {
+        let mut number_frequency = NumberFrequencyAccount::default();
+        number_frequency.frequencies[7] = u32::MAX;
+
+        assert!(number_frequency.record_draw(&[1, 2, 3, 4, 5, 7]).is_err());
+    }
+
+    #[test]
+    fn test_hot_and_cold_numbers() {
+        let mut number_frequency = NumberFrequencyAccount::default();
+
+        // Empty histogram: every number ties, lowest wins both
+        assert_eq!(number_frequency.hot_number(), MIN_NUMBER);
+        assert_eq!(number_frequency.cold_number(), MIN_NUMBER);
+
+        for number in MIN_NUMBER..=MAX_NUMBER {
+            number_frequency.frequencies[number as usize] = 5;
+        }
+        number_frequency.frequencies[17] = 9;
+        number_frequency.frequencies[33] = 9;
+        number_frequency.frequencies[8] = 1;
+
+        assert_eq!(number_frequency.hot_number(), 17);
+        assert_eq!(number_frequency.cold_number(), 8);
+    }
+
+    #[test]
+    fn test_expected_frequency() {
+        let mut number_frequency = NumberFrequencyAccount::default();
+        assert_eq!(number_frequency.expected_frequency(), 0);
+
+        number_frequency.total_draws_recorded = 46;
+        assert_eq!(number_frequency.expected_frequency(), 6);
+
+        number_frequency.total_draws_recorded = 100;
+        assert_eq!(number_frequency.expected_frequency(), 13);
+    }
+}
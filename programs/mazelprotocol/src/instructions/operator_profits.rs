@@ -0,0 +1,282 @@
+//! Operator Profit Sharing Instructions
+//!
+//! Whitelabel deployments pay the operator a platform fee out of the house
+//! fees. `distribute_operator_profits` splits a house fee account between
+//! the operator (`lottery_state.operator`, or the authority when none is
+//! set) and the authority by `lottery_state.operator_profit_share_bps`,
+//! which only changes through the config timelock. Distributions share the
+//! daily withdrawal cap and cosigner rule of `withdraw_house_fees`.
+//!
+//! While a house fee split is active, each recipient's sub-account belongs
+//! to that recipient and is paid out with `withdraw_house_fees`; only the
+//! original house fee account and sub-accounts of recipients no longer in
+//! the split can be distributed.
+//!
+//! - set_operator_profit_share: Propose a new operator share (authority only)
+//! - distribute_operator_profits: Split the house fees (authority only)
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::OperatorProfitsDistributed;
+use crate::instructions::admin::{
+    house_fee_source_address, propose_config_change, record_house_fee_withdrawal,
+    UpdateConfigParams,
+};
+use crate::state::{HouseFeeRecipients, LotteryState, SchemaVersioned};
+
+/// Split `amount` into (operator share, authority share) at `share_bps`
+pub fn operator_profit_split(amount: u64, share_bps: u16) -> (u64, u64) {
+    let operator_share = (amount as u128 * share_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+    (operator_share, amount - operator_share)
+}
+
+// ============================================================================
+// SET OPERATOR PROFIT SHARE
+// ============================================================================
+
+/// Accounts required for proposing a new operator profit share
+#[derive(Accounts)]
+pub struct SetOperatorProfitShare<'info> {
+    /// The lottery authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = !lottery_state.is_draw_in_progress @ LottoError::DrawInProgress,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+/// Propose `bps` as the operator's share of distributed house fees
+///
+/// Starts the config timelock like `propose_config`; the share applies once
+/// `execute_config` is called with only `operator_profit_share_bps` set.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `bps` - Operator share in basis points (max 10000)
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_set_operator_profit_share(
+    ctx: Context<SetOperatorProfitShare>,
+    bps: u16,
+) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    propose_config_change(
+        &mut ctx.accounts.lottery_state,
+        authority,
+        UpdateConfigParams {
+            operator_profit_share_bps: Some(bps),
+            ..Default::default()
+        },
+    )
+}
+
+// ============================================================================
+// DISTRIBUTE OPERATOR PROFITS
+// ============================================================================
+
+/// Accounts required for distributing house fees to the operator and authority
+#[derive(Accounts)]
+pub struct DistributeOperatorProfits<'info> {
+    /// The lottery authority
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// House fee USDC token account to distribute: the original house fee
+    /// account for HOUSE_FEE_LEGACY_INDEX, otherwise a former recipient's
+    /// sub-account (address verified in the handler)
+    #[account(mut)]
+    pub house_fee_usdc: Account<'info, TokenAccount>,
+
+    /// Operator's USDC token account (the authority's when no operator is set)
+    #[account(
+        mut,
+        constraint = operator_usdc.owner == lottery_state.operator.unwrap_or(lottery_state.authority) @ LottoError::TokenAccountOwnerMismatch,
+        constraint = operator_usdc.mint == house_fee_usdc.mint @ LottoError::InvalidUsdcMint
+    )]
+    pub operator_usdc: Account<'info, TokenAccount>,
+
+    /// Authority's USDC token account
+    #[account(
+        mut,
+        constraint = authority_usdc.owner == authority.key() @ LottoError::TokenAccountOwnerMismatch,
+        constraint = authority_usdc.mint == house_fee_usdc.mint @ LottoError::InvalidUsdcMint
+    )]
+    pub authority_usdc: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// House fee split config (required for recipient sub-account distributions)
+    #[account(
+        seeds = [FEE_RECIPIENTS_SEED],
+        bump = fee_recipients.bump
+    )]
+    pub fee_recipients: Option<Account<'info, HouseFeeRecipients>>,
+
+    /// The multisig cosigner (required once the window's withdrawals exceed
+    /// WITHDRAWAL_COSIGN_THRESHOLD_BPS of the daily cap while
+    /// `lottery_state.multisig_cosigner` is set)
+    pub optional_cosigner: Option<Signer<'info>>,
+}
+
+/// Split a house fee account's balance between the operator and the authority
+///
+/// With a nonzero `daily_withdrawal_cap_bps`, only what is left of the
+/// current window's cap is distributed, and it counts towards the cap like a
+/// `withdraw_house_fees` withdrawal, including the multisig cosigner rule.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `recipient_index` - Former recipient sub-account to distribute, or
+///   HOUSE_FEE_LEGACY_INDEX for the original house fee account
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_distribute_operator_profits(
+    ctx: Context<DistributeOperatorProfits>,
+    recipient_index: u8,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Resolve the source account; an active recipient's fees are theirs
+    let fee_recipients = ctx.accounts.fee_recipients.as_deref();
+    let expected_source =
+        house_fee_source_address(fee_recipients, recipient_index, ctx.program_id)?;
+    require!(
+        ctx.accounts.house_fee_usdc.key() == expected_source,
+        LottoError::InvalidHouseFeeAccounts
+    );
+    require!(
+        fee_recipients
+            .and_then(|fee_recipients| fee_recipients.recipients.get(recipient_index as usize))
+            .is_none(),
+        LottoError::InvalidHouseFeeAccounts
+    );
+
+    let balance = ctx.accounts.house_fee_usdc.amount;
+    require!(balance > 0, LottoError::InsufficientFunds);
+
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    lottery_state.roll_withdrawal_window(clock.unix_timestamp);
+    let total_distributed =
+        match house_fee_withdrawal_cap(balance, lottery_state.daily_withdrawal_cap_bps) {
+            Some(cap) => balance.min(cap.saturating_sub(lottery_state.withdrawals_in_window)),
+            None => balance,
+        };
+    require!(
+        total_distributed > 0,
+        LottoError::DailyWithdrawalCapExceeded
+    );
+    record_house_fee_withdrawal(
+        lottery_state,
+        balance,
+        total_distributed,
+        ctx.accounts.optional_cosigner.as_ref().map(|s| s.key()),
+        clock.unix_timestamp,
+    )?;
+
+    let (operator_amount, authority_amount) =
+        operator_profit_split(total_distributed, lottery_state.operator_profit_share_bps);
+
+    let seeds = &[LOTTERY_SEED, &[ctx.accounts.lottery_state.bump]];
+    let signer_seeds = &[&seeds[..]];
+    for (destination, amount) in [
+        (&ctx.accounts.operator_usdc, operator_amount),
+        (&ctx.accounts.authority_usdc, authority_amount),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.house_fee_usdc.to_account_info(),
+            to: destination.to_account_info(),
+            authority: ctx.accounts.lottery_state.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, amount)?;
+    }
+
+    emit!(OperatorProfitsDistributed {
+        operator_amount,
+        authority_amount,
+        total_distributed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Operator profits distributed");
+    msg!("  Operator: {} USDC lamports", operator_amount);
+    msg!("  Authority: {} USDC lamports", authority_amount);
+    msg!(
+        "  Remaining balance: {} USDC lamports",
+        balance - total_distributed
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operator_profit_split() {
+        assert_eq!(operator_profit_split(1_000_000, 0), (0, 1_000_000));
+        assert_eq!(operator_profit_split(1_000_000, 2500), (250_000, 750_000));
+        assert_eq!(operator_profit_split(1_000_000, 10000), (1_000_000, 0));
+        // Rounding favours the authority
+        assert_eq!(operator_profit_split(3, 5000), (1, 2));
+    }
+
+    #[test]
+    fn test_withdrawal_cosign_counts_the_window_total() {
+        let cosigner = Pubkey::new_unique();
+        let mut lottery_state = LotteryState {
+            daily_withdrawal_cap_bps: 5000,
+            multisig_cosigner: Some(cosigner),
+            ..Default::default()
+        };
+
+        // Cap is 500_000; the first 250_000 stays within the cosign threshold
+        record_house_fee_withdrawal(&mut lottery_state, 1_000_000, 250_000, None, 0).unwrap();
+        // Splitting does not avoid the cosigner once the total passes it
+        let err =
+            record_house_fee_withdrawal(&mut lottery_state, 1_000_000, 1, None, 0).unwrap_err();
+        assert_eq!(err, LottoError::CosignerRequired.into());
+        assert_eq!(lottery_state.withdrawals_in_window, 250_000);
+
+        record_house_fee_withdrawal(&mut lottery_state, 1_000_000, 1, Some(cosigner), 0).unwrap();
+        assert_eq!(lottery_state.withdrawals_in_window, 250_001);
+
+        // A new window starts from zero again
+        record_house_fee_withdrawal(
+            &mut lottery_state,
+            1_000_000,
+            250_000,
+            None,
+            HOUSE_FEE_WITHDRAWAL_WINDOW_SECONDS,
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,261 @@
+//! Oracle Health Instruction
+//!
+//! `check_oracle_health` lets anyone check the lottery's committed
+//! Switchboard randomness account (`current_randomness_account`): it must
+//! belong to the lottery's queue, hold a revealed value, and have
+//! been revealed within `draw_interval + ORACLE_MAX_STALENESS_SECONDS`
+//! (randomness is revealed once per draw). Randomness accounts record
+//! slots rather than times, so staleness is estimated at
+//! APPROX_SLOT_DURATION_MS per slot.
+//!
+//! Failed checks count towards `oracle_consecutive_failures`, at most once
+//! per ORACLE_FAILURE_COUNT_INTERVAL_SECONDS so repeated calls cannot rush
+//! the count. Reaching `max_oracle_failures` pauses the lottery. A healthy
+//! check or an `execute_draw` using the oracle's randomness resets it.
+//!
+//! - check_oracle_health: Check the committed randomness account (anyone)
+
+use anchor_lang::prelude::*;
+use switchboard_on_demand::accounts::RandomnessAccountData;
+use switchboard_on_demand::{ON_DEMAND_DEVNET_PID, ON_DEMAND_MAINNET_PID};
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{EmergencyPause, OracleHealthAlert, OracleHealthReport};
+use crate::state::{LotteryState, SchemaVersioned};
+
+/// Fields of a Switchboard randomness account the health check reads
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RandomnessSnapshot {
+    pub queue: Pubkey,
+    pub reveal_slot: u64,
+    pub value: [u8; 32],
+}
+
+/// Result of checking a randomness account
+#[derive(Debug, PartialEq, Eq)]
+pub struct OracleHealth {
+    pub is_healthy: bool,
+    pub last_update_timestamp: i64,
+    pub staleness_seconds: i64,
+    pub queue_matches: bool,
+    pub failure_reason: Option<&'static str>,
+}
+
+/// Whether `owner` is a Switchboard On-Demand program
+pub fn is_switchboard_program(owner: &Pubkey) -> bool {
+    let owner = owner.to_bytes();
+    owner == ON_DEMAND_MAINNET_PID.to_bytes() || owner == ON_DEMAND_DEVNET_PID.to_bytes()
+}
+
+/// Check `snapshot` against the lottery's queue and staleness limit
+///
+/// The failure reason is the first failed check.
+pub fn assess_oracle_health(
+    snapshot: &RandomnessSnapshot,
+    expected_queue: &Pubkey,
+    current_slot: u64,
+    now: i64,
+    max_staleness_seconds: i64,
+) -> OracleHealth {
+    let staleness_slots = current_slot.saturating_sub(snapshot.reveal_slot);
+    let staleness_seconds = (staleness_slots as u128 * APPROX_SLOT_DURATION_MS as u128 / 1000)
+        .min(i64::MAX as u128) as i64;
+    let last_update_timestamp = now.saturating_sub(staleness_seconds).max(0);
+    let queue_matches = snapshot.queue == *expected_queue;
+
+    let failure_reason = if !queue_matches {
+        Some("randomness account is not on the lottery's queue")
+    } else if snapshot.value == [0u8; 32] {
+        Some("randomness value is empty")
+    } else if staleness_seconds > max_staleness_seconds {
+        Some("randomness is stale")
+    } else {
+        None
+    };
+
+    OracleHealth {
+        is_healthy: failure_reason.is_none(),
+        last_update_timestamp,
+        staleness_seconds,
+        queue_matches,
+        failure_reason,
+    }
+}
+
+/// Whether a failed check at `now` counts towards `oracle_consecutive_failures`
+pub fn counts_as_oracle_failure(lottery_state: &LotteryState, now: i64) -> bool {
+    lottery_state.oracle_consecutive_failures == 0
+        || now
+            >= lottery_state
+                .oracle_last_failure_timestamp
+                .saturating_add(ORACLE_FAILURE_COUNT_INTERVAL_SECONDS)
+}
+
+/// Accounts required for checking oracle health
+#[derive(Accounts)]
+pub struct CheckOracleHealth<'info> {
+    /// Anyone checking the oracle
+    pub checker: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The lottery's committed Switchboard randomness account
+    /// CHECK: Must be the committed account and owned by Switchboard; parsed
+    /// manually as RandomnessAccountData
+    #[account(
+        constraint = randomness_account_data.key() == lottery_state.current_randomness_account @ LottoError::InvalidRandomnessAccount
+    )]
+    pub randomness_account_data: AccountInfo<'info>,
+}
+
+/// Check the committed randomness account and track oracle health
+///
+/// Emits `OracleHealthReport`. When a check fails, emits
+/// `OracleHealthAlert` and, once `max_oracle_failures` failures have been
+/// counted, pauses the lottery.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_check_oracle_health(ctx: Context<CheckOracleHealth>) -> Result<()> {
+    let clock = Clock::get()?;
+    let randomness_account = &ctx.accounts.randomness_account_data;
+    require!(
+        is_switchboard_program(randomness_account.owner),
+        LottoError::InvalidRandomnessAccount
+    );
+    let snapshot = {
+        let data = RandomnessAccountData::parse(randomness_account.data.borrow())
+            .map_err(|_| LottoError::RandomnessParseError)?;
+        RandomnessSnapshot {
+            queue: Pubkey::new_from_array(data.queue.to_bytes()),
+            reveal_slot: data.reveal_slot,
+            value: data.value,
+        }
+    };
+
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    let health = assess_oracle_health(
+        &snapshot,
+        &lottery_state.switchboard_queue,
+        clock.slot,
+        clock.unix_timestamp,
+        lottery_state
+            .draw_interval
+            .saturating_add(ORACLE_MAX_STALENESS_SECONDS),
+    );
+
+    emit!(OracleHealthReport {
+        is_healthy: health.is_healthy,
+        last_update_timestamp: health.last_update_timestamp,
+        staleness_seconds: health.staleness_seconds,
+        queue_matches: health.queue_matches,
+    });
+
+    msg!("Oracle health checked: {}", randomness_account.key());
+    msg!("  Healthy: {}", health.is_healthy);
+    msg!("  Staleness: ~{} seconds", health.staleness_seconds);
+
+    let Some(reason) = health.failure_reason else {
+        lottery_state.oracle_consecutive_failures = 0;
+        lottery_state.oracle_last_healthy_timestamp = clock.unix_timestamp;
+        return Ok(());
+    };
+
+    emit!(OracleHealthAlert {
+        reason: reason.to_string(),
+    });
+    msg!("⚠️  Oracle unhealthy: {}", reason);
+
+    if !counts_as_oracle_failure(lottery_state, clock.unix_timestamp) {
+        return Ok(());
+    }
+    lottery_state.oracle_consecutive_failures =
+        lottery_state.oracle_consecutive_failures.saturating_add(1);
+    lottery_state.oracle_last_failure_timestamp = clock.unix_timestamp;
+    msg!(
+        "  Consecutive failures: {}/{}",
+        lottery_state.oracle_consecutive_failures,
+        lottery_state.max_oracle_failures
+    );
+
+    if lottery_state.max_oracle_failures > 0
+        && lottery_state.oracle_consecutive_failures >= lottery_state.max_oracle_failures
+        && !lottery_state.is_paused
+    {
+        lottery_state.is_paused = true;
+        msg!("  Lottery has been PAUSED pending oracle review.");
+
+        emit!(EmergencyPause {
+            authority: ctx.accounts.checker.key(),
+            reason: format!("Oracle unhealthy: {}", reason),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assess_oracle_health() {
+        let queue = Pubkey::new_unique();
+        let mut snapshot = RandomnessSnapshot {
+            queue,
+            reveal_slot: 1_000,
+            value: [7u8; 32],
+        };
+
+        // 2,500 slots at 400ms is 1,000 seconds
+        let health = assess_oracle_health(&snapshot, &queue, 3_500, 10_000, 1_000);
+        assert!(health.is_healthy);
+        assert_eq!(health.staleness_seconds, 1_000);
+        assert_eq!(health.last_update_timestamp, 9_000);
+
+        let health = assess_oracle_health(&snapshot, &queue, 3_501, 10_000, 999);
+        assert_eq!(health.failure_reason, Some("randomness is stale"));
+
+        snapshot.value = [0u8; 32];
+        let health = assess_oracle_health(&snapshot, &queue, 3_500, 10_000, 1_000);
+        assert_eq!(health.failure_reason, Some("randomness value is empty"));
+
+        let health = assess_oracle_health(&snapshot, &Pubkey::new_unique(), 3_500, 10_000, 1_000);
+        assert!(!health.queue_matches);
+        assert!(!health.is_healthy);
+
+        assert!(!is_switchboard_program(&Pubkey::new_unique()));
+        assert!(is_switchboard_program(&Pubkey::new_from_array(
+            ON_DEMAND_MAINNET_PID.to_bytes()
+        )));
+    }
+
+    #[test]
+    fn test_counts_as_oracle_failure() {
+        let mut lottery_state = LotteryState::default();
+        assert!(counts_as_oracle_failure(&lottery_state, 0));
+
+        lottery_state.oracle_consecutive_failures = 1;
+        lottery_state.oracle_last_failure_timestamp = 1_000;
+        assert!(!counts_as_oracle_failure(
+            &lottery_state,
+            999 + ORACLE_FAILURE_COUNT_INTERVAL_SECONDS
+        ));
+        assert!(counts_as_oracle_failure(
+            &lottery_state,
+            1_000 + ORACLE_FAILURE_COUNT_INTERVAL_SECONDS
+        ));
+    }
+}
@@ -0,0 +1,139 @@
+//! Prize Escrow Release Instruction
+//!
+//! Match 6 prizes above `lottery_state.escrow_threshold_usdc` are moved by
+//! `claim_prize` into a per-ticket escrow token account rather than paid to
+//! the winner. Once `escrow_release_delay_seconds` has elapsed the winner
+//! releases the prize with this instruction, which also closes both escrow
+//! accounts and returns their rent.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::PrizeEscrowReleased;
+use crate::state::{LotteryState, PrizeEscrow, SchemaVersioned};
+
+/// Accounts required for releasing an escrowed prize
+#[derive(Accounts)]
+pub struct ReleaseEscrowPrize<'info> {
+    /// The escrow beneficiary (receives the prize and escrow rent)
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    /// The main lottery state account (escrow token account authority)
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The escrow record (closed on release)
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, prize_escrow.ticket.as_ref()],
+        bump = prize_escrow.bump,
+        constraint = prize_escrow.beneficiary == beneficiary.key() @ LottoError::Unauthorized,
+        close = beneficiary
+    )]
+    pub prize_escrow: Account<'info, PrizeEscrow>,
+
+    /// The escrow USDC token account holding the prize
+    #[account(
+        mut,
+        seeds = [ESCROW_USDC_SEED, prize_escrow.ticket.as_ref()],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = lottery_state
+    )]
+    pub escrow_usdc: Account<'info, TokenAccount>,
+
+    /// Beneficiary's USDC token account (to receive the prize)
+    #[account(
+        mut,
+        constraint = beneficiary_usdc.owner == beneficiary.key() @ LottoError::TokenAccountOwnerMismatch,
+        constraint = beneficiary_usdc.mint == usdc_mint.key() @ LottoError::InvalidUsdcMint
+    )]
+    pub beneficiary_usdc: Account<'info, TokenAccount>,
+
+    /// USDC mint
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Release an escrowed prize to its beneficiary
+///
+/// This instruction:
+/// 1. Verifies the escrow release delay has elapsed
+/// 2. Transfers the escrowed USDC to the beneficiary
+/// 3. Closes the escrow token account and escrow record
+/// 4. Counts the prize towards `total_prizes_paid`
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_release_escrow_prize(ctx: Context<ReleaseEscrowPrize>) -> Result<()> {
+    let clock = Clock::get()?;
+    let prize_escrow = &ctx.accounts.prize_escrow;
+
+    require!(
+        prize_escrow.is_releasable(clock.unix_timestamp),
+        LottoError::EscrowLocked
+    );
+
+    let ticket_id = prize_escrow.ticket;
+    let beneficiary = prize_escrow.beneficiary;
+    let amount = ctx.accounts.escrow_usdc.amount;
+
+    let lottery_bump = ctx.accounts.lottery_state.bump;
+    let seeds = &[LOTTERY_SEED, &[lottery_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    if amount > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.escrow_usdc.to_account_info(),
+            to: ctx.accounts.beneficiary_usdc.to_account_info(),
+            authority: ctx.accounts.lottery_state.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, amount)?;
+    }
+
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.escrow_usdc.to_account_info(),
+        destination: ctx.accounts.beneficiary.to_account_info(),
+        authority: ctx.accounts.lottery_state.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::close_account(cpi_ctx)?;
+
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    lottery_state.total_prizes_paid = lottery_state.total_prizes_paid.saturating_add(amount);
+
+    emit!(PrizeEscrowReleased {
+        ticket_id,
+        beneficiary,
+        amount,
+    });
+
+    msg!("Escrowed prize released!");
+    msg!("  Ticket: {}", ticket_id);
+    msg!("  Beneficiary: {}", beneficiary);
+    msg!("  Amount: {} USDC lamports", amount);
+
+    Ok(())
+}
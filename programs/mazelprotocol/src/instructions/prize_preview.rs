@@ -0,0 +1,183 @@
+//! Prize Preview Instructions
+//!
+//! Read-only instructions that tell a wallet what a ticket won before the
+//! player pays for the claim. Both require a finalized draw so an unfinalized
+//! draw's zeroed prize tiers are never reported as a losing ticket.
+//!
+//! - preview_prize: Preview the prize of a single ticket
+//! - preview_batch_prizes: Preview the prize of one ticket of a unified ticket
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{BatchPrizePreview, PrizePreview};
+use crate::instructions::claim_prize::count_matches;
+use crate::state::{DrawResult, SchemaVersioned, TicketData, UnifiedTicket};
+
+/// Match count and prize for `numbers` in a finalized draw
+pub fn preview_prize_for(numbers: &[u8; 6], draw_result: &DrawResult) -> (u8, u64) {
+    let match_count = count_matches(numbers, &draw_result.winning_numbers);
+    (match_count, draw_result.get_prize_for_matches(match_count))
+}
+
+/// Last timestamp at which prizes of `draw_result` can be claimed
+fn claim_expires_at(draw_result: &DrawResult) -> i64 {
+    draw_result
+        .timestamp
+        .saturating_add(TICKET_CLAIM_EXPIRATION)
+}
+
+// ============================================================================
+// PREVIEW PRIZE
+// ============================================================================
+
+/// Accounts required for previewing a ticket's prize
+#[derive(Accounts)]
+pub struct PreviewPrize<'info> {
+    /// The ticket being previewed
+    #[account(
+        constraint = ticket.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub ticket: Account<'info, TicketData>,
+
+    /// The draw result for the ticket's draw (must be finalized)
+    #[account(
+        seeds = [DRAW_SEED, &ticket.draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = draw_result.draw_id == ticket.draw_id @ LottoError::DrawIdMismatch,
+        constraint = draw_result.is_finalized() @ LottoError::DrawNotFinalized,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub draw_result: Account<'info, DrawResult>,
+}
+
+/// Emit the prize a ticket would receive if claimed
+///
+/// Permissionless and read-only; emits `PrizePreview`.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_preview_prize(ctx: Context<PreviewPrize>) -> Result<()> {
+    let ticket = &ctx.accounts.ticket;
+    let draw_result = &ctx.accounts.draw_result;
+    let (match_count, prize_amount) = preview_prize_for(&ticket.numbers, draw_result);
+    let claim_expires_at = claim_expires_at(draw_result);
+
+    emit!(PrizePreview {
+        ticket_id: ticket.key(),
+        draw_id: ticket.draw_id,
+        match_count,
+        prize_amount,
+        is_claimed: ticket.is_claimed,
+        claim_expires_at,
+    });
+
+    msg!("Prize preview");
+    msg!("  Ticket: {}", ticket.key());
+    msg!("  Draw ID: {}", ticket.draw_id);
+    msg!("  Matches: {}", match_count);
+    msg!("  Prize: {} USDC lamports", prize_amount);
+    msg!("  Claimed: {}", ticket.is_claimed);
+    msg!("  Claim expires at: {}", claim_expires_at);
+
+    Ok(())
+}
+
+// ============================================================================
+// PREVIEW BATCH PRIZES
+// ============================================================================
+
+/// Accounts required for previewing a unified ticket entry's prize
+#[derive(Accounts)]
+pub struct PreviewBatchPrizes<'info> {
+    /// The unified ticket containing the entry
+    pub unified_ticket: Account<'info, UnifiedTicket>,
+
+    /// The draw result for the unified ticket's draw (must be finalized)
+    #[account(
+        seeds = [DRAW_SEED, &unified_ticket.draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = draw_result.draw_id == unified_ticket.draw_id @ LottoError::DrawIdMismatch,
+        constraint = draw_result.is_finalized() @ LottoError::DrawNotFinalized,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub draw_result: Account<'info, DrawResult>,
+}
+
+/// Emit the prize one entry of a unified ticket would receive if claimed
+///
+/// Permissionless and read-only; emits `BatchPrizePreview`.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `ticket_index` - Index of the entry within the unified ticket
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_preview_batch_prizes(
+    ctx: Context<PreviewBatchPrizes>,
+    ticket_index: u32,
+) -> Result<()> {
+    let unified_ticket = &ctx.accounts.unified_ticket;
+    let draw_result = &ctx.accounts.draw_result;
+    let index = ticket_index as usize;
+    require!(
+        index < unified_ticket.ticket_count as usize && index < unified_ticket.numbers.len(),
+        LottoError::InvalidTicket
+    );
+
+    let (match_count, prize_amount) =
+        preview_prize_for(&unified_ticket.numbers[index], draw_result);
+    let is_claimed = unified_ticket.is_ticket_claimed(index);
+    let claim_expires_at = claim_expires_at(draw_result);
+
+    emit!(BatchPrizePreview {
+        batch_id: unified_ticket.key(),
+        ticket_index,
+        draw_id: unified_ticket.draw_id,
+        match_count,
+        prize_amount,
+        is_claimed,
+        claim_expires_at,
+    });
+
+    msg!("Batch prize preview");
+    msg!("  Unified ticket: {}", unified_ticket.key());
+    msg!("  Ticket index: {}", ticket_index);
+    msg!("  Draw ID: {}", unified_ticket.draw_id);
+    msg!("  Matches: {}", match_count);
+    msg!("  Prize: {} USDC lamports", prize_amount);
+    msg!("  Claimed: {}", is_claimed);
+    msg!("  Claim expires at: {}", claim_expires_at);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preview_prize_for() {
+        let draw_result = DrawResult {
+            winning_numbers: [3, 8, 15, 22, 31, 44],
+            match_3_prize_per_winner: MATCH_3_PRIZE,
+            match_4_prize_per_winner: MATCH_4_PRIZE,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            preview_prize_for(&[3, 8, 15, 22, 40, 45], &draw_result),
+            (4, MATCH_4_PRIZE)
+        );
+        assert_eq!(
+            preview_prize_for(&[3, 8, 15, 23, 40, 45], &draw_result),
+            (3, MATCH_3_PRIZE)
+        );
+        assert_eq!(preview_prize_for(&[1, 2, 4, 5, 6, 7], &draw_result), (0, 0));
+    }
+}
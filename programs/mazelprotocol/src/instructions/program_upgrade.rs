@@ -0,0 +1,226 @@
+//! Program Upgrade Announcement Instructions
+//!
+//! Before deploying new bytecode the authority announces its SHA256, giving
+//! players UPGRADE_DELAY_SLOTS of notice. While the announcement is in its
+//! waiting period, `finalize_draw` emits `UpgradePendingWarning`. After the
+//! deployment anyone can call `verify_upgrade_hash` to check that the
+//! deployed bytecode is what was announced.
+//!
+//! The hash covers the program data account's bytes after its metadata
+//! header, including any zero padding after the ELF.
+//!
+//! - announce_program_upgrade: Announce the next bytecode hash (authority only)
+//! - clear_upgrade_announcement: Cancel the announcement (authority only)
+//! - verify_upgrade_hash: Check the deployed bytecode (anyone)
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
+use sha2::{Digest, Sha256};
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{ProgramUpgradeActivated, ProgramUpgradeAnnounced, UpgradeHashVerified};
+use crate::state::{LotteryState, SchemaVersioned};
+
+/// Address of this program's program data account
+pub fn program_data_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::ID).0
+}
+
+/// SHA256 of the bytecode in raw program data account `data`
+pub fn program_bytecode_hash(data: &[u8]) -> Result<[u8; 32]> {
+    let bytecode = data
+        .get(UpgradeableLoaderState::size_of_programdata_metadata()..)
+        .ok_or(LottoError::InvalidProgramDataAccount)?;
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(Sha256::digest(bytecode).as_slice());
+    Ok(hash)
+}
+
+// ============================================================================
+// ANNOUNCE PROGRAM UPGRADE
+// ============================================================================
+
+/// Accounts required for announcing a program upgrade
+#[derive(Accounts)]
+pub struct AnnounceProgramUpgrade<'info> {
+    /// The lottery authority
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+/// Announce `new_bytecode_hash` as the next program upgrade
+///
+/// Replacing an earlier announcement restarts the waiting period.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `new_bytecode_hash` - SHA256 of the new program bytecode
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_announce_program_upgrade(
+    ctx: Context<AnnounceProgramUpgrade>,
+    new_bytecode_hash: [u8; 32],
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    lottery_state.pending_upgrade_hash = Some(new_bytecode_hash);
+    lottery_state.upgrade_announce_slot = clock.slot;
+    let earliest_slot = lottery_state.upgrade_earliest_slot();
+
+    emit!(ProgramUpgradeAnnounced {
+        hash: new_bytecode_hash,
+        earliest_slot,
+    });
+
+    msg!("Program upgrade announced");
+    msg!("  Bytecode hash: {:?}", new_bytecode_hash);
+    msg!("  Earliest slot: {}", earliest_slot);
+
+    Ok(())
+}
+
+// ============================================================================
+// CLEAR UPGRADE ANNOUNCEMENT
+// ============================================================================
+
+/// Accounts required for cancelling a program upgrade announcement
+#[derive(Accounts)]
+pub struct ClearUpgradeAnnouncement<'info> {
+    /// The lottery authority
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.pending_upgrade_hash.is_some() @ LottoError::NoUpgradeAnnounced,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+/// Cancel the pending program upgrade announcement
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_clear_upgrade_announcement(ctx: Context<ClearUpgradeAnnouncement>) -> Result<()> {
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    lottery_state.pending_upgrade_hash = None;
+    lottery_state.upgrade_announce_slot = 0;
+
+    msg!("Program upgrade announcement cleared");
+
+    Ok(())
+}
+
+// ============================================================================
+// VERIFY UPGRADE HASH
+// ============================================================================
+
+/// Accounts required for verifying the deployed bytecode
+#[derive(Accounts)]
+pub struct VerifyUpgradeHash<'info> {
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.pending_upgrade_hash.is_some() @ LottoError::NoUpgradeAnnounced,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// This program's program data account
+    /// CHECK: Address is checked against the program's program data PDA;
+    /// only the bytecode after the metadata header is read
+    #[account(
+        address = program_data_address(&crate::ID) @ LottoError::InvalidProgramDataAccount
+    )]
+    pub program_data: AccountInfo<'info>,
+}
+
+/// Compare the deployed bytecode against the announced hash
+///
+/// Emits `UpgradeHashVerified`. On a match the announcement is complete:
+/// it is cleared and `ProgramUpgradeActivated` is emitted.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_verify_upgrade_hash(ctx: Context<VerifyUpgradeHash>) -> Result<()> {
+    let clock = Clock::get()?;
+    let deployed_hash = program_bytecode_hash(&ctx.accounts.program_data.try_borrow_data()?)?;
+
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    let matches = lottery_state.pending_upgrade_hash == Some(deployed_hash);
+
+    emit!(UpgradeHashVerified { matches });
+    msg!("Upgrade hash verified: {}", matches);
+    msg!("  Deployed hash: {:?}", deployed_hash);
+
+    if matches {
+        if lottery_state.is_upgrade_pending(clock.slot) {
+            msg!(
+                "⚠️  Upgrade deployed before the waiting period ended (slot {})",
+                lottery_state.upgrade_earliest_slot()
+            );
+        }
+        lottery_state.pending_upgrade_hash = None;
+        lottery_state.upgrade_announce_slot = 0;
+
+        emit!(ProgramUpgradeActivated { slot: clock.slot });
+        msg!("Program upgrade activated at slot {}", clock.slot);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_program_bytecode_hash() {
+        let header = UpgradeableLoaderState::size_of_programdata_metadata();
+        let mut data = vec![0u8; header + 4];
+        data[header..].copy_from_slice(b"code");
+        let hash = program_bytecode_hash(&data).unwrap();
+
+        // Header bytes do not affect the hash
+        data[0] = 3;
+        assert_eq!(program_bytecode_hash(&data).unwrap(), hash);
+
+        assert!(program_bytecode_hash(&data[..header - 1]).is_err());
+    }
+
+    #[test]
+    fn test_is_upgrade_pending() {
+        let mut lottery_state = LotteryState {
+            upgrade_announce_slot: 100,
+            ..Default::default()
+        };
+        assert!(!lottery_state.is_upgrade_pending(100));
+
+        lottery_state.pending_upgrade_hash = Some([1u8; 32]);
+        assert!(lottery_state.is_upgrade_pending(100 + UPGRADE_DELAY_SLOTS));
+        assert!(!lottery_state.is_upgrade_pending(101 + UPGRADE_DELAY_SLOTS));
+    }
+}
@@ -0,0 +1,230 @@
+//! Protocol Registry Instructions
+//!
+//! A singleton `ProtocolRegistry` lists every Mazel Protocol program (the
+//! main lottery and Quick Pick Express) so clients and other programs can
+//! discover them and check their versions without hardcoded IDs.
+//!
+//! - register_program: Register or re-register a program (authority only)
+//! - deactivate_program: Mark a program as out of service (authority only)
+//! - verify_program_registered: Emit a program's registry status (anyone)
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{ProgramDeactivated, ProgramRegistered, ProgramVerified};
+use crate::state::{LotteryState, ProgramType, ProtocolRegistry, SchemaVersioned};
+
+// ============================================================================
+// REGISTER PROGRAM
+// ============================================================================
+
+/// Accounts required for registering a program
+#[derive(Accounts)]
+pub struct RegisterProgram<'info> {
+    /// The lottery authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The protocol registry (created on the first registration)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PROTOCOL_REGISTRY_SIZE,
+        seeds = [PROTOCOL_REGISTRY_SEED],
+        bump
+    )]
+    pub protocol_registry: Account<'info, ProtocolRegistry>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Register `program_id` as a `program_type` program
+///
+/// Re-registering a listed program reactivates it under the next version.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `program_id` - Program to register
+/// * `program_type` - Kind of program
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_register_program(
+    ctx: Context<RegisterProgram>,
+    program_id: Pubkey,
+    program_type: ProgramType,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let registry = &mut ctx.accounts.protocol_registry;
+    registry.bump = ctx.bumps.protocol_registry;
+
+    let version = registry.register(program_id, program_type, clock.unix_timestamp)?;
+
+    emit!(ProgramRegistered {
+        program_id,
+        program_type,
+        version,
+    });
+
+    msg!("Program registered");
+    msg!("  Program: {}", program_id);
+    msg!("  Type: {:?}", program_type);
+    msg!("  Version: {}", version);
+
+    Ok(())
+}
+
+// ============================================================================
+// DEACTIVATE PROGRAM
+// ============================================================================
+
+/// Accounts required for deactivating a registered program
+#[derive(Accounts)]
+pub struct DeactivateProgram<'info> {
+    /// The lottery authority
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The protocol registry
+    #[account(
+        mut,
+        seeds = [PROTOCOL_REGISTRY_SEED],
+        bump = protocol_registry.bump
+    )]
+    pub protocol_registry: Account<'info, ProtocolRegistry>,
+}
+
+/// Mark `program_id` as out of service
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `program_id` - Registered program to deactivate
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_deactivate_program(
+    ctx: Context<DeactivateProgram>,
+    program_id: Pubkey,
+) -> Result<()> {
+    ctx.accounts.protocol_registry.deactivate(&program_id)?;
+
+    emit!(ProgramDeactivated { program_id });
+
+    msg!("Program deactivated");
+    msg!("  Program: {}", program_id);
+
+    Ok(())
+}
+
+// ============================================================================
+// VERIFY PROGRAM REGISTERED
+// ============================================================================
+
+/// Accounts required for looking up a program in the registry
+#[derive(Accounts)]
+pub struct VerifyProgramRegistered<'info> {
+    /// The protocol registry
+    #[account(
+        seeds = [PROTOCOL_REGISTRY_SEED],
+        bump = protocol_registry.bump
+    )]
+    pub protocol_registry: Account<'info, ProtocolRegistry>,
+}
+
+/// Emit `program_id`'s registry status
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `program_id` - Program to look up
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_verify_program_registered(
+    ctx: Context<VerifyProgramRegistered>,
+    program_id: Pubkey,
+) -> Result<()> {
+    let entry = ctx.accounts.protocol_registry.find_entry(&program_id);
+
+    emit!(ProgramVerified {
+        program_id,
+        is_registered: entry.is_some(),
+        is_active: entry.is_some_and(|e| e.is_active),
+        version: entry.map_or(0, |e| e.version),
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_and_deactivate() {
+        let mut registry = ProtocolRegistry::default();
+        let lottery = Pubkey::new_unique();
+        let quickpick = Pubkey::new_unique();
+
+        assert_eq!(
+            registry
+                .register(lottery, ProgramType::MainLottery, 100)
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            registry
+                .register(quickpick, ProgramType::QuickPick, 100)
+                .unwrap(),
+            1
+        );
+        assert!(registry.deactivate(&Pubkey::new_unique()).is_err());
+
+        registry.deactivate(&quickpick).unwrap();
+        assert!(!registry.find_entry(&quickpick).unwrap().is_active);
+        assert!(registry.find_entry(&lottery).unwrap().is_active);
+
+        // Re-registering reactivates under the next version
+        assert_eq!(
+            registry
+                .register(quickpick, ProgramType::QuickPick, 200)
+                .unwrap(),
+            2
+        );
+        let entry = registry.find_entry(&quickpick).unwrap();
+        assert!(entry.is_active);
+        assert_eq!(entry.registered_at, 200);
+        assert_eq!(registry.entries.len(), 2);
+    }
+
+    #[test]
+    fn test_registry_full() {
+        let mut registry = ProtocolRegistry::default();
+        for _ in 0..MAX_PROTOCOL_REGISTRY_ENTRIES {
+            registry
+                .register(Pubkey::new_unique(), ProgramType::QuickPick, 0)
+                .unwrap();
+        }
+        assert!(registry
+            .register(Pubkey::new_unique(), ProgramType::QuickPick, 0)
+            .is_err());
+    }
+}
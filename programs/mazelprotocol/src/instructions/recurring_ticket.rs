@@ -0,0 +1,591 @@
+//! Buy Recurring Ticket Instruction
+//!
+//! A recurring ticket plays the same numbers in up to
+//! MAX_RECURRING_TICKET_DRAWS consecutive future draws. Unlike a
+//! `MultiDrawTicket` (one account with a claimed-draws bitmask), it creates
+//! one `TicketData` per draw at `[RECURRING_TICKET_SEED, player, draw_id]`,
+//! so each is claimed through the standard `claim_prize` path. The full price
+//! is allocated to the pools at purchase time, like a bulk purchase.
+//!
+//! Each ticket is recorded in the `RecurringTicketSchedule` and joins its
+//! draw's `current_draw_tickets` when that draw opens, so it counts towards
+//! `min_tickets_per_draw` and can be refunded if the draw is abandoned. It
+//! also counts towards the player's per-draw wallet limit for that draw.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::RecurringTicketPurchased;
+use crate::instructions::buy_ticket::{
+    check_numbers_entropy, emit_jackpot_milestones, record_jackpot_ath, resolve_ticket_price,
+    route_jackpot_overflow, unlock_milestone_bonuses,
+};
+use crate::instructions::daily_limit::{
+    enforce_daily_limit, enforce_purchase_velocity, enforce_self_exclusion,
+};
+use crate::instructions::house_fee::{
+    replenish_insurance_from_house_fee, route_house_fee, HouseFeeDestination, HouseFeeSource,
+};
+use crate::instructions::kyc::enforce_kyc;
+use crate::instructions::referral::credit_referrer_if_qualified;
+use crate::instructions::terms::enforce_terms_accepted;
+use crate::instructions::whitelist::enforce_whitelist;
+use crate::state::{
+    HouseFeeRecipients, KYCRecord, LotteryState, RecurringTicketSchedule, SchemaVersioned,
+    TicketData, UserStats, Whitelist,
+};
+
+/// Accounts required for buying a recurring ticket
+///
+/// `remaining_accounts` holds the uncreated ticket PDAs, one per draw ID in
+/// order, followed by any house fee recipient sub-accounts.
+#[derive(Accounts)]
+pub struct BuyRecurringTicket<'info> {
+    /// The player purchasing the tickets
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// The main lottery state account (boxed to keep the stack frame small)
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = !lottery_state.is_paused @ LottoError::Paused,
+        constraint = lottery_state.is_funded @ LottoError::LotteryNotInitialized,
+        constraint = !lottery_state.is_draw_in_progress @ LottoError::DrawInProgress,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Box<Account<'info, LotteryState>>,
+
+    /// Player's USDC token account
+    #[account(
+        mut,
+        constraint = player_usdc.owner == player.key() @ LottoError::TokenAccountOwnerMismatch,
+        constraint = player_usdc.mint == usdc_mint.key() @ LottoError::InvalidUsdcMint
+    )]
+    pub player_usdc: Account<'info, TokenAccount>,
+
+    /// Prize pool USDC token account
+    #[account(
+        mut,
+        seeds = [PRIZE_POOL_USDC_SEED],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+
+    /// House fee USDC token account
+    #[account(
+        mut,
+        seeds = [HOUSE_FEE_USDC_SEED],
+        bump
+    )]
+    pub house_fee_usdc: Account<'info, TokenAccount>,
+
+    /// Insurance tier 1 USDC token account
+    #[account(
+        mut,
+        seeds = [INSURANCE_TIER1_SEED],
+        bump
+    )]
+    pub insurance_pool_usdc: Account<'info, TokenAccount>,
+
+    /// Insurance tier 2 USDC token account
+    #[account(
+        mut,
+        seeds = [INSURANCE_TIER2_SEED],
+        bump
+    )]
+    pub insurance_tier2_usdc: Box<Account<'info, TokenAccount>>,
+
+    /// USDC mint
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// User statistics account
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = USER_STATS_SIZE,
+        seeds = [USER_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Account<'info, UserStats>,
+
+    /// Pending recurring tickets per future draw
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = RECURRING_SCHEDULE_SIZE,
+        seeds = [RECURRING_SCHEDULE_SEED],
+        bump
+    )]
+    pub recurring_schedule: Box<Account<'info, RecurringTicketSchedule>>,
+
+    /// Referrer's user statistics account (required only when this purchase
+    /// makes a referred player qualify; see `register_referral`)
+    #[account(
+        mut,
+        constraint = user_stats.referrer == Some(referrer_stats.wallet) @ LottoError::InvalidReferrer,
+        constraint = referrer_stats.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub referrer_stats: Option<Account<'info, UserStats>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// House fee split config (required while a split is active)
+    #[account(
+        seeds = [FEE_RECIPIENTS_SEED],
+        bump = fee_recipients.bump
+    )]
+    pub fee_recipients: Option<Account<'info, HouseFeeRecipients>>,
+
+    /// Purchase whitelist (required while `whitelist_mode` is not None)
+    #[account(
+        seeds = [WHITELIST_SEED],
+        bump = whitelist.bump
+    )]
+    pub whitelist: Option<Account<'info, Whitelist>>,
+
+    /// Player's token account for the whitelist gate mint (required in
+    /// TokenGated mode)
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Player's KYC record (required while `compliance_mode` is KYC)
+    #[account(
+        seeds = [KYC_SEED, player.key().as_ref()],
+        bump = kyc_record.bump
+    )]
+    pub kyc_record: Option<Account<'info, KYCRecord>>,
+}
+
+impl<'info> BuyRecurringTicket<'info> {
+    /// Transfer USDC from player to a pool token account
+    fn transfer_from_player(&self, to: &Account<'info, TokenAccount>, amount: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: self.player_usdc.to_account_info(),
+            to: to.to_account_info(),
+            authority: self.player.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, amount)
+    }
+
+    /// Transfer USDC from player to the house fee account(s)
+    fn transfer_to_house_fee(
+        &self,
+        amount: u64,
+        sub_accounts: &[AccountInfo<'info>],
+        program_id: &Pubkey,
+    ) -> Result<()> {
+        route_house_fee(
+            &HouseFeeSource {
+                from: self.player_usdc.to_account_info(),
+                authority: self.player.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                signer_seeds: &[],
+            },
+            &HouseFeeDestination {
+                house_fee_usdc: self.house_fee_usdc.to_account_info(),
+                fee_recipients: self.fee_recipients.as_deref(),
+                sub_accounts,
+            },
+            self.lottery_state.house_fee_split_active,
+            amount,
+            self.lottery_state.current_draw_id,
+            program_id,
+        )
+    }
+
+    /// Create `ticket` at its recurring ticket PDA, the uncreated `ticket_info`
+    fn create_ticket(
+        &self,
+        ticket_info: &AccountInfo<'info>,
+        mut ticket: TicketData,
+        program_id: &Pubkey,
+    ) -> Result<()> {
+        let player_key = self.player.key();
+        let draw_id_bytes = ticket.draw_id.to_le_bytes();
+        let (expected_key, bump) = Pubkey::find_program_address(
+            &[RECURRING_TICKET_SEED, player_key.as_ref(), &draw_id_bytes],
+            program_id,
+        );
+        require!(
+            ticket_info.key() == expected_key
+                && ticket_info.is_writable
+                && ticket_info.data_is_empty(),
+            LottoError::InvalidRecurringTicketAccount
+        );
+
+        let ticket_seeds: &[&[u8]] = &[
+            RECURRING_TICKET_SEED,
+            player_key.as_ref(),
+            &draw_id_bytes,
+            &[bump],
+        ];
+        let rent = Rent::get()?;
+        anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                anchor_lang::system_program::CreateAccount {
+                    from: self.player.to_account_info(),
+                    to: ticket_info.clone(),
+                },
+                &[ticket_seeds],
+            ),
+            rent.minimum_balance(TICKET_SIZE),
+            TICKET_SIZE as u64,
+            program_id,
+        )?;
+
+        ticket.bump = bump;
+        let mut data = ticket_info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        ticket.try_serialize(&mut writer)
+    }
+}
+
+/// Buy the same numbers for several consecutive future draws
+///
+/// This instruction:
+/// 1. Validates the draw IDs (1-10 consecutive draws after the current one,
+///    at most MAX_RECURRING_DRAW_LOOKAHEAD ahead) and the selected numbers
+/// 2. Charges the effective ticket price times `draw_ids.len()`, split
+///    across the prize pool, house fee and insurance pool like a bulk
+///    purchase
+/// 3. Creates one `TicketData` per draw from `remaining_accounts` and adds
+///    it to the draw's pending tickets in the recurring ticket schedule
+/// 4. Updates user statistics and lottery state, applying the daily and
+///    hourly velocity limits
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+/// * `numbers` - 6 numbers, each between 1 and 46
+/// * `future_draw_ids` - Consecutive future draws to play
+/// * `max_price_usdc` - Highest per-draw ticket price the player accepts
+///   (slippage protection for dynamic pricing)
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BuyRecurringTicket<'info>>,
+    numbers: [u8; NUMBERS_PER_TICKET],
+    future_draw_ids: Vec<u64>,
+    max_price_usdc: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let player_key = ctx.accounts.player.key();
+    let current_draw_id = ctx.accounts.lottery_state.current_draw_id;
+
+    require!(
+        is_valid_recurring_draw_ids(&future_draw_ids, current_draw_id),
+        LottoError::InvalidRecurringDrawIds
+    );
+    require!(
+        validate_lottery_numbers(&numbers),
+        LottoError::InvalidNumbers
+    );
+    check_numbers_entropy(
+        ctx.accounts.lottery_state.entropy_validation_enabled,
+        player_key,
+        &numbers,
+    )?;
+    require!(
+        ctx.accounts.lottery_state.jackpot_balance >= ctx.accounts.lottery_state.seed_amount,
+        LottoError::InsufficientJackpotFunding
+    );
+    enforce_whitelist(
+        ctx.accounts.lottery_state.whitelist_mode,
+        ctx.accounts.whitelist.as_deref(),
+        ctx.accounts.gate_token_account.as_deref(),
+        &player_key,
+    )?;
+    enforce_kyc(
+        ctx.accounts.lottery_state.compliance_mode,
+        ctx.accounts.kyc_record.as_deref(),
+        &player_key,
+        clock.unix_timestamp,
+    )?;
+
+    let draw_count = future_draw_ids.len();
+    require!(
+        ctx.remaining_accounts.len() >= draw_count,
+        LottoError::InvalidRecurringTicketAccount
+    );
+    let (ticket_infos, fee_sub_accounts) = ctx.remaining_accounts.split_at(draw_count);
+
+    let mut sorted_numbers = numbers;
+    sorted_numbers.sort();
+
+    let ticket_price = resolve_ticket_price(&ctx.accounts.lottery_state, max_price_usdc)?;
+    let soft_cap = ctx.accounts.lottery_state.soft_cap;
+    let house_fee_bps = ctx.accounts.lottery_state.get_current_house_fee_bps();
+
+    // Fund allocation (same split as buy_bulk, scaled by the draw count)
+    let total_price = ticket_price
+        .checked_mul(draw_count as u64)
+        .ok_or(LottoError::Overflow)?;
+    let house_fee = (total_price as u128 * house_fee_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+    let after_house_fee = total_price.saturating_sub(house_fee);
+    let insurance_contribution = (after_house_fee as u128 * INSURANCE_ALLOCATION_BPS as u128
+        / BPS_DENOMINATOR as u128) as u64;
+    let prize_pool_transfer = after_house_fee.saturating_sub(insurance_contribution);
+    let jackpot_contribution = (prize_pool_transfer as u128 * JACKPOT_ALLOCATION_BPS as u128
+        / BPS_DENOMINATOR as u128) as u64;
+    let fixed_prize_contribution = (prize_pool_transfer as u128
+        * FIXED_PRIZE_ALLOCATION_BPS as u128
+        / BPS_DENOMINATOR as u128) as u64;
+    let reserve_contribution = prize_pool_transfer
+        .saturating_sub(jackpot_contribution)
+        .saturating_sub(fixed_prize_contribution);
+
+    require!(
+        ctx.accounts.player_usdc.amount >= total_price,
+        LottoError::InsufficientFunds
+    );
+
+    // Part of the house fee tops up the insurance pool while it is below minimum
+    let insurance_replenishment =
+        replenish_insurance_from_house_fee(&mut ctx.accounts.lottery_state, house_fee)?;
+    let house_fee_transfer = house_fee - insurance_replenishment;
+    let (insurance_tier1_contribution, insurance_tier2_transfer) =
+        split_insurance_contribution(insurance_contribution);
+    let insurance_transfer = insurance_tier1_contribution + insurance_replenishment;
+
+    ctx.accounts
+        .transfer_from_player(&ctx.accounts.prize_pool_usdc, prize_pool_transfer)?;
+    ctx.accounts
+        .transfer_to_house_fee(house_fee_transfer, fee_sub_accounts, ctx.program_id)?;
+    if insurance_transfer > 0 {
+        ctx.accounts
+            .transfer_from_player(&ctx.accounts.insurance_pool_usdc, insurance_transfer)?;
+    }
+    if insurance_tier2_transfer > 0 {
+        ctx.accounts
+            .transfer_from_player(&ctx.accounts.insurance_tier2_usdc, insurance_tier2_transfer)?;
+    }
+
+    require!(
+        house_fee_transfer + prize_pool_transfer + insurance_transfer + insurance_tier2_transfer
+            == total_price,
+        LottoError::SafetyCheckFailed
+    );
+
+    // Create one ticket per future draw
+    ctx.accounts.recurring_schedule.bump = ctx.bumps.recurring_schedule;
+    for (draw_id, ticket_info) in future_draw_ids.iter().zip(ticket_infos) {
+        let ticket = TicketData {
+            schema_version: CURRENT_SCHEMA_VERSION,
+            owner: player_key,
+            draw_id: *draw_id,
+            numbers: sorted_numbers,
+            purchase_timestamp: clock.unix_timestamp,
+            original_owner: player_key,
+            // Counted in the draw's tickets once it opens
+            requires_refund: true,
+            ..Default::default()
+        };
+        ctx.accounts
+            .create_ticket(ticket_info, ticket, ctx.program_id)?;
+        ctx.accounts.recurring_schedule.add_ticket(*draw_id)?;
+    }
+
+    // Update lottery state with internal accounting
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    let old_jackpot_balance = lottery_state.jackpot_balance;
+    lottery_state.jackpot_balance = lottery_state
+        .jackpot_balance
+        .checked_add(jackpot_contribution)
+        .ok_or(LottoError::Overflow)?;
+    route_jackpot_overflow(lottery_state)?;
+    lottery_state.reserve_balance = lottery_state
+        .reserve_balance
+        .checked_add(reserve_contribution)
+        .ok_or(LottoError::Overflow)?;
+    lottery_state.credit_insurance(insurance_contribution)?;
+    lottery_state.fixed_prize_balance = lottery_state
+        .fixed_prize_balance
+        .checked_add(fixed_prize_contribution)
+        .ok_or(LottoError::Overflow)?;
+    lottery_state.total_tickets_sold = lottery_state
+        .total_tickets_sold
+        .checked_add(draw_count as u64)
+        .ok_or(LottoError::Overflow)?;
+    lottery_state.pending_recurring_tickets = lottery_state
+        .pending_recurring_tickets
+        .checked_add(draw_count as u64)
+        .ok_or(LottoError::Overflow)?;
+    lottery_state.house_fee_bps = lottery_state.get_current_house_fee_bps();
+
+    if lottery_state.jackpot_balance >= soft_cap && !lottery_state.is_rolldown_active {
+        lottery_state.is_rolldown_active = true;
+        msg!(
+            "🎰 ROLLDOWN ACTIVATED! Jackpot {} >= Soft Cap {}",
+            lottery_state.jackpot_balance,
+            soft_cap
+        );
+    }
+
+    emit_jackpot_milestones(lottery_state);
+    unlock_milestone_bonuses(lottery_state, old_jackpot_balance);
+    record_jackpot_ath(lottery_state, clock.unix_timestamp);
+
+    let daily_limit_enabled = lottery_state.daily_limit_enabled;
+    let max_tickets_per_wallet_per_hour = lottery_state.max_tickets_per_wallet_per_hour;
+
+    // Update user stats
+    let user_stats = &mut ctx.accounts.user_stats;
+
+    // Initialize if new
+    if user_stats.wallet == Pubkey::default() {
+        user_stats.schema_version = CURRENT_SCHEMA_VERSION;
+        user_stats.wallet = player_key;
+        user_stats.bump = ctx.bumps.user_stats;
+        user_stats.tickets_this_draw = 0;
+        user_stats.last_draw_participated = 0;
+        user_stats.daily_ticket_limit = DEFAULT_DAILY_TICKET_LIMIT;
+        user_stats.join_timestamp = clock.unix_timestamp;
+    }
+    user_stats.require_current_schema()?;
+
+    enforce_self_exclusion(user_stats, clock.unix_timestamp)?;
+    enforce_terms_accepted(lottery_state.terms_version, user_stats)?;
+
+    enforce_daily_limit(
+        daily_limit_enabled,
+        user_stats,
+        draw_count as u32,
+        clock.unix_timestamp,
+    )?;
+
+    enforce_purchase_velocity(
+        max_tickets_per_wallet_per_hour,
+        user_stats,
+        draw_count as u32,
+        clock.unix_timestamp,
+    )?;
+
+    user_stats.record_recurring_draws(&future_draw_ids, current_draw_id);
+
+    let previous_total_tickets = user_stats.total_tickets;
+    user_stats.total_tickets = user_stats
+        .total_tickets
+        .checked_add(draw_count as u64)
+        .ok_or(LottoError::Overflow)?;
+    user_stats.total_spent = user_stats
+        .total_spent
+        .checked_add(total_price)
+        .ok_or(LottoError::Overflow)?;
+
+    credit_referrer_if_qualified(
+        user_stats,
+        previous_total_tickets,
+        ctx.accounts.referrer_stats.as_mut(),
+    )?;
+
+    emit!(RecurringTicketPurchased {
+        player: player_key,
+        numbers: sorted_numbers,
+        draw_ids: future_draw_ids.clone(),
+        total_price,
+    });
+
+    msg!("Recurring ticket purchased successfully!");
+    msg!("  Player: {}", player_key);
+    msg!("  Numbers: {:?}", sorted_numbers);
+    msg!("  Draws: {:?}", future_draw_ids);
+    msg!("  Total price: {} USDC lamports", total_price);
+    msg!(
+        "  House fee ({}bps): {} USDC lamports",
+        house_fee_bps,
+        house_fee
+    );
+    msg!(
+        "  Prize pool transfer: {} USDC lamports",
+        prize_pool_transfer
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recurring_draw_ids() {
+        assert!(is_valid_recurring_draw_ids(&[11], 10));
+        assert!(is_valid_recurring_draw_ids(&[12, 13, 14], 10));
+        assert!(is_valid_recurring_draw_ids(
+            &[101, 102, 103, 104, 105, 106, 107, 108, 109, 110],
+            10
+        ));
+
+        // Empty, current or past draws
+        assert!(!is_valid_recurring_draw_ids(&[], 10));
+        assert!(!is_valid_recurring_draw_ids(&[10, 11], 10));
+        // Duplicates, gaps and descending order
+        assert!(!is_valid_recurring_draw_ids(&[11, 11], 10));
+        assert!(!is_valid_recurring_draw_ids(&[11, 13], 10));
+        assert!(!is_valid_recurring_draw_ids(&[12, 11], 10));
+        // Beyond the lookahead window or too many draws
+        assert!(!is_valid_recurring_draw_ids(&[110, 111], 10));
+        let eleven: Vec<u64> = (11..22).collect();
+        assert!(!is_valid_recurring_draw_ids(&eleven, 10));
+    }
+
+    #[test]
+    fn test_pending_recurring_tickets_join_their_draw() {
+        let mut schedule = RecurringTicketSchedule {
+            bump: 0,
+            pending_tickets: [0; RECURRING_SCHEDULE_LENGTH],
+        };
+        let mut lottery_state = LotteryState {
+            current_draw_id: 10,
+            current_draw_tickets: 7,
+            pending_recurring_tickets: 3,
+            ..Default::default()
+        };
+        schedule.add_ticket(11).unwrap();
+        schedule.add_ticket(11).unwrap();
+        schedule
+            .add_ticket(10 + MAX_RECURRING_DRAW_LOOKAHEAD)
+            .unwrap();
+
+        // The schedule is required while tickets are pending
+        assert!(lottery_state.open_next_draw(None).is_err());
+
+        lottery_state.open_next_draw(Some(&mut schedule)).unwrap();
+        assert_eq!(lottery_state.current_draw_id, 11);
+        assert_eq!(lottery_state.current_draw_tickets, 2);
+        assert_eq!(lottery_state.pending_recurring_tickets, 1);
+        assert_eq!(schedule.take(11), 0);
+
+        lottery_state.open_next_draw(Some(&mut schedule)).unwrap();
+        assert_eq!(lottery_state.current_draw_tickets, 0);
+        assert_eq!(schedule.take(10 + MAX_RECURRING_DRAW_LOOKAHEAD), 1);
+    }
+
+    #[test]
+    fn test_recurring_ticket_counts_towards_wallet_draw_limit() {
+        let mut user_stats = UserStats::default();
+        user_stats.record_recurring_draws(&[11, 12], 10);
+        assert!(user_stats.has_recurring_ticket(11));
+        assert!(!user_stats.has_recurring_ticket(13));
+
+        // Draw 11 starts at one ticket for the recurring entry
+        assert!(!user_stats.record_wallet_draw_purchase(3, 11, 3));
+        assert!(user_stats.record_wallet_draw_purchase(2, 11, 3));
+        assert_eq!(user_stats.current_draw_tickets_count, 3);
+        assert!(user_stats.record_wallet_draw_purchase(3, 13, 3));
+
+        // Later purchases drop draws that have passed
+        user_stats.record_recurring_draws(&[200], 150);
+        assert!(!user_stats.has_recurring_ticket(12));
+        assert!(user_stats.has_recurring_ticket(200));
+    }
+}
@@ -0,0 +1,481 @@
+//! Referral Instructions
+//!
+//! Players can register the wallet that referred them. Once a referred player
+//! reaches REFERRAL_QUALIFYING_TICKETS lifetime ticket purchases, the referrer
+//! earns one referral credit in their UserStats account.
+//!
+//! Each referral credit can be redeemed for a single ticket. No USDC is
+//! transferred for a redeemed ticket - its price is funded from
+//! `lottery_state.reserve_balance` and allocated to the jackpot and fixed
+//! prize pools exactly like a purchased ticket, so pool accounting stays
+//! consistent with the USDC already held in the prize pool.
+//!
+//! Rules:
+//! - A referrer can only be set once, and only before the player qualifies
+//! - Players cannot refer themselves or the wallet that referred them
+//! - The referrer must already have a UserStats account
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{ReferralCreditRedeemed, ReferralRegistered, TicketPurchased};
+use crate::instructions::buy_ticket::{
+    check_numbers_entropy, check_ticket_sale_cutoff, resolve_ticket_price, route_jackpot_overflow,
+    unlock_milestone_bonuses, validate_numbers,
+};
+use crate::instructions::daily_limit::enforce_self_exclusion;
+use crate::instructions::kyc::enforce_kyc;
+use crate::instructions::terms::enforce_terms_accepted;
+use crate::instructions::whitelist::enforce_whitelist;
+use crate::state::{KYCRecord, LotteryState, SchemaVersioned, TicketData, UserStats, Whitelist};
+
+// ============================================================================
+// REGISTER REFERRAL
+// ============================================================================
+
+/// Accounts required for registering a referrer
+#[derive(Accounts)]
+#[instruction(referrer: Pubkey)]
+pub struct RegisterReferral<'info> {
+    /// The referred player
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// Player's user statistics account (created if needed so a referrer
+    /// can be registered before the first purchase)
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = USER_STATS_SIZE,
+        seeds = [USER_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Account<'info, UserStats>,
+
+    /// Referrer's user statistics account (must already exist)
+    #[account(
+        seeds = [USER_SEED, referrer.as_ref()],
+        bump = referrer_stats.bump,
+        constraint = referrer_stats.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub referrer_stats: Account<'info, UserStats>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Validate that `referrer` may be registered for `player`
+///
+/// # Arguments
+/// * `player` - The referred player
+/// * `referrer` - The proposed referrer
+/// * `current_referrer` - The player's existing referrer, if any
+/// * `referrer_referrer` - The referrer's own referrer, if any
+/// * `player_total_tickets` - Player's lifetime ticket count
+pub fn validate_referral(
+    player: &Pubkey,
+    referrer: &Pubkey,
+    current_referrer: Option<Pubkey>,
+    referrer_referrer: Option<Pubkey>,
+    player_total_tickets: u64,
+) -> Result<()> {
+    require!(current_referrer.is_none(), LottoError::ReferrerAlreadySet);
+    require!(
+        referrer != player && *referrer != Pubkey::default(),
+        LottoError::InvalidReferrer
+    );
+    // Prevent two wallets referring each other to farm credits
+    require!(
+        referrer_referrer != Some(*player),
+        LottoError::InvalidReferrer
+    );
+    // The referral must be registered before the player qualifies
+    require!(
+        player_total_tickets < REFERRAL_QUALIFYING_TICKETS,
+        LottoError::ReferralWindowClosed
+    );
+    Ok(())
+}
+
+/// Register the wallet that referred the player
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+/// * `referrer` - Wallet of the referring player
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_register_referral(ctx: Context<RegisterReferral>, referrer: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+    let player = ctx.accounts.player.key();
+
+    let user_stats = &mut ctx.accounts.user_stats;
+
+    // Initialize if new
+    if user_stats.wallet == Pubkey::default() {
+        user_stats.schema_version = CURRENT_SCHEMA_VERSION;
+        user_stats.wallet = player;
+        user_stats.bump = ctx.bumps.user_stats;
+        user_stats.tickets_this_draw = 0;
+        user_stats.last_draw_participated = 0;
+        user_stats.daily_ticket_limit = DEFAULT_DAILY_TICKET_LIMIT;
+        user_stats.join_timestamp = clock.unix_timestamp;
+    }
+    user_stats.require_current_schema()?;
+
+    validate_referral(
+        &player,
+        &referrer,
+        user_stats.referrer,
+        ctx.accounts.referrer_stats.referrer,
+        user_stats.total_tickets,
+    )?;
+
+    user_stats.referrer = Some(referrer);
+
+    emit!(ReferralRegistered {
+        player,
+        referrer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Referral registered!");
+    msg!("  Player: {}", player);
+    msg!("  Referrer: {}", referrer);
+
+    Ok(())
+}
+
+// ============================================================================
+// REFERRAL CREDIT ACCRUAL (used by buy_ticket and buy_bulk)
+// ============================================================================
+
+/// Check whether a purchase moved the player across the qualifying ticket count
+pub fn crossed_referral_threshold(previous_total: u64, new_total: u64) -> bool {
+    previous_total < REFERRAL_QUALIFYING_TICKETS && new_total >= REFERRAL_QUALIFYING_TICKETS
+}
+
+/// Credit the player's referrer if this purchase made the player qualify
+///
+/// The referrer's UserStats account must be supplied whenever the player has
+/// a referrer and the qualifying purchase happens, so credits cannot be lost.
+///
+/// # Arguments
+/// * `user_stats` - Player's user stats (after the purchase was recorded)
+/// * `previous_total` - Player's lifetime ticket count before the purchase
+/// * `referrer_stats` - The referrer's user stats account, if supplied
+pub fn credit_referrer_if_qualified(
+    user_stats: &UserStats,
+    previous_total: u64,
+    referrer_stats: Option<&mut Account<UserStats>>,
+) -> Result<()> {
+    let Some(referrer) = user_stats.referrer else {
+        return Ok(());
+    };
+    if !crossed_referral_threshold(previous_total, user_stats.total_tickets) {
+        return Ok(());
+    }
+
+    let referrer_stats = referrer_stats.ok_or(LottoError::ReferrerStatsRequired)?;
+    require!(
+        referrer_stats.wallet == referrer,
+        LottoError::InvalidReferrer
+    );
+
+    referrer_stats.referral_credits = referrer_stats
+        .referral_credits
+        .checked_add(1)
+        .ok_or(LottoError::Overflow)?;
+    referrer_stats.referred_players_bought = referrer_stats
+        .referred_players_bought
+        .checked_add(1)
+        .ok_or(LottoError::Overflow)?;
+
+    msg!("  Referral credit awarded to {}", referrer);
+
+    Ok(())
+}
+
+// ============================================================================
+// REDEEM REFERRAL CREDIT
+// ============================================================================
+
+/// Accounts required for redeeming a referral credit for a ticket
+#[derive(Accounts)]
+pub struct RedeemReferralCredit<'info> {
+    /// The player redeeming the credit
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = !lottery_state.is_paused @ LottoError::Paused,
+        constraint = lottery_state.is_funded @ LottoError::LotteryNotInitialized,
+        constraint = !lottery_state.is_draw_in_progress @ LottoError::DrawInProgress,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The ticket account to be created
+    #[account(
+        init,
+        payer = player,
+        space = TICKET_SIZE,
+        seeds = [
+            TICKET_SEED,
+            &lottery_state.current_draw_id.to_le_bytes(),
+            &lottery_state.current_draw_tickets.to_le_bytes()
+        ],
+        bump
+    )]
+    pub ticket: Account<'info, TicketData>,
+
+    /// Player's user statistics account (holds the referral credits)
+    #[account(
+        mut,
+        seeds = [USER_SEED, player.key().as_ref()],
+        bump = user_stats.bump,
+        constraint = user_stats.referral_credits > 0 @ LottoError::NoReferralCredits,
+        constraint = user_stats.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub user_stats: Account<'info, UserStats>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Purchase whitelist (required while `whitelist_mode` is not None)
+    #[account(
+        seeds = [WHITELIST_SEED],
+        bump = whitelist.bump
+    )]
+    pub whitelist: Option<Account<'info, Whitelist>>,
+
+    /// Player's token account for the whitelist gate mint (required in
+    /// TokenGated mode)
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Player's KYC record (required while `compliance_mode` is KYC)
+    #[account(
+        seeds = [KYC_SEED, player.key().as_ref()],
+        bump = kyc_record.bump
+    )]
+    pub kyc_record: Option<Account<'info, KYCRecord>>,
+}
+
+/// Split a reserve-funded ticket price into jackpot and fixed prize shares
+///
+/// The reserve share of the ticket stays in the reserve, so only the jackpot
+/// and fixed prize shares are moved out of `reserve_balance`.
+///
+/// # Returns
+/// * `(jackpot_share, fixed_prize_share)`
+pub fn split_reserve_funded_ticket(ticket_price: u64) -> (u64, u64) {
+    let jackpot_share =
+        (ticket_price as u128 * JACKPOT_ALLOCATION_BPS as u128 / BPS_DENOMINATOR as u128) as u64;
+    let fixed_prize_share = (ticket_price as u128 * FIXED_PRIZE_ALLOCATION_BPS as u128
+        / BPS_DENOMINATOR as u128) as u64;
+    (jackpot_share, fixed_prize_share)
+}
+
+/// Redeem one referral credit for a ticket in the current draw
+///
+/// This instruction:
+/// 1. Validates the numbers and that ticket sales are open
+/// 2. Funds the effective ticket price from the reserve (no USDC transfer)
+/// 3. Creates the ticket and consumes one referral credit
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+/// * `numbers` - 6 numbers, each between 1 and 46
+/// * `max_price_usdc` - Highest ticket price the credit may be redeemed at
+///   (slippage protection for dynamic pricing)
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_redeem_referral_credit(
+    ctx: Context<RedeemReferralCredit>,
+    numbers: [u8; NUMBERS_PER_TICKET],
+    max_price_usdc: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    validate_numbers(&numbers)?;
+    check_numbers_entropy(
+        ctx.accounts.lottery_state.entropy_validation_enabled,
+        ctx.accounts.player.key(),
+        &numbers,
+    )?;
+    let mut sorted_numbers = numbers;
+    sorted_numbers.sort();
+
+    let player = ctx.accounts.player.key();
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    let current_draw_id = lottery_state.current_draw_id;
+    let ticket_price = resolve_ticket_price(lottery_state, max_price_usdc)?;
+
+    // Check if ticket sales are open
+    check_ticket_sale_cutoff(lottery_state, clock.unix_timestamp)?;
+    enforce_whitelist(
+        lottery_state.whitelist_mode,
+        ctx.accounts.whitelist.as_deref(),
+        ctx.accounts.gate_token_account.as_deref(),
+        &player,
+    )?;
+    enforce_kyc(
+        lottery_state.compliance_mode,
+        ctx.accounts.kyc_record.as_deref(),
+        &player,
+        clock.unix_timestamp,
+    )?;
+
+    // Enforce per-user ticket limit
+    let user_stats = &mut ctx.accounts.user_stats;
+    enforce_self_exclusion(user_stats, clock.unix_timestamp)?;
+    enforce_terms_accepted(lottery_state.terms_version, user_stats)?;
+    let user_tickets_this_draw = if user_stats.last_draw_participated == current_draw_id {
+        user_stats.tickets_this_draw
+    } else {
+        0
+    };
+    require!(
+        user_tickets_this_draw < MAX_TICKETS_PER_DRAW_PER_USER,
+        LottoError::MaxTicketsPerDrawExceeded
+    );
+
+    // Fund the ticket from the reserve
+    require!(
+        lottery_state.reserve_balance >= ticket_price,
+        LottoError::InsufficientReserveForReferral
+    );
+    let (jackpot_share, fixed_prize_share) = split_reserve_funded_ticket(ticket_price);
+    lottery_state.reserve_balance = lottery_state
+        .reserve_balance
+        .checked_sub(jackpot_share)
+        .and_then(|v| v.checked_sub(fixed_prize_share))
+        .ok_or(LottoError::Underflow)?;
+    let old_jackpot_balance = lottery_state.jackpot_balance;
+    lottery_state.jackpot_balance = lottery_state
+        .jackpot_balance
+        .checked_add(jackpot_share)
+        .ok_or(LottoError::Overflow)?;
+    route_jackpot_overflow(lottery_state)?;
+    unlock_milestone_bonuses(lottery_state, old_jackpot_balance);
+    lottery_state.fixed_prize_balance = lottery_state
+        .fixed_prize_balance
+        .checked_add(fixed_prize_share)
+        .ok_or(LottoError::Overflow)?;
+
+    lottery_state.current_draw_tickets = lottery_state
+        .current_draw_tickets
+        .checked_add(1)
+        .ok_or(LottoError::Overflow)?;
+    lottery_state.total_tickets_sold = lottery_state
+        .total_tickets_sold
+        .checked_add(1)
+        .ok_or(LottoError::Overflow)?;
+
+    // Create ticket
+    let ticket = &mut ctx.accounts.ticket;
+    ticket.schema_version = CURRENT_SCHEMA_VERSION;
+    ticket.owner = player;
+    ticket.draw_id = current_draw_id;
+    ticket.numbers = sorted_numbers;
+    ticket.purchase_timestamp = clock.unix_timestamp;
+    ticket.is_claimed = false;
+    ticket.match_count = 0;
+    ticket.prize_amount = 0;
+    ticket.syndicate = None;
+    ticket.original_owner = player;
+    ticket.entered_second_chance = false;
+    ticket.bump = ctx.bumps.ticket;
+    // Reserve-funded, so there is no purchase to refund if the draw is cancelled
+    ticket.requires_refund = false;
+    ticket.claim_agent = None;
+
+    // Update user stats (redeemed tickets are not purchases, so total_tickets
+    // and total_spent are left unchanged)
+    if user_stats.last_draw_participated != current_draw_id {
+        user_stats.tickets_this_draw = 1;
+        user_stats.last_draw_participated = current_draw_id;
+    } else {
+        user_stats.tickets_this_draw = user_stats
+            .tickets_this_draw
+            .checked_add(1)
+            .ok_or(LottoError::Overflow)?;
+    }
+    user_stats.update_streak(current_draw_id);
+    user_stats.referral_credits = user_stats
+        .referral_credits
+        .checked_sub(1)
+        .ok_or(LottoError::NoReferralCredits)?;
+
+    emit!(TicketPurchased {
+        ticket: ctx.accounts.ticket.key(),
+        player,
+        draw_id: current_draw_id,
+        numbers: sorted_numbers,
+        price: 0,
+        syndicate: None,
+        timestamp: clock.unix_timestamp,
+    });
+
+    emit!(ReferralCreditRedeemed {
+        player,
+        draw_id: current_draw_id,
+        credits_remaining: user_stats.referral_credits,
+    });
+
+    msg!("Referral credit redeemed!");
+    msg!("  Player: {}", player);
+    msg!("  Draw ID: {}", current_draw_id);
+    msg!("  Numbers: {:?}", sorted_numbers);
+    msg!("  Funded from reserve: {} USDC lamports", ticket_price);
+    msg!("  Credits remaining: {}", user_stats.referral_credits);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crossed_referral_threshold() {
+        let q = REFERRAL_QUALIFYING_TICKETS;
+        assert!(crossed_referral_threshold(q - 1, q));
+        // Bulk purchases can jump past the threshold
+        assert!(crossed_referral_threshold(0, q + 10));
+        assert!(!crossed_referral_threshold(q, q + 1));
+        assert!(!crossed_referral_threshold(0, q - 1));
+    }
+
+    #[test]
+    fn test_validate_referral() {
+        let player = Pubkey::new_unique();
+        let referrer = Pubkey::new_unique();
+        assert!(validate_referral(&player, &referrer, None, None, 0).is_ok());
+        // Self-referral
+        assert!(validate_referral(&player, &player, None, None, 0).is_err());
+        // Already set
+        assert!(validate_referral(&player, &referrer, Some(referrer), None, 0).is_err());
+        // Circular referral
+        assert!(validate_referral(&player, &referrer, None, Some(player), 0).is_err());
+        // Already qualified
+        assert!(
+            validate_referral(&player, &referrer, None, None, REFERRAL_QUALIFYING_TICKETS).is_err()
+        );
+    }
+
+    #[test]
+    fn test_split_reserve_funded_ticket() {
+        let (jackpot, fixed) = split_reserve_funded_ticket(TICKET_PRICE);
+        assert!(jackpot + fixed <= TICKET_PRICE);
+        assert_eq!(jackpot, 1_390_000);
+        assert_eq!(fixed, 985_000);
+    }
+}
@@ -0,0 +1,459 @@
+//! Reserve History Instructions
+//!
+//! A singleton `ReserveHistory` keeps the reserve balance, prizes and ticket
+//! sales of the last RESERVE_HISTORY_LENGTH finalized draws. `finalize_draw`
+//! appends to it, and `compute_reserve_health` turns it into rolling
+//! averages so operators can see whether the reserve keeps up with payouts.
+//! When the reserve holds more than `reserve_target_multiplier_bps` of the
+//! average prizes per draw, the excess can be moved into the jackpot.
+//!
+//! - initialize_reserve_history: Create the buffer (authority only)
+//! - compute_reserve_health: Emit coverage, trend and allocation metrics
+//! - rebalance_reserve_to_jackpot: Move reserve excess into the jackpot (anyone)
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{ReserveCoverageAlert, ReserveHealthReport, ReserveToJackpotRebalanced};
+use crate::state::{LotteryState, ReserveHistory, ReserveSnapshot, SchemaVersioned};
+
+/// Rolling reserve metrics computed from the history window
+#[derive(Debug, PartialEq, Eq)]
+pub struct ReserveHealth {
+    pub coverage_ratio_draws: u64,
+    pub trend_bps: i64,
+    pub recommended_reserve_allocation_bps: u16,
+    pub avg_prizes_per_draw: u64,
+    pub avg_revenue_per_draw: u64,
+    pub draws_sampled: u8,
+}
+
+/// Compute reserve health from `snapshots` (oldest first)
+///
+/// Coverage is `reserve_balance / avg_prizes_per_draw`. The trend compares
+/// the newest snapshot's reserve with the oldest one. When coverage is
+/// below a nonzero `minimum_coverage_draws`, the recommended allocation
+/// scales RESERVE_ALLOCATION_BPS up by the shortfall, capped at
+/// MAX_RECOMMENDED_RESERVE_ALLOCATION_BPS. Returns `None` without snapshots.
+pub fn compute_reserve_health_metrics(
+    snapshots: &[ReserveSnapshot],
+    reserve_balance: u64,
+    ticket_price: u64,
+    minimum_coverage_draws: u64,
+) -> Option<ReserveHealth> {
+    let (oldest, newest) = (snapshots.first()?, snapshots.last()?);
+    let draws = snapshots.len() as u128;
+
+    let total_prizes: u128 = snapshots.iter().map(|s| s.prizes_distributed as u128).sum();
+    let total_revenue: u128 = snapshots
+        .iter()
+        .map(|s| s.tickets_sold as u128 * ticket_price as u128)
+        .sum();
+    let avg_prizes_per_draw = (total_prizes / draws).min(u64::MAX as u128) as u64;
+    let avg_revenue_per_draw = (total_revenue / draws).min(u64::MAX as u128) as u64;
+
+    let coverage_ratio_draws = if avg_prizes_per_draw == 0 {
+        u64::MAX
+    } else {
+        reserve_balance / avg_prizes_per_draw
+    };
+
+    let trend_bps = if oldest.reserve_balance == 0 {
+        if newest.reserve_balance > 0 {
+            BPS_DENOMINATOR as i64
+        } else {
+            0
+        }
+    } else {
+        let change = newest.reserve_balance as i128 - oldest.reserve_balance as i128;
+        (change * BPS_DENOMINATOR as i128 / oldest.reserve_balance as i128)
+            .clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    };
+
+    let recommended_reserve_allocation_bps =
+        if minimum_coverage_draws > 0 && coverage_ratio_draws < minimum_coverage_draws {
+            let scaled = RESERVE_ALLOCATION_BPS as u128 * minimum_coverage_draws as u128
+                / coverage_ratio_draws.max(1) as u128;
+            scaled.min(MAX_RECOMMENDED_RESERVE_ALLOCATION_BPS as u128) as u16
+        } else {
+            RESERVE_ALLOCATION_BPS
+        };
+
+    Some(ReserveHealth {
+        coverage_ratio_draws,
+        trend_bps,
+        recommended_reserve_allocation_bps,
+        avg_prizes_per_draw,
+        avg_revenue_per_draw,
+        draws_sampled: snapshots.len() as u8,
+    })
+}
+
+/// Reserve excess that `rebalance_reserve_to_jackpot` moves into the jackpot
+///
+/// The reserve keeps `avg_prizes_per_draw * multiplier_bps / BPS_DENOMINATOR`,
+/// and never less than `reserve_minimum`; the rest moves, up to what the
+/// jackpot needs to reach `soft_cap`. Nothing moves without prize data.
+pub fn reserve_rebalance_amount(
+    reserve_balance: u64,
+    jackpot_balance: u64,
+    soft_cap: u64,
+    avg_prizes_per_draw: u64,
+    multiplier_bps: u16,
+    reserve_minimum: u64,
+) -> u64 {
+    if avg_prizes_per_draw == 0 {
+        return 0;
+    }
+    let target_reserve = (avg_prizes_per_draw as u128 * multiplier_bps as u128
+        / BPS_DENOMINATOR as u128)
+        .min(u64::MAX as u128) as u64;
+    reserve_balance
+        .saturating_sub(target_reserve.max(reserve_minimum))
+        .min(soft_cap.saturating_sub(jackpot_balance))
+}
+
+// ============================================================================
+// INITIALIZE RESERVE HISTORY
+// ============================================================================
+
+/// Accounts required for creating the reserve history buffer
+#[derive(Accounts)]
+pub struct InitializeReserveHistory<'info> {
+    /// The lottery authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The reserve history buffer to be created (starts empty)
+    #[account(
+        init,
+        payer = authority,
+        space = RESERVE_HISTORY_SIZE,
+        seeds = [RESERVE_HISTORY_SEED],
+        bump
+    )]
+    pub reserve_history: Box<Account<'info, ReserveHistory>>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the reserve history buffer
+///
+/// Must run before the next `finalize_draw`, which records a reserve
+/// snapshot for every finalized draw.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_initialize_reserve_history(ctx: Context<InitializeReserveHistory>) -> Result<()> {
+    let reserve_history = &mut ctx.accounts.reserve_history;
+    reserve_history.bump = ctx.bumps.reserve_history;
+    reserve_history.head = 0;
+    reserve_history.total_snapshots = 0;
+
+    msg!("Reserve history initialized!");
+    msg!("  Buffer: {}", reserve_history.key());
+    msg!("  Capacity: {} draws", RESERVE_HISTORY_LENGTH);
+
+    Ok(())
+}
+
+// ============================================================================
+// COMPUTE RESERVE HEALTH
+// ============================================================================
+
+/// Accounts required for computing reserve health
+#[derive(Accounts)]
+pub struct ComputeReserveHealth<'info> {
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The reserve history buffer
+    #[account(
+        seeds = [RESERVE_HISTORY_SEED],
+        bump = reserve_history.bump
+    )]
+    pub reserve_history: Box<Account<'info, ReserveHistory>>,
+}
+
+/// Emit rolling reserve health metrics
+///
+/// Permissionless and read-only; emits `ReserveHealthReport`, plus
+/// `ReserveCoverageAlert` when coverage is below the lottery's nonzero
+/// `reserve_minimum_coverage_draws`.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_compute_reserve_health(ctx: Context<ComputeReserveHealth>) -> Result<()> {
+    let lottery_state = &ctx.accounts.lottery_state;
+    let reserve_balance = lottery_state.reserve_balance;
+    let minimum_coverage_draws = lottery_state.reserve_minimum_coverage_draws;
+
+    let health = compute_reserve_health_metrics(
+        &ctx.accounts.reserve_history.chronological(),
+        reserve_balance,
+        lottery_state.ticket_price,
+        minimum_coverage_draws,
+    )
+    .ok_or(LottoError::ReserveHistoryEmpty)?;
+
+    emit!(ReserveHealthReport {
+        coverage_ratio_draws: health.coverage_ratio_draws,
+        trend_bps: health.trend_bps,
+        recommended_reserve_allocation_bps: health.recommended_reserve_allocation_bps,
+        avg_prizes_per_draw: health.avg_prizes_per_draw,
+        avg_revenue_per_draw: health.avg_revenue_per_draw,
+        draws_sampled: health.draws_sampled,
+    });
+
+    msg!("Reserve health report");
+    msg!("  Draws sampled: {}", health.draws_sampled);
+    msg!("  Reserve balance: {} USDC lamports", reserve_balance);
+    msg!(
+        "  Avg prizes per draw: {} USDC lamports",
+        health.avg_prizes_per_draw
+    );
+    msg!(
+        "  Avg revenue per draw: {} USDC lamports",
+        health.avg_revenue_per_draw
+    );
+    msg!("  Coverage: {} draws", health.coverage_ratio_draws);
+    msg!("  Trend: {} bps", health.trend_bps);
+    msg!(
+        "  Recommended reserve allocation: {} bps",
+        health.recommended_reserve_allocation_bps
+    );
+
+    if minimum_coverage_draws > 0 && health.coverage_ratio_draws < minimum_coverage_draws {
+        emit!(ReserveCoverageAlert {
+            coverage_ratio_draws: health.coverage_ratio_draws,
+            minimum_coverage_draws,
+            reserve_balance,
+        });
+        msg!(
+            "⚠️  Reserve coverage {} draws is below the minimum of {}",
+            health.coverage_ratio_draws,
+            minimum_coverage_draws
+        );
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// REBALANCE RESERVE TO JACKPOT
+// ============================================================================
+
+/// Accounts required for moving reserve excess into the jackpot
+#[derive(Accounts)]
+pub struct RebalanceReserveToJackpot<'info> {
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = !lottery_state.is_draw_in_progress @ LottoError::DrawInProgress,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The reserve history buffer
+    #[account(
+        seeds = [RESERVE_HISTORY_SEED],
+        bump = reserve_history.bump
+    )]
+    pub reserve_history: Box<Account<'info, ReserveHistory>>,
+}
+
+/// Move the reserve's excess over its target into the jackpot
+///
+/// Permissionless. Both balances are held in the prize pool, so no USDC
+/// moves. Accelerates jackpot growth during low-ticket periods.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_rebalance_reserve_to_jackpot(ctx: Context<RebalanceReserveToJackpot>) -> Result<()> {
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    let health = compute_reserve_health_metrics(
+        &ctx.accounts.reserve_history.chronological(),
+        lottery_state.reserve_balance,
+        lottery_state.ticket_price,
+        lottery_state.reserve_minimum_coverage_draws,
+    )
+    .ok_or(LottoError::ReserveHistoryEmpty)?;
+    require!(
+        health.avg_prizes_per_draw > 0,
+        LottoError::NoReservePrizeHistory
+    );
+
+    let amount = reserve_rebalance_amount(
+        lottery_state.reserve_balance,
+        lottery_state.jackpot_balance,
+        lottery_state.soft_cap,
+        health.avg_prizes_per_draw,
+        lottery_state.reserve_target_multiplier_bps,
+        lottery_state.reserve_minimum,
+    );
+    require!(amount > 0, LottoError::NoReserveExcess);
+
+    lottery_state.reserve_balance -= amount;
+    lottery_state.jackpot_balance = lottery_state
+        .jackpot_balance
+        .checked_add(amount)
+        .ok_or(LottoError::Overflow)?;
+    lottery_state.house_fee_bps = lottery_state.get_current_house_fee_bps();
+    if lottery_state.jackpot_balance >= lottery_state.soft_cap {
+        lottery_state.is_rolldown_active = true;
+    }
+
+    emit!(ReserveToJackpotRebalanced {
+        amount,
+        new_jackpot: lottery_state.jackpot_balance,
+        new_reserve: lottery_state.reserve_balance,
+    });
+
+    msg!("Reserve rebalanced into the jackpot");
+    msg!("  Amount: {} USDC lamports", amount);
+    msg!(
+        "  New jackpot: {} USDC lamports",
+        lottery_state.jackpot_balance
+    );
+    msg!(
+        "  New reserve: {} USDC lamports",
+        lottery_state.reserve_balance
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(draw_id: u64, reserve_balance: u64, prizes: u64, tickets: u64) -> ReserveSnapshot {
+        ReserveSnapshot {
+            draw_id,
+            reserve_balance,
+            prizes_distributed: prizes,
+            tickets_sold: tickets,
+        }
+    }
+
+    #[test]
+    fn test_reserve_history_wraps_oldest_first() {
+        let mut history = ReserveHistory::default();
+        let pushed = RESERVE_HISTORY_LENGTH as u64 + 3;
+        for draw_id in 1..=pushed {
+            history.push(snapshot(draw_id, 0, 0, 0));
+        }
+
+        let draw_ids: Vec<u64> = history.chronological().iter().map(|s| s.draw_id).collect();
+        assert_eq!(draw_ids, (4..=pushed).collect::<Vec<u64>>());
+        assert_eq!(history.head, 3);
+        assert_eq!(history.total_snapshots, pushed);
+    }
+
+    #[test]
+    fn test_reserve_health_metrics() {
+        assert_eq!(
+            compute_reserve_health_metrics(&[], 0, TICKET_PRICE, 0),
+            None
+        );
+
+        let snapshots = [
+            snapshot(1, 1_000_000_000, 100_000_000, 100),
+            snapshot(2, 1_100_000_000, 300_000_000, 300),
+        ];
+        let health =
+            compute_reserve_health_metrics(&snapshots, 1_100_000_000, TICKET_PRICE, 0).unwrap();
+
+        assert_eq!(health.avg_prizes_per_draw, 200_000_000);
+        assert_eq!(health.avg_revenue_per_draw, 200 * TICKET_PRICE);
+        assert_eq!(health.coverage_ratio_draws, 5);
+        assert_eq!(health.trend_bps, 1000);
+        assert_eq!(
+            health.recommended_reserve_allocation_bps,
+            RESERVE_ALLOCATION_BPS
+        );
+        assert_eq!(health.draws_sampled, 2);
+    }
+
+    #[test]
+    fn test_reserve_rebalance_amount() {
+        // Target is 3x 100: 200 of excess, jackpot needs 500
+        assert_eq!(
+            reserve_rebalance_amount(500, 500, 1_000, 100, 30_000, 0),
+            200
+        );
+        // Capped by what the jackpot needs to reach the soft cap
+        assert_eq!(
+            reserve_rebalance_amount(500, 900, 1_000, 100, 30_000, 0),
+            100
+        );
+        assert_eq!(
+            reserve_rebalance_amount(500, 1_000, 1_000, 100, 30_000, 0),
+            0
+        );
+        // Reserve at or below target
+        assert_eq!(reserve_rebalance_amount(300, 0, 1_000, 100, 30_000, 0), 0);
+        // reserve_minimum above the target is the floor
+        assert_eq!(
+            reserve_rebalance_amount(500, 0, 1_000, 100, 30_000, 450),
+            50
+        );
+        assert_eq!(reserve_rebalance_amount(500, 0, 1_000, 100, 30_000, 600), 0);
+        // No prize data: nothing moves
+        assert_eq!(reserve_rebalance_amount(500, 0, 1_000, 0, 30_000, 0), 0);
+    }
+
+    #[test]
+    fn test_reserve_health_recommends_more_when_under_covered() {
+        let snapshots = [snapshot(1, 400_000_000, 200_000_000, 0)];
+
+        // Coverage 2 draws against a minimum of 4: allocation doubles
+        let health =
+            compute_reserve_health_metrics(&snapshots, 400_000_000, TICKET_PRICE, 4).unwrap();
+        assert_eq!(health.coverage_ratio_draws, 2);
+        assert_eq!(
+            health.recommended_reserve_allocation_bps,
+            RESERVE_ALLOCATION_BPS * 2
+        );
+
+        // Empty reserve: capped recommendation, shrinking trend
+        let shrinking = [
+            snapshot(1, 400_000_000, 200_000_000, 0),
+            snapshot(2, 0, 200_000_000, 0),
+        ];
+        let health = compute_reserve_health_metrics(&shrinking, 0, TICKET_PRICE, 4).unwrap();
+        assert_eq!(health.coverage_ratio_draws, 0);
+        assert_eq!(health.trend_bps, -10_000);
+        assert_eq!(
+            health.recommended_reserve_allocation_bps,
+            MAX_RECOMMENDED_RESERVE_ALLOCATION_BPS
+        );
+    }
+}
@@ -0,0 +1,780 @@
+//! Second Chance Draw Instructions
+//!
+//! Non-winning tickets of a finalized draw can be entered into that draw's
+//! second chance draw. The authority allocates the prize from
+//! `lottery_state.reserve_balance` and, once entries are in, draws a single
+//! winner with Switchboard randomness.
+//!
+//! - add_second_chance_prize: Allocate reserve funds to a draw's second chance prize
+//! - enter_second_chance: Enter a non-winning ticket (one entry per ticket)
+//! - commit_second_chance_randomness: Commit to unrevealed randomness and close entries
+//! - execute_second_chance_draw: Pick the winning entry and pay the prize
+//! - reclaim_second_chance_prize: Return an empty or expired draw's prize to the reserve
+//!
+//! The randomness account and its seed slot are fixed at commit, before the
+//! value is revealed, and execute only accepts that account's reveal.
+//!
+//! The prize never leaves the prize pool token account until it is paid, so
+//! funding only moves the amount from the reserve bucket into the draw.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use switchboard_on_demand::accounts::RandomnessAccountData;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{
+    SecondChancePrizeReclaimed, SecondChanceRandomnessCommitted, SecondChanceWinner,
+};
+use crate::instructions::close_ticket::is_claim_window_expired;
+use crate::instructions::commit_randomness::get_randomness_seed_slot;
+use crate::instructions::oracle_health::is_switchboard_program;
+use crate::state::{
+    DrawResult, LotteryState, SchemaVersioned, SecondChanceDraw, SecondChanceEntry, TicketData,
+};
+
+// ============================================================================
+// ADD SECOND CHANCE PRIZE
+// ============================================================================
+
+/// Accounts required for funding a second chance draw
+#[derive(Accounts)]
+#[instruction(draw_id: u64)]
+pub struct AddSecondChancePrize<'info> {
+    /// Lottery authority (pays for the draw account on first funding)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The second chance draw account (created on first funding)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SECOND_CHANCE_DRAW_SIZE,
+        seeds = [SECOND_CHANCE_SEED, &draw_id.to_le_bytes()],
+        bump
+    )]
+    pub second_chance_draw: Account<'info, SecondChanceDraw>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Allocate reserve funds to a draw's second chance prize
+///
+/// Can be called repeatedly to top up the prize until the draw is resolved.
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+/// * `draw_id` - Main draw the second chance draw belongs to
+/// * `amount` - USDC lamports moved from the reserve to the prize
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_add_second_chance_prize(
+    ctx: Context<AddSecondChancePrize>,
+    draw_id: u64,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, LottoError::InvalidAmount);
+
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    require!(
+        draw_id > 0 && draw_id <= lottery_state.current_draw_id,
+        LottoError::DrawIdMismatch
+    );
+    require!(
+        lottery_state.reserve_balance >= amount,
+        LottoError::InsufficientReserveForSecondChance
+    );
+
+    let second_chance_draw = &mut ctx.accounts.second_chance_draw;
+    require!(
+        !second_chance_draw.is_resolved,
+        LottoError::SecondChanceAlreadyResolved
+    );
+
+    // Initialize if new (draw IDs start at 1)
+    if second_chance_draw.draw_id == 0 {
+        second_chance_draw.draw_id = draw_id;
+        second_chance_draw.bump = ctx.bumps.second_chance_draw;
+    }
+
+    lottery_state.reserve_balance = lottery_state
+        .reserve_balance
+        .checked_sub(amount)
+        .ok_or(LottoError::Underflow)?;
+    second_chance_draw.prize_pool = second_chance_draw
+        .prize_pool
+        .checked_add(amount)
+        .ok_or(LottoError::Overflow)?;
+
+    msg!("Second chance prize funded!");
+    msg!("  Draw: #{}", draw_id);
+    msg!("  Added: {} USDC lamports", amount);
+    msg!(
+        "  Prize pool: {} USDC lamports",
+        second_chance_draw.prize_pool
+    );
+    msg!(
+        "  Remaining reserve: {} USDC lamports",
+        lottery_state.reserve_balance
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// ENTER SECOND CHANCE
+// ============================================================================
+
+/// Accounts required for entering a ticket into a second chance draw
+#[derive(Accounts)]
+pub struct EnterSecondChance<'info> {
+    /// The ticket owner (pays for the entry account)
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// The ticket being entered
+    #[account(
+        mut,
+        constraint = ticket.owner == player.key() @ LottoError::NotTicketOwner,
+        constraint = !ticket.is_claimed @ LottoError::AlreadyClaimed,
+        constraint = !ticket.entered_second_chance @ LottoError::SecondChanceAlreadyEntered,
+        constraint = ticket.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub ticket: Account<'info, TicketData>,
+
+    /// The draw result for the ticket's draw
+    #[account(
+        seeds = [DRAW_SEED, &ticket.draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = draw_result.draw_id == ticket.draw_id @ LottoError::DrawIdMismatch,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub draw_result: Account<'info, DrawResult>,
+
+    /// The second chance draw for the ticket's draw
+    #[account(
+        mut,
+        seeds = [SECOND_CHANCE_SEED, &ticket.draw_id.to_le_bytes()],
+        bump = second_chance_draw.bump,
+        constraint = !second_chance_draw.is_resolved @ LottoError::SecondChanceAlreadyResolved,
+        constraint = !second_chance_draw.is_randomness_committed() @ LottoError::SecondChanceRandomnessCommitted
+    )]
+    pub second_chance_draw: Account<'info, SecondChanceDraw>,
+
+    /// The entry account to be created
+    #[account(
+        init,
+        payer = player,
+        space = SECOND_CHANCE_ENTRY_SIZE,
+        seeds = [
+            SECOND_CHANCE_ENTRY_SEED,
+            &ticket.draw_id.to_le_bytes(),
+            &second_chance_draw.entry_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub entry: Account<'info, SecondChanceEntry>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Check whether a ticket qualifies for the second chance draw
+///
+/// Only tickets that won nothing qualify. Match 2 pays a free ticket, so a
+/// ticket needs fewer than 2 matches, and `match_count` must not have been
+/// recorded by a claim.
+///
+/// # Arguments
+/// * `ticket_numbers` - The ticket's numbers (sorted)
+/// * `winning_numbers` - The draw's winning numbers (sorted)
+/// * `recorded_match_count` - The ticket's stored `match_count`
+pub fn is_second_chance_eligible(
+    ticket_numbers: &[u8; NUMBERS_PER_TICKET],
+    winning_numbers: &[u8; NUMBERS_PER_TICKET],
+    recorded_match_count: u8,
+) -> bool {
+    recorded_match_count == 0 && calculate_match_count(ticket_numbers, winning_numbers) < 2
+}
+
+/// Enter a non-winning ticket into its draw's second chance draw
+///
+/// This instruction:
+/// 1. Verifies the draw is finalized and the ticket won nothing
+/// 2. Creates the entry at the next entry index
+/// 3. Marks the ticket as entered and increments the entry count
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_enter_second_chance(ctx: Context<EnterSecondChance>) -> Result<()> {
+    let draw_result = &ctx.accounts.draw_result;
+    require!(draw_result.is_finalized(), LottoError::DrawNotFinalized);
+
+    let ticket = &mut ctx.accounts.ticket;
+    require!(
+        is_second_chance_eligible(
+            &ticket.numbers,
+            &draw_result.winning_numbers,
+            ticket.match_count
+        ),
+        LottoError::SecondChanceNotEligible
+    );
+
+    let second_chance_draw = &mut ctx.accounts.second_chance_draw;
+    let entry_index = second_chance_draw.entry_count;
+
+    let entry = &mut ctx.accounts.entry;
+    entry.draw_id = ticket.draw_id;
+    entry.ticket = ticket.key();
+    entry.owner = ticket.owner;
+    entry.entry_index = entry_index;
+    entry.bump = ctx.bumps.entry;
+
+    ticket.entered_second_chance = true;
+    second_chance_draw.entry_count = entry_index.checked_add(1).ok_or(LottoError::Overflow)?;
+
+    msg!("Ticket entered into second chance draw!");
+    msg!("  Draw: #{}", ticket.draw_id);
+    msg!("  Ticket: {}", ticket.key());
+    msg!("  Entry index: {}", entry_index);
+
+    Ok(())
+}
+
+// ============================================================================
+// COMMIT SECOND CHANCE RANDOMNESS
+// ============================================================================
+
+/// Accounts required for committing randomness to a second chance draw
+#[derive(Accounts)]
+pub struct CommitSecondChanceRandomness<'info> {
+    /// Lottery authority
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = !lottery_state.is_paused @ LottoError::Paused,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The second chance draw being committed
+    #[account(
+        mut,
+        seeds = [SECOND_CHANCE_SEED, &second_chance_draw.draw_id.to_le_bytes()],
+        bump = second_chance_draw.bump,
+        constraint = !second_chance_draw.is_resolved @ LottoError::SecondChanceAlreadyResolved,
+        constraint = !second_chance_draw.is_randomness_committed() @ LottoError::SecondChanceRandomnessCommitted
+    )]
+    pub second_chance_draw: Account<'info, SecondChanceDraw>,
+
+    /// The Switchboard randomness account (fresh and not yet revealed)
+    /// CHECK: Must be owned by Switchboard; parsed manually as
+    /// RandomnessAccountData
+    pub randomness_account_data: AccountInfo<'info>,
+}
+
+/// Check that a randomness account is a Switchboard account on `expected_queue`
+fn require_lottery_randomness(
+    randomness_account_data: &AccountInfo,
+    expected_queue: &Pubkey,
+) -> Result<()> {
+    require!(
+        is_switchboard_program(randomness_account_data.owner),
+        LottoError::InvalidRandomnessAccount
+    );
+    let randomness_data = RandomnessAccountData::parse(randomness_account_data.data.borrow())
+        .map_err(|_| LottoError::RandomnessParseError)?;
+    require!(
+        randomness_data.queue.to_bytes() == expected_queue.to_bytes(),
+        LottoError::RandomnessQueueMismatch
+    );
+    Ok(())
+}
+
+/// Commit a second chance draw to unrevealed Switchboard randomness
+///
+/// This instruction:
+/// 1. Verifies the randomness account is on the lottery's queue, fresh and
+///    not yet revealed
+/// 2. Stores the account and its seed slot on the second chance draw
+/// 3. Closes entries, so the entry set is fixed before the value is known
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_commit_second_chance_randomness(
+    ctx: Context<CommitSecondChanceRandomness>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let randomness_account_data = &ctx.accounts.randomness_account_data;
+
+    let second_chance_draw = &mut ctx.accounts.second_chance_draw;
+    require!(
+        second_chance_draw.entry_count > 0,
+        LottoError::NoSecondChanceEntries
+    );
+
+    require_lottery_randomness(
+        randomness_account_data,
+        &ctx.accounts.lottery_state.switchboard_queue,
+    )?;
+    let seed_slot = get_randomness_seed_slot(randomness_account_data, clock.slot)?;
+
+    second_chance_draw.randomness_account = randomness_account_data.key();
+    second_chance_draw.commit_slot = seed_slot;
+
+    emit!(SecondChanceRandomnessCommitted {
+        draw_id: second_chance_draw.draw_id,
+        randomness_account: randomness_account_data.key(),
+        commit_slot: seed_slot,
+        entry_count: second_chance_draw.entry_count,
+    });
+
+    msg!("Second chance randomness committed!");
+    msg!("  Draw: #{}", second_chance_draw.draw_id);
+    msg!("  Randomness account: {}", randomness_account_data.key());
+    msg!("  Commit slot: {}", seed_slot);
+    msg!("  Entries (closed): {}", second_chance_draw.entry_count);
+
+    Ok(())
+}
+
+// ============================================================================
+// EXECUTE SECOND CHANCE DRAW
+// ============================================================================
+
+/// Accounts required for resolving a second chance draw
+///
+/// Remaining accounts must be, in order:
+/// 0. The winning `SecondChanceEntry` (writable not required)
+/// 1. The winner's USDC token account (writable)
+#[derive(Accounts)]
+pub struct ExecuteSecondChanceDraw<'info> {
+    /// Lottery authority
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = !lottery_state.is_paused @ LottoError::Paused,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The second chance draw being resolved
+    #[account(
+        mut,
+        seeds = [SECOND_CHANCE_SEED, &second_chance_draw.draw_id.to_le_bytes()],
+        bump = second_chance_draw.bump,
+        constraint = !second_chance_draw.is_resolved @ LottoError::SecondChanceAlreadyResolved,
+        constraint = second_chance_draw.is_randomness_committed() @ LottoError::SecondChanceRandomnessNotCommitted
+    )]
+    pub second_chance_draw: Account<'info, SecondChanceDraw>,
+
+    /// The committed Switchboard randomness account
+    /// CHECK: Must be the committed account and owned by Switchboard; parsed
+    /// manually as RandomnessAccountData
+    #[account(
+        constraint = randomness_account_data.key() == second_chance_draw.randomness_account @ LottoError::InvalidRandomnessAccount
+    )]
+    pub randomness_account_data: AccountInfo<'info>,
+
+    /// Prize pool USDC token account
+    #[account(
+        mut,
+        seeds = [PRIZE_POOL_USDC_SEED],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+
+    /// USDC mint
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ExecuteSecondChanceDraw<'info> {
+    /// Parse and validate the committed randomness account, returning the
+    /// revealed value
+    pub fn get_revealed_randomness(&self) -> Result<[u8; 32]> {
+        require_lottery_randomness(
+            &self.randomness_account_data,
+            &self.lottery_state.switchboard_queue,
+        )?;
+        let randomness_data =
+            RandomnessAccountData::parse(self.randomness_account_data.data.borrow())
+                .map_err(|_| LottoError::RandomnessParseError)?;
+
+        committed_reveal_value(
+            randomness_data.seed_slot,
+            randomness_data.reveal_slot,
+            &randomness_data.value,
+            self.second_chance_draw.commit_slot,
+        )
+    }
+}
+
+/// Revealed value of the randomness committed at `commit_slot`
+///
+/// The account must still hold the commit (`seed_slot == commit_slot`) and
+/// have been revealed after it. The value stays fixed once revealed, so it
+/// is read directly rather than only in the reveal slot.
+pub fn committed_reveal_value(
+    seed_slot: u64,
+    reveal_slot: u64,
+    value: &[u8; 32],
+    commit_slot: u64,
+) -> Result<[u8; 32]> {
+    require!(seed_slot == commit_slot, LottoError::RandomnessNotFresh);
+    require!(
+        reveal_slot > seed_slot && *value != [0u8; 32],
+        LottoError::RandomnessNotResolved
+    );
+    Ok(*value)
+}
+
+/// Select the winning entry index from revealed randomness
+///
+/// # Arguments
+/// * `randomness` - The revealed Switchboard value
+/// * `entry_count` - Number of entries (must be non-zero)
+pub fn select_second_chance_winner(randomness: &[u8; 32], entry_count: u64) -> u64 {
+    let mut vrf_bytes = [0u8; 8];
+    vrf_bytes.copy_from_slice(&randomness[0..8]);
+    u64::from_le_bytes(vrf_bytes) % entry_count
+}
+
+/// Draw the second chance winner and pay the prize
+///
+/// This instruction:
+/// 1. Reads the reveal of the randomness committed by
+///    `commit_second_chance_randomness`
+/// 2. Picks `winner_index = vrf_u64 % entry_count`
+/// 3. Verifies the winning entry and payout account from remaining_accounts
+/// 4. Transfers the prize and marks the draw resolved
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_execute_second_chance_draw<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteSecondChanceDraw<'info>>,
+) -> Result<()> {
+    let draw_id = ctx.accounts.second_chance_draw.draw_id;
+    let entry_count = ctx.accounts.second_chance_draw.entry_count;
+    let prize = ctx.accounts.second_chance_draw.prize_pool;
+    require!(entry_count > 0, LottoError::NoSecondChanceEntries);
+
+    let randomness = ctx.accounts.get_revealed_randomness()?;
+    let winner_index = select_second_chance_winner(&randomness, entry_count);
+
+    require!(
+        ctx.remaining_accounts.len() >= 2,
+        LottoError::InvalidSecondChanceWinner
+    );
+    let entry_info = &ctx.remaining_accounts[0];
+    let winner_usdc_info = &ctx.remaining_accounts[1];
+
+    // Verify the entry is the PDA at the selected index
+    require!(
+        entry_info.owner == ctx.program_id,
+        LottoError::InvalidAccountOwner
+    );
+    let entry = {
+        let data = entry_info.try_borrow_data()?;
+        let mut slice: &[u8] = &data;
+        SecondChanceEntry::try_deserialize(&mut slice)
+            .map_err(|_| LottoError::InvalidAccountData)?
+    };
+    let expected_entry = Pubkey::create_program_address(
+        &[
+            SECOND_CHANCE_ENTRY_SEED,
+            &draw_id.to_le_bytes(),
+            &winner_index.to_le_bytes(),
+            &[entry.bump],
+        ],
+        ctx.program_id,
+    )
+    .map_err(|_| LottoError::InvalidSecondChanceWinner)?;
+    require!(
+        entry_info.key() == expected_entry
+            && entry.draw_id == draw_id
+            && entry.entry_index == winner_index,
+        LottoError::InvalidSecondChanceWinner
+    );
+
+    // Verify the payout account belongs to the winner
+    require!(
+        winner_usdc_info.is_writable,
+        LottoError::InvalidSecondChanceWinner
+    );
+    let winner_usdc: Account<'info, TokenAccount> = Account::try_from(winner_usdc_info)?;
+    require!(
+        winner_usdc.owner == entry.owner,
+        LottoError::TokenAccountOwnerMismatch
+    );
+    require!(
+        winner_usdc.mint == ctx.accounts.usdc_mint.key(),
+        LottoError::InvalidUsdcMint
+    );
+
+    if prize > 0 {
+        require!(
+            ctx.accounts.prize_pool_usdc.amount >= prize,
+            LottoError::InsufficientPrizePool
+        );
+
+        let lottery_bump = ctx.accounts.lottery_state.bump;
+        let seeds = &[LOTTERY_SEED, &[lottery_bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.prize_pool_usdc.to_account_info(),
+            to: winner_usdc.to_account_info(),
+            authority: ctx.accounts.lottery_state.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, prize)?;
+    }
+
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    lottery_state.total_prizes_paid = lottery_state
+        .total_prizes_paid
+        .checked_add(prize)
+        .ok_or(LottoError::Overflow)?;
+
+    let second_chance_draw = &mut ctx.accounts.second_chance_draw;
+    second_chance_draw.winner = Some(entry.owner);
+    second_chance_draw.is_resolved = true;
+
+    emit!(SecondChanceWinner {
+        draw_id,
+        winner: entry.owner,
+        prize,
+    });
+
+    msg!("Second chance draw resolved!");
+    msg!("  Draw: #{}", draw_id);
+    msg!("  Entries: {}", entry_count);
+    msg!("  Winning entry: {}", winner_index);
+    msg!("  Winner: {}", entry.owner);
+    msg!("  Prize: {} USDC lamports", prize);
+
+    Ok(())
+}
+
+// ============================================================================
+// RECLAIM SECOND CHANCE PRIZE
+// ============================================================================
+
+/// Accounts required for returning a second chance prize to the reserve
+#[derive(Accounts)]
+pub struct ReclaimSecondChancePrize<'info> {
+    /// Lottery authority
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The second chance draw being closed
+    #[account(
+        mut,
+        seeds = [SECOND_CHANCE_SEED, &second_chance_draw.draw_id.to_le_bytes()],
+        bump = second_chance_draw.bump,
+        constraint = !second_chance_draw.is_resolved @ LottoError::SecondChanceAlreadyResolved
+    )]
+    pub second_chance_draw: Account<'info, SecondChanceDraw>,
+
+    /// The draw result for the second chance draw's main draw, required only
+    /// when the draw has entries
+    #[account(
+        seeds = [DRAW_SEED, &second_chance_draw.draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub draw_result: Option<Account<'info, DrawResult>>,
+}
+
+/// Whether an unresolved second chance draw's prize may be reclaimed
+///
+/// A draw nobody entered can be closed at any time. One with entries only
+/// once the claim window of its main draw (drawn at `draw_timestamp`) has
+/// expired.
+pub fn is_second_chance_reclaimable(
+    entry_count: u64,
+    draw_timestamp: Option<i64>,
+    current_time: i64,
+) -> bool {
+    entry_count == 0
+        || draw_timestamp.is_some_and(|timestamp| is_claim_window_expired(timestamp, current_time))
+}
+
+/// Return an unresolved second chance prize to the reserve
+///
+/// This instruction:
+/// 1. Verifies the draw has no entries or its claim window has expired
+/// 2. Credits the allocated prize back to `reserve_balance`
+/// 3. Marks the draw resolved without a winner, closing entries
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_reclaim_second_chance_prize(ctx: Context<ReclaimSecondChancePrize>) -> Result<()> {
+    let clock = Clock::get()?;
+    let second_chance_draw = &mut ctx.accounts.second_chance_draw;
+    let draw_id = second_chance_draw.draw_id;
+    let entry_count = second_chance_draw.entry_count;
+    let amount = second_chance_draw.prize_pool;
+
+    require!(
+        is_second_chance_reclaimable(
+            entry_count,
+            ctx.accounts.draw_result.as_ref().map(|draw| draw.timestamp),
+            clock.unix_timestamp,
+        ),
+        LottoError::SecondChanceNotReclaimable
+    );
+
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    lottery_state.reserve_balance = lottery_state
+        .reserve_balance
+        .checked_add(amount)
+        .ok_or(LottoError::Overflow)?;
+
+    second_chance_draw.prize_pool = 0;
+    second_chance_draw.is_resolved = true;
+
+    emit!(SecondChancePrizeReclaimed {
+        draw_id,
+        amount,
+        entry_count,
+        new_reserve_balance: lottery_state.reserve_balance,
+    });
+
+    msg!("Second chance prize reclaimed!");
+    msg!("  Draw: #{}", draw_id);
+    msg!("  Entries: {}", entry_count);
+    msg!("  Returned to reserve: {} USDC lamports", amount);
+    msg!(
+        "  Reserve balance: {} USDC lamports",
+        lottery_state.reserve_balance
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_second_chance_eligibility() {
+        let winning = [1, 2, 3, 4, 5, 6];
+
+        assert!(is_second_chance_eligible(
+            &[10, 20, 30, 40, 41, 42],
+            &winning,
+            0
+        ));
+        assert!(is_second_chance_eligible(
+            &[1, 20, 30, 40, 41, 42],
+            &winning,
+            0
+        ));
+
+        // Match 2 wins a free ticket, so it is not a losing ticket
+        assert!(!is_second_chance_eligible(
+            &[1, 2, 30, 40, 41, 42],
+            &winning,
+            0
+        ));
+        assert!(!is_second_chance_eligible(&winning, &winning, 0));
+
+        // A recorded match count disqualifies the ticket
+        assert!(!is_second_chance_eligible(
+            &[10, 20, 30, 40, 41, 42],
+            &winning,
+            3
+        ));
+    }
+
+    #[test]
+    fn test_committed_reveal_value() {
+        let value = [9u8; 32];
+        assert_eq!(
+            committed_reveal_value(100, 101, &value, 100).unwrap(),
+            value
+        );
+
+        // A different commit on the same account is rejected
+        assert!(committed_reveal_value(150, 151, &value, 100).is_err());
+        // Not revealed yet
+        assert!(committed_reveal_value(100, 0, &[0u8; 32], 100).is_err());
+        assert!(committed_reveal_value(100, 100, &value, 100).is_err());
+    }
+
+    #[test]
+    fn test_select_second_chance_winner() {
+        let mut randomness = [0u8; 32];
+        randomness[0..8].copy_from_slice(&17u64.to_le_bytes());
+        assert_eq!(select_second_chance_winner(&randomness, 5), 2);
+        assert_eq!(select_second_chance_winner(&randomness, 1), 0);
+        assert_eq!(select_second_chance_winner(&randomness, 100), 17);
+
+        // Only the first 8 bytes feed the index
+        randomness[8..].fill(0xFF);
+        assert_eq!(select_second_chance_winner(&randomness, 5), 2);
+
+        let max = [0xFFu8; 32];
+        assert!(select_second_chance_winner(&max, 7) < 7);
+    }
+
+    #[test]
+    fn test_second_chance_reclaimable() {
+        let drawn_at = 1_000;
+        let expired_at = drawn_at + TICKET_CLAIM_EXPIRATION + 1;
+
+        // Empty draws can be closed at any time
+        assert!(is_second_chance_reclaimable(0, None, drawn_at));
+        // Draws with entries wait for the claim window to expire
+        assert!(!is_second_chance_reclaimable(3, Some(drawn_at), drawn_at));
+        assert!(!is_second_chance_reclaimable(3, None, expired_at));
+        assert!(is_second_chance_reclaimable(3, Some(drawn_at), expired_at));
+    }
+}
@@ -0,0 +1,104 @@
+//! Spend Gate Verification Instruction
+//!
+//! QuickPick Express is only open to players who have spent at least
+//! `lottery_state.quickpick_spend_gate_threshold` in the main lottery.
+//! Rather than deserializing this program's `UserStats` layout itself,
+//! QuickPick CPIs into `verify_spend_gate`, which succeeds only when the
+//! signing player meets the threshold. The threshold is changed through the
+//! timelocked `propose_config` / `execute_config` flow.
+//!
+//! - verify_spend_gate: Read-only spend check, callable directly or via CPI
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::SpendGateVerified;
+use crate::state::{LotteryState, SchemaVersioned, UserStats};
+
+/// Whether a lifetime spend of `total_spent` passes the gate at `threshold`
+pub fn meets_spend_gate(total_spent: u64, threshold: u64) -> bool {
+    total_spent >= threshold
+}
+
+// ============================================================================
+// VERIFY SPEND GATE
+// ============================================================================
+
+/// Accounts required for verifying a player's lifetime spend
+#[derive(Accounts)]
+pub struct VerifySpendGate<'info> {
+    /// The player whose spend is checked
+    pub player: Signer<'info>,
+
+    /// The main lottery state account (holds the threshold)
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// Player's user statistics account
+    #[account(
+        seeds = [USER_SEED, player.key().as_ref()],
+        bump = user_stats.bump,
+        constraint = user_stats.wallet == player.key() @ LottoError::Unauthorized,
+        constraint = user_stats.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub user_stats: Account<'info, UserStats>,
+}
+
+/// Succeed only if the player's lifetime spend meets the QuickPick threshold
+///
+/// Does not modify any account. Emits `SpendGateVerified` on success and
+/// fails with `InsufficientMainLotterySpend` otherwise.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_verify_spend_gate(ctx: Context<VerifySpendGate>) -> Result<()> {
+    let player = ctx.accounts.player.key();
+    let spent = ctx.accounts.user_stats.total_spent;
+    let threshold = ctx.accounts.lottery_state.quickpick_spend_gate_threshold;
+
+    require!(
+        meets_spend_gate(spent, threshold),
+        LottoError::InsufficientMainLotterySpend
+    );
+
+    emit!(SpendGateVerified {
+        player,
+        spent,
+        threshold,
+    });
+
+    msg!("Spend gate verified!");
+    msg!("  Player: {}", player);
+    msg!("  Spent: {} / {}", spent, threshold);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meets_spend_gate_at_threshold() {
+        assert!(meets_spend_gate(
+            QUICK_PICK_MIN_SPEND_GATE,
+            QUICK_PICK_MIN_SPEND_GATE
+        ));
+        assert!(meets_spend_gate(
+            QUICK_PICK_MIN_SPEND_GATE + 1,
+            QUICK_PICK_MIN_SPEND_GATE
+        ));
+        assert!(!meets_spend_gate(
+            QUICK_PICK_MIN_SPEND_GATE - 1,
+            QUICK_PICK_MIN_SPEND_GATE
+        ));
+    }
+}
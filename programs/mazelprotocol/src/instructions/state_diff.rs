@@ -0,0 +1,120 @@
+//! Lottery State Diffs
+//!
+//! Handlers that move lottery funds or flip its status flags end by
+//! emitting `LotteryStateDiff`, so clients subscribed to program logs can
+//! follow the lottery state without polling the account. Each handler takes
+//! a `LotteryStateSnapshot` on entry and diffs it against the final state.
+//!
+//! Emitted by buy_ticket (and buy_lucky_dip), finalize_draw, claim_prize,
+//! emergency_fund_transfer, pause and unpause.
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::LotteryStateDiff;
+use crate::state::LotteryState;
+
+/// The lottery state fields covered by `LotteryStateDiff`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LotteryStateSnapshot {
+    pub jackpot_balance: u64,
+    pub reserve_balance: u64,
+    pub insurance_balance: u64,
+    pub total_tickets_sold: u64,
+    pub total_prizes_paid: u64,
+    pub is_paused: bool,
+    pub is_rolldown_active: bool,
+    pub is_draw_in_progress: bool,
+    pub is_funded: bool,
+}
+
+impl LotteryStateSnapshot {
+    /// Capture the diffed fields of `lottery_state`
+    pub fn capture(lottery_state: &LotteryState) -> Self {
+        Self {
+            jackpot_balance: lottery_state.jackpot_balance,
+            reserve_balance: lottery_state.reserve_balance,
+            insurance_balance: lottery_state
+                .insurance_tier1_balance
+                .saturating_add(lottery_state.insurance_tier2_balance),
+            total_tickets_sold: lottery_state.total_tickets_sold,
+            total_prizes_paid: lottery_state.total_prizes_paid,
+            is_paused: lottery_state.is_paused,
+            is_rolldown_active: lottery_state.is_rolldown_active,
+            is_draw_in_progress: lottery_state.is_draw_in_progress,
+            is_funded: lottery_state.is_funded,
+        }
+    }
+}
+
+/// Signed change from `old` to `new`, saturating at the i64 range
+fn signed_delta(old: u64, new: u64) -> i64 {
+    (new as i128 - old as i128).clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+/// Bitmask of the STATE_DIFF_FLAG_* flags that differ between snapshots
+pub fn state_flags_changed(old: &LotteryStateSnapshot, new: &LotteryStateSnapshot) -> u8 {
+    let mut flags = 0;
+    if old.is_paused != new.is_paused {
+        flags |= STATE_DIFF_FLAG_PAUSED;
+    }
+    if old.is_rolldown_active != new.is_rolldown_active {
+        flags |= STATE_DIFF_FLAG_ROLLDOWN_ACTIVE;
+    }
+    if old.is_draw_in_progress != new.is_draw_in_progress {
+        flags |= STATE_DIFF_FLAG_DRAW_IN_PROGRESS;
+    }
+    if old.is_funded != new.is_funded {
+        flags |= STATE_DIFF_FLAG_FUNDED;
+    }
+    flags
+}
+
+/// Emit `LotteryStateDiff` for the changes since `old` was captured
+pub fn emit_state_diff(old: &LotteryStateSnapshot, new: &LotteryState) {
+    let new_snapshot = LotteryStateSnapshot::capture(new);
+
+    emit!(LotteryStateDiff {
+        draw_id: new.current_draw_id,
+        jackpot_delta: signed_delta(old.jackpot_balance, new_snapshot.jackpot_balance),
+        reserve_delta: signed_delta(old.reserve_balance, new_snapshot.reserve_balance),
+        insurance_delta: signed_delta(old.insurance_balance, new_snapshot.insurance_balance),
+        tickets_delta: new_snapshot
+            .total_tickets_sold
+            .saturating_sub(old.total_tickets_sold),
+        prizes_paid_delta: new_snapshot
+            .total_prizes_paid
+            .saturating_sub(old.total_prizes_paid),
+        flags_changed: state_flags_changed(old, &new_snapshot),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_diff() {
+        assert_eq!(signed_delta(100, 250), 150);
+        assert_eq!(signed_delta(250, 100), -150);
+        assert_eq!(signed_delta(0, u64::MAX), i64::MAX);
+
+        let old = LotteryStateSnapshot {
+            is_paused: true,
+            is_rolldown_active: true,
+            ..Default::default()
+        };
+        assert_eq!(state_flags_changed(&old, &old), 0);
+
+        let new = LotteryStateSnapshot {
+            is_paused: false,
+            is_rolldown_active: true,
+            is_draw_in_progress: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            state_flags_changed(&old, &new),
+            STATE_DIFF_FLAG_PAUSED | STATE_DIFF_FLAG_DRAW_IN_PROGRESS
+        );
+    }
+}
@@ -1,12 +1,22 @@
 //! Syndicate Instructions
 //!
 //! This module contains instructions for syndicate (group buying pool) management:
-//! - create_syndicate: Create a new syndicate pool
+//! - create_syndicate: Create a new syndicate pool (listed in the registry if public)
+//! - create_syndicate_invite: Create an invite code for a private syndicate
 //! - join_syndicate: Join an existing syndicate
-//! - leave_syndicate: Leave a syndicate and receive refund
+//! - leave_syndicate: Leave a syndicate and receive refund (promotes the
+//!   first waitlisted wallet into the freed place)
+//! - join_syndicate_waitlist: Escrow a contribution to wait for a full syndicate
+//! - leave_waitlist: Leave a syndicate waitlist and reclaim the escrow
+//! - partial_withdraw_member_contribution: Withdraw part of a member's contribution
 //! - close_syndicate: Close an empty syndicate
 //! - buy_syndicate_tickets: Purchase tickets for the entire syndicate
-//! - distribute_syndicate_prize: Distribute prize to syndicate members
+//! - create_syndicate_ticket: Create one purchased ticket account
+//! - create_syndicate_tickets_batch: Create up to MAX_SYNDICATE_TICKET_BATCH
+//!   purchased ticket accounts in one call
+//! - stage_syndicate_prize: Verify a batch of winning tickets and stage their prize
+//! - finalize_staged_prize: Transfer a staged prize to syndicate members
+//! - release_syndicate_escrow_prize: Share out a syndicate's escrowed jackpot
 //! - claim_syndicate_member_prize: Claim individual member's share of prize
 //! - update_syndicate_config: Update syndicate configuration
 //! - remove_syndicate_member: Remove a member from syndicate (creator only)
@@ -14,14 +24,33 @@
 
 use anchor_lang::prelude::*;
 use anchor_lang::AccountDeserialize;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
 
 use crate::constants::*;
 use crate::errors::LottoError;
 use crate::events::{
-    BulkTicketsPurchased, SyndicateCreated, SyndicateMemberJoined, SyndicatePrizeDistributed,
+    BulkTicketsPurchased, FeeProposalRejected, FeeProposeStarted, MemberPartialWithdrawal,
+    MemberPromotedFromWaitlist, PrizeEscrowReleased, PrizeEscrowed, SyndicateAutoRegisteredForWars,
+    SyndicateContributionCapSet, SyndicateCreated, SyndicateInviteUsed, SyndicateLocked,
+    SyndicateMemberJoined, SyndicatePrizeDistributed, SyndicatePrizeStaged,
+    SyndicateTicketsBatchCreated, SyndicateUnlocked, WaitlistJoined, WaitlistLeft,
+};
+use crate::instructions::buy_ticket::{
+    check_numbers_entropy, check_ticket_sale_cutoff, resolve_ticket_price, route_jackpot_overflow,
+    unlock_milestone_bonuses,
+};
+use crate::instructions::claim_prize::{
+    check_prize_identity, deduct_prize_from_balances, emit_player_stats_updated,
+};
+use crate::instructions::house_fee::{route_house_fee, HouseFeeDestination, HouseFeeSource};
+use crate::instructions::syndicate_registry::sync_syndicate_registry;
+use crate::instructions::syndicate_wars::auto_register_for_syndicate_wars;
+use crate::state::{
+    DrawResult, HouseFeeRecipients, IdentityProof, LotteryState, PrizeEscrow, SchemaVersioned,
+    Syndicate, SyndicateFeeProposal, SyndicateInvite, SyndicateMember, SyndicatePrizeStage,
+    SyndicateRegistry, SyndicateUnlockMethod, SyndicateWaitlist, TicketData, UserStats,
+    WaitlistEntry,
 };
-use crate::state::{DrawResult, LotteryState, Syndicate, SyndicateMember, TicketData, UserStats};
 
 // ============================================================================
 // CREATE SYNDICATE INSTRUCTION
@@ -38,6 +67,18 @@ pub struct CreateSyndicateParams {
     pub is_public: bool,
     /// Manager fee in basis points (max 500 = 5%)
     pub manager_fee_bps: u16,
+    /// Maximum total contribution per member in USDC lamports (0 = no limit)
+    pub max_contribution_per_member: u64,
+    /// Register for the open Syndicate Wars competition in the same
+    /// transaction (None = don't)
+    pub wars_auto_register: Option<SyndicateWarsAutoRegisterParams>,
+}
+
+/// Syndicate Wars auto-registration options for `create_syndicate`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct SyndicateWarsAutoRegisterParams {
+    /// Register if a competition with open registration is supplied
+    pub auto_register: bool,
 }
 
 /// Accounts required for creating a syndicate
@@ -83,6 +124,22 @@ pub struct CreateSyndicate<'info> {
     /// USDC mint
     pub usdc_mint: Account<'info, anchor_spl::token::Mint>,
 
+    /// Public syndicate registry (public syndicates are listed)
+    #[account(
+        mut,
+        seeds = [SYNDICATE_REGISTRY_SEED],
+        bump = syndicate_registry.bump
+    )]
+    pub syndicate_registry: Account<'info, SyndicateRegistry>,
+
+    /// Creator's user statistics (reputation sets the member limit)
+    #[account(
+        seeds = [USER_SEED, creator.key().as_ref()],
+        bump = creator_user_stats.bump,
+        constraint = creator_user_stats.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub creator_user_stats: Account<'info, UserStats>,
+
     /// Token program
     pub token_program: Program<'info, Token>,
 
@@ -122,7 +179,12 @@ impl<'info> CreateSyndicate<'info> {
 /// 2. Creates the syndicate account PDA
 /// 3. Creates the syndicate's USDC token account
 /// 4. Adds the creator as the first member
-/// 5. Sets up the syndicate configuration
+/// 5. Sets up the syndicate configuration, with a member limit from the
+///    creator's reputation score
+/// 6. Lists the syndicate in the registry if it is public
+/// 7. With `wars_auto_register`, registers it for Syndicate Wars when
+///    `[syndicate_wars_state, wars_entry]` are passed as remaining accounts
+///    and registration is open (otherwise the option is ignored)
 ///
 /// # Arguments
 /// * `ctx` - The context containing required accounts
@@ -130,8 +192,8 @@ impl<'info> CreateSyndicate<'info> {
 ///
 /// # Returns
 /// * `Result<()>` - Success or error
-pub fn handler_create_syndicate(
-    ctx: Context<CreateSyndicate>,
+pub fn handler_create_syndicate<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateSyndicate<'info>>,
     params: CreateSyndicateParams,
 ) -> Result<()> {
     let clock = Clock::get()?;
@@ -139,9 +201,11 @@ pub fn handler_create_syndicate(
     // Validate parameters
     ctx.accounts.validate(&params)?;
 
+    let max_members = effective_max_members(ctx.accounts.creator_user_stats.reputation_score);
     let syndicate = &mut ctx.accounts.syndicate;
 
     // Initialize syndicate
+    syndicate.schema_version = CURRENT_SCHEMA_VERSION;
     syndicate.creator = ctx.accounts.creator.key();
     // SECURITY FIX (Issue #1): Store original creator for stable PDA derivation.
     // This field MUST NEVER be modified after creation.
@@ -154,6 +218,14 @@ pub fn handler_create_syndicate(
     syndicate.manager_fee_bps = params.manager_fee_bps;
     syndicate.usdc_account = ctx.accounts.syndicate_usdc.key();
     syndicate.bump = ctx.bumps.syndicate;
+    syndicate.max_contribution_per_member = params.max_contribution_per_member;
+    syndicate.is_locked = false;
+    syndicate.locked_draw_id = 0;
+    syndicate.pending_usdc = 0;
+    syndicate.max_members_at_creation = max_members;
+    syndicate.ticket_draw_id = 0;
+    syndicate.tickets_purchased = 0;
+    syndicate.tickets_created = 0;
 
     // Add creator as first member with 0 contribution
     // (They can contribute later via join_syndicate)
@@ -164,6 +236,31 @@ pub fn handler_create_syndicate(
         unclaimed_prize: 0,
     }];
 
+    sync_syndicate_registry(
+        &mut ctx.accounts.syndicate_registry,
+        ctx.accounts.syndicate.key(),
+        &ctx.accounts.syndicate,
+        &ctx.accounts.creator.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let auto_register = params
+        .wars_auto_register
+        .is_some_and(|options| options.auto_register);
+    let wars_season_id = if auto_register {
+        auto_register_for_syndicate_wars(
+            ctx.program_id,
+            ctx.accounts.syndicate.key(),
+            &ctx.accounts.syndicate,
+            ctx.remaining_accounts,
+            &ctx.accounts.creator.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            clock.unix_timestamp,
+        )?
+    } else {
+        None
+    };
+
     // Emit event
     emit!(SyndicateCreated {
         syndicate: ctx.accounts.syndicate.key(),
@@ -171,6 +268,7 @@ pub fn handler_create_syndicate(
         name: params.name,
         is_public: params.is_public,
         manager_fee_bps: params.manager_fee_bps,
+        max_members,
         timestamp: clock.unix_timestamp,
     });
 
@@ -185,8 +283,113 @@ pub fn handler_create_syndicate(
     msg!("  Name: {}", name_str);
     msg!("  Is public: {}", params.is_public);
     msg!("  Manager fee: {} bps", params.manager_fee_bps);
+    msg!("  Max members: {}", max_members);
+    msg!(
+        "  Max contribution per member: {} USDC lamports (0 = no limit)",
+        params.max_contribution_per_member
+    );
     msg!("  USDC account: {}", ctx.accounts.syndicate_usdc.key());
 
+    if let Some(wars_season_id) = wars_season_id {
+        emit!(SyndicateAutoRegisteredForWars {
+            syndicate: ctx.accounts.syndicate.key(),
+            wars_season_id,
+            registration_timestamp: clock.unix_timestamp,
+        });
+        msg!("  Syndicate Wars: registered for month {}", wars_season_id);
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// CREATE SYNDICATE INVITE INSTRUCTION
+// ============================================================================
+
+/// Accounts required for creating a private syndicate invite
+#[derive(Accounts)]
+#[instruction(code_hash: [u8; 32])]
+pub struct CreateSyndicateInvite<'info> {
+    /// The syndicate creator issuing the invite
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// The syndicate the invite grants access to
+    #[account(
+        constraint = syndicate.creator == creator.key() @ LottoError::Unauthorized,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub syndicate: Account<'info, Syndicate>,
+
+    /// The invite account to be created
+    #[account(
+        init,
+        payer = creator,
+        space = SYNDICATE_INVITE_SIZE,
+        seeds = [
+            SYNDICATE_INVITE_SEED,
+            syndicate.key().as_ref(),
+            code_hash.as_ref()
+        ],
+        bump
+    )]
+    pub syndicate_invite: Account<'info, SyndicateInvite>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Hash a plain invite code with SHA-256
+pub fn hash_invite_code(invite_code: &[u8; 32]) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+    let result = Sha256::digest(invite_code);
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(result.as_slice());
+    hash
+}
+
+/// Create an invite for a private syndicate
+///
+/// The creator generates a plain invite code off-chain and submits only its
+/// SHA-256 hash. Anyone who knows the plain code can join the syndicate while
+/// the invite has uses remaining and has not expired.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `code_hash` - SHA-256 hash of the plain invite code
+/// * `max_uses` - Maximum number of joins allowed with this invite
+/// * `expires_at` - Unix timestamp after which the invite is invalid
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_create_syndicate_invite(
+    ctx: Context<CreateSyndicateInvite>,
+    code_hash: [u8; 32],
+    max_uses: u8,
+    expires_at: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(max_uses > 0, LottoError::InvalidSyndicateConfig);
+    require!(
+        expires_at > clock.unix_timestamp,
+        LottoError::InvalidTimestamp
+    );
+
+    let invite = &mut ctx.accounts.syndicate_invite;
+    invite.syndicate = ctx.accounts.syndicate.key();
+    invite.code_hash = code_hash;
+    invite.use_count = 0;
+    invite.max_uses = max_uses;
+    invite.expires_at = expires_at;
+    invite.bump = ctx.bumps.syndicate_invite;
+
+    msg!("Syndicate invite created!");
+    msg!("  Syndicate: {}", ctx.accounts.syndicate.key());
+    msg!("  Invite: {}", ctx.accounts.syndicate_invite.key());
+    msg!("  Max uses: {}", max_uses);
+    msg!("  Expires at: {}", expires_at);
+
     Ok(())
 }
 
@@ -199,6 +402,8 @@ pub fn handler_create_syndicate(
 pub struct JoinSyndicateParams {
     /// USDC contribution amount
     pub contribution: u64,
+    /// Plain invite code (required for new members of private syndicates)
+    pub invite_code: Option<[u8; 32]>,
 }
 
 /// Accounts required for joining a syndicate
@@ -211,14 +416,32 @@ pub struct JoinSyndicate<'info> {
 
     /// The syndicate to join
     /// Note: realloc is handled conditionally in the handler to avoid
-    /// unnecessary space allocation when existing members add contributions
+    /// unnecessary space allocation when existing members add contributions.
+    /// Private syndicate access (creator, existing member or invite) is
+    /// checked in the handler.
     #[account(
         mut,
-        constraint = syndicate.is_public || syndicate.creator == member.key() @ LottoError::SyndicatePrivate,
-        constraint = (syndicate.member_count as usize) < MAX_SYNDICATE_MEMBERS @ LottoError::SyndicateFull
+        constraint = !syndicate.is_full() @ LottoError::SyndicateFull,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub syndicate: Account<'info, Syndicate>,
 
+    /// The main lottery state account (for the syndicate lock check)
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = !syndicate.is_locked_for_draw(lottery_state.current_draw_id) @ LottoError::SyndicateLocked,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// Invite for a private syndicate (required for new non-creator members)
+    #[account(
+        mut,
+        constraint = syndicate_invite.syndicate == syndicate.key() @ LottoError::InvalidInviteCode
+    )]
+    pub syndicate_invite: Option<Account<'info, SyndicateInvite>>,
+
     /// Member's USDC token account (source of contribution)
     #[account(
         mut,
@@ -244,10 +467,19 @@ pub struct JoinSyndicate<'info> {
     #[account(
         mut,
         seeds = [USER_SEED, member.key().as_ref()],
-        bump = user_stats.bump
+        bump = user_stats.bump,
+        constraint = user_stats.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub user_stats: Account<'info, UserStats>,
 
+    /// Public syndicate registry (listing refreshed after the join)
+    #[account(
+        mut,
+        seeds = [SYNDICATE_REGISTRY_SEED],
+        bump = syndicate_registry.bump
+    )]
+    pub syndicate_registry: Account<'info, SyndicateRegistry>,
+
     /// Token program
     pub token_program: Program<'info, Token>,
 
@@ -277,7 +509,8 @@ impl<'info> JoinSyndicate<'info> {
 /// Join an existing syndicate
 ///
 /// This instruction:
-/// 1. Validates the syndicate is joinable (public or creator)
+/// 1. Validates the syndicate is joinable (public, creator, existing member,
+///    or a valid invite code for private syndicates)
 /// 2. Validates the syndicate has space for new members
 /// 3. Transfers USDC contribution from member to syndicate
 /// 4. Adds the member to the syndicate (or updates existing contribution)
@@ -288,7 +521,7 @@ impl<'info> JoinSyndicate<'info> {
 ///
 /// # Arguments
 /// * `ctx` - The context containing required accounts
-/// * `params` - Contribution amount
+/// * `params` - Contribution amount and optional invite code
 ///
 /// # Returns
 /// * `Result<()>` - Success or error
@@ -310,12 +543,44 @@ pub fn handler_join_syndicate(
         .iter()
         .any(|m| m.wallet == member_key);
 
+    // Private syndicates: new non-creator members must present a valid invite
+    let is_creator = ctx.accounts.syndicate.creator == member_key;
+    if !ctx.accounts.syndicate.is_public && !is_creator && !is_existing_member {
+        let invite_code = params.invite_code.ok_or(LottoError::SyndicatePrivate)?;
+        let invite = ctx
+            .accounts
+            .syndicate_invite
+            .as_mut()
+            .ok_or(LottoError::SyndicatePrivate)?;
+
+        let code_hash = hash_invite_code(&invite_code);
+        require!(invite.code_hash == code_hash, LottoError::InvalidInviteCode);
+        invite.validate_use(clock.unix_timestamp)?;
+
+        invite.use_count = invite
+            .use_count
+            .checked_add(1)
+            .ok_or(LottoError::Overflow)?;
+
+        emit!(SyndicateInviteUsed {
+            syndicate: syndicate_key,
+            member: member_key,
+            code_hash,
+            remaining_uses: invite.remaining_uses(),
+        });
+
+        msg!(
+            "  Invite accepted ({} uses remaining)",
+            invite.remaining_uses()
+        );
+    }
+
     // FIXED: Validate minimum contribution for new members
     // New members must contribute at least 1 USDC (1_000_000 lamports) to prevent
     // zero-share members that dilute existing members' shares without contributing
     if !is_existing_member {
         require!(
-            params.contribution >= 1_000_000, // 1 USDC minimum for new members
+            params.contribution >= MIN_SYNDICATE_MEMBER_CONTRIBUTION,
             LottoError::InsufficientContribution
         );
     }
@@ -360,6 +625,8 @@ pub fn handler_join_syndicate(
     // Now do all mutable operations on syndicate
     let syndicate = &mut ctx.accounts.syndicate;
 
+    let max_contribution_per_member = syndicate.max_contribution_per_member;
+
     if is_existing_member {
         // If existing member, add to their contribution (no realloc needed)
         for member in syndicate.members.iter_mut() {
@@ -368,16 +635,22 @@ pub fn handler_join_syndicate(
                     .contribution
                     .checked_add(params.contribution)
                     .ok_or(LottoError::Overflow)?;
+                require!(
+                    max_contribution_per_member == 0
+                        || member.contribution <= max_contribution_per_member,
+                    LottoError::ContributionExceedsLimit
+                );
                 break;
             }
         }
     } else {
-        // New member (space already reallocated above)
-        // Check syndicate member limit
         require!(
-            (syndicate.member_count as usize) < MAX_SYNDICATE_MEMBERS,
-            LottoError::SyndicateFull
+            syndicate.is_within_contribution_cap(params.contribution),
+            LottoError::ContributionExceedsLimit
         );
+        // New member (space already reallocated above)
+        // Check syndicate member limit
+        require!(!syndicate.is_full(), LottoError::SyndicateFull);
         syndicate.members.push(SyndicateMember {
             wallet: member_key,
             contribution: params.contribution,
@@ -410,6 +683,14 @@ pub fn handler_join_syndicate(
     let member_count = syndicate.member_count;
     let total_contribution = syndicate.total_contribution;
 
+    sync_syndicate_registry(
+        &mut ctx.accounts.syndicate_registry,
+        syndicate_key,
+        &ctx.accounts.syndicate,
+        &ctx.accounts.member.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
     // Emit event
     emit!(SyndicateMemberJoined {
         syndicate: syndicate_key,
@@ -449,9 +730,21 @@ pub struct LeaveSyndicate<'info> {
     /// The syndicate to leave
     /// Note: Creator CAN leave if they have transferred creator role or have 0 contribution
     /// and there are other members with contributions
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
     pub syndicate: Account<'info, Syndicate>,
 
+    /// The main lottery state account (for the syndicate lock check)
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = !syndicate.is_locked_for_draw(lottery_state.current_draw_id) @ LottoError::SyndicateLocked,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
     /// Member's USDC token account (to receive refund)
     #[account(
         mut,
@@ -472,6 +765,22 @@ pub struct LeaveSyndicate<'info> {
     )]
     pub syndicate_usdc: Account<'info, TokenAccount>,
 
+    /// Public syndicate registry (listing refreshed after the member leaves)
+    #[account(
+        mut,
+        seeds = [SYNDICATE_REGISTRY_SEED],
+        bump = syndicate_registry.bump
+    )]
+    pub syndicate_registry: Account<'info, SyndicateRegistry>,
+
+    /// Syndicate waitlist (required while the syndicate holds waitlist escrow)
+    #[account(
+        mut,
+        seeds = [SYNDICATE_WAITLIST_SEED, syndicate.key().as_ref()],
+        bump = syndicate_waitlist.bump
+    )]
+    pub syndicate_waitlist: Option<Account<'info, SyndicateWaitlist>>,
+
     /// Token program
     pub token_program: Program<'info, Token>,
 
@@ -486,7 +795,8 @@ pub struct LeaveSyndicate<'info> {
 /// 2. Calculates the refund amount based on contribution
 /// 3. Transfers USDC refund from syndicate to member
 /// 4. Removes the member from the syndicate
-/// 5. Recalculates remaining member shares
+/// 5. Promotes the first waitlisted wallet, if any, into the freed place
+/// 6. Recalculates member shares
 ///
 /// Note: The creator cannot leave their own syndicate.
 ///
@@ -550,8 +860,50 @@ pub fn handler_leave_syndicate(ctx: Context<LeaveSyndicate>) -> Result<()> {
     // Use the remove_member helper to get contribution and update state
     let contribution = ctx.accounts.syndicate.remove_member(&member_key)?;
 
+    // Promote the longest-waiting wallet into the freed place. The account
+    // keeps its size, so the freed member slot is reused.
+    if ctx.accounts.syndicate.pending_usdc > 0 {
+        let waitlist = ctx
+            .accounts
+            .syndicate_waitlist
+            .as_mut()
+            .ok_or(LottoError::WaitlistAccountRequired)?;
+
+        if let Some(entry) = waitlist.pop_front() {
+            let syndicate = &mut ctx.accounts.syndicate;
+            syndicate.promote_from_waitlist(&entry)?;
+
+            let share_bps = syndicate
+                .find_member(&entry.wallet)
+                .map(|m| m.share_percentage_bps)
+                .unwrap_or(0);
+
+            emit!(MemberPromotedFromWaitlist {
+                syndicate: syndicate_key,
+                wallet: entry.wallet,
+                contribution: entry.desired_contribution,
+                share_bps,
+                member_count: syndicate.member_count,
+            });
+
+            msg!("Waitlisted wallet promoted to member: {}", entry.wallet);
+            msg!(
+                "  Contribution: {} USDC lamports",
+                entry.desired_contribution
+            );
+        }
+    }
+
     let remaining_members = ctx.accounts.syndicate.member_count;
 
+    sync_syndicate_registry(
+        &mut ctx.accounts.syndicate_registry,
+        syndicate_key,
+        &ctx.accounts.syndicate,
+        &ctx.accounts.member.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
     // FIXED: Actually transfer the contribution back to the member
     if contribution > 0 {
         // Verify syndicate has enough balance
@@ -597,33 +949,46 @@ pub fn handler_leave_syndicate(ctx: Context<LeaveSyndicate>) -> Result<()> {
 }
 
 // ============================================================================
-// CLOSE SYNDICATE INSTRUCTION
+// JOIN SYNDICATE WAITLIST INSTRUCTION
 // ============================================================================
 
-/// Accounts required for closing a syndicate
+/// Accounts required for joining a full syndicate's waitlist
 #[derive(Accounts)]
-pub struct CloseSyndicate<'info> {
-    /// The creator closing the syndicate
+#[instruction(contribution: u64)]
+pub struct JoinSyndicateWaitlist<'info> {
+    /// The wallet joining the waitlist
     #[account(mut)]
-    pub creator: Signer<'info>,
+    pub wallet: Signer<'info>,
 
-    /// The syndicate to close
+    /// The full syndicate to wait for
     #[account(
         mut,
-        close = creator,
-        constraint = syndicate.creator == creator.key() @ LottoError::Unauthorized,
-        constraint = syndicate.member_count <= 1 @ LottoError::SyndicateFull // Only creator left
+        constraint = syndicate.is_full() @ LottoError::SyndicateNotFull,
+        constraint = syndicate.is_public @ LottoError::SyndicatePrivate,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub syndicate: Account<'info, Syndicate>,
 
-    /// Creator's USDC token account (to receive remaining funds)
+    /// The syndicate's waitlist (created empty on first use; grows one entry
+    /// per join)
+    #[account(
+        init_if_needed,
+        payer = wallet,
+        space = SyndicateWaitlist::size_for_entries(0),
+        seeds = [SYNDICATE_WAITLIST_SEED, syndicate.key().as_ref()],
+        bump
+    )]
+    pub syndicate_waitlist: Account<'info, SyndicateWaitlist>,
+
+    /// Wallet's USDC token account (source of the escrow)
     #[account(
         mut,
-        constraint = creator_usdc.owner == creator.key() @ LottoError::TokenAccountOwnerMismatch
+        constraint = wallet_usdc.owner == wallet.key() @ LottoError::TokenAccountOwnerMismatch,
+        constraint = wallet_usdc.amount >= contribution @ LottoError::InsufficientFunds
     )]
-    pub creator_usdc: Account<'info, TokenAccount>,
+    pub wallet_usdc: Account<'info, TokenAccount>,
 
-    /// Syndicate's USDC token account (will be closed)
+    /// Syndicate's USDC token account (holds the escrow)
     #[account(
         mut,
         seeds = [
@@ -638,123 +1003,413 @@ pub struct CloseSyndicate<'info> {
 
     /// Token program
     pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
 }
 
-/// Close a syndicate
+/// Join the waitlist of a full public syndicate
 ///
 /// This instruction:
-/// 1. Validates the caller is the creator
-/// 2. Validates all other members have left
-/// 3. Transfers any remaining USDC to creator
-/// 4. Closes the syndicate USDC account
-/// 5. Closes the syndicate account and returns rent to creator
-///
-/// # Dust Handling
-/// Any remaining USDC in the syndicate account goes to the creator.
-/// This should only be the creator's own contribution or small amounts
-/// from rounding during ticket purchases. All other members should have
-/// received their full refund when leaving.
+/// 1. Validates the wallet is neither a member nor already waiting
+/// 2. Validates the contribution against the member minimum and cap
+/// 3. Escrows the contribution in the syndicate's USDC account
+///    (tracked in `pending_usdc`, so it cannot be spent on tickets)
+/// 4. Appends the wallet to the waitlist
 ///
-/// If significant funds remain (indicating a bug or failed refunds),
-/// consider using withdraw_creator_contribution first to verify amounts.
+/// `leave_syndicate` promotes the first waiting wallet when a member leaves.
 ///
 /// # Arguments
 /// * `ctx` - The context containing required accounts
+/// * `contribution` - USDC to escrow and contribute on promotion
 ///
 /// # Returns
 /// * `Result<()>` - Success or error
-pub fn handler_close_syndicate(ctx: Context<CloseSyndicate>) -> Result<()> {
+pub fn handler_join_syndicate_waitlist(
+    ctx: Context<JoinSyndicateWaitlist>,
+    contribution: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let wallet_key = ctx.accounts.wallet.key();
     let syndicate_key = ctx.accounts.syndicate.key();
-    // SECURITY FIX (Issue #1): Use original_creator for signer seeds
-    let syndicate_original_creator = ctx.accounts.syndicate.original_creator;
-    let syndicate_id = ctx.accounts.syndicate.syndicate_id;
-    let syndicate_bump = ctx.accounts.syndicate.bump;
-    let remaining_balance = ctx.accounts.syndicate_usdc.amount;
 
-    // Get creator's recorded contribution for comparison
-    let current_creator = ctx.accounts.syndicate.creator;
-    let creator_contribution = ctx
-        .accounts
-        .syndicate
-        .find_member(&current_creator)
-        .map(|m| m.contribution)
-        .unwrap_or(0);
+    require!(
+        ctx.accounts.syndicate.find_member(&wallet_key).is_none(),
+        LottoError::AlreadySyndicateMember
+    );
+    require!(
+        contribution >= MIN_SYNDICATE_MEMBER_CONTRIBUTION,
+        LottoError::InsufficientContribution
+    );
+    require!(
+        ctx.accounts
+            .syndicate
+            .is_within_contribution_cap(contribution),
+        LottoError::ContributionExceedsLimit
+    );
 
-    // FIXED: Warn if remaining balance exceeds creator's contribution (potential unfairness)
-    let excess_funds = remaining_balance.saturating_sub(creator_contribution);
-    if excess_funds > 0 {
-        msg!(
-            "⚠️  WARNING: Remaining balance ({}) exceeds creator contribution ({}).",
-            remaining_balance,
-            creator_contribution
-        );
-        msg!(
-            "    Excess of {} USDC lamports may be from failed refunds or rounding.",
-            excess_funds
-        );
-        msg!("    All funds will be transferred to creator.");
+    let waitlist = &mut ctx.accounts.syndicate_waitlist;
+    if waitlist.syndicate == Pubkey::default() {
+        waitlist.syndicate = syndicate_key;
+        waitlist.bump = ctx.bumps.syndicate_waitlist;
     }
+    require!(
+        !waitlist.contains(&wallet_key),
+        LottoError::AlreadyOnWaitlist
+    );
+    require!(
+        waitlist.waitlist.len() < MAX_SYNDICATE_WAITLIST_ENTRIES,
+        LottoError::WaitlistFull
+    );
 
-    // Transfer any remaining USDC to creator
-    if remaining_balance > 0 {
-        let seeds = &[
-            SYNDICATE_SEED,
-            syndicate_original_creator.as_ref(),
-            &syndicate_id.to_le_bytes(),
-            &[syndicate_bump],
-        ];
-        let signer_seeds = &[&seeds[..]];
-
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.syndicate_usdc.to_account_info(),
-            to: ctx.accounts.creator_usdc.to_account_info(),
-            authority: ctx.accounts.syndicate.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-
-        token::transfer(cpi_ctx, remaining_balance)?;
-
-        msg!(
-            "Transferred remaining {} USDC lamports to creator",
-            remaining_balance
-        );
-        if excess_funds > 0 {
-            msg!(
-                "  (includes {} excess beyond creator's tracked contribution)",
-                excess_funds
+    // Grow the waitlist account by one entry
+    let new_size = SyndicateWaitlist::size_for_entries(waitlist.waitlist.len() + 1);
+    let waitlist_info = waitlist.to_account_info();
+    if new_size > waitlist_info.data_len() {
+        let rent = Rent::get()?;
+        let lamports_diff = rent
+            .minimum_balance(new_size)
+            .saturating_sub(waitlist_info.lamports());
+
+        if lamports_diff > 0 {
+            let cpi_context = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.wallet.to_account_info(),
+                    to: waitlist_info.clone(),
+                },
             );
+            anchor_lang::system_program::transfer(cpi_context, lamports_diff)?;
         }
-    }
 
-    // Close the syndicate USDC token account
-    let seeds = &[
-        SYNDICATE_SEED,
-        syndicate_original_creator.as_ref(),
-        &syndicate_id.to_le_bytes(),
-        &[syndicate_bump],
-    ];
-    let signer_seeds = &[&seeds[..]];
+        waitlist_info.resize(new_size)?;
+    }
 
-    let cpi_accounts = token::CloseAccount {
-        account: ctx.accounts.syndicate_usdc.to_account_info(),
-        destination: ctx.accounts.creator.to_account_info(),
-        authority: ctx.accounts.syndicate.to_account_info(),
+    // Escrow the contribution in the syndicate's USDC account
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.wallet_usdc.to_account_info(),
+        to: ctx.accounts.syndicate_usdc.to_account_info(),
+        authority: ctx.accounts.wallet.to_account_info(),
     };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, contribution)?;
 
-    token::close_account(cpi_ctx)?;
+    let syndicate = &mut ctx.accounts.syndicate;
+    syndicate.pending_usdc = syndicate
+        .pending_usdc
+        .checked_add(contribution)
+        .ok_or(LottoError::Overflow)?;
 
-    msg!("Syndicate closed!");
-    msg!("  Syndicate: {}", syndicate_key);
-    msg!("  Creator: {}", ctx.accounts.creator.key());
-    msg!(
-        "  Final balance transferred: {} USDC lamports",
-        remaining_balance
-    );
+    let waitlist = &mut ctx.accounts.syndicate_waitlist;
+    waitlist.waitlist.push(WaitlistEntry {
+        wallet: wallet_key,
+        desired_contribution: contribution,
+        joined_at: clock.unix_timestamp,
+    });
+    let position = waitlist.waitlist.len() as u32;
 
-    // Account closure is handled automatically by the `close` constraint
+    emit!(WaitlistJoined {
+        syndicate: syndicate_key,
+        wallet: wallet_key,
+        contribution,
+        position,
+    });
+
+    msg!("Joined syndicate waitlist!");
+    msg!("  Syndicate: {}", syndicate_key);
+    msg!("  Wallet: {}", wallet_key);
+    msg!("  Escrowed: {} USDC lamports", contribution);
+    msg!("  Position: {}", position);
+
+    Ok(())
+}
+
+// ============================================================================
+// LEAVE WAITLIST INSTRUCTION
+// ============================================================================
+
+/// Accounts required for leaving a syndicate waitlist
+#[derive(Accounts)]
+pub struct LeaveWaitlist<'info> {
+    /// The waiting wallet
+    pub wallet: Signer<'info>,
+
+    /// The syndicate being waited for
+    #[account(
+        mut,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub syndicate: Account<'info, Syndicate>,
+
+    /// The syndicate's waitlist
+    #[account(
+        mut,
+        seeds = [SYNDICATE_WAITLIST_SEED, syndicate.key().as_ref()],
+        bump = syndicate_waitlist.bump
+    )]
+    pub syndicate_waitlist: Account<'info, SyndicateWaitlist>,
+
+    /// Wallet's USDC token account (receives the refund)
+    #[account(
+        mut,
+        constraint = wallet_usdc.owner == wallet.key() @ LottoError::TokenAccountOwnerMismatch
+    )]
+    pub wallet_usdc: Account<'info, TokenAccount>,
+
+    /// Syndicate's USDC token account (source of the refund)
+    #[account(
+        mut,
+        seeds = [
+            SYNDICATE_SEED,
+            b"usdc",
+            syndicate.key().as_ref()
+        ],
+        bump,
+        constraint = syndicate_usdc.key() == syndicate.usdc_account @ LottoError::InvalidTokenAccount
+    )]
+    pub syndicate_usdc: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Leave a syndicate waitlist and reclaim the escrowed USDC
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_leave_waitlist(ctx: Context<LeaveWaitlist>) -> Result<()> {
+    let wallet_key = ctx.accounts.wallet.key();
+    let syndicate_key = ctx.accounts.syndicate.key();
+
+    let entry = ctx
+        .accounts
+        .syndicate_waitlist
+        .remove(&wallet_key)
+        .ok_or(LottoError::NotOnWaitlist)?;
+    let refund = entry.desired_contribution;
+
+    let syndicate = &mut ctx.accounts.syndicate;
+    syndicate.pending_usdc = syndicate
+        .pending_usdc
+        .checked_sub(refund)
+        .ok_or(LottoError::Underflow)?;
+
+    require!(
+        ctx.accounts.syndicate_usdc.amount >= refund,
+        LottoError::InsufficientTokenBalance
+    );
+
+    // SECURITY FIX (Issue #1): Use original_creator for signer seeds
+    let syndicate_original_creator = ctx.accounts.syndicate.original_creator;
+    let syndicate_id = ctx.accounts.syndicate.syndicate_id;
+    let seeds = &[
+        SYNDICATE_SEED,
+        syndicate_original_creator.as_ref(),
+        &syndicate_id.to_le_bytes(),
+        &[ctx.accounts.syndicate.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.syndicate_usdc.to_account_info(),
+        to: ctx.accounts.wallet_usdc.to_account_info(),
+        authority: ctx.accounts.syndicate.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    token::transfer(cpi_ctx, refund)?;
+
+    emit!(WaitlistLeft {
+        syndicate: syndicate_key,
+        wallet: wallet_key,
+        refund,
+    });
+
+    msg!("Left syndicate waitlist!");
+    msg!("  Syndicate: {}", syndicate_key);
+    msg!("  Wallet: {}", wallet_key);
+    msg!("  Refund: {} USDC lamports", refund);
+
+    Ok(())
+}
+
+// ============================================================================
+// CLOSE SYNDICATE INSTRUCTION
+// ============================================================================
+
+/// Accounts required for closing a syndicate
+#[derive(Accounts)]
+pub struct CloseSyndicate<'info> {
+    /// The creator closing the syndicate
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// The syndicate to close
+    #[account(
+        mut,
+        close = creator,
+        constraint = syndicate.creator == creator.key() @ LottoError::Unauthorized,
+        constraint = syndicate.member_count <= 1 @ LottoError::SyndicateFull, // Only creator left
+        constraint = syndicate.pending_usdc == 0 @ LottoError::WaitlistNotEmpty,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub syndicate: Account<'info, Syndicate>,
+
+    /// Creator's USDC token account (to receive remaining funds)
+    #[account(
+        mut,
+        constraint = creator_usdc.owner == creator.key() @ LottoError::TokenAccountOwnerMismatch
+    )]
+    pub creator_usdc: Account<'info, TokenAccount>,
+
+    /// Syndicate's USDC token account (will be closed)
+    #[account(
+        mut,
+        seeds = [
+            SYNDICATE_SEED,
+            b"usdc",
+            syndicate.key().as_ref()
+        ],
+        bump,
+        constraint = syndicate_usdc.key() == syndicate.usdc_account @ LottoError::InvalidTokenAccount
+    )]
+    pub syndicate_usdc: Account<'info, TokenAccount>,
+
+    /// Public syndicate registry (the syndicate is delisted)
+    #[account(
+        mut,
+        seeds = [SYNDICATE_REGISTRY_SEED],
+        bump = syndicate_registry.bump
+    )]
+    pub syndicate_registry: Account<'info, SyndicateRegistry>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Close a syndicate
+///
+/// This instruction:
+/// 1. Validates the caller is the creator
+/// 2. Validates all other members have left
+/// 3. Transfers any remaining USDC to creator
+/// 4. Closes the syndicate USDC account
+/// 5. Removes the syndicate from the public registry
+/// 6. Closes the syndicate account and returns rent to creator
+///
+/// # Dust Handling
+/// Any remaining USDC in the syndicate account goes to the creator.
+/// This should only be the creator's own contribution or small amounts
+/// from rounding during ticket purchases. All other members should have
+/// received their full refund when leaving.
+///
+/// If significant funds remain (indicating a bug or failed refunds),
+/// consider using withdraw_creator_contribution first to verify amounts.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_close_syndicate(ctx: Context<CloseSyndicate>) -> Result<()> {
+    let syndicate_key = ctx.accounts.syndicate.key();
+    // SECURITY FIX (Issue #1): Use original_creator for signer seeds
+    let syndicate_original_creator = ctx.accounts.syndicate.original_creator;
+    let syndicate_id = ctx.accounts.syndicate.syndicate_id;
+    let syndicate_bump = ctx.accounts.syndicate.bump;
+    let remaining_balance = ctx.accounts.syndicate_usdc.amount;
+
+    // Get creator's recorded contribution for comparison
+    let current_creator = ctx.accounts.syndicate.creator;
+    let creator_contribution = ctx
+        .accounts
+        .syndicate
+        .find_member(&current_creator)
+        .map(|m| m.contribution)
+        .unwrap_or(0);
+
+    // FIXED: Warn if remaining balance exceeds creator's contribution (potential unfairness)
+    let excess_funds = remaining_balance.saturating_sub(creator_contribution);
+    if excess_funds > 0 {
+        msg!(
+            "⚠️  WARNING: Remaining balance ({}) exceeds creator contribution ({}).",
+            remaining_balance,
+            creator_contribution
+        );
+        msg!(
+            "    Excess of {} USDC lamports may be from failed refunds or rounding.",
+            excess_funds
+        );
+        msg!("    All funds will be transferred to creator.");
+    }
+
+    // Transfer any remaining USDC to creator
+    if remaining_balance > 0 {
+        let seeds = &[
+            SYNDICATE_SEED,
+            syndicate_original_creator.as_ref(),
+            &syndicate_id.to_le_bytes(),
+            &[syndicate_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.syndicate_usdc.to_account_info(),
+            to: ctx.accounts.creator_usdc.to_account_info(),
+            authority: ctx.accounts.syndicate.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+        token::transfer(cpi_ctx, remaining_balance)?;
+
+        msg!(
+            "Transferred remaining {} USDC lamports to creator",
+            remaining_balance
+        );
+        if excess_funds > 0 {
+            msg!(
+                "  (includes {} excess beyond creator's tracked contribution)",
+                excess_funds
+            );
+        }
+    }
+
+    // Close the syndicate USDC token account
+    let seeds = &[
+        SYNDICATE_SEED,
+        syndicate_original_creator.as_ref(),
+        &syndicate_id.to_le_bytes(),
+        &[syndicate_bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = token::CloseAccount {
+        account: ctx.accounts.syndicate_usdc.to_account_info(),
+        destination: ctx.accounts.creator.to_account_info(),
+        authority: ctx.accounts.syndicate.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+    token::close_account(cpi_ctx)?;
+
+    if ctx.accounts.syndicate_registry.remove_entry(&syndicate_key) {
+        msg!("Syndicate removed from public registry");
+    }
+
+    msg!("Syndicate closed!");
+    msg!("  Syndicate: {}", syndicate_key);
+    msg!("  Creator: {}", ctx.accounts.creator.key());
+    msg!(
+        "  Final balance transferred: {} USDC lamports",
+        remaining_balance
+    );
+
+    // Account closure is handled automatically by the `close` constraint
 
     Ok(())
 }
@@ -773,10 +1428,20 @@ pub struct WithdrawCreatorContribution<'info> {
     /// The syndicate
     #[account(
         mut,
-        constraint = syndicate.creator == creator.key() @ LottoError::Unauthorized
+        constraint = syndicate.creator == creator.key() @ LottoError::Unauthorized,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub syndicate: Account<'info, Syndicate>,
 
+    /// The main lottery state account (for the syndicate lock check)
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = !syndicate.is_locked_for_draw(lottery_state.current_draw_id) @ LottoError::SyndicateLocked,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
     /// Creator's USDC token account (to receive withdrawal)
     #[account(
         mut,
@@ -887,14 +1552,185 @@ pub fn handler_withdraw_creator_contribution(
 }
 
 // ============================================================================
-// BUY SYNDICATE TICKETS INSTRUCTION
+// PARTIAL WITHDRAW MEMBER CONTRIBUTION
 // ============================================================================
 
-/// Parameters for buying syndicate tickets
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct BuySyndicateTicketsParams {
-    /// Array of ticket numbers (each ticket has 6 numbers)
+/// Accounts required for a member to withdraw part of their contribution
+#[derive(Accounts)]
+pub struct PartialWithdrawMemberContribution<'info> {
+    /// The member withdrawing (not the creator)
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    /// The syndicate
+    #[account(
+        mut,
+        constraint = syndicate.creator != member.key() @ LottoError::Unauthorized,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub syndicate: Account<'info, Syndicate>,
+
+    /// The main lottery state account (for the syndicate lock check)
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = !syndicate.is_locked_for_draw(lottery_state.current_draw_id) @ LottoError::SyndicateLocked,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// Member's USDC token account (to receive withdrawal)
+    #[account(
+        mut,
+        constraint = member_usdc.owner == member.key() @ LottoError::TokenAccountOwnerMismatch
+    )]
+    pub member_usdc: Account<'info, TokenAccount>,
+
+    /// Syndicate's USDC token account
+    #[account(
+        mut,
+        seeds = [
+            SYNDICATE_SEED,
+            b"usdc",
+            syndicate.key().as_ref()
+        ],
+        bump,
+        constraint = syndicate_usdc.key() == syndicate.usdc_account @ LottoError::InvalidTokenAccount
+    )]
+    pub syndicate_usdc: Account<'info, TokenAccount>,
+
+    /// Public syndicate registry (listing refreshed after the withdrawal)
+    #[account(
+        mut,
+        seeds = [SYNDICATE_REGISTRY_SEED],
+        bump = syndicate_registry.bump
+    )]
+    pub syndicate_registry: Account<'info, SyndicateRegistry>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Allow a non-creator member to withdraw part of their contribution
+///
+/// The member stays in the syndicate with at least
+/// MIN_SYNDICATE_MEMBER_CONTRIBUTION; to withdraw everything they leave
+/// instead. The creator uses `withdraw_creator_contribution`.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `amount` - Amount to withdraw (must be <= member's contribution)
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_partial_withdraw_member_contribution(
+    ctx: Context<PartialWithdrawMemberContribution>,
+    amount: u64,
+) -> Result<()> {
+    let member_key = ctx.accounts.member.key();
+    let syndicate_key = ctx.accounts.syndicate.key();
+
+    let member_contribution = ctx
+        .accounts
+        .syndicate
+        .find_member(&member_key)
+        .map(|m| m.contribution)
+        .ok_or(LottoError::NotSyndicateMember)?;
+
+    // Validate withdrawal amount
+    require!(amount > 0, LottoError::InvalidAmount);
+    require!(amount <= member_contribution, LottoError::InsufficientFunds);
+    let new_contribution = member_contribution - amount;
+    require!(
+        new_contribution >= MIN_SYNDICATE_MEMBER_CONTRIBUTION,
+        LottoError::InsufficientContribution
+    );
+    require!(
+        ctx.accounts.syndicate_usdc.amount >= amount,
+        LottoError::InsufficientTokenBalance
+    );
+
+    // SECURITY FIX (Issue #1): Use original_creator for signer seeds
+    let syndicate_original_creator = ctx.accounts.syndicate.original_creator;
+    let syndicate_id = ctx.accounts.syndicate.syndicate_id;
+    let syndicate_bump = ctx.accounts.syndicate.bump;
+
+    // Update the member's contribution in place
+    let syndicate = &mut ctx.accounts.syndicate;
+    if let Some(member) = syndicate.find_member_mut(&member_key) {
+        member.contribution = new_contribution;
+    }
+    syndicate.total_contribution = syndicate.total_contribution.saturating_sub(amount);
+    syndicate.recalculate_shares();
+
+    let new_share_bps = syndicate
+        .find_member(&member_key)
+        .map(|m| m.share_percentage_bps)
+        .unwrap_or(0);
+
+    sync_syndicate_registry(
+        &mut ctx.accounts.syndicate_registry,
+        syndicate_key,
+        &ctx.accounts.syndicate,
+        &ctx.accounts.member.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    // Transfer USDC to member
+    let seeds = &[
+        SYNDICATE_SEED,
+        syndicate_original_creator.as_ref(),
+        &syndicate_id.to_le_bytes(),
+        &[syndicate_bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.syndicate_usdc.to_account_info(),
+        to: ctx.accounts.member_usdc.to_account_info(),
+        authority: ctx.accounts.syndicate.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+    token::transfer(cpi_ctx, amount)?;
+
+    emit!(MemberPartialWithdrawal {
+        syndicate: syndicate_key,
+        member: member_key,
+        amount,
+        new_contribution,
+        new_share_bps,
+    });
+
+    msg!("Member contribution partially withdrawn!");
+    msg!("  Syndicate: {}", syndicate_key);
+    msg!("  Member: {}", member_key);
+    msg!("  Amount withdrawn: {} USDC lamports", amount);
+    msg!(
+        "  Remaining contribution: {} USDC lamports",
+        new_contribution
+    );
+    msg!("  New share: {} bps", new_share_bps);
+
+    Ok(())
+}
+
+// ============================================================================
+// BUY SYNDICATE TICKETS INSTRUCTION
+// ============================================================================
+
+/// Parameters for buying syndicate tickets
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BuySyndicateTicketsParams {
+    /// Array of ticket numbers (each ticket has 6 numbers)
     pub tickets: Vec<[u8; 6]>,
+    /// Highest per-ticket price the manager accepts (slippage protection
+    /// for dynamic pricing)
+    pub max_price_usdc: u64,
 }
 
 /// Accounts required for buying syndicate tickets
@@ -916,7 +1752,8 @@ pub struct BuySyndicateTickets<'info> {
             &syndicate.syndicate_id.to_le_bytes()
         ],
         bump = syndicate.bump,
-        constraint = syndicate.creator == creator.key() @ LottoError::Unauthorized
+        constraint = syndicate.creator == creator.key() @ LottoError::Unauthorized,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub syndicate: Account<'info, Syndicate>,
 
@@ -927,7 +1764,8 @@ pub struct BuySyndicateTickets<'info> {
         bump = lottery_state.bump,
         constraint = !lottery_state.is_paused @ LottoError::Paused,
         constraint = lottery_state.is_funded @ LottoError::LotteryNotInitialized,
-        constraint = !lottery_state.is_draw_in_progress @ LottoError::DrawInProgress
+        constraint = !lottery_state.is_draw_in_progress @ LottoError::DrawInProgress,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
@@ -960,14 +1798,22 @@ pub struct BuySyndicateTickets<'info> {
     )]
     pub house_fee_usdc: Account<'info, TokenAccount>,
 
-    /// Insurance pool USDC token account
+    /// Insurance tier 1 USDC token account
     #[account(
         mut,
-        seeds = [INSURANCE_POOL_USDC_SEED],
+        seeds = [INSURANCE_TIER1_SEED],
         bump
     )]
     pub insurance_pool_usdc: Account<'info, TokenAccount>,
 
+    /// Insurance tier 2 USDC token account
+    #[account(
+        mut,
+        seeds = [INSURANCE_TIER2_SEED],
+        bump
+    )]
+    pub insurance_tier2_usdc: Box<Account<'info, TokenAccount>>,
+
     /// USDC mint
     pub usdc_mint: Account<'info, Mint>,
 
@@ -976,23 +1822,32 @@ pub struct BuySyndicateTickets<'info> {
 
     /// System program
     pub system_program: Program<'info, System>,
+
+    /// House fee split config (required while a split is active; the
+    /// recipient sub-accounts are passed as remaining_accounts)
+    #[account(
+        seeds = [FEE_RECIPIENTS_SEED],
+        bump = fee_recipients.bump
+    )]
+    pub fee_recipients: Option<Account<'info, HouseFeeRecipients>>,
 }
 
 /// Validate ticket numbers
+///
+/// Uses the same single-pass bitmask check as `buy_ticket::validate_numbers`
+/// (no copy or sort).
 fn validate_ticket_numbers(numbers: &[u8; 6]) -> Result<()> {
-    // Check range for each number
+    // Single pass: range check, then one bit per number (MAX_NUMBER < 64)
+    let mut seen: u64 = 0;
     for &num in numbers.iter() {
         require!(
             num >= MIN_NUMBER && num <= MAX_NUMBER,
             LottoError::NumbersOutOfRange
         );
-    }
 
-    // Check for duplicates by sorting and comparing adjacent
-    let mut sorted = *numbers;
-    sorted.sort();
-    for i in 0..5 {
-        require!(sorted[i] != sorted[i + 1], LottoError::DuplicateNumbers);
+        let bit = 1u64 << num;
+        require!(seen & bit == 0, LottoError::DuplicateNumbers);
+        seen |= bit;
     }
 
     Ok(())
@@ -1003,7 +1858,8 @@ fn validate_ticket_numbers(numbers: &[u8; 6]) -> Result<()> {
 /// This instruction:
 /// 1. Validates the caller is the syndicate creator
 /// 2. Validates all ticket numbers
-/// 3. Checks syndicate has sufficient funds
+/// 3. Prices the tickets at the effective ticket price and checks the
+///    syndicate has sufficient funds
 /// 4. Calculates fees and transfers USDC from syndicate to prize pool and house fee
 /// 5. Creates ticket accounts for each ticket (owned by syndicate)
 /// 6. Updates lottery state
@@ -1017,8 +1873,8 @@ fn validate_ticket_numbers(numbers: &[u8; 6]) -> Result<()> {
 ///
 /// # Returns
 /// * `Result<()>` - Success or error
-pub fn handler_buy_syndicate_tickets(
-    ctx: Context<BuySyndicateTickets>,
+pub fn handler_buy_syndicate_tickets<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BuySyndicateTickets<'info>>,
     params: BuySyndicateTicketsParams,
 ) -> Result<()> {
     let clock = Clock::get()?;
@@ -1034,31 +1890,32 @@ pub fn handler_buy_syndicate_tickets(
     // Validate all ticket numbers
     for ticket in &params.tickets {
         validate_ticket_numbers(ticket)?;
+        check_numbers_entropy(
+            ctx.accounts.lottery_state.entropy_validation_enabled,
+            ctx.accounts.creator.key(),
+            ticket,
+        )?;
     }
 
     // Get lottery state values
-    let ticket_price = ctx.accounts.lottery_state.ticket_price;
-    let next_draw_timestamp = ctx.accounts.lottery_state.next_draw_timestamp;
+    let ticket_price = resolve_ticket_price(&ctx.accounts.lottery_state, params.max_price_usdc)?;
     let current_draw_id = ctx.accounts.lottery_state.current_draw_id;
     let house_fee_bps = ctx.accounts.lottery_state.get_current_house_fee_bps();
 
     // Check if ticket sales are open
-    require!(
-        clock.unix_timestamp
-            < next_draw_timestamp
-                .checked_sub(TICKET_SALE_CUTOFF)
-                .unwrap_or(i64::MIN),
-        LottoError::TicketSaleEnded
-    );
+    check_ticket_sale_cutoff(&ctx.accounts.lottery_state, clock.unix_timestamp)?;
 
     // Calculate total cost
     let total_cost = ticket_price
         .checked_mul(ticket_count as u64)
         .ok_or(LottoError::Overflow)?;
 
-    // Verify syndicate has sufficient funds
+    // Verify syndicate has sufficient funds (waitlist escrow is not spendable)
     require!(
-        ctx.accounts.syndicate_usdc.amount >= total_cost,
+        ctx.accounts
+            .syndicate
+            .spendable_usdc(ctx.accounts.syndicate_usdc.amount)
+            >= total_cost,
         LottoError::InsufficientFunds
     );
 
@@ -1081,17 +1938,25 @@ pub fn handler_buy_syndicate_tickets(
     ];
     let signer_seeds = &[&seeds[..]];
 
-    // Transfer house fee from syndicate to house fee account
-    if total_house_fee > 0 {
-        let cpi_accounts = Transfer {
+    // Transfer house fee from syndicate to house fee account (or fee
+    // recipient sub-accounts)
+    route_house_fee(
+        &HouseFeeSource {
             from: ctx.accounts.syndicate_usdc.to_account_info(),
-            to: ctx.accounts.house_fee_usdc.to_account_info(),
             authority: ctx.accounts.syndicate.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-        token::transfer(cpi_ctx, total_house_fee)?;
-    }
+            token_program: ctx.accounts.token_program.to_account_info(),
+            signer_seeds,
+        },
+        &HouseFeeDestination {
+            house_fee_usdc: ctx.accounts.house_fee_usdc.to_account_info(),
+            fee_recipients: ctx.accounts.fee_recipients.as_deref(),
+            sub_accounts: ctx.remaining_accounts,
+        },
+        ctx.accounts.lottery_state.house_fee_split_active,
+        total_house_fee,
+        current_draw_id,
+        ctx.program_id,
+    )?;
 
     // Calculate jackpot, reserve, and insurance contributions BEFORE transfers
     // so we can split the prize pool transfer correctly.
@@ -1106,7 +1971,7 @@ pub fn handler_buy_syndicate_tickets(
         / BPS_DENOMINATOR as u128) as u64;
 
     // FIXED: Transfer insurance portion to the insurance pool token account.
-    // Previously, insurance_contribution was only added to lottery_state.insurance_balance
+    // Previously, insurance_contribution was only added to lottery_state.insurance_tier1_balance
     // without an actual USDC transfer, creating an accounting mismatch.
     let prize_pool_transfer = total_prize_pool.saturating_sub(insurance_contribution);
 
@@ -1121,7 +1986,9 @@ pub fn handler_buy_syndicate_tickets(
         token::transfer(cpi_ctx, prize_pool_transfer)?;
     }
 
-    if insurance_contribution > 0 {
+    let (insurance_tier1_contribution, insurance_tier2_contribution) =
+        split_insurance_contribution(insurance_contribution);
+    if insurance_tier1_contribution > 0 {
         let cpi_accounts = Transfer {
             from: ctx.accounts.syndicate_usdc.to_account_info(),
             to: ctx.accounts.insurance_pool_usdc.to_account_info(),
@@ -1129,27 +1996,38 @@ pub fn handler_buy_syndicate_tickets(
         };
         let cpi_program = ctx.accounts.token_program.to_account_info();
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-        token::transfer(cpi_ctx, insurance_contribution)?;
+        token::transfer(cpi_ctx, insurance_tier1_contribution)?;
+    }
+    if insurance_tier2_contribution > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.syndicate_usdc.to_account_info(),
+            to: ctx.accounts.insurance_tier2_usdc.to_account_info(),
+            authority: ctx.accounts.syndicate.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, insurance_tier2_contribution)?;
     }
 
     // Update syndicate total contribution (deduct spent amount)
     let syndicate = &mut ctx.accounts.syndicate;
     syndicate.total_contribution = syndicate.total_contribution.saturating_sub(total_cost);
+    // Ticket accounts for these can now be created, up to the count paid for
+    syndicate.record_tickets_purchased(current_draw_id, ticket_count as u32)?;
 
     // Update lottery state
     let lottery_state = &mut ctx.accounts.lottery_state;
+    let old_jackpot_balance = lottery_state.jackpot_balance;
     lottery_state.jackpot_balance = lottery_state
         .jackpot_balance
         .checked_add(jackpot_contribution)
         .ok_or(LottoError::Overflow)?;
+    route_jackpot_overflow(lottery_state)?;
     lottery_state.reserve_balance = lottery_state
         .reserve_balance
         .checked_add(reserve_contribution)
         .ok_or(LottoError::Overflow)?;
-    lottery_state.insurance_balance = lottery_state
-        .insurance_balance
-        .checked_add(insurance_contribution)
-        .ok_or(LottoError::Overflow)?;
+    lottery_state.credit_insurance(insurance_contribution)?;
     // SECURITY FIX (Issue #4): Track dedicated fixed prize pool balance.
     if fixed_prize_contribution > 0 {
         lottery_state.fixed_prize_balance = lottery_state
@@ -1173,9 +2051,22 @@ pub fn handler_buy_syndicate_tickets(
     if lottery_state.jackpot_balance >= lottery_state.soft_cap {
         lottery_state.is_rolldown_active = true;
     }
+    unlock_milestone_bonuses(lottery_state, old_jackpot_balance);
 
     let syndicate_key = ctx.accounts.syndicate.key();
 
+    // Freeze membership until the draw is finalized to prevent share dilution
+    let syndicate = &mut ctx.accounts.syndicate;
+    if !syndicate.is_locked_for_draw(current_draw_id) {
+        syndicate.is_locked = true;
+        syndicate.locked_draw_id = current_draw_id;
+
+        emit!(SyndicateLocked {
+            syndicate: syndicate_key,
+            draw_id: current_draw_id,
+        });
+    }
+
     // Emit event
     emit!(BulkTicketsPurchased {
         player: ctx.accounts.creator.key(),
@@ -1200,7 +2091,7 @@ pub fn handler_buy_syndicate_tickets(
         ctx.accounts.syndicate.total_contribution
     );
     msg!(
-        "  NOTE: Individual ticket accounts must be created separately via create_syndicate_ticket"
+        "  NOTE: Individual ticket accounts must be created separately via create_syndicate_ticket or create_syndicate_tickets_batch"
     );
 
     Ok(())
@@ -1220,27 +2111,32 @@ pub struct CreateSyndicateTicket<'info> {
 
     /// The syndicate that owns the ticket
     #[account(
-        constraint = syndicate.creator == payer.key() @ LottoError::Unauthorized
+        mut,
+        constraint = syndicate.creator == payer.key() @ LottoError::Unauthorized,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub syndicate: Account<'info, Syndicate>,
 
     /// The lottery state account
     #[account(
-        mut,
         seeds = [LOTTERY_SEED],
-        bump = lottery_state.bump
+        bump = lottery_state.bump,
+        constraint = !lottery_state.is_draw_in_progress @ LottoError::DrawInProgress,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
-    /// The ticket account to be created
+    /// The ticket account to be created, at the syndicate's next ticket
+    /// index for the draw
     #[account(
         init,
         payer = payer,
         space = TICKET_SIZE,
         seeds = [
             TICKET_SEED,
+            syndicate.key().as_ref(),
             &lottery_state.current_draw_id.to_le_bytes(),
-            &lottery_state.current_draw_tickets.to_le_bytes()
+            &syndicate.tickets_created.to_le_bytes()
         ],
         bump
     )]
@@ -1253,7 +2149,8 @@ pub struct CreateSyndicateTicket<'info> {
 /// Create a single ticket account for a syndicate
 ///
 /// This is called after buy_syndicate_tickets to create individual ticket accounts.
-/// The funds have already been transferred; this just creates the account records.
+/// The funds have already been transferred; this just creates the account records,
+/// at most one per ticket bought for the current draw.
 ///
 /// # Arguments
 /// * `ctx` - The context containing required accounts
@@ -1276,9 +2173,14 @@ pub fn handler_create_syndicate_ticket(
 
     let current_draw_id = ctx.accounts.lottery_state.current_draw_id;
     let syndicate_key = ctx.accounts.syndicate.key();
+    let ticket_index = ctx
+        .accounts
+        .syndicate
+        .take_ticket_indexes(current_draw_id, 1)?;
 
     // Create ticket
     let ticket = &mut ctx.accounts.ticket;
+    ticket.schema_version = CURRENT_SCHEMA_VERSION;
     ticket.owner = syndicate_key; // Syndicate owns the ticket
     ticket.draw_id = current_draw_id;
     ticket.numbers = sorted_numbers;
@@ -1287,7 +2189,13 @@ pub fn handler_create_syndicate_ticket(
     ticket.match_count = 0;
     ticket.prize_amount = 0;
     ticket.syndicate = Some(syndicate_key);
+    ticket.original_owner = syndicate_key;
+    ticket.entered_second_chance = false;
     ticket.bump = ctx.bumps.ticket;
+    // Bought with pooled funds that a refund to the syndicate could not
+    // return to members, so syndicate tickets are not refundable
+    ticket.requires_refund = false;
+    ticket.claim_agent = None;
 
     // Note: lottery_state.current_draw_tickets is NOT incremented here
     // because it was already incremented in buy_syndicate_tickets
@@ -1296,150 +2204,322 @@ pub fn handler_create_syndicate_ticket(
     msg!("Syndicate ticket created!");
     msg!("  Ticket: {}", ctx.accounts.ticket.key());
     msg!("  Syndicate: {}", syndicate_key);
+    msg!("  Ticket index: {}", ticket_index);
     msg!("  Numbers: {:?}", sorted_numbers);
 
     Ok(())
 }
 
 // ============================================================================
-// DISTRIBUTE SYNDICATE PRIZE INSTRUCTION
+// CREATE SYNDICATE TICKETS BATCH INSTRUCTION
 // ============================================================================
 
-/// Parameters for distributing a prize to syndicate members
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct DistributeSyndicatePrizeParams {
-    /// Draw ID for which the prize is being distributed
-    pub draw_id: u64,
+/// Address and bump of `syndicate`'s ticket PDA at `ticket_index` in `draw_id`
+pub fn syndicate_ticket_address(
+    syndicate: &Pubkey,
+    draw_id: u64,
+    ticket_index: u32,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            TICKET_SEED,
+            syndicate.as_ref(),
+            &draw_id.to_le_bytes(),
+            &ticket_index.to_le_bytes(),
+        ],
+        program_id,
+    )
 }
 
-/// Accounts required for distributing a syndicate prize
+/// Accounts required for creating a batch of syndicate ticket accounts
 ///
-/// ## Fix #7 — On-chain ticket verification
+/// The uncreated ticket PDAs are passed as writable `remaining_accounts`,
+/// one per ticket, in the order of the `tickets` argument.
+#[derive(Accounts)]
+pub struct CreateSyndicateTicketsBatch<'info> {
+    /// The payer for the ticket accounts (typically syndicate creator)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The syndicate that owns the tickets
+    #[account(
+        mut,
+        constraint = syndicate.creator == payer.key() @ LottoError::Unauthorized,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub syndicate: Account<'info, Syndicate>,
+
+    /// The lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = !lottery_state.is_draw_in_progress @ LottoError::DrawInProgress,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Create up to MAX_SYNDICATE_TICKET_BATCH ticket accounts for a syndicate
+///
+/// Batch form of `create_syndicate_ticket`: ticket `i` is created at
+/// `[TICKET_SEED, syndicate, draw_id, tickets_created + i]`, and together
+/// the syndicate's ticket accounts for a draw cannot outnumber the tickets
+/// it bought. All numbers and accounts are validated before any account is
+/// created.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `tickets` - The 6 numbers for each ticket
 ///
-/// Previously this instruction accepted a `total_prize` parameter from the
-/// authority and blindly transferred that amount from the prize pool.  A
-/// compromised or malicious authority could inflate the amount at will.
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_create_syndicate_tickets_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateSyndicateTicketsBatch<'info>>,
+    tickets: Vec<[u8; 6]>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let program_id = ctx.program_id;
+
+    let count = tickets.len();
+    require!(count > 0, LottoError::EmptyTicketArray);
+    require!(
+        count <= MAX_SYNDICATE_TICKET_BATCH,
+        LottoError::BulkPurchaseLimitExceeded
+    );
+    require!(
+        ctx.remaining_accounts.len() == count,
+        LottoError::InvalidTicketAccount
+    );
+
+    let current_draw_id = ctx.accounts.lottery_state.current_draw_id;
+    let syndicate_key = ctx.accounts.syndicate.key();
+    let first_index = ctx
+        .accounts
+        .syndicate
+        .take_ticket_indexes(current_draw_id, count as u32)?;
+
+    // Validate every ticket before creating any account
+    let mut sorted_tickets = Vec::with_capacity(count);
+    for (i, (numbers, ticket_info)) in tickets.iter().zip(ctx.remaining_accounts).enumerate() {
+        validate_ticket_numbers(numbers)?;
+        let ticket_index = first_index + i as u32;
+        let (expected_pda, bump) =
+            syndicate_ticket_address(&syndicate_key, current_draw_id, ticket_index, program_id);
+        require!(
+            ticket_info.key() == expected_pda
+                && ticket_info.is_writable
+                && ticket_info.data_is_empty(),
+            LottoError::InvalidTicketAccount
+        );
+
+        let mut sorted_numbers = *numbers;
+        sorted_numbers.sort();
+        sorted_tickets.push((sorted_numbers, ticket_index, bump));
+    }
+
+    let rent = Rent::get()?;
+    let draw_id_bytes = current_draw_id.to_le_bytes();
+    for ((numbers, ticket_index, bump), ticket_info) in
+        sorted_tickets.into_iter().zip(ctx.remaining_accounts)
+    {
+        let ticket_index_bytes = ticket_index.to_le_bytes();
+        let ticket_seeds: &[&[u8]] = &[
+            TICKET_SEED,
+            syndicate_key.as_ref(),
+            &draw_id_bytes,
+            &ticket_index_bytes,
+            &[bump],
+        ];
+        anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::CreateAccount {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ticket_info.clone(),
+                },
+                &[ticket_seeds],
+            ),
+            rent.minimum_balance(TICKET_SIZE),
+            TICKET_SIZE as u64,
+            program_id,
+        )?;
+
+        let ticket = TicketData {
+            schema_version: CURRENT_SCHEMA_VERSION,
+            owner: syndicate_key, // Syndicate owns the ticket
+            draw_id: current_draw_id,
+            numbers,
+            purchase_timestamp: clock.unix_timestamp,
+            syndicate: Some(syndicate_key),
+            original_owner: syndicate_key,
+            bump,
+            // Not refundable, as in create_syndicate_ticket
+            requires_refund: false,
+            ..Default::default()
+        };
+        let mut data = ticket_info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        ticket.try_serialize(&mut writer)?;
+    }
+
+    // As in create_syndicate_ticket, current_draw_tickets was already
+    // incremented by buy_syndicate_tickets
+
+    emit!(SyndicateTicketsBatchCreated {
+        syndicate: syndicate_key,
+        draw_id: current_draw_id,
+        count: count as u8,
+        start_ticket_pda: ctx.remaining_accounts[0].key(),
+    });
+
+    msg!("Syndicate tickets batch created!");
+    msg!("  Syndicate: {}", syndicate_key);
+    msg!("  Ticket count: {}", count);
+    msg!("  First ticket index: {}", first_index);
+
+    Ok(())
+}
+
+// ============================================================================
+// STAGE SYNDICATE PRIZE INSTRUCTION
+// ============================================================================
+
+/// Accounts required for staging a batch of syndicate tickets
+///
+/// ## Fix #7 — On-chain ticket verification
 ///
-/// Now the prize is **computed on-chain** from the actual ticket accounts
-/// passed as `remaining_accounts`.  For every ticket the instruction:
+/// The prize is **computed on-chain** from the actual ticket accounts
+/// passed as `remaining_accounts`, never supplied by the authority.  For
+/// every ticket the instruction:
 ///
 /// 1. Deserializes it as `TicketData`
 /// 2. Verifies `ticket.syndicate == Some(syndicate.key())`
-/// 3. Verifies `ticket.draw_id == params.draw_id`
+/// 3. Verifies `ticket.draw_id == draw_result.draw_id`
 /// 4. Verifies `!ticket.is_claimed`
 /// 5. Counts matches against `draw_result.winning_numbers`
 /// 6. Looks up the per-winner prize via `draw_result.get_prize_for_matches()`
-/// 7. Marks the ticket as claimed and writes it back
-/// 8. Accumulates the batch prize total
-///
-/// The accumulated total is then transferred — no authority-supplied amount
-/// can exceed what the tickets actually won.
+/// 7. Requires the manager's identity proof for prizes above
+///    `identity_verification_threshold`
+/// 8. Marks the ticket as claimed and writes it back
+/// 9. Adds the prize to the stage's `staged_amount` and its tier total, and
+///    Match 6 prizes above the escrow threshold to `escrowed_amount`
 ///
-/// For large syndicates the instruction can be called in batches (different
-/// subsets of ticket accounts in `remaining_accounts` each call).
+/// No USDC moves here; `finalize_staged_prize` transfers the staged total
+/// once every batch has been staged.
 #[derive(Accounts)]
-#[instruction(params: DistributeSyndicatePrizeParams)]
-pub struct DistributeSyndicatePrize<'info> {
+pub struct StageSyndicatePrize<'info> {
     /// The syndicate creator/manager (must also be lottery authority)
     #[account(mut)]
     pub manager: Signer<'info>,
 
-    /// Lottery state (authority for prize pool)
+    /// Lottery state
     #[account(
-        mut,
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
-        constraint = lottery_state.authority == manager.key() @ LottoError::Unauthorized
+        constraint = lottery_state.authority == manager.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
-    /// The finalized draw result for `params.draw_id`.
+    /// The finalized draw result the tickets were entered in.
     /// Used to look up winning numbers and per-tier prize amounts.
     #[account(
-        seeds = [DRAW_SEED, &params.draw_id.to_le_bytes()],
+        seeds = [DRAW_SEED, &draw_result.draw_id.to_le_bytes()],
         bump = draw_result.bump,
-        constraint = draw_result.draw_id == params.draw_id @ LottoError::DrawIdMismatch,
-        constraint = draw_result.is_finalized() @ LottoError::DrawNotFinalized
+        constraint = draw_result.is_finalized() @ LottoError::DrawNotFinalized,
+        constraint = !draw_result.are_claims_frozen() @ LottoError::DrawClaimsFrozen,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub draw_result: Account<'info, DrawResult>,
 
     /// The syndicate account
     #[account(
-        mut,
         seeds = [
             SYNDICATE_SEED,
             syndicate.original_creator.as_ref(),
             &syndicate.syndicate_id.to_le_bytes()
         ],
-        bump = syndicate.bump
+        bump = syndicate.bump,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub syndicate: Account<'info, Syndicate>,
 
-    /// Syndicate's USDC token account (receives the prize)
+    /// The prize stage for this syndicate and draw (created by the first batch)
     #[account(
-        mut,
+        init_if_needed,
+        payer = manager,
+        space = SYNDICATE_PRIZE_STAGE_SIZE,
         seeds = [
-            SYNDICATE_SEED,
-            b"usdc",
-            syndicate.key().as_ref()
+            SYNDICATE_PRIZE_STAGE_SEED,
+            syndicate.key().as_ref(),
+            &draw_result.draw_id.to_le_bytes()
         ],
-        bump,
-        constraint = syndicate_usdc.key() == syndicate.usdc_account @ LottoError::InvalidTokenAccount
+        bump
     )]
-    pub syndicate_usdc: Account<'info, TokenAccount>,
+    pub prize_stage: Account<'info, SyndicatePrizeStage>,
 
-    /// Prize pool USDC token account (source of prize)
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// The syndicate manager's identity proof, required only for prizes
+    /// above `identity_verification_threshold`
     #[account(
-        mut,
-        seeds = [b"prize_pool_usdc"],
-        bump
+        seeds = [IDENTITY_SEED, syndicate.creator.as_ref()],
+        bump = identity_proof.bump,
+        constraint = identity_proof.player == syndicate.creator @ LottoError::InvalidIdentityProof
     )]
-    pub prize_pool_usdc: Account<'info, TokenAccount>,
-
-    /// USDC mint
-    pub usdc_mint: Account<'info, Mint>,
-
-    /// Token program
-    pub token_program: Program<'info, Token>,
+    pub identity_proof: Option<Account<'info, IdentityProof>>,
 }
 
-/// Distribute prize to syndicate members by verifying ticket wins on-chain.
-///
-/// ## Fix #7 — On-chain syndicate prize verification
+/// Stage a batch of syndicate tickets by verifying their wins on-chain.
 ///
 /// Syndicate ticket accounts must be passed as **writable** `remaining_accounts`.
 /// The instruction iterates over each one, verifies ownership / draw / claim
 /// state, counts matches, looks up the prize, marks the ticket claimed, and
-/// accumulates the total.  Only the verified total is transferred.
+/// adds the verified total to the prize stage.
 ///
 /// For syndicates with many tickets, call this instruction in batches (each
-/// batch is a different subset of remaining_accounts).
+/// batch is a different subset of remaining_accounts), then call
+/// `finalize_staged_prize` once.
+///
+/// A ticket whose prize is above `identity_verification_threshold` needs a
+/// current identity proof of the syndicate manager. Match 6 prizes above
+/// `escrow_threshold_usdc` are staged as escrowed and moved to the
+/// syndicate's escrow for the draw by `finalize_staged_prize`.
 ///
 /// # Arguments
-/// * `ctx`    - The context containing required accounts
-/// * `params` - Distribution parameters (draw_id)
+/// * `ctx` - The context containing required accounts
 ///
 /// # Returns
 /// * `Result<()>` - Success or error
-pub fn handler_distribute_syndicate_prize<'info>(
-    ctx: Context<'_, '_, 'info, 'info, DistributeSyndicatePrize<'info>>,
-    params: DistributeSyndicatePrizeParams,
+pub fn handler_stage_syndicate_prize<'info>(
+    ctx: Context<'_, '_, 'info, 'info, StageSyndicatePrize<'info>>,
 ) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
     let syndicate_key = ctx.accounts.syndicate.key();
+    let syndicate_manager = ctx.accounts.syndicate.creator;
     let draw_result = &ctx.accounts.draw_result;
+    let draw_id = draw_result.draw_id;
     let program_id = ctx.program_id;
 
-    // =========================================================================
-    // STEP 1: Iterate over remaining_accounts (syndicate ticket accounts),
-    //         verify each ticket, compute prize, mark claimed.
-    // =========================================================================
     let remaining = ctx.remaining_accounts;
     require!(!remaining.is_empty(), LottoError::NoWinningTicketsInBatch);
 
-    let mut total_prize: u64 = 0;
+    let mut batch_prize: u64 = 0;
     let mut tickets_processed: u32 = 0;
     let mut tickets_won: u32 = 0;
 
+    let prize_stage = &mut ctx.accounts.prize_stage;
+    if prize_stage.syndicate == Pubkey::default() {
+        prize_stage.syndicate = syndicate_key;
+        prize_stage.draw_id = draw_id;
+        prize_stage.bump = ctx.bumps.prize_stage;
+    }
+
     for ticket_account_info in remaining.iter() {
         // a) Ticket account must be writable so we can mark it claimed
         require!(
@@ -1458,6 +2538,7 @@ pub fn handler_distribute_syndicate_prize<'info>(
         let mut readable: &[u8] = &ticket_data_raw;
         let mut ticket = TicketData::try_deserialize(&mut readable)
             .map_err(|_| LottoError::InvalidTicketAccount)?;
+        ticket.require_current_schema()?;
 
         // d) Verify the ticket belongs to this syndicate
         require!(
@@ -1467,7 +2548,7 @@ pub fn handler_distribute_syndicate_prize<'info>(
 
         // e) Verify the ticket is for the correct draw
         require!(
-            ticket.draw_id == params.draw_id,
+            ticket.draw_id == draw_id,
             LottoError::SyndicateTicketDrawMismatch
         );
 
@@ -1482,6 +2563,19 @@ pub fn handler_distribute_syndicate_prize<'info>(
 
         // h) Look up prize for this match tier
         let prize = draw_result.get_prize_for_matches(match_count);
+        check_prize_identity(
+            &ctx.accounts.lottery_state,
+            syndicate_manager,
+            ticket_account_info.key(),
+            prize,
+            ctx.accounts.identity_proof.as_deref(),
+            now,
+        )?;
+        let escrowed = is_escrow_required(
+            match_count,
+            prize,
+            ctx.accounts.lottery_state.escrow_threshold_usdc,
+        );
 
         // i) Mark ticket as claimed and record match/prize info
         ticket.is_claimed = true;
@@ -1494,155 +2588,681 @@ pub fn handler_distribute_syndicate_prize<'info>(
             .try_serialize(&mut writer)
             .map_err(|_| LottoError::InvalidTicketAccount)?;
 
+        ctx.accounts
+            .prize_stage
+            .record_ticket(match_count, prize, escrowed)?;
         tickets_processed += 1;
         if prize > 0 {
-            total_prize = total_prize.checked_add(prize).ok_or(LottoError::Overflow)?;
+            batch_prize = batch_prize.checked_add(prize).ok_or(LottoError::Overflow)?;
             tickets_won += 1;
         }
     }
 
+    let prize_stage = &ctx.accounts.prize_stage;
+
+    emit!(SyndicatePrizeStaged {
+        syndicate: syndicate_key,
+        draw_id,
+        batch_tickets: tickets_processed,
+        accumulated_amount: prize_stage.staged_amount,
+    });
+
+    msg!("Syndicate prize batch staged (on-chain verified)!");
+    msg!("  Syndicate: {}", syndicate_key);
+    msg!("  Draw ID: {}", draw_id);
+    msg!("  Tickets processed: {}", tickets_processed);
+    msg!("  Tickets won: {}", tickets_won);
+    msg!("  Batch prize: {} USDC lamports", batch_prize);
     msg!(
-        "  Tickets processed: {}, winning: {}, total_prize: {}",
-        tickets_processed,
-        tickets_won,
-        total_prize
+        "  Staged so far: {} USDC lamports over {} tickets",
+        prize_stage.staged_amount,
+        prize_stage.tickets_staged
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// FINALIZE STAGED PRIZE INSTRUCTION
+// ============================================================================
+
+/// Accounts required for paying out a staged syndicate prize
+#[derive(Accounts)]
+pub struct FinalizeStagedPrize<'info> {
+    /// The syndicate creator/manager (must also be lottery authority;
+    /// receives the prize stage's rent)
+    #[account(mut)]
+    pub manager: Signer<'info>,
+
+    /// Lottery state (authority for prize pool)
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == manager.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The draw the prize was staged for (claims must still be open)
+    #[account(
+        seeds = [DRAW_SEED, &prize_stage.draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = !draw_result.are_claims_frozen() @ LottoError::DrawClaimsFrozen,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub draw_result: Account<'info, DrawResult>,
+
+    /// The syndicate account
+    #[account(
+        mut,
+        seeds = [
+            SYNDICATE_SEED,
+            syndicate.original_creator.as_ref(),
+            &syndicate.syndicate_id.to_le_bytes()
+        ],
+        bump = syndicate.bump,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub syndicate: Account<'info, Syndicate>,
+
+    /// Syndicate's USDC token account (receives the prize)
+    #[account(
+        mut,
+        seeds = [
+            SYNDICATE_SEED,
+            b"usdc",
+            syndicate.key().as_ref()
+        ],
+        bump,
+        constraint = syndicate_usdc.key() == syndicate.usdc_account @ LottoError::InvalidTokenAccount
+    )]
+    pub syndicate_usdc: Account<'info, TokenAccount>,
+
+    /// Prize pool USDC token account (source of prize)
+    #[account(
+        mut,
+        seeds = [b"prize_pool_usdc"],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+
+    /// The prize stage to pay out (closed afterwards, so each stage pays once)
+    #[account(
+        mut,
+        close = manager,
+        seeds = [
+            SYNDICATE_PRIZE_STAGE_SEED,
+            syndicate.key().as_ref(),
+            &prize_stage.draw_id.to_le_bytes()
+        ],
+        bump = prize_stage.bump,
+        constraint = prize_stage.syndicate == syndicate.key() @ LottoError::InvalidSyndicateConfig
+    )]
+    pub prize_stage: Account<'info, SyndicatePrizeStage>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// USDC mint
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// Escrow record for the syndicate and draw, required only when the
+    /// stage holds escrowed prizes
+    #[account(
+        init,
+        payer = manager,
+        space = PRIZE_ESCROW_SIZE,
+        seeds = [ESCROW_SEED, syndicate.key().as_ref(), &prize_stage.draw_id.to_le_bytes()],
+        bump
+    )]
+    pub prize_escrow: Option<Account<'info, PrizeEscrow>>,
+
+    /// Escrow USDC token account, required only when the stage holds
+    /// escrowed prizes
+    #[account(
+        init,
+        payer = manager,
+        seeds = [ESCROW_USDC_SEED, syndicate.key().as_ref(), &prize_stage.draw_id.to_le_bytes()],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = lottery_state
+    )]
+    pub escrow_usdc: Option<Account<'info, TokenAccount>>,
+
+    /// System program, required only when the stage holds escrowed prizes
+    pub system_program: Option<Program<'info, System>>,
+}
+
+/// Pay out a staged syndicate prize in a single transfer.
+///
+/// Deducts each tier's staged prize from the lottery's balances with
+/// `deduct_prize_from_balances`, moves the paid part of the stage from the
+/// prize pool to the syndicate, snapshots each member's share into
+/// `unclaimed_prize`, releases the draw lock and closes the stage. Members
+/// claim their shares separately.
+///
+/// The stage's `escrowed_amount` is moved to the syndicate's escrow for the
+/// draw instead, and is shared out by `release_syndicate_escrow_prize` after
+/// `escrow_release_delay_seconds`. The escrow accounts must be supplied
+/// exactly when the stage holds escrowed prizes.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_finalize_staged_prize(ctx: Context<FinalizeStagedPrize>) -> Result<()> {
+    let syndicate_key = ctx.accounts.syndicate.key();
+    let draw_id = ctx.accounts.prize_stage.draw_id;
+    let total_prize = ctx.accounts.prize_stage.staged_amount;
+    let escrowed_amount = ctx.accounts.prize_stage.escrowed_amount;
+    let paid_amount = ctx.accounts.prize_stage.paid_amount();
+    let tickets_staged = ctx.accounts.prize_stage.tickets_staged;
+
+    let has_escrow_accounts = ctx.accounts.prize_escrow.is_some()
+        && ctx.accounts.escrow_usdc.is_some()
+        && ctx.accounts.system_program.is_some();
+    require!(
+        has_escrow_accounts == (escrowed_amount > 0),
+        LottoError::InvalidEscrowAccounts
     );
 
-    // If no winnings in this batch, nothing to transfer — still a valid call
-    // (allows processing non-winning tickets to mark them claimed).
+    // =========================================================================
+    // STEP 1: Release the draw lock
+    // =========================================================================
+    // Prizes for the locked draw are being distributed, so membership can change again
+    let manager_key = ctx.accounts.manager.key();
+    let syndicate = &mut ctx.accounts.syndicate;
+    if syndicate.is_locked && syndicate.locked_draw_id == draw_id {
+        release_syndicate_lock(
+            syndicate,
+            syndicate_key,
+            manager_key,
+            SyndicateUnlockMethod::PrizeDistribution,
+        );
+    }
+
+    // If none of the staged tickets won, there is nothing to transfer — the
+    // stage is still closed.
     if total_prize == 0 {
-        msg!("  No prize to distribute in this batch (all tickets non-winning).");
+        msg!("  No prize to distribute (all staged tickets non-winning).");
+        return Ok(());
+    }
+
+    // =========================================================================
+    // STEP 2: Verify sufficient prize pool balance and deduct each tier
+    // =========================================================================
+    require!(
+        ctx.accounts.prize_pool_usdc.amount >= total_prize,
+        LottoError::InsufficientFunds
+    );
+
+    // Deducting before the transfer means an insolvent stage fails without
+    // moving any USDC
+    let tier_prizes: Vec<(u8, u64)> = ctx.accounts.prize_stage.tier_prizes().collect();
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    for (match_count, amount) in tier_prizes {
+        if amount > 0 {
+            deduct_prize_from_balances(lottery_state, draw_id, match_count, amount)?;
+        }
+    }
+    msg!(
+        "  Lottery state updated: jackpot={}, reserve={}, fixed_prize={}",
+        lottery_state.jackpot_balance,
+        lottery_state.reserve_balance,
+        lottery_state.fixed_prize_balance
+    );
+
+    // =========================================================================
+    // STEP 3: Transfer the paid part to syndicate USDC and the rest to escrow
+    // =========================================================================
+    let lottery_bump = ctx.accounts.lottery_state.bump;
+    let seeds = &[LOTTERY_SEED, &[lottery_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    if paid_amount > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.prize_pool_usdc.to_account_info(),
+            to: ctx.accounts.syndicate_usdc.to_account_info(),
+            authority: ctx.accounts.lottery_state.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, paid_amount)?;
+    }
+
+    if let Some(escrow_usdc) = &ctx.accounts.escrow_usdc {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.prize_pool_usdc.to_account_info(),
+            to: escrow_usdc.to_account_info(),
+            authority: ctx.accounts.lottery_state.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, escrowed_amount)?;
+    }
+
+    if let Some(prize_escrow) = ctx.accounts.prize_escrow.as_mut() {
+        let release_timestamp = Clock::get()?
+            .unix_timestamp
+            .checked_add(ctx.accounts.lottery_state.escrow_release_delay_seconds)
+            .ok_or(LottoError::Overflow)?;
+
+        prize_escrow.ticket = syndicate_key;
+        prize_escrow.beneficiary = syndicate_key;
+        prize_escrow.amount = escrowed_amount;
+        prize_escrow.release_timestamp = release_timestamp;
+        prize_escrow.draw_id = draw_id;
+        prize_escrow.bump = ctx
+            .bumps
+            .prize_escrow
+            .ok_or(LottoError::InvalidEscrowAccounts)?;
+
+        emit!(PrizeEscrowed {
+            ticket_id: syndicate_key,
+            amount: escrowed_amount,
+            release_at: release_timestamp,
+        });
+
+        msg!("  Escrowed: {} USDC lamports", escrowed_amount);
+        msg!("  Releasable at: {} (unix timestamp)", release_timestamp);
+    }
+
+    if paid_amount == 0 {
         return Ok(());
     }
 
-    // =========================================================================
-    // STEP 2: Verify sufficient prize pool balance
-    // =========================================================================
+    // =========================================================================
+    // STEP 4: Snapshot per-member unclaimed_prize
+    // =========================================================================
+    let syndicate = &mut ctx.accounts.syndicate;
+    let (manager_fee, member_pool) = allocate_syndicate_prize(syndicate, paid_amount);
+
+    // =========================================================================
+    // STEP 5: Emit event
+    // =========================================================================
+    emit!(SyndicatePrizeDistributed {
+        syndicate: syndicate_key,
+        draw_id,
+        total_prize: paid_amount,
+        manager_fee,
+        members_paid: syndicate.member_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Syndicate prize distributed!");
+    msg!("  Syndicate: {}", syndicate_key);
+    msg!("  Draw ID: {}", draw_id);
+    msg!("  Tickets staged: {}", tickets_staged);
+    msg!("  Total prize (verified): {} USDC lamports", total_prize);
+    msg!("  Paid now: {} USDC lamports", paid_amount);
+    msg!("  Manager fee: {} USDC lamports", manager_fee);
+    msg!("  Member pool: {} USDC lamports", member_pool);
+    msg!("  Members to receive: {}", syndicate.member_count);
+
+    Ok(())
+}
+
+/// Split `amount` paid into the syndicate's USDC account into the manager
+/// fee and the members' shares, adding each share to `unclaimed_prize`.
+///
+/// Integer division dust goes to the first member. Returns the manager fee
+/// and the member pool.
+fn allocate_syndicate_prize(syndicate: &mut Syndicate, amount: u64) -> (u64, u64) {
+    let manager_fee_bps = syndicate.manager_fee_bps;
+    let manager_fee = if manager_fee_bps > 0 {
+        (amount as u128 * manager_fee_bps as u128 / BPS_DENOMINATOR as u128) as u64
+    } else {
+        0
+    };
+    let member_pool = amount.saturating_sub(manager_fee);
+
+    let mut total_allocated = 0u64;
+    for i in 0..syndicate.members.len() {
+        let share_bps = syndicate.members[i].share_percentage_bps;
+        let member_share =
+            (member_pool as u128 * share_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+        syndicate.members[i].unclaimed_prize = syndicate.members[i]
+            .unclaimed_prize
+            .checked_add(member_share)
+            .unwrap_or(u64::MAX);
+        total_allocated = total_allocated.saturating_add(member_share);
+    }
+
+    // Handle dust from integer division — give remainder to first member
+    let dust = member_pool.saturating_sub(total_allocated);
+    if dust > 0 && !syndicate.members.is_empty() {
+        syndicate.members[0].unclaimed_prize =
+            syndicate.members[0].unclaimed_prize.saturating_add(dust);
+    }
+
+    msg!(
+        "  Per-member unclaimed_prize snapshot set ({} members, {} allocated, {} dust)",
+        syndicate.members.len(),
+        total_allocated,
+        dust
+    );
+
+    (manager_fee, member_pool)
+}
+
+// ============================================================================
+// RELEASE SYNDICATE ESCROW PRIZE INSTRUCTION
+// ============================================================================
+
+/// Accounts required for releasing a syndicate's escrowed prize
+#[derive(Accounts)]
+pub struct ReleaseSyndicateEscrowPrize<'info> {
+    /// The lottery authority (receives the escrow rent)
+    #[account(mut)]
+    pub manager: Signer<'info>,
+
+    /// Lottery state (escrow token account authority)
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == manager.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The syndicate account
+    #[account(
+        mut,
+        seeds = [
+            SYNDICATE_SEED,
+            syndicate.original_creator.as_ref(),
+            &syndicate.syndicate_id.to_le_bytes()
+        ],
+        bump = syndicate.bump,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub syndicate: Account<'info, Syndicate>,
+
+    /// Syndicate's USDC token account (receives the prize)
+    #[account(
+        mut,
+        seeds = [
+            SYNDICATE_SEED,
+            b"usdc",
+            syndicate.key().as_ref()
+        ],
+        bump,
+        constraint = syndicate_usdc.key() == syndicate.usdc_account @ LottoError::InvalidTokenAccount
+    )]
+    pub syndicate_usdc: Account<'info, TokenAccount>,
+
+    /// The escrow record (closed on release)
+    #[account(
+        mut,
+        close = manager,
+        seeds = [ESCROW_SEED, syndicate.key().as_ref(), &prize_escrow.draw_id.to_le_bytes()],
+        bump = prize_escrow.bump,
+        constraint = prize_escrow.beneficiary == syndicate.key() @ LottoError::Unauthorized
+    )]
+    pub prize_escrow: Account<'info, PrizeEscrow>,
+
+    /// The escrow USDC token account holding the prize
+    #[account(
+        mut,
+        seeds = [ESCROW_USDC_SEED, syndicate.key().as_ref(), &prize_escrow.draw_id.to_le_bytes()],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = lottery_state
+    )]
+    pub escrow_usdc: Account<'info, TokenAccount>,
+
+    /// USDC mint
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Release a syndicate's escrowed prize once the escrow delay has elapsed.
+///
+/// Moves the escrowed USDC to the syndicate, snapshots each member's share
+/// into `unclaimed_prize` as `finalize_staged_prize` does, and closes both
+/// escrow accounts.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_release_syndicate_escrow_prize(
+    ctx: Context<ReleaseSyndicateEscrowPrize>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.prize_escrow.is_releasable(now),
+        LottoError::EscrowLocked
+    );
+
+    let syndicate_key = ctx.accounts.syndicate.key();
+    let draw_id = ctx.accounts.prize_escrow.draw_id;
+    let amount = ctx.accounts.escrow_usdc.amount;
+
+    let lottery_bump = ctx.accounts.lottery_state.bump;
+    let seeds = &[LOTTERY_SEED, &[lottery_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    if amount > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.escrow_usdc.to_account_info(),
+            to: ctx.accounts.syndicate_usdc.to_account_info(),
+            authority: ctx.accounts.lottery_state.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, amount)?;
+    }
+
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.escrow_usdc.to_account_info(),
+        destination: ctx.accounts.manager.to_account_info(),
+        authority: ctx.accounts.lottery_state.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::close_account(cpi_ctx)?;
+
+    let syndicate = &mut ctx.accounts.syndicate;
+    let (manager_fee, member_pool) = allocate_syndicate_prize(syndicate, amount);
+
+    emit!(PrizeEscrowReleased {
+        ticket_id: syndicate_key,
+        beneficiary: syndicate_key,
+        amount,
+    });
+    emit!(SyndicatePrizeDistributed {
+        syndicate: syndicate_key,
+        draw_id,
+        total_prize: amount,
+        manager_fee,
+        members_paid: syndicate.member_count,
+        timestamp: now,
+    });
+
+    msg!("Syndicate escrowed prize released!");
+    msg!("  Syndicate: {}", syndicate_key);
+    msg!("  Draw ID: {}", draw_id);
+    msg!("  Amount: {} USDC lamports", amount);
+    msg!("  Manager fee: {} USDC lamports", manager_fee);
+    msg!("  Member pool: {} USDC lamports", member_pool);
+
+    Ok(())
+}
+
+// ============================================================================
+// UNLOCK SYNDICATE INSTRUCTIONS
+// ============================================================================
+
+/// Clear the syndicate's draw lock and emit `SyndicateUnlocked`
+fn release_syndicate_lock(
+    syndicate: &mut Syndicate,
+    syndicate_key: Pubkey,
+    unlocked_by: Pubkey,
+    method: SyndicateUnlockMethod,
+) {
+    let draw_id = syndicate.locked_draw_id;
+    syndicate.is_locked = false;
+
+    emit!(SyndicateUnlocked {
+        syndicate: syndicate_key,
+        draw_id,
+        unlocked_by,
+        method,
+    });
+
+    msg!("  Syndicate unlocked for draw {}", draw_id);
+}
+
+/// Accounts required for unlocking a syndicate after its draw is finalized
+#[derive(Accounts)]
+pub struct UnlockSyndicate<'info> {
+    /// The syndicate manager or any member
+    pub caller: Signer<'info>,
+
+    /// The locked syndicate
+    #[account(
+        mut,
+        seeds = [
+            SYNDICATE_SEED,
+            syndicate.original_creator.as_ref(),
+            &syndicate.syndicate_id.to_le_bytes()
+        ],
+        bump = syndicate.bump,
+        constraint = syndicate.is_locked @ LottoError::SyndicateNotLocked,
+        constraint = syndicate.is_manager_or_member(&caller.key()) @ LottoError::NotSyndicateMember,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub syndicate: Account<'info, Syndicate>,
+
+    /// Draw result for the draw the syndicate is locked for
+    #[account(
+        seeds = [DRAW_SEED, &syndicate.locked_draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = draw_result.draw_id == syndicate.locked_draw_id @ LottoError::DrawIdMismatch,
+        constraint = draw_result.is_finalized() @ LottoError::DrawNotFinalized,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub draw_result: Account<'info, DrawResult>,
+}
+
+/// Unlock a syndicate once the draw it is locked for has been finalized
+///
+/// Lets members leave or change the syndicate without waiting for
+/// `finalize_staged_prize`. Force-finalized draws must go through
+/// `unlock_syndicate_after_force_finalize` instead.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_unlock_syndicate(ctx: Context<UnlockSyndicate>) -> Result<()> {
     require!(
-        ctx.accounts.prize_pool_usdc.amount >= total_prize,
-        LottoError::InsufficientFunds
+        ctx.accounts.draw_result.force_finalized_at == 0,
+        LottoError::DrawWasForceFinalized
     );
 
-    // =========================================================================
-    // STEP 3: Calculate manager fee and member pool
-    // =========================================================================
-    let manager_fee_bps = ctx.accounts.syndicate.manager_fee_bps;
-    let manager_fee = if manager_fee_bps > 0 {
-        (total_prize as u128 * manager_fee_bps as u128 / BPS_DENOMINATOR as u128) as u64
-    } else {
-        0
-    };
-    let member_pool = total_prize.saturating_sub(manager_fee);
+    let syndicate_key = ctx.accounts.syndicate.key();
+    let caller = ctx.accounts.caller.key();
 
-    // =========================================================================
-    // STEP 4: Transfer verified total from prize pool to syndicate USDC
-    // =========================================================================
-    let lottery_bump = ctx.accounts.lottery_state.bump;
-    let seeds = &[LOTTERY_SEED, &[lottery_bump]];
-    let signer_seeds = &[&seeds[..]];
+    release_syndicate_lock(
+        &mut ctx.accounts.syndicate,
+        syndicate_key,
+        caller,
+        SyndicateUnlockMethod::DrawFinalized,
+    );
 
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.prize_pool_usdc.to_account_info(),
-        to: ctx.accounts.syndicate_usdc.to_account_info(),
-        authority: ctx.accounts.lottery_state.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-    token::transfer(cpi_ctx, total_prize)?;
+    msg!("Syndicate unlocked!");
+    msg!("  Syndicate: {}", syndicate_key);
+    msg!("  Unlocked by: {}", caller);
 
-    // =========================================================================
-    // STEP 5: Update lottery_state internal accounting
-    // =========================================================================
-    // Deduction priority: fixed_prize_balance → reserve → jackpot (last resort)
-    {
-        let lottery_state = &mut ctx.accounts.lottery_state;
-        let mut remaining_deduct = total_prize;
-
-        // 1. Deduct from fixed_prize_balance
-        let from_fixed = remaining_deduct.min(lottery_state.fixed_prize_balance);
-        lottery_state.fixed_prize_balance =
-            lottery_state.fixed_prize_balance.saturating_sub(from_fixed);
-        remaining_deduct = remaining_deduct.saturating_sub(from_fixed);
-
-        // 2. Deduct from reserve_balance
-        if remaining_deduct > 0 {
-            let from_reserve = remaining_deduct.min(lottery_state.reserve_balance);
-            lottery_state.reserve_balance =
-                lottery_state.reserve_balance.saturating_sub(from_reserve);
-            remaining_deduct = remaining_deduct.saturating_sub(from_reserve);
-        }
+    Ok(())
+}
 
-        // 3. Last resort: deduct from jackpot_balance
-        if remaining_deduct > 0 {
-            lottery_state.jackpot_balance = lottery_state
-                .jackpot_balance
-                .saturating_sub(remaining_deduct);
-            msg!(
-                "WARNING: Syndicate prize distribution required {} from jackpot",
-                remaining_deduct
-            );
-        }
+/// Accounts required for unlocking a syndicate after its draw was force finalized
+#[derive(Accounts)]
+pub struct UnlockSyndicateAfterForceFinalize<'info> {
+    /// The syndicate manager or any member
+    pub caller: Signer<'info>,
 
-        msg!(
-            "  Lottery state updated: jackpot={}, reserve={}, fixed_prize={}",
-            lottery_state.jackpot_balance,
-            lottery_state.reserve_balance,
-            lottery_state.fixed_prize_balance
-        );
-    }
+    /// The locked syndicate
+    #[account(
+        mut,
+        seeds = [
+            SYNDICATE_SEED,
+            syndicate.original_creator.as_ref(),
+            &syndicate.syndicate_id.to_le_bytes()
+        ],
+        bump = syndicate.bump,
+        constraint = syndicate.is_locked @ LottoError::SyndicateNotLocked,
+        constraint = syndicate.is_manager_or_member(&caller.key()) @ LottoError::NotSyndicateMember,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub syndicate: Account<'info, Syndicate>,
 
-    // =========================================================================
-    // STEP 6: Snapshot per-member unclaimed_prize
-    // =========================================================================
-    let syndicate = &mut ctx.accounts.syndicate;
+    /// Draw result for the draw the syndicate is locked for
+    #[account(
+        seeds = [DRAW_SEED, &syndicate.locked_draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = draw_result.draw_id == syndicate.locked_draw_id @ LottoError::DrawIdMismatch,
+        constraint = draw_result.is_explicitly_finalized @ LottoError::DrawNotFinalized,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub draw_result: Account<'info, DrawResult>,
+}
 
-    let mut total_allocated = 0u64;
-    for i in 0..syndicate.members.len() {
-        let share_bps = syndicate.members[i].share_percentage_bps;
-        let member_share =
-            (member_pool as u128 * share_bps as u128 / BPS_DENOMINATOR as u128) as u64;
-        syndicate.members[i].unclaimed_prize = syndicate.members[i]
-            .unclaimed_prize
-            .checked_add(member_share)
-            .unwrap_or(u64::MAX);
-        total_allocated = total_allocated.saturating_add(member_share);
-    }
+/// Unlock a syndicate whose locked draw was force finalized
+///
+/// Requires SYNDICATE_FORCE_FINALIZE_UNLOCK_DELAY_SECONDS (48 hours) to
+/// have passed since `force_finalize_draw`, giving members time to assess
+/// the outcome before membership can change.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_unlock_syndicate_after_force_finalize(
+    ctx: Context<UnlockSyndicateAfterForceFinalize>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let force_finalized_at = ctx.accounts.draw_result.force_finalized_at;
 
-    // Handle dust from integer division — give remainder to first member
-    let dust = member_pool.saturating_sub(total_allocated);
-    if dust > 0 && !syndicate.members.is_empty() {
-        syndicate.members[0].unclaimed_prize =
-            syndicate.members[0].unclaimed_prize.saturating_add(dust);
-    }
+    require!(force_finalized_at > 0, LottoError::DrawNotForceFinalized);
 
-    msg!(
-        "  Per-member unclaimed_prize snapshot set ({} members, {} allocated, {} dust)",
-        syndicate.members.len(),
-        total_allocated,
-        dust
+    let unlock_at =
+        force_finalized_at.saturating_add(SYNDICATE_FORCE_FINALIZE_UNLOCK_DELAY_SECONDS);
+    require!(
+        clock.unix_timestamp >= unlock_at,
+        LottoError::SyndicateUnlockTooEarly
     );
 
-    // =========================================================================
-    // STEP 7: Emit event
-    // =========================================================================
-    emit!(SyndicatePrizeDistributed {
-        syndicate: syndicate_key,
-        draw_id: params.draw_id,
-        total_prize,
-        manager_fee,
-        members_paid: syndicate.member_count,
-        timestamp: Clock::get()?.unix_timestamp,
-    });
+    let syndicate_key = ctx.accounts.syndicate.key();
+    let caller = ctx.accounts.caller.key();
 
-    msg!("Syndicate prize distributed (on-chain verified)!");
+    release_syndicate_lock(
+        &mut ctx.accounts.syndicate,
+        syndicate_key,
+        caller,
+        SyndicateUnlockMethod::ForceFinalized,
+    );
+
+    msg!("Syndicate unlocked after force finalization!");
     msg!("  Syndicate: {}", syndicate_key);
-    msg!("  Draw ID: {}", params.draw_id);
-    msg!("  Tickets processed: {}", tickets_processed);
-    msg!("  Tickets won: {}", tickets_won);
-    msg!("  Total prize (verified): {} USDC lamports", total_prize);
-    msg!("  Manager fee: {} USDC lamports", manager_fee);
-    msg!("  Member pool: {} USDC lamports", member_pool);
-    msg!("  Members to receive: {}", syndicate.member_count);
+    msg!("  Unlocked by: {}", caller);
+    msg!("  Force finalized at: {}", force_finalized_at);
 
     Ok(())
 }
@@ -1675,7 +3295,8 @@ pub struct ClaimSyndicateMemberPrize<'info> {
             syndicate.original_creator.as_ref(),
             &syndicate.syndicate_id.to_le_bytes()
         ],
-        bump = syndicate.bump
+        bump = syndicate.bump,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub syndicate: Account<'info, Syndicate>,
 
@@ -1706,6 +3327,15 @@ pub struct ClaimSyndicateMemberPrize<'info> {
     /// USDC mint
     pub usdc_mint: Account<'info, Mint>,
 
+    /// Member's user statistics account (win history)
+    #[account(
+        mut,
+        seeds = [USER_SEED, member.key().as_ref()],
+        bump = user_stats.bump,
+        constraint = user_stats.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub user_stats: Account<'info, UserStats>,
+
     /// Token program
     pub token_program: Program<'info, Token>,
 }
@@ -1751,7 +3381,7 @@ pub fn handler_claim_syndicate_member_prize(
     // of computing the claim from the live token account balance. This prevents
     // the race condition where early claimers reduce the balance for later members.
     //
-    // The unclaimed_prize was set during distribute_syndicate_prize based on
+    // The unclaimed_prize was set during finalize_staged_prize based on
     // the member's share_percentage_bps at distribution time. Claims simply
     // deduct from this per-member snapshot.
     require!(
@@ -1797,6 +3427,15 @@ pub fn handler_claim_syndicate_member_prize(
         .unclaimed_prize
         .saturating_sub(claim_amount);
 
+    // Track win history (syndicate shares have no single ticket/draw to attribute)
+    let user_stats = &mut ctx.accounts.user_stats;
+    user_stats.total_won = user_stats
+        .total_won
+        .checked_add(claim_amount)
+        .ok_or(LottoError::Overflow)?;
+    user_stats.record_winnings(claim_amount, claim_amount, 0, None)?;
+    emit_player_stats_updated(user_stats, Clock::get()?.unix_timestamp);
+
     msg!("Syndicate member prize claimed!");
     msg!("  Member: {}", member_key);
     msg!("  Syndicate: {}", syndicate_key);
@@ -1830,6 +3469,8 @@ pub struct UpdateSyndicateConfigParams {
     pub is_public: Option<bool>,
     /// New manager fee in basis points (max 500 = 5%)
     pub manager_fee_bps: Option<u16>,
+    /// New per-member contribution cap in USDC lamports (0 = no limit)
+    pub max_contribution_per_member: Option<u64>,
 }
 
 /// Accounts required for updating syndicate configuration
@@ -1851,9 +3492,31 @@ pub struct UpdateSyndicateConfig<'info> {
             &syndicate.syndicate_id.to_le_bytes()
         ],
         bump = syndicate.bump,
-        constraint = syndicate.creator == manager.key() @ LottoError::Unauthorized
+        constraint = syndicate.creator == manager.key() @ LottoError::Unauthorized,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub syndicate: Account<'info, Syndicate>,
+
+    /// Public syndicate registry (listing follows name and visibility changes)
+    #[account(
+        mut,
+        seeds = [SYNDICATE_REGISTRY_SEED],
+        bump = syndicate_registry.bump
+    )]
+    pub syndicate_registry: Account<'info, SyndicateRegistry>,
+
+    /// Manager fee increase proposal (created on first use)
+    #[account(
+        init_if_needed,
+        payer = manager,
+        space = SYNDICATE_FEE_PROPOSAL_SIZE,
+        seeds = [SYNDICATE_FEE_PROPOSAL_SEED, syndicate.key().as_ref()],
+        bump
+    )]
+    pub fee_proposal: Account<'info, SyndicateFeeProposal>,
+
+    /// System program (registry growth and fee proposal creation)
+    pub system_program: Program<'info, System>,
 }
 
 /// Update syndicate configuration
@@ -1861,7 +3524,12 @@ pub struct UpdateSyndicateConfig<'info> {
 /// This instruction allows the syndicate creator to update:
 /// - Syndicate name
 /// - Public/private status
-/// - Manager fee (within limits)
+/// - Manager fee (within limits; increases are proposed and only apply
+///   after a SYNDICATE_FEE_TIMELOCK_SECONDS member veto window)
+/// - Per-member contribution cap (cannot drop below an existing contribution)
+///
+/// The registry listing is refreshed afterwards: a syndicate made public is
+/// listed and one made private is delisted.
 ///
 /// # Arguments
 /// * `ctx` - The context containing required accounts
@@ -1903,19 +3571,79 @@ pub fn handler_update_syndicate_config(
         );
     }
 
-    // Update manager fee if provided
+    // Update manager fee if provided (increases go through the veto window)
     if let Some(manager_fee_bps) = params.manager_fee_bps {
         require!(
             manager_fee_bps <= MAX_MANAGER_FEE_BPS,
             LottoError::ManagerFeeTooHigh
         );
-        ctx.accounts.syndicate.manager_fee_bps = manager_fee_bps;
+
+        if manager_fee_bps > ctx.accounts.syndicate.manager_fee_bps {
+            let clock = Clock::get()?;
+            let fee_proposal = &mut ctx.accounts.fee_proposal;
+            require!(!fee_proposal.is_pending(), LottoError::FeeProposalPending);
+
+            fee_proposal.syndicate = syndicate_key;
+            fee_proposal.bump = ctx.bumps.fee_proposal;
+            fee_proposal.proposed_fee_bps = manager_fee_bps;
+            fee_proposal.proposed_at = clock.unix_timestamp;
+            fee_proposal.executable_after = clock
+                .unix_timestamp
+                .checked_add(SYNDICATE_FEE_TIMELOCK_SECONDS)
+                .ok_or(LottoError::Overflow)?;
+
+            emit!(FeeProposeStarted {
+                syndicate: syndicate_key,
+                proposed_fee: manager_fee_bps,
+                executable_after: fee_proposal.executable_after,
+            });
+
+            msg!(
+                "Proposed manager fee increase: {} -> {} BPS (executable after {})",
+                ctx.accounts.syndicate.manager_fee_bps,
+                manager_fee_bps,
+                fee_proposal.executable_after
+            );
+        } else {
+            ctx.accounts.syndicate.manager_fee_bps = manager_fee_bps;
+            msg!("Updated manager fee: {} BPS", manager_fee_bps);
+        }
+        updated = true;
+    }
+
+    // Update per-member contribution cap if provided
+    if let Some(max_contribution_per_member) = params.max_contribution_per_member {
+        // A non-zero cap must still admit every existing member's contribution
+        if max_contribution_per_member != 0 {
+            require!(
+                max_contribution_per_member >= ctx.accounts.syndicate.largest_member_contribution(),
+                LottoError::ContributionExceedsLimit
+            );
+        }
+        ctx.accounts.syndicate.max_contribution_per_member = max_contribution_per_member;
         updated = true;
-        msg!("Updated manager fee: {} BPS", manager_fee_bps);
+
+        emit!(SyndicateContributionCapSet {
+            syndicate: syndicate_key,
+            new_cap: max_contribution_per_member,
+        });
+
+        msg!(
+            "Updated max contribution per member: {} USDC lamports",
+            max_contribution_per_member
+        );
     }
 
     require!(updated, LottoError::InvalidSyndicateConfig);
 
+    sync_syndicate_registry(
+        &mut ctx.accounts.syndicate_registry,
+        syndicate_key,
+        &ctx.accounts.syndicate,
+        &ctx.accounts.manager.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
     msg!("Syndicate configuration updated!");
     msg!("  Syndicate: {}", syndicate_key);
     msg!("  Creator: {}", ctx.accounts.manager.key());
@@ -1923,6 +3651,150 @@ pub fn handler_update_syndicate_config(
     Ok(())
 }
 
+// ============================================================================
+// MANAGER FEE PROPOSAL INSTRUCTIONS
+// ============================================================================
+
+/// Accounts required for a member to veto a manager fee increase
+#[derive(Accounts)]
+pub struct RejectFeeProposal<'info> {
+    /// The vetoing member
+    pub member: Signer<'info>,
+
+    /// The syndicate account
+    #[account(
+        seeds = [
+            SYNDICATE_SEED,
+            syndicate.original_creator.as_ref(),
+            &syndicate.syndicate_id.to_le_bytes()
+        ],
+        bump = syndicate.bump,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub syndicate: Account<'info, Syndicate>,
+
+    /// The pending fee proposal
+    #[account(
+        mut,
+        seeds = [SYNDICATE_FEE_PROPOSAL_SEED, syndicate.key().as_ref()],
+        bump = fee_proposal.bump,
+        constraint = fee_proposal.is_pending() @ LottoError::NoFeeProposal
+    )]
+    pub fee_proposal: Account<'info, SyndicateFeeProposal>,
+}
+
+/// Veto a pending manager fee increase
+///
+/// Any member holding at least SYNDICATE_FEE_VETO_MIN_SHARE_BPS of the
+/// syndicate can cancel the proposal before its veto window closes.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_reject_fee_proposal(ctx: Context<RejectFeeProposal>) -> Result<()> {
+    let clock = Clock::get()?;
+    let member = ctx.accounts.member.key();
+    let fee_proposal = &mut ctx.accounts.fee_proposal;
+
+    require!(
+        clock.unix_timestamp < fee_proposal.executable_after,
+        LottoError::FeeVetoWindowClosed
+    );
+
+    let share_bps = ctx
+        .accounts
+        .syndicate
+        .find_member(&member)
+        .ok_or(LottoError::NotSyndicateMember)?
+        .share_percentage_bps;
+    require!(
+        share_bps >= SYNDICATE_FEE_VETO_MIN_SHARE_BPS,
+        LottoError::FeeVetoShareTooLow
+    );
+
+    let proposed_fee_bps = fee_proposal.proposed_fee_bps;
+    fee_proposal.clear();
+
+    emit!(FeeProposalRejected {
+        syndicate: ctx.accounts.syndicate.key(),
+        member,
+        share_bps,
+    });
+
+    msg!("Manager fee proposal rejected!");
+    msg!("  Syndicate: {}", ctx.accounts.syndicate.key());
+    msg!("  Member: {} ({} BPS)", member, share_bps);
+    msg!("  Rejected fee: {} BPS", proposed_fee_bps);
+
+    Ok(())
+}
+
+/// Accounts required for applying a manager fee increase
+#[derive(Accounts)]
+pub struct ExecuteFeeProposal<'info> {
+    /// The syndicate creator/manager
+    pub manager: Signer<'info>,
+
+    /// The syndicate account
+    #[account(
+        mut,
+        seeds = [
+            SYNDICATE_SEED,
+            syndicate.original_creator.as_ref(),
+            &syndicate.syndicate_id.to_le_bytes()
+        ],
+        bump = syndicate.bump,
+        constraint = syndicate.creator == manager.key() @ LottoError::Unauthorized,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub syndicate: Account<'info, Syndicate>,
+
+    /// The pending fee proposal
+    #[account(
+        mut,
+        seeds = [SYNDICATE_FEE_PROPOSAL_SEED, syndicate.key().as_ref()],
+        bump = fee_proposal.bump,
+        constraint = fee_proposal.is_pending() @ LottoError::NoFeeProposal
+    )]
+    pub fee_proposal: Account<'info, SyndicateFeeProposal>,
+}
+
+/// Apply a manager fee increase that survived its veto window
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_execute_fee_proposal(ctx: Context<ExecuteFeeProposal>) -> Result<()> {
+    let clock = Clock::get()?;
+    let fee_proposal = &mut ctx.accounts.fee_proposal;
+
+    require!(
+        clock.unix_timestamp >= fee_proposal.executable_after,
+        LottoError::FeeProposalTimelockActive
+    );
+
+    let new_fee_bps = fee_proposal.proposed_fee_bps;
+    require!(
+        new_fee_bps <= MAX_MANAGER_FEE_BPS,
+        LottoError::ManagerFeeTooHigh
+    );
+    fee_proposal.clear();
+
+    let syndicate = &mut ctx.accounts.syndicate;
+    let old_fee_bps = syndicate.manager_fee_bps;
+    syndicate.manager_fee_bps = new_fee_bps;
+
+    msg!("Manager fee proposal executed!");
+    msg!("  Syndicate: {}", syndicate.key());
+    msg!("  Manager fee: {} -> {} BPS", old_fee_bps, new_fee_bps);
+
+    Ok(())
+}
+
 // ============================================================================
 // REMOVE SYNDICATE MEMBER INSTRUCTION
 // ============================================================================
@@ -1955,10 +3827,20 @@ pub struct RemoveSyndicateMember<'info> {
             &syndicate.syndicate_id.to_le_bytes()
         ],
         bump = syndicate.bump,
-        constraint = syndicate.creator == manager.key() @ LottoError::Unauthorized
+        constraint = syndicate.creator == manager.key() @ LottoError::Unauthorized,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub syndicate: Account<'info, Syndicate>,
 
+    /// The main lottery state account (for the syndicate lock check)
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = !syndicate.is_locked_for_draw(lottery_state.current_draw_id) @ LottoError::SyndicateLocked,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
     /// Member's USDC token account (receives refund)
     #[account(
         mut,
@@ -2106,7 +3988,8 @@ pub struct TransferSyndicateCreator<'info> {
             &syndicate.syndicate_id.to_le_bytes()
         ],
         bump = syndicate.bump,
-        constraint = syndicate.creator == creator.key() @ LottoError::Unauthorized
+        constraint = syndicate.creator == creator.key() @ LottoError::Unauthorized,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub syndicate: Account<'info, Syndicate>,
 }
@@ -2160,3 +4043,56 @@ pub fn handler_transfer_syndicate_creator(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ticket_accounts_capped_at_tickets_purchased() {
+        let mut syndicate = Syndicate::default();
+        assert!(syndicate.take_ticket_indexes(1, 1).is_err());
+
+        syndicate.record_tickets_purchased(1, 3).unwrap();
+        assert_eq!(syndicate.take_ticket_indexes(1, 2).unwrap(), 0);
+        assert!(syndicate.take_ticket_indexes(1, 2).is_err());
+        assert_eq!(syndicate.take_ticket_indexes(1, 1).unwrap(), 2);
+        assert!(syndicate.take_ticket_indexes(1, 1).is_err());
+
+        // Tickets bought for a draw cannot be created in a later one
+        syndicate.record_tickets_purchased(1, 1).unwrap();
+        assert!(syndicate.take_ticket_indexes(2, 1).is_err());
+
+        syndicate.record_tickets_purchased(2, 1).unwrap();
+        assert_eq!(syndicate.tickets_purchased, 1);
+        assert_eq!(syndicate.take_ticket_indexes(2, 1).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_prize_stage_records_tier_prizes() {
+        let mut prize_stage = SyndicatePrizeStage::default();
+        prize_stage.record_ticket(1, 0, false).unwrap();
+        prize_stage.record_ticket(6, 5_000_000_000, true).unwrap();
+        prize_stage.record_ticket(3, 5_000_000, false).unwrap();
+        prize_stage.record_ticket(3, 5_000_000, false).unwrap();
+        assert!(prize_stage.record_ticket(7, 1, false).is_err());
+
+        assert_eq!(prize_stage.tickets_staged, 5);
+        assert_eq!(prize_stage.staged_amount, 5_010_000_000);
+        assert_eq!(prize_stage.escrowed_amount, 5_000_000_000);
+        assert_eq!(prize_stage.paid_amount(), 10_000_000);
+        let tiers: Vec<(u8, u64)> = prize_stage.tier_prizes().collect();
+        assert_eq!(
+            tiers,
+            vec![(2, 0), (3, 10_000_000), (4, 0), (5, 0), (6, 5_000_000_000)]
+        );
+    }
+
+    #[test]
+    fn test_syndicate_ticket_address_is_scoped_to_syndicate() {
+        let program_id = crate::ID;
+        let (a, _) = syndicate_ticket_address(&Pubkey::new_unique(), 1, 0, &program_id);
+        let (b, _) = syndicate_ticket_address(&Pubkey::new_unique(), 1, 0, &program_id);
+        assert_ne!(a, b);
+    }
+}
@@ -0,0 +1,177 @@
+//! Syndicate Registry Instructions
+//!
+//! A singleton `SyndicateRegistry` lists every public syndicate so wallets
+//! can discover joinable syndicates without scanning program accounts. The
+//! syndicate instructions keep the listing current:
+//! - create_syndicate / update_syndicate_config: list or delist on visibility
+//! - join_syndicate / leave_syndicate: refresh member count and contributions
+//! - close_syndicate: delist
+//!
+//! - initialize_syndicate_registry: Create the registry (authority only)
+//! - get_public_syndicates: Emit the current listing
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::PublicSyndicatesSnapshot;
+use crate::state::{
+    LotteryState, SchemaVersioned, Syndicate, SyndicateRegistry, SyndicateRegistryEntry,
+};
+
+/// Bring the registry listing for `syndicate` in line with its current state
+///
+/// Public syndicates are listed (or refreshed) and private ones delisted.
+/// Listing a new syndicate grows the registry by one entry, with `payer`
+/// covering the extra rent. Fails with `RegistryFull` at
+/// MAX_SYNDICATE_REGISTRY_ENTRIES.
+pub(crate) fn sync_syndicate_registry<'info>(
+    registry: &mut Account<'info, SyndicateRegistry>,
+    syndicate_key: Pubkey,
+    syndicate: &Syndicate,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let existing = registry.find_entry(&syndicate_key);
+
+    if !syndicate.is_public {
+        if existing.is_some() {
+            registry.remove_entry(&syndicate_key);
+            msg!("Syndicate removed from public registry");
+        }
+        return Ok(());
+    }
+
+    let entry = SyndicateRegistryEntry::from_syndicate(syndicate_key, syndicate);
+    if let Some(index) = existing {
+        registry.entries[index] = entry;
+        return Ok(());
+    }
+
+    require!(
+        registry.entries.len() < MAX_SYNDICATE_REGISTRY_ENTRIES,
+        LottoError::RegistryFull
+    );
+
+    let new_size = SyndicateRegistry::size_for_entries(registry.entries.len() + 1);
+    let registry_info = registry.to_account_info();
+    if new_size > registry_info.data_len() {
+        let rent = Rent::get()?;
+        let lamports_diff = rent
+            .minimum_balance(new_size)
+            .saturating_sub(registry_info.lamports());
+
+        if lamports_diff > 0 {
+            let cpi_context = CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: registry_info.clone(),
+                },
+            );
+            anchor_lang::system_program::transfer(cpi_context, lamports_diff)?;
+        }
+
+        registry_info.resize(new_size)?;
+    }
+
+    registry.entries.push(entry);
+    msg!("Syndicate listed in public registry");
+
+    Ok(())
+}
+
+// ============================================================================
+// INITIALIZE SYNDICATE REGISTRY
+// ============================================================================
+
+/// Accounts required for creating the syndicate registry
+#[derive(Accounts)]
+pub struct InitializeSyndicateRegistry<'info> {
+    /// The lottery authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The registry account to be created (starts with no entries)
+    #[account(
+        init,
+        payer = authority,
+        space = SyndicateRegistry::size_for_entries(0),
+        seeds = [SYNDICATE_REGISTRY_SEED],
+        bump
+    )]
+    pub syndicate_registry: Account<'info, SyndicateRegistry>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the public syndicate registry
+///
+/// Must run before syndicates can be created, joined, left, closed or
+/// reconfigured, since those instructions keep the registry up to date.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_initialize_syndicate_registry(
+    ctx: Context<InitializeSyndicateRegistry>,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.syndicate_registry;
+    registry.bump = ctx.bumps.syndicate_registry;
+    registry.entries = Vec::new();
+
+    msg!("Syndicate registry initialized!");
+    msg!("  Registry: {}", registry.key());
+
+    Ok(())
+}
+
+// ============================================================================
+// GET PUBLIC SYNDICATES
+// ============================================================================
+
+/// Accounts required for reading the syndicate registry
+#[derive(Accounts)]
+pub struct GetPublicSyndicates<'info> {
+    /// The public syndicate registry
+    #[account(
+        seeds = [SYNDICATE_REGISTRY_SEED],
+        bump = syndicate_registry.bump
+    )]
+    pub syndicate_registry: Account<'info, SyndicateRegistry>,
+}
+
+/// Emit the current list of public syndicates
+///
+/// Permissionless and read-only; emits `PublicSyndicatesSnapshot`.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_get_public_syndicates(ctx: Context<GetPublicSyndicates>) -> Result<()> {
+    let entries = ctx.accounts.syndicate_registry.entries.clone();
+    let count = entries.len() as u32;
+    let joinable = entries.iter().filter(|e| !e.is_full).count();
+
+    emit!(PublicSyndicatesSnapshot { count, entries });
+
+    msg!("Public syndicates snapshot");
+    msg!("  Listed: {}", count);
+    msg!("  Joinable: {}", joinable);
+
+    Ok(())
+}
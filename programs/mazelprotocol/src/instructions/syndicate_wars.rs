@@ -3,18 +3,29 @@
 //! This module contains instructions for the Syndicate Wars monthly competition:
 //! - initialize_syndicate_wars: Initialize competition for a month
 //! - register_for_syndicate_wars: Register syndicate for competition
+//!   (`create_syndicate` can also auto-register a new public syndicate)
 //! - update_syndicate_wars_stats: Update syndicate stats during competition
+//! - compute_wars_score_on_chain: Score a draw from the syndicate's ticket accounts
 //! - finalize_syndicate_wars: Finalize competition and calculate rankings
 //! - claim_syndicate_wars_prize: Claim competition prize for syndicate
 //! - distribute_syndicate_wars_prizes: Distribute prizes to top syndicates
+//! - start_wars_bracket: Seed a knockout bracket from registered syndicates
+//! - advance_wars_bracket: Record a bracket round's winners and pair the next round
+//! - claim_finals_bracket_prize: Claim a finalist's share of the finals prize
 
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::constants::*;
 use crate::errors::LottoError;
-use crate::events::{SyndicateWarsConcluded, SyndicateWarsFinalized};
-use crate::state::{LotteryState, Syndicate, SyndicateWarsEntry, SyndicateWarsState};
+use crate::events::{
+    SyndicateWarsBracketAdvanced, SyndicateWarsBracketStarted, SyndicateWarsConcluded,
+    SyndicateWarsFinalized, SyndicateWarsFinalsReached, WarsScoreComputedOnChain,
+};
+use crate::state::{
+    DrawResult, LotteryState, SchemaVersioned, Syndicate, SyndicateWarsEntry, SyndicateWarsState,
+    TicketData, WarsMatchup,
+};
 
 // ============================================================================
 // INITIALIZE SYNDICATE WARS INSTRUCTION
@@ -46,7 +57,8 @@ pub struct InitializeSyndicateWars<'info> {
         mut,
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
-        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
@@ -54,7 +66,7 @@ pub struct InitializeSyndicateWars<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + std::mem::size_of::<SyndicateWarsState>(),
+        space = SYNDICATE_WARS_STATE_SIZE,
         seeds = [
             SYNDICATE_WARS_SEED,
             &params.month.to_le_bytes()
@@ -208,6 +220,11 @@ pub fn handler_initialize_syndicate_wars(
     state.is_active = true;
     state.is_distributed = false;
     state.bump = ctx.bumps.syndicate_wars_state;
+    state.bracket_rounds = 0;
+    state.current_round = 0;
+    state.matchup_pairs = Vec::new();
+    state.finals_prize_bps = 0;
+    state.finalists = [Pubkey::default(); 2];
 
     msg!("Syndicate Wars competition initialized!");
     msg!("  Month: {}", params.month);
@@ -241,7 +258,8 @@ pub struct RegisterForSyndicateWars<'info> {
             &syndicate.syndicate_id.to_le_bytes()
         ],
         bump = syndicate.bump,
-        constraint = syndicate.creator == manager.key() @ LottoError::Unauthorized
+        constraint = syndicate.creator == manager.key() @ LottoError::Unauthorized,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub syndicate: Account<'info, Syndicate>,
 
@@ -305,16 +323,11 @@ pub fn handler_register_for_syndicate_wars(ctx: Context<RegisterForSyndicateWars
     );
 
     // Initialize entry
-    let entry = &mut ctx.accounts.wars_entry;
-    entry.syndicate = ctx.accounts.syndicate.key();
-    entry.month = state.month;
-    entry.tickets_purchased = 0;
-    entry.prizes_won = 0;
-    entry.win_count = 0;
-    entry.win_rate = 0;
-    entry.final_rank = None;
-    entry.prize_claimed = false;
-    entry.bump = ctx.bumps.wars_entry;
+    ctx.accounts.wars_entry.set_inner(new_syndicate_wars_entry(
+        ctx.accounts.syndicate.key(),
+        state.month,
+        ctx.bumps.wars_entry,
+    ));
 
     // Update state
     let state = &mut ctx.accounts.syndicate_wars_state;
@@ -328,6 +341,145 @@ pub fn handler_register_for_syndicate_wars(ctx: Context<RegisterForSyndicateWars
     Ok(())
 }
 
+/// Fresh competition entry for `syndicate` in `month`
+fn new_syndicate_wars_entry(syndicate: Pubkey, month: u64, bump: u8) -> SyndicateWarsEntry {
+    SyndicateWarsEntry {
+        syndicate,
+        month,
+        tickets_purchased: 0,
+        prizes_won: 0,
+        win_count: 0,
+        win_rate: 0,
+        final_rank: None,
+        prize_claimed: false,
+        bump,
+        finals_prize_claimed: false,
+        submitted_score: 0,
+        on_chain_score: 0,
+        last_scored_draw_id: 0,
+    }
+}
+
+// ============================================================================
+// AUTO-REGISTRATION ON SYNDICATE CREATION
+// ============================================================================
+
+/// Whether a syndicate being created may be auto-registered for Syndicate Wars
+///
+/// A new syndicate only has its creator, so it cannot meet the member
+/// minimum of `register_for_syndicate_wars`. Public syndicates can fill up
+/// during the competition and still have to reach `min_tickets` to place;
+/// private ones must register explicitly once they have grown.
+pub fn is_eligible_for_wars_auto_registration(syndicate: &Syndicate) -> bool {
+    syndicate.is_public
+}
+
+/// Register a syndicate being created for the open Syndicate Wars competition
+///
+/// `remaining_accounts` must be empty or hold `[syndicate_wars_state,
+/// wars_entry]`, with `wars_entry` the uncreated entry PDA. Returns the
+/// competition month when the syndicate was registered, or `None` when no
+/// competition was supplied or its registration is closed.
+pub(crate) fn auto_register_for_syndicate_wars<'info>(
+    program_id: &Pubkey,
+    syndicate_key: Pubkey,
+    syndicate: &Syndicate,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    current_time: i64,
+) -> Result<Option<u64>> {
+    let (wars_info, entry_info) = match remaining_accounts {
+        [] => return Ok(None),
+        [wars_info, entry_info] => (wars_info, entry_info),
+        _ => return Err(LottoError::InvalidSyndicateWarsAccounts.into()),
+    };
+
+    let mut wars_state: Account<'info, SyndicateWarsState> =
+        Account::try_from(wars_info).map_err(|_| LottoError::InvalidSyndicateWarsAccounts)?;
+    let expected_wars_key = Pubkey::create_program_address(
+        &[
+            SYNDICATE_WARS_SEED,
+            &wars_state.month.to_le_bytes(),
+            &[wars_state.bump],
+        ],
+        program_id,
+    )
+    .map_err(|_| LottoError::InvalidSyndicateWarsAccounts)?;
+    require!(
+        wars_info.key() == expected_wars_key && wars_info.is_writable,
+        LottoError::InvalidSyndicateWarsAccounts
+    );
+
+    if !wars_state.is_registration_open(current_time) {
+        msg!("Syndicate Wars registration closed; auto-registration skipped");
+        return Ok(None);
+    }
+
+    require!(
+        is_eligible_for_wars_auto_registration(syndicate),
+        LottoError::SyndicateNotEligibleForWars
+    );
+
+    let month = wars_state.month;
+    let month_bytes = month.to_le_bytes();
+    let (expected_entry_key, entry_bump) = Pubkey::find_program_address(
+        &[
+            SYNDICATE_WARS_SEED,
+            b"entry",
+            &month_bytes,
+            syndicate_key.as_ref(),
+        ],
+        program_id,
+    );
+    require!(
+        entry_info.key() == expected_entry_key
+            && entry_info.is_writable
+            && entry_info.data_is_empty(),
+        LottoError::InvalidSyndicateWarsAccounts
+    );
+
+    // Create the entry PDA (what `init` does in register_for_syndicate_wars)
+    let entry_seeds: &[&[u8]] = &[
+        SYNDICATE_WARS_SEED,
+        b"entry",
+        &month_bytes,
+        syndicate_key.as_ref(),
+        &[entry_bump],
+    ];
+    let space = SyndicateWarsEntry::LEN;
+    let rent = Rent::get()?;
+    anchor_lang::system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::CreateAccount {
+                from: payer.clone(),
+                to: entry_info.clone(),
+            },
+            &[entry_seeds],
+        ),
+        rent.minimum_balance(space),
+        space as u64,
+        program_id,
+    )?;
+
+    let entry = new_syndicate_wars_entry(syndicate_key, month, entry_bump);
+    {
+        let mut data = entry_info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        entry.try_serialize(&mut writer)?;
+    }
+
+    wars_state.registered_count = wars_state.registered_count.saturating_add(1);
+    wars_state.exit(program_id)?;
+
+    msg!("Syndicate auto-registered for Syndicate Wars!");
+    msg!("  Month: {}", month);
+    msg!("  Registered count: {}", wars_state.registered_count);
+
+    Ok(Some(month))
+}
+
 // ============================================================================
 // UPDATE SYNDICATE WARS STATS INSTRUCTION
 // ============================================================================
@@ -341,6 +493,8 @@ pub struct UpdateSyndicateWarsStatsParams {
     pub prizes_won: u64,
     /// Additional win count (Match 3+)
     pub win_count: u32,
+    /// Additional score (Match 6 = 10,000 down to Match 2 = 1 per ticket)
+    pub score: u64,
 }
 
 /// Accounts required for updating syndicate wars stats
@@ -354,7 +508,8 @@ pub struct UpdateSyndicateWarsStats<'info> {
     #[account(
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
-        constraint = lottery_state.authority == updater.key() @ LottoError::Unauthorized
+        constraint = lottery_state.authority == updater.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
@@ -391,7 +546,8 @@ pub struct UpdateSyndicateWarsStats<'info> {
             syndicate.original_creator.as_ref(),
             &syndicate.syndicate_id.to_le_bytes()
         ],
-        bump = syndicate.bump
+        bump = syndicate.bump,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub syndicate: Account<'info, Syndicate>,
 }
@@ -430,6 +586,7 @@ pub fn handler_update_syndicate_wars_stats(
         .saturating_add(params.tickets_purchased);
     entry.prizes_won = entry.prizes_won.saturating_add(params.prizes_won);
     entry.win_count = entry.win_count.saturating_add(params.win_count);
+    entry.submitted_score = entry.submitted_score.saturating_add(params.score);
 
     // Calculate win rate (fixed-point × 1,000,000)
     if entry.tickets_purchased > 0 {
@@ -445,6 +602,137 @@ pub fn handler_update_syndicate_wars_stats(
     msg!("  Prizes won: {} USDC lamports", entry.prizes_won);
     msg!("  Win count: {}", entry.win_count);
     msg!("  Win rate: {:.6}", entry.win_rate as f64 / 1_000_000.0);
+    msg!("  Submitted score: {}", entry.submitted_score);
+
+    Ok(())
+}
+
+// ============================================================================
+// COMPUTE WARS SCORE ON CHAIN INSTRUCTION
+// ============================================================================
+
+/// Accounts required for scoring a draw from a syndicate's ticket accounts
+#[derive(Accounts)]
+#[instruction(syndicate: Pubkey, draw_id: u64)]
+pub struct ComputeWarsScoreOnChain<'info> {
+    /// Anyone may submit the syndicate's tickets
+    pub caller: Signer<'info>,
+
+    /// Syndicate Wars state
+    #[account(
+        seeds = [
+            SYNDICATE_WARS_SEED,
+            &syndicate_wars_state.month.to_le_bytes()
+        ],
+        bump = syndicate_wars_state.bump,
+        constraint = syndicate_wars_state.is_active @ LottoError::SyndicateWarsNotActive
+    )]
+    pub syndicate_wars_state: Account<'info, SyndicateWarsState>,
+
+    /// Syndicate Wars entry of `syndicate`
+    #[account(
+        mut,
+        seeds = [
+            SYNDICATE_WARS_SEED,
+            b"entry",
+            &syndicate_wars_state.month.to_le_bytes(),
+            syndicate.as_ref()
+        ],
+        bump = wars_entry.bump
+    )]
+    pub wars_entry: Account<'info, SyndicateWarsEntry>,
+
+    /// The draw result being scored (must be finalized)
+    #[account(
+        seeds = [DRAW_SEED, &draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = draw_result.is_finalized() @ LottoError::DrawNotFinalized,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub draw_result: Account<'info, DrawResult>,
+}
+
+/// Score one draw for a syndicate from its ticket accounts
+///
+/// The syndicate's tickets for the draw are passed as `remaining_accounts`.
+/// Each must be a distinct program-owned `TicketData` of `syndicate` for
+/// `draw_id`. Every ticket scores `wars_score_for_match` of its matches
+/// against the winning numbers, and the total is added to the entry's
+/// `on_chain_score`. Draws must be scored in increasing order, once each,
+/// and must have been drawn during the competition.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `syndicate` - Syndicate whose tickets are scored
+/// * `draw_id` - Draw the tickets are for
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_compute_wars_score_on_chain(
+    ctx: Context<ComputeWarsScoreOnChain>,
+    syndicate: Pubkey,
+    draw_id: u64,
+) -> Result<()> {
+    let state = &ctx.accounts.syndicate_wars_state;
+    let draw_result = &ctx.accounts.draw_result;
+    require!(
+        (state.start_timestamp..=state.end_timestamp).contains(&draw_result.timestamp),
+        LottoError::WarsDrawOutsideCompetition
+    );
+    require!(
+        draw_id > ctx.accounts.wars_entry.last_scored_draw_id,
+        LottoError::WarsDrawAlreadyScored
+    );
+
+    let remaining = ctx.remaining_accounts;
+    require!(!remaining.is_empty(), LottoError::InvalidTicketAccount);
+
+    let mut score: u64 = 0;
+    for (i, ticket_info) in remaining.iter().enumerate() {
+        require!(
+            !remaining[..i].iter().any(|t| t.key() == ticket_info.key()),
+            LottoError::DuplicateWarsScoreTicket
+        );
+        require!(
+            ticket_info.owner == ctx.program_id,
+            LottoError::InvalidTicketAccount
+        );
+
+        let ticket = {
+            let data = ticket_info.try_borrow_data()?;
+            let mut slice: &[u8] = &data;
+            TicketData::try_deserialize(&mut slice).map_err(|_| LottoError::InvalidTicketAccount)?
+        };
+        require!(
+            ticket.syndicate == Some(syndicate),
+            LottoError::SyndicateTicketNotOwned
+        );
+        require!(
+            ticket.draw_id == draw_id,
+            LottoError::SyndicateTicketDrawMismatch
+        );
+
+        let match_count = calculate_match_count(&ticket.numbers, &draw_result.winning_numbers);
+        score = score.saturating_add(wars_score_for_match(match_count));
+    }
+
+    let entry = &mut ctx.accounts.wars_entry;
+    entry.on_chain_score = entry.on_chain_score.saturating_add(score);
+    entry.last_scored_draw_id = draw_id;
+
+    emit!(WarsScoreComputedOnChain {
+        syndicate,
+        draw_id,
+        score,
+    });
+
+    msg!("Syndicate Wars score computed on-chain!");
+    msg!("  Syndicate: {}", syndicate);
+    msg!("  Draw ID: {}", draw_id);
+    msg!("  Tickets scored: {}", remaining.len());
+    msg!("  Draw score: {}", score);
+    msg!("  On-chain score: {}", entry.on_chain_score);
+    msg!("  Ranking score: {}", entry.ranking_score());
 
     Ok(())
 }
@@ -465,7 +753,8 @@ pub struct FinalizeSyndicateWars<'info> {
         mut,
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
-        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
@@ -561,8 +850,8 @@ pub fn handler_claim_syndicate_wars_prize(
         LottoError::InvalidRank
     );
 
-    // Calculate prize amount based on rank
-    let prize_pool = ctx.accounts.syndicate_wars_state.prize_pool;
+    // Calculate prize amount based on rank (the finals share is paid separately)
+    let prize_pool = ctx.accounts.syndicate_wars_state.ranked_prize_pool();
     let prize_amount = match params.rank {
         1 => prize_pool * 50 / 100,     // 50% for 1st place
         2 => prize_pool * 25 / 100,     // 25% for 2nd place
@@ -631,7 +920,8 @@ pub struct DistributeSyndicateWarsPrizes<'info> {
         mut,
         seeds = [LOTTERY_SEED],
         bump = lottery_state.bump,
-        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
@@ -700,10 +990,10 @@ pub fn handler_distribute_syndicate_wars_prizes<'info>(
     // times, overwriting syndicate rankings and enabling prize manipulation.
     require!(!state.is_distributed, LottoError::AlreadyDistributed);
 
-    let prize_pool = state.prize_pool;
+    let prize_pool = state.ranked_prize_pool();
     let month = state.month;
 
-    // Calculate prize amounts
+    // Calculate prize amounts (the finals share is paid separately)
     let first_prize = prize_pool * 50 / 100; // 50%
     let second_prize = prize_pool * 25 / 100; // 25%
     let third_prize = prize_pool * 15 / 100; // 15%
@@ -714,6 +1004,7 @@ pub fn handler_distribute_syndicate_wars_prizes<'info>(
     // which is brittle (breaks if field order/types change) and unsafe (no
     // discriminator or owner validation on the remaining accounts).
     let remaining_accounts = ctx.remaining_accounts;
+    let mut previous_ranking_score = u64::MAX;
     for (i, account_info) in remaining_accounts.iter().enumerate() {
         if i >= 10 {
             break; // Only process up to 10 entries
@@ -751,6 +1042,13 @@ pub fn handler_distribute_syndicate_wars_prizes<'info>(
         // Validate the entry is for this competition month
         require!(entry.month == month, LottoError::InvalidSyndicateConfig);
 
+        // Ranks must follow the tamper-resistant ranking score
+        require!(
+            entry.ranking_score() <= previous_ranking_score,
+            LottoError::WarsRankingOutOfOrder
+        );
+        previous_ranking_score = entry.ranking_score();
+
         // Set the rank
         let rank = (i + 1) as u32;
         entry.final_rank = Some(rank);
@@ -814,7 +1112,8 @@ pub struct ClaimSyndicateWarsPrize<'info> {
             &syndicate.syndicate_id.to_le_bytes()
         ],
         bump = syndicate.bump,
-        constraint = syndicate.creator == manager.key() @ LottoError::Unauthorized
+        constraint = syndicate.creator == manager.key() @ LottoError::Unauthorized,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
     )]
     pub syndicate: Account<'info, Syndicate>,
 
@@ -874,3 +1173,489 @@ pub struct ClaimSyndicateWarsPrize<'info> {
     /// Token program
     pub token_program: Program<'info, Token>,
 }
+
+// ============================================================================
+// SYNDICATE WARS BRACKET INSTRUCTIONS
+// ============================================================================
+
+/// Deserialize the SyndicateWarsEntry accounts passed as remaining accounts
+///
+/// Every entry must be owned by this program, belong to `month` and be for
+/// a different syndicate.
+fn load_bracket_entries(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    month: u64,
+) -> Result<Vec<SyndicateWarsEntry>> {
+    let mut entries: Vec<SyndicateWarsEntry> = Vec::with_capacity(accounts.len());
+
+    for account_info in accounts {
+        require!(
+            account_info.owner == program_id,
+            LottoError::InvalidAccountOwner
+        );
+
+        let entry = {
+            let data = account_info.try_borrow_data()?;
+            let mut slice: &[u8] = &data;
+            SyndicateWarsEntry::try_deserialize(&mut slice)
+                .map_err(|_| LottoError::InvalidAccountData)?
+        };
+
+        require!(entry.month == month, LottoError::InvalidSyndicateConfig);
+        require!(
+            !entries.iter().any(|e| e.syndicate == entry.syndicate),
+            LottoError::InvalidBracketWinners
+        );
+
+        entries.push(entry);
+    }
+
+    Ok(entries)
+}
+
+/// Order syndicates by bracket score: ranking score, then win rate, then
+/// prizes won, best first
+///
+/// Remaining ties are broken by syndicate key so seeding is deterministic.
+pub(crate) fn seed_bracket_entries(entries: &mut [SyndicateWarsEntry]) -> Vec<Pubkey> {
+    entries.sort_by(|a, b| {
+        b.ranking_score()
+            .cmp(&a.ranking_score())
+            .then(b.win_rate.cmp(&a.win_rate))
+            .then(b.prizes_won.cmp(&a.prizes_won))
+            .then(a.syndicate.cmp(&b.syndicate))
+    });
+    entries.iter().map(|e| e.syndicate).collect()
+}
+
+/// Pair seeded syndicates best against worst (1 v n, 2 v n-1, ...)
+pub(crate) fn build_wars_matchups(seeded: &[Pubkey]) -> Vec<WarsMatchup> {
+    let count = seeded.len();
+    (0..count / 2)
+        .map(|i| WarsMatchup {
+            higher_seed: seeded[i],
+            lower_seed: seeded[count - 1 - i],
+        })
+        .collect()
+}
+
+/// Whether `winners` holds exactly one syndicate from each matchup
+pub(crate) fn is_valid_round_result(matchups: &[WarsMatchup], winners: &[Pubkey]) -> bool {
+    winners.len() == matchups.len()
+        && matchups
+            .iter()
+            .all(|m| winners.iter().filter(|w| m.contains(w)).count() == 1)
+}
+
+/// Record the finalists and emit `SyndicateWarsFinalsReached`
+fn record_wars_finalists(state: &mut SyndicateWarsState) {
+    let finals = state.matchup_pairs[0];
+    state.finalists = [finals.higher_seed, finals.lower_seed];
+
+    emit!(SyndicateWarsFinalsReached {
+        finalists: state.finalists,
+        prize_pool: state.finals_prize_pool(),
+    });
+
+    msg!(
+        "  Finals reached: {} vs {}",
+        finals.higher_seed,
+        finals.lower_seed
+    );
+}
+
+/// Accounts required for starting or advancing a Syndicate Wars bracket
+#[derive(Accounts)]
+pub struct AdvanceWarsBracket<'info> {
+    /// Lottery authority
+    pub authority: Signer<'info>,
+
+    /// Lottery state
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// Syndicate Wars state
+    #[account(
+        mut,
+        seeds = [
+            SYNDICATE_WARS_SEED,
+            &syndicate_wars_state.month.to_le_bytes()
+        ],
+        bump = syndicate_wars_state.bump,
+        constraint = !syndicate_wars_state.is_distributed @ LottoError::AlreadyDistributed
+    )]
+    pub syndicate_wars_state: Account<'info, SyndicateWarsState>,
+}
+
+/// Seed the knockout bracket from registered syndicates
+///
+/// Remaining accounts must be the SyndicateWarsEntry accounts of exactly
+/// 2^`bracket_rounds` entrants. They are seeded by score and paired best
+/// against worst. `finals_prize_bps` of the wars prize pool is set aside for
+/// the two finalists and no longer counts towards the rank prizes.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `bracket_rounds` - Knockout rounds including the finals (1-5)
+/// * `finals_prize_bps` - Finals share of the wars prize pool
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_start_wars_bracket<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AdvanceWarsBracket<'info>>,
+    bracket_rounds: u8,
+    finals_prize_bps: u16,
+) -> Result<()> {
+    let state = &mut ctx.accounts.syndicate_wars_state;
+
+    require!(state.bracket_rounds == 0, LottoError::BracketAlreadyStarted);
+    require!(
+        (1..=MAX_WARS_BRACKET_ROUNDS).contains(&bracket_rounds)
+            && finals_prize_bps <= MAX_WARS_FINALS_PRIZE_BPS,
+        LottoError::InvalidBracketConfig
+    );
+
+    let mut entries = load_bracket_entries(ctx.remaining_accounts, ctx.program_id, state.month)?;
+    require!(
+        entries.len() == 1usize << bracket_rounds,
+        LottoError::InvalidBracketConfig
+    );
+
+    let seeded = seed_bracket_entries(&mut entries);
+    let matchups = build_wars_matchups(&seeded);
+
+    state.bracket_rounds = bracket_rounds;
+    state.current_round = 0;
+    state.matchup_pairs = matchups.clone();
+    state.finals_prize_bps = finals_prize_bps;
+
+    emit!(SyndicateWarsBracketStarted {
+        month: state.month,
+        bracket_rounds,
+        matchups,
+    });
+
+    msg!("Syndicate Wars bracket started!");
+    msg!("  Month: {}", state.month);
+    msg!("  Entrants: {}", seeded.len());
+    msg!("  Rounds: {}", bracket_rounds);
+    msg!(
+        "  Finals prize: {} USDC lamports",
+        state.finals_prize_pool()
+    );
+
+    if state.is_finals_reached() {
+        record_wars_finalists(state);
+    }
+
+    Ok(())
+}
+
+/// Record the winners of the current bracket round
+///
+/// `winners` must hold exactly one syndicate from every current matchup.
+/// Remaining accounts must be the winners' SyndicateWarsEntry accounts;
+/// the winners are re-seeded by score and paired for the next round.
+/// Emits `SyndicateWarsFinalsReached` once the final round is paired.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `winners` - One winning syndicate per current matchup
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_advance_wars_bracket<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AdvanceWarsBracket<'info>>,
+    winners: Vec<Pubkey>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.syndicate_wars_state;
+
+    require!(state.bracket_rounds > 0, LottoError::BracketNotStarted);
+    require!(!state.is_finals_reached(), LottoError::FinalsAlreadyReached);
+    require!(
+        is_valid_round_result(&state.matchup_pairs, &winners),
+        LottoError::InvalidBracketWinners
+    );
+
+    let mut entries = load_bracket_entries(ctx.remaining_accounts, ctx.program_id, state.month)?;
+    require!(
+        entries.len() == winners.len() && entries.iter().all(|e| winners.contains(&e.syndicate)),
+        LottoError::InvalidBracketWinners
+    );
+
+    let seeded = seed_bracket_entries(&mut entries);
+    let next_matchups = build_wars_matchups(&seeded);
+
+    state.current_round = state.current_round.saturating_add(1);
+    state.matchup_pairs = next_matchups.clone();
+
+    emit!(SyndicateWarsBracketAdvanced {
+        round: state.current_round,
+        winners,
+        next_matchups,
+    });
+
+    msg!("Syndicate Wars bracket advanced!");
+    msg!("  Month: {}", state.month);
+    msg!(
+        "  Round: {} of {}",
+        state.current_round + 1,
+        state.bracket_rounds
+    );
+    msg!("  Matchups: {}", state.matchup_pairs.len());
+
+    if state.is_finals_reached() {
+        record_wars_finalists(state);
+    }
+
+    Ok(())
+}
+
+/// Accounts required for claiming a share of the finals bracket prize
+#[derive(Accounts)]
+pub struct ClaimFinalsBracketPrize<'info> {
+    /// Syndicate creator/manager
+    #[account(mut)]
+    pub manager: Signer<'info>,
+
+    /// Syndicate account
+    #[account(
+        seeds = [
+            SYNDICATE_SEED,
+            syndicate.original_creator.as_ref(),
+            &syndicate.syndicate_id.to_le_bytes()
+        ],
+        bump = syndicate.bump,
+        constraint = syndicate.creator == manager.key() @ LottoError::Unauthorized,
+        constraint = syndicate.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub syndicate: Account<'info, Syndicate>,
+
+    /// Syndicate Wars state
+    #[account(
+        seeds = [
+            SYNDICATE_WARS_SEED,
+            &syndicate_wars_state.month.to_le_bytes()
+        ],
+        bump = syndicate_wars_state.bump,
+        constraint = !syndicate_wars_state.is_active @ LottoError::SyndicateWarsNotActive
+    )]
+    pub syndicate_wars_state: Account<'info, SyndicateWarsState>,
+
+    /// Syndicate Wars entry
+    #[account(
+        mut,
+        seeds = [
+            SYNDICATE_WARS_SEED,
+            b"entry",
+            &syndicate_wars_state.month.to_le_bytes(),
+            syndicate.key().as_ref()
+        ],
+        bump = wars_entry.bump,
+        constraint = wars_entry.syndicate == syndicate.key() @ LottoError::InvalidSyndicateConfig,
+        constraint = !wars_entry.finals_prize_claimed @ LottoError::AlreadyClaimed
+    )]
+    pub wars_entry: Account<'info, SyndicateWarsEntry>,
+
+    /// Syndicate's USDC token account (receives prize)
+    #[account(
+        mut,
+        constraint = syndicate_usdc.owner == syndicate.key(),
+        constraint = syndicate_usdc.mint == usdc_mint.key()
+    )]
+    pub syndicate_usdc: Account<'info, TokenAccount>,
+
+    /// Syndicate Wars prize pool USDC token account
+    #[account(
+        mut,
+        seeds = [
+            SYNDICATE_WARS_SEED,
+            b"prize_pool",
+            &syndicate_wars_state.month.to_le_bytes()
+        ],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = syndicate_wars_state
+    )]
+    pub wars_prize_pool_usdc: Account<'info, TokenAccount>,
+
+    /// USDC mint
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Claim a finalist's half of the finals bracket prize
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_claim_finals_bracket_prize(ctx: Context<ClaimFinalsBracketPrize>) -> Result<()> {
+    let state = &ctx.accounts.syndicate_wars_state;
+    let syndicate_key = ctx.accounts.syndicate.key();
+
+    require!(state.is_finals_reached(), LottoError::FinalsNotReached);
+    require!(
+        state.finalists.contains(&syndicate_key),
+        LottoError::NotAFinalist
+    );
+
+    let prize_amount = state.finals_prize_pool() / 2;
+    require!(prize_amount > 0, LottoError::InsufficientFunds);
+    require!(
+        ctx.accounts.wars_prize_pool_usdc.amount >= prize_amount,
+        LottoError::InsufficientFunds
+    );
+
+    let month_bytes = state.month.to_le_bytes();
+    let seeds = &[SYNDICATE_WARS_SEED, month_bytes.as_ref(), &[state.bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.wars_prize_pool_usdc.to_account_info(),
+        to: ctx.accounts.syndicate_usdc.to_account_info(),
+        authority: ctx.accounts.syndicate_wars_state.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    token::transfer(cpi_ctx, prize_amount)?;
+
+    ctx.accounts.wars_entry.finals_prize_claimed = true;
+
+    msg!("Syndicate Wars finals prize claimed!");
+    msg!("  Syndicate: {}", syndicate_key);
+    msg!("  Month: {}", ctx.accounts.syndicate_wars_state.month);
+    msg!("  Prize amount: {} USDC lamports", prize_amount);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(syndicate: Pubkey, win_rate: u64, prizes_won: u64) -> SyndicateWarsEntry {
+        SyndicateWarsEntry {
+            syndicate,
+            win_rate,
+            prizes_won,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_seed_orders_by_win_rate_then_prizes() {
+        let (a, b, c, d) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut entries = vec![
+            entry(a, 100, 0),
+            entry(b, 300, 0),
+            entry(c, 200, 50),
+            entry(d, 200, 90),
+        ];
+
+        assert_eq!(seed_bracket_entries(&mut entries), vec![b, d, c, a]);
+    }
+
+    #[test]
+    fn test_seed_orders_by_ranking_score_first() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut entries = vec![entry(a, 300, 0), entry(b, 100, 0)];
+        // b's inflated submission is floored by its on-chain score
+        entries[0].submitted_score = 500;
+        entries[0].on_chain_score = 600;
+        entries[1].submitted_score = 10_000;
+        entries[1].on_chain_score = 20;
+
+        assert_eq!(entries[0].ranking_score(), 500);
+        assert_eq!(entries[1].ranking_score(), 20);
+        assert_eq!(seed_bracket_entries(&mut entries), vec![a, b]);
+    }
+
+    #[test]
+    fn test_wars_score_for_match() {
+        let scores: Vec<u64> = (0..=6).map(wars_score_for_match).collect();
+        assert_eq!(scores, vec![0, 0, 1, 10, 100, 1_000, 10_000]);
+    }
+
+    #[test]
+    fn test_matchups_pair_best_against_worst() {
+        let seeded: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let matchups = build_wars_matchups(&seeded);
+
+        assert_eq!(matchups.len(), 2);
+        assert_eq!(matchups[0].higher_seed, seeded[0]);
+        assert_eq!(matchups[0].lower_seed, seeded[3]);
+        assert_eq!(matchups[1].higher_seed, seeded[1]);
+        assert_eq!(matchups[1].lower_seed, seeded[2]);
+    }
+
+    #[test]
+    fn test_round_result_needs_one_winner_per_matchup() {
+        let seeded: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let matchups = build_wars_matchups(&seeded);
+
+        assert!(is_valid_round_result(&matchups, &[seeded[3], seeded[1]]));
+        // Both winners from the same matchup
+        assert!(!is_valid_round_result(&matchups, &[seeded[0], seeded[3]]));
+        // Duplicate winner
+        assert!(!is_valid_round_result(&matchups, &[seeded[0], seeded[0]]));
+        // Outsider
+        assert!(!is_valid_round_result(
+            &matchups,
+            &[seeded[0], Pubkey::new_unique()]
+        ));
+        // Missing a matchup
+        assert!(!is_valid_round_result(&matchups, &[seeded[0]]));
+    }
+
+    #[test]
+    fn test_finals_prize_is_carved_out_of_rank_prizes() {
+        let mut state = SyndicateWarsState {
+            prize_pool: 1_000_000,
+            bracket_rounds: 3,
+            finals_prize_bps: 2000,
+            ..Default::default()
+        };
+
+        assert_eq!(state.finals_prize_pool(), 200_000);
+        assert_eq!(state.ranked_prize_pool(), 800_000);
+        assert!(!state.is_finals_reached());
+
+        state.current_round = 2;
+        assert!(state.is_finals_reached());
+    }
+
+    #[test]
+    fn test_new_entry_and_auto_registration_eligibility() {
+        let syndicate_key = Pubkey::new_unique();
+        let entry = new_syndicate_wars_entry(syndicate_key, 202_501, 253);
+        assert_eq!(entry.syndicate, syndicate_key);
+        assert_eq!(entry.month, 202_501);
+        assert_eq!(entry.bump, 253);
+        assert_eq!(entry.final_rank, None);
+        assert!(!entry.prize_claimed && !entry.finals_prize_claimed);
+
+        let mut syndicate = Syndicate {
+            is_public: true,
+            member_count: 1,
+            ..Default::default()
+        };
+        assert!(is_eligible_for_wars_auto_registration(&syndicate));
+        syndicate.is_public = false;
+        assert!(!is_eligible_for_wars_auto_registration(&syndicate));
+    }
+}
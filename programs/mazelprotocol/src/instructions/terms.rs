@@ -0,0 +1,176 @@
+//! Terms Acceptance Instructions
+//!
+//! The lottery's terms live off-chain (typically on IPFS) at
+//! `lottery_state.terms_uri`. Each `update_terms` bumps
+//! `lottery_state.terms_version`, after which every ticket purchase and
+//! referral credit redemption rejects players until they `accept_terms`
+//! again. Terms are advisory, so updates are not timelocked, and claims are
+//! never blocked.
+//!
+//! - accept_terms: Accept the current terms version (player)
+//! - update_terms: Publish a new terms version (authority only)
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{TermsAccepted, TermsUpdated};
+use crate::state::{LotteryState, SchemaVersioned, UserStats};
+
+/// Fail with `TermsNotAccepted` until the player has accepted the current
+/// terms version
+pub fn enforce_terms_accepted(terms_version: u8, user_stats: &UserStats) -> Result<()> {
+    require!(
+        user_stats.accepted_terms_version >= terms_version,
+        LottoError::TermsNotAccepted
+    );
+    Ok(())
+}
+
+// ============================================================================
+// ACCEPT TERMS
+// ============================================================================
+
+/// Accounts required for accepting the lottery terms
+#[derive(Accounts)]
+pub struct AcceptTerms<'info> {
+    /// The player accepting the terms
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// Player's user statistics account (created if needed so terms can be
+    /// accepted before the first purchase)
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = USER_STATS_SIZE,
+        seeds = [USER_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Account<'info, UserStats>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Accept the current terms version
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_accept_terms(ctx: Context<AcceptTerms>) -> Result<()> {
+    let clock = Clock::get()?;
+    let player = ctx.accounts.player.key();
+    let version = ctx.accounts.lottery_state.terms_version;
+    let user_stats = &mut ctx.accounts.user_stats;
+
+    // Initialize if new
+    if user_stats.wallet == Pubkey::default() {
+        user_stats.schema_version = CURRENT_SCHEMA_VERSION;
+        user_stats.wallet = player;
+        user_stats.bump = ctx.bumps.user_stats;
+        user_stats.tickets_this_draw = 0;
+        user_stats.last_draw_participated = 0;
+        user_stats.daily_ticket_limit = DEFAULT_DAILY_TICKET_LIMIT;
+        user_stats.join_timestamp = clock.unix_timestamp;
+    }
+    user_stats.require_current_schema()?;
+
+    user_stats.accepted_terms_version = version;
+
+    emit!(TermsAccepted {
+        player,
+        version,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Terms accepted!");
+    msg!("  Player: {}", player);
+    msg!("  Version: {}", version);
+
+    Ok(())
+}
+
+// ============================================================================
+// UPDATE TERMS
+// ============================================================================
+
+/// Accounts required for publishing new lottery terms
+#[derive(Accounts)]
+pub struct UpdateTerms<'info> {
+    /// The lottery authority
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+/// Publish new terms at `new_uri`, requiring every player to accept them
+/// again before their next purchase
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `new_uri` - Location of the new terms (1 to MAX_TERMS_URI_LENGTH bytes)
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_update_terms(ctx: Context<UpdateTerms>, new_uri: String) -> Result<()> {
+    require!(
+        !new_uri.is_empty() && new_uri.len() <= MAX_TERMS_URI_LENGTH,
+        LottoError::InvalidTermsUri
+    );
+
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    let version = lottery_state
+        .terms_version
+        .checked_add(1)
+        .ok_or(LottoError::Overflow)?;
+    lottery_state.terms_version = version;
+    lottery_state.terms_uri = new_uri.clone();
+
+    msg!("Terms updated!");
+    msg!("  Version: {}", version);
+    msg!("  URI: {}", new_uri);
+
+    emit!(TermsUpdated {
+        version,
+        uri: new_uri,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enforce_terms_accepted() {
+        let mut user_stats = UserStats::default();
+
+        // No terms published yet
+        assert!(enforce_terms_accepted(0, &user_stats).is_ok());
+
+        assert!(enforce_terms_accepted(1, &user_stats).is_err());
+        user_stats.accepted_terms_version = 1;
+        assert!(enforce_terms_accepted(1, &user_stats).is_ok());
+        assert!(enforce_terms_accepted(2, &user_stats).is_err());
+    }
+}
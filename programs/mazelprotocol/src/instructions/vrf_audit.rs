@@ -0,0 +1,117 @@
+//! VRF Audit Instruction
+//!
+//! `execute_draw` stores the raw randomness bytes a draw's winning numbers
+//! were derived from in `DrawResult.vrf_result_raw`. `audit_vrf_result` lets
+//! anyone re-run the derivation on-chain and compare it against the stored
+//! numbers, so fairness can be checked without the Switchboard SDK. A
+//! mismatch pauses the lottery.
+//!
+//! - audit_vrf_result: Re-derive and check a draw's winning numbers (anyone)
+
+use anchor_lang::prelude::*;
+use sha2::{Digest, Sha256};
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{EmergencyPause, VrfAuditFailed, VrfAuditPassed};
+use crate::instructions::execute_draw::generate_winning_numbers;
+use crate::state::{DrawResult, LotteryState, SchemaVersioned};
+
+/// Whether `draw_result` can be audited
+///
+/// Draws executed before `vrf_result_raw` was stored, and draws whose
+/// numbers were deliberately replaced by an override, have nothing to check.
+pub fn is_vrf_auditable(draw_result: &DrawResult) -> bool {
+    draw_result.vrf_result_raw != [0u8; 32] && !draw_result.was_overridden
+}
+
+/// Accounts required for auditing a draw's VRF result
+#[derive(Accounts)]
+#[instruction(draw_id: u64)]
+pub struct AuditVrfResult<'info> {
+    /// Anyone auditing the draw
+    pub auditor: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The audited draw's result
+    #[account(
+        seeds = [DRAW_SEED, &draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = is_vrf_auditable(&draw_result) @ LottoError::VrfAuditNotApplicable
+    )]
+    pub draw_result: Account<'info, DrawResult>,
+}
+
+/// Re-derive `draw_id`'s winning numbers from its stored VRF bytes
+///
+/// Emits `VrfAuditPassed` when they match the stored numbers. Otherwise
+/// emits `VrfAuditFailed` and pauses the lottery.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `draw_id` - Draw to audit
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_audit_vrf_result(ctx: Context<AuditVrfResult>, draw_id: u64) -> Result<()> {
+    let draw_result = &ctx.accounts.draw_result;
+    let stored_numbers = draw_result.winning_numbers;
+    let derived_numbers = generate_winning_numbers(&draw_result.vrf_result_raw)?;
+
+    if derived_numbers == stored_numbers {
+        let mut vrf_hash = [0u8; 32];
+        vrf_hash.copy_from_slice(Sha256::digest(draw_result.vrf_result_raw).as_slice());
+
+        emit!(VrfAuditPassed { draw_id, vrf_hash });
+
+        msg!("VRF audit passed for draw #{}", draw_id);
+        msg!("  Winning numbers: {:?}", stored_numbers);
+        return Ok(());
+    }
+
+    let clock = Clock::get()?;
+    ctx.accounts.lottery_state.is_paused = true;
+
+    msg!("⚠️  VRF audit FAILED for draw #{}", draw_id);
+    msg!("  Stored numbers: {:?}", stored_numbers);
+    msg!("  Derived numbers: {:?}", derived_numbers);
+    msg!("  Lottery has been PAUSED pending review.");
+
+    emit!(VrfAuditFailed {
+        draw_id,
+        stored_numbers,
+        derived_numbers,
+    });
+    emit!(EmergencyPause {
+        authority: ctx.accounts.auditor.key(),
+        reason: format!("VRF audit failed for draw {}", draw_id),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_vrf_auditable() {
+        let mut draw_result = DrawResult::default();
+        assert!(!is_vrf_auditable(&draw_result));
+
+        draw_result.vrf_result_raw = [7u8; 32];
+        assert!(is_vrf_auditable(&draw_result));
+
+        draw_result.was_overridden = true;
+        assert!(!is_vrf_auditable(&draw_result));
+    }
+}
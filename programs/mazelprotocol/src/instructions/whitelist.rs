@@ -0,0 +1,432 @@
+//! Purchase Whitelist Instructions
+//!
+//! For private betas and geofenced launches the authority can restrict who
+//! may buy through `buy_ticket`, `buy_bulk`, `buy_multi_draw_ticket`,
+//! `buy_recurring_ticket` and `redeem_referral_credit` with
+//! `lottery_state.whitelist_mode`:
+//! - PlayerWhitelist: only wallets listed in the `Whitelist` account
+//! - TokenGated: only wallets holding the whitelist's `gate_mint`
+//!
+//! Purchases then pass the `Whitelist` account (and, when token gated, the
+//! player's token account for the gate mint). Claims are never restricted.
+//!
+//! - set_whitelist_mode: Enable a mode, creating the whitelist if needed
+//! - add_to_whitelist / remove_from_whitelist: Maintain the wallet list
+//! - disable_whitelist: Reopen purchases to everyone
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::{WalletRemovedFromWhitelist, WalletWhitelisted, WhitelistModeSet};
+use crate::state::{LotteryState, SchemaVersioned, Whitelist, WhitelistMode};
+
+/// Fail unless `player` may buy under `mode`
+///
+/// No-op in `WhitelistMode::None`. Otherwise the whitelist is required, and
+/// in `TokenGated` mode so is a token account of the player's holding a
+/// nonzero balance of `whitelist.gate_mint`.
+pub fn enforce_whitelist(
+    mode: WhitelistMode,
+    whitelist: Option<&Whitelist>,
+    gate_token_account: Option<&TokenAccount>,
+    player: &Pubkey,
+) -> Result<()> {
+    if mode == WhitelistMode::None {
+        return Ok(());
+    }
+    let whitelist = whitelist.ok_or(LottoError::WhitelistAccountRequired)?;
+
+    match mode {
+        WhitelistMode::None => Ok(()),
+        WhitelistMode::PlayerWhitelist => {
+            require!(whitelist.contains(player), LottoError::PlayerNotWhitelisted);
+            Ok(())
+        }
+        WhitelistMode::TokenGated => {
+            let gate_token_account =
+                gate_token_account.ok_or(LottoError::WhitelistAccountRequired)?;
+            require!(
+                gate_token_account.owner == *player
+                    && gate_token_account.mint == whitelist.gate_mint
+                    && gate_token_account.amount > 0,
+                LottoError::TokenGateNotSatisfied
+            );
+            Ok(())
+        }
+    }
+}
+
+// ============================================================================
+// SET WHITELIST MODE
+// ============================================================================
+
+/// Accounts required for enabling a whitelist mode
+#[derive(Accounts)]
+pub struct SetWhitelistMode<'info> {
+    /// The lottery authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The whitelist (created empty on first use)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Whitelist::size_for_wallets(0),
+        seeds = [WHITELIST_SEED],
+        bump
+    )]
+    pub whitelist: Account<'info, Whitelist>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Restrict purchases to `mode`
+///
+/// `TokenGated` needs a `gate_mint`, either passed here or already stored
+/// on the whitelist. Use `disable_whitelist` to reopen purchases.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `mode` - PlayerWhitelist or TokenGated
+/// * `gate_mint` - Mint required in TokenGated mode (replaces the stored one)
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_set_whitelist_mode(
+    ctx: Context<SetWhitelistMode>,
+    mode: WhitelistMode,
+    gate_mint: Option<Pubkey>,
+) -> Result<()> {
+    require!(
+        mode != WhitelistMode::None,
+        LottoError::InvalidWhitelistMode
+    );
+
+    let whitelist = &mut ctx.accounts.whitelist;
+    whitelist.bump = ctx.bumps.whitelist;
+    if let Some(gate_mint) = gate_mint {
+        whitelist.gate_mint = gate_mint;
+    }
+    if mode == WhitelistMode::TokenGated {
+        require!(
+            whitelist.gate_mint != Pubkey::default(),
+            LottoError::InvalidWhitelistMode
+        );
+    }
+
+    ctx.accounts.lottery_state.whitelist_mode = mode;
+
+    emit!(WhitelistModeSet { mode });
+
+    msg!("Whitelist mode set!");
+    msg!("  Mode: {:?}", mode);
+    msg!("  Whitelisted wallets: {}", whitelist.allowed_wallets.len());
+    if mode == WhitelistMode::TokenGated {
+        msg!("  Gate mint: {}", whitelist.gate_mint);
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// ADD TO WHITELIST
+// ============================================================================
+
+/// Accounts required for whitelisting wallets
+#[derive(Accounts)]
+pub struct AddToWhitelist<'info> {
+    /// The lottery authority (pays for the whitelist growth)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The whitelist
+    #[account(
+        mut,
+        seeds = [WHITELIST_SEED],
+        bump = whitelist.bump
+    )]
+    pub whitelist: Account<'info, Whitelist>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Add `wallets` to the whitelist
+///
+/// Wallets already listed (or repeated in `wallets`) are skipped. The
+/// account grows by the number of new wallets, with the authority covering
+/// the extra rent. Fails with `WhitelistFull` past MAX_WHITELIST_WALLETS.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `wallets` - Wallets to whitelist
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_add_to_whitelist(ctx: Context<AddToWhitelist>, wallets: Vec<Pubkey>) -> Result<()> {
+    require!(!wallets.is_empty(), LottoError::EmptyWalletList);
+
+    let mut new_wallets: Vec<Pubkey> = Vec::with_capacity(wallets.len());
+    for wallet in wallets {
+        if !ctx.accounts.whitelist.contains(&wallet) && !new_wallets.contains(&wallet) {
+            new_wallets.push(wallet);
+        }
+    }
+
+    let new_count = ctx.accounts.whitelist.allowed_wallets.len() + new_wallets.len();
+    require!(
+        new_count <= MAX_WHITELIST_WALLETS,
+        LottoError::WhitelistFull
+    );
+
+    let new_size = Whitelist::size_for_wallets(new_count);
+    let whitelist_info = ctx.accounts.whitelist.to_account_info();
+    if new_size > whitelist_info.data_len() {
+        let rent = Rent::get()?;
+        let lamports_diff = rent
+            .minimum_balance(new_size)
+            .saturating_sub(whitelist_info.lamports());
+
+        if lamports_diff > 0 {
+            let cpi_context = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: whitelist_info.clone(),
+                },
+            );
+            anchor_lang::system_program::transfer(cpi_context, lamports_diff)?;
+        }
+
+        whitelist_info.resize(new_size)?;
+    }
+
+    for wallet in &new_wallets {
+        emit!(WalletWhitelisted { wallet: *wallet });
+    }
+    let whitelist = &mut ctx.accounts.whitelist;
+    whitelist
+        .allowed_wallets
+        .extend(new_wallets.iter().copied());
+
+    msg!("Wallets whitelisted!");
+    msg!("  Added: {}", new_wallets.len());
+    msg!("  Total: {}", whitelist.allowed_wallets.len());
+
+    Ok(())
+}
+
+// ============================================================================
+// REMOVE FROM WHITELIST
+// ============================================================================
+
+/// Accounts required for removing wallets from the whitelist
+#[derive(Accounts)]
+pub struct RemoveFromWhitelist<'info> {
+    /// The lottery authority
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The whitelist
+    #[account(
+        mut,
+        seeds = [WHITELIST_SEED],
+        bump = whitelist.bump
+    )]
+    pub whitelist: Account<'info, Whitelist>,
+}
+
+/// Remove `wallets` from the whitelist
+///
+/// Wallets that are not listed are skipped. The account keeps its size so
+/// later additions reuse the freed space.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `wallets` - Wallets to remove
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_remove_from_whitelist(
+    ctx: Context<RemoveFromWhitelist>,
+    wallets: Vec<Pubkey>,
+) -> Result<()> {
+    require!(!wallets.is_empty(), LottoError::EmptyWalletList);
+
+    let whitelist = &mut ctx.accounts.whitelist;
+    let mut removed = 0u32;
+    for wallet in wallets {
+        if let Some(index) = whitelist.allowed_wallets.iter().position(|w| *w == wallet) {
+            whitelist.allowed_wallets.swap_remove(index);
+            removed += 1;
+            emit!(WalletRemovedFromWhitelist { wallet });
+        }
+    }
+
+    msg!("Wallets removed from whitelist!");
+    msg!("  Removed: {}", removed);
+    msg!("  Total: {}", whitelist.allowed_wallets.len());
+
+    Ok(())
+}
+
+// ============================================================================
+// DISABLE WHITELIST
+// ============================================================================
+
+/// Accounts required for disabling the whitelist
+#[derive(Accounts)]
+pub struct DisableWhitelist<'info> {
+    /// The lottery authority
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.authority == authority.key() @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+/// Reopen purchases to every wallet
+///
+/// The whitelist account and its wallets are kept, so a later
+/// `set_whitelist_mode` restores the same list.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_disable_whitelist(ctx: Context<DisableWhitelist>) -> Result<()> {
+    let lottery_state = &mut ctx.accounts.lottery_state;
+    let previous_mode = lottery_state.whitelist_mode;
+    lottery_state.whitelist_mode = WhitelistMode::None;
+
+    emit!(WhitelistModeSet {
+        mode: WhitelistMode::None,
+    });
+
+    msg!("Whitelist disabled!");
+    msg!("  Previous mode: {:?}", previous_mode);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_spl::token::spl_token::state::{Account as SplTokenAccount, AccountState};
+
+    fn gate_token_account(owner: Pubkey, mint: Pubkey, amount: u64) -> TokenAccount {
+        let mut data = [0u8; SplTokenAccount::LEN];
+        SplTokenAccount {
+            mint,
+            owner,
+            amount,
+            state: AccountState::Initialized,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut data);
+        TokenAccount::try_deserialize_unchecked(&mut data.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn test_player_whitelist() {
+        let player = Pubkey::new_unique();
+        let mut whitelist = Whitelist::default();
+
+        assert!(enforce_whitelist(WhitelistMode::None, None, None, &player).is_ok());
+        assert_eq!(
+            enforce_whitelist(WhitelistMode::PlayerWhitelist, None, None, &player).unwrap_err(),
+            LottoError::WhitelistAccountRequired.into()
+        );
+        assert_eq!(
+            enforce_whitelist(
+                WhitelistMode::PlayerWhitelist,
+                Some(&whitelist),
+                None,
+                &player
+            )
+            .unwrap_err(),
+            LottoError::PlayerNotWhitelisted.into()
+        );
+
+        whitelist.allowed_wallets.push(player);
+        assert!(enforce_whitelist(
+            WhitelistMode::PlayerWhitelist,
+            Some(&whitelist),
+            None,
+            &player
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_token_gated_whitelist() {
+        let player = Pubkey::new_unique();
+        let gate_mint = Pubkey::new_unique();
+        let whitelist = Whitelist {
+            gate_mint,
+            ..Default::default()
+        };
+        let check = |account: Option<&TokenAccount>| {
+            enforce_whitelist(
+                WhitelistMode::TokenGated,
+                Some(&whitelist),
+                account,
+                &player,
+            )
+        };
+
+        assert_eq!(
+            check(None).unwrap_err(),
+            LottoError::WhitelistAccountRequired.into()
+        );
+        assert!(check(Some(&gate_token_account(player, gate_mint, 1))).is_ok());
+        for account in [
+            gate_token_account(player, gate_mint, 0),
+            gate_token_account(player, Pubkey::new_unique(), 1),
+            gate_token_account(Pubkey::new_unique(), gate_mint, 1),
+        ] {
+            assert_eq!(
+                check(Some(&account)).unwrap_err(),
+                LottoError::TokenGateNotSatisfied.into()
+            );
+        }
+    }
+}
@@ -0,0 +1,243 @@
+//! Winner Merkle Root Instructions
+//!
+//! After a draw is finalized, the authority (or an operator granted
+//! `OPERATOR_PERMISSION_SUBMIT_MERKLE_ROOT`) can publish the Merkle root of the
+//! winning ticket set on the `DrawResult`. Wallets can then show a player that
+//! their ticket won before they decide to claim.
+//!
+//! - submit_winner_merkle_root: Store the winners' Merkle root (once, before claim expiry)
+//! - verify_ticket_merkle_inclusion: Check a ticket against the root (no claim made)
+//!
+//! Leaves are `SHA-256(ticket_pubkey)`. Parent nodes are
+//! `SHA-256(left || right)`, with the sibling order taken from the bits of the
+//! leaf index (bit clear = current node is the left child).
+
+use anchor_lang::prelude::*;
+use sha2::{Digest, Sha256};
+
+use crate::constants::*;
+use crate::errors::LottoError;
+use crate::events::TicketMerkleVerified;
+use crate::instructions::close_ticket::is_claim_window_expired;
+use crate::state::{DrawResult, LotteryState, SchemaVersioned, TicketData};
+
+/// Merkle leaf for a ticket account
+pub fn ticket_merkle_leaf(ticket: &Pubkey) -> [u8; 32] {
+    Sha256::digest(ticket.as_ref()).into()
+}
+
+/// Recompute the Merkle root from a leaf and its inclusion proof
+///
+/// Returns `None` if `leaf_index` has bits set above the proof height.
+pub fn compute_merkle_root(
+    leaf: [u8; 32],
+    proof: &[[u8; 32]],
+    leaf_index: u64,
+) -> Option<[u8; 32]> {
+    if proof.len() < 64 && leaf_index >> proof.len() != 0 {
+        return None;
+    }
+
+    let mut node = leaf;
+    let mut index = leaf_index;
+    for sibling in proof {
+        let mut hasher = Sha256::new();
+        if index & 1 == 0 {
+            hasher.update(node);
+            hasher.update(sibling);
+        } else {
+            hasher.update(sibling);
+            hasher.update(node);
+        }
+        node = hasher.finalize().into();
+        index >>= 1;
+    }
+
+    Some(node)
+}
+
+// ============================================================================
+// SUBMIT WINNER MERKLE ROOT
+// ============================================================================
+
+/// Accounts required for submitting a draw's winner Merkle root
+#[derive(Accounts)]
+#[instruction(draw_id: u64)]
+pub struct SubmitWinnerMerkleRoot<'info> {
+    /// Lottery authority, or the operator when granted the permission
+    pub authority: Signer<'info>,
+
+    /// The main lottery state account
+    #[account(
+        seeds = [LOTTERY_SEED],
+        bump = lottery_state.bump,
+        constraint = lottery_state.can_run_draw_step(
+            &authority.key(),
+            OPERATOR_PERMISSION_SUBMIT_MERKLE_ROOT
+        ) @ LottoError::Unauthorized,
+        constraint = lottery_state.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// The finalized draw result to attach the root to
+    #[account(
+        mut,
+        seeds = [DRAW_SEED, &draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = draw_result.draw_id == draw_id @ LottoError::DrawIdMismatch,
+        constraint = draw_result.is_finalized() @ LottoError::DrawNotFinalized,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub draw_result: Account<'info, DrawResult>,
+}
+
+/// Store the Merkle root of a draw's winning tickets
+///
+/// The root can be submitted once per draw, and only while the draw's
+/// claim window is still open.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `draw_id` - Draw the root belongs to
+/// * `root` - Merkle root over `SHA-256(ticket_pubkey)` leaves
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_submit_winner_merkle_root(
+    ctx: Context<SubmitWinnerMerkleRoot>,
+    draw_id: u64,
+    root: [u8; 32],
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let draw_result = &mut ctx.accounts.draw_result;
+
+    require!(
+        draw_result.winner_merkle_root.is_none(),
+        LottoError::WinnerMerkleRootAlreadySubmitted
+    );
+    require!(
+        !is_claim_window_expired(draw_result.timestamp, clock.unix_timestamp),
+        LottoError::TicketExpired
+    );
+
+    draw_result.winner_merkle_root = Some(root);
+    draw_result.merkle_root_submitted_at = clock.unix_timestamp;
+
+    msg!("Winner Merkle root submitted!");
+    msg!("  Draw ID: {}", draw_id);
+    msg!("  Root: {:?}", root);
+
+    Ok(())
+}
+
+// ============================================================================
+// VERIFY TICKET MERKLE INCLUSION
+// ============================================================================
+
+/// Accounts required for verifying a ticket against a draw's winner root
+#[derive(Accounts)]
+pub struct VerifyTicketMerkleInclusion<'info> {
+    /// The ticket to verify
+    #[account(
+        constraint = ticket.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub ticket: Account<'info, TicketData>,
+
+    /// The draw result holding the winner Merkle root
+    #[account(
+        seeds = [DRAW_SEED, &ticket.draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = draw_result.draw_id == ticket.draw_id @ LottoError::DrawIdMismatch,
+        constraint = draw_result.is_current_schema() @ LottoError::SchemaVersionMismatch
+    )]
+    pub draw_result: Account<'info, DrawResult>,
+}
+
+/// Verify that a ticket is included in its draw's winner Merkle root
+///
+/// Permissionless and read-only: emits `TicketMerkleVerified` with the
+/// result and never claims or moves funds.
+///
+/// # Arguments
+/// * `ctx` - The context containing required accounts
+/// * `proof` - Sibling hashes from leaf to root
+/// * `leaf_index` - Position of the ticket's leaf in the tree
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_verify_ticket_merkle_inclusion(
+    ctx: Context<VerifyTicketMerkleInclusion>,
+    proof: Vec<[u8; 32]>,
+    leaf_index: u64,
+) -> Result<()> {
+    let root = ctx
+        .accounts
+        .draw_result
+        .winner_merkle_root
+        .ok_or(LottoError::WinnerMerkleRootNotSubmitted)?;
+
+    let ticket_pubkey = ctx.accounts.ticket.key();
+    let draw_id = ctx.accounts.draw_result.draw_id;
+    let leaf = ticket_merkle_leaf(&ticket_pubkey);
+    let is_included = compute_merkle_root(leaf, &proof, leaf_index) == Some(root);
+
+    emit!(TicketMerkleVerified {
+        is_included,
+        ticket_pubkey,
+        draw_id,
+    });
+
+    msg!("Ticket Merkle inclusion checked");
+    msg!("  Ticket: {}", ticket_pubkey);
+    msg!("  Draw ID: {}", draw_id);
+    msg!("  Included: {}", is_included);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash_pair(left: [u8; 32], right: [u8; 32]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(left);
+        hasher.update(right);
+        hasher.finalize().into()
+    }
+
+    #[test]
+    fn test_compute_merkle_root() {
+        let leaves: Vec<[u8; 32]> = (0..4u8)
+            .map(|i| ticket_merkle_leaf(&Pubkey::new_from_array([i; 32])))
+            .collect();
+        let left = hash_pair(leaves[0], leaves[1]);
+        let right = hash_pair(leaves[2], leaves[3]);
+        let root = hash_pair(left, right);
+
+        assert_eq!(
+            compute_merkle_root(leaves[0], &[leaves[1], right], 0),
+            Some(root)
+        );
+        assert_eq!(
+            compute_merkle_root(leaves[3], &[leaves[2], left], 3),
+            Some(root)
+        );
+
+        // Wrong index or wrong sibling does not reproduce the root
+        assert_ne!(
+            compute_merkle_root(leaves[0], &[leaves[1], right], 1),
+            Some(root)
+        );
+        assert_ne!(
+            compute_merkle_root(leaves[0], &[leaves[2], right], 0),
+            Some(root)
+        );
+
+        // Index beyond the proof height is rejected
+        assert_eq!(compute_merkle_root(leaves[0], &[leaves[1], right], 4), None);
+
+        // Single-leaf tree: the leaf is the root
+        assert_eq!(compute_merkle_root(leaves[0], &[], 0), Some(leaves[0]));
+    }
+}
@@ -54,25 +54,105 @@ pub use state::*;
 #[allow(ambiguous_glob_reexports)]
 pub use instructions::admin::*;
 #[allow(ambiguous_glob_reexports)]
+pub use instructions::analytics::*;
+#[allow(ambiguous_glob_reexports)]
 pub use instructions::buy_bulk::*;
 #[allow(ambiguous_glob_reexports)]
 pub use instructions::buy_ticket::*;
 #[allow(ambiguous_glob_reexports)]
+pub use instructions::claim_agent::*;
+#[allow(ambiguous_glob_reexports)]
 pub use instructions::claim_bulk_prize::*;
 #[allow(ambiguous_glob_reexports)]
 pub use instructions::claim_prize::*;
 #[allow(ambiguous_glob_reexports)]
+pub use instructions::close_ticket::*;
+#[allow(ambiguous_glob_reexports)]
 pub use instructions::commit_randomness::*;
 #[allow(ambiguous_glob_reexports)]
+pub use instructions::daily_limit::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::draw_challenge::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::draw_history::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::draw_override::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::draw_refund::*;
+#[allow(ambiguous_glob_reexports)]
 pub use instructions::execute_draw::*;
 #[allow(ambiguous_glob_reexports)]
 pub use instructions::finalize_draw::*;
 #[allow(ambiguous_glob_reexports)]
+pub use instructions::free_quickpick_credit::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::gift_ticket::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::house_fee::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::identity::*;
+#[allow(ambiguous_glob_reexports)]
 pub use instructions::initialize::*;
 #[allow(ambiguous_glob_reexports)]
+pub use instructions::insurance_tier2::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::jackpot_guarantee::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::keeper::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::kyc::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::leaderboard::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::lifetime_value::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::lottery_stats::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::migration::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::milestone_bonus::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::multi_draw_ticket::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::number_bias::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::number_frequency::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::operator_profits::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::oracle_health::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::prize_escrow::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::prize_preview::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::program_upgrade::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::protocol_registry::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::recurring_ticket::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::referral::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::reserve_history::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::second_chance::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::spend_gate::*;
+#[allow(ambiguous_glob_reexports)]
 pub use instructions::syndicate::*;
 #[allow(ambiguous_glob_reexports)]
+pub use instructions::syndicate_registry::*;
+#[allow(ambiguous_glob_reexports)]
 pub use instructions::syndicate_wars::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::terms::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::vrf_audit::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::whitelist::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::winner_merkle::*;
 
 // Program ID - Update this after deployment
 declare_id!("7WyaHk2u8AgonsryMpnvbtp42CfLJFPQpyY5p9ys6FiF");
@@ -116,8 +196,8 @@ pub mod solana_lotto {
 
     /// Add funds to the reserve pool
     ///
-    /// Allows the authority to add additional funds to the reserve,
-    /// which can be used to seed jackpots after wins or rollovers.
+    /// Permissionless: anyone may add funds to the reserve, which can be
+    /// used to seed jackpots after wins or rollovers.
     ///
     /// # Arguments
     /// * `ctx` - AddReserveFunds accounts context
@@ -126,6 +206,67 @@ pub mod solana_lotto {
         instructions::initialize::handler_add_reserve_funds(ctx, amount)
     }
 
+    /// Add funds to the jackpot guarantee reserve
+    ///
+    /// Permissionless: anyone may back the guaranteed minimum jackpot that
+    /// finalize_draw tops up from this reserve.
+    ///
+    /// # Arguments
+    /// * `ctx` - FundJackpotGuarantee accounts context
+    /// * `amount` - Amount of USDC lamports to add
+    pub fn fund_jackpot_guarantee(ctx: Context<FundJackpotGuarantee>, amount: u64) -> Result<()> {
+        instructions::jackpot_guarantee::handler_fund_jackpot_guarantee(ctx, amount)
+    }
+
+    /// Withdraw funds from the jackpot guarantee reserve
+    ///
+    /// Authority only, and only while the lottery is paused.
+    ///
+    /// # Arguments
+    /// * `ctx` - WithdrawGuaranteeReserve accounts context
+    /// * `amount` - Amount of USDC lamports to withdraw
+    pub fn withdraw_guarantee_reserve(
+        ctx: Context<WithdrawGuaranteeReserve>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::jackpot_guarantee::handler_withdraw_guarantee_reserve(ctx, amount)
+    }
+
+    /// Deposit USDC into the reserve that pays milestone bonuses (authority only)
+    ///
+    /// # Arguments
+    /// * `ctx` - FundMilestonePrizePool accounts context
+    /// * `amount` - Amount of USDC lamports to deposit
+    pub fn fund_milestone_prize_pool(
+        ctx: Context<FundMilestonePrizePool>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::milestone_bonus::handler_fund_milestone_prize_pool(ctx, amount)
+    }
+
+    /// Claim an unlocked jackpot milestone bonus
+    ///
+    /// First come, first served: the first caller after the jackpot crosses
+    /// the milestone receives the bonus from the reserve.
+    ///
+    /// # Arguments
+    /// * `ctx` - ClaimMilestoneBonus accounts context
+    /// * `index` - Milestone index (0-4)
+    pub fn claim_milestone_bonus(ctx: Context<ClaimMilestoneBonus>, index: u8) -> Result<()> {
+        instructions::milestone_bonus::handler_claim_milestone_bonus(ctx, index)
+    }
+
+    /// Reseed the jackpot up to the seed amount from the reserve
+    ///
+    /// Permissionless and available while paused. Fails with
+    /// `InsufficientReserve` if the reserve cannot cover the deficit.
+    ///
+    /// # Arguments
+    /// * `ctx` - ReseedJackpot accounts context
+    pub fn reseed_jackpot(ctx: Context<ReseedJackpot>) -> Result<()> {
+        instructions::initialize::handler_reseed_jackpot(ctx)
+    }
+
     // =========================================================================
     // ADMIN INSTRUCTIONS
     // =========================================================================
@@ -214,16 +355,94 @@ pub mod solana_lotto {
         instructions::admin::handler_check_solvency(ctx)
     }
 
+    /// Emit a snapshot of lottery-wide statistics (permissionless)
+    ///
+    /// Limited to once per hour. Emits `LotteryStatsSnapshot`, including
+    /// the prize pool collateral ratio, for analytics indexers.
+    ///
+    /// # Arguments
+    /// * `ctx` - SnapshotLotteryStats accounts context
+    pub fn snapshot_lottery_stats(ctx: Context<SnapshotLotteryStats>) -> Result<()> {
+        instructions::lottery_stats::handler_snapshot_lottery_stats(ctx)
+    }
+
     /// Withdraw accumulated house fees
     ///
     /// Transfers house fees to a treasury or operator account.
-    /// Only the authority can withdraw.
+    /// Only the authority can withdraw, up to `daily_withdrawal_cap_bps` of
+    /// the account balance per day when a cap is set.
     ///
     /// # Arguments
     /// * `ctx` - WithdrawHouseFees accounts context
     /// * `amount` - Amount to withdraw in USDC lamports
-    pub fn withdraw_house_fees(ctx: Context<WithdrawHouseFees>, amount: u64) -> Result<()> {
-        instructions::admin::handler_withdraw_house_fees(ctx, amount)
+    /// * `recipient_index` - Fee recipient sub-account, or
+    ///   HOUSE_FEE_LEGACY_INDEX (255) for the original house fee account
+    pub fn withdraw_house_fees(
+        ctx: Context<WithdrawHouseFees>,
+        amount: u64,
+        recipient_index: u8,
+    ) -> Result<()> {
+        instructions::admin::handler_withdraw_house_fees(ctx, amount, recipient_index)
+    }
+
+    /// Propose the operator's share of distributed house fees
+    ///
+    /// Authority only. Starts the config timelock; apply it with
+    /// `execute_config` once the timelock expires.
+    ///
+    /// # Arguments
+    /// * `ctx` - SetOperatorProfitShare accounts context
+    /// * `bps` - Operator share in basis points (max 10000)
+    pub fn set_operator_profit_share(ctx: Context<SetOperatorProfitShare>, bps: u16) -> Result<()> {
+        instructions::operator_profits::handler_set_operator_profit_share(ctx, bps)
+    }
+
+    /// Split the house fee balance between the operator and the authority
+    ///
+    /// Authority only. The operator receives `operator_profit_share_bps`,
+    /// the authority the rest, within the daily withdrawal cap and with the
+    /// multisig cosigner above its threshold.
+    ///
+    /// # Arguments
+    /// * `ctx` - DistributeOperatorProfits accounts context
+    /// * `recipient_index` - Former recipient sub-account to distribute, or
+    ///   HOUSE_FEE_LEGACY_INDEX for the original house fee account
+    pub fn distribute_operator_profits(
+        ctx: Context<DistributeOperatorProfits>,
+        recipient_index: u8,
+    ) -> Result<()> {
+        instructions::operator_profits::handler_distribute_operator_profits(ctx, recipient_index)
+    }
+
+    /// Create the USDC sub-account for a house fee recipient index
+    ///
+    /// Authority only. Required for every index before a split using it
+    /// can be applied.
+    ///
+    /// # Arguments
+    /// * `ctx` - InitializeHouseFeeSubAccount accounts context
+    /// * `recipient_index` - Recipient index (0-3)
+    pub fn initialize_house_fee_sub_account(
+        ctx: Context<InitializeHouseFeeSubAccount>,
+        recipient_index: u8,
+    ) -> Result<()> {
+        instructions::house_fee::handler_initialize_house_fee_sub_account(ctx, recipient_index)
+    }
+
+    /// Propose or apply a house fee split across up to 4 recipients
+    ///
+    /// Authority only. The first call stages the split and starts the config
+    /// timelock; calling again with the same list after the timelock applies
+    /// it. Shares must sum to 10000 bps; an empty list disables splitting.
+    ///
+    /// # Arguments
+    /// * `ctx` - SetHouseFeeRecipients accounts context
+    /// * `recipients` - Recipient wallets and their shares in basis points
+    pub fn set_house_fee_recipients(
+        ctx: Context<SetHouseFeeRecipients>,
+        recipients: Vec<FeeRecipient>,
+    ) -> Result<()> {
+        instructions::house_fee::handler_set_house_fee_recipients(ctx, recipients)
     }
 
     /// Propose authority transfer (Step 1 of 2)
@@ -261,17 +480,142 @@ pub mod solana_lotto {
         instructions::admin::handler_cancel_authority_transfer(ctx)
     }
 
+    /// Set or clear the draw operator
+    ///
+    /// Delegates `commit_randomness` / `execute_draw` to an operator key
+    /// according to the permission bitmask. Only the authority can call this.
+    ///
+    /// # Arguments
+    /// * `ctx` - SetOperator accounts context
+    /// * `operator` - Operator address, or `None` to remove it
+    /// * `permissions` - `OPERATOR_PERMISSION_*` bitmask
+    pub fn set_operator(
+        ctx: Context<SetOperator>,
+        operator: Option<Pubkey>,
+        permissions: u64,
+    ) -> Result<()> {
+        instructions::admin::handler_set_operator(ctx, operator, permissions)
+    }
+
+    /// Propose or apply a multisig cosigner change
+    ///
+    /// The first call starts a 48-hour timelock; calling again with the same
+    /// value afterwards applies it. While a cosigner is set, PrizePool
+    /// emergency fund transfers need both the authority and the cosigner.
+    /// Only the authority can call this.
+    ///
+    /// # Arguments
+    /// * `ctx` - SetMultisigCosigner accounts context
+    /// * `cosigner` - Cosigner address, or `None` to remove it
+    pub fn set_multisig_cosigner(
+        ctx: Context<SetMultisigCosigner>,
+        cosigner: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::admin::handler_set_multisig_cosigner(ctx, cosigner)
+    }
+
+    /// Propose or run a fixed prize balance reconciliation
+    ///
+    /// Requires the lottery to be paused. The first call starts a 24-hour
+    /// timelock; calling again afterwards resets `fixed_prize_balance` to the
+    /// value implied by lifetime sales and payouts, offsetting the reserve.
+    /// Only the authority can call this.
+    ///
+    /// # Arguments
+    /// * `ctx` - ReconcilePrizePool accounts context
+    pub fn reconcile_prize_pool_accounting(ctx: Context<ReconcilePrizePool>) -> Result<()> {
+        instructions::admin::handler_reconcile_prize_pool_accounting(ctx)
+    }
+
+    /// Migrate the lottery state to the current schema version
+    ///
+    /// Initializes any fields added since the account's `schema_version`
+    /// and bumps it to CURRENT_SCHEMA_VERSION. Accounts written before
+    /// versioning are read in their original layout and reallocated.
+    /// Every other instruction rejects out-of-date accounts. Only the
+    /// authority can call this.
+    ///
+    /// # Arguments
+    /// * `ctx` - MigrateLotteryState accounts context
+    pub fn migrate_lottery_state(ctx: Context<MigrateLotteryState>) -> Result<()> {
+        instructions::migration::handler_migrate_lottery_state(ctx)
+    }
+
+    /// Migrate a draw result, ticket, user stats or syndicate account to
+    /// the current schema version
+    ///
+    /// Same as `migrate_lottery_state` for the per-draw and per-user
+    /// accounts. Anyone can call this and pays any extra rent.
+    ///
+    /// # Arguments
+    /// * `ctx` - MigrateAccount accounts context
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migration::handler_migrate_account(ctx)
+    }
+
     /// Cancel a stuck draw (timeout recovery)
     ///
     /// Allows the authority to cancel a draw that has timed out
     /// (more than 1 hour since randomness commit). This is a recovery
     /// mechanism for when the oracle fails or network congestion prevents
-    /// execute_draw from being called.
+    /// execute_draw from being called. `PreserveTickets` reschedules the
+    /// same draw; `RefundTickets` abandons it and opens ticket refunds.
     ///
     /// # Arguments
     /// * `ctx` - CancelDraw accounts context
-    pub fn cancel_draw(ctx: Context<CancelDraw>) -> Result<()> {
-        instructions::admin::handler_cancel_draw(ctx)
+    /// * `mode` - Whether to carry the tickets over or refund them
+    pub fn cancel_draw(ctx: Context<CancelDraw>, mode: CancelDrawMode) -> Result<()> {
+        instructions::admin::handler_cancel_draw(ctx, mode)
+    }
+
+    /// Refund a ticket of a draw cancelled with `RefundTickets`
+    ///
+    /// Permissionless. Pays TICKET_PRICE from the reserve to the ticket
+    /// owner and clears the ticket's `requires_refund` flag.
+    ///
+    /// # Arguments
+    /// * `ctx` - ClaimCancelledDrawRefund accounts context
+    pub fn claim_cancelled_draw_refund(ctx: Context<ClaimCancelledDrawRefund>) -> Result<()> {
+        instructions::draw_refund::handler_claim_cancelled_draw_refund(ctx)
+    }
+
+    /// Refund a bulk ticket account of a draw cancelled with `RefundTickets`
+    ///
+    /// Permissionless. Pays TICKET_PRICE per unclaimed ticket from the
+    /// reserve to the owner and marks every ticket claimed.
+    ///
+    /// # Arguments
+    /// * `ctx` - ClaimCancelledDrawBulkRefund accounts context
+    pub fn claim_cancelled_draw_bulk_refund(
+        ctx: Context<ClaimCancelledDrawBulkRefund>,
+    ) -> Result<()> {
+        instructions::draw_refund::handler_claim_cancelled_draw_bulk_refund(ctx)
+    }
+
+    /// Refund a multi-draw ticket whose first draw was cancelled with
+    /// `RefundTickets`
+    ///
+    /// Permissionless. Pays TICKET_PRICE from the reserve to the owner and
+    /// marks that draw claimed.
+    ///
+    /// # Arguments
+    /// * `ctx` - ClaimCancelledDrawMultiDrawRefund accounts context
+    pub fn claim_cancelled_draw_multi_draw_refund(
+        ctx: Context<ClaimCancelledDrawMultiDrawRefund>,
+    ) -> Result<()> {
+        instructions::draw_refund::handler_claim_cancelled_draw_multi_draw_refund(ctx)
+    }
+
+    /// Postpone the current draw when the minimum ticket count is not met
+    ///
+    /// Advances next_draw_timestamp without changing current_draw_id,
+    /// so all purchased tickets remain valid for the postponed draw.
+    ///
+    /// # Arguments
+    /// * `ctx` - PostponeDraw accounts context
+    /// * `extension_seconds` - Delay to add (must be > 0 and <= draw_interval)
+    pub fn postpone_draw(ctx: Context<PostponeDraw>, extension_seconds: i64) -> Result<()> {
+        instructions::admin::handler_postpone_draw(ctx, extension_seconds)
     }
 
     /// Force finalize a draw (emergency only)
@@ -283,6 +627,9 @@ pub mod solana_lotto {
     /// WARNING: Tickets will not receive prizes. Users may need
     /// off-chain compensation.
     ///
+    /// Anyone may call once FINALIZE_AUTO_FORCE_SECONDS have passed since
+    /// `execute_draw`, earning `force_finalize_reward_lamports`.
+    ///
     /// # Arguments
     /// * `ctx` - ForceFinalizeDraw accounts context
     /// * `reason` - Reason for the force finalization (logged)
@@ -290,6 +637,186 @@ pub mod solana_lotto {
         instructions::admin::handler_force_finalize_draw(ctx, reason)
     }
 
+    /// Re-derive a draw's winning numbers from its stored VRF bytes
+    ///
+    /// Permissionless. Emits `VrfAuditPassed` on a match; on a mismatch
+    /// emits `VrfAuditFailed` and pauses the lottery.
+    ///
+    /// # Arguments
+    /// * `ctx` - AuditVrfResult accounts context
+    /// * `draw_id` - Draw to audit
+    pub fn audit_vrf_result(ctx: Context<AuditVrfResult>, draw_id: u64) -> Result<()> {
+        instructions::vrf_audit::handler_audit_vrf_result(ctx, draw_id)
+    }
+
+    /// Announce the SHA256 of the next program bytecode
+    ///
+    /// Authority only. The upgrade should not be deployed until
+    /// UPGRADE_DELAY_SLOTS after the announcement.
+    ///
+    /// # Arguments
+    /// * `ctx` - AnnounceProgramUpgrade accounts context
+    /// * `new_bytecode_hash` - SHA256 of the new program bytecode
+    pub fn announce_program_upgrade(
+        ctx: Context<AnnounceProgramUpgrade>,
+        new_bytecode_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::program_upgrade::handler_announce_program_upgrade(ctx, new_bytecode_hash)
+    }
+
+    /// Cancel the pending program upgrade announcement
+    ///
+    /// # Arguments
+    /// * `ctx` - ClearUpgradeAnnouncement accounts context
+    pub fn clear_upgrade_announcement(ctx: Context<ClearUpgradeAnnouncement>) -> Result<()> {
+        instructions::program_upgrade::handler_clear_upgrade_announcement(ctx)
+    }
+
+    /// Check the deployed bytecode against the announced upgrade hash
+    ///
+    /// Permissionless. A match completes the announcement.
+    ///
+    /// # Arguments
+    /// * `ctx` - VerifyUpgradeHash accounts context
+    pub fn verify_upgrade_hash(ctx: Context<VerifyUpgradeHash>) -> Result<()> {
+        instructions::program_upgrade::handler_verify_upgrade_hash(ctx)
+    }
+
+    /// Check the committed Switchboard randomness account for signs of an
+    /// unhealthy oracle
+    ///
+    /// Permissionless. Emits `OracleHealthReport`; when a check fails, also
+    /// emits `OracleHealthAlert` and pauses the lottery after
+    /// `max_oracle_failures` consecutive failures.
+    ///
+    /// # Arguments
+    /// * `ctx` - CheckOracleHealth accounts context
+    pub fn check_oracle_health(ctx: Context<CheckOracleHealth>) -> Result<()> {
+        instructions::oracle_health::handler_check_oracle_health(ctx)
+    }
+
+    /// Dispute a finalized draw's winner count for one prize tier
+    ///
+    /// Callable by anyone within CHALLENGE_WINDOW_SECONDS of finalization,
+    /// once per wallet per draw. CHALLENGE_THRESHOLD challenges pause the
+    /// lottery and freeze the draw's prize claims pending review.
+    ///
+    /// # Arguments
+    /// * `ctx` - ChallengeWinnerCount accounts context
+    /// * `draw_id` - Challenged draw
+    /// * `evidence_hash` - Hash of the off-chain evidence
+    /// * `disputed_tier` - Match tier whose winner count is disputed (2-6)
+    pub fn challenge_winner_count(
+        ctx: Context<ChallengeWinnerCount>,
+        draw_id: u64,
+        evidence_hash: [u8; 32],
+        disputed_tier: u8,
+    ) -> Result<()> {
+        instructions::draw_challenge::handler_challenge_winner_count(
+            ctx,
+            draw_id,
+            evidence_hash,
+            disputed_tier,
+        )
+    }
+
+    /// Uphold or reject a draw under challenge review (authority only)
+    ///
+    /// # Arguments
+    /// * `ctx` - ResolveDrawChallenge accounts context
+    /// * `draw_id` - Draw under review
+    /// * `accept_challenge` - Whether the challenge is upheld
+    pub fn resolve_draw_challenge(
+        ctx: Context<ResolveDrawChallenge>,
+        draw_id: u64,
+        accept_challenge: bool,
+    ) -> Result<()> {
+        instructions::draw_challenge::handler_resolve_draw_challenge(ctx, draw_id, accept_challenge)
+    }
+
+    /// Correct the winner counts of a draw whose challenge was upheld
+    /// (authority only)
+    ///
+    /// Re-splits each tier's prize across the corrected counts and
+    /// unfreezes the draw's prize claims.
+    ///
+    /// # Arguments
+    /// * `ctx` - CorrectChallengedWinnerCounts accounts context
+    /// * `draw_id` - Draw whose challenge was upheld
+    /// * `winner_counts` - Corrected number of winners in each tier
+    pub fn correct_challenged_winner_counts(
+        ctx: Context<CorrectChallengedWinnerCounts>,
+        draw_id: u64,
+        winner_counts: WinnerCounts,
+    ) -> Result<()> {
+        instructions::draw_challenge::handler_correct_challenged_winner_counts(
+            ctx,
+            draw_id,
+            winner_counts,
+        )
+    }
+
+    /// Propose replacing an unfinalized draw's winning numbers (authority only)
+    ///
+    /// For recovering from a VRF interpretation bug. The lottery must be
+    /// paused; the override can be applied after
+    /// WINNING_NUMBERS_OVERRIDE_DELAY.
+    ///
+    /// # Arguments
+    /// * `ctx` - ProposeWinningNumbersOverride accounts context
+    /// * `draw_id` - Draw whose numbers are replaced
+    /// * `new_numbers` - Replacement winning numbers
+    /// * `justification_hash` - SHA256 of the public explanation document
+    pub fn propose_winning_numbers_override(
+        ctx: Context<ProposeWinningNumbersOverride>,
+        draw_id: u64,
+        new_numbers: [u8; NUMBERS_PER_TICKET],
+        justification_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::draw_override::handler_propose_winning_numbers_override(
+            ctx,
+            draw_id,
+            new_numbers,
+            justification_hash,
+        )
+    }
+
+    /// Apply a proposed winning numbers override (authority only)
+    ///
+    /// Only valid while paused, after the timelock, and before
+    /// `finalize_draw`.
+    ///
+    /// # Arguments
+    /// * `ctx` - OverrideWinningNumbers accounts context
+    /// * `draw_id` - Draw whose numbers are replaced
+    /// * `new_numbers` - Replacement winning numbers (as proposed)
+    /// * `justification_hash` - SHA256 of the public explanation document (as proposed)
+    pub fn override_winning_numbers(
+        ctx: Context<OverrideWinningNumbers>,
+        draw_id: u64,
+        new_numbers: [u8; NUMBERS_PER_TICKET],
+        justification_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::draw_override::handler_override_winning_numbers(
+            ctx,
+            draw_id,
+            new_numbers,
+            justification_hash,
+        )
+    }
+
+    /// Cancel a pending winning numbers override (authority only)
+    ///
+    /// # Arguments
+    /// * `ctx` - CancelWinningNumbersOverride accounts context
+    /// * `draw_id` - Draw of the pending override
+    pub fn cancel_winning_numbers_override(
+        ctx: Context<CancelWinningNumbersOverride>,
+        draw_id: u64,
+    ) -> Result<()> {
+        instructions::draw_override::handler_cancel_winning_numbers_override(ctx, draw_id)
+    }
+
     /// Transfer authority (DEPRECATED - use propose_authority + accept_authority)
     ///
     /// Legacy single-step authority transfer. Now only sets pending_authority
@@ -329,6 +856,72 @@ pub mod solana_lotto {
         instructions::admin::handler_emergency_fund_transfer(ctx, source, amount, reason)
     }
 
+    /// Request moving insurance tier 2 funds to tier 1 (authority only)
+    ///
+    /// The transfer can be executed after INSURANCE_TIER2_WITHDRAWAL_DELAY.
+    /// Only one request can be pending at a time.
+    ///
+    /// # Arguments
+    /// * `ctx` - RequestTier2Withdrawal accounts context
+    /// * `amount` - Amount to move in USDC lamports
+    pub fn request_tier2_withdrawal(
+        ctx: Context<RequestTier2Withdrawal>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::insurance_tier2::handler_request_tier2_withdrawal(ctx, amount)
+    }
+
+    /// Execute a pending insurance tier 2 withdrawal (authority only)
+    ///
+    /// Moves the requested amount from tier 2 to tier 1 once the delay has
+    /// elapsed and closes the request.
+    ///
+    /// # Arguments
+    /// * `ctx` - ExecuteTier2Withdrawal accounts context
+    pub fn execute_tier2_withdrawal(ctx: Context<ExecuteTier2Withdrawal>) -> Result<()> {
+        instructions::insurance_tier2::handler_execute_tier2_withdrawal(ctx)
+    }
+
+    /// Register a Mazel Protocol program in the protocol registry (authority only)
+    ///
+    /// Creates the registry on first use. Re-registering a listed program
+    /// reactivates it under the next version.
+    ///
+    /// # Arguments
+    /// * `ctx` - RegisterProgram accounts context
+    /// * `program_id` - Program to register
+    /// * `program_type` - Kind of program
+    pub fn register_program(
+        ctx: Context<RegisterProgram>,
+        program_id: Pubkey,
+        program_type: ProgramType,
+    ) -> Result<()> {
+        instructions::protocol_registry::handler_register_program(ctx, program_id, program_type)
+    }
+
+    /// Mark a registered program as out of service (authority only)
+    ///
+    /// # Arguments
+    /// * `ctx` - DeactivateProgram accounts context
+    /// * `program_id` - Registered program to deactivate
+    pub fn deactivate_program(ctx: Context<DeactivateProgram>, program_id: Pubkey) -> Result<()> {
+        instructions::protocol_registry::handler_deactivate_program(ctx, program_id)
+    }
+
+    /// Emit a program's protocol registry status
+    ///
+    /// Permissionless and read-only. Emits `ProgramVerified`.
+    ///
+    /// # Arguments
+    /// * `ctx` - VerifyProgramRegistered accounts context
+    /// * `program_id` - Program to look up
+    pub fn verify_program_registered(
+        ctx: Context<VerifyProgramRegistered>,
+        program_id: Pubkey,
+    ) -> Result<()> {
+        instructions::protocol_registry::handler_verify_program_registered(ctx, program_id)
+    }
+
     /// Reclaim expired/unclaimed prize funds from a past draw
     ///
     /// Sweeps unclaimed committed prizes back into reserve_balance after
@@ -359,13 +952,348 @@ pub mod solana_lotto {
     /// Numbers must be unique and within valid range.
     /// USDC is transferred from player to prize pool and house fee accounts.
     ///
-    /// Enforces per-user ticket limits (max 5000 per draw).
+    /// Enforces per-user ticket limits (max 5000 per draw).
+    ///
+    /// # Arguments
+    /// * `ctx` - BuyTicket accounts context
+    /// * `params` - Selected numbers
+    pub fn buy_ticket<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuyTicket<'info>>,
+        params: BuyTicketParams,
+    ) -> Result<()> {
+        instructions::buy_ticket::handler(ctx, params)
+    }
+
+    /// Buy a single lottery ticket with on-chain derived numbers
+    ///
+    /// Derives 6 unique numbers from SHA256(player, slot, timestamp,
+    /// total tickets sold) using rejection sampling, then purchases the
+    /// ticket exactly like `buy_ticket`. Emits `LuckyDipGenerated` so the
+    /// derivation can be verified.
+    ///
+    /// # Arguments
+    /// * `ctx` - BuyLuckyDip accounts context
+    /// * `max_price_usdc` - Highest ticket price the player accepts
+    pub fn buy_lucky_dip<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuyLuckyDip<'info>>,
+        max_price_usdc: u64,
+    ) -> Result<()> {
+        instructions::buy_ticket::handler_buy_lucky_dip(ctx, max_price_usdc)
+    }
+
+    /// Gift an unclaimed ticket to another wallet
+    ///
+    /// Transfers ownership of the ticket to `recipient` before the draw is
+    /// finalized, as recorded on the draw's DrawResult. The original
+    /// purchaser is preserved in `original_owner`.
+    ///
+    /// # Arguments
+    /// * `ctx` - GiftTicket accounts context
+    /// * `recipient` - Wallet receiving the ticket
+    pub fn gift_ticket(ctx: Context<GiftTicket>, recipient: Pubkey) -> Result<()> {
+        instructions::gift_ticket::handler_gift_ticket(ctx, recipient)
+    }
+
+    /// Gift an unclaimed unified (bulk) ticket to another wallet
+    ///
+    /// All tickets in the unified account are transferred together.
+    ///
+    /// # Arguments
+    /// * `ctx` - GiftTicketBatch accounts context
+    /// * `recipient` - Wallet receiving the tickets
+    pub fn gift_ticket_batch(ctx: Context<GiftTicketBatch>, recipient: Pubkey) -> Result<()> {
+        instructions::gift_ticket::handler_gift_ticket_batch(ctx, recipient)
+    }
+
+    /// Close a ticket account and return its rent to the owner
+    ///
+    /// Allowed once the draw is finalized and the ticket is claimed or its
+    /// claim window has expired. Non-owners may close expired tickets when
+    /// `allow_third_party_closure` is enabled.
+    ///
+    /// # Arguments
+    /// * `ctx` - CloseTicket accounts context
+    pub fn close_ticket(ctx: Context<CloseTicket>) -> Result<()> {
+        instructions::close_ticket::handler_close_ticket(ctx)
+    }
+
+    /// Close a unified (bulk) ticket account and return its rent to the owner
+    ///
+    /// Every ticket in the account must be claimed, or the claim window
+    /// must have expired.
+    ///
+    /// # Arguments
+    /// * `ctx` - CloseTicketBatch accounts context
+    pub fn close_ticket_batch(ctx: Context<CloseTicketBatch>) -> Result<()> {
+        instructions::close_ticket::handler_close_ticket_batch(ctx)
+    }
+
+    /// Close up to 20 expired, unclaimed ticket accounts in one call
+    ///
+    /// Permissionless. Remaining accounts are (ticket, draw result) pairs.
+    /// The caller receives the recovered rent plus `cleanup_reward_lamports`
+    /// per closed account.
+    ///
+    /// # Arguments
+    /// * `ctx` - CleanupExpiredTickets accounts context
+    pub fn cleanup_expired_tickets<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CleanupExpiredTickets<'info>>,
+    ) -> Result<()> {
+        instructions::close_ticket::handler_cleanup_expired_tickets(ctx)
+    }
+
+    /// Close up to 20 expired unified ticket accounts in one call
+    ///
+    /// Permissionless. Remaining accounts are (unified ticket, draw result)
+    /// pairs. Rewards are paid as for `cleanup_expired_tickets`.
+    ///
+    /// # Arguments
+    /// * `ctx` - CleanupExpiredTicketBatches accounts context
+    pub fn cleanup_expired_ticket_batches<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CleanupExpiredTicketBatches<'info>>,
+    ) -> Result<()> {
+        instructions::close_ticket::handler_cleanup_expired_ticket_batches(ctx)
+    }
+
+    /// Register the wallet that referred the player
+    ///
+    /// Can only be set once, before the player's qualifying purchase.
+    /// The referrer earns one credit when the player reaches
+    /// REFERRAL_QUALIFYING_TICKETS lifetime tickets.
+    ///
+    /// # Arguments
+    /// * `ctx` - RegisterReferral accounts context
+    /// * `referrer` - Wallet of the referring player (must have UserStats)
+    pub fn register_referral(ctx: Context<RegisterReferral>, referrer: Pubkey) -> Result<()> {
+        instructions::referral::handler_register_referral(ctx, referrer)
+    }
+
+    /// Redeem one referral credit for a ticket in the current draw
+    ///
+    /// No USDC is transferred; the ticket price is funded from the reserve.
+    ///
+    /// # Arguments
+    /// * `ctx` - RedeemReferralCredit accounts context
+    /// * `numbers` - 6 numbers, each between 1 and 46
+    /// * `max_price_usdc` - Highest ticket price the credit may be redeemed at
+    pub fn redeem_referral_credit(
+        ctx: Context<RedeemReferralCredit>,
+        numbers: [u8; NUMBERS_PER_TICKET],
+        max_price_usdc: u64,
+    ) -> Result<()> {
+        instructions::referral::handler_redeem_referral_credit(ctx, numbers, max_price_usdc)
+    }
+
+    /// Lower the player's own daily ticket limit (self-exclusion)
+    ///
+    /// Limits can only be lowered, never raised. Enforced on `buy_ticket`
+    /// and `buy_bulk` while the lottery-wide daily limit is enabled.
+    ///
+    /// # Arguments
+    /// * `ctx` - SetPersonalDailyLimit accounts context
+    /// * `limit` - New daily ticket limit (0 blocks all purchases)
+    pub fn set_personal_daily_limit(ctx: Context<SetPersonalDailyLimit>, limit: u32) -> Result<()> {
+        instructions::daily_limit::handler_set_personal_daily_limit(ctx, limit)
+    }
+
+    /// Block the player's own ticket purchases for a fixed period
+    ///
+    /// Exclusions can only be extended, never shortened, and the authority
+    /// cannot lift them. Prize claims stay open while excluded.
+    ///
+    /// # Arguments
+    /// * `ctx` - SelfExclude accounts context
+    /// * `duration_seconds` - Exclusion length (1 day to 1 year)
+    pub fn self_exclude(ctx: Context<SelfExclude>, duration_seconds: i64) -> Result<()> {
+        instructions::daily_limit::handler_self_exclude(ctx, duration_seconds)
+    }
+
+    /// Restrict ticket purchases to whitelisted wallets or gate token
+    /// holders (authority only)
+    ///
+    /// Creates the whitelist on first use.
+    ///
+    /// # Arguments
+    /// * `ctx` - SetWhitelistMode accounts context
+    /// * `mode` - PlayerWhitelist or TokenGated
+    /// * `gate_mint` - Mint required in TokenGated mode
+    pub fn set_whitelist_mode(
+        ctx: Context<SetWhitelistMode>,
+        mode: WhitelistMode,
+        gate_mint: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::whitelist::handler_set_whitelist_mode(ctx, mode, gate_mint)
+    }
+
+    /// Add wallets to the purchase whitelist (authority only)
+    ///
+    /// # Arguments
+    /// * `ctx` - AddToWhitelist accounts context
+    /// * `wallets` - Wallets to whitelist (up to MAX_WHITELIST_WALLETS in total)
+    pub fn add_to_whitelist(ctx: Context<AddToWhitelist>, wallets: Vec<Pubkey>) -> Result<()> {
+        instructions::whitelist::handler_add_to_whitelist(ctx, wallets)
+    }
+
+    /// Remove wallets from the purchase whitelist (authority only)
+    ///
+    /// # Arguments
+    /// * `ctx` - RemoveFromWhitelist accounts context
+    /// * `wallets` - Wallets to remove
+    pub fn remove_from_whitelist(
+        ctx: Context<RemoveFromWhitelist>,
+        wallets: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::whitelist::handler_remove_from_whitelist(ctx, wallets)
+    }
+
+    /// Reopen ticket purchases to every wallet (authority only)
+    ///
+    /// # Arguments
+    /// * `ctx` - DisableWhitelist accounts context
+    pub fn disable_whitelist(ctx: Context<DisableWhitelist>) -> Result<()> {
+        instructions::whitelist::handler_disable_whitelist(ctx)
+    }
+
+    /// Approve a player for KYC-gated purchases (KYC authority only)
+    ///
+    /// Creates the player's KYC record on first approval and renews it
+    /// afterwards.
+    ///
+    /// # Arguments
+    /// * `ctx` - ApproveKYC accounts context
+    /// * `player` - Player being approved
+    /// * `jurisdiction` - ISO 3166-1 alpha-2 country code of the player
+    /// * `expires_at` - When the approval lapses
+    pub fn approve_kyc(
+        ctx: Context<ApproveKYC>,
+        player: Pubkey,
+        jurisdiction: [u8; 2],
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::kyc::handler_approve_kyc(ctx, player, jurisdiction, expires_at)
+    }
+
+    /// Revoke a player's KYC approval (KYC authority only)
+    ///
+    /// # Arguments
+    /// * `ctx` - RevokeKYC accounts context
+    /// * `player` - Player being revoked
+    /// * `reason` - Why the approval is revoked
+    pub fn revoke_kyc(ctx: Context<RevokeKYC>, player: Pubkey, reason: String) -> Result<()> {
+        instructions::kyc::handler_revoke_kyc(ctx, player, reason)
+    }
+
+    /// Issue or renew a player's identity proof (identity oracle only)
+    ///
+    /// # Arguments
+    /// * `ctx` - IssueIdentityProof accounts context
+    /// * `player` - Verified player
+    /// * `jurisdiction` - ISO 3166-1 alpha-2 country code of the player
+    /// * `expires_at` - When the proof lapses
+    pub fn issue_identity_proof(
+        ctx: Context<IssueIdentityProof>,
+        player: Pubkey,
+        jurisdiction: [u8; 2],
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::identity::handler_issue_identity_proof(ctx, player, jurisdiction, expires_at)
+    }
+
+    /// Accept the current lottery terms version
+    ///
+    /// Required before buying or redeeming tickets whenever the authority
+    /// publishes a new version.
+    ///
+    /// # Arguments
+    /// * `ctx` - AcceptTerms accounts context
+    pub fn accept_terms(ctx: Context<AcceptTerms>) -> Result<()> {
+        instructions::terms::handler_accept_terms(ctx)
+    }
+
+    /// Publish a new lottery terms version (authority only)
+    ///
+    /// # Arguments
+    /// * `ctx` - UpdateTerms accounts context
+    /// * `new_uri` - Location of the new terms (e.g. an IPFS link)
+    pub fn update_terms(ctx: Context<UpdateTerms>, new_uri: String) -> Result<()> {
+        instructions::terms::handler_update_terms(ctx, new_uri)
+    }
+
+    /// Check that the player has met the QuickPick spend gate
+    ///
+    /// Read-only and CPI-callable: succeeds only if the player's lifetime
+    /// `total_spent` is at least `quickpick_spend_gate_threshold`.
+    ///
+    /// # Arguments
+    /// * `ctx` - VerifySpendGate accounts context
+    pub fn verify_spend_gate(ctx: Context<VerifySpendGate>) -> Result<()> {
+        instructions::spend_gate::handler_verify_spend_gate(ctx)
+    }
+
+    /// Allocate reserve funds to a draw's second chance prize
+    ///
+    /// Authority only. Creates the second chance draw on first funding.
+    ///
+    /// # Arguments
+    /// * `ctx` - AddSecondChancePrize accounts context
+    /// * `draw_id` - Main draw the second chance draw belongs to
+    /// * `amount` - USDC lamports moved from the reserve to the prize
+    pub fn add_second_chance_prize(
+        ctx: Context<AddSecondChancePrize>,
+        draw_id: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::second_chance::handler_add_second_chance_prize(ctx, draw_id, amount)
+    }
+
+    /// Enter a non-winning ticket into its draw's second chance draw
+    ///
+    /// The draw must be finalized and the ticket must have fewer than 2
+    /// matches. Each ticket can be entered once.
+    ///
+    /// # Arguments
+    /// * `ctx` - EnterSecondChance accounts context
+    pub fn enter_second_chance(ctx: Context<EnterSecondChance>) -> Result<()> {
+        instructions::second_chance::handler_enter_second_chance(ctx)
+    }
+
+    /// Commit a second chance draw to unrevealed Switchboard randomness
+    ///
+    /// Authority only. The randomness account must be on the lottery's queue.
+    /// Stores the account and its seed slot and closes entries.
+    ///
+    /// # Arguments
+    /// * `ctx` - CommitSecondChanceRandomness accounts context
+    pub fn commit_second_chance_randomness(
+        ctx: Context<CommitSecondChanceRandomness>,
+    ) -> Result<()> {
+        instructions::second_chance::handler_commit_second_chance_randomness(ctx)
+    }
+
+    /// Draw the second chance winner with Switchboard randomness and pay the prize
+    ///
+    /// Authority only. Uses the reveal of the committed randomness account.
+    /// Remaining accounts: the winning SecondChanceEntry, then the winner's
+    /// USDC token account.
+    ///
+    /// # Arguments
+    /// * `ctx` - ExecuteSecondChanceDraw accounts context
+    pub fn execute_second_chance_draw<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteSecondChanceDraw<'info>>,
+    ) -> Result<()> {
+        instructions::second_chance::handler_execute_second_chance_draw(ctx)
+    }
+
+    /// Return an unresolved second chance prize to the reserve
+    ///
+    /// Authority only. Allowed for a draw nobody entered, or once the claim
+    /// window of its main draw has expired (pass `draw_result`). Marks the
+    /// draw resolved without a winner.
     ///
     /// # Arguments
-    /// * `ctx` - BuyTicket accounts context
-    /// * `params` - Selected numbers
-    pub fn buy_ticket(ctx: Context<BuyTicket>, params: BuyTicketParams) -> Result<()> {
-        instructions::buy_ticket::handler(ctx, params)
+    /// * `ctx` - ReclaimSecondChancePrize accounts context
+    pub fn reclaim_second_chance_prize(ctx: Context<ReclaimSecondChancePrize>) -> Result<()> {
+        instructions::second_chance::handler_reclaim_second_chance_prize(ctx)
     }
 
     /// Buy multiple lottery tickets in a single transaction
@@ -380,10 +1308,54 @@ pub mod solana_lotto {
     /// # Arguments
     /// * `ctx` - BuyBulk accounts context
     /// * `params` - Array of ticket number sets (max 50 tickets)
-    pub fn buy_bulk(ctx: Context<BuyBulk>, params: BuyBulkParams) -> Result<()> {
+    pub fn buy_bulk<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuyBulk<'info>>,
+        params: BuyBulkParams,
+    ) -> Result<()> {
         instructions::buy_bulk::handler(ctx, params)
     }
 
+    /// Buy one ticket valid for several consecutive draws
+    ///
+    /// Covers `draw_count` draws (2-52) starting with the current draw, for
+    /// the effective ticket price times `draw_count`. Prizes are claimed per
+    /// draw with `claim_multi_draw_ticket_prize`.
+    ///
+    /// # Arguments
+    /// * `ctx` - BuyMultiDrawTicket accounts context
+    /// * `numbers` - 6 numbers, each between 1 and 46
+    /// * `draw_count` - Number of consecutive draws covered
+    /// * `max_price_usdc` - Highest per-draw ticket price the player accepts
+    pub fn buy_multi_draw_ticket<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuyMultiDrawTicket<'info>>,
+        numbers: [u8; NUMBERS_PER_TICKET],
+        draw_count: u8,
+        max_price_usdc: u64,
+    ) -> Result<()> {
+        instructions::multi_draw_ticket::handler(ctx, numbers, draw_count, max_price_usdc)
+    }
+
+    /// Buy the same numbers for up to 10 consecutive future draws
+    ///
+    /// Creates one ticket per draw, each claimed with `claim_prize` and
+    /// counted in its draw's tickets once that draw opens. The
+    /// uncreated ticket PDAs are passed as `remaining_accounts` in draw order,
+    /// followed by any house fee recipient sub-accounts.
+    ///
+    /// # Arguments
+    /// * `ctx` - BuyRecurringTicket accounts context
+    /// * `numbers` - 6 numbers, each between 1 and 46
+    /// * `future_draw_ids` - Consecutive future draws to play
+    /// * `max_price_usdc` - Highest per-draw ticket price the player accepts
+    pub fn buy_recurring_ticket<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuyRecurringTicket<'info>>,
+        numbers: [u8; NUMBERS_PER_TICKET],
+        future_draw_ids: Vec<u64>,
+        max_price_usdc: u64,
+    ) -> Result<()> {
+        instructions::recurring_ticket::handler(ctx, numbers, future_draw_ids, max_price_usdc)
+    }
+
     // =========================================================================
     // DRAW INSTRUCTIONS
     // =========================================================================
@@ -398,6 +1370,7 @@ pub mod solana_lotto {
     /// - Must be called BEFORE randomness is revealed
     /// - Stores seed_slot and timestamp for verification during reveal
     /// - Marks draw as in progress
+    /// - Requires at least min_tickets_per_draw tickets (see postpone_draw)
     ///
     /// # Timeout
     /// - If execute_draw is not called within 1 hour, the draw can be
@@ -409,6 +1382,69 @@ pub mod solana_lotto {
         instructions::commit_randomness::handler(ctx)
     }
 
+    /// Check a draw's commitment hash against an expected value
+    ///
+    /// Permissionless and read-only. Emits `DrawCommitmentVerified` with
+    /// the stored and expected hashes and whether they match.
+    ///
+    /// # Arguments
+    /// * `ctx` - VerifyDrawCommitment accounts context
+    /// * `draw_id` - Draw to check
+    /// * `expected_hash` - Hash from `compute_draw_commit_hash`
+    pub fn verify_draw_commitment(
+        ctx: Context<VerifyDrawCommitment>,
+        draw_id: u64,
+        expected_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::commit_randomness::handler_verify_draw_commitment(ctx, draw_id, expected_hash)
+    }
+
+    /// Commit randomness for an overdue draw as a keeper
+    ///
+    /// Permissionless once `keeper_grace_period_seconds` have passed after
+    /// the scheduled draw time. Runs the same logic as `commit_randomness` and
+    /// pays the caller `keeper_reward_lamports` when the reserve allows.
+    ///
+    /// # Arguments
+    /// * `ctx` - TriggerDrawIfReady accounts context
+    pub fn trigger_draw_if_ready(ctx: Context<TriggerDrawIfReady>) -> Result<()> {
+        instructions::keeper::handler_trigger_draw_if_ready(ctx)
+    }
+
+    /// Commit randomness for an overdue draw as a keeper, without a reward
+    ///
+    /// Permissionless once `keeper_grace_period_seconds` have passed after
+    /// the scheduled draw time. Runs the same logic as `commit_randomness`
+    /// and records the caller in `last_keeper`.
+    ///
+    /// # Arguments
+    /// * `ctx` - KeeperCommitRandomness accounts context
+    pub fn keeper_commit_randomness(ctx: Context<KeeperCommitRandomness>) -> Result<()> {
+        instructions::keeper::handler_keeper_commit_randomness(ctx)
+    }
+
+    /// Top up the SOL balance used for keeper rewards
+    ///
+    /// Authority only.
+    ///
+    /// # Arguments
+    /// * `ctx` - FundKeeperReserve accounts context
+    /// * `amount` - Lamports to add
+    pub fn fund_keeper_reserve(ctx: Context<FundKeeperReserve>, amount: u64) -> Result<()> {
+        instructions::keeper::handler_fund_keeper_reserve(ctx, amount)
+    }
+
+    /// Set the keeper grace period
+    ///
+    /// Authority only. Applies immediately (no timelock).
+    ///
+    /// # Arguments
+    /// * `ctx` - SetKeeperGracePeriod accounts context
+    /// * `seconds` - Grace period in seconds (60 to 86400)
+    pub fn set_keeper_grace_period(ctx: Context<SetKeeperGracePeriod>, seconds: i64) -> Result<()> {
+        instructions::keeper::handler_set_keeper_grace_period(ctx, seconds)
+    }
+
     /// Execute the draw by revealing randomness
     ///
     /// This is the REVEAL phase of the commit-reveal pattern.
@@ -442,6 +1478,148 @@ pub mod solana_lotto {
         instructions::finalize_draw::handler(ctx, params)
     }
 
+    /// Create the recent draw history ring buffer (authority only)
+    ///
+    /// Must be called once before the next `finalize_draw`, which records
+    /// each finalized draw in the buffer.
+    ///
+    /// # Arguments
+    /// * `ctx` - InitializeDrawHistory accounts context
+    pub fn initialize_draw_history(ctx: Context<InitializeDrawHistory>) -> Result<()> {
+        instructions::draw_history::handler_initialize_draw_history(ctx)
+    }
+
+    /// Emit the last DRAW_HISTORY_LENGTH finalized draws
+    ///
+    /// Permissionless and read-only. Emits `DrawHistorySnapshot` with the
+    /// recorded draws oldest first.
+    ///
+    /// # Arguments
+    /// * `ctx` - GetDrawHistory accounts context
+    pub fn get_draw_history(ctx: Context<GetDrawHistory>) -> Result<()> {
+        instructions::draw_history::handler_get_draw_history(ctx)
+    }
+
+    /// Create the reserve history ring buffer (authority only)
+    ///
+    /// Must be called once before the next `finalize_draw`, which records a
+    /// reserve snapshot for each finalized draw.
+    ///
+    /// # Arguments
+    /// * `ctx` - InitializeReserveHistory accounts context
+    pub fn initialize_reserve_history(ctx: Context<InitializeReserveHistory>) -> Result<()> {
+        instructions::reserve_history::handler_initialize_reserve_history(ctx)
+    }
+
+    /// Emit rolling reserve health metrics
+    ///
+    /// Permissionless and read-only. Emits `ReserveHealthReport` and, when
+    /// coverage is below `reserve_minimum_coverage_draws`, a
+    /// `ReserveCoverageAlert`.
+    ///
+    /// # Arguments
+    /// * `ctx` - ComputeReserveHealth accounts context
+    pub fn compute_reserve_health(ctx: Context<ComputeReserveHealth>) -> Result<()> {
+        instructions::reserve_history::handler_compute_reserve_health(ctx)
+    }
+
+    /// Move reserve excess into the jackpot
+    ///
+    /// Permissionless. Moves whatever the reserve holds above
+    /// `reserve_target_multiplier_bps` of the rolling average prizes per
+    /// draw (and above `reserve_minimum`), up to what the jackpot needs to
+    /// reach the soft cap.
+    ///
+    /// # Arguments
+    /// * `ctx` - RebalanceReserveToJackpot accounts context
+    pub fn rebalance_reserve_to_jackpot(ctx: Context<RebalanceReserveToJackpot>) -> Result<()> {
+        instructions::reserve_history::handler_rebalance_reserve_to_jackpot(ctx)
+    }
+
+    /// Emit how often each number has been drawn
+    ///
+    /// Permissionless and read-only. Emits `NumberFrequencySnapshot` with
+    /// the per-number counts, the hot and cold numbers and the expected
+    /// count per number.
+    ///
+    /// # Arguments
+    /// * `ctx` - GetNumberFrequencies accounts context
+    pub fn get_number_frequencies(ctx: Context<GetNumberFrequencies>) -> Result<()> {
+        instructions::number_frequency::handler_get_number_frequencies(ctx)
+    }
+
+    /// Emit the lifetime lottery analytics
+    ///
+    /// Permissionless and read-only. Emits `LotteryAnalyticsSnapshot` with
+    /// the draw, jackpot, rolldown, prize and player counts.
+    ///
+    /// # Arguments
+    /// * `ctx` - GetAnalytics accounts context
+    pub fn get_analytics(ctx: Context<GetAnalytics>) -> Result<()> {
+        instructions::analytics::handler_get_analytics(ctx)
+    }
+
+    /// Emit a player's lifetime value
+    ///
+    /// Permissionless and read-only. Emits `PlayerLifetimeValueSnapshot`
+    /// with the player's spend, winnings, house fees paid, net ROI and
+    /// tenure in days.
+    ///
+    /// # Arguments
+    /// * `ctx` - GetPlayerLifetimeValue accounts context
+    /// * `player` - Player whose lifetime value is emitted
+    pub fn get_player_lifetime_value(
+        ctx: Context<GetPlayerLifetimeValue>,
+        player: Pubkey,
+    ) -> Result<()> {
+        instructions::lifetime_value::handler_get_player_lifetime_value(ctx, player)
+    }
+
+    /// Create the winning number bias detector (authority only)
+    ///
+    /// Must be called once before the next `finalize_draw`, which counts
+    /// every draw's winning numbers and runs a chi-squared bias test every
+    /// BIAS_TEST_INTERVAL_DRAWS draws.
+    ///
+    /// # Arguments
+    /// * `ctx` - InitializeBiasDetector accounts context
+    pub fn initialize_bias_detector(ctx: Context<InitializeBiasDetector>) -> Result<()> {
+        instructions::number_bias::handler_initialize_bias_detector(ctx)
+    }
+
+    /// Publish the Merkle root of a finalized draw's winning tickets
+    ///
+    /// Authority, or operator with the Merkle root permission. Once per
+    /// draw, before the claim window expires.
+    ///
+    /// # Arguments
+    /// * `ctx` - SubmitWinnerMerkleRoot accounts context
+    /// * `draw_id` - Draw the root belongs to
+    /// * `root` - Merkle root over `SHA-256(ticket_pubkey)` leaves
+    pub fn submit_winner_merkle_root(
+        ctx: Context<SubmitWinnerMerkleRoot>,
+        draw_id: u64,
+        root: [u8; 32],
+    ) -> Result<()> {
+        instructions::winner_merkle::handler_submit_winner_merkle_root(ctx, draw_id, root)
+    }
+
+    /// Check whether a ticket is in its draw's winner Merkle root
+    ///
+    /// Permissionless. Emits `TicketMerkleVerified` without claiming.
+    ///
+    /// # Arguments
+    /// * `ctx` - VerifyTicketMerkleInclusion accounts context
+    /// * `proof` - Sibling hashes from leaf to root
+    /// * `leaf_index` - Position of the ticket's leaf in the tree
+    pub fn verify_ticket_merkle_inclusion(
+        ctx: Context<VerifyTicketMerkleInclusion>,
+        proof: Vec<[u8; 32]>,
+        leaf_index: u64,
+    ) -> Result<()> {
+        instructions::winner_merkle::handler_verify_ticket_merkle_inclusion(ctx, proof, leaf_index)
+    }
+
     // =========================================================================
     // PRIZE INSTRUCTIONS
     // =========================================================================
@@ -451,7 +1629,11 @@ pub mod solana_lotto {
     /// Calculates match count against winning numbers and
     /// transfers the appropriate prize from the prize pool.
     ///
-    /// Verifies prize pool solvency before transfer.
+    /// Verifies prize pool solvency before transfer. Match 6 prizes above
+    /// the escrow threshold are escrowed and require the optional escrow
+    /// accounts; see `release_escrow_prize`. Match 5 claims also create a
+    /// free QuickPick ticket credit and require the optional `quickpick_state`
+    /// and `free_qp_credit` accounts.
     ///
     /// # Prize Tiers (Normal Mode)
     /// - Match 6: Jackpot (variable)
@@ -471,10 +1653,97 @@ pub mod solana_lotto {
         instructions::claim_prize::handler(ctx)
     }
 
+    /// Claim a prize above the identity verification threshold
+    ///
+    /// Same as `claim_prize`, plus the player's unexpired identity proof
+    /// issued by the identity oracle.
+    ///
+    /// # Arguments
+    /// * `ctx` - ClaimPrizeWithIdentity accounts context
+    pub fn claim_prize_with_identity(ctx: Context<ClaimPrizeWithIdentity>) -> Result<()> {
+        instructions::claim_prize::handler_claim_prize_with_identity(ctx)
+    }
+
+    /// Mark a free QuickPick ticket credit as used
+    ///
+    /// Only callable by QuickPick's `buy_ticket` via CPI, signed by the
+    /// Quick Pick state PDA.
+    ///
+    /// # Arguments
+    /// * `ctx` - RedeemFreeQuickPickCredit accounts context
+    /// * `qp_draw_id` - QuickPick draw the free ticket is bought for
+    pub fn redeem_free_quickpick_credit(
+        ctx: Context<RedeemFreeQuickPickCredit>,
+        qp_draw_id: u64,
+    ) -> Result<()> {
+        instructions::free_quickpick_credit::handler_redeem_free_quickpick_credit(ctx, qp_draw_id)
+    }
+
+    /// Authorize a wallet to claim a ticket's prize on the owner's behalf
+    ///
+    /// Only for tickets of the most recent draw. The prize is still paid to
+    /// the ticket owner.
+    ///
+    /// # Arguments
+    /// * `ctx` - AuthorizeClaimAgent accounts context
+    /// * `agent` - Wallet allowed to claim
+    pub fn authorize_claim_agent(ctx: Context<AuthorizeClaimAgent>, agent: Pubkey) -> Result<()> {
+        instructions::claim_agent::handler_authorize_claim_agent(ctx, agent)
+    }
+
+    /// Remove a ticket's claim agent
+    ///
+    /// # Arguments
+    /// * `ctx` - DeauthorizeClaimAgent accounts context
+    pub fn deauthorize_claim_agent(ctx: Context<DeauthorizeClaimAgent>) -> Result<()> {
+        instructions::claim_agent::handler_deauthorize_claim_agent(ctx)
+    }
+
+    /// Claim prizes for tickets across multiple draws in one transaction
+    ///
+    /// Tickets and their draw results are passed as `remaining_accounts`
+    /// in alternating order (draw, ticket, draw, ticket, ...), up to
+    /// MAX_MULTI_DRAW_CLAIM_PAIRS pairs. Already-claimed tickets are skipped.
+    /// The accumulated prize is paid in a single USDC transfer. Match 5 wins
+    /// issue a free QuickPick credit, as with `claim_prize`.
+    ///
+    /// # Arguments
+    /// * `ctx` - ClaimMultiDrawPrizes accounts context
+    pub fn claim_multi_draw_prizes<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimMultiDrawPrizes<'info>>,
+    ) -> Result<()> {
+        instructions::claim_prize::handler_claim_multi_draw_prizes(ctx)
+    }
+
+    /// Claim the prize for one draw covered by a multi-draw ticket
+    ///
+    /// The draw must be finalized, within the ticket's range and not yet
+    /// claimed on this ticket. Escrow accounts are required exactly when
+    /// the prize is escrowed, and Match 5 wins issue a free QuickPick
+    /// credit, as with `claim_prize`.
+    ///
+    /// # Arguments
+    /// * `ctx` - ClaimMultiDrawTicketPrize accounts context
+    pub fn claim_multi_draw_ticket_prize(ctx: Context<ClaimMultiDrawTicketPrize>) -> Result<()> {
+        instructions::claim_prize::handler_claim_multi_draw_ticket_prize(ctx)
+    }
+
+    /// Release an escrowed jackpot prize to its beneficiary
+    ///
+    /// Available once the escrow release delay has elapsed. Closes the
+    /// escrow accounts and returns their rent to the beneficiary.
+    ///
+    /// # Arguments
+    /// * `ctx` - ReleaseEscrowPrize accounts context
+    pub fn release_escrow_prize(ctx: Context<ReleaseEscrowPrize>) -> Result<()> {
+        instructions::prize_escrow::handler_release_escrow_prize(ctx)
+    }
+
     /// Claim prize for a specific ticket within a unified ticket (bulk purchase)
     ///
     /// Calculates match count against winning numbers for a specific ticket
     /// in the unified ticket account and transfers the appropriate prize.
+    /// Match 5 wins issue a free QuickPick credit, as with `claim_prize`.
     ///
     /// # Arguments
     /// * `ctx` - ClaimBulkPrize accounts context
@@ -491,6 +1760,7 @@ pub mod solana_lotto {
     /// Iterates through all tickets in the unified ticket and claims any
     /// unclaimed prizes. May fail for very large unified tickets due to
     /// compute limits - use claim_bulk_prize for individual claims instead.
+    /// Match 5 wins issue a free QuickPick credit, as with `claim_prize`.
     ///
     /// # Arguments
     /// * `ctx` - ClaimAllBulkPrizes accounts context
@@ -498,6 +1768,67 @@ pub mod solana_lotto {
         instructions::claim_bulk_prize::handler_claim_all(ctx)
     }
 
+    /// Claim prizes from a unified ticket a chunk at a time
+    ///
+    /// Processes up to `max_entries` tickets (at most BATCH_CLAIM_STEP_SIZE)
+    /// from `unified_ticket.claim_cursor` and pays them in one transfer.
+    /// Call repeatedly until `BatchClaimChunkProcessed.cursor_complete`.
+    /// Match 5 wins issue a free QuickPick credit, as with `claim_prize`.
+    ///
+    /// # Arguments
+    /// * `ctx` - ClaimBatchPrizesChunk accounts context
+    /// * `max_entries` - Tickets to process in this call
+    pub fn claim_batch_prizes_chunk(
+        ctx: Context<ClaimBatchPrizesChunk>,
+        max_entries: u8,
+    ) -> Result<()> {
+        instructions::claim_bulk_prize::handler_claim_batch_prizes_chunk(ctx, max_entries)
+    }
+
+    /// Preview the prize of a ticket without claiming it
+    ///
+    /// Permissionless and read-only. Emits `PrizePreview`; fails with
+    /// `DrawNotFinalized` until the ticket's draw is finalized.
+    ///
+    /// # Arguments
+    /// * `ctx` - PreviewPrize accounts context
+    pub fn preview_prize(ctx: Context<PreviewPrize>) -> Result<()> {
+        instructions::prize_preview::handler_preview_prize(ctx)
+    }
+
+    /// Preview the prize of one ticket of a unified ticket without claiming it
+    ///
+    /// Permissionless and read-only. Emits `BatchPrizePreview`; fails with
+    /// `DrawNotFinalized` until the draw is finalized.
+    ///
+    /// # Arguments
+    /// * `ctx` - PreviewBatchPrizes accounts context
+    /// * `ticket_index` - Index of the ticket within the unified ticket
+    pub fn preview_batch_prizes(ctx: Context<PreviewBatchPrizes>, ticket_index: u32) -> Result<()> {
+        instructions::prize_preview::handler_preview_batch_prizes(ctx, ticket_index)
+    }
+
+    /// Emit the all-time jackpot leaderboard
+    ///
+    /// Permissionless and read-only. Emits `LeaderboardSnapshot` with the
+    /// largest JACKPOT_LEADERBOARD_LENGTH prize wins, largest first.
+    ///
+    /// # Arguments
+    /// * `ctx` - GetLeaderboard accounts context
+    pub fn get_leaderboard(ctx: Context<GetLeaderboard>) -> Result<()> {
+        instructions::leaderboard::handler_get_leaderboard(ctx)
+    }
+
+    /// Emit the all-time and current-season jackpot highs
+    ///
+    /// Permissionless and read-only. Emits `JackpotATHSnapshot`.
+    ///
+    /// # Arguments
+    /// * `ctx` - GetJackpotATH accounts context
+    pub fn get_jackpot_ath(ctx: Context<GetJackpotATH>) -> Result<()> {
+        instructions::leaderboard::handler_get_jackpot_ath(ctx)
+    }
+
     // =========================================================================
     // SYNDICATE INSTRUCTIONS
     // =========================================================================
@@ -507,26 +1838,50 @@ pub mod solana_lotto {
     /// Creates a syndicate that allows multiple players to pool
     /// funds and share prizes proportionally.
     ///
-    /// Also creates the syndicate's USDC token account.
+    /// Also creates the syndicate's USDC token account. With
+    /// `wars_auto_register`, a public syndicate is registered for the open
+    /// Syndicate Wars competition passed in the remaining accounts.
     ///
     /// # Arguments
     /// * `ctx` - CreateSyndicate accounts context
     /// * `params` - Syndicate configuration
-    pub fn create_syndicate(
-        ctx: Context<CreateSyndicate>,
+    pub fn create_syndicate<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateSyndicate<'info>>,
         params: CreateSyndicateParams,
     ) -> Result<()> {
         instructions::syndicate::handler_create_syndicate(ctx, params)
     }
 
+    /// Create an invite code for a private syndicate (creator only)
+    ///
+    /// Stores only the SHA-256 hash of the plain code. Joiners submit the
+    /// plain code via join_syndicate while the invite has uses remaining.
+    ///
+    /// # Arguments
+    /// * `ctx` - CreateSyndicateInvite accounts context
+    /// * `code_hash` - SHA-256 hash of the plain invite code
+    /// * `max_uses` - Maximum number of joins allowed
+    /// * `expires_at` - Expiry unix timestamp
+    pub fn create_syndicate_invite(
+        ctx: Context<CreateSyndicateInvite>,
+        code_hash: [u8; 32],
+        max_uses: u8,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::syndicate::handler_create_syndicate_invite(
+            ctx, code_hash, max_uses, expires_at,
+        )
+    }
+
     /// Join an existing syndicate
     ///
     /// Adds the caller to a syndicate with a USDC contribution.
     /// Member shares are calculated proportionally to contributions.
+    /// New members of private syndicates must supply a valid invite code.
     ///
     /// # Arguments
     /// * `ctx` - JoinSyndicate accounts context
-    /// * `params` - Contribution amount
+    /// * `params` - Contribution amount and optional invite code
     pub fn join_syndicate(ctx: Context<JoinSyndicate>, params: JoinSyndicateParams) -> Result<()> {
         instructions::syndicate::handler_join_syndicate(ctx, params)
     }
@@ -542,6 +1897,29 @@ pub mod solana_lotto {
         instructions::syndicate::handler_leave_syndicate(ctx)
     }
 
+    /// Join the waitlist of a full public syndicate
+    ///
+    /// Escrows the contribution in the syndicate's USDC account. The first
+    /// waiting wallet becomes a member when someone leaves the syndicate.
+    ///
+    /// # Arguments
+    /// * `ctx` - JoinSyndicateWaitlist accounts context
+    /// * `contribution` - USDC to escrow and contribute on promotion
+    pub fn join_syndicate_waitlist(
+        ctx: Context<JoinSyndicateWaitlist>,
+        contribution: u64,
+    ) -> Result<()> {
+        instructions::syndicate::handler_join_syndicate_waitlist(ctx, contribution)
+    }
+
+    /// Leave a syndicate waitlist and reclaim the escrowed USDC
+    ///
+    /// # Arguments
+    /// * `ctx` - LeaveWaitlist accounts context
+    pub fn leave_waitlist(ctx: Context<LeaveWaitlist>) -> Result<()> {
+        instructions::syndicate::handler_leave_waitlist(ctx)
+    }
+
     /// Close a syndicate
     ///
     /// Closes the syndicate and returns rent to creator.
@@ -570,6 +1948,21 @@ pub mod solana_lotto {
         instructions::syndicate::handler_withdraw_creator_contribution(ctx, amount)
     }
 
+    /// Withdraw part of a member's syndicate contribution
+    ///
+    /// For non-creator members while the syndicate is not locked. The
+    /// member must keep at least 1 USDC contributed; shares are recalculated.
+    ///
+    /// # Arguments
+    /// * `ctx` - PartialWithdrawMemberContribution accounts context
+    /// * `amount` - Amount to withdraw (must be <= member's contribution)
+    pub fn partial_withdraw_member_contribution(
+        ctx: Context<PartialWithdrawMemberContribution>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::syndicate::handler_partial_withdraw_member_contribution(ctx, amount)
+    }
+
     /// Buy tickets for a syndicate using pooled funds
     ///
     /// Uses the syndicate's pooled USDC to purchase lottery tickets.
@@ -581,9 +1974,10 @@ pub mod solana_lotto {
     ///
     /// # Arguments
     /// * `ctx` - BuySyndicateTickets accounts context
-    /// * `params` - Ticket numbers to purchase (max 10 per call)
-    pub fn buy_syndicate_tickets(
-        ctx: Context<BuySyndicateTickets>,
+    /// * `params` - Ticket numbers to purchase (max 10 per call) and the
+    ///   highest per-ticket price accepted
+    pub fn buy_syndicate_tickets<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuySyndicateTickets<'info>>,
         params: BuySyndicateTicketsParams,
     ) -> Result<()> {
         instructions::syndicate::handler_buy_syndicate_tickets(ctx, params)
@@ -593,6 +1987,8 @@ pub mod solana_lotto {
     ///
     /// Called after buy_syndicate_tickets to create individual ticket accounts.
     /// The funds have already been transferred; this creates the account record.
+    /// A syndicate cannot create more ticket accounts for a draw than it
+    /// bought tickets for.
     ///
     /// # Arguments
     /// * `ctx` - CreateSyndicateTicket accounts context
@@ -604,19 +2000,82 @@ pub mod solana_lotto {
         instructions::syndicate::handler_create_syndicate_ticket(ctx, numbers)
     }
 
-    /// Distribute prize to syndicate members
+    /// Create up to 10 syndicate ticket accounts in one call
+    ///
+    /// Batch form of `create_syndicate_ticket`. The ticket PDAs are passed as
+    /// writable remaining accounts, one per ticket.
+    ///
+    /// # Arguments
+    /// * `ctx` - CreateSyndicateTicketsBatch accounts context
+    /// * `tickets` - The 6 numbers for each ticket
+    pub fn create_syndicate_tickets_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateSyndicateTicketsBatch<'info>>,
+        tickets: Vec<[u8; 6]>,
+    ) -> Result<()> {
+        instructions::syndicate::handler_create_syndicate_tickets_batch(ctx, tickets)
+    }
+
+    /// Stage a batch of syndicate tickets for prize distribution
     ///
-    /// Transfers prize from prize pool to syndicate account and calculates
-    /// manager fee. Individual members must claim their shares separately.
+    /// Verifies the tickets passed as remaining accounts, marks them claimed
+    /// and adds their prize to the syndicate's prize stage for the draw.
+    /// Can be called in batches.
     ///
     /// # Arguments
-    /// * `ctx` - DistributeSyndicatePrize accounts context
-    /// * `params` - Prize distribution parameters
-    pub fn distribute_syndicate_prize<'info>(
-        ctx: Context<'_, '_, 'info, 'info, DistributeSyndicatePrize<'info>>,
-        params: DistributeSyndicatePrizeParams,
+    /// * `ctx` - StageSyndicatePrize accounts context
+    pub fn stage_syndicate_prize<'info>(
+        ctx: Context<'_, '_, 'info, 'info, StageSyndicatePrize<'info>>,
     ) -> Result<()> {
-        instructions::syndicate::handler_distribute_syndicate_prize(ctx, params)
+        instructions::syndicate::handler_stage_syndicate_prize(ctx)
+    }
+
+    /// Distribute a staged prize to syndicate members
+    ///
+    /// Transfers the staged prize from prize pool to syndicate account,
+    /// calculates manager fee and closes the stage. Individual members must
+    /// claim their shares separately. Escrowed Match 6 prizes are moved to
+    /// the syndicate's escrow for the draw instead.
+    ///
+    /// # Arguments
+    /// * `ctx` - FinalizeStagedPrize accounts context
+    pub fn finalize_staged_prize(ctx: Context<FinalizeStagedPrize>) -> Result<()> {
+        instructions::syndicate::handler_finalize_staged_prize(ctx)
+    }
+
+    /// Release a syndicate's escrowed prize to its members
+    ///
+    /// Callable by the lottery authority once `escrow_release_delay_seconds`
+    /// has elapsed. Moves the escrow to the syndicate account and adds each
+    /// member's share to their unclaimed prize.
+    ///
+    /// # Arguments
+    /// * `ctx` - ReleaseSyndicateEscrowPrize accounts context
+    pub fn release_syndicate_escrow_prize(ctx: Context<ReleaseSyndicateEscrowPrize>) -> Result<()> {
+        instructions::syndicate::handler_release_syndicate_escrow_prize(ctx)
+    }
+
+    /// Unlock a syndicate after the draw it is locked for is finalized
+    ///
+    /// Callable by the syndicate manager or any member. Not available for
+    /// force-finalized draws; see `unlock_syndicate_after_force_finalize`.
+    ///
+    /// # Arguments
+    /// * `ctx` - UnlockSyndicate accounts context
+    pub fn unlock_syndicate(ctx: Context<UnlockSyndicate>) -> Result<()> {
+        instructions::syndicate::handler_unlock_syndicate(ctx)
+    }
+
+    /// Unlock a syndicate whose locked draw was force finalized
+    ///
+    /// Callable by the syndicate manager or any member once 48 hours have
+    /// passed since the force finalization.
+    ///
+    /// # Arguments
+    /// * `ctx` - UnlockSyndicateAfterForceFinalize accounts context
+    pub fn unlock_syndicate_after_force_finalize(
+        ctx: Context<UnlockSyndicateAfterForceFinalize>,
+    ) -> Result<()> {
+        instructions::syndicate::handler_unlock_syndicate_after_force_finalize(ctx)
     }
 
     /// Claim a member's share of syndicate prize
@@ -639,7 +2098,7 @@ pub mod solana_lotto {
     /// Allows the syndicate creator to update:
     /// - Syndicate name
     /// - Public/private status
-    /// - Manager fee (within 5% limit)
+    /// - Manager fee (within 5% limit; increases wait out a member veto window)
     ///
     /// # Arguments
     /// * `ctx` - UpdateSyndicateConfig accounts context
@@ -651,6 +2110,27 @@ pub mod solana_lotto {
         instructions::syndicate::handler_update_syndicate_config(ctx, params)
     }
 
+    /// Veto a pending syndicate manager fee increase
+    ///
+    /// Open to members holding at least 20% of the syndicate until the
+    /// proposal's veto window closes.
+    ///
+    /// # Arguments
+    /// * `ctx` - RejectFeeProposal accounts context
+    pub fn reject_fee_proposal(ctx: Context<RejectFeeProposal>) -> Result<()> {
+        instructions::syndicate::handler_reject_fee_proposal(ctx)
+    }
+
+    /// Apply a syndicate manager fee increase after its veto window
+    ///
+    /// Only the syndicate manager can execute the proposal.
+    ///
+    /// # Arguments
+    /// * `ctx` - ExecuteFeeProposal accounts context
+    pub fn execute_fee_proposal(ctx: Context<ExecuteFeeProposal>) -> Result<()> {
+        instructions::syndicate::handler_execute_fee_proposal(ctx)
+    }
+
     /// Remove a member from syndicate (creator only)
     ///
     /// Allows the syndicate creator to remove a member and refund their contribution.
@@ -682,6 +2162,28 @@ pub mod solana_lotto {
         instructions::syndicate::handler_transfer_syndicate_creator(ctx, params)
     }
 
+    /// Create the public syndicate registry (authority only)
+    ///
+    /// Must be called once before syndicates are created, since the
+    /// syndicate instructions keep the registry listing up to date.
+    ///
+    /// # Arguments
+    /// * `ctx` - InitializeSyndicateRegistry accounts context
+    pub fn initialize_syndicate_registry(ctx: Context<InitializeSyndicateRegistry>) -> Result<()> {
+        instructions::syndicate_registry::handler_initialize_syndicate_registry(ctx)
+    }
+
+    /// Emit the list of public syndicates
+    ///
+    /// Permissionless and read-only. Emits `PublicSyndicatesSnapshot` with
+    /// every listed syndicate so clients can find joinable ones.
+    ///
+    /// # Arguments
+    /// * `ctx` - GetPublicSyndicates accounts context
+    pub fn get_public_syndicates(ctx: Context<GetPublicSyndicates>) -> Result<()> {
+        instructions::syndicate_registry::handler_get_public_syndicates(ctx)
+    }
+
     // =========================================================================
     // SYNDICATE WARS COMPETITION INSTRUCTIONS
     // =========================================================================
@@ -728,6 +2230,24 @@ pub mod solana_lotto {
         instructions::syndicate_wars::handler_update_syndicate_wars_stats(ctx, params)
     }
 
+    /// Score a draw for a syndicate from its ticket accounts
+    ///
+    /// Permissionless. The syndicate's tickets for the draw are passed as
+    /// `remaining_accounts`; their score is added to the entry's
+    /// `on_chain_score`, which floors the submitted score in rankings.
+    ///
+    /// # Arguments
+    /// * `ctx` - ComputeWarsScoreOnChain accounts context
+    /// * `syndicate` - Syndicate whose tickets are scored
+    /// * `draw_id` - Draw the tickets are for
+    pub fn compute_wars_score_on_chain(
+        ctx: Context<ComputeWarsScoreOnChain>,
+        syndicate: Pubkey,
+        draw_id: u64,
+    ) -> Result<()> {
+        instructions::syndicate_wars::handler_compute_wars_score_on_chain(ctx, syndicate, draw_id)
+    }
+
     /// Finalize Syndicate Wars competition
     ///
     /// Marks competition as inactive after it has ended.
@@ -771,4 +2291,53 @@ pub mod solana_lotto {
     ) -> Result<()> {
         instructions::syndicate_wars::handler_claim_syndicate_wars_prize(ctx, params)
     }
+
+    /// Seed a Syndicate Wars knockout bracket
+    ///
+    /// Remaining accounts are the SyndicateWarsEntry accounts of exactly
+    /// 2^`bracket_rounds` entrants, paired best against worst by score.
+    /// Only lottery authority can start the bracket.
+    ///
+    /// # Arguments
+    /// * `ctx` - AdvanceWarsBracket accounts context
+    /// * `bracket_rounds` - Knockout rounds including the finals
+    /// * `finals_prize_bps` - Share of the wars prize pool for the finalists
+    pub fn start_wars_bracket<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AdvanceWarsBracket<'info>>,
+        bracket_rounds: u8,
+        finals_prize_bps: u16,
+    ) -> Result<()> {
+        instructions::syndicate_wars::handler_start_wars_bracket(
+            ctx,
+            bracket_rounds,
+            finals_prize_bps,
+        )
+    }
+
+    /// Record the winners of the current Syndicate Wars bracket round
+    ///
+    /// Remaining accounts are the winners' SyndicateWarsEntry accounts.
+    /// Winners are re-seeded by score and paired for the next round.
+    /// Only lottery authority can advance the bracket.
+    ///
+    /// # Arguments
+    /// * `ctx` - AdvanceWarsBracket accounts context
+    /// * `winners` - One winning syndicate per current matchup
+    pub fn advance_wars_bracket<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AdvanceWarsBracket<'info>>,
+        winners: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::syndicate_wars::handler_advance_wars_bracket(ctx, winners)
+    }
+
+    /// Claim a finalist's half of the Syndicate Wars finals prize
+    ///
+    /// Only the manager of one of the two finalists can claim, once the
+    /// competition is finalized.
+    ///
+    /// # Arguments
+    /// * `ctx` - ClaimFinalsBracketPrize accounts context
+    pub fn claim_finals_bracket_prize(ctx: Context<ClaimFinalsBracketPrize>) -> Result<()> {
+        instructions::syndicate_wars::handler_claim_finals_bracket_prize(ctx)
+    }
 }
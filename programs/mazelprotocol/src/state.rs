@@ -8,6 +8,57 @@ use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::LottoError;
 
+// ============================================================================
+// SCHEMA VERSIONING
+// ============================================================================
+
+/// Accounts that carry a `schema_version` layout tag
+pub trait SchemaVersioned {
+    /// Layout version stored in the account
+    fn version(&self) -> u8;
+
+    /// Whether the account is at CURRENT_SCHEMA_VERSION
+    fn is_current_schema(&self) -> bool {
+        self.version() == CURRENT_SCHEMA_VERSION
+    }
+
+    /// Fail with `SchemaVersionMismatch` unless the account is current
+    fn require_current_schema(&self) -> Result<()> {
+        require!(self.is_current_schema(), LottoError::SchemaVersionMismatch);
+        Ok(())
+    }
+}
+
+impl SchemaVersioned for LotteryState {
+    fn version(&self) -> u8 {
+        self.schema_version
+    }
+}
+
+impl SchemaVersioned for DrawResult {
+    fn version(&self) -> u8 {
+        self.schema_version
+    }
+}
+
+impl SchemaVersioned for TicketData {
+    fn version(&self) -> u8 {
+        self.schema_version
+    }
+}
+
+impl SchemaVersioned for UserStats {
+    fn version(&self) -> u8 {
+        self.schema_version
+    }
+}
+
+impl SchemaVersioned for Syndicate {
+    fn version(&self) -> u8 {
+        self.schema_version
+    }
+}
+
 // ============================================================================
 // CORE STATE STRUCTURES
 // ============================================================================
@@ -16,6 +67,9 @@ use crate::errors::LottoError;
 #[account]
 #[derive(Default)]
 pub struct LotteryState {
+    /// Account layout version (CURRENT_SCHEMA_VERSION when up to date)
+    pub schema_version: u8,
+
     /// Admin authority (multi-sig wallet recommended)
     pub authority: Pubkey,
 
@@ -37,8 +91,8 @@ pub struct LotteryState {
     /// Reserve fund balance for future draws
     pub reserve_balance: u64,
 
-    /// Insurance fund balance for guaranteed payouts
-    pub insurance_balance: u64,
+    /// Insurance tier 1 balance: liquid, usable immediately for payouts
+    pub insurance_tier1_balance: u64,
 
     /// Dedicated fixed prize pool balance in USDC lamports.
     /// Tracks the 39.4% allocation from ticket sales earmarked for fixed prizes
@@ -130,11 +184,403 @@ pub struct LotteryState {
     /// Window duration is 24 hours. When a new transfer exceeds the window,
     /// the total resets. Prevents unlimited repeated small drains.
     pub emergency_transfer_window_start: i64,
+
+    /// Whether non-owners may close expired, unclaimed ticket accounts.
+    /// Rent still goes to the ticket owner; this only enables community cleanup.
+    pub allow_third_party_closure: bool,
+
+    /// Minimum tickets sold in the current draw before randomness can be committed.
+    /// Prevents empty draws from consuming VRF requests.
+    pub min_tickets_per_draw: u64,
+
+    /// Rolldown share of the jackpot for Match 5 (basis points)
+    pub rolldown_match_5_bps: u16,
+
+    /// Rolldown share of the jackpot for Match 4 (basis points)
+    pub rolldown_match_4_bps: u16,
+
+    /// Rolldown share of the jackpot for Match 3 (basis points).
+    /// The three rolldown shares always sum to BPS_DENOMINATOR.
+    pub rolldown_match_3_bps: u16,
+
+    /// Match 6 prizes above this amount are escrowed before release (0 = disabled)
+    pub escrow_threshold_usdc: u64,
+
+    /// Seconds an escrowed prize is held before the winner can release it
+    pub escrow_release_delay_seconds: i64,
+
+    /// Whether house fees are split across the `HouseFeeRecipients` config
+    /// (purchases must then supply the config and recipient sub-accounts)
+    pub house_fee_split_active: bool,
+
+    /// Optional operator allowed to run draw steps on the authority's behalf.
+    /// The operator can never pause, withdraw fees, change config or move funds.
+    pub operator: Option<Pubkey>,
+
+    /// Draw steps the operator may run (`OPERATOR_PERMISSION_*` bitmask)
+    pub operator_permissions: u64,
+
+    /// When `execute_draw` ran for the in-progress draw (0 = not executed)
+    pub execute_draw_timestamp: i64,
+
+    /// Reserve floor; finalize_draw auto-pauses the lottery when the reserve
+    /// drops below it after reseeding (0 = disabled)
+    pub reserve_minimum: u64,
+
+    /// Whether the per-wallet daily ticket limit is enforced on purchases
+    pub daily_limit_enabled: bool,
+
+    /// Whether low-entropy number picks (short-step arithmetic runs) are rejected
+    pub entropy_validation_enabled: bool,
+
+    /// Seconds before `next_draw_timestamp` at which ticket sales close
+    pub ticket_sale_cutoff_seconds: i64,
+
+    /// SOL reward paid from this account's lamports to keepers who trigger
+    /// an overdue draw (0 = no reward)
+    pub keeper_reward_lamports: u64,
+    /// Insurance balance below which part of each house fee is diverted
+    /// to the insurance pool (0 = auto-replenishment disabled)
+    pub insurance_minimum: u64,
+    /// Share of the house fee diverted while below `insurance_minimum` (bps)
+    pub insurance_replenishment_bps: u16,
+    /// Last draw for which `InsuranceAutoReplenished` was emitted
+    pub insurance_replenished_draw_id: u64,
+    /// Jackpot balances that trigger `JackpotMilestoneReached`, ascending
+    pub jackpot_milestone_thresholds: [u64; 5],
+    /// Bitmask of milestones already announced this draw (bit i = threshold i)
+    pub milestones_emitted: u8,
+    /// Seconds after `next_draw_timestamp` before any keeper may commit
+    /// randomness for the draw
+    pub keeper_grace_period_seconds: i64,
+    /// Keeper who most recently committed randomness for a draw
+    pub last_keeper: Option<Pubkey>,
+    /// When `snapshot_lottery_stats` last ran (0 = never)
+    pub last_snapshot_timestamp: i64,
+    /// Whether execute_draw may fall back to slot-hash randomness once the
+    /// VRF result has been unset for VRF_FALLBACK_DELAY_SECONDS
+    pub allow_fallback_randomness: bool,
+    /// Draw randomness from `switchboard_pull_feed` instead of a
+    /// commit-reveal randomness account
+    pub use_pull_oracle: bool,
+    /// Switchboard pull feed used when `use_pull_oracle` is set
+    pub switchboard_pull_feed: Option<Pubkey>,
+    /// Pull feed round (result slot) recorded at commit; execute_draw only
+    /// accepts a later round
+    pub committed_pull_round_id: u64,
+    /// Hash of the draw parameters fixed at commit (see
+    /// `compute_draw_commit_hash`); copied to the draw result by execute_draw
+    pub draw_commit_hash: [u8; 32],
+    /// Second signer required for PrizePool emergency fund transfers
+    /// (None = authority alone)
+    pub multisig_cosigner: Option<Pubkey>,
+    /// Cosigner change waiting on `multisig_cosigner_timelock_end`
+    pub pending_multisig_cosigner: Option<Pubkey>,
+    /// When the pending cosigner change can be applied (0 = none pending)
+    pub multisig_cosigner_timelock_end: i64,
+    /// Price tickets from `price_tiers` instead of `ticket_price`
+    pub dynamic_price_enabled: bool,
+    /// Jackpot-level ticket prices, ascending by threshold
+    pub price_tiers: [PriceTier; PRICE_TIER_COUNT],
+    /// Lamports paid per expired ticket account closed by a cleanup caller
+    pub cleanup_reward_lamports: u64,
+    /// USDC paid out for Match 2-5 prizes (lifetime, excludes jackpots)
+    pub total_fixed_prizes_claimed: u64,
+    /// When a proposed prize pool reconciliation can run (0 = none pending)
+    pub reconcile_timelock_end: i64,
+    /// Lifetime spend required by `verify_spend_gate` (USDC lamports)
+    pub quickpick_spend_gate_threshold: u64,
+    /// Per-draw cap on the total fixed payout of Match 2-5 (indices 0-3,
+    /// 0 = uncapped). Winners share the cap when a tier would exceed it.
+    pub max_fixed_payout_per_tier: [u64; FIXED_PAYOUT_CAP_TIER_COUNT],
+    /// Draws of average prizes the reserve should cover before
+    /// `compute_reserve_health` raises `ReserveCoverageAlert` (0 = disabled)
+    pub reserve_minimum_coverage_draws: u64,
+    /// Who may buy or redeem tickets
+    pub whitelist_mode: WhitelistMode,
+    /// Tickets a wallet may buy per VELOCITY_WINDOW_SECONDS through
+    /// `buy_ticket` and `buy_bulk` (0 = unlimited)
+    pub max_tickets_per_wallet_per_hour: u32,
+    /// Highest jackpot balance ever reached through ticket sales
+    pub jackpot_all_time_high: u64,
+    /// Draw during which `jackpot_all_time_high` was set
+    pub jackpot_ath_draw_id: u64,
+    /// When `jackpot_all_time_high` was set
+    pub jackpot_ath_timestamp: i64,
+    /// Highest jackpot balance since the last jackpot win or rolldown
+    pub jackpot_ath_for_season: u64,
+    /// Draws after a forced hard-cap rolldown during which rolldowns stay
+    /// disabled (0 = no cooldown)
+    pub rolldown_cooldown_draws: u8,
+    /// Last draw ID of the current rolldown cooldown (inclusive)
+    pub rolldown_cooldown_until_draw: u64,
+    /// Purchase compliance mode (COMPLIANCE_MODE_NONE or COMPLIANCE_MODE_KYC)
+    pub compliance_mode: u8,
+    /// Wallet allowed to approve and revoke KYC records
+    pub kyc_authority: Option<Pubkey>,
+    /// Prizes above this amount are only claimable through
+    /// `claim_prize_with_identity` (0 = disabled)
+    pub identity_verification_threshold: u64,
+    /// Oracle allowed to issue identity proofs
+    pub identity_oracle: Pubkey,
+    /// Current terms version players must accept before buying (0 = none)
+    pub terms_version: u8,
+    /// Location of the current terms (max MAX_TERMS_URI_LENGTH bytes)
+    pub terms_uri: String,
+
+    /// Insurance tier 2 balance: delayed, reachable only by moving it to
+    /// tier 1 through a timelocked tier 2 withdrawal
+    pub insurance_tier2_balance: u64,
+
+    /// Draw time of day in seconds since midnight UTC; `finalize_draw`
+    /// schedules the next draw on this grid so late draws don't drift
+    pub anchor_draw_time: i64,
+
+    /// Jackpot balances that unlock a milestone bonus, ascending
+    pub milestone_thresholds: [u64; 5],
+    /// Bonus paid from the reserve to the first claimer of each milestone
+    /// (0 = no bonus)
+    pub milestone_prizes: [u64; 5],
+    /// Whether each milestone bonus has been claimed this jackpot cycle
+    pub milestone_claimed: [bool; 5],
+
+    /// SOL bounty paid from this account's lamports to whoever force
+    /// finalizes an overdue draw (0 = no bounty)
+    pub force_finalize_reward_lamports: u64,
+
+    /// Tickets a wallet may buy per draw through `buy_ticket` and
+    /// `buy_bulk` (0 = unlimited)
+    pub max_tickets_per_wallet_per_draw: u64,
+
+    /// Share of a house fee account's balance that `withdraw_house_fees`
+    /// may pay out per HOUSE_FEE_WITHDRAWAL_WINDOW_SECONDS (0 = uncapped)
+    pub daily_withdrawal_cap_bps: u16,
+    /// Start of the current house fee withdrawal window
+    pub withdrawal_window_start: i64,
+    /// House fees withdrawn in the current window
+    pub withdrawals_in_window: u64,
+
+    /// Share of house fees paid to the operator by
+    /// `distribute_operator_profits`, in basis points
+    pub operator_profit_share_bps: u16,
+
+    /// Jackpot floor topped up from `guarantee_reserve_balance` at each
+    /// finalization (0 = no guarantee)
+    pub jackpot_guarantee_amount: u64,
+    /// USDC in the prize pool set aside for the jackpot guarantee
+    pub guarantee_reserve_balance: u64,
+
+    /// One-time ticket discounts for reaching a draw participation streak
+    /// (entries with a zero streak length are unused)
+    pub streak_milestone_bps: [StreakMilestone; STREAK_MILESTONE_COUNT],
+
+    /// SHA256 of the announced program bytecode (None = no upgrade announced)
+    pub pending_upgrade_hash: Option<[u8; 32]>,
+    /// Slot the pending upgrade was announced in
+    pub upgrade_announce_slot: u64,
+
+    /// Reserve kept back by `rebalance_reserve_to_jackpot`, as a multiple of
+    /// the rolling average prizes per draw in basis points
+    pub reserve_target_multiplier_bps: u16,
+
+    /// Last time the Switchboard oracle was seen healthy
+    pub oracle_last_healthy_timestamp: i64,
+    /// Failed oracle health checks since the oracle was last seen healthy
+    pub oracle_consecutive_failures: u8,
+    /// Consecutive oracle health check failures that pause the lottery
+    pub max_oracle_failures: u8,
+    /// Last failed oracle health check counted in `oracle_consecutive_failures`
+    pub oracle_last_failure_timestamp: i64,
+
+    /// Recurring tickets bought for draws that have not opened yet (see
+    /// `RecurringTicketSchedule`)
+    pub pending_recurring_tickets: u64,
+
+    /// Bitmask of milestone bonuses unlocked this jackpot cycle (bit i =
+    /// `milestone_thresholds[i]`), set when a purchase carries the jackpot
+    /// across the threshold
+    pub milestone_unlocked: u8,
+}
+
+/// Ticket price that applies once the jackpot reaches `threshold`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct PriceTier {
+    /// Minimum jackpot balance for this tier
+    pub threshold: u64,
+    /// Ticket price in USDC lamports
+    pub price: u64,
+}
+
+impl PriceTier {
+    /// Whether `tiers` is a usable schedule: positive prices, thresholds strictly ascending
+    pub fn is_valid_schedule(tiers: &[PriceTier]) -> bool {
+        tiers.iter().all(|tier| tier.price > 0)
+            && tiers
+                .windows(2)
+                .all(|pair| pair[0].threshold < pair[1].threshold)
+    }
+}
+
+/// Ticket discount for reaching a participation streak
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct StreakMilestone {
+    /// Consecutive draws played to reach the milestone (0 = unused)
+    pub streak_length: u32,
+    /// Discount off one ticket price in basis points
+    pub discount_bps: u16,
+}
+
+impl StreakMilestone {
+    /// Whether `milestones` is a usable schedule: discounts at most 100%,
+    /// used streak lengths strictly ascending
+    pub fn is_valid_schedule(milestones: &[StreakMilestone]) -> bool {
+        milestones
+            .iter()
+            .all(|milestone| milestone.discount_bps as u64 <= BPS_DENOMINATOR)
+            && milestones
+                .iter()
+                .filter(|milestone| milestone.streak_length > 0)
+                .collect::<Vec<_>>()
+                .windows(2)
+                .all(|pair| pair[0].streak_length < pair[1].streak_length)
+    }
 }
 
 impl LotteryState {
     pub const LEN: usize = LOTTERY_STATE_SIZE;
 
+    /// Set every field introduced in schema v1 to its initial value
+    ///
+    /// Shared by `initialize` and the v0 migration, so a migrated lottery
+    /// starts from the same configuration as a new one. Expects
+    /// `next_draw_timestamp` to be set already.
+    pub fn init_v1_fields(&mut self) {
+        self.allow_third_party_closure = false;
+        self.min_tickets_per_draw = DEFAULT_MIN_TICKETS_PER_DRAW;
+        self.rolldown_match_5_bps = ROLLDOWN_MATCH_5_BPS;
+        self.rolldown_match_4_bps = ROLLDOWN_MATCH_4_BPS;
+        self.rolldown_match_3_bps = ROLLDOWN_MATCH_3_BPS;
+        self.escrow_threshold_usdc = 0; // Escrow disabled until configured
+        self.escrow_release_delay_seconds = DEFAULT_ESCROW_RELEASE_DELAY;
+        self.house_fee_split_active = false;
+        self.operator = None;
+        self.operator_permissions = 0;
+        self.execute_draw_timestamp = 0;
+        self.reserve_minimum = 0; // Reserve floor disabled until configured
+        self.daily_limit_enabled = false;
+        self.entropy_validation_enabled = false;
+        self.ticket_sale_cutoff_seconds = TICKET_SALE_CUTOFF;
+        self.keeper_reward_lamports = 0;
+        self.insurance_minimum = 0;
+        self.insurance_replenishment_bps = 0;
+        self.insurance_replenished_draw_id = 0;
+        self.jackpot_milestone_thresholds = DEFAULT_JACKPOT_MILESTONE_THRESHOLDS;
+        self.milestones_emitted = 0;
+        self.jackpot_all_time_high = 0;
+        self.jackpot_ath_draw_id = 0;
+        self.jackpot_ath_timestamp = 0;
+        self.jackpot_ath_for_season = 0;
+        self.keeper_grace_period_seconds = DEFAULT_KEEPER_GRACE_PERIOD_SECONDS;
+        self.last_keeper = None;
+        self.last_snapshot_timestamp = 0;
+        self.allow_fallback_randomness = false;
+        self.use_pull_oracle = false;
+        self.switchboard_pull_feed = None;
+        self.committed_pull_round_id = 0;
+        self.draw_commit_hash = [0u8; 32];
+        self.multisig_cosigner = None;
+        self.pending_multisig_cosigner = None;
+        self.multisig_cosigner_timelock_end = 0;
+        self.dynamic_price_enabled = false;
+        self.price_tiers = [PriceTier::default(); PRICE_TIER_COUNT];
+        self.cleanup_reward_lamports = 0;
+        self.total_fixed_prizes_claimed = 0;
+        self.reconcile_timelock_end = 0;
+        self.quickpick_spend_gate_threshold = QUICK_PICK_MIN_SPEND_GATE;
+        self.max_fixed_payout_per_tier = [0; FIXED_PAYOUT_CAP_TIER_COUNT];
+        self.reserve_minimum_coverage_draws = 0; // Coverage alert disabled until configured
+        self.whitelist_mode = WhitelistMode::None; // Open to all players
+        self.max_tickets_per_wallet_per_hour = 0; // Velocity limit disabled until configured
+        self.rolldown_cooldown_draws = 0; // No cooldown after forced rolldowns
+        self.rolldown_cooldown_until_draw = 0;
+        self.compliance_mode = COMPLIANCE_MODE_NONE; // KYC not required
+        self.kyc_authority = None;
+        self.identity_verification_threshold = 0; // Identity checks disabled until configured
+        self.identity_oracle = Pubkey::default();
+        self.terms_version = 0; // No terms published yet
+        self.terms_uri = String::new();
+        self.insurance_tier2_balance = 0;
+        // Later draws keep the first draw's time of day
+        self.anchor_draw_time = self.next_draw_timestamp.rem_euclid(SECONDS_PER_DAY);
+        self.milestone_thresholds = DEFAULT_JACKPOT_MILESTONE_THRESHOLDS;
+        self.milestone_prizes = [0; JACKPOT_MILESTONE_COUNT]; // No bonuses until configured
+        self.milestone_claimed = [false; JACKPOT_MILESTONE_COUNT];
+        self.force_finalize_reward_lamports = 0;
+        self.max_tickets_per_wallet_per_draw = 0; // Per-draw wallet limit disabled until configured
+        self.daily_withdrawal_cap_bps = 0; // House fee withdrawals uncapped until configured
+        self.withdrawal_window_start = 0;
+        self.withdrawals_in_window = 0;
+        self.operator_profit_share_bps = 0; // All distributed house fees go to the authority until configured
+        self.jackpot_guarantee_amount = 0; // No jackpot guarantee until configured
+        self.guarantee_reserve_balance = 0;
+        self.streak_milestone_bps = [StreakMilestone::default(); STREAK_MILESTONE_COUNT]; // No streak discounts until configured
+        self.pending_upgrade_hash = None;
+        self.upgrade_announce_slot = 0;
+        self.reserve_target_multiplier_bps = DEFAULT_RESERVE_TARGET_MULTIPLIER_BPS;
+        self.oracle_last_healthy_timestamp = 0;
+        self.oracle_consecutive_failures = 0;
+        self.max_oracle_failures = DEFAULT_MAX_ORACLE_FAILURES;
+        self.oracle_last_failure_timestamp = 0;
+        self.pending_recurring_tickets = 0;
+        self.milestone_unlocked = 0;
+    }
+
+    /// Earliest slot the announced program upgrade may be deployed in
+    pub fn upgrade_earliest_slot(&self) -> u64 {
+        self.upgrade_announce_slot
+            .saturating_add(UPGRADE_DELAY_SLOTS)
+    }
+
+    /// Whether an announced upgrade is still in its waiting period at `slot`
+    pub fn is_upgrade_pending(&self, slot: u64) -> bool {
+        self.pending_upgrade_hash.is_some() && slot <= self.upgrade_earliest_slot()
+    }
+
+    /// Discount for a player whose streak just reached `streak`, if it is a
+    /// milestone
+    pub fn streak_discount_bps(&self, streak: u32) -> Option<u16> {
+        self.streak_milestone_bps
+            .iter()
+            .find(|milestone| milestone.streak_length > 0 && milestone.streak_length == streak)
+            .map(|milestone| milestone.discount_bps)
+    }
+
+    /// Rolldown split as [Match 5, Match 4, Match 3] basis points
+    pub fn rolldown_split_bps(&self) -> [u16; 3] {
+        [
+            self.rolldown_match_5_bps,
+            self.rolldown_match_4_bps,
+            self.rolldown_match_3_bps,
+        ]
+    }
+
+    /// Whether `signer` may run a draw step gated by `permission`
+    ///
+    /// The authority may always run draw steps; the operator only when it
+    /// has been granted the matching permission bit.
+    pub fn can_run_draw_step(&self, signer: &Pubkey, permission: u64) -> bool {
+        if *signer == self.authority {
+            return true;
+        }
+        self.operator == Some(*signer) && self.operator_permissions & permission == permission
+    }
+
+    /// Time at which ticket sales for the current draw close
+    pub fn ticket_sale_cutoff_time(&self) -> Option<i64> {
+        self.next_draw_timestamp
+            .checked_sub(self.ticket_sale_cutoff_seconds)
+    }
+
     /// Check if ticket sales are open for the current draw with safety checks
     pub fn is_ticket_sale_open(&self, current_timestamp: i64) -> bool {
         // Check basic state conditions
@@ -148,7 +594,7 @@ impl LotteryState {
         }
 
         // Calculate sale cutoff time with overflow protection
-        match self.next_draw_timestamp.checked_sub(TICKET_SALE_CUTOFF) {
+        match self.ticket_sale_cutoff_time() {
             Some(cutoff_time) => current_timestamp < cutoff_time,
             None => {
                 // Underflow occurred - sale cutoff would be in the past
@@ -158,6 +604,22 @@ impl LotteryState {
         }
     }
 
+    /// Ticket price for the current jackpot level
+    ///
+    /// With dynamic pricing, the price of the highest tier whose threshold the
+    /// jackpot has reached; `ticket_price` when disabled or below every tier.
+    pub fn effective_ticket_price(&self) -> u64 {
+        if !self.dynamic_price_enabled {
+            return self.ticket_price;
+        }
+
+        self.price_tiers
+            .iter()
+            .rev()
+            .find(|tier| tier.threshold <= self.jackpot_balance)
+            .map_or(self.ticket_price, |tier| tier.price)
+    }
+
     /// Calculate current house fee based on jackpot level with validation
     pub fn get_current_house_fee_bps(&self) -> u16 {
         // Validate state before calculation
@@ -190,7 +652,11 @@ impl LotteryState {
             return 0; // Invalid configuration
         }
 
-        calculate_rolldown_probability_bps(self.jackpot_balance)
+        calculate_rolldown_probability_bps_for_caps(
+            self.jackpot_balance,
+            self.soft_cap,
+            self.hard_cap,
+        )
     }
 
     /// Check if the draw commit has timed out with safety checks
@@ -210,6 +676,57 @@ impl LotteryState {
         }
     }
 
+    /// Check if the executed draw has gone unfinalized past the timeout and grace period
+    pub fn is_finalize_overdue(&self, current_timestamp: i64) -> bool {
+        self.is_draw_in_progress
+            && is_finalize_overdue(self.execute_draw_timestamp, current_timestamp)
+    }
+
+    /// Raise the season and all-time jackpot highs to the current balance
+    ///
+    /// Returns the previous all-time high when a new one was set.
+    pub fn record_jackpot_high(&mut self, current_timestamp: i64) -> Option<u64> {
+        self.jackpot_ath_for_season = self.jackpot_ath_for_season.max(self.jackpot_balance);
+        if self.jackpot_balance <= self.jackpot_all_time_high {
+            return None;
+        }
+
+        let previous_ath = self.jackpot_all_time_high;
+        self.jackpot_all_time_high = self.jackpot_balance;
+        self.jackpot_ath_draw_id = self.current_draw_id;
+        self.jackpot_ath_timestamp = current_timestamp;
+        Some(previous_ath)
+    }
+
+    /// Whether `draw_id` falls inside the cooldown after a forced rolldown
+    pub fn is_rolldown_cooldown_active(&self, draw_id: u64) -> bool {
+        draw_id <= self.rolldown_cooldown_until_draw
+    }
+
+    /// Start the rolldown cooldown after a forced rolldown of the current
+    /// draw, returning the last draw ID it covers
+    pub fn start_rolldown_cooldown(&mut self) -> u64 {
+        self.rolldown_cooldown_until_draw = self
+            .current_draw_id
+            .saturating_add(self.rolldown_cooldown_draws as u64);
+        self.rolldown_cooldown_until_draw
+    }
+
+    /// Start a new house fee withdrawal window if the current one has elapsed
+    pub fn roll_withdrawal_window(&mut self, current_timestamp: i64) {
+        if current_timestamp.saturating_sub(self.withdrawal_window_start)
+            >= HOUSE_FEE_WITHDRAWAL_WINDOW_SECONDS
+        {
+            self.withdrawal_window_start = current_timestamp;
+            self.withdrawals_in_window = 0;
+        }
+    }
+
+    /// Check if the reserve has fallen below the configured minimum
+    pub fn is_reserve_below_minimum(&self) -> bool {
+        self.reserve_balance < self.reserve_minimum
+    }
+
     /// Reset draw state (used for timeout recovery or after finalization)
     /// Includes comprehensive state cleanup
     ///
@@ -220,11 +737,35 @@ impl LotteryState {
         self.is_rolldown_active = false;
         self.commit_slot = 0;
         self.commit_timestamp = 0;
+        self.execute_draw_timestamp = 0;
         self.current_randomness_account = Pubkey::default();
+        self.committed_pull_round_id = 0;
+        self.draw_commit_hash = [0u8; 32];
         // Note: current_draw_tickets is reset separately in finalize_draw
         // to allow cancel_draw to preserve tickets for rescheduled draws
     }
 
+    /// Move on to the next draw ID
+    ///
+    /// The new draw starts with the recurring tickets bought for it, taken
+    /// from `schedule`, which is required while any are pending.
+    pub fn open_next_draw(&mut self, schedule: Option<&mut RecurringTicketSchedule>) -> Result<()> {
+        let next_draw_id = self.current_draw_id.saturating_add(1);
+        let recurring_tickets = if self.pending_recurring_tickets > 0 {
+            let schedule = schedule.ok_or(LottoError::RecurringScheduleRequired)?;
+            schedule.take(next_draw_id) as u64
+        } else {
+            0
+        };
+
+        self.current_draw_id = next_draw_id;
+        self.current_draw_tickets = recurring_tickets;
+        self.pending_recurring_tickets = self
+            .pending_recurring_tickets
+            .saturating_sub(recurring_tickets);
+        Ok(())
+    }
+
     /// Check if jackpot is properly funded (meets minimum seed amount)
     /// Returns true if jackpot >= minimum required amount
     /// Minimum is either seed_amount or a reasonable fraction of it
@@ -293,13 +834,14 @@ impl LotteryState {
         self.jackpot_balance
             .saturating_add(self.reserve_balance)
             .saturating_add(self.fixed_prize_balance)
-            .saturating_add(self.insurance_balance)
+            .saturating_add(self.insurance_tier1_balance)
     }
 
     /// Get the total safety buffer (reserve + insurance)
     /// This is the 5% buffer mentioned in documentation (3% reserve + 2% insurance)
     pub fn get_safety_buffer(&self) -> u64 {
-        self.reserve_balance.saturating_add(self.insurance_balance)
+        self.reserve_balance
+            .saturating_add(self.insurance_tier1_balance)
     }
 
     /// Check if lottery can pay out prizes for given winner counts
@@ -354,14 +896,14 @@ impl LotteryState {
         let shortfall = total_required.saturating_sub(primary_funds);
 
         // Check if insurance can cover the shortfall
-        let can_cover_with_insurance = self.insurance_balance >= shortfall;
+        let can_cover_with_insurance = self.insurance_tier1_balance >= shortfall;
 
         if can_cover_with_insurance {
             return (true, shortfall, true); // Solvent with insurance
         }
 
         // Not fully solvent even with insurance
-        let remaining_shortfall = shortfall.saturating_sub(self.insurance_balance);
+        let remaining_shortfall = shortfall.saturating_sub(self.insurance_tier1_balance);
         (false, remaining_shortfall, true) // Insurance will be used but still short
     }
 
@@ -393,12 +935,59 @@ impl LotteryState {
         remaining = remaining.saturating_sub(from_reserve);
 
         // Use insurance last (emergency)
-        let from_insurance = remaining.min(self.insurance_balance);
+        let from_insurance = remaining.min(self.insurance_tier1_balance);
         remaining = remaining.saturating_sub(from_insurance);
 
         (from_jackpot, from_reserve, from_insurance, remaining)
     }
 
+    /// Whether milestone bonus `index` can be claimed now
+    ///
+    /// The milestone must carry a bonus, be unlocked and unclaimed this
+    /// jackpot cycle. A jackpot that later falls back below the threshold
+    /// does not lock it again.
+    pub fn is_milestone_bonus_claimable(&self, index: usize) -> bool {
+        index < JACKPOT_MILESTONE_COUNT
+            && self.milestone_prizes[index] > 0
+            && self.milestone_unlocked & (1 << index) != 0
+            && !self.milestone_claimed[index]
+    }
+
+    /// Move any jackpot above `hard_cap` to the reserve
+    ///
+    /// Caps the jackpot at `hard_cap` and, outside a draw, activates the
+    /// rolldown. Returns the amount moved (0 when under the cap).
+    pub fn route_jackpot_overflow(&mut self) -> Result<u64> {
+        if self.jackpot_balance <= self.hard_cap {
+            return Ok(0);
+        }
+
+        let overflow_amount = self.jackpot_balance - self.hard_cap;
+        self.reserve_balance = self
+            .reserve_balance
+            .checked_add(overflow_amount)
+            .ok_or(LottoError::Overflow)?;
+        self.jackpot_balance = self.hard_cap;
+        if !self.is_draw_in_progress {
+            self.is_rolldown_active = true;
+        }
+        Ok(overflow_amount)
+    }
+
+    /// Credit a ticket insurance contribution, split across both tiers
+    pub fn credit_insurance(&mut self, insurance_contribution: u64) -> Result<()> {
+        let (tier1, tier2) = split_insurance_contribution(insurance_contribution);
+        self.insurance_tier1_balance = self
+            .insurance_tier1_balance
+            .checked_add(tier1)
+            .ok_or(LottoError::Overflow)?;
+        self.insurance_tier2_balance = self
+            .insurance_tier2_balance
+            .checked_add(tier2)
+            .ok_or(LottoError::Overflow)?;
+        Ok(())
+    }
+
     /// Calculate the insurance coverage ratio
     /// Returns the percentage of potential shortfall that insurance can cover (in BPS)
     pub fn get_insurance_coverage_ratio(&self, potential_liability: u64) -> u16 {
@@ -406,7 +995,8 @@ impl LotteryState {
             return 10000; // 100% coverage if no liability
         }
 
-        let coverage = (self.insurance_balance as u128 * 10000u128) / potential_liability as u128;
+        let coverage =
+            (self.insurance_tier1_balance as u128 * 10000u128) / potential_liability as u128;
         coverage.min(10000) as u16
     }
 
@@ -414,7 +1004,7 @@ impl LotteryState {
     /// Returns true if reserve is depleted and insurance is needed
     pub fn needs_emergency_insurance(&self, required_amount: u64) -> bool {
         let available_without_insurance = self.jackpot_balance.saturating_add(self.reserve_balance);
-        required_amount > available_without_insurance && self.insurance_balance > 0
+        required_amount > available_without_insurance && self.insurance_tier1_balance > 0
     }
 
     /// Get the current fee tier description based on jackpot balance
@@ -448,6 +1038,9 @@ impl LotteryState {
 #[account]
 #[derive(Default)]
 pub struct DrawResult {
+    /// Account layout version (CURRENT_SCHEMA_VERSION when up to date)
+    pub schema_version: u8,
+
     /// Draw identifier
     pub draw_id: u64,
 
@@ -493,8 +1086,52 @@ pub struct DrawResult {
     /// `total_reclaimed <= total_committed` is enforced on every reclaim.
     pub total_reclaimed: u64,
 
+    /// VRF sample (0-9999) rolled against the rolldown probability
+    pub rolldown_vrf_sample: u16,
+
+    /// Rolldown probability (basis points) at execution time
+    pub rolldown_probability_bps: u16,
+
     /// PDA bump seed
     pub bump: u8,
+
+    /// Merkle root over the winning tickets (`SHA-256(ticket_pubkey)` leaves)
+    pub winner_merkle_root: Option<[u8; 32]>,
+
+    /// When the winner Merkle root was submitted (0 = not submitted)
+    pub merkle_root_submitted_at: i64,
+
+    /// Whether the winning numbers came from fallback randomness instead
+    /// of the Switchboard VRF result
+    pub used_fallback_randomness: bool,
+
+    /// Hash of the draw parameters fixed before randomness was revealed
+    pub commit_hash: [u8; 32],
+
+    /// When `force_finalize_draw` closed this draw (0 = not force finalized)
+    pub force_finalized_at: i64,
+
+    /// Last moment winner counts can be challenged (0 = not challengeable)
+    pub challenge_deadline: i64,
+
+    /// Challenges submitted since the last rejected review
+    pub challenge_count: u8,
+
+    /// Whether CHALLENGE_THRESHOLD challenges await the authority's review
+    pub is_under_challenge_review: bool,
+
+    /// Whether the authority upheld a challenge against this draw
+    pub is_challenge_upheld: bool,
+
+    /// SHA256 of the public explanation of a winning numbers override
+    pub override_justification: [u8; 32],
+
+    /// Whether the winning numbers were replaced by `override_winning_numbers`
+    pub was_overridden: bool,
+
+    /// Raw 32 randomness bytes the winning numbers were derived from, kept
+    /// so `audit_vrf_result` can re-derive them
+    pub vrf_result_raw: [u8; 32],
 }
 
 impl DrawResult {
@@ -528,374 +1165,2113 @@ impl DrawResult {
     pub fn get_reclaimable_amount(&self) -> u64 {
         self.total_committed.saturating_sub(self.total_reclaimed)
     }
+
+    /// Whether prize claims are frozen by a pending or upheld challenge
+    pub fn are_claims_frozen(&self) -> bool {
+        self.is_under_challenge_review || self.is_challenge_upheld
+    }
 }
 
-/// Ticket account - represents a single lottery ticket
+/// Winning numbers override waiting out its timelock
+///
+/// PDA: [PENDING_OVERRIDE_SEED, draw_id]. Created by
+/// `propose_winning_numbers_override` and closed when the override is
+/// applied or cancelled.
 #[account]
 #[derive(Default)]
-pub struct TicketData {
-    /// Ticket owner
-    pub owner: Pubkey,
+pub struct PendingOverride {
+    /// PDA bump seed
+    pub bump: u8,
 
-    /// Draw this ticket is for
+    /// Draw whose winning numbers are replaced
     pub draw_id: u64,
 
-    /// Selected numbers (sorted ascending)
-    pub numbers: [u8; 6],
+    /// Replacement winning numbers (sorted ascending)
+    pub new_numbers: [u8; NUMBERS_PER_TICKET],
 
-    /// Purchase timestamp
-    pub purchase_timestamp: i64,
+    /// SHA256 of the public explanation document
+    pub justification_hash: [u8; 32],
 
-    /// Whether prize has been claimed
-    pub is_claimed: bool,
+    /// When the override was proposed
+    pub proposed_at: i64,
 
-    /// Number of matches (set after draw)
-    pub match_count: u8,
+    /// Earliest time the override can be applied
+    pub executable_at: i64,
+}
 
-    /// Prize amount (set after draw)
-    pub prize_amount: u64,
+/// Pending transfer from insurance tier 2 to tier 1
+///
+/// PDA: [TIER2_WITHDRAWAL_SEED]. At most one is pending at a time; it is
+/// closed when executed.
+#[account]
+#[derive(Default)]
+pub struct Tier2WithdrawalRequest {
+    /// PDA bump seed
+    pub bump: u8,
 
-    /// Syndicate (if purchased through one)
-    pub syndicate: Option<Pubkey>,
+    /// USDC lamports to move from tier 2 to tier 1
+    pub amount: u64,
+
+    /// When the withdrawal was requested
+    pub requested_at: i64,
 
+    /// Earliest time the withdrawal can be executed
+    pub executable_after: i64,
+}
+
+/// A Mazel Protocol program listed in the protocol registry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RegistryEntry {
+    /// Program ID
+    pub program_id: Pubkey,
+
+    /// Which protocol program this is
+    pub program_type: ProgramType,
+
+    /// Whether the program is currently in service
+    pub is_active: bool,
+
+    /// When the program was last (re-)registered
+    pub registered_at: i64,
+
+    /// Registration version, bumped on each re-registration
+    pub version: u8,
+}
+
+/// Singleton list of Mazel Protocol programs for on-chain discovery
+///
+/// PDA: [PROTOCOL_REGISTRY_SEED]. Created on the first `register_program`
+/// with room for MAX_PROTOCOL_REGISTRY_ENTRIES; deactivated programs keep
+/// their entry.
+#[account]
+#[derive(Default)]
+pub struct ProtocolRegistry {
     /// PDA bump seed
     pub bump: u8,
+
+    /// Registered programs
+    pub entries: Vec<RegistryEntry>,
 }
 
-impl TicketData {
-    pub const LEN: usize = TICKET_SIZE;
+impl ProtocolRegistry {
+    /// The entry for `program_id`, if registered
+    pub fn find_entry(&self, program_id: &Pubkey) -> Option<&RegistryEntry> {
+        self.entries.iter().find(|e| e.program_id == *program_id)
+    }
 
-    /// Calculate matches against winning numbers
-    pub fn calculate_matches(&mut self, winning_numbers: &[u8; 6]) {
-        self.match_count = calculate_match_count(&self.numbers, winning_numbers);
+    /// Register `program_id`, or re-register and reactivate it under the
+    /// next version; returns the entry's version
+    pub fn register(
+        &mut self,
+        program_id: Pubkey,
+        program_type: ProgramType,
+        current_timestamp: i64,
+    ) -> Result<u8> {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.program_id == program_id) {
+            entry.version = entry.version.checked_add(1).ok_or(LottoError::Overflow)?;
+            entry.program_type = program_type;
+            entry.is_active = true;
+            entry.registered_at = current_timestamp;
+            return Ok(entry.version);
+        }
+
+        require!(
+            self.entries.len() < MAX_PROTOCOL_REGISTRY_ENTRIES,
+            LottoError::ProtocolRegistryFull
+        );
+        self.entries.push(RegistryEntry {
+            program_id,
+            program_type,
+            is_active: true,
+            registered_at: current_timestamp,
+            version: 1,
+        });
+        Ok(1)
+    }
+
+    /// Mark `program_id` as no longer in service
+    pub fn deactivate(&mut self, program_id: &Pubkey) -> Result<()> {
+        let entry = self
+            .entries
+            .iter_mut()
+            .find(|e| e.program_id == *program_id)
+            .ok_or(LottoError::ProgramNotRegistered)?;
+        entry.is_active = false;
+        Ok(())
     }
 }
 
-/// User statistics account - tracks player participation and achievements
+/// One challenger's dispute of a draw's winner counts
+///
+/// PDA: [CHALLENGE_SEED, draw_result, challenger], so each wallet counts
+/// once per draw.
 #[account]
 #[derive(Default)]
-pub struct UserStats {
-    /// User's wallet address
-    pub wallet: Pubkey,
+pub struct DrawChallenge {
+    /// PDA bump seed
+    pub bump: u8,
 
-    /// Total tickets purchased (lifetime)
-    pub total_tickets: u64,
+    /// Challenged draw
+    pub draw_id: u64,
 
-    /// Total USDC spent (lifetime)
-    pub total_spent: u64,
+    /// Wallet that submitted the challenge
+    pub challenger: Pubkey,
 
-    /// Total USDC won (lifetime)
-    pub total_won: u64,
+    /// Hash of the off-chain evidence
+    pub evidence_hash: [u8; 32],
 
-    /// Current consecutive draw participation streak
-    pub current_streak: u32,
+    /// Match tier whose winner count is disputed (2-6)
+    pub disputed_tier: u8,
 
-    /// Best streak achieved
-    pub best_streak: u32,
+    /// When the challenge was submitted
+    pub submitted_at: i64,
+}
 
-    /// Number of jackpot wins
-    pub jackpot_wins: u32,
+/// Compact record of a finalized draw kept in the `DrawHistoryBuffer`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct DrawHistorySummary {
+    /// Draw identifier
+    pub draw_id: u64,
 
-    /// Last draw ID where user participated
-    pub last_draw_participated: u64,
+    /// Winning numbers (sorted ascending)
+    pub winning_numbers: [u8; 6],
 
-    /// Number of tickets purchased in the current draw (for limit enforcement)
-    pub tickets_this_draw: u64,
+    /// Total tickets sold for this draw
+    pub total_tickets: u64,
 
-    /// Number of free tickets available (from Match 2 wins)
-    pub free_tickets_available: u32,
+    /// Whether the draw paid out as a rolldown
+    pub was_rolldown: bool,
+
+    /// Total prizes committed at finalization (USDC lamports)
+    pub total_prizes: u64,
+
+    /// Jackpot balance after finalization (USDC lamports)
+    pub jackpot_after: u64,
+
+    /// Draw execution timestamp
+    pub timestamp: i64,
+}
 
+/// Ring buffer of the most recent finalized draws for UIs
+///
+/// PDA: [DRAW_HISTORY_SEED]. `finalize_draw` writes each summary at `head`
+/// and advances it, overwriting the oldest entry once the buffer is full.
+#[account]
+#[derive(Default)]
+pub struct DrawHistoryBuffer {
     /// PDA bump seed
     pub bump: u8,
+
+    /// Slot the next summary is written to (0..DRAW_HISTORY_LENGTH)
+    pub head: u8,
+
+    /// Total summaries ever recorded
+    pub total_draws: u64,
+
+    /// Recorded summaries; only the first `total_draws` slots are valid
+    /// until the buffer wraps
+    pub entries: [DrawHistorySummary; DRAW_HISTORY_LENGTH],
 }
 
-impl UserStats {
-    pub const LEN: usize = USER_STATS_SIZE;
+impl DrawHistoryBuffer {
+    pub const LEN: usize = DRAW_HISTORY_BUFFER_SIZE;
 
-    /// Update streak based on current draw
-    pub fn update_streak(&mut self, current_draw_id: u64) {
-        // FIXED: Handle edge case where current_draw_id could be 0 or 1
-        if current_draw_id > 0
-            && self.last_draw_participated == current_draw_id.saturating_sub(1)
-            && self.last_draw_participated > 0
-        {
-            self.current_streak = self.current_streak.saturating_add(1);
-            if self.current_streak > self.best_streak {
-                self.best_streak = self.current_streak;
-            }
-        } else if self.last_draw_participated != current_draw_id {
-            self.current_streak = 1;
-        }
-        self.last_draw_participated = current_draw_id;
+    /// Record `summary`, overwriting the oldest entry when full
+    pub fn push(&mut self, summary: DrawHistorySummary) {
+        let index = self.head as usize % DRAW_HISTORY_LENGTH;
+        self.entries[index] = summary;
+        self.head = ((index + 1) % DRAW_HISTORY_LENGTH) as u8;
+        self.total_draws = self.total_draws.saturating_add(1);
     }
 
-    /// Calculate streak bonus (basis points)
-    pub fn get_streak_bonus_bps(&self) -> u16 {
-        // 0.5% bonus per consecutive draw, max 5%
-        let bonus = (self.current_streak as u16) * 50;
-        if bonus > 500 {
-            500
+    /// Recorded summaries, oldest first
+    pub fn chronological(&self) -> Vec<DrawHistorySummary> {
+        let len = (self.total_draws.min(DRAW_HISTORY_LENGTH as u64)) as usize;
+        let start = if len < DRAW_HISTORY_LENGTH {
+            0
         } else {
-            bonus
-        }
+            self.head as usize % DRAW_HISTORY_LENGTH
+        };
+
+        (0..len)
+            .map(|i| self.entries[(start + i) % DRAW_HISTORY_LENGTH])
+            .collect()
     }
 }
 
-// ============================================================================
-// SYNDICATE STRUCTURES
-// ============================================================================
-
-/// Syndicate member information
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
-pub struct SyndicateMember {
-    /// Member wallet
-    pub wallet: Pubkey,
+/// Reserve state after a finalized draw, kept in the `ReserveHistory`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct ReserveSnapshot {
+    /// Draw identifier
+    pub draw_id: u64,
 
-    /// Amount contributed in USDC lamports
-    pub contribution: u64,
+    /// Reserve balance after finalization (USDC lamports)
+    pub reserve_balance: u64,
 
-    /// Share percentage in basis points (10000 = 100%)
-    pub share_percentage_bps: u16,
+    /// Total prizes committed at finalization (USDC lamports)
+    pub prizes_distributed: u64,
 
-    /// Unclaimed prize balance in USDC lamports.
-    /// Snapshot-based: set during distribute_syndicate_prize based on
-    /// share_percentage_bps at distribution time. Decremented on claim.
-    /// Prevents race condition where early claimers reduce the live token
-    /// balance and later claimers get less than their fair share.
-    pub unclaimed_prize: u64,
+    /// Total tickets sold for this draw
+    pub tickets_sold: u64,
 }
 
-impl SyndicateMember {
-    pub const LEN: usize = SYNDICATE_MEMBER_SIZE;
+/// Ring buffer of reserve snapshots for reserve health monitoring
+///
+/// PDA: [RESERVE_HISTORY_SEED]. `finalize_draw` writes each snapshot at
+/// `head` and advances it, overwriting the oldest entry once the buffer is
+/// full.
+#[account]
+#[derive(Default)]
+pub struct ReserveHistory {
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Slot the next snapshot is written to (0..RESERVE_HISTORY_LENGTH)
+    pub head: u8,
+
+    /// Total snapshots ever recorded
+    pub total_snapshots: u64,
+
+    /// Recorded snapshots; only the first `total_snapshots` slots are valid
+    /// until the buffer wraps
+    pub snapshots: [ReserveSnapshot; RESERVE_HISTORY_LENGTH],
+}
+
+impl ReserveHistory {
+    pub const LEN: usize = RESERVE_HISTORY_SIZE;
+
+    /// Record `snapshot`, overwriting the oldest entry when full
+    pub fn push(&mut self, snapshot: ReserveSnapshot) {
+        let index = self.head as usize % RESERVE_HISTORY_LENGTH;
+        self.snapshots[index] = snapshot;
+        self.head = ((index + 1) % RESERVE_HISTORY_LENGTH) as u8;
+        self.total_snapshots = self.total_snapshots.saturating_add(1);
+    }
+
+    /// Recorded snapshots, oldest first
+    pub fn chronological(&self) -> Vec<ReserveSnapshot> {
+        let len = (self.total_snapshots.min(RESERVE_HISTORY_LENGTH as u64)) as usize;
+        let start = if len < RESERVE_HISTORY_LENGTH {
+            0
+        } else {
+            self.head as usize % RESERVE_HISTORY_LENGTH
+        };
+
+        (0..len)
+            .map(|i| self.snapshots[(start + i) % RESERVE_HISTORY_LENGTH])
+            .collect()
+    }
+}
+
+/// Recurring tickets waiting for their draw to open
+///
+/// PDA: [RECURRING_SCHEDULE_SEED]. `buy_recurring_ticket` adds one ticket per
+/// future draw at slot `draw_id % RECURRING_SCHEDULE_LENGTH`; opening a draw
+/// moves its slot into `LotteryState::current_draw_tickets`.
+#[account]
+pub struct RecurringTicketSchedule {
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Tickets bought for each future draw, by slot
+    pub pending_tickets: [u32; RECURRING_SCHEDULE_LENGTH],
+}
+
+impl RecurringTicketSchedule {
+    pub const LEN: usize = RECURRING_SCHEDULE_SIZE;
+
+    /// Add one pending ticket to `draw_id`
+    pub fn add_ticket(&mut self, draw_id: u64) -> Result<()> {
+        let slot = &mut self.pending_tickets[Self::slot(draw_id)];
+        *slot = slot.checked_add(1).ok_or(LottoError::Overflow)?;
+        Ok(())
+    }
+
+    /// Take the pending tickets of `draw_id`, clearing its slot
+    pub fn take(&mut self, draw_id: u64) -> u32 {
+        std::mem::take(&mut self.pending_tickets[Self::slot(draw_id)])
+    }
+
+    fn slot(draw_id: u64) -> usize {
+        (draw_id % RECURRING_SCHEDULE_LENGTH as u64) as usize
+    }
+}
+
+/// Purchase whitelist for private betas and geofenced launches
+///
+/// PDA: [WHITELIST_SEED]. Consulted by every player ticket purchase while
+/// `LotteryState::whitelist_mode` is not `None`. The account grows one
+/// wallet at a time up to MAX_WHITELIST_WALLETS.
+#[account]
+#[derive(Default)]
+pub struct Whitelist {
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Mint a player must hold in `TokenGated` mode
+    pub gate_mint: Pubkey,
+
+    /// Wallets allowed to buy in `PlayerWhitelist` mode
+    pub allowed_wallets: Vec<Pubkey>,
+}
+
+impl Whitelist {
+    /// Calculate size for a given number of wallets
+    pub fn size_for_wallets(wallet_count: usize) -> usize {
+        WHITELIST_BASE_SIZE + (wallet_count * WHITELIST_WALLET_SIZE)
+    }
+
+    /// Whether `wallet` is whitelisted
+    pub fn contains(&self, wallet: &Pubkey) -> bool {
+        self.allowed_wallets.contains(wallet)
+    }
+}
+
+/// Off-chain identity verification of a player, attested by the identity oracle
+///
+/// PDA: [IDENTITY_SEED, player]. Required by `claim_prize_with_identity` and
+/// `claim_bulk_prize` for prizes above `identity_verification_threshold`.
+#[account]
+#[derive(Default)]
+pub struct IdentityProof {
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Verified player
+    pub player: Pubkey,
+
+    /// ISO 3166-1 alpha-2 country code of the player
+    pub jurisdiction: [u8; 2],
+
+    /// When the proof was (last) issued
+    pub issued_at: i64,
+
+    /// When the proof lapses
+    pub expires_at: i64,
+}
+
+impl IdentityProof {
+    /// Whether the proof has lapsed at `current_timestamp`
+    pub fn is_expired(&self, current_timestamp: i64) -> bool {
+        current_timestamp >= self.expires_at
+    }
+}
+
+/// Free QuickPick ticket granted for a main lottery Match 5 win
+///
+/// PDA: [FREE_QP_CREDIT_SEED, beneficiary, draw_id], with `draw_id` the
+/// QuickPick draw on sale when the prize was claimed. Redeemed by QuickPick's
+/// `buy_ticket` through `redeem_free_quickpick_credit`.
+#[account]
+#[derive(Default)]
+pub struct FreeQuickPickCredit {
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Player who may redeem the credit
+    pub beneficiary: Pubkey,
+
+    /// QuickPick draw the free ticket is for
+    pub draw_id: u64,
+
+    /// When the credit lapses
+    pub expires_at: i64,
+
+    /// Whether the free ticket has been bought
+    pub is_used: bool,
+}
+
+impl FreeQuickPickCredit {
+    /// Whether the credit has lapsed at `current_timestamp`
+    pub fn is_expired(&self, current_timestamp: i64) -> bool {
+        current_timestamp >= self.expires_at
+    }
+}
+
+/// A player's KYC approval
+///
+/// PDA: [KYC_SEED, player]. Written by the `kyc_authority` and required by
+/// every player ticket purchase while `compliance_mode` is KYC.
+#[account]
+#[derive(Default)]
+pub struct KYCRecord {
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Approved player
+    pub player: Pubkey,
+
+    /// ISO 3166-1 alpha-2 country code of the player
+    pub jurisdiction: [u8; 2],
+
+    /// When the approval was (last) granted
+    pub approved_at: i64,
+
+    /// When the approval lapses
+    pub expires_at: i64,
+
+    /// Whether the approval has been revoked
+    pub is_revoked: bool,
+}
+
+/// One prize win on the `JackpotLeaderboard`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct LeaderboardEntry {
+    /// Prize winner
+    pub winner: Pubkey,
+
+    /// Prize paid (USDC lamports); zero marks an empty slot
+    pub prize_amount: u64,
+
+    /// Draw the prize was won in
+    pub draw_id: u64,
+
+    /// Numbers matched
+    pub match_count: u8,
+
+    /// Claim timestamp
+    pub timestamp: i64,
+}
+
+/// All-time largest prize wins
+///
+/// PDA: [JACKPOT_LEADERBOARD_SEED]. Entries are ordered by `prize_amount`
+/// descending; prize claims insert qualifying wins and drop the smallest.
+#[account]
+#[derive(Default)]
+pub struct JackpotLeaderboard {
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Ranked entries, largest prize first
+    pub entries: [LeaderboardEntry; JACKPOT_LEADERBOARD_LENGTH],
+}
+
+impl JackpotLeaderboard {
+    pub const LEN: usize = JACKPOT_LEADERBOARD_SIZE;
+
+    /// Insert `entry` in rank order if it beats the last-ranked prize
+    ///
+    /// Ties rank below existing entries. Returns the 1-based rank, or None
+    /// if the prize does not qualify.
+    pub fn insert(&mut self, entry: LeaderboardEntry) -> Option<u8> {
+        let index = self
+            .entries
+            .iter()
+            .position(|e| entry.prize_amount > e.prize_amount)?;
+
+        self.entries
+            .copy_within(index..JACKPOT_LEADERBOARD_LENGTH - 1, index + 1);
+        self.entries[index] = entry;
+
+        Some((index + 1) as u8)
+    }
+
+    /// Filled entries, largest prize first
+    pub fn ranked(&self) -> Vec<LeaderboardEntry> {
+        self.entries
+            .iter()
+            .take_while(|e| e.prize_amount > 0)
+            .copied()
+            .collect()
+    }
+}
+
+/// How often each number has been drawn across all finalized draws
+///
+/// PDA: [NUMBER_FREQUENCY_SEED]. `finalize_draw` counts the six winning
+/// numbers of every draw.
+#[account]
+pub struct NumberFrequencyAccount {
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Times each number has been drawn (index = number, slot 0 unused)
+    pub frequencies: [u32; NUMBER_FREQUENCY_SLOTS],
+
+    /// Finalized draws counted so far
+    pub total_draws_recorded: u64,
+}
+
+impl Default for NumberFrequencyAccount {
+    fn default() -> Self {
+        Self {
+            bump: 0,
+            frequencies: [0; NUMBER_FREQUENCY_SLOTS],
+            total_draws_recorded: 0,
+        }
+    }
+}
+
+impl NumberFrequencyAccount {
+    pub const LEN: usize = NUMBER_FREQUENCY_SIZE;
+
+    /// Count the winning numbers of one finalized draw
+    pub fn record_draw(&mut self, winning_numbers: &[u8; NUMBERS_PER_TICKET]) -> Result<()> {
+        for &number in winning_numbers {
+            require!(
+                (MIN_NUMBER..=MAX_NUMBER).contains(&number),
+                LottoError::InvalidNumbers
+            );
+            let count = &mut self.frequencies[number as usize];
+            *count = count.checked_add(1).ok_or(LottoError::Overflow)?;
+        }
+
+        self.total_draws_recorded = self
+            .total_draws_recorded
+            .checked_add(1)
+            .ok_or(LottoError::Overflow)?;
+
+        Ok(())
+    }
+
+    /// Most frequently drawn number (lowest number on ties)
+    pub fn hot_number(&self) -> u8 {
+        (MIN_NUMBER..=MAX_NUMBER).fold(MIN_NUMBER, |best, number| {
+            if self.frequencies[number as usize] > self.frequencies[best as usize] {
+                number
+            } else {
+                best
+            }
+        })
+    }
+
+    /// Least frequently drawn number (lowest number on ties)
+    pub fn cold_number(&self) -> u8 {
+        (MIN_NUMBER..=MAX_NUMBER).fold(MIN_NUMBER, |best, number| {
+            if self.frequencies[number as usize] < self.frequencies[best as usize] {
+                number
+            } else {
+                best
+            }
+        })
+    }
+
+    /// Draws each number would have if every number came up equally often
+    pub fn expected_frequency(&self) -> u64 {
+        self.total_draws_recorded
+            .saturating_mul(NUMBERS_PER_TICKET as u64)
+            / MAX_NUMBER as u64
+    }
+}
+
+/// Lifetime lottery statistics for dashboards
+///
+/// PDA: [ANALYTICS_SEED]. Draw, rolldown and jackpot counts are updated by
+/// `finalize_draw`; the largest jackpot and player counts by `buy_ticket`
+/// and `buy_bulk`.
+#[account]
+#[derive(Default)]
+pub struct LotteryAnalytics {
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Finalized draws
+    pub total_draws: u64,
+
+    /// Finalized draws with at least one Match 6 winner
+    pub total_jackpots_won: u32,
+
+    /// Largest jackpot balance ever reached
+    pub largest_jackpot_ever: u64,
+
+    /// Draw in which `largest_jackpot_ever` was reached
+    pub largest_jackpot_draw_id: u64,
+
+    /// Finalized rolldown draws
+    pub total_rolldowns_executed: u32,
+
+    /// Prizes paid out as of the last finalized draw
+    pub total_prizes_ever_paid: u64,
+
+    /// Wallets that have ever bought a ticket
+    pub all_time_player_count: u64,
+
+    /// Wallets that have bought a ticket in the current season
+    pub current_season_player_count: u64,
+
+    /// Current season (starts at 1)
+    pub season_number: u32,
+
+    /// When the current season started
+    pub season_start: i64,
+}
+
+impl LotteryAnalytics {
+    pub const LEN: usize = LOTTERY_ANALYTICS_SIZE;
+
+    /// Record the jackpot balance after a purchase in `draw_id`
+    pub fn record_jackpot(&mut self, jackpot_balance: u64, draw_id: u64) {
+        if jackpot_balance > self.largest_jackpot_ever {
+            self.largest_jackpot_ever = jackpot_balance;
+            self.largest_jackpot_draw_id = draw_id;
+        }
+    }
+
+    /// Count the buyer of a purchase, before their stats are updated
+    ///
+    /// Starts a new season first if the current one has ended. A player is
+    /// counted once all-time and once per season.
+    pub fn record_player(&mut self, user_stats: &mut UserStats, current_timestamp: i64) {
+        if current_timestamp.saturating_sub(self.season_start) >= ANALYTICS_SEASON_SECONDS {
+            self.season_number = self.season_number.saturating_add(1);
+            self.season_start = current_timestamp;
+            self.current_season_player_count = 0;
+        }
+
+        if user_stats.total_tickets == 0 {
+            self.all_time_player_count = self.all_time_player_count.saturating_add(1);
+        }
+        if user_stats.analytics_season != self.season_number {
+            user_stats.analytics_season = self.season_number;
+            self.current_season_player_count = self.current_season_player_count.saturating_add(1);
+        }
+    }
+
+    /// Count one finalized draw
+    pub fn record_finalized_draw(
+        &mut self,
+        was_rolldown: bool,
+        jackpot_won: bool,
+        total_prizes_paid: u64,
+    ) {
+        self.total_draws = self.total_draws.saturating_add(1);
+        if was_rolldown {
+            self.total_rolldowns_executed = self.total_rolldowns_executed.saturating_add(1);
+        }
+        if jackpot_won {
+            self.total_jackpots_won = self.total_jackpots_won.saturating_add(1);
+        }
+        self.total_prizes_ever_paid = total_prizes_paid;
+    }
+}
+
+/// Winning number counts for the periodic chi-squared bias test
+///
+/// PDA: [BIAS_DETECTOR_SEED]. `finalize_draw` counts the six winning
+/// numbers of every draw and tests the distribution every
+/// BIAS_TEST_INTERVAL_DRAWS draws.
+#[account]
+pub struct NumberBiasDetector {
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Times each number has been drawn (index = number, slot 0 unused)
+    pub win_counts: [u32; NUMBER_FREQUENCY_SLOTS],
+
+    /// Finalized draws counted so far
+    pub total_draws: u32,
+
+    /// Draw at which the last bias test ran (0 = never)
+    pub last_bias_test_draw_id: u64,
+}
+
+impl Default for NumberBiasDetector {
+    fn default() -> Self {
+        Self {
+            bump: 0,
+            win_counts: [0; NUMBER_FREQUENCY_SLOTS],
+            total_draws: 0,
+            last_bias_test_draw_id: 0,
+        }
+    }
+}
+
+impl NumberBiasDetector {
+    pub const LEN: usize = NUMBER_BIAS_DETECTOR_SIZE;
+
+    /// Count the winning numbers of one finalized draw
+    pub fn record_draw(&mut self, winning_numbers: &[u8; NUMBERS_PER_TICKET]) -> Result<()> {
+        for &number in winning_numbers {
+            require!(
+                (MIN_NUMBER..=MAX_NUMBER).contains(&number),
+                LottoError::InvalidNumbers
+            );
+            let count = &mut self.win_counts[number as usize];
+            *count = count.checked_add(1).ok_or(LottoError::Overflow)?;
+        }
+
+        self.total_draws = self
+            .total_draws
+            .checked_add(1)
+            .ok_or(LottoError::Overflow)?;
+
+        Ok(())
+    }
+
+    /// Whether the draw just recorded completes a bias test interval
+    pub fn is_bias_test_due(&self) -> bool {
+        self.total_draws > 0 && self.total_draws % BIAS_TEST_INTERVAL_DRAWS == 0
+    }
+}
+
+/// Ticket account - represents a single lottery ticket
+#[account]
+#[derive(Default)]
+pub struct TicketData {
+    /// Account layout version (CURRENT_SCHEMA_VERSION when up to date)
+    pub schema_version: u8,
+
+    /// Ticket owner
+    pub owner: Pubkey,
+
+    /// Draw this ticket is for
+    pub draw_id: u64,
+
+    /// Selected numbers (sorted ascending)
+    pub numbers: [u8; 6],
+
+    /// Purchase timestamp
+    pub purchase_timestamp: i64,
+
+    /// Whether prize has been claimed
+    pub is_claimed: bool,
+
+    /// Number of matches (set after draw)
+    pub match_count: u8,
+
+    /// Prize amount (set after draw)
+    pub prize_amount: u64,
+
+    /// Syndicate (if purchased through one)
+    pub syndicate: Option<Pubkey>,
+
+    /// Wallet that originally purchased the ticket (set once, never modified).
+    /// Differs from `owner` after the ticket has been gifted.
+    pub original_owner: Pubkey,
+
+    /// Whether this ticket has been entered into its draw's second chance draw
+    pub entered_second_chance: bool,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Whether the ticket price is still owed back if its draw is cancelled
+    /// with `CancelDrawMode::RefundTickets`. Set on paid tickets at purchase,
+    /// cleared by `claim_cancelled_draw_refund`.
+    pub requires_refund: bool,
+
+    /// Wallet the owner allowed to claim this ticket's prize on their behalf
+    /// (the prize is still paid to the owner)
+    pub claim_agent: Option<Pubkey>,
+}
+
+impl TicketData {
+    pub const LEN: usize = TICKET_SIZE;
+
+    /// Calculate matches against winning numbers
+    pub fn calculate_matches(&mut self, winning_numbers: &[u8; 6]) {
+        self.match_count = calculate_match_count(&self.numbers, winning_numbers);
+    }
+}
+
+/// User statistics account - tracks player participation and achievements
+#[account]
+#[derive(Default)]
+pub struct UserStats {
+    /// Account layout version (CURRENT_SCHEMA_VERSION when up to date)
+    pub schema_version: u8,
+
+    /// User's wallet address
+    pub wallet: Pubkey,
+
+    /// Total tickets purchased (lifetime)
+    pub total_tickets: u64,
+
+    /// Total USDC spent (lifetime)
+    pub total_spent: u64,
+
+    /// Total USDC won (lifetime)
+    pub total_won: u64,
+
+    /// Current consecutive draw participation streak
+    pub current_streak: u32,
+
+    /// Best streak achieved
+    pub best_streak: u32,
+
+    /// Number of jackpot wins
+    pub jackpot_wins: u32,
+
+    /// Last draw ID where user participated
+    pub last_draw_participated: u64,
+
+    /// Number of tickets purchased in the current draw (for limit enforcement)
+    pub tickets_this_draw: u64,
+
+    /// Number of free tickets available (from Match 2 wins)
+    pub free_tickets_available: u32,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Wallet that referred this user (set once via register_referral)
+    pub referrer: Option<Pubkey>,
+
+    /// Referral credits earned, each redeemable for one free ticket
+    pub referral_credits: u32,
+
+    /// Number of referred players who reached the qualifying ticket count
+    pub referred_players_bought: u32,
+
+    // ==========================================================================
+    // WIN HISTORY (player ROI tracking)
+    // ==========================================================================
+    /// Total USDC prizes won across all claim paths (lifetime)
+    pub total_prizes_won: u64,
+
+    /// Number of distinct draws entered (lifetime)
+    pub total_draws_entered: u64,
+
+    /// Largest single USDC prize won
+    pub largest_prize_won: u64,
+
+    /// Best match count achieved on a USDC-winning ticket
+    pub best_match_count: u8,
+
+    /// Draw ID of the most recent USDC win
+    pub last_win_draw_id: u64,
+
+    /// Number of consecutive draws played up to last_played_draw_id
+    pub consecutive_draws_played: u32,
+
+    /// Most recent draw the player bought tickets for
+    pub last_played_draw_id: u64,
+
+    // ==========================================================================
+    // DAILY PURCHASE LIMIT (self-exclusion)
+    // ==========================================================================
+    /// Tickets bought in the current daily window
+    pub daily_tickets_purchased: u32,
+
+    /// Start of the current daily window
+    pub daily_window_start: i64,
+
+    /// Player's daily ticket limit (starts at DEFAULT_DAILY_TICKET_LIMIT, can only be lowered)
+    pub daily_ticket_limit: u32,
+
+    /// Purchases are blocked until this timestamp (0 = not excluded, can only be extended)
+    pub self_excluded_until: i64,
+
+    // ==========================================================================
+    // PURCHASE VELOCITY (bot detection)
+    // ==========================================================================
+    /// Tickets bought in the current hourly velocity window
+    pub tickets_last_window: u32,
+
+    /// Start of the current hourly velocity window
+    pub velocity_window_start: i64,
+
+    // ==========================================================================
+    // TERMS ACCEPTANCE
+    // ==========================================================================
+    /// Latest terms version the player has accepted
+    pub accepted_terms_version: u8,
+
+    // ==========================================================================
+    // ANALYTICS
+    // ==========================================================================
+    /// Analytics season of the player's last counted purchase (0 = never)
+    pub analytics_season: u32,
+
+    // ==========================================================================
+    // LIFETIME VALUE
+    // ==========================================================================
+    /// House fees paid on the player's tickets (lifetime)
+    pub total_house_fees_paid: u64,
+
+    /// total_prizes_won minus total_spent (negative while the player is down)
+    pub net_roi_lamports: i64,
+
+    /// When the account was created (0 for accounts created before tracking)
+    pub join_timestamp: i64,
+
+    // ==========================================================================
+    // PER-DRAW WALLET LIMIT
+    // ==========================================================================
+    /// Draw that `current_draw_tickets_count` counts purchases for
+    pub current_draw_id_purchased: u64,
+
+    /// Tickets bought in `current_draw_id_purchased` while the per-draw
+    /// wallet limit was enabled
+    pub current_draw_tickets_count: u64,
+
+    // ==========================================================================
+    // REPUTATION
+    // ==========================================================================
+    /// One point per distinct draw entered; sets the member limit of
+    /// syndicates the player creates (see `effective_max_members`)
+    pub reputation_score: u32,
+
+    // ==========================================================================
+    // PARTICIPATION STREAK
+    // ==========================================================================
+    /// Last draw counted towards `current_streak`
+    pub last_participated_draw_id: u64,
+
+    // ==========================================================================
+    // RECURRING TICKETS
+    // ==========================================================================
+    /// Draw ID of bit 0 of `recurring_draw_mask`
+    pub recurring_draw_base: u64,
+
+    /// Future draws the player holds a recurring ticket for, relative to
+    /// `recurring_draw_base`
+    pub recurring_draw_mask: u128,
+}
+
+impl UserStats {
+    pub const LEN: usize = USER_STATS_SIZE;
+
+    /// Record `count` purchases against the daily ticket limit
+    ///
+    /// Starts a new window if the current one has elapsed. Returns false,
+    /// without recording, if the purchase would exceed the limit.
+    pub fn record_daily_purchase(&mut self, count: u32, current_timestamp: i64) -> bool {
+        if is_daily_window_expired(self.daily_window_start, current_timestamp) {
+            self.daily_window_start = current_timestamp;
+            self.daily_tickets_purchased = 0;
+        }
+
+        match self.daily_tickets_purchased.checked_add(count) {
+            Some(total) if total <= self.daily_ticket_limit => {
+                self.daily_tickets_purchased = total;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Record `count` purchases in the hourly velocity window
+    ///
+    /// Starts a new window if the current one has elapsed. With a nonzero
+    /// `max_per_window`, returns false without recording if the purchase
+    /// would exceed it.
+    pub fn record_velocity_purchase(
+        &mut self,
+        count: u32,
+        current_timestamp: i64,
+        max_per_window: u32,
+    ) -> bool {
+        if is_velocity_window_expired(self.velocity_window_start, current_timestamp) {
+            self.velocity_window_start = current_timestamp;
+            self.tickets_last_window = 0;
+        }
+
+        let total = self.tickets_last_window.saturating_add(count);
+        if max_per_window > 0 && total > max_per_window {
+            return false;
+        }
+        self.tickets_last_window = total;
+        true
+    }
+
+    /// Record `count` purchases against the per-draw wallet limit
+    ///
+    /// Starts counting afresh once `current_draw_id` moves past the last
+    /// draw purchased in. Returns false, without recording the tickets, if
+    /// the purchase would exceed `max_per_draw`.
+    pub fn record_wallet_draw_purchase(
+        &mut self,
+        count: u64,
+        current_draw_id: u64,
+        max_per_draw: u64,
+    ) -> bool {
+        if self.current_draw_id_purchased < current_draw_id {
+            // The player's recurring ticket for this draw counts against it
+            self.current_draw_tickets_count = self.has_recurring_ticket(current_draw_id) as u64;
+        }
+        self.current_draw_id_purchased = current_draw_id;
+
+        match self.current_draw_tickets_count.checked_add(count) {
+            Some(total) if total <= max_per_draw => {
+                self.current_draw_tickets_count = total;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Record recurring tickets bought for `draw_ids`
+    ///
+    /// Drops draws before `current_draw_id` from the mask first; recurring
+    /// draws are at most MAX_RECURRING_DRAW_LOOKAHEAD ahead, so they fit.
+    pub fn record_recurring_draws(&mut self, draw_ids: &[u64], current_draw_id: u64) {
+        if current_draw_id > self.recurring_draw_base {
+            let shift = current_draw_id - self.recurring_draw_base;
+            self.recurring_draw_mask = if shift < u128::BITS as u64 {
+                self.recurring_draw_mask >> shift
+            } else {
+                0
+            };
+            self.recurring_draw_base = current_draw_id;
+        }
+        for draw_id in draw_ids {
+            let offset = draw_id.saturating_sub(self.recurring_draw_base);
+            if offset < u128::BITS as u64 {
+                self.recurring_draw_mask |= 1 << offset;
+            }
+        }
+    }
+
+    /// Whether the player bought a recurring ticket for `draw_id`
+    pub fn has_recurring_ticket(&self, draw_id: u64) -> bool {
+        draw_id
+            .checked_sub(self.recurring_draw_base)
+            .is_some_and(|offset| {
+                offset < u128::BITS as u64 && (self.recurring_draw_mask >> offset) & 1 == 1
+            })
+    }
+
+    /// Whether the player's self-exclusion is still running at `current_timestamp`
+    pub fn is_self_excluded(&self, current_timestamp: i64) -> bool {
+        current_timestamp <= self.self_excluded_until
+    }
+
+    /// Streak a purchase in `current_draw_id` would bring the player to
+    ///
+    /// None when the draw already counts towards the streak. Skipping one or
+    /// more draws restarts the streak at 1.
+    pub fn next_streak(&self, current_draw_id: u64) -> Option<u32> {
+        let has_streak = self.current_streak > 0;
+        if has_streak && self.last_participated_draw_id == current_draw_id {
+            return None;
+        }
+        if has_streak && self.last_participated_draw_id.saturating_add(1) == current_draw_id {
+            Some(self.current_streak.saturating_add(1))
+        } else {
+            Some(1)
+        }
+    }
+
+    /// Update streak based on current draw
+    ///
+    /// Returns the new streak on the player's first purchase in the draw.
+    pub fn update_streak(&mut self, current_draw_id: u64) -> Option<u32> {
+        let streak = self.next_streak(current_draw_id)?;
+        self.current_streak = streak;
+        self.best_streak = self.best_streak.max(streak);
+        self.last_participated_draw_id = current_draw_id;
+        Some(streak)
+    }
+
+    /// Record participation in a draw for win-history tracking
+    ///
+    /// Counts each draw once. `consecutive_draws_played` resets if the
+    /// player skipped one or more draws since `last_played_draw_id`.
+    pub fn record_draw_played(&mut self, current_draw_id: u64) -> Result<()> {
+        let has_played = self.total_draws_entered > 0;
+        if has_played && self.last_played_draw_id == current_draw_id {
+            return Ok(());
+        }
+
+        if has_played && self.last_played_draw_id < current_draw_id.saturating_sub(1) {
+            self.consecutive_draws_played = 0;
+        }
+        self.consecutive_draws_played = self
+            .consecutive_draws_played
+            .checked_add(1)
+            .ok_or(LottoError::Overflow)?;
+        self.total_draws_entered = self
+            .total_draws_entered
+            .checked_add(1)
+            .ok_or(LottoError::Overflow)?;
+        self.reputation_score = self.reputation_score.saturating_add(1);
+        self.last_played_draw_id = current_draw_id;
+        Ok(())
+    }
+
+    /// Record USDC winnings for win-history tracking
+    ///
+    /// # Arguments
+    /// * `total_amount` - Total USDC won in this claim
+    /// * `largest_single_prize` - Largest single-ticket prize within the claim
+    /// * `match_count` - Best match count within the claim (0 if unknown)
+    /// * `draw_id` - Draw the prize was won in, if known
+    pub fn record_winnings(
+        &mut self,
+        total_amount: u64,
+        largest_single_prize: u64,
+        match_count: u8,
+        draw_id: Option<u64>,
+    ) -> Result<()> {
+        if total_amount == 0 {
+            return Ok(());
+        }
+        self.total_prizes_won = self
+            .total_prizes_won
+            .checked_add(total_amount)
+            .ok_or(LottoError::Overflow)?;
+        if largest_single_prize > self.largest_prize_won {
+            self.largest_prize_won = largest_single_prize;
+        }
+        if match_count > self.best_match_count {
+            self.best_match_count = match_count;
+        }
+        if let Some(draw_id) = draw_id {
+            self.last_win_draw_id = draw_id;
+        }
+        self.refresh_net_roi();
+        Ok(())
+    }
+
+    /// Record the house fee paid on a purchase and refresh `net_roi_lamports`
+    ///
+    /// Call after `total_spent` has been updated.
+    pub fn record_house_fee(&mut self, house_fee: u64) -> Result<()> {
+        self.total_house_fees_paid = self
+            .total_house_fees_paid
+            .checked_add(house_fee)
+            .ok_or(LottoError::Overflow)?;
+        self.refresh_net_roi();
+        Ok(())
+    }
+
+    /// Recompute `net_roi_lamports` from lifetime winnings and spend
+    pub fn refresh_net_roi(&mut self) {
+        let net_roi = self.total_prizes_won as i128 - self.total_spent as i128;
+        self.net_roi_lamports = net_roi.clamp(i64::MIN as i128, i64::MAX as i128) as i64;
+    }
+
+    /// Whole days since the account was created (0 if unknown)
+    pub fn tenure_days(&self, current_timestamp: i64) -> u64 {
+        if self.join_timestamp == 0 {
+            return 0;
+        }
+        (current_timestamp.saturating_sub(self.join_timestamp) / SECONDS_PER_DAY).max(0) as u64
+    }
+
+    /// Calculate streak bonus (basis points)
+    pub fn get_streak_bonus_bps(&self) -> u16 {
+        // 0.5% bonus per consecutive draw, max 5%
+        let bonus = (self.current_streak as u16) * 50;
+        if bonus > 500 {
+            500
+        } else {
+            bonus
+        }
+    }
+}
+
+// ============================================================================
+// SYNDICATE STRUCTURES
+// ============================================================================
+
+/// Syndicate member information
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SyndicateMember {
+    /// Member wallet
+    pub wallet: Pubkey,
+
+    /// Amount contributed in USDC lamports
+    pub contribution: u64,
+
+    /// Share percentage in basis points (10000 = 100%)
+    pub share_percentage_bps: u16,
+
+    /// Unclaimed prize balance in USDC lamports.
+    /// Snapshot-based: set during finalize_staged_prize based on
+    /// share_percentage_bps at distribution time. Decremented on claim.
+    /// Prevents race condition where early claimers reduce the live token
+    /// balance and later claimers get less than their fair share.
+    pub unclaimed_prize: u64,
+}
+
+impl SyndicateMember {
+    pub const LEN: usize = SYNDICATE_MEMBER_SIZE;
+}
+
+/// Syndicate account - represents a group buying pool
+#[account]
+#[derive(Default)]
+pub struct Syndicate {
+    /// Account layout version (CURRENT_SCHEMA_VERSION when up to date)
+    pub schema_version: u8,
+
+    /// Current syndicate creator/manager (mutable — can be transferred)
+    pub creator: Pubkey,
+
+    /// Original creator at PDA creation time (immutable — used for PDA seed derivation).
+    /// This MUST NEVER be changed after initialization. All PDA seeds and CPI signer
+    /// seeds must use this field instead of `creator` to prevent fund-lock after
+    /// creator transfers. See Issue #1 in security audit.
+    pub original_creator: Pubkey,
+
+    /// Unique identifier
+    pub syndicate_id: u64,
+
+    /// Name (UTF-8, max 32 bytes)
+    pub name: [u8; 32],
+
+    /// Whether anyone can join
+    pub is_public: bool,
+
+    /// Current member count
+    pub member_count: u32,
+
+    /// Total USDC contributed
+    pub total_contribution: u64,
+
+    /// Manager fee (basis points, max 500 = 5%)
+    pub manager_fee_bps: u16,
+
+    /// Syndicate's USDC token account (PDA-controlled)
+    pub usdc_account: Pubkey,
+
+    /// List of members
+    pub members: Vec<SyndicateMember>,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Maximum total contribution per member in USDC lamports (0 = no limit)
+    pub max_contribution_per_member: u64,
+
+    /// Whether membership is frozen because tickets were bought for a draw
+    pub is_locked: bool,
+
+    /// Draw the syndicate bought tickets for while locked
+    pub locked_draw_id: u64,
+
+    /// USDC held in `usdc_account` for waitlisted wallets; not part of
+    /// `total_contribution` and not spendable on tickets
+    pub pending_usdc: u64,
+
+    /// Member limit, from the creator's reputation at creation
+    /// (see `effective_max_members`)
+    pub max_members_at_creation: u8,
+
+    /// Draw that `tickets_purchased` and `tickets_created` count for
+    pub ticket_draw_id: u64,
+
+    /// Tickets paid for by `buy_syndicate_tickets` in `ticket_draw_id`
+    pub tickets_purchased: u32,
+
+    /// Ticket accounts created so far for `ticket_draw_id`; also the index
+    /// of the next ticket PDA
+    pub tickets_created: u32,
+}
+
+impl Syndicate {
+    /// Calculate size for a given number of members
+    pub fn size_for_members(member_count: usize) -> usize {
+        SYNDICATE_BASE_SIZE + (member_count * SYNDICATE_MEMBER_SIZE)
+    }
+
+    /// Whether the syndicate has reached `max_members_at_creation`
+    pub fn is_full(&self) -> bool {
+        self.member_count >= self.max_members_at_creation as u32
+    }
+
+    /// Whether membership changes are blocked for the current draw.
+    /// The lock lapses automatically once the locked draw is finalized
+    /// (current_draw_id advances) or prizes are distributed.
+    pub fn is_locked_for_draw(&self, current_draw_id: u64) -> bool {
+        self.is_locked && self.locked_draw_id == current_draw_id
+    }
+
+    /// Count `count` tickets paid for in `draw_id`, starting the counts
+    /// over when the draw changes
+    pub fn record_tickets_purchased(&mut self, draw_id: u64, count: u32) -> Result<()> {
+        if self.ticket_draw_id != draw_id {
+            self.ticket_draw_id = draw_id;
+            self.tickets_purchased = 0;
+            self.tickets_created = 0;
+        }
+        self.tickets_purchased = self
+            .tickets_purchased
+            .checked_add(count)
+            .ok_or(LottoError::Overflow)?;
+        Ok(())
+    }
+
+    /// Take the next `count` ticket indexes in `draw_id`, returning the
+    /// first. Fails once more accounts would exist than were paid for.
+    pub fn take_ticket_indexes(&mut self, draw_id: u64, count: u32) -> Result<u32> {
+        let first_index = self.tickets_created;
+        let created = first_index.checked_add(count).ok_or(LottoError::Overflow)?;
+        require!(
+            self.ticket_draw_id == draw_id && created <= self.tickets_purchased,
+            LottoError::SyndicateTicketsNotPurchased
+        );
+        self.tickets_created = created;
+        Ok(first_index)
+    }
+
+    /// Whether `wallet` is the manager or a current member
+    pub fn is_manager_or_member(&self, wallet: &Pubkey) -> bool {
+        self.creator == *wallet || self.find_member(wallet).is_some()
+    }
+
+    /// Check a member's total contribution against the per-member cap
+    pub fn is_within_contribution_cap(&self, contribution: u64) -> bool {
+        self.max_contribution_per_member == 0 || contribution <= self.max_contribution_per_member
+    }
+
+    /// Largest contribution held by any current member
+    pub fn largest_member_contribution(&self) -> u64 {
+        self.members
+            .iter()
+            .map(|m| m.contribution)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// USDC in the syndicate account that is not escrowed for the waitlist
+    pub fn spendable_usdc(&self, usdc_balance: u64) -> u64 {
+        usdc_balance.saturating_sub(self.pending_usdc)
+    }
+
+    /// Turn a waitlist entry's escrowed USDC into membership
+    ///
+    /// Adds the wallet as a member, or tops up its contribution if it has
+    /// since joined directly, and recalculates shares.
+    pub fn promote_from_waitlist(&mut self, entry: &WaitlistEntry) -> Result<()> {
+        self.pending_usdc = self
+            .pending_usdc
+            .checked_sub(entry.desired_contribution)
+            .ok_or(LottoError::Underflow)?;
+
+        if let Some(member) = self.members.iter_mut().find(|m| m.wallet == entry.wallet) {
+            member.contribution = member
+                .contribution
+                .checked_add(entry.desired_contribution)
+                .ok_or(LottoError::Overflow)?;
+            self.total_contribution = self
+                .total_contribution
+                .checked_add(entry.desired_contribution)
+                .ok_or(LottoError::Overflow)?;
+            self.recalculate_shares();
+            return Ok(());
+        }
+
+        self.add_member(entry.wallet, entry.desired_contribution)
+    }
+
+    /// Add a new member to the syndicate
+    pub fn add_member(&mut self, wallet: Pubkey, contribution: u64) -> Result<()> {
+        require!(!self.is_full(), LottoError::SyndicateFull);
+
+        // Check if already a member
+        for member in &self.members {
+            require!(member.wallet != wallet, LottoError::AlreadySyndicateMember);
+        }
+
+        self.members.push(SyndicateMember {
+            wallet,
+            contribution,
+            share_percentage_bps: 0, // Will be calculated
+            unclaimed_prize: 0,
+        });
+        self.total_contribution = self.total_contribution.saturating_add(contribution);
+        self.member_count = self.member_count.saturating_add(1);
+
+        // Recalculate shares
+        self.recalculate_shares();
+
+        Ok(())
+    }
+
+    /// Remove a member from the syndicate
+    /// Returns the member's contribution amount for refund
+    pub fn remove_member(&mut self, wallet: &Pubkey) -> Result<u64> {
+        let member_index = self
+            .members
+            .iter()
+            .position(|m| m.wallet == *wallet)
+            .ok_or(LottoError::NotSyndicateMember)?;
+
+        let contribution = self.members[member_index].contribution;
+
+        // Remove the member
+        self.members.remove(member_index);
+        self.member_count = self.member_count.saturating_sub(1);
+        self.total_contribution = self.total_contribution.saturating_sub(contribution);
+
+        // Recalculate shares
+        self.recalculate_shares();
+
+        Ok(contribution)
+    }
+
+    /// Recalculate member shares based on contributions
+    /// FIXED: Ensures total shares always sum to exactly 10000 BPS
+    pub fn recalculate_shares(&mut self) {
+        if self.members.is_empty() {
+            return;
+        }
+
+        if self.total_contribution == 0 {
+            // If no contributions, distribute equally
+            // Use largest remainder method to ensure sum = 10000
+            let base_share = BPS_DENOMINATOR as u16 / self.member_count as u16;
+            let remainder = BPS_DENOMINATOR as u16 % self.member_count as u16;
+
+            for (i, member) in self.members.iter_mut().enumerate() {
+                // Give 1 extra BPS to the first 'remainder' members
+                member.share_percentage_bps = if (i as u16) < remainder {
+                    base_share + 1
+                } else {
+                    base_share
+                };
+            }
+            return;
+        }
+
+        // Calculate initial shares using integer division
+        let mut total_assigned: u16 = 0;
+        let mut shares: Vec<(usize, u16, u64)> = Vec::with_capacity(self.members.len());
+
+        for (i, member) in self.members.iter().enumerate() {
+            let share = ((member.contribution as u128 * BPS_DENOMINATOR as u128)
+                / self.total_contribution as u128) as u16;
+            shares.push((i, share, member.contribution));
+            total_assigned += share;
+        }
+
+        // Distribute remaining BPS to members with highest contributions
+        // This ensures the total always sums to exactly 10000
+        let mut remainder = BPS_DENOMINATOR as u16 - total_assigned;
+
+        if remainder > 0 {
+            // Sort by contribution descending to give remainder to largest contributors
+            shares.sort_by(|a, b| b.2.cmp(&a.2));
+
+            for (idx, share, _) in shares.iter_mut() {
+                if remainder == 0 {
+                    break;
+                }
+                *share += 1;
+                remainder -= 1;
+                // Update the actual member
+                self.members[*idx].share_percentage_bps = *share;
+            }
+
+            // For members not getting extra, set their calculated share
+            for (idx, share, _) in &shares {
+                if self.members[*idx].share_percentage_bps != *share {
+                    self.members[*idx].share_percentage_bps = *share;
+                }
+            }
+        } else {
+            // No remainder, just set the shares directly
+            for (idx, share, _) in &shares {
+                self.members[*idx].share_percentage_bps = *share;
+            }
+        }
+    }
+
+    /// Find a member by wallet address
+    pub fn find_member(&self, wallet: &Pubkey) -> Option<&SyndicateMember> {
+        self.members.iter().find(|m| m.wallet == *wallet)
+    }
+
+    /// Find a member mutably by wallet address
+    pub fn find_member_mut(&mut self, wallet: &Pubkey) -> Option<&mut SyndicateMember> {
+        self.members.iter_mut().find(|m| m.wallet == *wallet)
+    }
+
+    /// Get total funds in syndicate (contribution + prizes)
+    pub fn get_total_funds(&self, syndicate_usdc_balance: u64) -> u64 {
+        self.total_contribution
+            .saturating_add(syndicate_usdc_balance)
+    }
+
+    /// Calculate member's share amount based on current syndicate funds
+    pub fn calculate_member_share(
+        &self,
+        member_wallet: &Pubkey,
+        syndicate_usdc_balance: u64,
+    ) -> Option<u64> {
+        let member = self.find_member(member_wallet)?;
+        let total_funds = self.get_total_funds(syndicate_usdc_balance);
+
+        Some(
+            (total_funds as u128 * member.share_percentage_bps as u128 / BPS_DENOMINATOR as u128)
+                as u64,
+        )
+    }
+
+    /// Check if syndicate meets minimum requirements for Syndicate Wars
+    pub fn meets_wars_requirements(&self) -> bool {
+        self.member_count >= 5 && self.total_contribution > 0
+    }
+
+    /// Get syndicate statistics for display
+    pub fn get_stats(&self) -> SyndicateStats {
+        SyndicateStats {
+            member_count: self.member_count,
+            total_contribution: self.total_contribution,
+            manager_fee_bps: self.manager_fee_bps,
+            is_public: self.is_public,
+        }
+    }
+
+    /// Validate syndicate configuration
+    pub fn validate_config(&self) -> Result<()> {
+        require!(
+            self.manager_fee_bps <= MAX_MANAGER_FEE_BPS,
+            LottoError::ManagerFeeTooHigh
+        );
+
+        // Check name is not empty
+        let mut has_content = false;
+        for &byte in &self.name {
+            if byte != 0 {
+                has_content = true;
+                break;
+            }
+        }
+        require!(has_content, LottoError::InvalidSyndicateConfig);
+
+        Ok(())
+    }
 }
 
-/// Syndicate account - represents a group buying pool
+/// Invitation to join a private syndicate
+///
+/// PDA: [SYNDICATE_INVITE_SEED, syndicate, code_hash]. Only the SHA-256 hash
+/// of the plain invite code is stored on-chain; the creator shares the plain
+/// code off-chain and joiners submit it with join_syndicate.
 #[account]
 #[derive(Default)]
-pub struct Syndicate {
-    /// Current syndicate creator/manager (mutable — can be transferred)
-    pub creator: Pubkey,
+pub struct SyndicateInvite {
+    /// Syndicate this invite grants access to
+    pub syndicate: Pubkey,
+
+    /// SHA-256 hash of the plain invite code
+    pub code_hash: [u8; 32],
+
+    /// Number of times the invite has been used
+    pub use_count: u8,
+
+    /// Maximum number of uses
+    pub max_uses: u8,
+
+    /// Unix timestamp after which the invite is no longer valid
+    pub expires_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SyndicateInvite {
+    pub const LEN: usize = SYNDICATE_INVITE_SIZE;
+
+    /// Check the invite can be used at `current_time`
+    pub fn validate_use(&self, current_time: i64) -> Result<()> {
+        require!(current_time <= self.expires_at, LottoError::InviteExpired);
+        require!(self.use_count < self.max_uses, LottoError::InviteExhausted);
+        Ok(())
+    }
+
+    /// Remaining uses before the invite is exhausted
+    pub fn remaining_uses(&self) -> u8 {
+        self.max_uses.saturating_sub(self.use_count)
+    }
+}
+
+/// Public syndicate listing in the `SyndicateRegistry`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SyndicateRegistryEntry {
+    /// Syndicate account
+    pub syndicate: Pubkey,
+
+    /// Syndicate name (UTF-8, max 32 bytes)
+    pub name: [u8; 32],
+
+    /// Current member count
+    pub member_count: u8,
+
+    /// Total USDC contributed
+    pub total_contribution: u64,
+
+    /// Whether the syndicate has reached its member limit
+    pub is_full: bool,
+}
+
+impl SyndicateRegistryEntry {
+    /// Build the listing for `syndicate` from its current state
+    pub fn from_syndicate(key: Pubkey, syndicate: &Syndicate) -> Self {
+        Self {
+            syndicate: key,
+            name: syndicate.name,
+            member_count: syndicate.member_count.min(u8::MAX as u32) as u8,
+            total_contribution: syndicate.total_contribution,
+            is_full: syndicate.is_full(),
+        }
+    }
+}
+
+/// Singleton list of public syndicates for on-chain discovery
+///
+/// PDA: [SYNDICATE_REGISTRY_SEED]. Entries are kept in step with syndicate
+/// create, join, leave, close and config updates; the account grows one
+/// entry at a time as syndicates are listed.
+#[account]
+#[derive(Default)]
+pub struct SyndicateRegistry {
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Listed public syndicates
+    pub entries: Vec<SyndicateRegistryEntry>,
+}
+
+impl SyndicateRegistry {
+    /// Calculate size for a given number of entries
+    pub fn size_for_entries(entry_count: usize) -> usize {
+        SYNDICATE_REGISTRY_BASE_SIZE + (entry_count * SYNDICATE_REGISTRY_ENTRY_SIZE)
+    }
+
+    /// Index of the entry for `syndicate`, if listed
+    pub fn find_entry(&self, syndicate: &Pubkey) -> Option<usize> {
+        self.entries.iter().position(|e| e.syndicate == *syndicate)
+    }
+
+    /// Remove the entry for `syndicate`; returns whether one was listed
+    pub fn remove_entry(&mut self, syndicate: &Pubkey) -> bool {
+        match self.find_entry(syndicate) {
+            Some(index) => {
+                self.entries.swap_remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Wallet waiting for a place in a full syndicate
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct WaitlistEntry {
+    /// Waiting wallet
+    pub wallet: Pubkey,
+
+    /// USDC escrowed in the syndicate account, contributed on promotion
+    pub desired_contribution: u64,
+
+    /// Timestamp the wallet joined the waitlist
+    pub joined_at: i64,
+}
+
+/// First-come, first-served waitlist for a full syndicate
+///
+/// PDA: [SYNDICATE_WAITLIST_SEED, syndicate]. Escrowed USDC sits in the
+/// syndicate's token account and is tracked in `Syndicate::pending_usdc`.
+/// `leave_syndicate` promotes the first entry into the freed place.
+#[account]
+#[derive(Default)]
+pub struct SyndicateWaitlist {
+    /// Syndicate this waitlist belongs to
+    pub syndicate: Pubkey,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Waiting wallets in arrival order
+    pub waitlist: Vec<WaitlistEntry>,
+}
+
+impl SyndicateWaitlist {
+    /// Calculate size for a given number of entries
+    pub fn size_for_entries(entry_count: usize) -> usize {
+        SYNDICATE_WAITLIST_BASE_SIZE + (entry_count * WAITLIST_ENTRY_SIZE)
+    }
+
+    /// Whether `wallet` is waiting
+    pub fn contains(&self, wallet: &Pubkey) -> bool {
+        self.waitlist.iter().any(|e| e.wallet == *wallet)
+    }
+
+    /// Remove and return the entry for `wallet`, keeping the order of the rest
+    pub fn remove(&mut self, wallet: &Pubkey) -> Option<WaitlistEntry> {
+        let index = self.waitlist.iter().position(|e| e.wallet == *wallet)?;
+        Some(self.waitlist.remove(index))
+    }
+
+    /// Remove and return the longest-waiting entry
+    pub fn pop_front(&mut self) -> Option<WaitlistEntry> {
+        if self.waitlist.is_empty() {
+            return None;
+        }
+        Some(self.waitlist.remove(0))
+    }
+}
+
+/// Pending manager fee increase for a syndicate
+///
+/// PDA: [SYNDICATE_FEE_PROPOSAL_SEED, syndicate]. Created by
+/// update_syndicate_config and reused for later proposals; `proposed_at`
+/// is 0 while nothing is pending.
+#[account]
+#[derive(Default)]
+pub struct SyndicateFeeProposal {
+    /// Syndicate the proposal belongs to
+    pub syndicate: Pubkey,
+
+    /// Manager fee to apply, in basis points
+    pub proposed_fee_bps: u16,
+
+    /// When the increase was proposed (0 = no pending proposal)
+    pub proposed_at: i64,
+
+    /// When the manager can apply the increase if no member has vetoed it
+    pub executable_after: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SyndicateFeeProposal {
+    pub const LEN: usize = SYNDICATE_FEE_PROPOSAL_SIZE;
+
+    /// Whether a fee increase is waiting to be applied or vetoed
+    pub fn is_pending(&self) -> bool {
+        self.proposed_at != 0
+    }
+
+    /// Drop the pending proposal
+    pub fn clear(&mut self) {
+        self.proposed_fee_bps = 0;
+        self.proposed_at = 0;
+        self.executable_after = 0;
+    }
+}
+
+/// Prize won by a syndicate's tickets in one draw, awaiting transfer
+///
+/// PDA: [SYNDICATE_PRIZE_STAGE_SEED, syndicate, draw_id]. Filled in batches
+/// by stage_syndicate_prize and closed by finalize_staged_prize, which
+/// moves `staged_amount` to the syndicate in a single transfer, less the
+/// `escrowed_amount` it moves to the syndicate's escrow for the draw.
+#[account]
+#[derive(Default)]
+pub struct SyndicatePrizeStage {
+    /// Syndicate the prize belongs to
+    pub syndicate: Pubkey,
+
+    /// Draw the tickets were entered in
+    pub draw_id: u64,
+
+    /// Prize accumulated across all staged batches
+    pub staged_amount: u64,
+
+    /// Tickets marked claimed so far
+    pub tickets_staged: u32,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Part of `staged_amount` won at each tier, Match 2 through Match 6
+    pub prize_by_match: [u64; 5],
+
+    /// Part of `staged_amount` from Match 6 prizes above the escrow threshold
+    pub escrowed_amount: u64,
+}
+
+impl SyndicatePrizeStage {
+    pub const LEN: usize = SYNDICATE_PRIZE_STAGE_SIZE;
+
+    /// Add a verified ticket and its prize to the stage, marking the prize
+    /// for escrow when `escrowed` is set
+    pub fn record_ticket(&mut self, match_count: u8, prize: u64, escrowed: bool) -> Result<()> {
+        self.tickets_staged = self
+            .tickets_staged
+            .checked_add(1)
+            .ok_or(LottoError::Overflow)?;
+        if prize == 0 {
+            return Ok(());
+        }
+
+        let tier = match_count
+            .checked_sub(2)
+            .filter(|&tier| (tier as usize) < self.prize_by_match.len())
+            .ok_or(LottoError::InvalidMatchCount)? as usize;
+        self.prize_by_match[tier] = self.prize_by_match[tier]
+            .checked_add(prize)
+            .ok_or(LottoError::Overflow)?;
+        self.staged_amount = self
+            .staged_amount
+            .checked_add(prize)
+            .ok_or(LottoError::Overflow)?;
+        if escrowed {
+            self.escrowed_amount = self
+                .escrowed_amount
+                .checked_add(prize)
+                .ok_or(LottoError::Overflow)?;
+        }
+        Ok(())
+    }
+
+    /// Part of `staged_amount` paid to the syndicate at finalization
+    pub fn paid_amount(&self) -> u64 {
+        self.staged_amount.saturating_sub(self.escrowed_amount)
+    }
+
+    /// Staged prize per match count, Match 2 through Match 6
+    pub fn tier_prizes(&self) -> impl Iterator<Item = (u8, u64)> + '_ {
+        (2u8..).zip(self.prize_by_match.iter().copied())
+    }
+}
+
+// ============================================================================
+// SECOND CHANCE STRUCTURES
+// ============================================================================
+
+/// Second chance draw for non-winning tickets of a finalized main draw
+///
+/// PDA: [SECOND_CHANCE_SEED, draw_id]. The prize is allocated from
+/// `lottery_state.reserve_balance`; the USDC stays in the prize pool token
+/// account until the winner is paid.
+#[account]
+#[derive(Default)]
+pub struct SecondChanceDraw {
+    /// Main draw whose non-winning tickets are eligible
+    pub draw_id: u64,
+
+    /// Number of tickets entered
+    pub entry_count: u64,
+
+    /// Prize allocated to the winner
+    pub prize_pool: u64,
+
+    /// Winning ticket owner (set once resolved)
+    pub winner: Option<Pubkey>,
+
+    /// Whether the winner has been drawn and paid
+    pub is_resolved: bool,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Switchboard randomness account committed for the draw
+    /// (default until committed)
+    pub randomness_account: Pubkey,
+
+    /// Seed slot of the committed randomness
+    pub commit_slot: u64,
+}
+
+impl SecondChanceDraw {
+    pub const LEN: usize = SECOND_CHANCE_DRAW_SIZE;
+
+    /// Whether randomness has been committed (entries are closed)
+    pub fn is_randomness_committed(&self) -> bool {
+        self.randomness_account != Pubkey::default()
+    }
+}
+
+/// A single ticket's entry into a second chance draw
+///
+/// PDA: [SECOND_CHANCE_ENTRY_SEED, draw_id, entry_index]. Indexing entries
+/// lets the winner be located directly from the VRF-selected index.
+#[account]
+#[derive(Default)]
+pub struct SecondChanceEntry {
+    /// Main draw this entry belongs to
+    pub draw_id: u64,
 
-    /// Original creator at PDA creation time (immutable — used for PDA seed derivation).
-    /// This MUST NEVER be changed after initialization. All PDA seeds and CPI signer
-    /// seeds must use this field instead of `creator` to prevent fund-lock after
-    /// creator transfers. See Issue #1 in security audit.
-    pub original_creator: Pubkey,
+    /// Ticket account that was entered
+    pub ticket: Pubkey,
 
-    /// Unique identifier
-    pub syndicate_id: u64,
+    /// Ticket owner at the time of entry (receives the prize)
+    pub owner: Pubkey,
 
-    /// Name (UTF-8, max 32 bytes)
-    pub name: [u8; 32],
+    /// Position of this entry within the draw (0-based)
+    pub entry_index: u64,
 
-    /// Whether anyone can join
-    pub is_public: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
 
-    /// Current member count
-    pub member_count: u32,
+impl SecondChanceEntry {
+    pub const LEN: usize = SECOND_CHANCE_ENTRY_SIZE;
+}
 
-    /// Total USDC contributed
-    pub total_contribution: u64,
+// ============================================================================
+// HOUSE FEE RECIPIENT STRUCTURES
+// ============================================================================
 
-    /// Manager fee (basis points, max 500 = 5%)
-    pub manager_fee_bps: u16,
+/// One recipient's share of the house fee
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeRecipient {
+    /// Wallet entitled to this share
+    pub recipient: Pubkey,
 
-    /// Syndicate's USDC token account (PDA-controlled)
-    pub usdc_account: Pubkey,
+    /// Share of the house fee in basis points
+    pub bps: u16,
+}
 
-    /// List of members
-    pub members: Vec<SyndicateMember>,
+/// House fee split configuration
+///
+/// PDA: [FEE_RECIPIENTS_SEED]. Each recipient index has its own USDC
+/// sub-account at [HOUSE_FEE_USDC_SEED, index] owned by the lottery state PDA.
+/// Changes are staged in `pending_recipients` and applied after the config
+/// timelock by `set_house_fee_recipients`.
+#[account]
+#[derive(Default)]
+pub struct HouseFeeRecipients {
+    /// Active split (empty = all fees go to the original house fee account)
+    pub recipients: Vec<FeeRecipient>,
+
+    /// Proposed split awaiting the timelock
+    pub pending_recipients: Vec<FeeRecipient>,
+
+    /// Unix timestamp from which the pending split can be applied (0 = none)
+    pub pending_effective_at: i64,
+
+    /// Bump of each recipient sub-account (0 = not yet created)
+    pub sub_account_bumps: [u8; MAX_HOUSE_FEE_RECIPIENTS],
 
     /// PDA bump seed
     pub bump: u8,
 }
 
-impl Syndicate {
-    /// Calculate size for a given number of members
-    pub fn size_for_members(member_count: usize) -> usize {
-        SYNDICATE_BASE_SIZE + (member_count * SYNDICATE_MEMBER_SIZE)
-    }
+impl HouseFeeRecipients {
+    pub const LEN: usize = HOUSE_FEE_RECIPIENTS_SIZE;
 
-    /// Add a new member to the syndicate
-    pub fn add_member(&mut self, wallet: Pubkey, contribution: u64) -> Result<()> {
-        require!(
-            (self.member_count as usize) < MAX_SYNDICATE_MEMBERS,
-            LottoError::SyndicateFull
-        );
+    /// Whether a split is currently applied to purchases
+    pub fn is_split_active(&self) -> bool {
+        !self.recipients.is_empty()
+    }
+}
 
-        // Check if already a member
-        for member in &self.members {
-            require!(member.wallet != wallet, LottoError::AlreadySyndicateMember);
-        }
+// ============================================================================
+// PRIZE ESCROW STRUCTURES
+// ============================================================================
 
-        self.members.push(SyndicateMember {
-            wallet,
-            contribution,
-            share_percentage_bps: 0, // Will be calculated
-            unclaimed_prize: 0,
-        });
-        self.total_contribution = self.total_contribution.saturating_add(contribution);
-        self.member_count = self.member_count.saturating_add(1);
+/// Escrowed jackpot prize awaiting release
+///
+/// PDA: [ESCROW_SEED, ticket]. The USDC is held in a token account at
+/// [ESCROW_USDC_SEED, ticket] owned by the lottery state PDA. A syndicate's
+/// escrow is seeded by [syndicate, draw_id] instead, with `ticket` and
+/// `beneficiary` both set to the syndicate.
+#[account]
+#[derive(Default)]
+pub struct PrizeEscrow {
+    /// Ticket the prize was won with
+    pub ticket: Pubkey,
 
-        // Recalculate shares
-        self.recalculate_shares();
+    /// Wallet entitled to the prize
+    pub beneficiary: Pubkey,
 
-        Ok(())
-    }
+    /// Escrowed amount in USDC lamports
+    pub amount: u64,
 
-    /// Remove a member from the syndicate
-    /// Returns the member's contribution amount for refund
-    pub fn remove_member(&mut self, wallet: &Pubkey) -> Result<u64> {
-        let member_index = self
-            .members
-            .iter()
-            .position(|m| m.wallet == *wallet)
-            .ok_or(LottoError::NotSyndicateMember)?;
+    /// Unix timestamp from which the prize can be released
+    pub release_timestamp: i64,
 
-        let contribution = self.members[member_index].contribution;
+    /// Draw the prize was won in
+    pub draw_id: u64,
 
-        // Remove the member
-        self.members.remove(member_index);
-        self.member_count = self.member_count.saturating_sub(1);
-        self.total_contribution = self.total_contribution.saturating_sub(contribution);
+    /// PDA bump seed
+    pub bump: u8,
+}
 
-        // Recalculate shares
-        self.recalculate_shares();
+impl PrizeEscrow {
+    pub const LEN: usize = PRIZE_ESCROW_SIZE;
 
-        Ok(contribution)
+    /// Check whether the escrow can be released at `current_time`
+    pub fn is_releasable(&self, current_time: i64) -> bool {
+        current_time >= self.release_timestamp
     }
+}
 
-    /// Recalculate member shares based on contributions
-    /// FIXED: Ensures total shares always sum to exactly 10000 BPS
-    pub fn recalculate_shares(&mut self) {
-        if self.members.is_empty() {
-            return;
-        }
+/// Refund record for a draw cancelled with `CancelDrawMode::RefundTickets`
+///
+/// PDA: [REFUND_RECORD_SEED, draw_id]. Its existence marks the draw as
+/// cancelled for good; tickets for it are refunded TICKET_PRICE each from
+/// the reserve via `claim_cancelled_draw_refund`.
+#[account]
+#[derive(Default)]
+pub struct TicketRefundRecord {
+    /// Cancelled draw
+    pub draw_id: u64,
 
-        if self.total_contribution == 0 {
-            // If no contributions, distribute equally
-            // Use largest remainder method to ensure sum = 10000
-            let base_share = BPS_DENOMINATOR as u16 / self.member_count as u16;
-            let remainder = BPS_DENOMINATOR as u16 % self.member_count as u16;
+    /// Upper bound on refunds (tickets in the draw * TICKET_PRICE)
+    pub total_refundable: u64,
 
-            for (i, member) in self.members.iter_mut().enumerate() {
-                // Give 1 extra BPS to the first 'remainder' members
-                member.share_percentage_bps = if (i as u16) < remainder {
-                    base_share + 1
-                } else {
-                    base_share
-                };
-            }
-            return;
-        }
+    /// Refunds paid out so far
+    pub total_refunded: u64,
 
-        // Calculate initial shares using integer division
-        let mut total_assigned: u16 = 0;
-        let mut shares: Vec<(usize, u16, u64)> = Vec::with_capacity(self.members.len());
+    /// Whether refunds can still be claimed
+    pub is_active: bool,
 
-        for (i, member) in self.members.iter().enumerate() {
-            let share = ((member.contribution as u128 * BPS_DENOMINATOR as u128)
-                / self.total_contribution as u128) as u16;
-            shares.push((i, share, member.contribution));
-            total_assigned += share;
-        }
+    /// PDA bump seed
+    pub bump: u8,
+}
 
-        // Distribute remaining BPS to members with highest contributions
-        // This ensures the total always sums to exactly 10000
-        let mut remainder = BPS_DENOMINATOR as u16 - total_assigned;
+impl TicketRefundRecord {
+    pub const LEN: usize = TICKET_REFUND_RECORD_SIZE;
 
-        if remainder > 0 {
-            // Sort by contribution descending to give remainder to largest contributors
-            shares.sort_by(|a, b| b.2.cmp(&a.2));
+    /// Refund capacity left before `total_refundable` is exhausted
+    pub fn remaining(&self) -> u64 {
+        self.total_refundable.saturating_sub(self.total_refunded)
+    }
+}
 
-            for (idx, share, _) in shares.iter_mut() {
-                if remainder == 0 {
-                    break;
-                }
-                *share += 1;
-                remainder -= 1;
-                // Update the actual member
-                self.members[*idx].share_percentage_bps = *share;
-            }
+/// Ticket valid for a range of consecutive draws
+///
+/// PDA seeds: [MULTI_DRAW_TICKET_SEED, owner, start_draw_id]. Bit `n` of
+/// `claimed_draws` is set once draw `start_draw_id + n` has been claimed.
+#[account]
+#[derive(Default)]
+pub struct MultiDrawTicket {
+    /// Ticket owner
+    pub owner: Pubkey,
 
-            // For members not getting extra, set their calculated share
-            for (idx, share, _) in &shares {
-                if self.members[*idx].share_percentage_bps != *share {
-                    self.members[*idx].share_percentage_bps = *share;
-                }
-            }
-        } else {
-            // No remainder, just set the shares directly
-            for (idx, share, _) in &shares {
-                self.members[*idx].share_percentage_bps = *share;
-            }
-        }
-    }
+    /// First draw this ticket is valid for
+    pub start_draw_id: u64,
 
-    /// Find a member by wallet address
-    pub fn find_member(&self, wallet: &Pubkey) -> Option<&SyndicateMember> {
-        self.members.iter().find(|m| m.wallet == *wallet)
-    }
+    /// Last draw this ticket is valid for (inclusive)
+    pub end_draw_id: u64,
 
-    /// Find a member mutably by wallet address
-    pub fn find_member_mut(&mut self, wallet: &Pubkey) -> Option<&mut SyndicateMember> {
-        self.members.iter_mut().find(|m| m.wallet == *wallet)
-    }
+    /// Selected numbers (sorted ascending)
+    pub numbers: [u8; 6],
 
-    /// Get total funds in syndicate (contribution + prizes)
-    pub fn get_total_funds(&self, syndicate_usdc_balance: u64) -> u64 {
-        self.total_contribution
-            .saturating_add(syndicate_usdc_balance)
-    }
+    /// Bitmask of claimed draws, offset from `start_draw_id`
+    pub claimed_draws: u64,
 
-    /// Calculate member's share amount based on current syndicate funds
-    pub fn calculate_member_share(
-        &self,
-        member_wallet: &Pubkey,
-        syndicate_usdc_balance: u64,
-    ) -> Option<u64> {
-        let member = self.find_member(member_wallet)?;
-        let total_funds = self.get_total_funds(syndicate_usdc_balance);
+    /// Purchase timestamp
+    pub purchase_timestamp: i64,
 
-        Some(
-            (total_funds as u128 * member.share_percentage_bps as u128 / BPS_DENOMINATOR as u128)
-                as u64,
-        )
-    }
+    /// PDA bump seed
+    pub bump: u8,
+}
 
-    /// Check if syndicate meets minimum requirements for Syndicate Wars
-    pub fn meets_wars_requirements(&self) -> bool {
-        self.member_count >= 5 && self.total_contribution > 0
+impl MultiDrawTicket {
+    pub const LEN: usize = MULTI_DRAW_TICKET_SIZE;
+
+    /// Check whether the ticket is valid for `draw_id`
+    pub fn covers_draw(&self, draw_id: u64) -> bool {
+        self.start_draw_id <= draw_id && draw_id <= self.end_draw_id
     }
 
-    /// Get syndicate statistics for display
-    pub fn get_stats(&self) -> SyndicateStats {
-        SyndicateStats {
-            member_count: self.member_count,
-            total_contribution: self.total_contribution,
-            manager_fee_bps: self.manager_fee_bps,
-            is_public: self.is_public,
+    /// Bit for `draw_id` in `claimed_draws`, or `None` if not covered
+    fn draw_bit(&self, draw_id: u64) -> Option<u64> {
+        if !self.covers_draw(draw_id) {
+            return None;
         }
+        1u64.checked_shl(u32::try_from(draw_id - self.start_draw_id).ok()?)
     }
 
-    /// Validate syndicate configuration
-    pub fn validate_config(&self) -> Result<()> {
-        require!(
-            self.manager_fee_bps <= MAX_MANAGER_FEE_BPS,
-            LottoError::ManagerFeeTooHigh
-        );
+    /// Check whether the prize for `draw_id` has already been claimed
+    ///
+    /// Draws outside the ticket's range are reported as claimed.
+    pub fn is_draw_claimed(&self, draw_id: u64) -> bool {
+        self.draw_bit(draw_id)
+            .is_none_or(|bit| self.claimed_draws & bit != 0)
+    }
 
-        // Check name is not empty
-        let mut has_content = false;
-        for &byte in &self.name {
-            if byte != 0 {
-                has_content = true;
-                break;
+    /// Mark `draw_id` as claimed; returns false if the draw is not covered
+    pub fn mark_draw_claimed(&mut self, draw_id: u64) -> bool {
+        match self.draw_bit(draw_id) {
+            Some(bit) => {
+                self.claimed_draws |= bit;
+                true
             }
+            None => false,
         }
-        require!(has_content, LottoError::InvalidSyndicateConfig);
-
-        Ok(())
     }
 }
 
@@ -1118,13 +3494,27 @@ pub struct SyndicateWarsState {
 
     /// PDA bump
     pub bump: u8,
+
+    /// Knockout rounds in the bracket, including the finals (0 = no bracket)
+    pub bracket_rounds: u8,
+
+    /// Bracket round currently being played (0-based)
+    pub current_round: u8,
+
+    /// Matchups of the current bracket round
+    pub matchup_pairs: Vec<WarsMatchup>,
+
+    /// Share of `prize_pool` split between the two finalists (basis points)
+    pub finals_prize_bps: u16,
+
+    /// The two finalists once the bracket reaches its last round
+    /// (default keys until then)
+    pub finalists: [Pubkey; 2],
 }
 
 impl SyndicateWarsState {
     /// Account size including discriminator
-    /// NOTE: is_distributed field added in audit fix — if migrating existing
-    /// accounts, ensure reallocation covers the extra byte.
-    pub const LEN: usize = 8 + std::mem::size_of::<SyndicateWarsState>();
+    pub const LEN: usize = SYNDICATE_WARS_STATE_SIZE;
 
     /// Check if competition is open for registration
     pub fn is_registration_open(&self, current_timestamp: i64) -> bool {
@@ -1142,6 +3532,38 @@ impl SyndicateWarsState {
     pub fn duration(&self) -> i64 {
         self.end_timestamp.saturating_sub(self.start_timestamp)
     }
+
+    /// Whether the bracket has reached its final round
+    pub fn is_finals_reached(&self) -> bool {
+        self.bracket_rounds > 0 && self.current_round == self.bracket_rounds - 1
+    }
+
+    /// Part of `prize_pool` reserved for the two finalists
+    pub fn finals_prize_pool(&self) -> u64 {
+        (self.prize_pool as u128 * self.finals_prize_bps as u128 / BPS_DENOMINATOR as u128) as u64
+    }
+
+    /// Part of `prize_pool` paid out by rank (1st to 10th place)
+    pub fn ranked_prize_pool(&self) -> u64 {
+        self.prize_pool.saturating_sub(self.finals_prize_pool())
+    }
+}
+
+/// One Syndicate Wars bracket matchup
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct WarsMatchup {
+    /// Better-scored syndicate
+    pub higher_seed: Pubkey,
+
+    /// Worse-scored syndicate
+    pub lower_seed: Pubkey,
+}
+
+impl WarsMatchup {
+    /// Whether `syndicate` plays in this matchup
+    pub fn contains(&self, syndicate: &Pubkey) -> bool {
+        self.higher_seed == *syndicate || self.lower_seed == *syndicate
+    }
 }
 
 /// Syndicate Wars entry for a syndicate
@@ -1174,6 +3596,18 @@ pub struct SyndicateWarsEntry {
 
     /// PDA bump
     pub bump: u8,
+
+    /// Finals bracket prize claimed
+    pub finals_prize_claimed: bool,
+
+    /// Score submitted by the authority through `update_syndicate_wars_stats`
+    pub submitted_score: u64,
+
+    /// Score computed from ticket accounts by `compute_wars_score_on_chain`
+    pub on_chain_score: u64,
+
+    /// Last draw counted into `on_chain_score` (0 = none)
+    pub last_scored_draw_id: u64,
 }
 
 impl SyndicateWarsEntry {
@@ -1198,6 +3632,12 @@ impl SyndicateWarsEntry {
         self.tickets_purchased >= min_tickets
     }
 
+    /// Score used for rankings: the submitted score, floored by what the
+    /// ticket accounts prove on-chain
+    pub fn ranking_score(&self) -> u64 {
+        self.on_chain_score.min(self.submitted_score)
+    }
+
     /// Add stats from a draw
     pub fn add_draw_stats(&mut self, tickets: u64, prizes: u64, wins: u32) {
         self.tickets_purchased = self.tickets_purchased.saturating_add(tickets);
@@ -1300,6 +3740,17 @@ pub struct WinnerCounts {
     pub match_2: u32,
 }
 
+/// How a syndicate's draw lock was released
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SyndicateUnlockMethod {
+    /// Released by `finalize_staged_prize`
+    PrizeDistribution,
+    /// Released by `unlock_syndicate` after the draw was finalized
+    DrawFinalized,
+    /// Released by `unlock_syndicate_after_force_finalize`
+    ForceFinalized,
+}
+
 /// Prize mode - determines how prizes are calculated
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PrizeMode {
@@ -1310,6 +3761,38 @@ pub enum PrizeMode {
     PariMutuel,
 }
 
+/// How `cancel_draw` treats the tickets of the cancelled draw
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum CancelDrawMode {
+    /// Keep the draw ID so tickets carry over to the rescheduled draw
+    #[default]
+    PreserveTickets,
+    /// Abandon the draw ID and refund its tickets from the reserve
+    RefundTickets,
+}
+
+/// Kind of program listed in the protocol registry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum ProgramType {
+    /// The main 6/46 lottery program
+    #[default]
+    MainLottery,
+    /// The Quick Pick Express program
+    QuickPick,
+}
+
+/// Who may buy or redeem tickets
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum WhitelistMode {
+    /// Anyone may buy
+    #[default]
+    None,
+    /// Only wallets listed in the `Whitelist` account
+    PlayerWhitelist,
+    /// Only wallets holding the whitelist's `gate_mint`
+    TokenGated,
+}
+
 /// Parameters for ticket purchases
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct TicketPurchaseParams {
@@ -1355,8 +3838,15 @@ pub struct UnifiedTicket {
     /// Claimed status for each ticket (bitmap for efficiency)
     pub claimed_bitmap: Vec<u8>,
 
+    /// Wallet that originally purchased the tickets (set once, never modified)
+    pub original_owner: Pubkey,
+
     /// PDA bump
     pub bump: u8,
+
+    /// Next ticket index for `claim_batch_prizes_chunk`
+    /// (BATCH_CLAIM_CURSOR_COMPLETE once every ticket has been processed)
+    pub claim_cursor: u16,
 }
 
 impl UnifiedTicket {
@@ -1373,7 +3863,9 @@ impl UnifiedTicket {
         33 + // syndicate (Option<Pubkey>)
         4 +  // claimed_bitmap vector length
         ((ticket_count + 7) / 8) + // claimed_bitmap data (1 bit per ticket)
-        1 // bump
+        32 + // original_owner
+        1 +  // bump
+        2 // claim_cursor
     }
 
     /// Check if a specific ticket is claimed
@@ -1422,7 +3914,9 @@ impl Default for UnifiedTicket {
             purchase_timestamp: 0,
             syndicate: None,
             claimed_bitmap: Vec::new(),
+            original_owner: Pubkey::default(),
             bump: 0,
+            claim_cursor: 0,
         }
     }
 }
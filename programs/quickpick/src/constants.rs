@@ -11,8 +11,14 @@
 pub const QUICK_PICK_SEED: &[u8] = b"quick_pick";
 /// Seed for Quick Pick ticket PDA
 pub const QUICK_PICK_TICKET_SEED: &[u8] = b"quick_pick_ticket";
+/// Seed for Quick Pick ticket batch PDA (bulk purchases)
+pub const QUICK_PICK_TICKET_BATCH_SEED: &[u8] = b"quick_pick_ticket_batch";
 /// Seed for Quick Pick draw result PDA
 pub const QUICK_PICK_DRAW_SEED: &[u8] = b"quick_pick_draw";
+/// Seed for Quick Pick recent draw history PDA
+pub const QUICK_PICK_HISTORY_SEED: &[u8] = b"qp_history";
+/// Seed for Quick Pick winning number frequency PDA
+pub const QUICK_PICK_NUMBER_FREQUENCY_SEED: &[u8] = b"qp_number_freq";
 /// Seed for Quick Pick prize pool USDC account
 pub const PRIZE_POOL_USDC_SEED: &[u8] = b"prize_pool_usdc";
 /// Seed for Quick Pick house fee USDC account
@@ -24,10 +30,18 @@ pub const USER_SEED: &[u8] = b"user";
 /// Seed for main lottery state (used for authority verification)
 pub const LOTTERY_SEED: &[u8] = b"lottery";
 
-/// Main lottery program ID (for cross-program PDA derivation of UserStats)
+/// Main lottery program ID (target of the `verify_spend_gate` spend gate CPI)
 /// This must match the deployed main lottery program ID in Anchor.toml
 pub const MAIN_LOTTERY_PROGRAM_ID: &str = "7WyaHk2u8AgonsryMpnvbtp42CfLJFPQpyY5p9ys6FiF";
 
+/// Anchor discriminator of the main lottery's `verify_spend_gate` instruction
+/// (`sha256("global:verify_spend_gate")[..8]`)
+pub const VERIFY_SPEND_GATE_DISCRIMINATOR: [u8; 8] = [77, 138, 230, 103, 165, 165, 89, 126];
+
+/// Anchor discriminator of the main lottery's `redeem_free_quickpick_credit`
+/// instruction (`sha256("global:redeem_free_quickpick_credit")[..8]`)
+pub const REDEEM_FREE_QP_CREDIT_DISCRIMINATOR: [u8; 8] = [123, 143, 250, 75, 93, 107, 170, 163];
+
 // ============================================================================
 // GAME PARAMETERS (5/35 Matrix)
 // ============================================================================
@@ -40,16 +54,29 @@ pub const QUICK_PICK_NUMBERS: u8 = 5;
 pub const QUICK_PICK_RANGE: u8 = 35;
 /// Quick Pick draw interval: 4 hours (14400 seconds)
 pub const QUICK_PICK_INTERVAL: i64 = 14400;
-/// Ticket sale cutoff before draw (5 minutes)
+/// Default ticket sale cutoff before draw (5 minutes).
+/// Configurable via `QuickPickState::ticket_sale_cutoff_seconds`.
 pub const TICKET_SALE_CUTOFF: i64 = 300;
+/// Minimum configurable ticket sale cutoff (1 minute)
+pub const MIN_TICKET_SALE_CUTOFF: i64 = 60;
+/// Maximum tickets in a single bulk purchase
+pub const QUICK_PICK_MAX_BULK_TICKETS: usize = 50;
 
 // ============================================================================
 // ACCESS GATE
 // ============================================================================
 
 /// Quick Pick minimum spend gate: $50 lifetime main lottery spend required
+/// (default for the main lottery's `quickpick_spend_gate_threshold`)
 pub const QUICK_PICK_MIN_SPEND_GATE: u64 = 50_000_000;
 
+// ============================================================================
+// GOVERNANCE
+// ============================================================================
+
+/// Delay between proposing and executing a config change: 24 hours (in seconds)
+pub const CONFIG_TIMELOCK_DELAY: i64 = 86400;
+
 // ============================================================================
 // JACKPOT PARAMETERS
 // ============================================================================
@@ -121,6 +148,8 @@ pub const QUICK_PICK_INSURANCE_ALLOCATION_BPS: u16 = 300;
 pub const BPS_DENOMINATOR: u64 = 10000;
 /// Ticket claim expiration: 90 days (in seconds)
 pub const TICKET_CLAIM_EXPIRATION: i64 = 90 * 24 * 60 * 60;
+/// Maximum draw result / ticket pairs in a single batch claim
+pub const QUICK_PICK_MAX_BATCH_CLAIM_PAIRS: usize = 10;
 
 // ============================================================================
 // ACCOUNT SIZES
@@ -154,8 +183,12 @@ pub const QUICK_PICK_STATE_SIZE: usize = 8 +   // discriminator
     1 +    // is_rolldown_pending
     1 +    // is_paused
     1 +    // is_funded
+    1 +    // spend_gate_enabled
+    8 +    // config_timelock_end
+    32 +   // pending_config_hash
+    8 +    // ticket_sale_cutoff_seconds
     1 +    // bump
-    32; // padding for future use
+    24; // padding for future use
 
 /// Quick Pick Ticket account size
 pub const QUICK_PICK_TICKET_SIZE: usize = 8 +  // discriminator
@@ -187,6 +220,36 @@ pub const QUICK_PICK_DRAW_RESULT_SIZE: usize = 8 +  // discriminator
     1 +    // bump
     16; // padding
 
+/// Number of finalized draws kept in the Quick Pick draw history
+pub const QUICK_PICK_HISTORY_LENGTH: usize = 20;
+
+/// Size of one Quick Pick draw history entry
+pub const QUICK_PICK_HISTORY_ENTRY_SIZE: usize = 8 + // draw_id
+    5 +    // winning_numbers
+    8 +    // jackpot_balance
+    1 +    // was_rolldown
+    4 +    // match5_winners
+    4 +    // match4_winners
+    4 +    // match3_winners
+    8 +    // total_prizes
+    8; // timestamp
+
+/// Quick Pick draw history account size
+pub const QUICK_PICK_HISTORY_SIZE: usize = 8 + // discriminator
+    1 +    // head
+    8 +    // total_draws
+    1 +    // bump
+    QUICK_PICK_HISTORY_LENGTH * QUICK_PICK_HISTORY_ENTRY_SIZE; // entries
+
+/// Slots in the number frequency histogram (index = number, slot 0 unused)
+pub const QUICK_PICK_NUMBER_FREQUENCY_SLOTS: usize = QUICK_PICK_RANGE as usize + 1;
+
+/// Quick Pick number frequency account size
+pub const QUICK_PICK_NUMBER_FREQUENCY_SIZE: usize = 8 + // discriminator
+    1 +    // bump
+    QUICK_PICK_NUMBER_FREQUENCY_SLOTS * 4 + // frequencies
+    8; // total_draws_recorded
+
 // ============================================================================
 // HELPER FUNCTIONS
 // ============================================================================
@@ -264,6 +327,14 @@ pub fn calculate_quick_pick_fixed_prize(match_count: u8) -> u64 {
     }
 }
 
+/// Whether `cutoff` is a valid ticket sale cutoff for `draw_interval`
+///
+/// Must be at least MIN_TICKET_SALE_CUTOFF and at most a quarter of the
+/// draw interval.
+pub fn is_valid_ticket_sale_cutoff(cutoff: i64, draw_interval: i64) -> bool {
+    cutoff >= MIN_TICKET_SALE_CUTOFF && cutoff <= draw_interval / 4
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
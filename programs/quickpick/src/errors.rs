@@ -22,6 +22,10 @@ pub enum QuickPickError {
     #[msg("Invalid authority")]
     InvalidAuthority,
 
+    /// Account passed as the main lottery program is not MAIN_LOTTERY_PROGRAM_ID
+    #[msg("Invalid main lottery program")]
+    InvalidMainLotteryProgram,
+
     // =========================================================================
     // STATE ERRORS (6010-6019)
     // =========================================================================
@@ -61,6 +65,10 @@ pub enum QuickPickError {
     #[msg("Draw ID mismatch")]
     DrawIdMismatch,
 
+    /// Draw prizes have not been finalized yet
+    #[msg("Draw has not been finalized - prizes are not set yet")]
+    DrawNotFinalized,
+
     // =========================================================================
     // TICKET ERRORS (6020-6039)
     // =========================================================================
@@ -96,11 +104,23 @@ pub enum QuickPickError {
     #[msg("Numbers must be sorted in ascending order")]
     NumbersNotSorted,
 
+    /// Bulk purchase contains no tickets
+    #[msg("Bulk purchase must contain at least one ticket")]
+    InvalidTicketCount,
+
+    /// Bulk purchase exceeds the per-batch ticket limit
+    #[msg("Too many tickets in a single bulk purchase")]
+    TooManyTickets,
+
+    /// Batch claim accounts are not 1-10 draw result / ticket pairs
+    #[msg("Batch claim must contain 1-10 draw result and ticket account pairs")]
+    InvalidClaimBatch,
+
     // =========================================================================
     // ACCESS GATE ERRORS (6040-6049)
     // =========================================================================
     /// Insufficient main lottery spend for Quick Pick access
-    #[msg("Insufficient main lottery spend: $50 lifetime spend required")]
+    #[msg("Insufficient main lottery spend for Quick Pick access")]
     InsufficientMainLotterySpend = 6040,
 
     // =========================================================================
@@ -222,6 +242,10 @@ pub enum QuickPickError {
     #[msg("Invalid match count")]
     InvalidMatchCount,
 
+    /// Config params do not match the pending proposal, or require the timelock
+    #[msg("Configuration validation failed")]
+    ConfigValidationFailed,
+
     // =========================================================================
     // SYSTEM ERRORS (6120-6129)
     // =========================================================================
@@ -5,6 +5,9 @@
 
 use anchor_lang::prelude::*;
 
+use crate::constants::QUICK_PICK_NUMBER_FREQUENCY_SLOTS;
+use crate::state::QuickPickHistoryEntry;
+
 // ============================================================================
 // TICKET EVENTS
 // ============================================================================
@@ -20,12 +23,29 @@ pub struct QuickPickTicketPurchased {
     pub draw_id: u64,
     /// Selected numbers (5/35, sorted)
     pub numbers: [u8; 5],
-    /// Price paid in USDC lamports
+    /// Price paid in USDC lamports (0 for tickets bought with a free credit)
     pub price: u64,
     /// Purchase timestamp
     pub timestamp: i64,
 }
 
+/// Emitted when multiple Quick Pick tickets are purchased in one transaction
+#[event]
+pub struct QuickPickBulkPurchased {
+    /// Player wallet address
+    pub player: Pubkey,
+    /// Ticket batch account public key
+    pub ticket_batch: Pubkey,
+    /// Draw ID the tickets are for
+    pub draw_id: u64,
+    /// Number of tickets purchased
+    pub ticket_count: u32,
+    /// Total price paid in USDC lamports
+    pub total_price: u64,
+    /// Purchase timestamp
+    pub timestamp: i64,
+}
+
 // ============================================================================
 // DRAW EVENTS
 // ============================================================================
@@ -119,6 +139,17 @@ pub struct QuickPickPrizeClaimed {
     pub timestamp: i64,
 }
 
+/// Emitted when a player claims several Quick Pick tickets in one batch
+#[event]
+pub struct QuickPickBatchPrizeClaimed {
+    /// Player wallet address
+    pub player: Pubkey,
+    /// Unclaimed tickets settled in the batch (already claimed ones are skipped)
+    pub tickets_processed: u32,
+    /// Total prize paid in USDC lamports
+    pub total_prize: u64,
+}
+
 /// Emitted when the Quick Pick jackpot is won
 #[event]
 pub struct QuickPickJackpotWon {
@@ -140,6 +171,21 @@ pub struct QuickPickJackpotWon {
 // ADMIN EVENTS
 // ============================================================================
 
+/// Emitted when a Quick Pick configuration parameter is proposed or changed
+#[event]
+pub struct QuickPickConfigUpdated {
+    /// Parameter name
+    pub parameter: String,
+    /// Old value (booleans encoded as 0/1)
+    pub old_value: u64,
+    /// New value (booleans encoded as 0/1)
+    pub new_value: u64,
+    /// Authority who made the change
+    pub authority: Pubkey,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
 /// Emitted when Quick Pick Express is initialized
 #[event]
 pub struct QuickPickInitialized {
@@ -273,6 +319,41 @@ pub struct QuickPickDrawCancelled {
     pub timestamp: i64,
 }
 
+/// Emitted when a Quick Pick purchase is rejected after the sale cutoff
+#[event]
+pub struct QuickPickTicketSaleClosed {
+    /// Draw whose ticket sales have closed
+    pub draw_id: u64,
+    /// Time at which sales closed
+    pub cutoff_time: i64,
+    /// Tickets sold for the draw before the cutoff
+    pub tickets_sold: u64,
+}
+
+/// Snapshot of the Quick Pick winning number frequency histogram
+#[event]
+pub struct QuickPickNumberFrequencySnapshot {
+    /// Times each number has been drawn (index = number, slot 0 unused)
+    pub frequencies: [u32; QUICK_PICK_NUMBER_FREQUENCY_SLOTS],
+    /// Most frequently drawn number (lowest on ties)
+    pub hot_number: u8,
+    /// Least frequently drawn number (lowest on ties)
+    pub cold_number: u8,
+    /// Draws per number if all numbers came up equally often
+    pub expected_frequency: u64,
+    /// Finalized draws counted
+    pub total_draws: u64,
+}
+
+/// Snapshot of the recent Quick Pick draw history
+#[event]
+pub struct QuickPickHistorySnapshot {
+    /// Recent finalized draws, oldest first
+    pub entries: Vec<QuickPickHistoryEntry>,
+    /// Total draws ever recorded in the history
+    pub total_draws: u64,
+}
+
 /// Emitted when a Quick Pick draw is force finalized (emergency)
 #[event]
 pub struct QuickPickDrawForceFinalized {
@@ -2,6 +2,8 @@
 //!
 //! This module contains administrative instructions for managing Quick Pick Express:
 //! - update_config: Update Quick Pick configuration parameters
+//! - propose_quickpick_config: Propose configuration changes (starts 24hr timelock)
+//! - execute_quickpick_config: Execute proposed config changes (after timelock expires)
 //! - withdraw_house_fees: Withdraw accumulated house fees
 //! - cancel_draw: Cancel a stuck/problematic draw
 //! - force_finalize_draw: Force finalize a stuck draw
@@ -14,8 +16,8 @@ use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 use crate::constants::*;
 use crate::errors::QuickPickError;
 use crate::events::{
-    QuickPickDrawCancelled, QuickPickDrawForceFinalized, QuickPickFeeTierChanged,
-    QuickPickHouseFeesWithdrawn,
+    QuickPickConfigUpdated, QuickPickDrawCancelled, QuickPickDrawForceFinalized,
+    QuickPickFeeTierChanged, QuickPickHouseFeesWithdrawn,
 };
 use crate::state::{LotteryState, QuickPickDrawResult, QuickPickState};
 
@@ -40,6 +42,66 @@ pub struct UpdateQuickPickConfigParams {
     pub match_4_prize: Option<u64>,
     /// New Match 3 prize (optional)
     pub match_3_prize: Option<u64>,
+    /// Enable or disable the main lottery spend gate (optional, timelocked)
+    pub spend_gate_enabled: Option<bool>,
+    /// New ticket sale cutoff before each draw in seconds (optional, timelocked)
+    pub ticket_sale_cutoff_seconds: Option<i64>,
+}
+
+impl UpdateQuickPickConfigParams {
+    /// Compute a deterministic SHA256 hash of these config params for timelock verification.
+    /// Each Option field is encoded as a 0 byte (None) or a 1 byte followed by the value.
+    pub fn compute_hash(&self) -> [u8; 32] {
+        use sha2::{Digest, Sha256};
+
+        fn hash_option(hasher: &mut Sha256, value: Option<[u8; 8]>) {
+            match value {
+                Some(bytes) => {
+                    hasher.update([1u8]);
+                    hasher.update(bytes);
+                }
+                None => hasher.update([0u8]),
+            }
+        }
+
+        let mut hasher = Sha256::new();
+        hash_option(&mut hasher, self.ticket_price.map(u64::to_le_bytes));
+        hash_option(&mut hasher, self.soft_cap.map(u64::to_le_bytes));
+        hash_option(&mut hasher, self.hard_cap.map(u64::to_le_bytes));
+        hash_option(&mut hasher, self.seed_amount.map(u64::to_le_bytes));
+        hash_option(&mut hasher, self.draw_interval.map(i64::to_le_bytes));
+        hash_option(&mut hasher, self.match_4_prize.map(u64::to_le_bytes));
+        hash_option(&mut hasher, self.match_3_prize.map(u64::to_le_bytes));
+        match self.spend_gate_enabled {
+            Some(v) => {
+                hasher.update([1u8]);
+                hasher.update([v as u8]);
+            }
+            None => hasher.update([0u8]),
+        }
+        hash_option(
+            &mut hasher,
+            self.ticket_sale_cutoff_seconds.map(i64::to_le_bytes),
+        );
+
+        let result = hasher.finalize();
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(result.as_slice());
+        hash
+    }
+
+    /// Whether these params touch the spend gate (which requires the timelock)
+    ///
+    /// The threshold itself is the main lottery's
+    /// `quickpick_spend_gate_threshold`; only the on/off switch lives here.
+    pub fn changes_spend_gate(&self) -> bool {
+        self.spend_gate_enabled.is_some()
+    }
+
+    /// Whether these params must go through the config timelock
+    pub fn requires_timelock(&self) -> bool {
+        self.changes_spend_gate() || self.ticket_sale_cutoff_seconds.is_some()
+    }
 }
 
 /// Accounts required for updating Quick Pick configuration
@@ -85,7 +147,74 @@ pub fn handler_update_config(
     params: UpdateQuickPickConfigParams,
 ) -> Result<()> {
     let clock = Clock::get()?;
-    let quick_pick_state = &mut ctx.accounts.quick_pick_state;
+
+    // Spend gate and sale cutoff changes must go through
+    // propose_quickpick_config -> execute_quickpick_config
+    require!(
+        !params.requires_timelock(),
+        QuickPickError::ConfigValidationFailed
+    );
+
+    apply_config_params(
+        &mut ctx.accounts.quick_pick_state,
+        &params,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    msg!("Quick Pick config updated successfully!");
+
+    Ok(())
+}
+
+/// Emit a QuickPickConfigUpdated event for a single parameter change
+fn emit_config_updated(
+    parameter: &str,
+    old_value: u64,
+    new_value: u64,
+    authority: Pubkey,
+    timestamp: i64,
+) {
+    emit!(QuickPickConfigUpdated {
+        parameter: parameter.to_string(),
+        old_value,
+        new_value,
+        authority,
+        timestamp,
+    });
+}
+
+/// Validate the ticket sale cutoff that would result from applying `params`
+///
+/// Only checked when the cutoff or the draw interval changes.
+fn validate_ticket_sale_cutoff_params(
+    quick_pick_state: &QuickPickState,
+    params: &UpdateQuickPickConfigParams,
+) -> Result<()> {
+    if params.ticket_sale_cutoff_seconds.is_none() && params.draw_interval.is_none() {
+        return Ok(());
+    }
+    let cutoff = params
+        .ticket_sale_cutoff_seconds
+        .unwrap_or(quick_pick_state.ticket_sale_cutoff_seconds);
+    let draw_interval = params
+        .draw_interval
+        .unwrap_or(quick_pick_state.draw_interval);
+    require!(
+        is_valid_ticket_sale_cutoff(cutoff, draw_interval),
+        QuickPickError::InvalidConfig
+    );
+    Ok(())
+}
+
+/// Apply configuration changes to the Quick Pick state
+fn apply_config_params(
+    quick_pick_state: &mut QuickPickState,
+    params: &UpdateQuickPickConfigParams,
+    authority: Pubkey,
+    timestamp: i64,
+) -> Result<()> {
+    validate_ticket_sale_cutoff_params(quick_pick_state, params)?;
 
     // Track old house fee for event
     let old_fee_bps = quick_pick_state.house_fee_bps;
@@ -98,6 +227,13 @@ pub fn handler_update_config(
             quick_pick_state.ticket_price,
             ticket_price
         );
+        emit_config_updated(
+            "ticket_price",
+            quick_pick_state.ticket_price,
+            ticket_price,
+            authority,
+            timestamp,
+        );
         quick_pick_state.ticket_price = ticket_price;
     }
 
@@ -109,6 +245,13 @@ pub fn handler_update_config(
             quick_pick_state.soft_cap,
             soft_cap
         );
+        emit_config_updated(
+            "soft_cap",
+            quick_pick_state.soft_cap,
+            soft_cap,
+            authority,
+            timestamp,
+        );
         quick_pick_state.soft_cap = soft_cap;
     }
 
@@ -124,6 +267,13 @@ pub fn handler_update_config(
             quick_pick_state.hard_cap,
             hard_cap
         );
+        emit_config_updated(
+            "hard_cap",
+            quick_pick_state.hard_cap,
+            hard_cap,
+            authority,
+            timestamp,
+        );
         quick_pick_state.hard_cap = hard_cap;
     }
 
@@ -139,6 +289,13 @@ pub fn handler_update_config(
             quick_pick_state.seed_amount,
             seed_amount
         );
+        emit_config_updated(
+            "seed_amount",
+            quick_pick_state.seed_amount,
+            seed_amount,
+            authority,
+            timestamp,
+        );
         quick_pick_state.seed_amount = seed_amount;
     }
 
@@ -154,6 +311,13 @@ pub fn handler_update_config(
             quick_pick_state.draw_interval,
             draw_interval
         );
+        emit_config_updated(
+            "draw_interval",
+            quick_pick_state.draw_interval as u64,
+            draw_interval as u64,
+            authority,
+            timestamp,
+        );
         quick_pick_state.draw_interval = draw_interval;
     }
 
@@ -165,6 +329,13 @@ pub fn handler_update_config(
             quick_pick_state.match_4_prize,
             match_4_prize
         );
+        emit_config_updated(
+            "match_4_prize",
+            quick_pick_state.match_4_prize,
+            match_4_prize,
+            authority,
+            timestamp,
+        );
         quick_pick_state.match_4_prize = match_4_prize;
     }
 
@@ -176,9 +347,50 @@ pub fn handler_update_config(
             quick_pick_state.match_3_prize,
             match_3_prize
         );
+        emit_config_updated(
+            "match_3_prize",
+            quick_pick_state.match_3_prize,
+            match_3_prize,
+            authority,
+            timestamp,
+        );
         quick_pick_state.match_3_prize = match_3_prize;
     }
 
+    // Enable or disable the spend gate
+    if let Some(spend_gate_enabled) = params.spend_gate_enabled {
+        msg!(
+            "  Spend gate enabled: {} -> {}",
+            quick_pick_state.spend_gate_enabled,
+            spend_gate_enabled
+        );
+        emit_config_updated(
+            "spend_gate_enabled",
+            quick_pick_state.spend_gate_enabled as u64,
+            spend_gate_enabled as u64,
+            authority,
+            timestamp,
+        );
+        quick_pick_state.spend_gate_enabled = spend_gate_enabled;
+    }
+
+    // Update ticket sale cutoff
+    if let Some(ticket_sale_cutoff_seconds) = params.ticket_sale_cutoff_seconds {
+        msg!(
+            "  Ticket sale cutoff: {} -> {} seconds",
+            quick_pick_state.ticket_sale_cutoff_seconds,
+            ticket_sale_cutoff_seconds
+        );
+        emit_config_updated(
+            "ticket_sale_cutoff_seconds",
+            quick_pick_state.ticket_sale_cutoff_seconds as u64,
+            ticket_sale_cutoff_seconds as u64,
+            authority,
+            timestamp,
+        );
+        quick_pick_state.ticket_sale_cutoff_seconds = ticket_sale_cutoff_seconds;
+    }
+
     // Recalculate house fee if jackpot-related params changed
     let new_fee_bps = quick_pick_state.get_current_house_fee_bps();
     if new_fee_bps != old_fee_bps {
@@ -189,11 +401,120 @@ pub fn handler_update_config(
             old_fee_bps,
             new_fee_bps,
             jackpot_balance: quick_pick_state.jackpot_balance,
-            timestamp: clock.unix_timestamp,
+            timestamp,
         });
     }
 
-    msg!("Quick Pick config updated successfully!");
+    Ok(())
+}
+
+// ============================================================================
+// CONFIG TIMELOCK
+// ============================================================================
+// Spend gate changes use a two-phase timelock, mirroring the main lottery:
+//   1. propose_quickpick_config: Authority submits desired changes, starts CONFIG_TIMELOCK_DELAY countdown
+//   2. execute_quickpick_config: After the delay, authority applies the exact proposed changes
+
+/// Propose Quick Pick configuration changes (Phase 1 of timelock)
+///
+/// Stores a hash of the proposed changes and sets `config_timelock_end` to
+/// now + CONFIG_TIMELOCK_DELAY. Nothing is applied until
+/// `execute_quickpick_config` is called with the same params.
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+/// * `params` - The configuration parameters being proposed
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_propose_config(
+    ctx: Context<UpdateQuickPickConfig>,
+    params: UpdateQuickPickConfigParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let quick_pick_state = &mut ctx.accounts.quick_pick_state;
+
+    // Reject if there's already a pending proposal
+    require!(
+        quick_pick_state.config_timelock_end == 0,
+        QuickPickError::InvalidDrawState
+    );
+
+    // Pre-validate so errors surface before waiting out the timelock
+    validate_ticket_sale_cutoff_params(quick_pick_state, &params)?;
+
+    quick_pick_state.pending_config_hash = params.compute_hash();
+    quick_pick_state.config_timelock_end = clock
+        .unix_timestamp
+        .checked_add(CONFIG_TIMELOCK_DELAY)
+        .ok_or(QuickPickError::Overflow)?;
+
+    emit_config_updated(
+        "config_proposed",
+        0,
+        quick_pick_state.config_timelock_end as u64,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    );
+
+    msg!("Quick Pick configuration change PROPOSED (timelock started)");
+    msg!(
+        "  Config hash: {:?}",
+        &quick_pick_state.pending_config_hash[..8]
+    );
+    msg!(
+        "  Executable after: {} (unix timestamp)",
+        quick_pick_state.config_timelock_end
+    );
+
+    Ok(())
+}
+
+/// Execute a proposed Quick Pick configuration change (Phase 2 of timelock)
+///
+/// Applies the proposed changes once the timelock has expired. The params
+/// must hash to the value stored by `propose_quickpick_config`.
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+/// * `params` - The configuration parameters (must match proposal)
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_execute_config(
+    ctx: Context<UpdateQuickPickConfig>,
+    params: UpdateQuickPickConfigParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let quick_pick_state = &mut ctx.accounts.quick_pick_state;
+
+    // Verify there is a pending proposal whose timelock has expired
+    require!(
+        quick_pick_state.config_timelock_end != 0,
+        QuickPickError::InvalidDrawState
+    );
+    require!(
+        clock.unix_timestamp >= quick_pick_state.config_timelock_end,
+        QuickPickError::InvalidTimestamp
+    );
+
+    // Verify the params match the proposal (prevents bait-and-switch)
+    require!(
+        params.compute_hash() == quick_pick_state.pending_config_hash,
+        QuickPickError::ConfigValidationFailed
+    );
+
+    quick_pick_state.pending_config_hash = [0u8; 32];
+    quick_pick_state.config_timelock_end = 0;
+
+    apply_config_params(
+        quick_pick_state,
+        &params,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+    )?;
+
+    msg!("Quick Pick config executed successfully!");
 
     Ok(())
 }
@@ -793,6 +1114,58 @@ mod tests {
         assert!(params.draw_interval.is_none());
         assert!(params.match_4_prize.is_none());
         assert!(params.match_3_prize.is_none());
+        assert!(params.spend_gate_enabled.is_none());
+        assert!(!params.changes_spend_gate());
+    }
+
+    #[test]
+    fn test_config_hash_distinguishes_spend_gate_changes() {
+        let base = UpdateQuickPickConfigParams::default();
+        let disabled = UpdateQuickPickConfigParams {
+            spend_gate_enabled: Some(false),
+            ..Default::default()
+        };
+        let enabled = UpdateQuickPickConfigParams {
+            spend_gate_enabled: Some(true),
+            ..Default::default()
+        };
+
+        assert_eq!(base.compute_hash(), base.clone().compute_hash());
+        assert_ne!(base.compute_hash(), disabled.compute_hash());
+        assert_ne!(disabled.compute_hash(), enabled.compute_hash());
+        assert!(enabled.changes_spend_gate());
+    }
+
+    #[test]
+    fn test_validate_ticket_sale_cutoff_params() {
+        let state = QuickPickState {
+            draw_interval: QUICK_PICK_INTERVAL,
+            ticket_sale_cutoff_seconds: TICKET_SALE_CUTOFF,
+            ..Default::default()
+        };
+        let too_short = UpdateQuickPickConfigParams {
+            ticket_sale_cutoff_seconds: Some(MIN_TICKET_SALE_CUTOFF - 1),
+            ..Default::default()
+        };
+        let too_long = UpdateQuickPickConfigParams {
+            ticket_sale_cutoff_seconds: Some(QUICK_PICK_INTERVAL / 4 + 1),
+            ..Default::default()
+        };
+        let valid = UpdateQuickPickConfigParams {
+            ticket_sale_cutoff_seconds: Some(QUICK_PICK_INTERVAL / 4),
+            ..Default::default()
+        };
+        assert!(validate_ticket_sale_cutoff_params(&state, &too_short).is_err());
+        assert!(validate_ticket_sale_cutoff_params(&state, &too_long).is_err());
+        assert!(validate_ticket_sale_cutoff_params(&state, &valid).is_ok());
+        assert!(valid.requires_timelock());
+
+        // Shrinking the draw interval re-checks the existing cutoff
+        let short_interval = UpdateQuickPickConfigParams {
+            draw_interval: Some(TICKET_SALE_CUTOFF * 4 - 1),
+            ..Default::default()
+        };
+        assert!(validate_ticket_sale_cutoff_params(&state, &short_interval).is_err());
     }
 
     #[test]
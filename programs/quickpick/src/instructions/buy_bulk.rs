@@ -0,0 +1,318 @@
+//! Buy Quick Pick Bulk Instruction
+//!
+//! This instruction allows eligible players to purchase multiple Quick Pick
+//! Express tickets for the current draw in a single transaction.
+//! It handles:
+//! - Main lottery spend gate verification (once per call, when enabled)
+//! - Number validation for every entry (5 unique numbers from 1-35)
+//! - Dynamic fee calculation based on jackpot level
+//! - A single USDC transfer per destination (prize pool + house fee + insurance)
+//! - Ticket batch account creation storing all entries
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::constants::*;
+use crate::errors::QuickPickError;
+use crate::events::QuickPickBulkPurchased;
+use crate::instructions::buy_ticket::{
+    check_ticket_sale_cutoff, enforce_spend_gate, record_quick_pick_sale, split_quick_pick_payment,
+    validate_quick_pick_numbers_internal,
+};
+use crate::state::{QuickPickState, QuickPickTicketBatch};
+
+/// Parameters for buying multiple Quick Pick tickets
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BuyQuickPickBulkParams {
+    /// Number sets, one per ticket (5 numbers each, between 1 and 35)
+    pub tickets: Vec<[u8; 5]>,
+}
+
+/// Accounts required for buying multiple Quick Pick tickets
+#[derive(Accounts)]
+#[instruction(params: BuyQuickPickBulkParams)]
+pub struct BuyQuickPickBulk<'info> {
+    /// The player purchasing the tickets
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// The Quick Pick state account
+    #[account(
+        mut,
+        seeds = [QUICK_PICK_SEED],
+        bump = quick_pick_state.bump,
+        constraint = !quick_pick_state.is_paused @ QuickPickError::Paused,
+        constraint = !quick_pick_state.is_draw_in_progress @ QuickPickError::InvalidDrawState
+    )]
+    pub quick_pick_state: Account<'info, QuickPickState>,
+
+    /// The ticket batch account to be created
+    #[account(
+        init,
+        payer = player,
+        space = QuickPickTicketBatch::size_for_count(params.tickets.len()),
+        seeds = [
+            QUICK_PICK_TICKET_BATCH_SEED,
+            player.key().as_ref(),
+            &quick_pick_state.current_draw.to_le_bytes(),
+            &quick_pick_state.current_draw_tickets.to_le_bytes()
+        ],
+        bump
+    )]
+    pub ticket_batch: Account<'info, QuickPickTicketBatch>,
+
+    /// Player's USDC token account
+    #[account(
+        mut,
+        constraint = player_usdc.owner == player.key() @ QuickPickError::TokenAccountOwnerMismatch,
+        constraint = player_usdc.mint == usdc_mint.key() @ QuickPickError::InvalidUsdcMint
+    )]
+    pub player_usdc: Account<'info, TokenAccount>,
+
+    /// Prize pool USDC token account
+    #[account(
+        mut,
+        seeds = [PRIZE_POOL_USDC_SEED],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+
+    /// House fee USDC token account
+    #[account(
+        mut,
+        seeds = [HOUSE_FEE_USDC_SEED],
+        bump
+    )]
+    pub house_fee_usdc: Account<'info, TokenAccount>,
+
+    /// Insurance pool USDC token account
+    #[account(
+        mut,
+        seeds = [INSURANCE_POOL_USDC_SEED],
+        bump
+    )]
+    pub insurance_pool_usdc: Account<'info, TokenAccount>,
+
+    /// USDC mint
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// User statistics account (to verify $50 gate)
+    /// This account is owned by the main lottery program, NOT this program.
+    /// CHECK: Passed to the main lottery's `verify_spend_gate`, which checks
+    /// the PDA, owner and total_spent >= gate
+    pub user_stats: UncheckedAccount<'info>,
+
+    /// The main lottery's state account (holds the spend gate threshold)
+    /// CHECK: PDA verified by the main lottery's `verify_spend_gate`
+    pub main_lottery_state: UncheckedAccount<'info>,
+
+    /// The main lottery program
+    /// CHECK: Program ID verified against MAIN_LOTTERY_PROGRAM_ID before the CPI
+    pub main_lottery_program: UncheckedAccount<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> BuyQuickPickBulk<'info> {
+    /// Transfer USDC from player to a pool account
+    fn transfer_from_player(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: self.player_usdc.to_account_info(),
+            to,
+            authority: self.player.to_account_info(),
+        };
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, amount)
+    }
+}
+
+/// Validate the number of tickets in a bulk purchase
+fn validate_bulk_ticket_count(ticket_count: usize) -> Result<()> {
+    require!(ticket_count > 0, QuickPickError::InvalidTicketCount);
+    require!(
+        ticket_count <= QuickPickTicketBatch::MAX_TICKETS,
+        QuickPickError::TooManyTickets
+    );
+    Ok(())
+}
+
+/// Validate every number set and return them sorted for storage
+fn validate_and_sort_tickets(tickets: &[[u8; 5]]) -> Result<Vec<[u8; 5]>> {
+    tickets
+        .iter()
+        .map(|numbers| {
+            validate_quick_pick_numbers_internal(numbers)?;
+            let mut sorted = *numbers;
+            sorted.sort();
+            Ok(sorted)
+        })
+        .collect()
+}
+
+/// Buy multiple Quick Pick Express tickets
+///
+/// This instruction:
+/// 1. Verifies the main lottery spend gate requirement (once, when enabled)
+/// 2. Validates the ticket count and every number set
+/// 3. Checks if ticket sales are open for the current draw
+/// 4. Splits the total price across prize pool, house fee, and insurance
+/// 5. Creates the ticket batch account holding all entries
+/// 6. Updates Quick Pick state (jackpot contribution, ticket count)
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+/// * `params` - The bulk purchase parameters (number sets)
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler(ctx: Context<BuyQuickPickBulk>, params: BuyQuickPickBulkParams) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Validate the main lottery spend gate once for the whole purchase
+    enforce_spend_gate(
+        &ctx.accounts.quick_pick_state,
+        &ctx.accounts.main_lottery_program,
+        &ctx.accounts.main_lottery_state,
+        &ctx.accounts.user_stats,
+        &ctx.accounts.player.to_account_info(),
+    )?;
+
+    validate_bulk_ticket_count(params.tickets.len())?;
+    let sorted_tickets = validate_and_sort_tickets(&params.tickets)?;
+    let ticket_count = sorted_tickets.len();
+
+    let quick_pick_state = &ctx.accounts.quick_pick_state;
+    let current_draw = quick_pick_state.current_draw;
+    let start_ticket_id = quick_pick_state.current_draw_tickets;
+
+    // Check if ticket sales are open (closes ticket_sale_cutoff_seconds before the draw)
+    check_ticket_sale_cutoff(quick_pick_state, clock.unix_timestamp)?;
+
+    // Check if jackpot is properly funded (minimum 100% of seed amount)
+    require!(
+        quick_pick_state.jackpot_balance >= quick_pick_state.seed_amount,
+        QuickPickError::InsufficientJackpotFunding
+    );
+
+    let total_price = quick_pick_state
+        .ticket_price
+        .checked_mul(ticket_count as u64)
+        .ok_or(QuickPickError::Overflow)?;
+    require!(
+        ctx.accounts.player_usdc.amount >= total_price,
+        QuickPickError::InsufficientFunds
+    );
+
+    let split = split_quick_pick_payment(
+        total_price,
+        quick_pick_state.jackpot_balance,
+        quick_pick_state.is_rolldown_pending,
+    );
+    require!(
+        split.house_fee + split.prize_pool_transfer + split.insurance_contribution == total_price,
+        QuickPickError::InternalError
+    );
+
+    // Perform USDC transfers
+    ctx.accounts.transfer_from_player(
+        ctx.accounts.prize_pool_usdc.to_account_info(),
+        split.prize_pool_transfer,
+    )?;
+    ctx.accounts.transfer_from_player(
+        ctx.accounts.house_fee_usdc.to_account_info(),
+        split.house_fee,
+    )?;
+    if split.insurance_contribution > 0 {
+        ctx.accounts.transfer_from_player(
+            ctx.accounts.insurance_pool_usdc.to_account_info(),
+            split.insurance_contribution,
+        )?;
+    }
+
+    // Update Quick Pick state
+    let quick_pick_state = &mut ctx.accounts.quick_pick_state;
+    record_quick_pick_sale(quick_pick_state, &split, ticket_count as u64)?;
+
+    // Create ticket batch
+    let ticket_batch = &mut ctx.accounts.ticket_batch;
+    ticket_batch.owner = ctx.accounts.player.key();
+    ticket_batch.draw_id = current_draw;
+    ticket_batch.start_ticket_id = start_ticket_id;
+    ticket_batch.ticket_count = ticket_count as u32;
+    ticket_batch.numbers = sorted_tickets;
+    ticket_batch.purchase_timestamp = clock.unix_timestamp;
+    ticket_batch.claimed_bitmap = vec![0u8; ticket_count.div_ceil(8)];
+    ticket_batch.bump = ctx.bumps.ticket_batch;
+
+    emit!(QuickPickBulkPurchased {
+        player: ctx.accounts.player.key(),
+        ticket_batch: ticket_batch.key(),
+        draw_id: current_draw,
+        ticket_count: ticket_count as u32,
+        total_price,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Quick Pick Express bulk purchase complete!");
+    msg!("  Draw: #{}", current_draw);
+    msg!("  Tickets: {}", ticket_count);
+    msg!("  Total price: {} USDC lamports", total_price);
+    msg!(
+        "  House fee: {} bps ({}%)",
+        split.house_fee_bps,
+        split.house_fee_bps as f64 / 100.0
+    );
+    msg!(
+        "  Current jackpot: {} USDC lamports",
+        quick_pick_state.jackpot_balance
+    );
+    if quick_pick_state.is_rolldown_pending {
+        msg!("  ⚠️ ROLLDOWN PENDING: Jackpot exceeds soft cap!");
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_bulk_ticket_count() {
+        assert!(validate_bulk_ticket_count(0).is_err());
+        assert!(validate_bulk_ticket_count(1).is_ok());
+        assert!(validate_bulk_ticket_count(QuickPickTicketBatch::MAX_TICKETS).is_ok());
+        assert!(validate_bulk_ticket_count(QuickPickTicketBatch::MAX_TICKETS + 1).is_err());
+    }
+
+    #[test]
+    fn test_validate_and_sort_tickets() {
+        let sorted = validate_and_sort_tickets(&[[35, 1, 20, 15, 30], [5, 4, 3, 2, 1]]).unwrap();
+        assert_eq!(sorted, vec![[1, 15, 20, 30, 35], [1, 2, 3, 4, 5]]);
+
+        // One invalid entry rejects the whole purchase
+        assert!(validate_and_sort_tickets(&[[1, 2, 3, 4, 5], [1, 1, 2, 3, 4]]).is_err());
+        assert!(validate_and_sort_tickets(&[[1, 2, 3, 4, 36]]).is_err());
+    }
+
+    #[test]
+    fn test_bulk_split_conserves_total() {
+        let total_price = QUICK_PICK_TICKET_PRICE * QuickPickTicketBatch::MAX_TICKETS as u64;
+        let split = split_quick_pick_payment(total_price, QUICK_PICK_SEED_AMOUNT, false);
+        assert_eq!(
+            split.house_fee + split.prize_pool_transfer + split.insurance_contribution,
+            total_price
+        );
+        assert_eq!(
+            split.jackpot_contribution
+                + split.fixed_prize_contribution
+                + split.reserve_contribution,
+            split.prize_pool_transfer
+        );
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! This instruction allows eligible players to purchase Quick Pick Express tickets.
 //! It handles:
-//! - $50 main lottery spend gate verification
+//! - Main lottery spend gate verification (CPI into `verify_spend_gate`, $50 by default)
+//! - Free tickets from main lottery Match 5 credits (CPI into `redeem_free_quickpick_credit`)
 //! - Number validation (5 unique numbers from 1-35)
 //! - Dynamic fee calculation based on jackpot level
 //! - USDC transfer (player -> prize pool + house fee + insurance)
@@ -15,12 +16,13 @@
 //! - No free tickets (Match 2 doesn't exist)
 
 use anchor_lang::prelude::*;
-use anchor_lang::AccountDeserialize;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::constants::*;
 use crate::errors::QuickPickError;
-use crate::events::QuickPickTicketPurchased;
+use crate::events::{QuickPickTicketPurchased, QuickPickTicketSaleClosed};
 use crate::state::{QuickPickState, QuickPickTicket};
 
 /// Parameters for buying a Quick Pick ticket
@@ -99,18 +101,30 @@ pub struct BuyQuickPickTicket<'info> {
 
     /// User statistics account (to verify $50 gate)
     /// This account is owned by the main lottery program, NOT this program.
-    /// We use UncheckedAccount + manual validation because Anchor's #[account]
-    /// derives PDAs under the current program ID, but this PDA lives under the
-    /// main lottery program.
-    /// CHECK: Validated manually in handler: owner == main lottery program,
-    /// PDA derivation verified, discriminator checked, total_spent >= gate
+    /// CHECK: Passed to the main lottery's `verify_spend_gate`, which checks
+    /// the PDA, owner and total_spent >= gate
     pub user_stats: UncheckedAccount<'info>,
 
+    /// The main lottery's state account (holds the spend gate threshold)
+    /// CHECK: PDA verified by the main lottery's `verify_spend_gate`
+    pub main_lottery_state: UncheckedAccount<'info>,
+
+    /// The main lottery program
+    /// CHECK: Program ID verified against MAIN_LOTTERY_PROGRAM_ID before the CPI
+    pub main_lottery_program: UncheckedAccount<'info>,
+
     /// Token program
     pub token_program: Program<'info, Token>,
 
     /// System program
     pub system_program: Program<'info, System>,
+
+    /// The player's free ticket credit from a main lottery Match 5 win for
+    /// the current draw; when supplied the ticket is not paid for
+    /// CHECK: PDA, owner and beneficiary verified by the main lottery's
+    /// `redeem_free_quickpick_credit`
+    #[account(mut)]
+    pub free_qp_credit: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> BuyQuickPickTicket<'info> {
@@ -152,7 +166,7 @@ impl<'info> BuyQuickPickTicket<'info> {
 }
 
 /// Validate Quick Pick numbers (5 unique numbers from 1-35)
-fn validate_quick_pick_numbers_internal(numbers: &[u8; 5]) -> Result<()> {
+pub(crate) fn validate_quick_pick_numbers_internal(numbers: &[u8; 5]) -> Result<()> {
     // Check each number is in valid range
     for &num in numbers.iter() {
         require!(
@@ -171,14 +185,125 @@ fn validate_quick_pick_numbers_internal(numbers: &[u8; 5]) -> Result<()> {
     Ok(())
 }
 
+/// Allocation of a Quick Pick payment across pools
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct QuickPickPaymentSplit {
+    /// House fee rate applied (basis points)
+    pub house_fee_bps: u16,
+    /// Amount transferred to the house fee account
+    pub house_fee: u64,
+    /// Amount transferred to the insurance pool
+    pub insurance_contribution: u64,
+    /// Amount transferred to the prize pool
+    pub prize_pool_transfer: u64,
+    /// Portion of the prize pool transfer credited to the jackpot
+    pub jackpot_contribution: u64,
+    /// Portion of the prize pool transfer credited to fixed prizes
+    pub fixed_prize_contribution: u64,
+    /// Rounding remainder credited to the reserve
+    pub reserve_contribution: u64,
+}
+
+/// Split a Quick Pick payment using the dynamic house fee for the current jackpot
+pub(crate) fn split_quick_pick_payment(
+    amount: u64,
+    jackpot_balance: u64,
+    is_rolldown_pending: bool,
+) -> QuickPickPaymentSplit {
+    // Calculate dynamic house fee based on current jackpot level
+    let house_fee_bps = calculate_quick_pick_house_fee_bps(jackpot_balance, is_rolldown_pending);
+    let house_fee = (amount as u128 * house_fee_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+
+    // Calculate what's left after house fee
+    let after_house_fee = amount.saturating_sub(house_fee);
+
+    // Insurance gets 3% of after_house_fee
+    let insurance_contribution = (after_house_fee as u128
+        * QUICK_PICK_INSURANCE_ALLOCATION_BPS as u128
+        / BPS_DENOMINATOR as u128) as u64;
+
+    // Prize pool transfer is after_house_fee MINUS insurance
+    let prize_pool_transfer = after_house_fee.saturating_sub(insurance_contribution);
+
+    // From the prize pool transfer, calculate internal accounting allocations:
+    let jackpot_contribution = (prize_pool_transfer as u128
+        * QUICK_PICK_JACKPOT_ALLOCATION_BPS as u128
+        / BPS_DENOMINATOR as u128) as u64;
+    let fixed_prize_contribution = (prize_pool_transfer as u128
+        * QUICK_PICK_FIXED_PRIZE_ALLOCATION_BPS as u128
+        / BPS_DENOMINATOR as u128) as u64;
+    // SECURITY FIX: Track the integer division remainder as reserve_balance.
+    // Without this, dust from rounding (prize_pool_transfer - jackpot - fixed)
+    // is never accounted for, and rolldown reseeding can't draw on it.
+    let reserve_contribution = prize_pool_transfer
+        .saturating_sub(jackpot_contribution)
+        .saturating_sub(fixed_prize_contribution);
+
+    QuickPickPaymentSplit {
+        house_fee_bps,
+        house_fee,
+        insurance_contribution,
+        prize_pool_transfer,
+        jackpot_contribution,
+        fixed_prize_contribution,
+        reserve_contribution,
+    }
+}
+
+/// Credit a completed sale of `ticket_count` tickets to the Quick Pick state
+pub(crate) fn record_quick_pick_sale(
+    quick_pick_state: &mut QuickPickState,
+    split: &QuickPickPaymentSplit,
+    ticket_count: u64,
+) -> Result<()> {
+    quick_pick_state.jackpot_balance = quick_pick_state
+        .jackpot_balance
+        .checked_add(split.jackpot_contribution)
+        .ok_or(QuickPickError::Overflow)?;
+    quick_pick_state.prize_pool_balance = quick_pick_state
+        .prize_pool_balance
+        .checked_add(split.fixed_prize_contribution)
+        .ok_or(QuickPickError::Overflow)?;
+    quick_pick_state.insurance_balance = quick_pick_state
+        .insurance_balance
+        .checked_add(split.insurance_contribution)
+        .ok_or(QuickPickError::Overflow)?;
+    // Track remainder (dust) as reserve — used for jackpot reseeding after rolldown/win
+    if split.reserve_contribution > 0 {
+        quick_pick_state.reserve_balance = quick_pick_state
+            .reserve_balance
+            .checked_add(split.reserve_contribution)
+            .ok_or(QuickPickError::Overflow)?;
+    }
+    quick_pick_state.current_draw_tickets = quick_pick_state
+        .current_draw_tickets
+        .checked_add(ticket_count)
+        .ok_or(QuickPickError::Overflow)?;
+    quick_pick_state.total_tickets_sold = quick_pick_state
+        .total_tickets_sold
+        .checked_add(ticket_count)
+        .ok_or(QuickPickError::Overflow)?;
+
+    // Update house fee (dynamic)
+    quick_pick_state.house_fee_bps = split.house_fee_bps;
+
+    // Check if rolldown is now pending (jackpot >= soft_cap)
+    if quick_pick_state.jackpot_balance >= quick_pick_state.soft_cap {
+        quick_pick_state.is_rolldown_pending = true;
+    }
+
+    Ok(())
+}
+
 /// Buy a Quick Pick Express ticket
 ///
 /// This instruction:
-/// 1. Verifies the $50 main lottery spend gate requirement
+/// 1. Verifies the main lottery spend gate requirement (when enabled)
 /// 2. Validates the selected numbers (1-35, unique, 5 numbers)
 /// 3. Checks if ticket sales are open for the current draw
 /// 4. Calculates the dynamic house fee based on jackpot level
-/// 5. Transfers USDC from player to prize pool, house fee, and insurance accounts
+/// 5. Transfers USDC from player to prize pool, house fee, and insurance
+///    accounts, or redeems the supplied free ticket credit instead
 /// 6. Creates the ticket account with the selected numbers
 /// 7. Updates Quick Pick state (jackpot contribution, ticket count)
 ///
@@ -188,84 +313,152 @@ fn validate_quick_pick_numbers_internal(numbers: &[u8; 5]) -> Result<()> {
 ///
 /// # Returns
 /// * `Result<()>` - Success or error
-/// Verify the UserStats account from the main lottery program and extract
-/// the `total_spent` value for the $50 gate check.
-///
-/// ## Fix #4 — Type-safe cross-program deserialization
-///
-/// Previously this function extracted `total_spent` via a hard-coded byte
-/// offset (`data[48..56]`). That is fragile: if the main lottery ever
-/// reorders fields, adds a field before `total_spent`, or changes a field's
-/// size, the offset silently reads garbage and the gate check produces
-/// incorrect results (either blocking legitimate users or allowing
-/// unqualified ones through).
-///
-/// The new implementation uses Anchor's `UserStats::try_deserialize` which:
-/// 1. Validates the 8-byte Anchor discriminator (`sha256("account:UserStats")[..8]`)
-/// 2. Borsh-deserializes every field in declared order
-/// 3. Fails loudly with a clear error if the struct layout doesn't match
+/// Check the main lottery spend gate by CPI into its `verify_spend_gate`
 ///
-/// We still perform the two manual checks that Anchor can't do for a
-/// cross-program account:
-/// - **Owner check**: account must be owned by the main lottery program
-/// - **PDA derivation**: account address must equal the expected PDA
-///
-/// ### Coordination note
-/// The QuickPick `UserStats` struct (in `state.rs`) must stay in sync with
-/// the main lottery's `UserStats` (same field order, types, sizes). This is
-/// safer than raw offsets because any mismatch causes a deserialization
-/// error rather than silent corruption.
-fn verify_main_lottery_user_stats(
-    user_stats_info: &AccountInfo,
-    player_key: &Pubkey,
-) -> Result<u64> {
-    // 1. Verify the account is owned by the main lottery program
+/// The main lottery owns both the `UserStats` layout and the threshold, so
+/// QuickPick no longer deserializes `UserStats` itself; the CPI fails (and
+/// with it the purchase) unless the player has spent at least
+/// `quickpick_spend_gate_threshold` there. The main lottery re-derives the
+/// `lottery_state` and `user_stats` PDAs, so only the program ID needs
+/// checking here.
+pub(crate) fn verify_main_lottery_spend_gate<'info>(
+    main_lottery_program: &AccountInfo<'info>,
+    main_lottery_state: &AccountInfo<'info>,
+    user_stats: &AccountInfo<'info>,
+    player: &AccountInfo<'info>,
+) -> Result<()> {
     let main_lottery_id = MAIN_LOTTERY_PROGRAM_ID
         .parse::<Pubkey>()
         .map_err(|_| QuickPickError::InsufficientMainLotterySpend)?;
 
     require!(
-        user_stats_info.owner == &main_lottery_id,
+        main_lottery_program.key() == main_lottery_id,
         QuickPickError::InsufficientMainLotterySpend
     );
 
-    // 2. Verify PDA derivation: seeds = [USER_SEED, player.key().as_ref()]
-    //    under the main lottery program
-    let (expected_pda, _bump) =
-        Pubkey::find_program_address(&[USER_SEED, player_key.as_ref()], &main_lottery_id);
+    // Account order matches the main lottery's `VerifySpendGate` struct
+    let instruction = Instruction {
+        program_id: main_lottery_id,
+        accounts: vec![
+            AccountMeta::new_readonly(player.key(), true),
+            AccountMeta::new_readonly(main_lottery_state.key(), false),
+            AccountMeta::new_readonly(user_stats.key(), false),
+        ],
+        data: VERIFY_SPEND_GATE_DISCRIMINATOR.to_vec(),
+    };
+
+    invoke(
+        &instruction,
+        &[
+            player.clone(),
+            main_lottery_state.clone(),
+            user_stats.clone(),
+            main_lottery_program.clone(),
+        ],
+    )?;
+
+    Ok(())
+}
+
+/// Spend a main lottery free ticket credit by CPI into its
+/// `redeem_free_quickpick_credit`
+///
+/// The Quick Pick state PDA signs the CPI so the main lottery can tell the
+/// call comes from this program. The main lottery checks the credit's PDA,
+/// beneficiary, expiry and that it is unused.
+pub(crate) fn redeem_free_quickpick_credit<'info>(
+    main_lottery_program: &AccountInfo<'info>,
+    quick_pick_state: &AccountInfo<'info>,
+    player: &AccountInfo<'info>,
+    free_qp_credit: &AccountInfo<'info>,
+    draw_id: u64,
+    quick_pick_bump: u8,
+) -> Result<()> {
+    let main_lottery_id = MAIN_LOTTERY_PROGRAM_ID
+        .parse::<Pubkey>()
+        .map_err(|_| QuickPickError::InvalidMainLotteryProgram)?;
     require!(
-        user_stats_info.key() == expected_pda,
-        QuickPickError::InsufficientMainLotterySpend
+        main_lottery_program.key() == main_lottery_id,
+        QuickPickError::InvalidMainLotteryProgram
     );
 
-    // 3. Deserialize using Anchor's typed deserialization.
-    //    `try_deserialize` checks the 8-byte discriminator and then
-    //    Borsh-deserializes all fields. If the struct layout doesn't match
-    //    (e.g. a field was added/removed/reordered in the main program),
-    //    this will return an error instead of silently reading wrong data.
-    let data = user_stats_info.try_borrow_data()?;
-    let mut data_slice: &[u8] = &data;
+    // Account order matches the main lottery's `RedeemFreeQuickPickCredit` struct
+    let mut data = REDEEM_FREE_QP_CREDIT_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&draw_id.to_le_bytes());
+    let instruction = Instruction {
+        program_id: main_lottery_id,
+        accounts: vec![
+            AccountMeta::new_readonly(quick_pick_state.key(), true),
+            AccountMeta::new_readonly(player.key(), true),
+            AccountMeta::new(free_qp_credit.key(), false),
+        ],
+        data,
+    };
+
+    invoke_signed(
+        &instruction,
+        &[
+            quick_pick_state.clone(),
+            player.clone(),
+            free_qp_credit.clone(),
+            main_lottery_program.clone(),
+        ],
+        &[&[QUICK_PICK_SEED, &[quick_pick_bump]]],
+    )?;
 
-    let user_stats = crate::state::UserStats::try_deserialize(&mut data_slice).map_err(|_| {
-        msg!("Failed to deserialize main-lottery UserStats account");
-        QuickPickError::InsufficientMainLotterySpend
-    })?;
+    Ok(())
+}
+
+/// Reject purchases once the current draw's ticket sale cutoff has passed
+///
+/// Emits `QuickPickTicketSaleClosed` with each rejected purchase.
+pub(crate) fn check_ticket_sale_cutoff(
+    quick_pick_state: &QuickPickState,
+    current_timestamp: i64,
+) -> Result<()> {
+    let cutoff_time = quick_pick_state.ticket_sale_cutoff_time();
+    if current_timestamp >= cutoff_time {
+        emit!(QuickPickTicketSaleClosed {
+            draw_id: quick_pick_state.current_draw,
+            cutoff_time,
+            tickets_sold: quick_pick_state.current_draw_tickets,
+        });
+        msg!(
+            "Quick Pick ticket sales closed for draw {}",
+            quick_pick_state.current_draw
+        );
+        return Err(QuickPickError::TicketSaleEnded.into());
+    }
+    Ok(())
+}
 
-    // 4. Return total_spent via typed field access (no byte offsets!)
-    Ok(user_stats.total_spent)
+/// Enforce the main lottery spend gate when it is enabled
+///
+/// When `spend_gate_enabled` is false the main lottery is not called.
+pub(crate) fn enforce_spend_gate<'info>(
+    quick_pick_state: &QuickPickState,
+    main_lottery_program: &AccountInfo<'info>,
+    main_lottery_state: &AccountInfo<'info>,
+    user_stats: &AccountInfo<'info>,
+    player: &AccountInfo<'info>,
+) -> Result<()> {
+    if !quick_pick_state.spend_gate_enabled {
+        return Ok(());
+    }
+    verify_main_lottery_spend_gate(main_lottery_program, main_lottery_state, user_stats, player)
 }
 
 pub fn handler(ctx: Context<BuyQuickPickTicket>, params: BuyQuickPickTicketParams) -> Result<()> {
     let clock = Clock::get()?;
 
-    // Validate the $50 main lottery spend gate via cross-program PDA verification
-    let total_spent =
-        verify_main_lottery_user_stats(&ctx.accounts.user_stats, &ctx.accounts.player.key())?;
-
-    require!(
-        total_spent >= QUICK_PICK_MIN_SPEND_GATE,
-        QuickPickError::InsufficientMainLotterySpend
-    );
+    // Validate the main lottery spend gate via CPI
+    enforce_spend_gate(
+        &ctx.accounts.quick_pick_state,
+        &ctx.accounts.main_lottery_program,
+        &ctx.accounts.main_lottery_state,
+        &ctx.accounts.user_stats,
+        &ctx.accounts.player.to_account_info(),
+    )?;
 
     // Validate numbers first (before any borrows)
     validate_quick_pick_numbers_internal(&params.numbers)?;
@@ -276,18 +469,13 @@ pub fn handler(ctx: Context<BuyQuickPickTicket>, params: BuyQuickPickTicketParam
 
     // Get values needed for validation and calculation
     let ticket_price = ctx.accounts.quick_pick_state.ticket_price;
-    let next_draw_timestamp = ctx.accounts.quick_pick_state.next_draw_timestamp;
     let current_draw = ctx.accounts.quick_pick_state.current_draw;
     let jackpot_balance = ctx.accounts.quick_pick_state.jackpot_balance;
     let is_rolldown_pending = ctx.accounts.quick_pick_state.is_rolldown_pending;
     let seed_amount = ctx.accounts.quick_pick_state.seed_amount;
 
-    // Check if ticket sales are open (4-hour window with 5-minute cutoff)
-    let sale_cutoff_time = next_draw_timestamp.saturating_sub(TICKET_SALE_CUTOFF);
-    require!(
-        clock.unix_timestamp < sale_cutoff_time,
-        QuickPickError::TicketSaleEnded
-    );
+    // Check if ticket sales are open (closes ticket_sale_cutoff_seconds before the draw)
+    check_ticket_sale_cutoff(&ctx.accounts.quick_pick_state, clock.unix_timestamp)?;
 
     // Check if jackpot is properly funded (minimum 100% of seed amount)
     let minimum_jackpot = seed_amount;
@@ -296,94 +484,58 @@ pub fn handler(ctx: Context<BuyQuickPickTicket>, params: BuyQuickPickTicketParam
         QuickPickError::InsufficientJackpotFunding
     );
 
+    // A free ticket credit replaces the payment
+    let price_paid = match &ctx.accounts.free_qp_credit {
+        Some(free_qp_credit) => {
+            redeem_free_quickpick_credit(
+                &ctx.accounts.main_lottery_program,
+                &ctx.accounts.quick_pick_state.to_account_info(),
+                &ctx.accounts.player.to_account_info(),
+                free_qp_credit,
+                current_draw,
+                ctx.accounts.quick_pick_state.bump,
+            )?;
+            msg!("Free ticket credit redeemed");
+            0
+        }
+        None => ticket_price,
+    };
+
     // Verify player has sufficient USDC balance
     require!(
-        ctx.accounts.player_usdc.amount >= ticket_price,
+        ctx.accounts.player_usdc.amount >= price_paid,
         QuickPickError::InsufficientFunds
     );
 
-    // Calculate dynamic house fee based on current jackpot level
-    let house_fee_bps = calculate_quick_pick_house_fee_bps(jackpot_balance, is_rolldown_pending);
-    let house_fee = (ticket_price as u128 * house_fee_bps as u128 / BPS_DENOMINATOR as u128) as u64;
-
-    // Calculate what's left after house fee
-    let after_house_fee = ticket_price.saturating_sub(house_fee);
-
-    // Insurance gets 3% of after_house_fee
-    let insurance_contribution = (after_house_fee as u128
-        * QUICK_PICK_INSURANCE_ALLOCATION_BPS as u128
-        / BPS_DENOMINATOR as u128) as u64;
-
-    // Prize pool transfer is after_house_fee MINUS insurance
-    let prize_pool_transfer = after_house_fee.saturating_sub(insurance_contribution);
-
-    // From the prize pool transfer, calculate internal accounting allocations:
-    let jackpot_contribution = (prize_pool_transfer as u128
-        * QUICK_PICK_JACKPOT_ALLOCATION_BPS as u128
-        / BPS_DENOMINATOR as u128) as u64;
-    let fixed_prize_contribution = (prize_pool_transfer as u128
-        * QUICK_PICK_FIXED_PRIZE_ALLOCATION_BPS as u128
-        / BPS_DENOMINATOR as u128) as u64;
-    // SECURITY FIX: Track the integer division remainder as reserve_balance.
-    // Without this, dust from rounding (prize_pool_transfer - jackpot - fixed)
-    // is never accounted for, and rolldown reseeding can't draw on it.
-    let reserve_contribution = prize_pool_transfer
-        .saturating_sub(jackpot_contribution)
-        .saturating_sub(fixed_prize_contribution);
+    // Split the payment into house fee, insurance, and prize pool allocations
+    let split = split_quick_pick_payment(price_paid, jackpot_balance, is_rolldown_pending);
+    let house_fee_bps = split.house_fee_bps;
+    let house_fee = split.house_fee;
+    let insurance_contribution = split.insurance_contribution;
+    let prize_pool_transfer = split.prize_pool_transfer;
+    let jackpot_contribution = split.jackpot_contribution;
 
     // Perform USDC transfers
-    ctx.accounts.transfer_to_prize_pool(prize_pool_transfer)?;
-    ctx.accounts.transfer_to_house_fee(house_fee)?;
-    if insurance_contribution > 0 {
-        ctx.accounts
-            .transfer_to_insurance_pool(insurance_contribution)?;
+    if price_paid > 0 {
+        ctx.accounts.transfer_to_prize_pool(prize_pool_transfer)?;
+        ctx.accounts.transfer_to_house_fee(house_fee)?;
+        if insurance_contribution > 0 {
+            ctx.accounts
+                .transfer_to_insurance_pool(insurance_contribution)?;
+        }
     }
 
     // SECURITY FIX (Issue #8): Replace debug_assert with runtime require!
     // debug_assert is stripped in release builds, leaving this critical
     // invariant unchecked in production. Use require! to enforce it always.
     require!(
-        house_fee + prize_pool_transfer + insurance_contribution == ticket_price,
+        house_fee + prize_pool_transfer + insurance_contribution == price_paid,
         QuickPickError::InternalError
     );
 
     // Update Quick Pick state
     let quick_pick_state = &mut ctx.accounts.quick_pick_state;
-    quick_pick_state.jackpot_balance = quick_pick_state
-        .jackpot_balance
-        .checked_add(jackpot_contribution)
-        .ok_or(QuickPickError::Overflow)?;
-    quick_pick_state.prize_pool_balance = quick_pick_state
-        .prize_pool_balance
-        .checked_add(fixed_prize_contribution)
-        .ok_or(QuickPickError::Overflow)?;
-    quick_pick_state.insurance_balance = quick_pick_state
-        .insurance_balance
-        .checked_add(insurance_contribution)
-        .ok_or(QuickPickError::Overflow)?;
-    // Track remainder (dust) as reserve — used for jackpot reseeding after rolldown/win
-    if reserve_contribution > 0 {
-        quick_pick_state.reserve_balance = quick_pick_state
-            .reserve_balance
-            .checked_add(reserve_contribution)
-            .ok_or(QuickPickError::Overflow)?;
-    }
-    quick_pick_state.current_draw_tickets = quick_pick_state
-        .current_draw_tickets
-        .checked_add(1)
-        .ok_or(QuickPickError::Overflow)?;
-    quick_pick_state.total_tickets_sold = quick_pick_state
-        .total_tickets_sold
-        .checked_add(1)
-        .ok_or(QuickPickError::Overflow)?;
-
-    // Update house fee (dynamic)
-    quick_pick_state.house_fee_bps = house_fee_bps;
-
-    // Check if rolldown is now pending (jackpot >= soft_cap)
-    if quick_pick_state.jackpot_balance >= quick_pick_state.soft_cap {
-        quick_pick_state.is_rolldown_pending = true;
-    }
+    record_quick_pick_sale(quick_pick_state, &split, 1)?;
 
     // Create ticket
     let ticket = &mut ctx.accounts.ticket;
@@ -402,14 +554,14 @@ pub fn handler(ctx: Context<BuyQuickPickTicket>, params: BuyQuickPickTicketParam
         player: ctx.accounts.player.key(),
         draw_id: current_draw,
         numbers: sorted_numbers,
-        price: ticket_price,
+        price: price_paid,
         timestamp: clock.unix_timestamp,
     });
 
     msg!("Quick Pick Express ticket purchased!");
     msg!("  Draw: #{}", current_draw);
     msg!("  Numbers: {:?}", sorted_numbers);
-    msg!("  Price: {} USDC lamports", ticket_price);
+    msg!("  Price: {} USDC lamports", price_paid);
 
     // Log jackpot funding status
     let minimum_jackpot = seed_amount;
@@ -445,6 +597,20 @@ pub fn handler(ctx: Context<BuyQuickPickTicket>, params: BuyQuickPickTicketParam
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_verify_spend_gate_discriminator() {
+        use sha2::{Digest, Sha256};
+        let hash = Sha256::digest(b"global:verify_spend_gate");
+        assert_eq!(VERIFY_SPEND_GATE_DISCRIMINATOR, hash[..8]);
+    }
+
+    #[test]
+    fn test_redeem_free_qp_credit_discriminator() {
+        use sha2::{Digest, Sha256};
+        let hash = Sha256::digest(b"global:redeem_free_quickpick_credit");
+        assert_eq!(REDEEM_FREE_QP_CREDIT_DISCRIMINATOR, hash[..8]);
+    }
+
     #[test]
     fn test_validate_quick_pick_numbers_valid() {
         let numbers = [1, 15, 20, 30, 35];
@@ -1,7 +1,9 @@
-//! Claim Quick Pick Prize Instruction
+//! Claim Quick Pick Prize Instructions
 //!
-//! This instruction allows players to claim their winnings from Quick Pick Express draws.
-//! It handles:
+//! These instructions allow players to claim their winnings from Quick Pick Express draws,
+//! one ticket at a time, in batches of tickets across draws, or for every
+//! entry of a bulk-purchase ticket batch.
+//! They handle:
 //! - Verification that the draw is complete and finalized
 //! - Ticket ownership verification
 //! - Match count calculation against winning numbers
@@ -15,12 +17,13 @@
 //! - Only Match 3, Match 4, and Match 5 (jackpot) tiers
 
 use anchor_lang::prelude::*;
+use anchor_lang::AccountDeserialize;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::constants::*;
 use crate::errors::QuickPickError;
-use crate::events::QuickPickPrizeClaimed;
-use crate::state::{QuickPickDrawResult, QuickPickState, QuickPickTicket};
+use crate::events::{QuickPickBatchPrizeClaimed, QuickPickPrizeClaimed};
+use crate::state::{QuickPickDrawResult, QuickPickState, QuickPickTicket, QuickPickTicketBatch};
 
 /// Accounts required for claiming a Quick Pick prize
 #[derive(Accounts)]
@@ -45,11 +48,13 @@ pub struct ClaimQuickPickPrize<'info> {
     )]
     pub ticket: Account<'info, QuickPickTicket>,
 
-    /// The draw result for the ticket's draw
+    /// The draw result for the ticket's draw (must be finalized: before
+    /// finalize_draw every prize is zero and claiming would forfeit the ticket)
     #[account(
         seeds = [QUICK_PICK_DRAW_SEED, &ticket.draw_id.to_le_bytes()],
         bump = draw_result.bump,
-        constraint = draw_result.draw_id == ticket.draw_id @ QuickPickError::DrawIdMismatch
+        constraint = draw_result.draw_id == ticket.draw_id @ QuickPickError::DrawIdMismatch,
+        constraint = draw_result.is_finalized() @ QuickPickError::DrawNotFinalized
     )]
     pub draw_result: Account<'info, QuickPickDrawResult>,
 
@@ -141,6 +146,44 @@ fn transfer_quick_pick_prize<'info>(
     token::transfer(cpi_ctx, amount)
 }
 
+/// Deduct a paid prize from the Quick Pick state's internal balances
+///
+/// Deduction priority depends on prize tier:
+/// - Match 5 (jackpot): deduct from jackpot_balance first, then prize_pool_balance.
+/// - Match 3/4 (fixed prizes): deduct from prize_pool_balance first, then jackpot_balance.
+fn deduct_paid_prize(qp_state: &mut QuickPickState, match_count: u8, amount: u64) {
+    if match_count == 5 {
+        // Jackpot prize: deduct from jackpot_balance first
+        if qp_state.jackpot_balance >= amount {
+            qp_state.jackpot_balance = qp_state.jackpot_balance.saturating_sub(amount);
+        } else {
+            let from_jackpot = qp_state.jackpot_balance;
+            let remainder = amount.saturating_sub(from_jackpot);
+            qp_state.jackpot_balance = 0;
+            qp_state.prize_pool_balance = qp_state.prize_pool_balance.saturating_sub(remainder);
+        }
+    } else {
+        // Fixed prizes (Match 3/4): deduct from prize_pool_balance first
+        if qp_state.prize_pool_balance >= amount {
+            qp_state.prize_pool_balance = qp_state.prize_pool_balance.saturating_sub(amount);
+        } else {
+            let from_pool = qp_state.prize_pool_balance;
+            let remainder = amount.saturating_sub(from_pool);
+            qp_state.prize_pool_balance = 0;
+            qp_state.jackpot_balance = qp_state.jackpot_balance.saturating_sub(remainder);
+        }
+    }
+
+    // Increment total_prizes_paid at actual claim time for accurate tracking
+    qp_state.total_prizes_paid = qp_state.total_prizes_paid.saturating_add(amount);
+}
+
+/// Whether tickets of a draw executed at `draw_timestamp` can no longer be claimed
+fn is_ticket_claim_expired(draw_timestamp: i64, current_timestamp: i64) -> bool {
+    TICKET_CLAIM_EXPIRATION > 0
+        && current_timestamp > draw_timestamp.saturating_add(TICKET_CLAIM_EXPIRATION)
+}
+
 /// Claim prize for a winning Quick Pick ticket
 ///
 /// This instruction:
@@ -180,12 +223,6 @@ pub fn handler(ctx: Context<ClaimQuickPickPrize>) -> Result<()> {
     let prize_pool_balance = ctx.accounts.prize_pool_usdc.amount;
     let draw_timestamp = ctx.accounts.draw_result.timestamp;
 
-    // Verify draw is finalized
-    require!(
-        ctx.accounts.draw_result.is_finalized(),
-        QuickPickError::DrawNotInProgress
-    );
-
     // Check ticket claim expiration (90 days)
     if TICKET_CLAIM_EXPIRATION > 0 {
         let claim_deadline = draw_timestamp
@@ -243,43 +280,9 @@ pub fn handler(ctx: Context<ClaimQuickPickPrize>) -> Result<()> {
     // Previously, claim_prize performed the token transfer but did NOT update
     // quick_pick_state balances, causing internal books to drift and future
     // finalizations/solvency checks to be incorrect.
-    //
-    // Deduction priority depends on prize tier:
-    // - Match 5 (jackpot): deduct from jackpot_balance first, then prize_pool_balance.
-    // - Match 3/4 (fixed prizes): deduct from prize_pool_balance first, then jackpot_balance.
     if actual_transfer_amount > 0 {
         let qp_state = &mut ctx.accounts.quick_pick_state;
-
-        if match_count == 5 {
-            // Jackpot prize: deduct from jackpot_balance first
-            if qp_state.jackpot_balance >= actual_transfer_amount {
-                qp_state.jackpot_balance = qp_state
-                    .jackpot_balance
-                    .saturating_sub(actual_transfer_amount);
-            } else {
-                let from_jackpot = qp_state.jackpot_balance;
-                let remainder = actual_transfer_amount.saturating_sub(from_jackpot);
-                qp_state.jackpot_balance = 0;
-                qp_state.prize_pool_balance = qp_state.prize_pool_balance.saturating_sub(remainder);
-            }
-        } else {
-            // Fixed prizes (Match 3/4): deduct from prize_pool_balance first
-            if qp_state.prize_pool_balance >= actual_transfer_amount {
-                qp_state.prize_pool_balance = qp_state
-                    .prize_pool_balance
-                    .saturating_sub(actual_transfer_amount);
-            } else {
-                let from_pool = qp_state.prize_pool_balance;
-                let remainder = actual_transfer_amount.saturating_sub(from_pool);
-                qp_state.prize_pool_balance = 0;
-                qp_state.jackpot_balance = qp_state.jackpot_balance.saturating_sub(remainder);
-            }
-        }
-
-        // Increment total_prizes_paid at actual claim time for accurate tracking
-        qp_state.total_prizes_paid = qp_state
-            .total_prizes_paid
-            .saturating_add(actual_transfer_amount);
+        deduct_paid_prize(qp_state, match_count, actual_transfer_amount);
 
         msg!(
             "  QuickPick state updated: jackpot={}, prize_pool={}, total_paid={}",
@@ -329,10 +332,365 @@ pub fn handler(ctx: Context<ClaimQuickPickPrize>) -> Result<()> {
     Ok(())
 }
 
+// ============================================================================
+// BATCH CLAIM
+// ============================================================================
+
+/// Accounts required for claiming several Quick Pick tickets at once
+///
+/// The draw result / ticket pairs are passed as `remaining_accounts`:
+/// `[draw_result_0, ticket_0, draw_result_1, ticket_1, ...]`, with every
+/// ticket writable.
+#[derive(Accounts)]
+pub struct ClaimQuickPickPrizesBatch<'info> {
+    /// The player claiming the prizes (must own every ticket)
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// The Quick Pick state account
+    #[account(
+        mut,
+        seeds = [QUICK_PICK_SEED],
+        bump = quick_pick_state.bump
+    )]
+    pub quick_pick_state: Account<'info, QuickPickState>,
+
+    /// Player's USDC token account (to receive prizes)
+    #[account(
+        mut,
+        constraint = player_usdc.owner == player.key() @ QuickPickError::TokenAccountOwnerMismatch,
+        constraint = player_usdc.mint == usdc_mint.key() @ QuickPickError::InvalidUsdcMint
+    )]
+    pub player_usdc: Account<'info, TokenAccount>,
+
+    /// Prize pool USDC token account
+    #[account(
+        mut,
+        seeds = [PRIZE_POOL_USDC_SEED],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+
+    /// USDC mint
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Claim up to QUICK_PICK_MAX_BATCH_CLAIM_PAIRS tickets across draws
+///
+/// For each draw result / ticket pair the draw must be finalized and the
+/// ticket owned by the player, for that draw and within its claim window.
+/// Tickets that are already claimed are skipped instead of failing the
+/// batch. The prizes are paid out in a single transfer.
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_claim_quickpick_prizes_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimQuickPickPrizesBatch<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let program_id = ctx.program_id;
+    let player_key = ctx.accounts.player.key();
+
+    let remaining = ctx.remaining_accounts;
+    require!(
+        !remaining.is_empty()
+            && remaining.len() % 2 == 0
+            && remaining.len() / 2 <= QUICK_PICK_MAX_BATCH_CLAIM_PAIRS,
+        QuickPickError::InvalidClaimBatch
+    );
+
+    let mut total_prize: u64 = 0;
+    let mut tickets_processed: u32 = 0;
+
+    for pair in remaining.chunks(2) {
+        let (draw_result_info, ticket_info) = (&pair[0], &pair[1]);
+
+        // Draw result: a finalized draw result PDA of this program
+        require!(
+            draw_result_info.owner == program_id,
+            QuickPickError::InvalidTicket
+        );
+        let draw_result = {
+            let data = draw_result_info.try_borrow_data()?;
+            let mut readable: &[u8] = &data;
+            QuickPickDrawResult::try_deserialize(&mut readable)
+                .map_err(|_| QuickPickError::InvalidTicket)?
+        };
+        let expected_draw_result = Pubkey::create_program_address(
+            &[
+                QUICK_PICK_DRAW_SEED,
+                &draw_result.draw_id.to_le_bytes(),
+                &[draw_result.bump],
+            ],
+            program_id,
+        )
+        .map_err(|_| QuickPickError::InvalidTicket)?;
+        require!(
+            draw_result_info.key() == expected_draw_result,
+            QuickPickError::InvalidTicket
+        );
+        require!(draw_result.is_finalized(), QuickPickError::DrawNotFinalized);
+
+        // Ticket: a writable ticket of this program owned by the player
+        require!(
+            ticket_info.is_writable && ticket_info.owner == program_id,
+            QuickPickError::InvalidTicket
+        );
+        let mut ticket_data = ticket_info.try_borrow_mut_data()?;
+        let mut readable: &[u8] = &ticket_data;
+        let mut ticket = QuickPickTicket::try_deserialize(&mut readable)
+            .map_err(|_| QuickPickError::InvalidTicket)?;
+        require!(ticket.owner == player_key, QuickPickError::NotTicketOwner);
+        require!(
+            ticket.draw_id == draw_result.draw_id,
+            QuickPickError::DrawIdMismatch
+        );
+
+        if ticket.is_claimed {
+            msg!("  Skipping already claimed ticket {}", ticket_info.key());
+            continue;
+        }
+        require!(
+            !is_ticket_claim_expired(draw_result.timestamp, clock.unix_timestamp),
+            QuickPickError::TicketExpired
+        );
+
+        let match_count = count_quick_pick_matches(&ticket.numbers, &draw_result.winning_numbers);
+        let prize_amount = draw_result.get_prize_for_matches(match_count);
+
+        ticket.match_count = match_count;
+        ticket.prize_amount = prize_amount;
+        ticket.is_claimed = true;
+
+        let mut writer: &mut [u8] = &mut ticket_data;
+        ticket
+            .try_serialize(&mut writer)
+            .map_err(|_| QuickPickError::InvalidTicket)?;
+
+        if prize_amount > 0 {
+            deduct_paid_prize(
+                &mut ctx.accounts.quick_pick_state,
+                match_count,
+                prize_amount,
+            );
+            total_prize = total_prize
+                .checked_add(prize_amount)
+                .ok_or(QuickPickError::Overflow)?;
+        }
+        tickets_processed += 1;
+    }
+
+    transfer_quick_pick_prize(
+        &ctx.accounts.prize_pool_usdc,
+        &ctx.accounts.player_usdc,
+        &ctx.accounts.quick_pick_state,
+        &ctx.accounts.token_program,
+        total_prize,
+        ctx.accounts.quick_pick_state.bump,
+    )?;
+
+    emit!(QuickPickBatchPrizeClaimed {
+        player: player_key,
+        tickets_processed,
+        total_prize,
+    });
+
+    msg!("Quick Pick batch claim complete!");
+    msg!("  Player: {}", player_key);
+    msg!("  Tickets processed: {}", tickets_processed);
+    msg!(
+        "  Total prize: {} USDC lamports (${})",
+        total_prize,
+        total_prize as f64 / 1_000_000.0
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// BULK TICKET CLAIM
+// ============================================================================
+
+/// Accounts required for claiming every entry of a Quick Pick ticket batch
+#[derive(Accounts)]
+pub struct ClaimQuickPickBulkPrizes<'info> {
+    /// The player claiming the prizes (must own the ticket batch)
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// The Quick Pick state account
+    #[account(
+        mut,
+        seeds = [QUICK_PICK_SEED],
+        bump = quick_pick_state.bump
+    )]
+    pub quick_pick_state: Account<'info, QuickPickState>,
+
+    /// The ticket batch being claimed
+    #[account(
+        mut,
+        seeds = [
+            QUICK_PICK_TICKET_BATCH_SEED,
+            player.key().as_ref(),
+            &ticket_batch.draw_id.to_le_bytes(),
+            &ticket_batch.start_ticket_id.to_le_bytes()
+        ],
+        bump = ticket_batch.bump,
+        constraint = ticket_batch.owner == player.key() @ QuickPickError::NotTicketOwner
+    )]
+    pub ticket_batch: Account<'info, QuickPickTicketBatch>,
+
+    /// The draw result for the batch's draw (must be finalized)
+    #[account(
+        seeds = [QUICK_PICK_DRAW_SEED, &ticket_batch.draw_id.to_le_bytes()],
+        bump = draw_result.bump,
+        constraint = draw_result.draw_id == ticket_batch.draw_id @ QuickPickError::DrawIdMismatch,
+        constraint = draw_result.is_finalized() @ QuickPickError::DrawNotFinalized
+    )]
+    pub draw_result: Account<'info, QuickPickDrawResult>,
+
+    /// Player's USDC token account (to receive prizes)
+    #[account(
+        mut,
+        constraint = player_usdc.owner == player.key() @ QuickPickError::TokenAccountOwnerMismatch,
+        constraint = player_usdc.mint == usdc_mint.key() @ QuickPickError::InvalidUsdcMint
+    )]
+    pub player_usdc: Account<'info, TokenAccount>,
+
+    /// Prize pool USDC token account
+    #[account(
+        mut,
+        seeds = [PRIZE_POOL_USDC_SEED],
+        bump
+    )]
+    pub prize_pool_usdc: Account<'info, TokenAccount>,
+
+    /// USDC mint
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Mark every unclaimed entry of `batch` claimed, returning the
+/// (match count, prize) of each newly claimed entry
+fn claim_ticket_batch_entries(
+    batch: &mut QuickPickTicketBatch,
+    draw_result: &QuickPickDrawResult,
+) -> Vec<(u8, u64)> {
+    let mut claimed = Vec::new();
+    for index in 0..batch.ticket_count as usize {
+        if batch.is_ticket_claimed(index) {
+            continue;
+        }
+        let Some(numbers) = batch.numbers.get(index) else {
+            continue;
+        };
+        let match_count = count_quick_pick_matches(numbers, &draw_result.winning_numbers);
+        claimed.push((match_count, draw_result.get_prize_for_matches(match_count)));
+        batch.mark_ticket_claimed(index);
+    }
+    claimed
+}
+
+/// Claim the prizes of every unclaimed entry in a Quick Pick ticket batch
+///
+/// Entries are marked claimed in the batch's claimed bitmap, so a second
+/// call pays nothing. The prizes are paid out in a single transfer.
+///
+/// # Arguments
+/// * `ctx` - The context containing all required accounts
+///
+/// # Returns
+/// * `Result<()>` - Success or error
+pub fn handler_claim_quick_pick_bulk_prizes(ctx: Context<ClaimQuickPickBulkPrizes>) -> Result<()> {
+    let clock = Clock::get()?;
+    let player_key = ctx.accounts.player.key();
+    require!(
+        !is_ticket_claim_expired(ctx.accounts.draw_result.timestamp, clock.unix_timestamp),
+        QuickPickError::TicketExpired
+    );
+
+    let claimed =
+        claim_ticket_batch_entries(&mut ctx.accounts.ticket_batch, &ctx.accounts.draw_result);
+    require!(!claimed.is_empty(), QuickPickError::AlreadyClaimed);
+
+    let mut total_prize: u64 = 0;
+    for &(match_count, prize_amount) in &claimed {
+        if prize_amount == 0 {
+            continue;
+        }
+        deduct_paid_prize(
+            &mut ctx.accounts.quick_pick_state,
+            match_count,
+            prize_amount,
+        );
+        total_prize = total_prize
+            .checked_add(prize_amount)
+            .ok_or(QuickPickError::Overflow)?;
+    }
+
+    transfer_quick_pick_prize(
+        &ctx.accounts.prize_pool_usdc,
+        &ctx.accounts.player_usdc,
+        &ctx.accounts.quick_pick_state,
+        &ctx.accounts.token_program,
+        total_prize,
+        ctx.accounts.quick_pick_state.bump,
+    )?;
+
+    emit!(QuickPickBatchPrizeClaimed {
+        player: player_key,
+        tickets_processed: claimed.len() as u32,
+        total_prize,
+    });
+
+    msg!("Quick Pick bulk claim complete!");
+    msg!("  Ticket batch: {}", ctx.accounts.ticket_batch.key());
+    msg!("  Tickets processed: {}", claimed.len());
+    msg!(
+        "  Total prize: {} USDC lamports (${})",
+        total_prize,
+        total_prize as f64 / 1_000_000.0
+    );
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_claim_ticket_batch_entries() {
+        let mut batch = QuickPickTicketBatch {
+            ticket_count: 3,
+            numbers: vec![[1, 2, 3, 4, 5], [1, 2, 3, 30, 31], [10, 11, 12, 13, 14]],
+            claimed_bitmap: vec![0b010],
+            ..Default::default()
+        };
+        let draw_result = QuickPickDrawResult {
+            winning_numbers: [1, 2, 3, 4, 35],
+            match_4_prize_per_winner: 100,
+            match_3_prize_per_winner: 4,
+            ..Default::default()
+        };
+
+        // Entry 1 was already claimed
+        assert_eq!(
+            claim_ticket_batch_entries(&mut batch, &draw_result),
+            vec![(4, 100), (0, 0)]
+        );
+        assert!((0..3).all(|index| batch.is_ticket_claimed(index)));
+        assert!(claim_ticket_batch_entries(&mut batch, &draw_result).is_empty());
+    }
+
     #[test]
     fn test_count_quick_pick_matches_full_match() {
         let ticket = [1, 2, 3, 4, 5];
@@ -383,6 +741,33 @@ mod tests {
         assert_eq!(count_quick_pick_matches(&ticket, &winning), 2);
     }
 
+    #[test]
+    fn test_deduct_paid_prize() {
+        let mut qp_state = QuickPickState {
+            jackpot_balance: 1_000,
+            prize_pool_balance: 300,
+            ..Default::default()
+        };
+
+        // Fixed prizes come out of the prize pool first
+        deduct_paid_prize(&mut qp_state, 4, 500);
+        assert_eq!(qp_state.prize_pool_balance, 0);
+        assert_eq!(qp_state.jackpot_balance, 800);
+
+        // Jackpot prizes come out of the jackpot first
+        deduct_paid_prize(&mut qp_state, 5, 800);
+        assert_eq!(qp_state.jackpot_balance, 0);
+        assert_eq!(qp_state.total_prizes_paid, 1_300);
+    }
+
+    #[test]
+    fn test_is_ticket_claim_expired() {
+        let draw_timestamp = 1_700_000_000;
+        let deadline = draw_timestamp + TICKET_CLAIM_EXPIRATION;
+        assert!(!is_ticket_claim_expired(draw_timestamp, deadline));
+        assert!(is_ticket_claim_expired(draw_timestamp, deadline + 1));
+    }
+
     #[test]
     fn test_count_quick_pick_matches_edge_numbers() {
         let ticket = [1, 2, 33, 34, 35];
@@ -0,0 +1,44 @@
+//! Quick Pick Draw History Instruction
+//!
+//! finalize_draw records a summary of every finalized draw in the
+//! `QuickPickDrawHistory` ring buffer, which keeps the last
+//! QUICK_PICK_HISTORY_LENGTH draws. The account is created by fund_seed.
+//!
+//! - get_quickpick_history: Emit the recent draws, oldest first
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::QuickPickHistorySnapshot;
+use crate::state::QuickPickDrawHistory;
+
+/// Accounts required for reading the Quick Pick draw history
+#[derive(Accounts)]
+pub struct GetQuickPickHistory<'info> {
+    /// The recent draw history account
+    #[account(
+        seeds = [QUICK_PICK_HISTORY_SEED],
+        bump = draw_history.bump
+    )]
+    pub draw_history: Box<Account<'info, QuickPickDrawHistory>>,
+}
+
+/// Emit the recent Quick Pick draw history
+///
+/// Permissionless and read-only; emits `QuickPickHistorySnapshot`.
+pub fn handler_get_quickpick_history(ctx: Context<GetQuickPickHistory>) -> Result<()> {
+    let history = &ctx.accounts.draw_history;
+    let entries = history.chronological();
+    let count = entries.len();
+
+    emit!(QuickPickHistorySnapshot {
+        entries,
+        total_draws: history.total_draws,
+    });
+
+    msg!("Quick Pick draw history snapshot");
+    msg!("  Entries: {}", count);
+    msg!("  Total draws recorded: {}", history.total_draws);
+
+    Ok(())
+}
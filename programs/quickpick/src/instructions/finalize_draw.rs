@@ -15,7 +15,10 @@ use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::QuickPickError;
 use crate::events::QuickPickDrawFinalized;
-use crate::state::{LotteryState, QuickPickDrawResult, QuickPickState, QuickPickWinnerCounts};
+use crate::state::{
+    LotteryState, QuickPickDrawHistory, QuickPickDrawResult, QuickPickHistoryEntry,
+    QuickPickNumberFrequencyAccount, QuickPickState, QuickPickWinnerCounts,
+};
 
 /// Parameters for finalizing the Quick Pick draw
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -62,6 +65,22 @@ pub struct FinalizeQuickPickDraw<'info> {
         constraint = !draw_result.is_explicitly_finalized @ QuickPickError::DrawAlreadyCompleted
     )]
     pub draw_result: Account<'info, QuickPickDrawResult>,
+
+    /// Recent draw history (records this draw's summary)
+    #[account(
+        mut,
+        seeds = [QUICK_PICK_HISTORY_SEED],
+        bump = draw_history.bump
+    )]
+    pub draw_history: Box<Account<'info, QuickPickDrawHistory>>,
+
+    /// Winning number frequency histogram (counts this draw's numbers)
+    #[account(
+        mut,
+        seeds = [QUICK_PICK_NUMBER_FREQUENCY_SEED],
+        bump = number_frequency.bump
+    )]
+    pub number_frequency: Box<Account<'info, QuickPickNumberFrequencyAccount>>,
 }
 
 /// Prize calculation result
@@ -591,6 +610,24 @@ pub fn handler(
         quick_pick_state.is_rolldown_pending = false;
     }
 
+    // Record the draw in the recent history
+    let draw_result = &ctx.accounts.draw_result;
+    ctx.accounts.draw_history.push(QuickPickHistoryEntry {
+        draw_id: current_draw,
+        winning_numbers: draw_result.winning_numbers,
+        jackpot_balance: quick_pick_state.jackpot_balance,
+        was_rolldown,
+        match5_winners: params.winner_counts.match_5,
+        match4_winners: params.winner_counts.match_4,
+        match3_winners: params.winner_counts.match_3,
+        total_prizes: prize_calc.total_distributed,
+        timestamp: draw_result.timestamp,
+    });
+    let winning_numbers = draw_result.winning_numbers;
+    ctx.accounts
+        .number_frequency
+        .record_draw(&winning_numbers)?;
+
     // Emit event
     emit!(QuickPickDrawFinalized {
         draw_id: current_draw,
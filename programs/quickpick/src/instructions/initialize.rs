@@ -16,7 +16,9 @@ use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 use crate::constants::*;
 use crate::errors::QuickPickError;
 use crate::events::{QuickPickInitialized, QuickPickPaused, QuickPickSeeded, QuickPickUnpaused};
-use crate::state::{LotteryState, QuickPickState};
+use crate::state::{
+    LotteryState, QuickPickDrawHistory, QuickPickNumberFrequencyAccount, QuickPickState,
+};
 
 /// Parameters for initializing Quick Pick Express
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -94,6 +96,16 @@ pub struct InitializeQuickPick<'info> {
     )]
     pub insurance_pool_usdc: Account<'info, TokenAccount>,
 
+    /// Winning number frequency histogram (starts empty)
+    #[account(
+        init,
+        payer = authority,
+        space = QUICK_PICK_NUMBER_FREQUENCY_SIZE,
+        seeds = [QUICK_PICK_NUMBER_FREQUENCY_SEED],
+        bump
+    )]
+    pub number_frequency: Box<Account<'info, QuickPickNumberFrequencyAccount>>,
+
     /// System program
     pub system_program: Program<'info, System>,
 
@@ -176,8 +188,15 @@ pub fn handler(ctx: Context<InitializeQuickPick>, params: InitializeQuickPickPar
     quick_pick_state.is_paused = true; // Start paused, must be funded and unpaused
     quick_pick_state.is_funded = false; // Will be set true after fund_seed
 
+    // Spend gate and config timelock
+    quick_pick_state.spend_gate_enabled = true;
+    quick_pick_state.config_timelock_end = 0;
+    quick_pick_state.pending_config_hash = [0u8; 32];
+    quick_pick_state.ticket_sale_cutoff_seconds = TICKET_SALE_CUTOFF;
+
     // Store bump
     quick_pick_state.bump = ctx.bumps.quick_pick_state;
+    ctx.accounts.number_frequency.bump = ctx.bumps.number_frequency;
 
     // Emit event
     emit!(QuickPickInitialized {
@@ -269,8 +288,21 @@ pub struct FundQuickPickSeed<'info> {
     )]
     pub prize_pool_usdc: Account<'info, TokenAccount>,
 
+    /// Recent draw history account to be created
+    #[account(
+        init,
+        payer = authority,
+        space = QUICK_PICK_HISTORY_SIZE,
+        seeds = [QUICK_PICK_HISTORY_SEED],
+        bump
+    )]
+    pub draw_history: Box<Account<'info, QuickPickDrawHistory>>,
+
     /// Token program
     pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
 }
 
 /// Fund Quick Pick seed and unpause
@@ -302,6 +334,9 @@ pub fn handler_fund_seed(ctx: Context<FundQuickPickSeed>) -> Result<()> {
     quick_pick_state.is_funded = true;
     quick_pick_state.is_paused = false;
 
+    // Start with an empty draw history
+    ctx.accounts.draw_history.bump = ctx.bumps.draw_history;
+
     // Emit event
     emit!(QuickPickSeeded {
         authority: ctx.accounts.authority.key(),
@@ -21,6 +21,9 @@ pub mod initialize;
 // Buy Quick Pick ticket
 pub mod buy_ticket;
 
+// Buy multiple Quick Pick tickets in one transaction
+pub mod buy_bulk;
+
 // Commit randomness for Quick Pick draw
 pub mod commit_randomness;
 
@@ -30,9 +33,15 @@ pub mod execute_draw;
 // Finalize Quick Pick draw with winner counts
 pub mod finalize_draw;
 
-// Claim Quick Pick prize
+// Claim Quick Pick prizes (single ticket or batch)
 pub mod claim_prize;
 
+// Recent Quick Pick draw history
+pub mod draw_history;
+
+// Quick Pick winning number frequency histogram
+pub mod number_frequency;
+
 // Re-export account structs and params from initialize
 pub use initialize::{
     FundQuickPickSeed, InitializeQuickPick, InitializeQuickPickParams, PauseQuickPick,
@@ -41,6 +50,9 @@ pub use initialize::{
 // Re-export account structs and params from buy_ticket
 pub use buy_ticket::{BuyQuickPickTicket, BuyQuickPickTicketParams};
 
+// Re-export account structs and params from buy_bulk
+pub use buy_bulk::{BuyQuickPickBulk, BuyQuickPickBulkParams};
+
 // Re-export account structs from commit_randomness
 pub use commit_randomness::CommitQuickPickRandomness;
 
@@ -51,7 +63,13 @@ pub use execute_draw::ExecuteQuickPickDraw;
 pub use finalize_draw::{FinalizeQuickPickDraw, FinalizeQuickPickDrawParams};
 
 // Re-export account structs from claim_prize
-pub use claim_prize::ClaimQuickPickPrize;
+pub use claim_prize::{ClaimQuickPickBulkPrizes, ClaimQuickPickPrize, ClaimQuickPickPrizesBatch};
+
+// Re-export account structs from draw_history
+pub use draw_history::GetQuickPickHistory;
+
+// Re-export account structs from number_frequency
+pub use number_frequency::GetQuickPickNumberFrequencies;
 
 // Re-export account structs and params from admin
 pub use admin::{
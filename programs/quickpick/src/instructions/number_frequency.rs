@@ -0,0 +1,53 @@
+//! Quick Pick Number Frequency Instruction
+//!
+//! initialize creates the `QuickPickNumberFrequencyAccount`, which counts how
+//! often each number from 1 to QUICK_PICK_RANGE has been drawn.
+//! finalize_draw adds the five winning numbers of every draw.
+//!
+//! - get_quickpick_number_frequencies: Emit the histogram with hot and cold numbers
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::QuickPickNumberFrequencySnapshot;
+use crate::state::QuickPickNumberFrequencyAccount;
+
+/// Accounts required for reading the Quick Pick number frequency histogram
+#[derive(Accounts)]
+pub struct GetQuickPickNumberFrequencies<'info> {
+    /// The number frequency histogram
+    #[account(
+        seeds = [QUICK_PICK_NUMBER_FREQUENCY_SEED],
+        bump = number_frequency.bump
+    )]
+    pub number_frequency: Box<Account<'info, QuickPickNumberFrequencyAccount>>,
+}
+
+/// Emit the Quick Pick number frequency histogram
+///
+/// Permissionless and read-only; emits `QuickPickNumberFrequencySnapshot`.
+pub fn handler_get_quickpick_number_frequencies(
+    ctx: Context<GetQuickPickNumberFrequencies>,
+) -> Result<()> {
+    let number_frequency = &ctx.accounts.number_frequency;
+    let hot_number = number_frequency.hot_number();
+    let cold_number = number_frequency.cold_number();
+    let expected_frequency = number_frequency.expected_frequency();
+    let total_draws = number_frequency.total_draws_recorded;
+
+    emit!(QuickPickNumberFrequencySnapshot {
+        frequencies: number_frequency.frequencies,
+        hot_number,
+        cold_number,
+        expected_frequency,
+        total_draws,
+    });
+
+    msg!("Quick Pick number frequency snapshot");
+    msg!("  Draws recorded: {}", total_draws);
+    msg!("  Hot number: {}", hot_number);
+    msg!("  Cold number: {}", cold_number);
+    msg!("  Expected per number: {}", expected_frequency);
+
+    Ok(())
+}
@@ -50,17 +50,23 @@ pub use state::*;
 #[allow(ambiguous_glob_reexports)]
 pub use instructions::admin::*;
 #[allow(ambiguous_glob_reexports)]
+pub use instructions::buy_bulk::*;
+#[allow(ambiguous_glob_reexports)]
 pub use instructions::buy_ticket::*;
 #[allow(ambiguous_glob_reexports)]
 pub use instructions::claim_prize::*;
 #[allow(ambiguous_glob_reexports)]
 pub use instructions::commit_randomness::*;
 #[allow(ambiguous_glob_reexports)]
+pub use instructions::draw_history::*;
+#[allow(ambiguous_glob_reexports)]
 pub use instructions::execute_draw::*;
 #[allow(ambiguous_glob_reexports)]
 pub use instructions::finalize_draw::*;
 #[allow(ambiguous_glob_reexports)]
 pub use instructions::initialize::*;
+#[allow(ambiguous_glob_reexports)]
+pub use instructions::number_frequency::*;
 
 // Program ID - Update this after deployment
 declare_id!("7XC1KT5mvsHHXbR2mH6er138fu2tJ4L2fAgmpjLnnZK2");
@@ -145,6 +151,36 @@ pub mod quickpick {
         instructions::admin::handler_update_config(ctx, params)
     }
 
+    /// Propose Quick Pick Express configuration changes (starts 24hr timelock)
+    ///
+    /// Stores a hash of the proposed params. Required for spend gate changes,
+    /// which cannot be made through `update_config`.
+    ///
+    /// # Arguments
+    /// * `ctx` - UpdateQuickPickConfig accounts context
+    /// * `params` - Configuration parameters being proposed
+    pub fn propose_quickpick_config(
+        ctx: Context<UpdateQuickPickConfig>,
+        params: UpdateQuickPickConfigParams,
+    ) -> Result<()> {
+        instructions::admin::handler_propose_config(ctx, params)
+    }
+
+    /// Execute proposed Quick Pick Express configuration changes
+    ///
+    /// Applies the proposal once the 24hr timelock has expired.
+    /// The params must exactly match the proposal.
+    ///
+    /// # Arguments
+    /// * `ctx` - UpdateQuickPickConfig accounts context
+    /// * `params` - Configuration parameters (must match proposal)
+    pub fn execute_quickpick_config(
+        ctx: Context<UpdateQuickPickConfig>,
+        params: UpdateQuickPickConfigParams,
+    ) -> Result<()> {
+        instructions::admin::handler_execute_config(ctx, params)
+    }
+
     /// Withdraw accumulated house fees
     ///
     /// Transfers house fees to a destination account.
@@ -228,8 +264,9 @@ pub mod quickpick {
     /// Buy a Quick Pick Express ticket
     ///
     /// Purchases a ticket with 5 selected numbers from 1-35.
-    /// Requires $50 lifetime spend in the main lottery ($50 gate).
-    /// USDC is transferred from player to prize pool, house fee, and insurance accounts.
+    /// Requires the configured lifetime spend in the main lottery ($50 gate by default).
+    /// USDC is transferred from player to prize pool, house fee, and insurance accounts,
+    /// unless the optional `free_qp_credit` from a main lottery Match 5 win is supplied.
     ///
     /// # Arguments
     /// * `ctx` - BuyQuickPickTicket accounts context
@@ -241,6 +278,22 @@ pub mod quickpick {
         instructions::buy_ticket::handler(ctx, params)
     }
 
+    /// Buy multiple Quick Pick Express tickets in one transaction
+    ///
+    /// Purchases up to 50 tickets for the current draw, stored in a single
+    /// ticket batch account. The spend gate is checked once per call and
+    /// the total price is split like a single-ticket purchase.
+    ///
+    /// # Arguments
+    /// * `ctx` - BuyQuickPickBulk accounts context
+    /// * `params` - Number sets, one per ticket (5 unique numbers from 1-35)
+    pub fn buy_quick_pick_bulk(
+        ctx: Context<BuyQuickPickBulk>,
+        params: BuyQuickPickBulkParams,
+    ) -> Result<()> {
+        instructions::buy_bulk::handler(ctx, params)
+    }
+
     // =========================================================================
     // DRAW EXECUTION INSTRUCTIONS
     // =========================================================================
@@ -313,4 +366,58 @@ pub mod quickpick {
     pub fn claim_prize(ctx: Context<ClaimQuickPickPrize>) -> Result<()> {
         instructions::claim_prize::handler(ctx)
     }
+
+    /// Claim prizes for up to 10 Quick Pick tickets across draws
+    ///
+    /// Takes draw result / ticket pairs as remaining accounts, skips tickets
+    /// that are already claimed and pays the total in a single transfer.
+    ///
+    /// # Arguments
+    /// * `ctx` - ClaimQuickPickPrizesBatch accounts context
+    pub fn claim_quickpick_prizes_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimQuickPickPrizesBatch<'info>>,
+    ) -> Result<()> {
+        instructions::claim_prize::handler_claim_quickpick_prizes_batch(ctx)
+    }
+
+    /// Claim the prizes of every unclaimed entry in a bulk ticket batch
+    ///
+    /// Uses the batch's claimed bitmap and pays the total in a single
+    /// transfer.
+    ///
+    /// # Arguments
+    /// * `ctx` - ClaimQuickPickBulkPrizes accounts context
+    pub fn claim_quick_pick_bulk_prizes(ctx: Context<ClaimQuickPickBulkPrizes>) -> Result<()> {
+        instructions::claim_prize::handler_claim_quick_pick_bulk_prizes(ctx)
+    }
+
+    // =========================================================================
+    // VIEW INSTRUCTIONS
+    // =========================================================================
+
+    /// Emit the recent Quick Pick draw history
+    ///
+    /// Emits the last 20 finalized draws (winning numbers, winner counts,
+    /// prizes and jackpot balance) as a `QuickPickHistorySnapshot` event.
+    /// Permissionless.
+    ///
+    /// # Arguments
+    /// * `ctx` - GetQuickPickHistory accounts context
+    pub fn get_quickpick_history(ctx: Context<GetQuickPickHistory>) -> Result<()> {
+        instructions::draw_history::handler_get_quickpick_history(ctx)
+    }
+
+    /// Emit how often each Quick Pick number has been drawn
+    ///
+    /// Emits a `QuickPickNumberFrequencySnapshot` event with the per-number
+    /// counts, the hot and cold numbers and the expected count per number.
+    /// Permissionless.
+    ///
+    /// # Arguments
+    /// * `ctx` - GetQuickPickNumberFrequencies accounts context
+    pub fn get_quickpick_number_frequencies(
+        ctx: Context<GetQuickPickNumberFrequencies>,
+    ) -> Result<()> {
+        instructions::number_frequency::handler_get_quickpick_number_frequencies(ctx)
+    }
 }
@@ -6,6 +6,7 @@
 use anchor_lang::prelude::*;
 
 use crate::constants::*;
+use crate::errors::QuickPickError;
 
 // ============================================================================
 // QUICK PICK STATE
@@ -96,6 +97,19 @@ pub struct QuickPickState {
     /// Is the lottery funded (seed amount deposited)
     pub is_funded: bool,
 
+    /// Whether the main lottery spend gate is enforced (the threshold is the
+    /// main lottery's `quickpick_spend_gate_threshold`)
+    pub spend_gate_enabled: bool,
+
+    /// Unix timestamp when the pending config proposal becomes executable (0 = none)
+    pub config_timelock_end: i64,
+
+    /// SHA256 hash of the pending config proposal (zero = none)
+    pub pending_config_hash: [u8; 32],
+
+    /// Seconds before `next_draw_timestamp` at which ticket sales close
+    pub ticket_sale_cutoff_seconds: i64,
+
     /// PDA bump seed
     pub bump: u8,
 }
@@ -129,8 +143,12 @@ impl QuickPickState {
         1 +    // is_rolldown_pending
         1 +    // is_paused
         1 +    // is_funded
+        1 +    // spend_gate_enabled
+        8 +    // config_timelock_end
+        32 +   // pending_config_hash
+        8 +    // ticket_sale_cutoff_seconds
         1 +    // bump
-        32; // padding for future use
+        24; // padding for future use
 
     /// Get current house fee based on jackpot level
     pub fn get_current_house_fee_bps(&self) -> u16 {
@@ -144,9 +162,13 @@ impl QuickPickState {
             return false;
         }
 
-        // Sales close 5 minutes before draw
-        let sale_cutoff = self.next_draw_timestamp.saturating_sub(TICKET_SALE_CUTOFF);
-        current_timestamp < sale_cutoff
+        current_timestamp < self.ticket_sale_cutoff_time()
+    }
+
+    /// Time at which ticket sales for the current draw close
+    pub fn ticket_sale_cutoff_time(&self) -> i64 {
+        self.next_draw_timestamp
+            .saturating_sub(self.ticket_sale_cutoff_seconds)
     }
 
     /// Check if jackpot is properly funded (meets minimum seed amount)
@@ -340,6 +362,86 @@ impl QuickPickTicket {
     }
 }
 
+// ============================================================================
+// QUICK PICK TICKET BATCH (for bulk purchases)
+// ============================================================================
+
+/// Quick Pick Express ticket batch for bulk purchases (5/35 Matrix)
+///
+/// Stores several tickets for the same draw in a single account, mirroring
+/// the main lottery's unified ticket layout with 5-number entries.
+#[account]
+#[derive(Default)]
+pub struct QuickPickTicketBatch {
+    /// Wallet that owns all tickets in this batch
+    pub owner: Pubkey,
+
+    /// Draw ID that all tickets are for
+    pub draw_id: u64,
+
+    /// Starting ticket index within the draw
+    pub start_ticket_id: u64,
+
+    /// Number of tickets in this batch
+    pub ticket_count: u32,
+
+    /// Selected numbers, one sorted [u8; 5] entry per ticket
+    pub numbers: Vec<[u8; 5]>,
+
+    /// Purchase timestamp (unix timestamp)
+    pub purchase_timestamp: i64,
+
+    /// Claimed status for each ticket (1 bit per ticket)
+    pub claimed_bitmap: Vec<u8>,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl QuickPickTicketBatch {
+    /// Maximum tickets a single batch can hold
+    pub const MAX_TICKETS: usize = QUICK_PICK_MAX_BULK_TICKETS;
+
+    /// Calculate account size for a batch of `ticket_count` tickets
+    pub fn size_for_count(ticket_count: usize) -> usize {
+        8 + // discriminator
+        32 + // owner
+        8 +  // draw_id
+        8 +  // start_ticket_id
+        4 +  // ticket_count
+        4 +  // numbers vector length
+        (ticket_count * 5) + // numbers data (5 bytes each)
+        8 +  // purchase_timestamp
+        4 +  // claimed_bitmap vector length
+        ticket_count.div_ceil(8) + // claimed_bitmap data (1 bit per ticket)
+        1 // bump
+    }
+
+    /// Check if a specific ticket is claimed
+    ///
+    /// Out-of-bounds indices (or a truncated bitmap) are treated as claimed
+    /// so malformed state can never be claimed twice.
+    pub fn is_ticket_claimed(&self, index: usize) -> bool {
+        if index >= self.ticket_count as usize {
+            return true;
+        }
+        match self.claimed_bitmap.get(index / 8) {
+            Some(byte) => (byte & (1 << (index % 8))) != 0,
+            None => true,
+        }
+    }
+
+    /// Mark a specific ticket as claimed
+    pub fn mark_ticket_claimed(&mut self, index: usize) {
+        if index >= self.ticket_count as usize {
+            return;
+        }
+        if let Some(byte) = self.claimed_bitmap.get_mut(index / 8) {
+            *byte |= 1 << (index % 8);
+        }
+    }
+}
+
 // ============================================================================
 // QUICK PICK DRAW RESULT
 // ============================================================================
@@ -440,6 +542,176 @@ impl QuickPickDrawResult {
     }
 }
 
+// ============================================================================
+// DRAW HISTORY
+// ============================================================================
+
+/// Compact record of a finalized draw kept in the `QuickPickDrawHistory`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct QuickPickHistoryEntry {
+    /// Draw identifier
+    pub draw_id: u64,
+
+    /// Winning numbers (sorted ascending)
+    pub winning_numbers: [u8; 5],
+
+    /// Jackpot balance after finalization
+    pub jackpot_balance: u64,
+
+    /// Whether the draw paid out as a rolldown
+    pub was_rolldown: bool,
+
+    /// Match 5 (Jackpot) winners
+    pub match5_winners: u32,
+
+    /// Match 4 winners
+    pub match4_winners: u32,
+
+    /// Match 3 winners
+    pub match3_winners: u32,
+
+    /// Total prizes committed at finalization
+    pub total_prizes: u64,
+
+    /// Draw execution timestamp
+    pub timestamp: i64,
+}
+
+/// Ring buffer of the most recent finalized Quick Pick draws
+///
+/// PDA: [QUICK_PICK_HISTORY_SEED]. Created by fund_seed; finalize_draw
+/// writes each entry at `head` and advances it, overwriting the oldest
+/// entry once the buffer is full.
+#[account]
+#[derive(Default)]
+pub struct QuickPickDrawHistory {
+    /// Slot the next entry is written to (0..QUICK_PICK_HISTORY_LENGTH)
+    pub head: u8,
+
+    /// Total entries ever recorded
+    pub total_draws: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Recorded entries; only the first `total_draws` slots are valid
+    /// until the buffer wraps
+    pub entries: [QuickPickHistoryEntry; QUICK_PICK_HISTORY_LENGTH],
+}
+
+impl QuickPickDrawHistory {
+    /// Account size including discriminator
+    pub const LEN: usize = QUICK_PICK_HISTORY_SIZE;
+
+    /// Record `entry`, overwriting the oldest entry when full
+    pub fn push(&mut self, entry: QuickPickHistoryEntry) {
+        let index = self.head as usize % QUICK_PICK_HISTORY_LENGTH;
+        self.entries[index] = entry;
+        self.head = ((index + 1) % QUICK_PICK_HISTORY_LENGTH) as u8;
+        self.total_draws = self.total_draws.saturating_add(1);
+    }
+
+    /// Recorded entries, oldest first
+    pub fn chronological(&self) -> Vec<QuickPickHistoryEntry> {
+        let len = (self.total_draws.min(QUICK_PICK_HISTORY_LENGTH as u64)) as usize;
+        let start = if len < QUICK_PICK_HISTORY_LENGTH {
+            0
+        } else {
+            self.head as usize % QUICK_PICK_HISTORY_LENGTH
+        };
+
+        (0..len)
+            .map(|i| self.entries[(start + i) % QUICK_PICK_HISTORY_LENGTH])
+            .collect()
+    }
+}
+
+// ============================================================================
+// NUMBER FREQUENCY
+// ============================================================================
+
+/// How often each number has been drawn across all finalized Quick Pick draws
+///
+/// PDA: [QUICK_PICK_NUMBER_FREQUENCY_SEED]. Created by initialize;
+/// finalize_draw counts the five winning numbers of every draw.
+#[account]
+pub struct QuickPickNumberFrequencyAccount {
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Times each number has been drawn (index = number, slot 0 unused)
+    pub frequencies: [u32; QUICK_PICK_NUMBER_FREQUENCY_SLOTS],
+
+    /// Finalized draws counted so far
+    pub total_draws_recorded: u64,
+}
+
+impl Default for QuickPickNumberFrequencyAccount {
+    fn default() -> Self {
+        Self {
+            bump: 0,
+            frequencies: [0; QUICK_PICK_NUMBER_FREQUENCY_SLOTS],
+            total_draws_recorded: 0,
+        }
+    }
+}
+
+impl QuickPickNumberFrequencyAccount {
+    /// Account size including discriminator
+    pub const LEN: usize = QUICK_PICK_NUMBER_FREQUENCY_SIZE;
+
+    /// Count the winning numbers of one finalized draw
+    pub fn record_draw(
+        &mut self,
+        winning_numbers: &[u8; QUICK_PICK_NUMBERS as usize],
+    ) -> Result<()> {
+        for &number in winning_numbers {
+            require!(
+                (1..=QUICK_PICK_RANGE).contains(&number),
+                QuickPickError::InvalidNumbers
+            );
+            let count = &mut self.frequencies[number as usize];
+            *count = count.checked_add(1).ok_or(QuickPickError::Overflow)?;
+        }
+
+        self.total_draws_recorded = self
+            .total_draws_recorded
+            .checked_add(1)
+            .ok_or(QuickPickError::Overflow)?;
+
+        Ok(())
+    }
+
+    /// Most frequently drawn number (lowest number on ties)
+    pub fn hot_number(&self) -> u8 {
+        (1..=QUICK_PICK_RANGE).fold(1, |best, number| {
+            if self.frequencies[number as usize] > self.frequencies[best as usize] {
+                number
+            } else {
+                best
+            }
+        })
+    }
+
+    /// Least frequently drawn number (lowest number on ties)
+    pub fn cold_number(&self) -> u8 {
+        (1..=QUICK_PICK_RANGE).fold(1, |best, number| {
+            if self.frequencies[number as usize] < self.frequencies[best as usize] {
+                number
+            } else {
+                best
+            }
+        })
+    }
+
+    /// Draws each number would have if every number came up equally often
+    pub fn expected_frequency(&self) -> u64 {
+        self.total_draws_recorded
+            .saturating_mul(QUICK_PICK_NUMBERS as u64)
+            / QUICK_PICK_RANGE as u64
+    }
+}
+
 // ============================================================================
 // HELPER TYPES
 // ============================================================================
@@ -480,6 +752,9 @@ impl QuickPickWinnerCounts {
 #[account]
 #[derive(Default)]
 pub struct UserStats {
+    /// Main lottery account layout version
+    pub schema_version: u8,
+
     /// User's wallet address
     pub wallet: Pubkey,
 
@@ -518,6 +793,7 @@ impl UserStats {
     /// Account size including discriminator
     /// FIXED: free_tickets_available is u32 (4 bytes), matching main lottery
     pub const LEN: usize = 8 +  // discriminator
+        1 +     // schema_version
         32 +    // wallet
         8 +     // total_tickets
         8 +     // total_spent
@@ -543,6 +819,9 @@ impl UserStats {
 /// The actual LotteryState account is owned by the main lottery program.
 #[account]
 pub struct LotteryState {
+    /// Main lottery account layout version
+    pub schema_version: u8,
+
     /// Authority (owner) of the lottery
     pub authority: Pubkey,
 
@@ -626,6 +905,90 @@ pub struct LotteryState {
 mod tests {
     use super::*;
 
+    fn history_entry(draw_id: u64) -> QuickPickHistoryEntry {
+        QuickPickHistoryEntry {
+            draw_id,
+            ..Default::default()
+        }
+    }
+
+    fn history_draw_ids(history: &QuickPickDrawHistory) -> Vec<u64> {
+        history.chronological().iter().map(|e| e.draw_id).collect()
+    }
+
+    #[test]
+    fn test_draw_history_partial_fill_is_chronological() {
+        let mut history = QuickPickDrawHistory::default();
+        assert!(history.chronological().is_empty());
+
+        for draw_id in 1..=3 {
+            history.push(history_entry(draw_id));
+        }
+
+        assert_eq!(history_draw_ids(&history), vec![1, 2, 3]);
+        assert_eq!(history.total_draws, 3);
+    }
+
+    #[test]
+    fn test_draw_history_wraps_and_keeps_latest() {
+        let mut history = QuickPickDrawHistory::default();
+        let total = QUICK_PICK_HISTORY_LENGTH as u64 + 5;
+
+        for draw_id in 1..=total {
+            history.push(history_entry(draw_id));
+        }
+
+        let expected: Vec<u64> = (6..=total).collect();
+        assert_eq!(history_draw_ids(&history), expected);
+        assert_eq!(history.total_draws, total);
+    }
+
+    #[test]
+    fn test_number_frequency_records_draws() {
+        let mut number_frequency = QuickPickNumberFrequencyAccount::default();
+
+        number_frequency.record_draw(&[1, 2, 3, 4, 35]).unwrap();
+        number_frequency.record_draw(&[2, 9, 18, 27, 35]).unwrap();
+
+        assert_eq!(number_frequency.total_draws_recorded, 2);
+        assert_eq!(number_frequency.frequencies[2], 2);
+        assert_eq!(number_frequency.frequencies[35], 2);
+        assert_eq!(number_frequency.frequencies[9], 1);
+        assert_eq!(number_frequency.frequencies.iter().sum::<u32>(), 10);
+
+        assert!(number_frequency.record_draw(&[0, 2, 3, 4, 5]).is_err());
+        assert!(number_frequency.record_draw(&[1, 2, 3, 4, 36]).is_err());
+    }
+
+    #[test]
+    fn test_number_frequency_hot_cold_and_expected() {
+        let mut number_frequency = QuickPickNumberFrequencyAccount::default();
+        assert_eq!(number_frequency.hot_number(), 1);
+        assert_eq!(number_frequency.cold_number(), 1);
+        assert_eq!(number_frequency.expected_frequency(), 0);
+
+        for number in 1..=QUICK_PICK_RANGE {
+            number_frequency.frequencies[number as usize] = 3;
+        }
+        number_frequency.frequencies[12] = 8;
+        number_frequency.frequencies[30] = 8;
+        number_frequency.frequencies[5] = 0;
+        number_frequency.total_draws_recorded = 70;
+
+        assert_eq!(number_frequency.hot_number(), 12);
+        assert_eq!(number_frequency.cold_number(), 5);
+        assert_eq!(number_frequency.expected_frequency(), 10);
+    }
+
+    #[test]
+    fn test_draw_history_size_matches_layout() {
+        let history = QuickPickDrawHistory::default();
+        assert_eq!(
+            8 + history.try_to_vec().unwrap().len(),
+            QUICK_PICK_HISTORY_SIZE
+        );
+    }
+
     #[test]
     fn test_quick_pick_ticket_calculate_matches_full() {
         let ticket = QuickPickTicket {
@@ -656,6 +1019,34 @@ mod tests {
         assert_eq!(ticket.calculate_matches(&winning), 3);
     }
 
+    #[test]
+    fn test_quick_pick_ticket_batch_claimed_bitmap() {
+        let mut batch = QuickPickTicketBatch {
+            ticket_count: 10,
+            numbers: vec![[1, 2, 3, 4, 5]; 10],
+            claimed_bitmap: vec![0; 2],
+            ..Default::default()
+        };
+
+        assert!(!batch.is_ticket_claimed(9));
+        batch.mark_ticket_claimed(9);
+        assert!(batch.is_ticket_claimed(9));
+        assert!(!batch.is_ticket_claimed(8));
+
+        // Out of bounds is always treated as claimed
+        assert!(batch.is_ticket_claimed(10));
+    }
+
+    #[test]
+    fn test_quick_pick_ticket_batch_size() {
+        // 8 + 32 + 8 + 8 + 4 + (4 + 5) + 8 + (4 + 1) + 1
+        assert_eq!(QuickPickTicketBatch::size_for_count(1), 83);
+        assert_eq!(
+            QuickPickTicketBatch::size_for_count(QuickPickTicketBatch::MAX_TICKETS),
+            83 + 49 * 5 + 6
+        );
+    }
+
     #[test]
     fn test_quick_pick_state_rolldown_probability() {
         let mut state = QuickPickState {
@@ -710,4 +1101,24 @@ mod tests {
         // 1 * $10,000 + 10 * $100 + 100 * $4 = $10,000 + $1,000 + $400 = $11,400
         assert_eq!(total, 11_400_000_000);
     }
+
+    #[test]
+    fn test_draw_result_is_finalized() {
+        // Executed but not finalized: winning numbers set, prizes still zero
+        let mut result = QuickPickDrawResult {
+            winning_numbers: [3, 9, 14, 22, 31],
+            ..Default::default()
+        };
+        assert!(!result.is_finalized());
+
+        result.match_3_prize_per_winner = 4_000_000;
+        assert!(result.is_finalized());
+
+        // A draw with no winners is finalized only by the explicit flag
+        let no_winners = QuickPickDrawResult {
+            is_explicitly_finalized: true,
+            ..Default::default()
+        };
+        assert!(no_winners.is_finalized());
+    }
 }
@@ -317,6 +317,7 @@ describe("mazelprotocol", () => {
       // Fetch and verify the lottery state
       const state = await programAccounts.lotteryState.fetch(pdas.lotteryState);
 
+      expect(state.schemaVersion).to.equal(1);
       expect(state.authority.toString()).to.equal(
         authority.publicKey.toString(),
       );
@@ -386,6 +387,7 @@ describe("mazelprotocol", () => {
           .buyTicket({
             numbers: [1, 2, 3, 4, 5, 6],
             useFreeTicket: false,
+            maxPriceUsdc: TICKET_PRICE,
           })
           .accountsPartial({
             player: player1.publicKey,
@@ -604,6 +606,7 @@ describe("mazelprotocol", () => {
         .buyTicket({
           numbers: [3, 10, 22, 33, 40, 46],
           useFreeTicket: false,
+          maxPriceUsdc: TICKET_PRICE,
         })
         .accountsPartial({
           player: player1.publicKey,
@@ -673,6 +676,7 @@ describe("mazelprotocol", () => {
         .buyTicket({
           numbers: [46, 1, 30, 15, 8, 23],
           useFreeTicket: false,
+          maxPriceUsdc: TICKET_PRICE,
         })
         .accountsPartial({
           player: player1.publicKey,
@@ -708,6 +712,7 @@ describe("mazelprotocol", () => {
           .buyTicket({
             numbers: [0, 1, 2, 3, 4, 5],
             useFreeTicket: false,
+            maxPriceUsdc: TICKET_PRICE,
           })
           .accountsPartial({
             player: player1.publicKey,
@@ -746,6 +751,7 @@ describe("mazelprotocol", () => {
           .buyTicket({
             numbers: [1, 2, 3, 4, 5, 47],
             useFreeTicket: false,
+            maxPriceUsdc: TICKET_PRICE,
           })
           .accountsPartial({
             player: player1.publicKey,
@@ -784,6 +790,7 @@ describe("mazelprotocol", () => {
           .buyTicket({
             numbers: [1, 1, 3, 4, 5, 6],
             useFreeTicket: false,
+            maxPriceUsdc: TICKET_PRICE,
           })
           .accountsPartial({
             player: player1.publicKey,
@@ -821,6 +828,7 @@ describe("mazelprotocol", () => {
         .buyTicket({
           numbers: [5, 12, 18, 29, 35, 44],
           useFreeTicket: false,
+          maxPriceUsdc: TICKET_PRICE,
         })
         .accountsPartial({
           player: player2.publicKey,
@@ -901,7 +909,7 @@ describe("mazelprotocol", () => {
       ];
 
       await program.methods
-        .buyBulk({ tickets })
+        .buyBulk({ tickets, maxPriceUsdc: TICKET_PRICE })
         .accountsPartial({
           player: player1.publicKey,
           lotteryState: pdas.lotteryState,
@@ -959,7 +967,7 @@ describe("mazelprotocol", () => {
 
       try {
         await program.methods
-          .buyBulk({ tickets: [] })
+          .buyBulk({ tickets: [], maxPriceUsdc: TICKET_PRICE })
           .accountsPartial({
             player: player1.publicKey,
             lotteryState: pdas.lotteryState,
@@ -1004,6 +1012,7 @@ describe("mazelprotocol", () => {
               [1, 2, 3, 4, 5, 6],
               [7, 8, 9, 10, 10, 12], // duplicate 10
             ],
+            maxPriceUsdc: TICKET_PRICE,
           })
           .accountsPartial({
             player: player1.publicKey,
@@ -2019,6 +2028,7 @@ describe("mazelprotocol", () => {
               (i * 6 + 6) % 46 || 6,
             ],
             useFreeTicket: false,
+            maxPriceUsdc: TICKET_PRICE,
           })
           .accountsPartial({
             player: player2.publicKey,
@@ -2085,6 +2095,7 @@ describe("mazelprotocol", () => {
           .buyTicket({
             numbers: [1, 2, 3, 4, 5, 6],
             useFreeTicket: false,
+            maxPriceUsdc: TICKET_PRICE,
           })
           .accountsPartial({
             player: brokePlayer.publicKey,
@@ -2132,6 +2143,7 @@ describe("mazelprotocol", () => {
           .buyTicket({
             numbers: [1, 2, 3, 4, 5, 6],
             useFreeTicket: true, // Request free ticket
+            maxPriceUsdc: TICKET_PRICE,
           })
           .accountsPartial({
             player: player1.publicKey,
@@ -2182,6 +2194,7 @@ describe("mazelprotocol", () => {
             lotteryState: pdas.lotteryState,
             randomnessAccountData: fakeRandomnessAccount.publicKey,
             switchboardQueue: switchboardQueue,
+            prizePoolUsdc: pdas.prizePoolUsdc,
             systemProgram: SystemProgram.programId,
           })
           .rpc();
@@ -2192,6 +2205,25 @@ describe("mazelprotocol", () => {
       }
     });
 
+    it("prize pool covers jackpot + reserve, so the commit solvency gate is open", async () => {
+      // commit_randomness fails with InsufficientPrizePool (leaving the
+      // lottery unpaused) when the prize pool holds less than
+      // jackpot + reserve - SOLVENCY_TOLERANCE (100 lamports)
+      const SOLVENCY_TOLERANCE = 100;
+      const state = await programAccounts.lotteryState.fetch(pdas.lotteryState);
+      const prizePool = await getAccount(
+        provider.connection,
+        pdas.prizePoolUsdc,
+      );
+
+      const expected =
+        state.jackpotBalance.toNumber() +
+        state.reserveBalance.toNumber() -
+        SOLVENCY_TOLERANCE;
+      expect(Number(prizePool.amount)).to.be.at.least(expected);
+      expect(state.isPaused).to.be.false;
+    });
+
     it("execute_draw fails when no draw is in progress", async () => {
       const state = await programAccounts.lotteryState.fetch(pdas.lotteryState);
       expect(state.isDrawInProgress).to.be.false;
@@ -2260,7 +2292,7 @@ describe("mazelprotocol", () => {
 
       try {
         await program.methods
-          .cancelDraw()
+          .cancelDraw({ preserveTickets: {} })
           .accountsPartial({
             authority: authority.publicKey,
             lotteryState: pdas.lotteryState,
@@ -2290,7 +2322,7 @@ describe("mazelprotocol", () => {
     it("cancel_draw fails from non-authority", async () => {
       try {
         await program.methods
-          .cancelDraw()
+          .cancelDraw({ preserveTickets: {} })
           .accountsPartial({
             authority: unauthorizedUser.publicKey,
             lotteryState: pdas.lotteryState,
@@ -2333,6 +2365,7 @@ describe("mazelprotocol", () => {
         .buyTicket({
           numbers: [7, 14, 21, 28, 35, 42],
           useFreeTicket: false,
+          maxPriceUsdc: TICKET_PRICE,
         })
         .accountsPartial({
           player: player1.publicKey,
@@ -2431,7 +2464,7 @@ describe("mazelprotocol", () => {
         // cancel_draw
         () =>
           program.methods
-            .cancelDraw()
+            .cancelDraw({ preserveTickets: {} })
             .accountsPartial({
               authority: unauthorizedUser.publicKey,
               lotteryState: pdas.lotteryState,
@@ -379,7 +379,11 @@ describe("quickpick", () => {
       ];
 
       await mainProgram.methods
-        .buyTicket({ numbers: finalNums, useFreeTicket: false })
+        .buyTicket({
+          numbers: finalNums,
+          useFreeTicket: false,
+          maxPriceUsdc: MAIN_TICKET_PRICE,
+        })
         .accountsPartial({
           player: player1.publicKey,
           lotteryState: mainPDAs.lotteryState,
@@ -522,6 +526,8 @@ describe("quickpick", () => {
             insurancePoolUsdc: qpPDAs.insurancePoolUsdc,
             usdcMint,
             userStats: userStatsPda,
+            mainLotteryState: mainPDAs.lotteryState,
+            mainLotteryProgram: mainProgramId,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
@@ -737,6 +743,8 @@ describe("quickpick", () => {
           insurancePoolUsdc: qpPDAs.insurancePoolUsdc,
           usdcMint,
           userStats: userStatsPda,
+          mainLotteryState: mainPDAs.lotteryState,
+          mainLotteryProgram: mainProgramId,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
@@ -795,6 +803,8 @@ describe("quickpick", () => {
           insurancePoolUsdc: qpPDAs.insurancePoolUsdc,
           usdcMint,
           userStats: userStatsPda,
+          mainLotteryState: mainPDAs.lotteryState,
+          mainLotteryProgram: mainProgramId,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
@@ -835,6 +845,8 @@ describe("quickpick", () => {
             insurancePoolUsdc: qpPDAs.insurancePoolUsdc,
             usdcMint,
             userStats: userStatsPda,
+            mainLotteryState: mainPDAs.lotteryState,
+            mainLotteryProgram: mainProgramId,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
@@ -878,6 +890,8 @@ describe("quickpick", () => {
             insurancePoolUsdc: qpPDAs.insurancePoolUsdc,
             usdcMint,
             userStats: userStatsPda,
+            mainLotteryState: mainPDAs.lotteryState,
+            mainLotteryProgram: mainProgramId,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
@@ -917,6 +931,8 @@ describe("quickpick", () => {
             insurancePoolUsdc: qpPDAs.insurancePoolUsdc,
             usdcMint,
             userStats: userStatsPda,
+            mainLotteryState: mainPDAs.lotteryState,
+            mainLotteryProgram: mainProgramId,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
@@ -956,6 +972,8 @@ describe("quickpick", () => {
             insurancePoolUsdc: qpPDAs.insurancePoolUsdc,
             usdcMint,
             userStats: userStatsPda,
+            mainLotteryState: mainPDAs.lotteryState,
+            mainLotteryProgram: mainProgramId,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
@@ -995,6 +1013,8 @@ describe("quickpick", () => {
             insurancePoolUsdc: qpPDAs.insurancePoolUsdc,
             usdcMint,
             userStats: userStatsPda,
+            mainLotteryState: mainPDAs.lotteryState,
+            mainLotteryProgram: mainProgramId,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
@@ -1049,6 +1069,8 @@ describe("quickpick", () => {
             insurancePoolUsdc: qpPDAs.insurancePoolUsdc,
             usdcMint,
             userStats: userStatsPda,
+            mainLotteryState: mainPDAs.lotteryState,
+            mainLotteryProgram: mainProgramId,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
@@ -1691,6 +1713,8 @@ describe("quickpick", () => {
             insurancePoolUsdc: qpPDAs.insurancePoolUsdc,
             usdcMint,
             userStats: userStatsPda,
+            mainLotteryState: mainPDAs.lotteryState,
+            mainLotteryProgram: mainProgramId,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })